@@ -59,5 +59,41 @@ fn parse(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, parse);
+fn parse_long(c: &mut Criterion) {
+    use usql::{ansi::AnsiDialect, Lexer, Parser};
+
+    let mut group = c.benchmark_group("parse_long");
+    group.sample_size(10);
+    let dialect = AnsiDialect::default();
+
+    let list = (0..100_000)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!("SELECT * FROM table1 WHERE id IN ({})", list);
+    let tokens = Lexer::new(&dialect, &query).tokenize().unwrap();
+    group.bench_function("usql in list 100k", |b| {
+        b.iter(|| {
+            let _stmt = black_box(
+                Parser::new_with_tokens(&dialect, tokens.clone())
+                    .parse_select_stmt()
+                    .unwrap(),
+            );
+        });
+    });
+
+    let query = format!("SELECT '{}' FROM table1", "x".repeat(1_000_000));
+    let tokens = Lexer::new(&dialect, &query).tokenize().unwrap();
+    group.bench_function("usql string literal 1m", |b| {
+        b.iter(|| {
+            let _stmt = black_box(
+                Parser::new_with_tokens(&dialect, tokens.clone())
+                    .parse_select_stmt()
+                    .unwrap(),
+            );
+        });
+    });
+}
+
+criterion_group!(benches, parse, parse_long);
 criterion_main!(benches);
@@ -46,5 +46,23 @@ fn tokenize(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, tokenize);
+fn tokenize_keywords(c: &mut Criterion) {
+    use usql::{postgres::PostgresDialect, Lexer};
+
+    let mut group = c.benchmark_group("tokenize_keywords");
+    // the words are looked up in the keywords of the dialect
+    let query = "SELECT a.id, b.name, count(*) AS total FROM accounts AS a \
+                 LEFT OUTER JOIN balances b ON a.id = b.account_id \
+                 WHERE a.created_at > CURRENT_DATE AND b.amount IS NOT NULL \
+                 GROUP BY a.id, b.name HAVING count(*) > 1 ORDER BY total DESC LIMIT 10;\n"
+        .repeat(100);
+    let dialect = PostgresDialect::default();
+    group.bench_function("usql keywords", |b| {
+        b.iter(|| {
+            let _tokens = black_box(Lexer::new(&dialect, &query).tokenize().unwrap());
+        });
+    });
+}
+
+criterion_group!(benches, tokenize, tokenize_keywords);
 criterion_main!(benches);
@@ -0,0 +1,133 @@
+//! Differential testing of usql against [sqlparser-rs](https://github.com/sqlparser-rs/sqlparser-rs).
+//!
+//! Every sample statement of the conformance tests (`usql/tests/samples/<dialect>.sql`) is parsed by
+//! both parsers with the corresponding dialects, and the statements which are accepted by only
+//! one of them are reported, the ones rejected by usql first:
+//!
+//! ```txt
+//! cargo run --manifest-path usql-compat/Cargo.toml [-- <dialect>...]
+//! ```
+//!
+//! **NOTE**: only the acceptance is compared, not the ASTs.
+
+use std::{env, fs, path::Path, process};
+
+use sqlparser::dialect as sp;
+
+/// A usql dialect and the closest sqlparser dialect, both of which parse the samples of the name.
+struct Target {
+    name: &'static str,
+    usql: fn(&str) -> Result<(), String>,
+    sqlparser: Box<dyn sp::Dialect>,
+}
+
+fn usql_parse<D: usql::Dialect + Default>(sql: &str) -> Result<(), String> {
+    usql::Parser::parse_script(&D::default(), sql)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+fn targets() -> Vec<Target> {
+    vec![
+        Target {
+            name: "postgres",
+            usql: usql_parse::<usql::postgres::PostgresDialect>,
+            sqlparser: Box::new(sp::PostgreSqlDialect {}),
+        },
+        // sqlparser has no MariaDB dialect
+        Target {
+            name: "mariadb",
+            usql: usql_parse::<usql::mariadb::MariadbDialect>,
+            sqlparser: Box::new(sp::MySqlDialect {}),
+        },
+        Target {
+            name: "mysql",
+            usql: usql_parse::<usql::mysql::MysqlDialect>,
+            sqlparser: Box::new(sp::MySqlDialect {}),
+        },
+        Target {
+            name: "sqlite",
+            usql: usql_parse::<usql::sqlite::SqliteDialect>,
+            sqlparser: Box::new(sp::SQLiteDialect {}),
+        },
+    ]
+}
+
+/// Returns the line numbers and the sample statements, one statement per line.
+fn load_samples(name: &str) -> Vec<(usize, String)> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../usql/tests/samples")
+        .join(format!("{}.sql", name));
+    let content = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err));
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("--"))
+        .map(|(line, sql)| (line, sql.to_string()))
+        .collect()
+}
+
+/// The result of parsing a statement by both parsers.
+#[derive(Default)]
+struct Report {
+    both: usize,
+    neither: usize,
+    /// `(line, sql, usql error)` of the statements accepted only by sqlparser.
+    usql_rejected: Vec<(usize, String, String)>,
+    /// `(line, sql, sqlparser error)` of the statements accepted only by usql.
+    sqlparser_rejected: Vec<(usize, String, String)>,
+}
+
+fn compare(target: &Target) -> Report {
+    let mut report = Report::default();
+    for (line, sql) in load_samples(target.name) {
+        let usql = (target.usql)(&sql);
+        let sqlparser = sqlparser::parser::Parser::parse_sql(&*target.sqlparser, &sql);
+        match (usql, sqlparser) {
+            (Ok(_), Ok(_)) => report.both += 1,
+            (Err(_), Err(_)) => report.neither += 1,
+            (Err(err), Ok(_)) => report.usql_rejected.push((line, sql, err)),
+            (Ok(_), Err(err)) => report.sqlparser_rejected.push((line, sql, err.to_string())),
+        }
+    }
+    report
+}
+
+fn main() {
+    let names = env::args().skip(1).collect::<Vec<_>>();
+    let targets = targets()
+        .into_iter()
+        .filter(|target| names.is_empty() || names.iter().any(|name| name == target.name))
+        .collect::<Vec<_>>();
+    if targets.is_empty() {
+        eprintln!("unknown dialects: {}", names.join(", "));
+        process::exit(1);
+    }
+
+    for target in &targets {
+        let report = compare(target);
+        println!(
+            "{}: both accepted {}, both rejected {}, only sqlparser accepted {}, only usql accepted {}",
+            target.name,
+            report.both,
+            report.neither,
+            report.usql_rejected.len(),
+            report.sqlparser_rejected.len(),
+        );
+        if !report.usql_rejected.is_empty() {
+            println!("\n  rejected by usql only:");
+            for (line, sql, err) in &report.usql_rejected {
+                println!("  {}:{}: {}\n    {}", target.name, line, sql, err);
+            }
+        }
+        if !report.sqlparser_rejected.is_empty() {
+            println!("\n  rejected by sqlparser only:");
+            for (line, sql, err) in &report.sqlparser_rejected {
+                println!("  {}:{}: {}\n    {}", target.name, line, sql, err);
+            }
+        }
+        println!();
+    }
+}
@@ -0,0 +1,444 @@
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
+use core::mem;
+
+use crate::{
+    analysis::{Catalog, TokenRewriter},
+    ast::{
+        expression::*,
+        statement::*,
+        types::{Ident, ObjectName},
+    },
+    dialect::{CaseFolding, Dialect, DialectLexerConf},
+    error::{parse_error, ParserError},
+    keywords::KeywordDef,
+    parser::Parser,
+    tokens::{Token, Word},
+};
+
+/// Returns the canonical form of the statement, so that two statements which differ only in
+/// the insignificant details are equal after canonicalization, e.g. for diffing the migrations
+/// between branches. The following rewrites are applied in order:
+///
+/// - `*` and `t.*` in the select list are expanded to the columns of the tables in the catalog,
+///   if all the tables of the `FROM` clause are in the catalog;
+/// - reserved keywords are upper-cased, the other words are replaced with their
+///   [normalized values](Ident::normalized_value), the unquoted ones are folded by the case
+///   folding of the dialect, and the quotes of quoted identifiers are removed if they are
+///   unnecessary, or unified to the quote character of the dialect, see [`Ident::to_sql`];
+/// - the column list of `INSERT ... VALUES` is sorted, together with the values of every row;
+/// - the operands of `AND` and `OR` chains, and the operands of `=` and `<>`, are sorted in the
+///   `WHERE`, `HAVING` and `ON` clauses.
+///
+/// The names in the catalog are treated as unquoted identifiers.
+///
+/// **NOTE**: The expressions are not traversed into, so the queries in the subquery expressions
+/// are not canonicalized. The operands are sorted regardless of their evaluation order, and the
+/// insertion values are sorted even if they refer to the columns set earlier (MySQL specific).
+pub fn canonicalize<D: Dialect>(
+    dialect: &D,
+    stmt: &Stmt,
+    catalog: Option<&Catalog>,
+) -> Result<Stmt, ParserError> {
+    let mut stmt = stmt.clone();
+    if let Some(catalog) = catalog {
+        walk_stmt(&mut stmt, &mut |spec| expand_wildcards(spec, catalog));
+    }
+    let mut stmt = normalize_idents(dialect, &stmt)?;
+    match &mut stmt {
+        Stmt::Insert(insert) => sort_insert_columns(insert),
+        Stmt::Update(UpdateStmt {
+            selection: Some(selection),
+            ..
+        })
+        | Stmt::Delete(DeleteStmt {
+            selection: Some(selection),
+            ..
+        }) => sort_predicates(&mut selection.expr),
+        _ => {}
+    }
+    walk_stmt(&mut stmt, &mut sort_query_spec);
+    Ok(stmt)
+}
+
+/// Calls the function on every query specification of the statement, the inner ones first.
+fn walk_stmt(stmt: &mut Stmt, f: &mut dyn FnMut(&mut QuerySpec)) {
+    match stmt {
+        Stmt::Select(SelectStmt(query)) => walk_query(query, f),
+        Stmt::Insert(insert) => walk_insert(insert, f),
+        Stmt::Update(update) => walk_with(&mut update.with, f),
+        Stmt::Delete(delete) => walk_with(&mut delete.with, f),
+        _ => {}
+    }
+}
+
+fn walk_insert(insert: &mut InsertStmt, f: &mut dyn FnMut(&mut QuerySpec)) {
+    walk_with(&mut insert.with, f);
+    if let InsertSource::Subquery { subquery, .. } = &mut insert.source {
+        walk_query(subquery, f);
+    }
+}
+
+fn walk_with(with: &mut Option<With>, f: &mut dyn FnMut(&mut QuerySpec)) {
+    for cte in with.iter_mut().flat_map(|with| &mut with.ctes) {
+        match &mut cte.body {
+            CteBody::Query(query) => walk_query(query, f),
+            CteBody::Insert(insert) => walk_insert(insert, f),
+            CteBody::Update(update) => walk_with(&mut update.with, f),
+            CteBody::Delete(delete) => walk_with(&mut delete.with, f),
+        }
+    }
+}
+
+fn walk_query(query: &mut Query, f: &mut dyn FnMut(&mut QuerySpec)) {
+    walk_with(&mut query.with, f);
+    walk_query_body(&mut query.body, f);
+}
+
+fn walk_query_body(body: &mut QueryBody, f: &mut dyn FnMut(&mut QuerySpec)) {
+    match body {
+        QueryBody::QuerySpec(spec) => {
+            for table in spec.from.iter_mut().flat_map(|from| &mut from.list) {
+                walk_table_reference(table, f);
+            }
+            f(spec);
+        }
+        QueryBody::Subquery(query) => walk_query(query, f),
+        QueryBody::Operation { left, right, .. } => {
+            walk_query_body(left, f);
+            walk_query_body(right, f);
+        }
+        _ => {}
+    }
+}
+
+fn walk_table_reference(table: &mut TableReference, f: &mut dyn FnMut(&mut QuerySpec)) {
+    walk_table_factor(&mut table.relation, f);
+    for join in &mut table.joins {
+        walk_table_factor(&mut join.relation, f);
+    }
+}
+
+fn walk_table_factor(factor: &mut TableFactor, f: &mut dyn FnMut(&mut QuerySpec)) {
+    match factor {
+        TableFactor::Derived { subquery, .. } => walk_query(subquery, f),
+        TableFactor::NestedJoin(table) => walk_table_reference(table, f),
+        _ => {}
+    }
+}
+
+fn expand_wildcards(spec: &mut QuerySpec, catalog: &Catalog) {
+    let tables = match spec
+        .from
+        .as_ref()
+        .and_then(|from| table_columns(from, catalog))
+    {
+        Some(tables) => tables,
+        None => return,
+    };
+    let column = |qualifier: &ObjectName, column: &Ident, qualified: bool| {
+        let expr = if qualified {
+            let mut idents = qualifier.0.clone();
+            idents.push(column.clone());
+            Expr::CompoundIdentifier(idents)
+        } else {
+            Expr::Identifier(column.clone())
+        };
+        SelectItem::DerivedColumn {
+            expr: Box::new(expr),
+            alias: None,
+        }
+    };
+    let mut projection = Vec::with_capacity(spec.projection.len());
+    for item in mem::take(&mut spec.projection) {
+        match item {
+            SelectItem::Wildcard => {
+                for (qualifier, columns) in &tables {
+                    projection.extend(
+                        columns
+                            .iter()
+                            .map(|c| column(qualifier, c, tables.len() > 1)),
+                    );
+                }
+            }
+            SelectItem::QualifiedWildcard(prefix) => {
+                match tables
+                    .iter()
+                    .find(|(qualifier, _)| same_name(qualifier, &prefix))
+                {
+                    Some((qualifier, columns)) => {
+                        projection.extend(columns.iter().map(|c| column(qualifier, c, true)))
+                    }
+                    None => projection.push(SelectItem::QualifiedWildcard(prefix)),
+                }
+            }
+            item => projection.push(item),
+        }
+    }
+    spec.projection = projection;
+}
+
+/// Returns the qualifiers and the columns of the tables in the `FROM` clause, or `None` if
+/// the columns of any table are unknown, or the columns are merged by a `NATURAL` or `USING`
+/// join.
+fn table_columns(from: &From, catalog: &Catalog) -> Option<Vec<(ObjectName, Vec<Ident>)>> {
+    let mut tables = vec![];
+    for table in &from.list {
+        for join in &table.joins {
+            match &join.join {
+                JoinOperator::CrossJoin => {}
+                JoinOperator::InnerJoin(spec)
+                | JoinOperator::LeftOuterJoin(spec)
+                | JoinOperator::RightOuterJoin(spec)
+                | JoinOperator::FullOuterJoin(spec)
+                    if matches!(spec, JoinSpec::On(_)) => {}
+                _ => return None,
+            }
+        }
+        let factors = Some(&table.relation)
+            .into_iter()
+            .chain(table.joins.iter().map(|join| &join.relation));
+        for factor in factors {
+            let (name, alias) = match factor {
+                TableFactor::Table { name, alias, .. } => (name, alias),
+                _ => return None,
+            };
+            let columns = match catalog.columns(name) {
+                Some(columns) if !columns.is_empty() => columns,
+                _ => return None,
+            };
+            let columns = match alias.as_ref().and_then(|alias| alias.columns.as_ref()) {
+                Some(columns) => columns.clone(),
+                None => columns.iter().map(|c| Ident::new(c.as_str())).collect(),
+            };
+            let qualifier = match alias {
+                Some(alias) => ObjectName(vec![alias.name.clone()]),
+                None => name.clone(),
+            };
+            tables.push((qualifier, columns));
+        }
+    }
+    Some(tables)
+}
+
+fn same_name(left: &ObjectName, right: &ObjectName) -> bool {
+    left.0.len() == right.0.len()
+        && left.0.iter().zip(&right.0).all(|(l, r)| {
+            l.normalized_value()
+                .eq_ignore_ascii_case(&r.normalized_value())
+        })
+}
+
+/// Normalizes the identifiers, folds the unquoted ones and normalizes the quotes of the quoted
+/// ones, by rewriting the tokens of the statement and parsing it again.
+fn normalize_idents<D: Dialect>(dialect: &D, stmt: &Stmt) -> Result<Stmt, ParserError> {
+    let sql = stmt.to_string();
+    let mut rewriter = TokenRewriter::new(dialect, &sql)?;
+    let folding = dialect.lexer_conf().identifier_case_folding();
+    rewriter.replace_tokens(|token| match token {
+        // the reserved keywords may be kept as they are in the AST, like `DEFAULT` in `VALUES`
+        Token::Word(Word {
+            keyword: Some(keyword),
+            value,
+            quote: None,
+        }) if D::Keyword::RESERVED_KEYWORDS.contains(keyword) => Some(value.to_uppercase()),
+        Token::Word(word) => {
+            let value = Ident::new(word.value.as_str()).normalized_value();
+            match (word.quote, folding) {
+                (None, CaseFolding::Upper) => Some(value.to_uppercase()),
+                (None, CaseFolding::Lower) => Some(value.to_lowercase()),
+                (None, CaseFolding::None) => Some(value),
+                (Some(_), _) => Some(Ident::new(value).to_sql(dialect)),
+            }
+        }
+        _ => None,
+    });
+    let sql = rewriter.render();
+    let mut parser = Parser::new_with_sql(dialect, &sql)?;
+    let stmt = parser.parse_stmt()?;
+    if parser.peek_token().is_some() {
+        return parse_error(format!("The canonical SQL is not a statement: {}", sql));
+    }
+    Ok(stmt)
+}
+
+fn sort_insert_columns(insert: &mut InsertStmt) {
+    if let InsertSource::Values {
+        columns: Some(columns),
+        values,
+        ..
+    } = &mut insert.source
+    {
+        if values.list.iter().any(|row| row.len() != columns.len()) {
+            return;
+        }
+        let mut order = (0..columns.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| columns[a].value.cmp(&columns[b].value));
+        *columns = order.iter().map(|&i| columns[i].clone()).collect();
+        for row in &mut values.list {
+            *row = order.iter().map(|&i| row[i].clone()).collect();
+        }
+    }
+}
+
+fn sort_query_spec(spec: &mut QuerySpec) {
+    if let Some(selection) = &mut spec.r#where {
+        sort_predicates(&mut selection.expr);
+    }
+    if let Some(having) = &mut spec.having {
+        sort_predicates(&mut having.expr);
+    }
+    for table in spec.from.iter_mut().flat_map(|from| &mut from.list) {
+        for join in &mut table.joins {
+            match &mut join.join {
+                JoinOperator::InnerJoin(JoinSpec::On(expr))
+                | JoinOperator::LeftOuterJoin(JoinSpec::On(expr))
+                | JoinOperator::RightOuterJoin(JoinSpec::On(expr))
+                | JoinOperator::FullOuterJoin(JoinSpec::On(expr)) => sort_predicates(expr),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn sort_predicates(expr: &mut Box<Expr>) {
+    **expr = sort_operands(expr);
+}
+
+fn sort_operands(expr: &Expr) -> Expr {
+    match expr {
+        Expr::BinaryOp(BinaryOpExpr {
+            op: op @ (BinaryOperator::And | BinaryOperator::Or),
+            ..
+        }) => {
+            let mut operands = vec![];
+            flatten(expr, *op, &mut operands);
+            let mut operands = operands.iter().map(sort_operands).collect::<Vec<_>>();
+            operands.sort_by_cached_key(|operand| operand.to_string());
+            operands
+                .into_iter()
+                .reduce(|left, right| Expr::binary(left, *op, right))
+                .expect("the chain has at least two operands")
+        }
+        // `a = b = c` is `(a = b) = c`, the operands are not swapped if any of them is a binary
+        // operation, which may be parsed differently after swapping.
+        Expr::BinaryOp(BinaryOpExpr {
+            left,
+            op: op @ (BinaryOperator::Equal | BinaryOperator::NotEqual),
+            right,
+        }) if !matches!(**left, Expr::BinaryOp(_)) && !matches!(**right, Expr::BinaryOp(_)) => {
+            let (left, right) = (sort_operands(left), sort_operands(right));
+            if right.to_string() < left.to_string() {
+                Expr::binary(right, *op, left)
+            } else {
+                Expr::binary(left, *op, right)
+            }
+        }
+        Expr::UnaryOp(UnaryOpExpr { op, expr }) => Expr::unary(op.clone(), sort_operands(expr)),
+        Expr::Nested(inner) => Expr::Nested(Box::new(sort_operands(inner))),
+        expr => expr.clone(),
+    }
+}
+
+/// Collects the operands of the chain of the operator, the parenthesized chains of the same
+/// operator are flattened too.
+fn flatten(expr: &Expr, op: BinaryOperator, operands: &mut Vec<Expr>) {
+    match expr {
+        Expr::BinaryOp(BinaryOpExpr {
+            left,
+            op: chain_op,
+            right,
+        }) if *chain_op == op => {
+            flatten(left, op, operands);
+            flatten(right, op, operands);
+        }
+        Expr::Nested(inner) if matches!(&**inner, Expr::BinaryOp(BinaryOpExpr { op: chain_op, .. }) if *chain_op == op) => {
+            flatten(inner, op, operands)
+        }
+        expr => operands.push(expr.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mysql::MysqlDialect, postgres::PostgresDialect};
+
+    fn canonical<D: Dialect>(
+        dialect: &D,
+        sql: &str,
+        catalog: Option<&Catalog>,
+    ) -> Result<String, ParserError> {
+        let stmt = Parser::new_with_sql(dialect, sql)?.parse_stmt()?;
+        Ok(canonicalize(dialect, &stmt, catalog)?.to_string())
+    }
+
+    #[test]
+    fn canonicalize_stmts() -> Result<(), ParserError> {
+        let dialect = PostgresDialect::default();
+        let cases = [
+            (
+                "select \"A\", \"b\", C from Foo WHERE C = 1 AND (b > 2 or 3 = a) and \"A\" IS NULL",
+                "SELECT \"A\", b, c FROM foo WHERE \"A\" IS NULL AND (3 = a OR b > 2) AND 1 = c",
+            ),
+            (
+                "INSERT INTO t (c, a, b) VALUES (1, 2, 3), (4, 5, DEFAULT)",
+                "INSERT INTO t (a, b, c) VALUES (2, 3, 1), (5, DEFAULT, 4)",
+            ),
+            (
+                "SELECT * FROM a JOIN b ON b.id = a.id AND a.x > 0",
+                "SELECT * FROM a INNER JOIN b ON a.id = b.id AND a.x > 0",
+            ),
+            (
+                "DELETE FROM t WHERE (b = 1 OR a = 1) OR c = 1",
+                "DELETE FROM t WHERE 1 = a OR 1 = b OR 1 = c",
+            ),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(canonical(&dialect, sql, None)?, expected);
+            assert_eq!(canonical(&dialect, expected, None)?, expected);
+        }
+
+        let dialect = MysqlDialect::default();
+        assert_eq!(
+            canonical(&dialect, "SELECT `Foo`, `select` FROM T WHERE b = a", None)?,
+            "SELECT Foo, \"select\" FROM T WHERE a = b"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expand_wildcards() -> Result<(), ParserError> {
+        let dialect = PostgresDialect::default();
+        let mut catalog = Catalog::new();
+        catalog.add_table_with_columns("public.users", &["id", "name"]);
+        catalog.add_table_with_columns("orders", &["id", "user_id"]);
+        catalog.add_table("logs");
+
+        let cases = [
+            ("SELECT * FROM users", "SELECT id, name FROM users"),
+            (
+                "SELECT *, 1 FROM users u, public.orders",
+                "SELECT u.id, u.name, public.orders.id, public.orders.user_id, 1 FROM users AS u, public.orders",
+            ),
+            (
+                "SELECT o.* FROM users JOIN orders o ON o.user_id = users.id",
+                "SELECT o.id, o.user_id FROM users INNER JOIN orders AS o ON o.user_id = users.id",
+            ),
+            (
+                "SELECT * FROM (SELECT * FROM users) AS t (a, b)",
+                "SELECT * FROM (SELECT id, name FROM users) AS t (a, b)",
+            ),
+            // the columns are unknown
+            ("SELECT * FROM users, logs", "SELECT * FROM users, logs"),
+            (
+                "SELECT * FROM users NATURAL JOIN orders",
+                "SELECT * FROM users NATURAL INNER JOIN orders",
+            ),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(canonical(&dialect, sql, Some(&catalog))?, expected);
+        }
+        Ok(())
+    }
+}
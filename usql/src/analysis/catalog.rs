@@ -0,0 +1,71 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::ast::types::{Ident, ObjectName};
+
+/// The known tables of a database and their columns, which are used by the analyses that need
+/// the schema, like [`validate`](super::validate) and [`canonicalize`](super::canonicalize).
+///
+/// The table names are matched case-insensitively, and a qualified name matches an unqualified
+/// one with the same last part, e.g. `public.users` matches `users` and vice versa. The names
+/// are compared by their [normalized values](Ident::normalized_value).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Catalog {
+    tables: Vec<CatalogTable>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CatalogTable {
+    /// The normalized lowercase parts of the table name.
+    name: Vec<String>,
+    /// The column names, in the order of definition.
+    columns: Vec<String>,
+}
+
+impl Catalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a table by its (optionally qualified) name, like `public.users`.
+    pub fn add_table(&mut self, name: &str) {
+        self.add_table_with_columns(name, &[]);
+    }
+
+    /// Adds a table by its (optionally qualified) name, with the names of its columns in the
+    /// order of definition.
+    pub fn add_table_with_columns(&mut self, name: &str, columns: &[&str]) {
+        self.tables.push(CatalogTable {
+            name: name
+                .split('.')
+                .map(|part| Ident::new(part).normalized_value().to_lowercase())
+                .collect(),
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+        });
+    }
+
+    /// Returns true if the table is in the catalog.
+    pub fn contains_table(&self, name: &ObjectName) -> bool {
+        self.find_table(name).is_some()
+    }
+
+    /// Returns the columns of the table, or `None` if the table is not in the catalog.
+    pub fn columns(&self, name: &ObjectName) -> Option<&[String]> {
+        self.find_table(name).map(|table| table.columns.as_slice())
+    }
+
+    fn find_table(&self, name: &ObjectName) -> Option<&CatalogTable> {
+        let parts = name
+            .0
+            .iter()
+            .map(|ident| ident.normalized_value().to_lowercase())
+            .collect::<Vec<_>>();
+        self.tables
+            .iter()
+            .find(|table| table.name.ends_with(&parts) || parts.ends_with(&table.name))
+    }
+}
@@ -0,0 +1,385 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    ast::types::{Ident, ObjectName},
+    dialect::Dialect,
+    error::{LexerError, LineColumn, ParserError, Span},
+    keywords::{Keyword, KeywordDef},
+    lexer::Lexer,
+    parser::Parser,
+    tokens::{Token, Word},
+};
+
+/// An identifier that stands for any name in the trial parses of [`completion_context`].
+const SENTINEL: &str = "usql_completion_sentinel";
+
+/// The second keywords of the compound keywords which are only matched as a whole,
+/// like `BY` of `ORDER BY`.
+const COMPOUND_SECOND: &[Keyword] = &[
+    Keyword::BY,
+    Keyword::EXISTS,
+    Keyword::KEY,
+    Keyword::LEVEL,
+    Keyword::NOT,
+    Keyword::NULL,
+    Keyword::TO,
+];
+
+/// The keywords after which a table name is expected.
+const TABLE_CONTEXT: &[Keyword] = &[
+    Keyword::FROM,
+    Keyword::INTO,
+    Keyword::JOIN,
+    Keyword::ON,
+    Keyword::REFERENCES,
+    Keyword::TABLE,
+    Keyword::UPDATE,
+];
+
+/// The keywords after which a column name is expected.
+const COLUMN_CONTEXT: &[Keyword] = &[Keyword::COLUMN, Keyword::SET];
+
+/// The auto-completion context at a cursor position, see [`completion_context`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CompletionContext {
+    /// The partially typed word before the cursor, which is empty if there is none.
+    pub prefix: String,
+    /// The byte range of the partially typed word, which is replaced by the completion.
+    pub span: Span,
+    /// The kind of name that is expected at the cursor, if any.
+    pub name: Option<NameContext>,
+    /// The keywords that can follow at the cursor (starting with the prefix), in sorted order.
+    pub keywords: Vec<Keyword>,
+}
+
+/// The kind of name that is expected at the cursor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NameContext {
+    /// A table name, optionally qualified by a schema name, like `s.` in `SELECT * FROM s.`.
+    Table {
+        /// The qualifier written before the cursor.
+        qualifier: Option<ObjectName>,
+    },
+    /// A column name (or the start of an expression), optionally qualified by a table name or
+    /// alias, like `t.` in `SELECT t.`.
+    Column {
+        /// The qualifier written before the cursor.
+        qualifier: Option<ObjectName>,
+    },
+    /// A data type, like in `CREATE TABLE t (a `.
+    DataType,
+}
+
+/// Reports what is syntactically expected at the byte offset `cursor` of the SQL text, for
+/// editor auto-completion, e.g. a table name after `SELECT * FROM `, a column name of `t`
+/// after `SELECT t.`, or the keywords `WHERE`, `ORDER`, ... after `SELECT * FROM t `.
+///
+/// Only the text of the current statement before the cursor is considered, so the statement
+/// can be incomplete and the text after the cursor doesn't matter. The keywords are found by
+/// trying every keyword of the dialect at the cursor, keywords that are only accepted as
+/// identifiers are left out. If there is a syntax error before the cursor, nothing is expected.
+///
+/// An error is returned if the text before the cursor can't be tokenized, like an unterminated
+/// string literal, or the cursor is beyond the end of the text or not on a character boundary.
+pub fn completion_context<D: Dialect>(
+    sql: &str,
+    cursor: usize,
+    dialect: &D,
+) -> Result<CompletionContext, LexerError> {
+    let text = match sql.get(..cursor) {
+        Some(text) => text,
+        None => {
+            // the error is located at the last character boundary before the cursor
+            let offset = (0..=cursor.min(sql.len()))
+                .rev()
+                .find(|&offset| sql.is_char_boundary(offset))
+                .unwrap_or_default();
+            return Err(LineColumn::from_offset(sql, offset)
+                .into_error("Invalid cursor, which is not on a character boundary of the text"));
+        }
+    };
+    let mut tokens = Lexer::new(dialect, text)
+        .tokenize_with_spans()?
+        .into_iter()
+        .filter(|(token, _)| !token.is_whitespace() && !token.is_comment())
+        .collect::<Vec<_>>();
+    // only the current statement is considered
+    if let Some(pos) = tokens
+        .iter()
+        .rposition(|(token, _)| *token == Token::SemiColon)
+    {
+        tokens.drain(..=pos);
+    }
+
+    let mut context = CompletionContext {
+        span: Span::new(cursor, cursor),
+        ..Default::default()
+    };
+    // the partially typed word before the cursor
+    if let Some((
+        Token::Word(Word {
+            value, quote: None, ..
+        }),
+        span,
+    )) = tokens.last()
+    {
+        if span.end == cursor {
+            context.prefix = value.clone();
+            context.span = *span;
+            tokens.pop();
+        }
+    }
+    let mut tokens = tokens
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect::<Vec<_>>();
+    // the qualifier of the name, like `a.b.`
+    let mut qualifier = vec![];
+    while tokens.last() == Some(&Token::Period) {
+        match tokens.len().checked_sub(2).map(|pos| &tokens[pos]) {
+            Some(Token::Word(word)) => {
+                qualifier.insert(
+                    0,
+                    Ident {
+                        value: word.value.clone(),
+                        quote: word.quote,
+                    },
+                );
+                tokens.truncate(tokens.len() - 2);
+            }
+            _ => break,
+        }
+    }
+    let qualifier = if qualifier.is_empty() { None } else { Some(ObjectName(qualifier)) };
+
+    context.name = expected_name(dialect, &tokens, qualifier.clone());
+    if qualifier.is_none() {
+        context.keywords = expected_keywords(dialect, &tokens, &context.prefix);
+    }
+    Ok(context)
+}
+
+fn expected_name<D: Dialect>(
+    dialect: &D,
+    tokens: &[Token],
+    qualifier: Option<ObjectName>,
+) -> Option<NameContext> {
+    if tokens.is_empty() {
+        return None;
+    }
+    let mut parser = Parser::new_with_tokens(dialect, tokens.to_vec());
+    let expected = match parser.parse_stmt() {
+        Err(ParserError::UnexpectedEof { expected }) => expected,
+        _ => return None,
+    };
+    if expected.contains("data type") {
+        Some(NameContext::DataType)
+    } else if expected.contains("expression") {
+        Some(NameContext::Column { qualifier })
+    } else if expected.contains("identifier") {
+        // a new alias is not completed
+        if tokens
+            .last()
+            .is_some_and(|token| token.is_keyword(Keyword::AS))
+        {
+            None
+        } else if is_table_context(tokens) {
+            Some(NameContext::Table { qualifier })
+        } else {
+            Some(NameContext::Column { qualifier })
+        }
+    } else {
+        None
+    }
+}
+
+/// Checks if the identifier at the end of the tokens is a table name, by the nearest clause
+/// keyword before it, a name in an unclosed parenthesis is a column name.
+fn is_table_context(tokens: &[Token]) -> bool {
+    let mut depth = 0usize;
+    for token in tokens.iter().rev() {
+        match token {
+            Token::RightParen => depth += 1,
+            Token::LeftParen if depth == 0 => return false,
+            Token::LeftParen => depth -= 1,
+            Token::Word(Word {
+                keyword: Some(keyword),
+                quote: None,
+                ..
+            }) if depth == 0 => {
+                if TABLE_CONTEXT.contains(keyword) {
+                    return true;
+                } else if COLUMN_CONTEXT.contains(keyword) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+fn expected_keywords<D: Dialect>(dialect: &D, tokens: &[Token], prefix: &str) -> Vec<Keyword> {
+    let prefix = prefix.to_uppercase();
+    let word = |value: &str| Token::word::<D::Keyword, _>(value, None);
+    // a keyword is tried alone, then followed by the second keyword of a compound keyword
+    let continuations = core::iter::once(None)
+        .chain(
+            COMPOUND_SECOND
+                .iter()
+                .map(|keyword| Some(word(&keyword.to_string()))),
+        )
+        .collect::<Vec<_>>();
+    // a keyword that is accepted the same way as an arbitrary identifier is not suggested
+    let sentinels = continuations
+        .iter()
+        .map(|next| trial_parse(dialect, tokens, word(SENTINEL), next.clone()))
+        .collect::<Vec<_>>();
+    D::Keyword::KEYWORDS_STRING
+        .iter()
+        .zip(D::Keyword::KEYWORDS)
+        .filter(|(value, _)| value.starts_with(prefix.as_str()))
+        .filter(|(value, _)| {
+            continuations
+                .iter()
+                .zip(&sentinels)
+                .any(|(next, sentinel)| {
+                    match trial_parse(dialect, tokens, word(value), next.clone()) {
+                        Some(outcome) => {
+                            sentinel.as_ref().map(|s| s.replace(SENTINEL, value)) != Some(outcome)
+                        }
+                        None => false,
+                    }
+                })
+        })
+        .map(|(_, keyword)| *keyword)
+        .collect()
+}
+
+/// Parses the tokens followed by the word (and the next word), returns the outcome if the words
+/// are accepted, i.e. the statement is complete or the parser fails at the end of the input.
+fn trial_parse<D: Dialect>(
+    dialect: &D,
+    tokens: &[Token],
+    word: Token,
+    next: Option<Token>,
+) -> Option<String> {
+    let mut tokens = tokens.to_vec();
+    tokens.push(word);
+    tokens.extend(next);
+    let mut parser = Parser::new_with_tokens(dialect, tokens);
+    match parser.parse_stmt() {
+        Ok(stmt) if parser.peek_token().is_none() => Some(format!("{:?}", stmt)),
+        Err(err @ ParserError::UnexpectedEof { .. }) => Some(err.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mysql::MysqlDialect, postgres::PostgresDialect};
+
+    fn context(sql: &str) -> CompletionContext {
+        let dialect = PostgresDialect::default();
+        completion_context(sql, sql.len(), &dialect).unwrap()
+    }
+
+    fn qualifier(name: &str) -> Option<ObjectName> {
+        Some(ObjectName(vec![Ident::new(name)]))
+    }
+
+    #[test]
+    fn completion_names() {
+        let column = Some(NameContext::Column { qualifier: None });
+        let table = Some(NameContext::Table { qualifier: None });
+        assert_eq!(context("SELECT ").name, column);
+        assert_eq!(context("SELECT a FROM ").name, table);
+        assert_eq!(context("SELECT a FROM t, ").name, table);
+        assert_eq!(context("SELECT a FROM t JOIN u ON ").name, column);
+        assert_eq!(context("SELECT a FROM t WHERE b = 1 AND ").name, column);
+        assert_eq!(context("INSERT INTO ").name, table);
+        assert_eq!(context("INSERT INTO t (").name, column);
+        assert_eq!(context("UPDATE t SET ").name, column);
+        assert_eq!(
+            context("CREATE TABLE t (a ").name,
+            Some(NameContext::DataType)
+        );
+        assert_eq!(context("SELECT a FROM t ").name, None);
+        // syntax error before the cursor
+        assert_eq!(context("SELECT FROM WHERE ").name, None);
+
+        let ctx = context("SELECT t.na");
+        assert_eq!(ctx.prefix, "na");
+        assert_eq!(ctx.span, Span::new(9, 11));
+        assert_eq!(
+            ctx.name,
+            Some(NameContext::Column {
+                qualifier: qualifier("t")
+            })
+        );
+        assert!(ctx.keywords.is_empty());
+        assert_eq!(
+            context("SELECT * FROM s.").name,
+            Some(NameContext::Table {
+                qualifier: qualifier("s")
+            })
+        );
+        // only the current statement is considered
+        assert_eq!(context("SELECT 1; SELECT a FROM ").name, table);
+        let sql = "SELECT a FROM t; SELECT ";
+        let dialect = PostgresDialect::default();
+        assert_eq!(completion_context(sql, 14, &dialect).unwrap().name, table);
+    }
+
+    #[test]
+    fn completion_cursor() {
+        let dialect = MysqlDialect::default();
+        let sql = "SELECT `naïve` FROM t WHERE naï";
+        let ctx = completion_context(sql, sql.len(), &dialect).unwrap();
+        assert_eq!(ctx.prefix, "naï");
+        assert_eq!(ctx.span, Span::new(29, 33));
+        assert_eq!(ctx.name, Some(NameContext::Column { qualifier: None }));
+
+        // the cursor must be on a character boundary within the text
+        let err = completion_context(sql, 11, &dialect).unwrap_err();
+        assert_eq!(err.location, LineColumn::new(1, 10));
+        let err = completion_context(sql, sql.len() + 1, &dialect).unwrap_err();
+        assert_eq!(err.location, LineColumn::new(1, 31));
+    }
+
+    #[test]
+    fn completion_keywords() {
+        let ctx = context("");
+        assert!(ctx.keywords.contains(&Keyword::SELECT));
+        assert!(ctx.keywords.contains(&Keyword::INSERT));
+        assert!(!ctx.keywords.contains(&Keyword::FROM));
+
+        let ctx = context("SELECT a FROM t ");
+        for keyword in [
+            Keyword::WHERE,
+            Keyword::ORDER,
+            Keyword::LIMIT,
+            Keyword::JOIN,
+        ] {
+            assert!(ctx.keywords.contains(&keyword), "{:?}", keyword);
+        }
+        assert!(!ctx.keywords.contains(&Keyword::SELECT));
+
+        let ctx = context("SELECT a FROM t WH");
+        assert_eq!(ctx.prefix, "WH");
+        assert_eq!(ctx.keywords, vec![Keyword::WHERE]);
+
+        // keywords that are only accepted as identifiers are left out
+        let ctx = context("SELECT a FROM t WHERE ");
+        assert!(ctx.keywords.contains(&Keyword::NOT));
+        assert!(ctx.keywords.contains(&Keyword::CASE));
+        assert!(!ctx.keywords.contains(&Keyword::ABORT));
+    }
+}
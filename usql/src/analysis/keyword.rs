@@ -0,0 +1,246 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{
+    ast::{
+        statement::*,
+        types::{Ident, ObjectName},
+    },
+    dialect::{Dialect, DialectLexerConf},
+    keywords::KeywordDef,
+};
+
+/// A name defined or referenced by a DDL statement that is a reserved keyword of the dialect,
+/// found by [`keyword_conflicts`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeywordConflict {
+    /// The kind of the named object, e.g. `table` or `column`.
+    pub kind: &'static str,
+    /// The conflicting identifier.
+    pub ident: Ident,
+    /// The identifier quoted for the dialect, which can be used safely.
+    pub quoted: String,
+}
+
+impl fmt::Display for KeywordConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The {} name `{}` is a reserved keyword, quote it as {}",
+            self.kind, self.ident.value, self.quoted
+        )
+    }
+}
+
+/// Returns the names of the DDL statement that collide with the reserved keywords of the
+/// dialect, e.g. a column named `order` when the statement is migrated to MySQL, together with
+/// the quoting needed to make them safe.
+///
+/// An identifier is reported if it's unquoted, or quoted with a quote character that isn't
+/// supported by the dialect, e.g. `"order"` is a string literal in MySQL.
+/// The names of the tables, views, columns, types, etc. are checked, the expressions and queries
+/// in the statement are not.
+pub fn keyword_conflicts<D: Dialect>(dialect: &D, stmt: &Stmt) -> Vec<KeywordConflict> {
+    let mut checker = Checker {
+        dialect,
+        conflicts: Vec::new(),
+    };
+    checker.check_stmt(stmt);
+    checker.conflicts
+}
+
+struct Checker<'a, D: Dialect> {
+    dialect: &'a D,
+    conflicts: Vec<KeywordConflict>,
+}
+
+impl<D: Dialect> Checker<'_, D> {
+    fn check_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::CreateTable(stmt) => {
+                self.check_name("table", &stmt.name);
+                if let TableContent::Definition { columns, .. } = &stmt.content {
+                    self.check_columns(columns);
+                }
+            }
+            Stmt::AlterTable(stmt) => {
+                self.check_name("table", &stmt.name);
+                match &stmt.action {
+                    AlterTableAction::AddColumn { column, .. } => {
+                        self.check_columns(core::slice::from_ref(column))
+                    }
+                    AlterTableAction::DropColumn { name, .. } => self.check_ident("column", name),
+                    AlterTableAction::RenameTo(name) => self.check_name("table", name),
+                    _ => {}
+                }
+            }
+            Stmt::RenameTable(stmt) => {
+                for table in &stmt.tables {
+                    self.check_name("table", &table.from);
+                    self.check_name("table", &table.to);
+                }
+            }
+            Stmt::CreateView(stmt) => {
+                self.check_name("view", &stmt.name);
+                self.check_idents("column", stmt.columns.iter().flatten());
+            }
+            Stmt::AlterView(stmt) => {
+                self.check_name("view", &stmt.name);
+                match &stmt.action {
+                    AlterViewAction::As { columns, .. } => {
+                        self.check_idents("column", columns.iter().flatten())
+                    }
+                    AlterViewAction::RenameTo(name) => self.check_ident("view", name),
+                }
+            }
+            Stmt::CreateDomain(stmt) => self.check_name("domain", &stmt.name),
+            Stmt::AlterDomain(stmt) => self.check_name("domain", &stmt.name),
+            Stmt::CreateType(stmt) => {
+                self.check_name("type", &stmt.name);
+                if let Some(TypeRepresentation::Attributes(attrs)) = &stmt.representation {
+                    self.check_idents("attribute", attrs.iter().map(|attr| &attr.name));
+                }
+            }
+            Stmt::AlterType(stmt) => {
+                self.check_name("type", &stmt.name);
+                match &stmt.action {
+                    AlterTypeAction::AddAttribute(attr) => {
+                        self.check_ident("attribute", &attr.name)
+                    }
+                    AlterTypeAction::DropAttribute(name) => self.check_ident("attribute", name),
+                }
+            }
+            Stmt::AlterSchema(stmt) => {
+                self.check_name("schema", &stmt.name);
+                if let AlterSchemaAction::RenameTo(name) = &stmt.action {
+                    self.check_ident("schema", name);
+                }
+            }
+            Stmt::AlterIndex(stmt) => {
+                self.check_name("index", &stmt.name);
+                if let AlterIndexAction::RenameTo(name) = &stmt.action {
+                    self.check_ident("index", name);
+                }
+            }
+            Stmt::CreateSequence(stmt) => self.check_name("sequence", &stmt.name),
+            Stmt::CreateDatabase(stmt) => self.check_name("database", &stmt.name),
+            Stmt::CreateForeignTable(stmt) => {
+                self.check_name("table", &stmt.name);
+                self.check_columns(&stmt.columns);
+            }
+            Stmt::Drop(stmt) => {
+                for name in &stmt.names {
+                    self.check_name("object", name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_columns(&mut self, columns: &[ColumnDef]) {
+        for column in columns {
+            self.check_ident("column", &column.name);
+            for constraint in &column.constraints {
+                if let Some(name) = &constraint.name {
+                    self.check_name("constraint", name);
+                }
+            }
+        }
+    }
+
+    fn check_name(&mut self, kind: &'static str, name: &ObjectName) {
+        self.check_idents(kind, &name.0);
+    }
+
+    fn check_idents<'i>(
+        &mut self,
+        kind: &'static str,
+        idents: impl IntoIterator<Item = &'i Ident>,
+    ) {
+        for ident in idents {
+            self.check_ident(kind, ident);
+        }
+    }
+
+    fn check_ident(&mut self, kind: &'static str, ident: &Ident) {
+        let quoted = match ident.quote {
+            Some(quote) => self
+                .dialect
+                .lexer_conf()
+                .is_delimited_identifier_start(quote),
+            None => false,
+        };
+        let reserved = D::Keyword::lookup(&ident.value)
+            .map(|keyword| D::Keyword::RESERVED_KEYWORDS.contains(&keyword))
+            .unwrap_or(false);
+        if reserved && !quoted {
+            self.conflicts.push(KeywordConflict {
+                kind,
+                ident: ident.clone(),
+                quoted: ident.to_sql(self.dialect),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ansi::AnsiDialect, mysql::MysqlDialect, parser::Parser, postgres::PostgresDialect,
+    };
+
+    fn conflicts<S: Dialect, T: Dialect>(source: &S, target: &T, sql: &str) -> Vec<String> {
+        let stmt = Parser::new_with_sql(source, sql)
+            .unwrap()
+            .parse_stmt()
+            .unwrap();
+        keyword_conflicts(target, &stmt)
+            .iter()
+            .map(|conflict| conflict.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn reserved_keyword_conflicts() {
+        let postgres = PostgresDialect::default();
+        let ansi = AnsiDialect::default();
+        let mysql = MysqlDialect::default();
+
+        let sql =
+            "CREATE TABLE s.index (key INT, \"order\" INT, name TEXT CONSTRAINT change NOT NULL)";
+        assert_eq!(
+            conflicts(&postgres, &mysql, sql),
+            vec![
+                "The table name `index` is a reserved keyword, quote it as \"index\"",
+                "The column name `key` is a reserved keyword, quote it as \"key\"",
+                "The constraint name `change` is a reserved keyword, quote it as \"change\"",
+            ]
+        );
+        assert!(conflicts(&postgres, &postgres, sql).is_empty());
+
+        // `"order"` is a string literal in MySQL unless the ANSI_QUOTES mode is enabled
+        let mysql = MysqlDialect::new(
+            crate::mysql::MySqlLexerConfig {
+                ansi_quotes_mode: false,
+            },
+            Default::default(),
+        );
+        let sql = "ALTER TABLE t ADD COLUMN \"order\" INT";
+        assert_eq!(
+            conflicts(&postgres, &mysql, sql),
+            vec!["The column name `order` is a reserved keyword, quote it as `order`"]
+        );
+        let sql = "ALTER TABLE t ADD COLUMN `order` INT";
+        assert!(conflicts(&mysql, &mysql, sql).is_empty());
+
+        let sql = "CREATE VIEW v (a, range) AS SELECT 1, 2";
+        assert_eq!(
+            conflicts(&postgres, &ansi, sql),
+            vec!["The column name `range` is a reserved keyword, quote it as \"range\""]
+        );
+        assert!(conflicts(&postgres, &postgres, sql).is_empty());
+    }
+}
@@ -0,0 +1,567 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    analysis::{
+        resolve::{same_ident, select_list},
+        Diagnostic,
+    },
+    ast::{
+        expression::*,
+        statement::*,
+        types::{Ident, Literal, ObjectName},
+        visit::{walk_stmt, Visitor},
+    },
+    dialect::Dialect,
+    error::Span,
+    keywords::Keyword,
+    lexer::Lexer,
+    parser::{split_stmt_tokens, stmt_span, BatchSeparators, Parser},
+    tokens::Token,
+};
+
+/// Finds the common mistakes of the SQL script, which are valid SQL but are probably not
+/// treated as intended by the author, and returns them as warnings in source order:
+///
+/// - the chained comparisons like `a < b < c`, which are evaluated as `(a < b) < c`;
+/// - the comparisons with `NULL` like `a = NULL`, which are never true;
+/// - the assignments of `UPDATE` (or MySQL `INSERT ... SET`) separated by `AND` instead of `,`,
+///   like `SET a = 1 AND b = 2`, which assigns `1 AND b = 2` to `a`;
+/// - the common table expressions which are never referenced;
+/// - the table names or aliases specified more than once in a `FROM` clause;
+/// - the column aliases specified more than once in a select list;
+/// - the `ORDER BY` names which refer to multiple different select list items.
+///
+/// The statements that can't be parsed are skipped, see [`validate`](crate::analysis::validate)
+/// for the lexer and parse errors.
+///
+/// **NOTE**: the span of a warning covers the expression or the name if its tokens are found in
+/// the statement as written, otherwise (e.g. the expression is written with redundant
+/// parentheses) it covers the whole statement.
+pub fn lint<D: Dialect>(sql: &str, dialect: &D) -> Vec<Diagnostic> {
+    lint_with_separators(sql, dialect, BatchSeparators::default())
+}
+
+/// Finds the common mistakes of the SQL script like [`lint`], the statements may also be
+/// terminated by the batch separators, see [`Parser::parse_script_with_separators`].
+pub fn lint_with_separators<D: Dialect>(
+    sql: &str,
+    dialect: &D,
+    separators: BatchSeparators,
+) -> Vec<Diagnostic> {
+    let stmts = match split_stmt_tokens(dialect, sql, separators, &mut vec![]) {
+        Ok(stmts) => stmts,
+        Err(_) => return vec![],
+    };
+    let mut diagnostics = vec![];
+    for tokens in stmts {
+        let stmt = Parser::parse_single_stmt(
+            dialect,
+            tokens.iter().map(|(token, _)| token.clone()).collect(),
+        );
+        let stmt = match stmt {
+            Ok(stmt) => stmt,
+            Err(_) => continue,
+        };
+        let mut linter = Linter::default();
+        linter.visit_stmt(&stmt);
+        for warning in linter.warnings {
+            let spans = text_spans(dialect, &tokens, &warning.text);
+            let span = if warning.last { spans.last() } else { spans.first() };
+            let span = span.copied().unwrap_or_else(|| stmt_span(&tokens));
+            diagnostics.push(Diagnostic::warning(warning.message, span));
+        }
+    }
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.span.start, diagnostic.span.end));
+    diagnostics
+}
+
+/// A warning of a statement, which is located by the text where it's found.
+struct Warning {
+    message: String,
+    text: String,
+    /// Whether the warning is located at the last occurrence of the text instead of the first
+    /// one, e.g. a duplicate alias is found after the first one.
+    last: bool,
+}
+
+/// Collects the warnings of a statement.
+#[derive(Default)]
+struct Linter {
+    warnings: Vec<Warning>,
+    /// The common table expressions in scope, from the outermost `WITH` clause to the innermost
+    /// one, and whether they are referenced.
+    ctes: Vec<Vec<(Ident, bool)>>,
+}
+
+impl Linter {
+    fn warn(&mut self, message: String, text: String, last: bool) {
+        self.warnings.push(Warning {
+            message,
+            text,
+            last,
+        });
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        let with = match stmt {
+            Stmt::Insert(insert) => &insert.with,
+            Stmt::Update(update) => &update.with,
+            Stmt::Delete(delete) => &delete.with,
+            _ => &None,
+        };
+        self.enter_with(with);
+        walk_stmt(self, stmt);
+        self.exit_with(with);
+        self.visit_assignments(stmt);
+    }
+
+    fn enter_with(&mut self, with: &Option<With>) {
+        if let Some(with) = with {
+            let ctes = with.ctes.iter().map(|cte| (cte.name.clone(), false));
+            self.ctes.push(ctes.collect());
+        }
+    }
+
+    fn exit_with(&mut self, with: &Option<With>) {
+        if with.is_none() {
+            return;
+        }
+        for (name, used) in self.ctes.pop().unwrap_or_default() {
+            if !used {
+                let message = format!("The common table expression `{}` is never used", name);
+                self.warn(message, name.to_string(), false);
+            }
+        }
+    }
+
+    fn visit_assignments(&mut self, stmt: &Stmt) {
+        let assignments = match stmt {
+            Stmt::Update(update) => &update.assignments,
+            Stmt::Insert(InsertStmt {
+                source: InsertSource::Set(assignments),
+                ..
+            }) => assignments,
+            _ => return,
+        };
+        for assignment in assignments {
+            let value = assignment.value.as_ref();
+            if matches!(
+                value,
+                Expr::BinaryOp(BinaryOpExpr {
+                    op: BinaryOperator::And,
+                    ..
+                })
+            ) && is_assignment_like(value)
+            {
+                let message = format!(
+                    "The value of `{}` is `{}`, the assignments should be separated by `,` \
+                    instead of `AND`",
+                    assignment.target, value
+                );
+                self.warn(message, assignment.to_string(), false);
+            }
+        }
+    }
+
+    fn visit_query_body(&mut self, body: &QueryBody) {
+        match body {
+            QueryBody::QuerySpec(spec) => {
+                self.visit_from(spec);
+                self.visit_projection(spec);
+            }
+            QueryBody::Operation { left, right, .. } => {
+                self.visit_query_body(left);
+                self.visit_query_body(right);
+            }
+            // the subqueries are visited as queries
+            _ => {}
+        }
+    }
+
+    fn visit_from(&mut self, spec: &QuerySpec) {
+        let mut factors = vec![];
+        for table in spec.from.iter().flat_map(|from| &from.list) {
+            table_factors(table, &mut factors);
+        }
+        let mut names: Vec<(&[Ident], bool)> = vec![];
+        for factor in factors {
+            let (name, alias) = match factor {
+                TableFactor::Table {
+                    alias: Some(alias), ..
+                }
+                | TableFactor::Derived {
+                    alias: Some(alias), ..
+                }
+                | TableFactor::Function {
+                    alias: Some(alias), ..
+                } => (core::slice::from_ref(&alias.name), true),
+                TableFactor::Table { name, .. } => (name.0.as_slice(), false),
+                _ => continue,
+            };
+            let duplicate = names.iter().any(|(other, _)| {
+                other.len() == name.len() && other.iter().zip(name).all(|(a, b)| same_ident(a, b))
+            });
+            if duplicate {
+                let name = name.iter().map(|ident| ident.to_string());
+                let message = format!(
+                    "The table {} `{}` is specified more than once in the FROM clause",
+                    if alias { "alias" } else { "name" },
+                    name.collect::<Vec<_>>().join(".")
+                );
+                self.warn(message, factor.to_string(), true);
+            }
+            names.push((name, alias));
+        }
+    }
+
+    fn visit_projection(&mut self, spec: &QuerySpec) {
+        let mut aliases: Vec<&Ident> = vec![];
+        for item in &spec.projection {
+            if let SelectItem::DerivedColumn {
+                alias: Some(alias), ..
+            } = item
+            {
+                if aliases.iter().any(|other| same_ident(other, alias)) {
+                    let message = format!(
+                        "The column alias `{}` is specified more than once in the select list",
+                        alias
+                    );
+                    self.warn(message, item.to_string(), true);
+                }
+                aliases.push(alias);
+            }
+        }
+    }
+
+    fn visit_order_by(&mut self, query: &Query) {
+        let projection = select_list(&query.body);
+        for spec in query.order_by.iter().flat_map(|order_by| &order_by.list) {
+            let ident = match (spec.ordinal, spec.expr.as_ref()) {
+                (None, Expr::Identifier(ident)) => ident,
+                _ => continue,
+            };
+            let mut exprs = projection.iter().filter_map(|item| match item {
+                SelectItem::DerivedColumn {
+                    expr,
+                    alias: Some(alias),
+                } if same_ident(alias, ident) => Some(expr),
+                SelectItem::DerivedColumn { expr, alias: None }
+                    if output_name(expr).is_some_and(|name| same_ident(name, ident)) =>
+                {
+                    Some(expr)
+                }
+                _ => None,
+            });
+            if let Some(first) = exprs.next() {
+                if exprs.any(|expr| !same_expr(expr, first)) {
+                    let message = format!(
+                        "The ORDER BY name `{}` is ambiguous, it refers to multiple select list \
+                        items",
+                        ident
+                    );
+                    self.warn(message, ident.to_string(), true);
+                }
+            }
+        }
+    }
+}
+
+impl Visitor for Linter {
+    fn pre_visit_query(&mut self, query: &Query) {
+        self.enter_with(&query.with);
+        self.visit_query_body(&query.body);
+        self.visit_order_by(query);
+    }
+
+    fn post_visit_query(&mut self, query: &Query) {
+        self.exit_with(&query.with);
+    }
+
+    fn visit_table_name(&mut self, name: &ObjectName) {
+        if let [ident] = name.0.as_slice() {
+            // the innermost common table expression with the name
+            let cte = self
+                .ctes
+                .iter_mut()
+                .rev()
+                .flat_map(|ctes| ctes.iter_mut())
+                .find(|(cte, _)| same_ident(cte, ident));
+            if let Some((_, used)) = cte {
+                *used = true;
+            }
+        }
+    }
+
+    fn pre_visit_expr(&mut self, expr: &Expr) {
+        let binary = match expr {
+            Expr::BinaryOp(binary) if is_comparison(binary.op) => binary,
+            _ => return,
+        };
+        // only the innermost chain is reported, like `a < b < c` of `a < b < c < d`
+        if is_comparison_chain(expr) && !is_comparison_chain(&binary.left) {
+            let message = format!(
+                "Chained comparison `{}` is evaluated as `({}) {} {}`",
+                expr, binary.left, binary.op, binary.right
+            );
+            self.warn(message, expr.to_string(), false);
+        }
+        if matches!(binary.op, BinaryOperator::Equal | BinaryOperator::NotEqual)
+            && (is_null(&binary.left) || is_null(&binary.right))
+        {
+            let not = if binary.op == BinaryOperator::NotEqual { "NOT " } else { "" };
+            let message = format!(
+                "Comparison `{}` is never true, use `IS {}NULL` instead",
+                expr, not
+            );
+            self.warn(message, expr.to_string(), false);
+        }
+    }
+}
+
+fn is_comparison(op: BinaryOperator) -> bool {
+    matches!(
+        op,
+        BinaryOperator::Greater
+            | BinaryOperator::Less
+            | BinaryOperator::GreaterOrEqual
+            | BinaryOperator::LessOrEqual
+            | BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::NullSafeEqual
+    )
+}
+
+/// Returns true if the expression is a comparison whose left operand is a comparison without
+/// parentheses.
+fn is_comparison_chain(expr: &Expr) -> bool {
+    match expr {
+        Expr::BinaryOp(BinaryOpExpr { left, op, .. }) if is_comparison(*op) => matches!(
+            left.as_ref(),
+            Expr::BinaryOp(BinaryOpExpr { op, .. }) if is_comparison(*op)
+        ),
+        _ => false,
+    }
+}
+
+fn is_null(expr: &Expr) -> bool {
+    matches!(expr, Expr::Literal(Literal::Null))
+}
+
+/// Returns true if one of the `AND` operands is like an assignment, i.e. `column = value`.
+fn is_assignment_like(expr: &Expr) -> bool {
+    match expr {
+        Expr::BinaryOp(BinaryOpExpr {
+            left,
+            op: BinaryOperator::And,
+            right,
+        }) => is_assignment_like(left) || is_assignment_like(right),
+        Expr::BinaryOp(BinaryOpExpr {
+            left,
+            op: BinaryOperator::Equal,
+            ..
+        }) => matches!(
+            left.as_ref(),
+            Expr::Identifier(_) | Expr::CompoundIdentifier(_)
+        ),
+        _ => false,
+    }
+}
+
+/// Returns the output column name of a select list item without alias.
+fn output_name(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::Identifier(ident) => Some(ident),
+        Expr::CompoundIdentifier(idents) => idents.last(),
+        _ => None,
+    }
+}
+
+/// Returns true if the select list items are the same, a qualified column is supposed to be the
+/// same as the unqualified one with the same name, e.g. `t.a` and `a`.
+fn same_expr(left: &Expr, right: &Expr) -> bool {
+    match (left, right) {
+        (Expr::Identifier(_), Expr::CompoundIdentifier(_))
+        | (Expr::CompoundIdentifier(_), Expr::Identifier(_)) => {
+            match (output_name(left), output_name(right)) {
+                (Some(left), Some(right)) => same_ident(left, right),
+                _ => false,
+            }
+        }
+        _ => left == right,
+    }
+}
+
+/// Collects the table factors of a table reference, including the ones of the joins.
+fn table_factors<'a>(table: &'a TableReference, factors: &mut Vec<&'a TableFactor>) {
+    for factor in
+        core::iter::once(&table.relation).chain(table.joins.iter().map(|join| &join.relation))
+    {
+        match factor {
+            TableFactor::NestedJoin(table) => table_factors(table, factors),
+            factor => factors.push(factor),
+        }
+    }
+}
+
+/// Finds the byte ranges of the occurrences of the text in the tokens, the unquoted words are
+/// matched case-insensitively and the `AS` keywords are ignored, so that an alias is matched
+/// with or without `AS`.
+fn text_spans<D: Dialect>(dialect: &D, tokens: &[(Token, Span)], text: &str) -> Vec<Span> {
+    let is_as = |token: &Token| token.is_keyword(Keyword::AS);
+    let expected = match Lexer::new(dialect, text).tokenize() {
+        Ok(expected) => expected,
+        Err(_) => return vec![],
+    };
+    let expected = expected
+        .iter()
+        .filter(|token| !token.is_whitespace() && !token.is_comment() && !is_as(token))
+        .collect::<Vec<_>>();
+    let tokens = tokens
+        .iter()
+        .filter(|(token, _)| !is_as(token))
+        .collect::<Vec<_>>();
+    if expected.is_empty() {
+        return vec![];
+    }
+    tokens
+        .windows(expected.len())
+        .filter(|window| {
+            window
+                .iter()
+                .zip(&expected)
+                .all(|((token, _), expected)| match (token, expected) {
+                    (Token::Word(word), Token::Word(expected))
+                        if word.quote.is_none() && expected.quote.is_none() =>
+                    {
+                        word.value.eq_ignore_ascii_case(&expected.value)
+                    }
+                    (token, expected) => token == *expected,
+                })
+        })
+        .map(|window| Span::new(window[0].1.start, window[expected.len() - 1].1.end))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mysql::MysqlDialect;
+
+    /// Returns the message and the text of the warnings.
+    fn check(sql: &str) -> Vec<(String, &str)> {
+        let dialect = MysqlDialect::default();
+        lint(sql, &dialect)
+            .into_iter()
+            .map(|d| (d.message, &sql[d.span.start..d.span.end]))
+            .collect()
+    }
+
+    #[test]
+    fn lint_comparison() {
+        assert_eq!(
+            check("SELECT * FROM t WHERE a < b < c AND (x = y) = z"),
+            vec![(
+                "Chained comparison `a < b < c` is evaluated as `(a < b) < c`".into(),
+                "a < b < c"
+            )]
+        );
+        assert_eq!(
+            check("SELECT a<b<=c<d FROM t"),
+            vec![(
+                "Chained comparison `a < b <= c` is evaluated as `(a < b) <= c`".into(),
+                "a<b<=c"
+            )]
+        );
+        assert_eq!(
+            check("DELETE FROM t WHERE a = null OR b != NULL; SELECT a IS NULL"),
+            vec![
+                (
+                    "Comparison `a = NULL` is never true, use `IS NULL` instead".into(),
+                    "a = null"
+                ),
+                (
+                    "Comparison `b <> NULL` is never true, use `IS NOT NULL` instead".into(),
+                    "b != NULL"
+                ),
+            ]
+        );
+        // the statements that can't be parsed are skipped
+        assert_eq!(check("SELECT a = NULL FROM; SELECT 'a"), vec![]);
+    }
+
+    #[test]
+    fn lint_assignments() {
+        let sql = "UPDATE t SET a = 1 AND b = 2, c = d AND e WHERE f = 1; \
+            INSERT INTO t SET a = (1) AND t.b = 2";
+        assert_eq!(
+            check(sql),
+            vec![
+                (
+                    "The value of `a` is `1 AND b = 2`, the assignments should be separated by \
+                    `,` instead of `AND`"
+                        .into(),
+                    "a = 1 AND b = 2"
+                ),
+                (
+                    "The value of `a` is `(1) AND t.b = 2`, the assignments should be separated \
+                    by `,` instead of `AND`"
+                        .into(),
+                    "a = (1) AND t.b = 2"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn lint_names() {
+        let sql = "WITH a AS (SELECT 1), b AS (SELECT * FROM a), c AS (SELECT 2) \
+            SELECT x AS y, z y FROM b JOIN t AS b ON b.x = t.x, u b JOIN s.t ON true";
+        assert_eq!(
+            check(sql),
+            vec![
+                ("The common table expression `c` is never used".into(), "c"),
+                (
+                    "The column alias `y` is specified more than once in the select list".into(),
+                    "z y"
+                ),
+                (
+                    "The table alias `b` is specified more than once in the FROM clause".into(),
+                    "t AS b"
+                ),
+                (
+                    "The table alias `b` is specified more than once in the FROM clause".into(),
+                    "u b"
+                ),
+            ]
+        );
+        // the names are scoped by the queries and statements
+        let sql =
+            "WITH a AS (SELECT 1), b AS (SELECT 2) DELETE FROM t WHERE x IN (SELECT * FROM b); \
+            WITH a AS (SELECT 1) SELECT * FROM t WHERE x IN (SELECT x FROM a) \
+            AND EXISTS (SELECT * FROM u t)";
+        assert_eq!(
+            check(sql),
+            vec![("The common table expression `a` is never used".into(), "a")]
+        );
+
+        let sql = "SELECT a AS b, b, c, t.c, c FROM t ORDER BY b, c";
+        assert_eq!(
+            check(sql),
+            vec![(
+                "The ORDER BY name `b` is ambiguous, it refers to multiple select list items"
+                    .into(),
+                "b"
+            )]
+        );
+        assert_eq!(
+            check("SELECT a, b AS a FROM t UNION SELECT 1, 2 ORDER BY a").len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,29 @@
+mod canonical;
+mod catalog;
+mod completion;
+mod keyword;
+mod lint;
+mod predicate;
+mod redact;
+mod render;
+mod resolve;
+mod rewrite;
+mod semantic;
+mod simplify;
+mod validate;
+
+pub use self::{
+    canonical::canonicalize,
+    catalog::Catalog,
+    completion::{completion_context, CompletionContext, NameContext},
+    keyword::{keyword_conflicts, KeywordConflict},
+    lint::{lint, lint_with_separators},
+    predicate::{extract_predicates, referenced_columns, Predicate},
+    redact::redact,
+    render::render,
+    resolve::{resolve_references, ResolvedQuery, ResolvedRef},
+    rewrite::TokenRewriter,
+    semantic::{semantic_eq, semantic_hash},
+    simplify::simplify,
+    validate::{validate, validate_with_separators, Diagnostic, Severity},
+};
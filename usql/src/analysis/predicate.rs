@@ -0,0 +1,282 @@
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::ast::{
+    expression::*,
+    types::{Ident, ObjectName},
+};
+
+/// The maximum number of predicates produced when distributing `OR` over `AND`, the
+/// disjunction is kept as a single predicate if the conversion would exceed it.
+const MAX_CNF_PREDICATES: usize = 64;
+
+/// A predicate of the conjunctive normal form of a search condition.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Predicate {
+    /// The predicate expression, which doesn't contain a top-level `AND`.
+    pub expr: Expr,
+    /// The columns referenced by the predicate, in order of first appearance.
+    pub columns: Vec<ObjectName>,
+}
+
+/// Decomposes the search condition of the where clause into a list of predicates, whose
+/// conjunction is equivalent to the search condition.
+///
+/// `NOT` is pushed down through `AND` and `OR`, and `OR` is distributed over `AND`, e.g.
+/// `NOT (a = 1 OR b = 2) AND (c = 3 AND d = 4 OR e = 5)` is decomposed into
+/// `NOT a = 1`, `NOT b = 2`, `c = 3 OR e = 5` and `d = 4 OR e = 5`.
+pub fn extract_predicates(selection: &Where) -> Vec<Predicate> {
+    conjuncts(&selection.expr, false)
+        .into_iter()
+        .map(|expr| Predicate {
+            columns: referenced_columns(&expr),
+            expr,
+        })
+        .collect()
+}
+
+/// Returns the columns referenced by the expression, in order of first appearance.
+///
+/// Columns referenced inside subqueries are not included, because they may refer to the tables
+/// of the subquery.
+pub fn referenced_columns(expr: &Expr) -> Vec<ObjectName> {
+    let mut columns = vec![];
+    collect_columns(expr, &mut columns);
+    columns
+}
+
+fn conjuncts(expr: &Expr, negated: bool) -> Vec<Expr> {
+    match expr {
+        Expr::Nested(expr) => conjuncts(expr, negated),
+        Expr::UnaryOp(UnaryOpExpr {
+            op: UnaryOperator::Not,
+            expr,
+        }) => conjuncts(expr, !negated),
+        Expr::BinaryOp(BinaryOpExpr {
+            left,
+            op: op @ (BinaryOperator::And | BinaryOperator::Or),
+            right,
+        }) => {
+            // De Morgan's laws: `NOT (a AND b)` => `NOT a OR NOT b`, and vice versa.
+            let is_and = (*op == BinaryOperator::And) != negated;
+            let (left, right) = (conjuncts(left, negated), conjuncts(right, negated));
+            if is_and {
+                left.into_iter().chain(right).collect()
+            } else if left.len() * right.len() > MAX_CNF_PREDICATES {
+                vec![disjunction(conjunction(left), conjunction(right))]
+            } else {
+                let mut exprs = Vec::with_capacity(left.len() * right.len());
+                for l in &left {
+                    for r in &right {
+                        exprs.push(disjunction(l.clone(), r.clone()));
+                    }
+                }
+                exprs
+            }
+        }
+        expr if negated => vec![Expr::unary(UnaryOperator::Not, expr.clone())],
+        expr => vec![expr.clone()],
+    }
+}
+
+fn conjunction(exprs: Vec<Expr>) -> Expr {
+    let mut exprs = exprs.into_iter();
+    let first = exprs.next().expect("conjuncts are never empty");
+    let expr = exprs.fold(first, |left, right| {
+        Expr::binary(left, BinaryOperator::And, right)
+    });
+    match expr {
+        expr @ Expr::BinaryOp(_) => Expr::nested(expr),
+        expr => expr,
+    }
+}
+
+fn disjunction(left: Expr, right: Expr) -> Expr {
+    Expr::binary(left, BinaryOperator::Or, right)
+}
+
+fn collect_columns(expr: &Expr, columns: &mut Vec<ObjectName>) {
+    let mut push = |idents: &[Ident]| {
+        let column = ObjectName(idents.to_vec());
+        if !columns.contains(&column) {
+            columns.push(column);
+        }
+    };
+    match expr {
+        Expr::Identifier(ident) => push(core::slice::from_ref(ident)),
+        Expr::CompoundIdentifier(idents) => push(idents),
+        Expr::Literal(_)
+        | Expr::SpecialValue(_)
+        | Expr::PseudoColumn(_)
+        | Expr::NextValueFor(_)
+        | Expr::Wildcard
+        | Expr::QualifiedWildcard(_)
+        | Expr::Subquery(_)
+        | Expr::ArraySubquery(_)
+        | Expr::Exists(_) => {}
+        Expr::CompositeWildcard(expr)
+        | Expr::Nested(expr)
+        | Expr::IsNull(IsNullExpr { expr, .. })
+        | Expr::UnaryOp(UnaryOpExpr { expr, .. })
+        | Expr::InSubquery(InSubqueryExpr { expr, .. })
+        | Expr::Cast(CastExpr { expr, .. })
+        | Expr::CompositeField(CompositeFieldExpr { expr, .. })
+        | Expr::Collate(CollateExpr { expr, .. })
+        | Expr::Extract(ExtractExpr { expr, .. })
+        | Expr::Translate(TranslateExpr { expr, .. }) => collect_columns(expr, columns),
+        Expr::IsDistinctFrom(IsDistinctFromExpr { left, right, .. })
+        | Expr::BinaryOp(BinaryOpExpr { left, right, .. })
+        | Expr::NullIf(NullIfExpr { left, right }) => {
+            collect_columns(left, columns);
+            collect_columns(right, columns);
+        }
+        Expr::InList(InListExpr { expr, list, .. }) => {
+            collect_columns(expr, columns);
+            list.iter().for_each(|expr| collect_columns(expr, columns));
+        }
+        Expr::Coalesce(exprs)
+        | Expr::Greatest(exprs)
+        | Expr::Least(exprs)
+        | Expr::Array(ArrayExpr {
+            elements: exprs, ..
+        }) => {
+            exprs.iter().for_each(|expr| collect_columns(expr, columns));
+        }
+        Expr::If(IfExpr {
+            condition,
+            then_result,
+            else_result,
+            ..
+        }) => {
+            collect_columns(condition, columns);
+            collect_columns(then_result, columns);
+            collect_columns(else_result, columns);
+        }
+        Expr::Position(PositionExpr { expr, r#in }) => {
+            collect_columns(expr, columns);
+            collect_columns(r#in, columns);
+        }
+        Expr::Overlay(OverlayExpr {
+            expr,
+            overlay_what,
+            overlay_from,
+            overlay_for,
+        }) => {
+            collect_columns(expr, columns);
+            collect_columns(overlay_what, columns);
+            collect_columns(overlay_from, columns);
+            if let Some(overlay_for) = overlay_for {
+                collect_columns(overlay_for, columns);
+            }
+        }
+        Expr::Between(BetweenExpr {
+            expr, low, high, ..
+        }) => {
+            collect_columns(expr, columns);
+            collect_columns(low, columns);
+            collect_columns(high, columns);
+        }
+        Expr::Case(CaseExpr {
+            operand,
+            conditions,
+            results,
+            else_result,
+        }) => {
+            operand
+                .iter()
+                .chain(else_result)
+                .for_each(|expr| collect_columns(expr, columns));
+            conditions
+                .iter()
+                .chain(results)
+                .for_each(|expr| collect_columns(expr, columns));
+        }
+        Expr::Function(Function { args, .. }) => {
+            for arg in args {
+                match arg {
+                    FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg) => {
+                        collect_columns(arg, columns)
+                    }
+                }
+            }
+        }
+        Expr::Substring(SubstringExpr {
+            expr,
+            substring_from,
+            substring_for,
+        }) => {
+            collect_columns(expr, columns);
+            substring_from
+                .iter()
+                .chain(substring_for)
+                .for_each(|expr| collect_columns(expr, columns));
+        }
+        Expr::Trim(TrimExpr { expr, trim_where }) => {
+            if let Some((_, trim_char)) = trim_where {
+                collect_columns(trim_char, columns);
+            }
+            collect_columns(expr, columns);
+        }
+        Expr::ListAgg(ListAggExpr {
+            expr, separator, ..
+        }) => {
+            collect_columns(expr, columns);
+            if let Some(separator) = separator {
+                collect_columns(separator, columns);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ansi::AnsiDialect, error::ParserError, parser::Parser};
+
+    #[test]
+    fn extract_where_predicates() -> Result<(), ParserError> {
+        let dialect = AnsiDialect::default();
+        let predicates = |sql: &str| -> Result<Vec<(String, String)>, ParserError> {
+            let selection = Where {
+                expr: Box::new(Parser::new_with_sql(&dialect, sql)?.parse_expr()?),
+            };
+            Ok(extract_predicates(&selection)
+                .into_iter()
+                .map(|p| {
+                    let columns = p.columns.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+                    (p.expr.to_string(), columns.join(", "))
+                })
+                .collect())
+        };
+
+        assert_eq!(
+            predicates("t.a = 1 AND (b > c AND a IN (1, 2))")?,
+            vec![
+                ("t.a = 1".into(), "t.a".into()),
+                ("b > c".into(), "b, c".into()),
+                ("a IN (1, 2)".into(), "a".into()),
+            ]
+        );
+        assert_eq!(
+            predicates("NOT (a = 1 OR b = 2) AND (c = 3 AND d = 4 OR e = 5)")?,
+            vec![
+                ("NOT a = 1".into(), "a".into()),
+                ("NOT b = 2".into(), "b".into()),
+                ("c = 3 OR e = 5".into(), "c, e".into()),
+                ("d = 4 OR e = 5".into(), "d, e".into()),
+            ]
+        );
+        assert_eq!(
+            predicates("NOT NOT a AND upper(b) = a")?,
+            vec![
+                ("a".into(), "a".into()),
+                ("upper(b) = a".into(), "b, a".into()),
+            ]
+        );
+        assert_eq!(
+            predicates("a = 1 OR b IN (SELECT c FROM t)")?,
+            vec![("a = 1 OR b IN (SELECT c FROM t)".into(), "a, b".into())]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::{dialect::Dialect, error::LexerError, lexer::Lexer, tokens::Token};
+
+/// The placeholder of the redacted literals.
+const PLACEHOLDER: &str = "?";
+
+/// Renders the AST node as SQL text, with all string and number literals replaced by the `?`
+/// placeholder, so that the SQL can be logged without leaking sensitive data, e.g.
+/// `SELECT * FROM t WHERE name = 'alice' LIMIT 10` is rendered as
+/// `SELECT * FROM t WHERE name = ? LIMIT ?`.
+///
+/// The literals are redacted in every clause, including the typed literals like `DATE '...'`.
+/// Numbers that are part of the syntax, like the length of `VARCHAR(255)`, are redacted too,
+/// since they can't be told apart from literals in the SQL text.
+/// `NULL`, `TRUE` and `FALSE` are kept as they are.
+pub fn redact<D: Dialect, T: fmt::Display>(dialect: &D, node: &T) -> Result<String, LexerError> {
+    let sql = node.to_string();
+    let tokens = Lexer::new(dialect, &sql).tokenize_with_spans()?;
+    let mut redacted = String::with_capacity(sql.len());
+    let mut last = 0;
+    for (token, span) in tokens {
+        if matches!(
+            token,
+            Token::Number(_)
+                | Token::String(_)
+                | Token::NationalString(_)
+                | Token::EscapeString { .. }
+                | Token::HexString(_)
+                | Token::BitString(_)
+                | Token::DollarString { .. }
+        ) {
+            redacted.push_str(&sql[last..span.start]);
+            redacted.push_str(PLACEHOLDER);
+            last = span.end;
+        }
+    }
+    redacted.push_str(&sql[last..]);
+    Ok(redacted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::ParserError, parser::Parser, postgres::PostgresDialect};
+
+    #[test]
+    fn redact_literals() -> Result<(), ParserError> {
+        let dialect = PostgresDialect::default();
+        let cases = [
+            (
+                "SELECT * FROM t WHERE name = 'alice' AND age > 30 LIMIT 10 OFFSET 5",
+                "SELECT * FROM t WHERE name = ? AND age > ? LIMIT ? OFFSET ?",
+            ),
+            (
+                "INSERT INTO t (a, b) VALUES (1, 'x'), (-2.5, NULL)",
+                "INSERT INTO t (a, b) VALUES (?, ?), (- ?, NULL)",
+            ),
+            (
+                "UPDATE t SET d = DATE '2021-01-01', b = TRUE WHERE id IN (1, 2)",
+                "UPDATE t SET d = DATE ?, b = TRUE WHERE id IN (?, ?)",
+            ),
+            (
+                "CREATE TABLE t (a TEXT DEFAULT 'secret', b VARCHAR(255))",
+                "CREATE TABLE t (a TEXT DEFAULT ?, b VARCHAR(?))",
+            ),
+            ("SELECT \"it's\" FROM t", "SELECT \"it's\" FROM t"),
+            (
+                "CREATE ROLE admin LOGIN PASSWORD 'secret'",
+                "CREATE ROLE admin WITH LOGIN PASSWORD ?",
+            ),
+        ];
+        for (sql, expected) in cases {
+            let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+            assert_eq!(redact(&dialect, &stmt)?, expected);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use crate::{
+    ast::statement::Stmt,
+    dialect::Dialect,
+    error::{parse_error, ParserError},
+    parser::Parser,
+};
+
+/// Renders the statement as SQL text of the dialect, and refuses to emit the SQL that is invalid
+/// in the dialect, e.g. a query with both the `LIMIT` and the `FETCH FIRST` clause, or a `LIMIT`
+/// clause in ANSI SQL.
+///
+/// The rendered SQL is validated by parsing it with the dialect, an error is returned if it
+/// can't be parsed, or if it's parsed as a different statement.
+pub fn render<D: Dialect>(dialect: &D, stmt: &Stmt) -> Result<String, ParserError> {
+    let sql = stmt.to_string();
+    let mut parser = Parser::new_with_sql(dialect, &sql)?;
+    let parsed = parser.parse_stmt()?;
+    if parser.peek_token().is_some() || parsed != *stmt {
+        return parse_error(format!("The rendered SQL is parsed differently: {}", sql));
+    }
+    Ok(sql)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{
+        expression::{Fetch, Limit},
+        statement::SelectStmt,
+        types::Literal,
+    };
+
+    fn parse<D: Dialect>(dialect: &D, sql: &str) -> Result<Stmt, ParserError> {
+        Parser::new_with_sql(dialect, sql)?.parse_stmt()
+    }
+
+    #[test]
+    fn render_valid_sql() -> Result<(), ParserError> {
+        let postgres = crate::postgres::PostgresDialect::default();
+        let stmt = parse(&postgres, "SELECT a FROM t OFFSET 5 LIMIT 10")?;
+        assert_eq!(
+            render(&postgres, &stmt)?,
+            "SELECT a FROM t LIMIT 10 OFFSET 5"
+        );
+        let stmt = parse(
+            &postgres,
+            "SELECT a FROM t FETCH FIRST 10 ROWS ONLY OFFSET 5 ROWS",
+        )?;
+        assert_eq!(
+            render(&postgres, &stmt)?,
+            "SELECT a FROM t OFFSET 5 ROWS FETCH FIRST 10 ROWS ONLY"
+        );
+
+        // the order of LIMIT and OFFSET is the one required by MySQL and SQLite
+        let mysql = crate::mysql::MysqlDialect::default();
+        let stmt = parse(&mysql, "SELECT a FROM t ORDER BY a LIMIT 10 OFFSET 5")?;
+        assert_eq!(
+            render(&mysql, &stmt)?,
+            "SELECT a FROM t ORDER BY a LIMIT 10 OFFSET 5"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn render_invalid_sql() -> Result<(), ParserError> {
+        let postgres = crate::postgres::PostgresDialect::default();
+        let mut stmt = parse(&postgres, "SELECT a FROM t LIMIT 10")?;
+        if let Stmt::Select(SelectStmt(query)) = &mut stmt {
+            query.fetch = Some(Fetch {
+                quantity: Some(Literal::Number("10".into())),
+                percent: false,
+                with_ties: false,
+            });
+        }
+        assert!(render(&postgres, &stmt).is_err());
+
+        // the statement is not supported by the dialect
+        let ansi = crate::ansi::AnsiDialect::default();
+        let mut stmt = parse(&ansi, "SELECT a FROM t")?;
+        assert!(render(&ansi, &stmt).is_ok());
+        if let Stmt::Select(SelectStmt(query)) = &mut stmt {
+            query.limit = Some(Limit {
+                count: Literal::Number("10".into()),
+            });
+        }
+        assert!(render(&ansi, &stmt).is_err());
+
+        // the boolean literals are supported by SQLite since 3.23
+        let sqlite = crate::sqlite::SqliteDialect::default();
+        let stmt = parse(&postgres, "SELECT a FROM t WHERE b = TRUE OR c = FALSE")?;
+        assert_eq!(
+            render(&sqlite, &stmt)?,
+            "SELECT a FROM t WHERE b = TRUE OR c = FALSE"
+        );
+
+        // the statement is parsed differently
+        let mysql = crate::mysql::MysqlDialect::default();
+        let stmt = parse(&postgres, "SELECT a || b FROM t")?;
+        assert_eq!(
+            render(&mysql, &stmt),
+            parse_error("The rendered SQL is parsed differently: SELECT a || b FROM t")
+        );
+        Ok(())
+    }
+}
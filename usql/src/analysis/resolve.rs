@@ -0,0 +1,291 @@
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+use crate::{
+    ast::{
+        expression::*,
+        types::{Ident, ObjectName},
+    },
+    dialect::{Dialect, DialectParserConf},
+    error::{parse_error, ParserError},
+};
+
+/// A reference of the `GROUP BY` or `ORDER BY` clause, resolved by [`resolve_references`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ResolvedRef<'a> {
+    /// An output column alias or a 1-based position, which refers to the select list item at
+    /// the 0-based index.
+    SelectItem {
+        /// The 0-based index in the select list.
+        index: usize,
+        /// The expression of the select list item.
+        expr: &'a Expr,
+    },
+    /// A grouping column of the input tables.
+    Column(&'a ObjectName),
+    /// A sort key expression of the input tables.
+    Expr(&'a Expr),
+}
+
+/// The `GROUP BY` and `ORDER BY` references of a query, see [`resolve_references`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResolvedQuery<'a> {
+    /// The grouping columns, in order of appearance, including the ones in `ROLLUP`, `CUBE` and
+    /// `GROUPING SETS`.
+    pub group_by: Vec<ResolvedRef<'a>>,
+    /// The sort keys, in order.
+    pub order_by: Vec<ResolvedRef<'a>>,
+}
+
+/// Resolves the output column aliases and the positions in the `GROUP BY` and `ORDER BY`
+/// clauses of the query to the select list items, e.g. `x` and `2` in
+/// `SELECT a + b AS x, count(*) FROM t GROUP BY x ORDER BY 2` are resolved to `a + b` and
+/// `count(*)`.
+///
+/// The `ORDER BY` clause of a set operation is resolved against the select list of its leftmost
+/// query. The `GROUP BY` aliases are resolved only if the dialect supports them, see
+/// [`DialectParserConf::supports_group_by_select_list`], otherwise a grouping column is always
+/// a column of the input tables.
+///
+/// An error is returned if a position is out of the select list or refers to a wildcard, or an
+/// alias refers to multiple select list items.
+///
+/// `ORDER BY ALL` is resolved to all the select list items, while `GROUP BY ALL` is not resolved.
+///
+/// **NOTE**: Only the explicit aliases are resolved, and an alias takes precedence over the
+/// input column with the same name, which is not the case for the `GROUP BY` of PostgreSQL.
+pub fn resolve_references<'a, D: Dialect>(
+    dialect: &D,
+    query: &'a Query,
+) -> Result<ResolvedQuery<'a>, ParserError> {
+    let projection = select_list(&query.body);
+    let mut resolved = ResolvedQuery::default();
+
+    if let QueryBody::QuerySpec(spec) = &query.body {
+        let aliases = dialect.parser_conf().supports_group_by_select_list();
+        let mut sets = Vec::new();
+        for element in spec.group_by.iter().flat_map(|group_by| &group_by.list) {
+            grouping_sets(element, &mut sets);
+        }
+        for set in sets {
+            match set {
+                GroupingSet::Column(name) => resolved
+                    .group_by
+                    .push(resolve_name(projection, name, aliases)?),
+                GroupingSet::Columns(names) => {
+                    for name in names {
+                        resolved
+                            .group_by
+                            .push(resolve_name(projection, name, aliases)?);
+                    }
+                }
+                GroupingSet::Ordinal(ordinal) => resolved
+                    .group_by
+                    .push(resolve_ordinal(projection, *ordinal)?),
+            }
+        }
+    }
+
+    if let Some(OrderBy { all: Some(_), .. }) = &query.order_by {
+        for ordinal in 1..=projection.len() {
+            resolved
+                .order_by
+                .push(resolve_ordinal(projection, ordinal as u64)?);
+        }
+    }
+    for spec in query.order_by.iter().flat_map(|order_by| &order_by.list) {
+        let reference = match (spec.ordinal, &*spec.expr) {
+            (Some(ordinal), _) => resolve_ordinal(projection, ordinal)?,
+            (None, Expr::Identifier(ident)) => match resolve_alias(projection, ident)? {
+                Some(reference) => reference,
+                None => ResolvedRef::Expr(&spec.expr),
+            },
+            (None, expr) => ResolvedRef::Expr(expr),
+        };
+        resolved.order_by.push(reference);
+    }
+    Ok(resolved)
+}
+
+/// Returns the select list of the leftmost query specification.
+pub(super) fn select_list(body: &QueryBody) -> &[SelectItem] {
+    match body {
+        QueryBody::QuerySpec(spec) => &spec.projection,
+        QueryBody::Subquery(query) => select_list(&query.body),
+        QueryBody::Operation { left, .. } => select_list(left),
+        _ => &[],
+    }
+}
+
+fn grouping_sets<'a>(element: &'a GroupingElement, sets: &mut Vec<&'a GroupingSet>) {
+    match element {
+        // the items of `GROUP BY ALL` depend on the aggregate functions in the select list
+        GroupingElement::All | GroupingElement::Empty => {}
+        GroupingElement::OrdinarySet(set) => sets.push(set),
+        GroupingElement::Rollup(list) | GroupingElement::Cube(list) => sets.extend(list),
+        GroupingElement::Sets(elements) => {
+            for element in elements {
+                grouping_sets(element, sets);
+            }
+        }
+    }
+}
+
+fn resolve_name<'a>(
+    projection: &'a [SelectItem],
+    name: &'a ObjectName,
+    aliases: bool,
+) -> Result<ResolvedRef<'a>, ParserError> {
+    if let (true, [ident]) = (aliases, name.0.as_slice()) {
+        if let Some(reference) = resolve_alias(projection, ident)? {
+            return Ok(reference);
+        }
+    }
+    Ok(ResolvedRef::Column(name))
+}
+
+fn resolve_alias<'a>(
+    projection: &'a [SelectItem],
+    ident: &Ident,
+) -> Result<Option<ResolvedRef<'a>>, ParserError> {
+    let mut found = None;
+    for (index, item) in projection.iter().enumerate() {
+        if let SelectItem::DerivedColumn {
+            expr,
+            alias: Some(alias),
+        } = item
+        {
+            if !same_ident(alias, ident) {
+                continue;
+            }
+            if found.is_some() {
+                return parse_error(format!("The reference `{}` is ambiguous", ident));
+            }
+            found = Some(ResolvedRef::SelectItem { index, expr });
+        }
+    }
+    Ok(found)
+}
+
+fn resolve_ordinal(
+    projection: &[SelectItem],
+    ordinal: u64,
+) -> Result<ResolvedRef<'_>, ParserError> {
+    let index = (ordinal as usize).wrapping_sub(1);
+    if index >= projection.len() {
+        return parse_error(format!(
+            "The position {} is not in the select list",
+            ordinal
+        ));
+    }
+    // the positions after a wildcard depend on the columns of the tables
+    match &projection[..=index] {
+        [.., SelectItem::DerivedColumn { expr, .. }]
+            if projection[..index]
+                .iter()
+                .all(|item| matches!(item, SelectItem::DerivedColumn { .. })) =>
+        {
+            Ok(ResolvedRef::SelectItem { index, expr })
+        }
+        _ => parse_error(format!(
+            "The position {} can't be resolved with the wildcard in the select list",
+            ordinal
+        )),
+    }
+}
+
+/// Unquoted identifiers are compared case-insensitively, see also [`Ident::normalized_value`].
+pub(super) fn same_ident(left: &Ident, right: &Ident) -> bool {
+    let (value, other) = (left.normalized_value(), right.normalized_value());
+    match (left.quote, right.quote) {
+        (None, None) => value.eq_ignore_ascii_case(&other),
+        _ => value == other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ansi::AnsiDialect, ast::statement::*, parser::Parser, postgres::PostgresDialect};
+
+    fn parse<D: Dialect>(dialect: &D, sql: &str) -> Result<Query, ParserError> {
+        match Parser::new_with_sql(dialect, sql)?.parse_stmt()? {
+            Stmt::Select(SelectStmt(query)) => Ok(*query),
+            stmt => panic!("not a query: {}", stmt),
+        }
+    }
+
+    fn display(refs: &[ResolvedRef<'_>]) -> Vec<String> {
+        refs.iter()
+            .map(|reference| match reference {
+                ResolvedRef::SelectItem { index, expr } => format!("#{} {}", index, expr),
+                ResolvedRef::Column(name) => name.to_string(),
+                ResolvedRef::Expr(expr) => expr.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn resolve_group_by_and_order_by() -> Result<(), ParserError> {
+        let dialect = PostgresDialect::default();
+        let query = parse(
+            &dialect,
+            "SELECT a + b AS X, c, count(*) AS n FROM t \
+             GROUP BY x, 2, ROLLUP (c, d) ORDER BY 3 DESC, x, d, c + 1",
+        )?;
+        let resolved = resolve_references(&dialect, &query)?;
+        assert_eq!(display(&resolved.group_by), ["#0 a + b", "#1 c", "c", "d"]);
+        assert_eq!(
+            display(&resolved.order_by),
+            ["#2 count(*)", "#0 a + b", "d", "c + 1"]
+        );
+
+        // the ORDER BY of a set operation refers to the leftmost select list
+        let query = parse(
+            &dialect,
+            "SELECT a AS x FROM t UNION SELECT b FROM u ORDER BY x, 1",
+        )?;
+        let resolved = resolve_references(&dialect, &query)?;
+        assert_eq!(display(&resolved.group_by), Vec::<String>::new());
+        assert_eq!(display(&resolved.order_by), ["#0 a", "#0 a"]);
+
+        // the GROUP BY aliases are not supported
+        let dialect = AnsiDialect::default();
+        let query = parse(&dialect, "SELECT a AS x FROM t GROUP BY x ORDER BY x")?;
+        let resolved = resolve_references(&dialect, &query)?;
+        assert_eq!(display(&resolved.group_by), ["x"]);
+        assert_eq!(display(&resolved.order_by), ["#0 a"]);
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_invalid_references() -> Result<(), ParserError> {
+        let dialect = PostgresDialect::default();
+        let cases = [
+            (
+                "SELECT a FROM t ORDER BY 2",
+                "The position 2 is not in the select list",
+            ),
+            (
+                "SELECT a FROM t GROUP BY 0",
+                "The position 0 is not in the select list",
+            ),
+            (
+                "SELECT *, a FROM t ORDER BY 2",
+                "The position 2 can't be resolved with the wildcard in the select list",
+            ),
+            (
+                "SELECT a AS x, b AS x FROM t ORDER BY x",
+                "The reference `x` is ambiguous",
+            ),
+        ];
+        for (sql, message) in cases {
+            let query = parse(&dialect, sql)?;
+            assert_eq!(
+                resolve_references(&dialect, &query),
+                Err(ParserError::ParseError(message.into()))
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    dialect::Dialect,
+    error::{LexerError, Span},
+    lexer::Lexer,
+    tokens::{Comment, Token},
+};
+
+/// Rewrites SQL text at the token level, without parsing it.
+///
+/// The edits are targeted at the byte ranges of the tokens, and all the other bytes of the SQL,
+/// including the whitespaces, the comments and the quoting of identifiers, are kept as they are.
+///
+/// ```rust
+/// # use usql::{analysis::TokenRewriter, ansi::AnsiDialect, LexerError};
+/// # fn main() -> Result<(), LexerError> {
+/// let dialect = AnsiDialect::default();
+/// let mut rewriter = TokenRewriter::new(&dialect, "SELECT * FROM foo /* hint */ WHERE foo.a = 1")?;
+/// rewriter.replace_ident("foo", "bar");
+/// rewriter.strip_comments();
+/// rewriter.insert(0, "-- generated\n");
+/// assert_eq!(rewriter.render(), "-- generated\nSELECT * FROM bar  WHERE bar.a = 1");
+/// # Ok(())
+/// # }
+/// ```
+pub struct TokenRewriter<'a> {
+    source: &'a str,
+    tokens: Vec<(Token, Span)>,
+    edits: Vec<(Span, String)>,
+}
+
+impl<'a> TokenRewriter<'a> {
+    /// Creates a new rewriter by tokenizing the SQL text with the dialect.
+    pub fn new<D: Dialect>(dialect: &D, source: &'a str) -> Result<Self, LexerError> {
+        let tokens = Lexer::new(dialect, source).tokenize_with_spans()?;
+        Ok(Self {
+            source,
+            tokens,
+            edits: Vec::new(),
+        })
+    }
+
+    /// Returns the original SQL text.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// Returns the tokens of the original SQL text, with their byte ranges.
+    pub fn tokens(&self) -> &[(Token, Span)] {
+        &self.tokens
+    }
+
+    /// Replaces the bytes in the span with the text.
+    ///
+    /// **NOTE**: The previous edits overlapping with the span are discarded.
+    pub fn replace(&mut self, span: Span, text: impl Into<String>) {
+        assert!(
+            span.start <= span.end && span.end <= self.source.len(),
+            "Span {} is out of the source",
+            span
+        );
+        self.edits
+            .retain(|(edit, _)| !(edit.start < span.end && span.start < edit.end));
+        self.edits.push((span, text.into()));
+    }
+
+    /// Inserts the text at the byte offset, e.g. inserting a comment header at the offset 0.
+    /// The texts inserted at the same offset are rendered in the order of insertion.
+    pub fn insert(&mut self, offset: usize, text: impl Into<String>) {
+        self.replace(Span::new(offset, offset), text);
+    }
+
+    /// Removes the bytes in the span.
+    pub fn remove(&mut self, span: Span) {
+        self.replace(span, "");
+    }
+
+    /// Replaces every token for which the function returns the replacement text.
+    pub fn replace_tokens<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Token) -> Option<String>,
+    {
+        let replacements = self
+            .tokens
+            .iter()
+            .filter_map(|(token, span)| f(token).map(|text| (*span, text)))
+            .collect::<Vec<_>>();
+        for (span, text) in replacements {
+            self.replace(span, text);
+        }
+    }
+
+    /// Replaces every identifier (or keyword) token whose value is `name` with the text, e.g.
+    /// renaming a table. The unquoted words are matched case-insensitively and the quoted
+    /// identifiers are matched exactly.
+    ///
+    /// **NOTE**: The text is inserted as it is, it must be quoted by the caller if necessary.
+    pub fn replace_ident(&mut self, name: &str, text: &str) {
+        self.replace_tokens(|token| match token {
+            Token::Word(word) if word.quote.is_none() && word.value.eq_ignore_ascii_case(name) => {
+                Some(text.to_string())
+            }
+            Token::Word(word) if word.quote.is_some() && word.value == name => {
+                Some(text.to_string())
+            }
+            _ => None,
+        });
+    }
+
+    /// Removes all the comments.
+    ///
+    /// A single-line comment is replaced with the newline that ends it, and a multi-line comment
+    /// is replaced with a space if it's the only separator between two tokens.
+    pub fn strip_comments(&mut self) {
+        let mut replacements = Vec::new();
+        for (i, (token, span)) in self.tokens.iter().enumerate() {
+            let text = match token {
+                Token::Comment(Comment::SingleLine { comment, .. }) if comment.ends_with('\n') => {
+                    "\n"
+                }
+                Token::Comment(Comment::SingleLine { .. }) => "",
+                Token::Comment(Comment::MultiLine(_)) => {
+                    let separated = |token: Option<&(Token, Span)>| match token {
+                        Some((token, _)) => token.is_whitespace() || token.is_comment(),
+                        None => true,
+                    };
+                    let prev = i.checked_sub(1).and_then(|i| self.tokens.get(i));
+                    if separated(prev) || separated(self.tokens.get(i + 1)) {
+                        ""
+                    } else {
+                        " "
+                    }
+                }
+                _ => continue,
+            };
+            replacements.push((*span, text));
+        }
+        for (span, text) in replacements {
+            self.replace(span, text);
+        }
+    }
+
+    /// Renders the rewritten SQL text.
+    pub fn render(&self) -> String {
+        let mut edits = self.edits.iter().collect::<Vec<_>>();
+        // stable sort, so that the insertions at the same offset keep their order
+        edits.sort_by_key(|(span, _)| (span.start, span.end));
+        let mut rendered = String::with_capacity(self.source.len());
+        let mut last = 0;
+        for (span, text) in edits {
+            rendered.push_str(&self.source[last..span.start]);
+            rendered.push_str(text);
+            last = span.end;
+        }
+        rendered.push_str(&self.source[last..]);
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mysql::MysqlDialect, postgres::PostgresDialect};
+
+    #[test]
+    fn rewrite_tokens() -> Result<(), LexerError> {
+        let dialect = PostgresDialect::default();
+        let sql = "SELECT  Foo.a, \"foo\".b,\t\"Foo\".c\nFROM foo -- the table\nWHERE a = 'foo'";
+        let mut rewriter = TokenRewriter::new(&dialect, sql)?;
+        assert_eq!(rewriter.render(), sql);
+        rewriter.replace_ident("foo", "\"bar\"");
+        assert_eq!(
+            rewriter.render(),
+            "SELECT  \"bar\".a, \"bar\".b,\t\"Foo\".c\nFROM \"bar\" -- the table\nWHERE a = 'foo'"
+        );
+
+        rewriter.strip_comments();
+        rewriter.insert(0, "-- generated\n");
+        rewriter.insert(0, "/* header */ ");
+        assert_eq!(
+            rewriter.render(),
+            "-- generated\n/* header */ SELECT  \"bar\".a, \"bar\".b,\t\"Foo\".c\nFROM \"bar\" \nWHERE a = 'foo'"
+        );
+
+        // the later edit discards the overlapping ones
+        rewriter.replace(Span::new(8, 12), "t.");
+        assert_eq!(
+            rewriter.render(),
+            "-- generated\n/* header */ SELECT  t.a, \"bar\".b,\t\"Foo\".c\nFROM \"bar\" \nWHERE a = 'foo'"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn strip_comments() -> Result<(), LexerError> {
+        let dialect = MysqlDialect::default();
+        let cases = [
+            ("SELECT/* a */1", "SELECT 1"),
+            ("SELECT /* a */ 1", "SELECT  1"),
+            ("SELECT 1 -- a\nFROM t -- b", "SELECT 1 \nFROM t "),
+            ("SELECT 1 /* a */", "SELECT 1 "),
+        ];
+        for (sql, expected) in cases {
+            let mut rewriter = TokenRewriter::new(&dialect, sql)?;
+            rewriter.strip_comments();
+            assert_eq!(rewriter.render(), expected);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    ast::types::Ident,
+    dialect::{CaseFolding, Dialect, DialectLexerConf},
+    lexer::Lexer,
+    tokens::{Token, Word},
+};
+
+/// The semantic form of an AST node, which is the token sequence of its canonical SQL text.
+#[derive(Eq, PartialEq, Hash)]
+enum SemanticForm {
+    Tokens(Vec<Token>),
+    // The canonical SQL text is used as it is if it can't be tokenized.
+    Text(String),
+}
+
+impl SemanticForm {
+    fn new<D: Dialect, T: fmt::Display>(dialect: &D, node: &T) -> Self {
+        let sql = node.to_string();
+        let folding = dialect.lexer_conf().identifier_case_folding();
+        match Lexer::new(dialect, &sql).tokenize() {
+            Ok(tokens) => Self::Tokens(
+                tokens
+                    .into_iter()
+                    .filter(|token| !token.is_whitespace())
+                    .map(|token| normalize(token, folding))
+                    .collect(),
+            ),
+            Err(_) => Self::Text(sql),
+        }
+    }
+}
+
+fn normalize(token: Token, folding: CaseFolding) -> Token {
+    match token {
+        // keywords are case-insensitive
+        Token::Word(Word {
+            keyword: Some(keyword),
+            value,
+            quote: None,
+        }) => Token::Word(Word {
+            keyword: Some(keyword),
+            value: value.to_uppercase(),
+            quote: None,
+        }),
+        // unquoted identifiers are folded, then compared with the quoted ones by value,
+        // e.g. `a` is the same as `"a"` in PostgreSQL, and the same as `"A"` in ANSI SQL
+        Token::Word(Word {
+            value, quote: None, ..
+        }) => delimited_ident(match folding {
+            CaseFolding::Upper => value.to_uppercase(),
+            CaseFolding::Lower => value.to_lowercase(),
+            CaseFolding::None => value,
+        }),
+        // `"ident"`, `` `ident` `` and `[ident]` are the same delimited identifier
+        Token::Word(Word { value, .. }) => delimited_ident(value),
+        token => token,
+    }
+}
+
+fn delimited_ident(value: String) -> Token {
+    Token::Word(Word {
+        keyword: None,
+        value: Ident::new(value).normalized_value(),
+        quote: Some('"'),
+    })
+}
+
+/// Checks if two AST nodes are logically identical, ignoring the quoting style of identifiers
+/// and the case of keywords, e.g. `SELECT "a" FROM t` and ``select `a` from t`` are semantically
+/// equal in SQLite.
+///
+/// The nodes are compared by the tokens of their canonical SQL text in the dialect, the unquoted
+/// identifiers are folded by [`identifier_case_folding`] of the dialect and compared with the
+/// quoted ones by value, e.g. `SELECT A FROM t` and `SELECT "a" FROM t` are semantically equal
+/// in PostgreSQL.
+///
+/// **NOTE**: an unquoted identifier spelt as a keyword is compared as a keyword.
+///
+/// [`identifier_case_folding`]: crate::dialect::DialectLexerConf::identifier_case_folding
+///
+/// **NOTE**: the `Eq` and `Hash` implementations of the AST nodes remain strict structural
+/// comparisons, source spans are never part of the semantic form.
+pub fn semantic_eq<D: Dialect, T: fmt::Display>(dialect: &D, left: &T, right: &T) -> bool {
+    SemanticForm::new(dialect, left) == SemanticForm::new(dialect, right)
+}
+
+/// Feeds the semantic form of the AST node into the hasher, which is consistent with
+/// [`semantic_eq`]: two semantically equal nodes have the same hash.
+pub fn semantic_hash<D: Dialect, T: fmt::Display, H: Hasher>(dialect: &D, node: &T, state: &mut H) {
+    SemanticForm::new(dialect, node).hash(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+
+    use super::*;
+    use crate::{error::ParserError, parser::Parser, sqlite::SqliteDialect};
+
+    fn hash<D: Dialect, T: fmt::Display>(dialect: &D, node: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        semantic_hash(dialect, node, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn semantic_eq_and_hash() -> Result<(), ParserError> {
+        let dialect = SqliteDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_stmt();
+
+        let left = parse("SELECT \"a\", [b] FROM t WHERE c = 'x'")?;
+        let right = parse("select `a`, `b` from t where c = 'x'")?;
+        assert_ne!(left, right);
+        assert!(semantic_eq(&dialect, &left, &right));
+        assert_eq!(hash(&dialect, &left), hash(&dialect, &right));
+
+        // quoted and unquoted identifiers are compared by the folded values
+        let unquoted = parse("SELECT a, b FROM t WHERE c = 'x'")?;
+        assert!(semantic_eq(&dialect, &left, &unquoted));
+        assert_eq!(hash(&dialect, &left), hash(&dialect, &unquoted));
+        let upper = parse("SELECT A, b FROM t WHERE c = 'x'")?;
+        assert!(!semantic_eq(&dialect, &left, &upper));
+        // string literals are not identifiers
+        let literal = parse("SELECT 'a', [b] FROM t WHERE c = 'x'")?;
+        assert!(!semantic_eq(&dialect, &left, &literal));
+        let other = parse("SELECT \"a\", [b] FROM t WHERE c = 'y'")?;
+        assert!(!semantic_eq(&dialect, &left, &other));
+        assert_ne!(hash(&dialect, &left), hash(&dialect, &other));
+
+        let dialect = crate::postgres::PostgresDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_stmt();
+        let left = parse("SELECT \"a\" FROM \"T\"")?;
+        assert!(semantic_eq(&dialect, &left, &parse("SELECT A FROM \"T\"")?));
+        assert!(!semantic_eq(&dialect, &left, &parse("SELECT A FROM T")?));
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_stmt();
+        let left = parse("SELECT \"X\" FROM t")?;
+        assert!(semantic_eq(&dialect, &left, &parse("select x from T")?));
+        assert!(!semantic_eq(
+            &dialect,
+            &left,
+            &parse("SELECT \"x\" FROM t")?
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::ToString};
+use core::{cmp::Ordering, mem};
+
+use crate::ast::{expression::*, types::Literal};
+
+/// Simplifies the expression by constant folding and boolean simplification, and returns the
+/// simplified expression. The following rewrites are applied bottom-up:
+///
+/// ```txt
+/// NOT TRUE           => FALSE
+/// NOT NOT x          => x
+/// TRUE AND x         => x
+/// FALSE AND x        => FALSE
+/// TRUE OR x          => TRUE
+/// FALSE OR x         => x
+/// 1 = 1              => TRUE
+/// 1 + 2              => 3
+/// NULL = x           => NULL
+/// NULL IS NULL       => TRUE
+/// (TRUE)             => TRUE
+/// ```
+///
+/// The rewrites follow the SQL three-valued logic, so `x AND NOT x` is left as it is.
+///
+/// The arguments of the functions (including `COALESCE`, `GREATEST` and `LEAST`) and the operands
+/// of the `CASE` expressions, the `IN` lists and the `BETWEEN` predicates are simplified too,
+/// but the `CASE` branches are not pruned. The other expressions, like `CAST` and the
+/// subqueries, are left as they are.
+pub fn simplify(expr: &Expr) -> Expr {
+    match expr {
+        Expr::Nested(inner) => match simplify(inner) {
+            expr @ (Expr::Literal(_) | Expr::Identifier(_) | Expr::CompoundIdentifier(_)) => expr,
+            expr => Expr::Nested(Box::new(expr)),
+        },
+        Expr::UnaryOp(UnaryOpExpr { op, expr }) => simplify_unary_op(op.clone(), simplify(expr)),
+        Expr::BinaryOp(BinaryOpExpr { left, op, right }) => {
+            simplify_binary_op(simplify(left), *op, simplify(right))
+        }
+        Expr::IsNull(IsNullExpr { negated, expr }) => match simplify(expr) {
+            Expr::Literal(ref literal) => boolean(matches!(literal, Literal::Null) != *negated),
+            expr => Expr::IsNull(IsNullExpr {
+                negated: *negated,
+                expr: Box::new(expr),
+            }),
+        },
+        Expr::InList(InListExpr {
+            expr,
+            negated,
+            list,
+        }) => Expr::InList(InListExpr {
+            expr: Box::new(simplify(expr)),
+            negated: *negated,
+            list: list.iter().map(simplify).collect(),
+        }),
+        Expr::Between(BetweenExpr {
+            expr,
+            negated,
+            low,
+            high,
+        }) => Expr::Between(BetweenExpr {
+            expr: Box::new(simplify(expr)),
+            negated: *negated,
+            low: Box::new(simplify(low)),
+            high: Box::new(simplify(high)),
+        }),
+        Expr::Case(CaseExpr {
+            operand,
+            conditions,
+            results,
+            else_result,
+        }) => Expr::Case(CaseExpr {
+            operand: operand.as_deref().map(|expr| Box::new(simplify(expr))),
+            conditions: conditions.iter().map(simplify).collect(),
+            results: results.iter().map(simplify).collect(),
+            else_result: else_result.as_deref().map(|expr| Box::new(simplify(expr))),
+        }),
+        Expr::Function(function) => {
+            let mut function = function.clone();
+            for arg in &mut function.args {
+                let (FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg)) = arg;
+                *arg = simplify(arg);
+            }
+            Expr::Function(function)
+        }
+        Expr::Coalesce(args) => Expr::Coalesce(args.iter().map(simplify).collect()),
+        Expr::Greatest(args) => Expr::Greatest(args.iter().map(simplify).collect()),
+        Expr::Least(args) => Expr::Least(args.iter().map(simplify).collect()),
+        expr => expr.clone(),
+    }
+}
+
+fn simplify_unary_op(op: UnaryOperator, mut expr: Expr) -> Expr {
+    // `NOT (NOT x)` is simplified like `NOT NOT x`.
+    if let Expr::Nested(inner) = &mut expr {
+        if matches!(
+            &**inner,
+            Expr::UnaryOp(UnaryOpExpr {
+                op: UnaryOperator::Not | UnaryOperator::LogicalNot,
+                ..
+            })
+        ) {
+            expr = take(inner);
+        }
+    }
+    if !matches!(op, UnaryOperator::Not | UnaryOperator::LogicalNot) {
+        return Expr::unary(op, expr);
+    }
+    match &mut expr {
+        Expr::Literal(Literal::Boolean(b)) => boolean(!*b),
+        Expr::Literal(Literal::Null) => null(),
+        Expr::UnaryOp(UnaryOpExpr {
+            op: UnaryOperator::Not | UnaryOperator::LogicalNot,
+            expr,
+        }) => take(expr),
+        _ => Expr::unary(op, expr),
+    }
+}
+
+/// Moves the expression out of the tree, as `Expr` implements `Drop` it can't be moved out of
+/// its parent directly.
+fn take(expr: &mut Expr) -> Expr {
+    mem::replace(expr, Expr::Wildcard)
+}
+
+fn simplify_binary_op(left: Expr, op: BinaryOperator, right: Expr) -> Expr {
+    match op {
+        BinaryOperator::And => match (as_boolean(&left), as_boolean(&right)) {
+            (Some(false), _) | (_, Some(false)) => return boolean(false),
+            (Some(true), _) => return right,
+            (_, Some(true)) => return left,
+            _ => {}
+        },
+        BinaryOperator::Or => match (as_boolean(&left), as_boolean(&right)) {
+            (Some(true), _) | (_, Some(true)) => return boolean(true),
+            (Some(false), _) => return right,
+            (_, Some(false)) => return left,
+            _ => {}
+        },
+        // `NULL = x` is `NULL` whatever `x` is
+        _ if propagates_null(&op)
+            && matches!(
+                (&left, &right),
+                (Expr::Literal(Literal::Null), _) | (_, Expr::Literal(Literal::Null))
+            ) =>
+        {
+            return null();
+        }
+        _ => {
+            if let (Expr::Literal(l), Expr::Literal(r)) = (&left, &right) {
+                if let Some(literal) = fold_literals(l, &op, r) {
+                    return Expr::Literal(literal);
+                }
+            }
+        }
+    }
+    Expr::binary(left, op, right)
+}
+
+fn fold_literals(left: &Literal, op: &BinaryOperator, right: &Literal) -> Option<Literal> {
+    use BinaryOperator::*;
+
+    let ordering = match (left, right) {
+        (Literal::Boolean(l), Literal::Boolean(r)) => l.cmp(r),
+        (Literal::Number(_), Literal::Number(_)) => {
+            let (l, r) = (left.as_decimal()?, right.as_decimal()?);
+            let scale = l.scale.max(r.scale);
+            let l = l
+                .mantissa
+                .checked_mul(10i128.checked_pow(scale - l.scale)?)?;
+            let r = r
+                .mantissa
+                .checked_mul(10i128.checked_pow(scale - r.scale)?)?;
+            match op {
+                Plus | Minus | Multiply => {
+                    let (l, r) = (left.as_i64()?, right.as_i64()?);
+                    let value = match op {
+                        Plus => l.checked_add(r)?,
+                        Minus => l.checked_sub(r)?,
+                        _ => l.checked_mul(r)?,
+                    };
+                    // Negative numbers are represented as unary minus expressions.
+                    return (value >= 0).then(|| Literal::Number(value.to_string()));
+                }
+                _ => l.cmp(&r),
+            }
+        }
+        _ => return None,
+    };
+    let value = match op {
+        Equal => ordering == Ordering::Equal,
+        NotEqual => ordering != Ordering::Equal,
+        Greater => ordering == Ordering::Greater,
+        Less => ordering == Ordering::Less,
+        GreaterOrEqual => ordering != Ordering::Less,
+        LessOrEqual => ordering != Ordering::Greater,
+        _ => return None,
+    };
+    Some(Literal::Boolean(value))
+}
+
+/// Returns true if the result of the operator is `NULL` if any operand is `NULL`.
+fn propagates_null(op: &BinaryOperator) -> bool {
+    use BinaryOperator::*;
+
+    matches!(
+        op,
+        Plus | Minus
+            | Multiply
+            | Divide
+            | Modulo
+            | Greater
+            | Less
+            | GreaterOrEqual
+            | LessOrEqual
+            | Equal
+            | NotEqual
+    )
+}
+
+fn as_boolean(expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::Literal(Literal::Boolean(b)) => Some(*b),
+        _ => None,
+    }
+}
+
+fn boolean(value: bool) -> Expr {
+    Expr::Literal(Literal::Boolean(value))
+}
+
+fn null() -> Expr {
+    Expr::Literal(Literal::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ansi::AnsiDialect, error::ParserError, parser::Parser};
+
+    #[test]
+    fn simplify_expr() -> Result<(), ParserError> {
+        let dialect = AnsiDialect::default();
+        let simplified = |sql: &str| -> Result<String, ParserError> {
+            let expr = Parser::new_with_sql(&dialect, sql)?.parse_expr()?;
+            Ok(simplify(&expr).to_string())
+        };
+
+        assert_eq!(simplified("TRUE AND a")?, "a");
+        assert_eq!(simplified("a AND TRUE")?, "a");
+        assert_eq!(simplified("a AND FALSE")?, "FALSE");
+        assert_eq!(simplified("FALSE OR a = 1")?, "a = 1");
+        assert_eq!(simplified("a OR TRUE")?, "TRUE");
+        assert_eq!(simplified("1 = 1")?, "TRUE");
+        assert_eq!(simplified("1.50 = 1.5")?, "TRUE");
+        assert_eq!(simplified("2 < 1")?, "FALSE");
+        assert_eq!(simplified("1 + 2 * 3")?, "7");
+        assert_eq!(simplified("1 - 2")?, "1 - 2");
+        assert_eq!(simplified("NULL = a")?, "NULL");
+        assert_eq!(simplified("a + 1 < NULL")?, "NULL");
+        assert_eq!(
+            simplified("NULL IS DISTINCT FROM a")?,
+            "NULL IS DISTINCT FROM a"
+        );
+        assert_eq!(simplified("NULL = 1")?, "NULL");
+        assert_eq!(simplified("NOT NOT a")?, "a");
+        assert_eq!(simplified("NOT (NOT a)")?, "a");
+        assert_eq!(simplified("NOT (-a)")?, "NOT (- a)");
+        assert_eq!(simplified("NOT (1 > 2)")?, "TRUE");
+        assert_eq!(simplified("NULL IS NOT NULL")?, "FALSE");
+        assert_eq!(simplified("(1 = 1 AND b) OR c")?, "b OR c");
+        assert_eq!(simplified("a AND NOT a")?, "a AND NOT a");
+
+        // the nested operands are simplified too
+        assert_eq!(simplified("f(a, 1 + 2)")?, "f(a, 3)");
+        assert_eq!(simplified("COALESCE(a, 1 + 2)")?, "COALESCE(a, 3)");
+        assert_eq!(
+            simplified("CASE WHEN TRUE AND a THEN 1 = 1 ELSE NOT NOT b END")?,
+            "CASE WHEN a THEN TRUE ELSE b END"
+        );
+        assert_eq!(simplified("a IN (1 + 1, b OR FALSE)")?, "a IN (2, b)");
+        assert_eq!(
+            simplified("a BETWEEN 1 * 2 AND 2 + 3")?,
+            "a BETWEEN 2 AND 5"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,376 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    analysis::Catalog,
+    ast::{expression::*, statement::*, types::ObjectName},
+    dialect::Dialect,
+    error::{LexerError, Span},
+    parser::{split_stmt_tokens, stmt_span, BatchSeparators, Parser, SplitError},
+    tokens::Token,
+};
+
+/// The severity of a [`Diagnostic`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// The SQL is invalid.
+    Error,
+    /// The SQL is valid, but may not be treated as written by the database.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        })
+    }
+}
+
+/// An issue of the SQL text found by [`validate`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostic {
+    /// The severity of the issue.
+    pub severity: Severity,
+    /// The description of the issue.
+    pub message: String,
+    /// The byte range of the SQL text where the issue is found, an empty range for a location.
+    pub span: Span,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at {}: {}", self.severity, self.span, self.message)
+    }
+}
+
+impl Diagnostic {
+    fn error(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+            span,
+        }
+    }
+
+    pub(super) fn warning(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+            span,
+        }
+    }
+
+    fn from_lexer_error(sql: &str, err: LexerError, severity: Severity) -> Self {
+        let offset = err.location.to_offset(sql);
+        Self {
+            severity,
+            message: err.message,
+            span: Span::new(offset, offset),
+        }
+    }
+}
+
+/// Validates the SQL script without executing it, and returns all the issues found, in source
+/// order, which are:
+///
+/// - the lexer warnings and errors, a lexer error stops the validation;
+/// - the parse errors, every `;` separated statement is parsed even if a previous one fails;
+/// - the references to the tables which are not in the catalog, if the catalog is given.
+///
+/// **NOTE**: only the tables referenced by the `FROM` clauses and the target tables of the DML
+/// statements are checked, the names of the common table expressions are skipped.
+pub fn validate<D: Dialect>(sql: &str, dialect: &D, catalog: Option<&Catalog>) -> Vec<Diagnostic> {
+    validate_with_separators(sql, dialect, catalog, BatchSeparators::default())
+}
+
+/// Validates the SQL script like [`validate`], the statements may also be terminated by the
+/// batch separators, see [`Parser::parse_script_with_separators`].
+///
+/// An invalid batch separator stops the validation like a lexer error.
+pub fn validate_with_separators<D: Dialect>(
+    sql: &str,
+    dialect: &D,
+    catalog: Option<&Catalog>,
+    separators: BatchSeparators,
+) -> Vec<Diagnostic> {
+    let mut warnings = vec![];
+    let stmts = match split_stmt_tokens(dialect, sql, separators, &mut warnings) {
+        Ok(stmts) => stmts,
+        Err(SplitError::Lexer(err)) => {
+            return vec![Diagnostic::from_lexer_error(sql, err, Severity::Error)]
+        }
+        Err(SplitError::Separator(message, span)) => return vec![Diagnostic::error(message, span)],
+    };
+    let mut diagnostics = warnings
+        .into_iter()
+        .map(|warning| Diagnostic::from_lexer_error(sql, warning, Severity::Warning))
+        .collect::<Vec<_>>();
+
+    for tokens in stmts {
+        validate_stmt(dialect, catalog, tokens, &mut diagnostics);
+    }
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.span.start, diagnostic.span.end));
+    diagnostics
+}
+
+fn validate_stmt<D: Dialect>(
+    dialect: &D,
+    catalog: Option<&Catalog>,
+    tokens: Vec<(Token, Span)>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let span = stmt_span(&tokens);
+    let stmt = Parser::parse_single_stmt(
+        dialect,
+        tokens.iter().map(|(token, _)| token.clone()).collect(),
+    );
+    let stmt = match stmt {
+        Ok(stmt) => stmt,
+        Err(err) => return diagnostics.push(Diagnostic::error(err.to_string(), span)),
+    };
+    let catalog = match catalog {
+        Some(catalog) => catalog,
+        None => return,
+    };
+    let mut tables = TableCollector::default();
+    tables.visit_stmt(&stmt);
+    for name in tables.tables {
+        let unqualified = name.0.len() == 1;
+        if (unqualified && tables.ctes.contains(&name.0[0].normalized_value()))
+            || catalog.contains_table(name)
+        {
+            continue;
+        }
+        diagnostics.push(Diagnostic::error(
+            format!("Unknown table `{}`", name),
+            name_span(&tokens, name).unwrap_or(span),
+        ));
+    }
+}
+
+/// Finds the byte range of the first occurrence of the name in the tokens.
+fn name_span(tokens: &[(Token, Span)], name: &ObjectName) -> Option<Span> {
+    let len = name.0.len() * 2 - 1;
+    tokens.windows(len).find_map(|window| {
+        let matched = window
+            .iter()
+            .enumerate()
+            .all(|(i, (token, _))| match token {
+                Token::Word(word) if i % 2 == 0 => word.value == name.0[i / 2].value,
+                Token::Period => i % 2 == 1,
+                _ => false,
+            });
+        if matched {
+            Some(Span::new(window[0].1.start, window[len - 1].1.end))
+        } else {
+            None
+        }
+    })
+}
+
+/// Collects the table names referenced by a statement.
+#[derive(Default)]
+struct TableCollector<'a> {
+    tables: Vec<&'a ObjectName>,
+    ctes: Vec<String>,
+}
+
+impl<'a> TableCollector<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::Select(SelectStmt(query)) => self.visit_query(query),
+            Stmt::Insert(insert) => self.visit_insert(insert),
+            Stmt::Update(update) => self.visit_update(update),
+            Stmt::Delete(delete) => self.visit_delete(delete),
+            _ => {}
+        }
+    }
+
+    fn visit_insert(&mut self, insert: &'a InsertStmt) {
+        self.visit_with(&insert.with);
+        self.tables.push(&insert.table);
+        if let InsertSource::Subquery { subquery, .. } = &insert.source {
+            self.visit_query(subquery);
+        }
+    }
+
+    fn visit_update(&mut self, update: &'a UpdateStmt) {
+        self.visit_with(&update.with);
+        self.tables.push(&update.table);
+    }
+
+    fn visit_delete(&mut self, delete: &'a DeleteStmt) {
+        self.visit_with(&delete.with);
+        self.tables.push(&delete.table);
+    }
+
+    fn visit_with(&mut self, with: &'a Option<With>) {
+        for cte in with.iter().flat_map(|with| &with.ctes) {
+            self.ctes.push(cte.name.normalized_value());
+            match &cte.body {
+                CteBody::Query(query) => self.visit_query(query),
+                CteBody::Insert(insert) => self.visit_insert(insert),
+                CteBody::Update(update) => self.visit_update(update),
+                CteBody::Delete(delete) => self.visit_delete(delete),
+            }
+        }
+    }
+
+    fn visit_query(&mut self, query: &'a Query) {
+        self.visit_with(&query.with);
+        self.visit_query_body(&query.body);
+    }
+
+    fn visit_query_body(&mut self, body: &'a QueryBody) {
+        match body {
+            QueryBody::QuerySpec(spec) => {
+                for table in spec.from.iter().flat_map(|from| &from.list) {
+                    self.visit_table_reference(table);
+                }
+            }
+            QueryBody::Subquery(query) => self.visit_query(query),
+            QueryBody::Table(name) => self.tables.push(name),
+            QueryBody::Operation { left, right, .. } => {
+                self.visit_query_body(left);
+                self.visit_query_body(right);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_table_reference(&mut self, table: &'a TableReference) {
+        self.visit_table_factor(&table.relation);
+        for join in &table.joins {
+            self.visit_table_factor(&join.relation);
+        }
+    }
+
+    fn visit_table_factor(&mut self, factor: &'a TableFactor) {
+        match factor {
+            TableFactor::Table { name, .. } => self.tables.push(name),
+            TableFactor::Derived { subquery, .. } => self.visit_query(subquery),
+            TableFactor::NestedJoin(table) => self.visit_table_reference(table),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::postgres::PostgresDialect;
+
+    /// Returns the severity, the message and the text of the diagnostics.
+    fn check<'a>(sql: &'a str, catalog: Option<&Catalog>) -> Vec<(Severity, String, &'a str)> {
+        let dialect = PostgresDialect::default();
+        validate(sql, &dialect, catalog)
+            .into_iter()
+            .map(|d| (d.severity, d.message, &sql[d.span.start..d.span.end]))
+            .collect()
+    }
+
+    #[test]
+    fn validate_script() {
+        assert_eq!(check("SELECT 1; ; DELETE FROM t -- end", None), vec![]);
+
+        // every statement is parsed even if a previous one fails
+        let long = "a".repeat(64);
+        let sql = format!("SELECT {} FROM t; SELECT FROM; UPDATE SET a = 1", long);
+        let diagnostics = check(&sql, None);
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].0, Severity::Warning);
+        assert_eq!(diagnostics[0].2, "");
+        assert_eq!(diagnostics[1].0, Severity::Error);
+        assert_eq!(diagnostics[1].2, "SELECT FROM");
+        assert_eq!(diagnostics[2].0, Severity::Error);
+        assert_eq!(diagnostics[2].2, "UPDATE SET a = 1");
+
+        // a lexer error stops the validation
+        let diagnostics = check("SELECT FROM; SELECT 'a", None);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, Severity::Error);
+        assert_eq!(diagnostics[0].2, "");
+    }
+
+    #[cfg(feature = "procedural")]
+    #[test]
+    fn validate_stored_programs() {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "CREATE PROCEDURE p() BEGIN DELETE FROM t; SELECT 1; END; SELECT FROM";
+        let diagnostics = validate(sql, &dialect, None);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(&sql[diagnostics[0].span.start..], "SELECT FROM");
+    }
+
+    #[test]
+    fn validate_with_batch_separators() {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let separators = BatchSeparators {
+            go: true,
+            delimiter: true,
+        };
+        let sql = "DELIMITER $$\nSELECT 1; SELECT FROM$$\nDELIMITER ;\nSELECT 2\nGO\nSELECT FROM";
+        let diagnostics = validate_with_separators(sql, &dialect, None, separators);
+        let texts = diagnostics
+            .iter()
+            .map(|d| &sql[d.span.start..d.span.end])
+            .collect::<Vec<_>>();
+        assert_eq!(texts, vec!["SELECT 1; SELECT FROM", "SELECT FROM"]);
+        // the separators are only recognized if enabled
+        assert!(validate(sql, &dialect, None)
+            .iter()
+            .any(|d| sql[d.span.start..].starts_with("DELIMITER $$")));
+
+        // an invalid separator stops the validation
+        let sql = "SELECT FROM;\nDELIMITER\nSELECT 1";
+        let diagnostics = validate_with_separators(sql, &dialect, None, separators);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "DELIMITER must be followed by a delimiter"
+        );
+        assert_eq!(
+            &sql[diagnostics[0].span.start..diagnostics[0].span.end],
+            "DELIMITER"
+        );
+    }
+
+    #[test]
+    fn validate_tables() {
+        let mut catalog = Catalog::new();
+        catalog.add_table("public.users");
+        catalog.add_table("Orders");
+
+        let sql = "WITH recent AS (SELECT * FROM orders) \
+            SELECT * FROM users u JOIN recent r ON u.id = r.uid, public.items; \
+            INSERT INTO PUBLIC.USERS SELECT * FROM (SELECT * FROM \"users\" UNION TABLE logs) t; \
+            DELETE FROM accounts; CREATE TABLE foo (a INT)";
+        assert_eq!(
+            check(sql, Some(&catalog)),
+            vec![
+                (
+                    Severity::Error,
+                    "Unknown table `public.items`".into(),
+                    "public.items"
+                ),
+                (Severity::Error, "Unknown table `logs`".into(), "logs"),
+                (
+                    Severity::Error,
+                    "Unknown table `accounts`".into(),
+                    "accounts"
+                ),
+            ]
+        );
+        assert_eq!(check(sql, None), vec![]);
+    }
+}
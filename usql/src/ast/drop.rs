@@ -0,0 +1,374 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::ast::{expression::*, types::ObjectName};
+
+// The expressions and queries are dropped iteratively, so that dropping a deeply nested tree
+// (e.g. a long chain of binary operators, which is parsed without recursion) doesn't overflow
+// the stack.
+//
+// Dropping a node moves its nested expressions and query bodies to a stack, leaving leaves in
+// their places, then the nodes on the stack are dropped one by one, whose children are moved to
+// the stack in turn. A node whose children have been moved out is dropped without recursion.
+
+/// A node moved out of the tree, which is dropped after its children are moved out.
+enum Node {
+    Expr(Expr),
+    Body(QueryBody),
+}
+
+fn drop_iteratively(mut stack: Vec<Node>) {
+    while let Some(node) = stack.pop() {
+        match node {
+            Node::Expr(mut expr) => take_expr_children(&mut expr, &mut stack),
+            Node::Body(mut body) => take_body_children(&mut body, &mut stack),
+        }
+    }
+}
+
+impl Drop for Expr {
+    fn drop(&mut self) {
+        let mut stack = Vec::new();
+        take_expr_children(self, &mut stack);
+        drop_iteratively(stack);
+    }
+}
+
+impl Drop for QueryBody {
+    fn drop(&mut self) {
+        let mut stack = Vec::new();
+        take_body_children(self, &mut stack);
+        drop_iteratively(stack);
+    }
+}
+
+/// Moves the expression to the stack, unless it's a leaf which can be dropped directly.
+fn take_expr(expr: &mut Expr, stack: &mut Vec<Node>) {
+    match expr {
+        Expr::Literal(_)
+        | Expr::SpecialValue(_)
+        | Expr::PseudoColumn(_)
+        | Expr::NextValueFor(_)
+        | Expr::Identifier(_)
+        | Expr::Wildcard
+        | Expr::QualifiedWildcard(_)
+        | Expr::CompoundIdentifier(_) => {}
+        expr => stack.push(Node::Expr(mem::replace(expr, Expr::Wildcard))),
+    }
+}
+
+fn take_exprs(exprs: &mut [Expr], stack: &mut Vec<Node>) {
+    for expr in exprs {
+        take_expr(expr, stack);
+    }
+}
+
+/// Moves the query body to the stack, unless it's a leaf which can be dropped directly.
+fn take_body(body: &mut QueryBody, stack: &mut Vec<Node>) {
+    if !matches!(body, QueryBody::Table(_)) {
+        let leaf = QueryBody::Table(ObjectName(Vec::new()));
+        stack.push(Node::Body(mem::replace(body, leaf)));
+    }
+}
+
+fn take_expr_children(expr: &mut Expr, stack: &mut Vec<Node>) {
+    match expr {
+        Expr::Literal(_)
+        | Expr::SpecialValue(_)
+        | Expr::PseudoColumn(_)
+        | Expr::NextValueFor(_)
+        | Expr::Identifier(_)
+        | Expr::Wildcard
+        | Expr::QualifiedWildcard(_)
+        | Expr::CompoundIdentifier(_) => {}
+        Expr::CompositeWildcard(expr)
+        | Expr::Nested(expr)
+        | Expr::CompositeField(CompositeFieldExpr { expr, .. })
+        | Expr::IsNull(IsNullExpr { expr, .. })
+        | Expr::UnaryOp(UnaryOpExpr { expr, .. })
+        | Expr::Cast(CastExpr { expr, .. })
+        | Expr::Collate(CollateExpr { expr, .. })
+        | Expr::Extract(ExtractExpr { expr, .. })
+        | Expr::Translate(TranslateExpr { expr, .. }) => take_expr(expr, stack),
+        Expr::IsDistinctFrom(IsDistinctFromExpr { left, right, .. })
+        | Expr::BinaryOp(BinaryOpExpr { left, right, .. })
+        | Expr::NullIf(NullIfExpr { left, right })
+        | Expr::Position(PositionExpr {
+            expr: left,
+            r#in: right,
+        }) => {
+            take_expr(left, stack);
+            take_expr(right, stack);
+        }
+        Expr::Subquery(query) | Expr::ArraySubquery(query) | Expr::Exists(query) => {
+            take_query_children(query, stack)
+        }
+        Expr::InList(InListExpr { expr, list, .. }) => {
+            take_expr(expr, stack);
+            take_exprs(list, stack);
+        }
+        Expr::InSubquery(InSubqueryExpr { expr, subquery, .. }) => {
+            take_expr(expr, stack);
+            take_query_children(subquery, stack);
+        }
+        Expr::Between(BetweenExpr {
+            expr, low, high, ..
+        }) => {
+            take_expr(expr, stack);
+            take_expr(low, stack);
+            take_expr(high, stack);
+        }
+        Expr::Case(CaseExpr {
+            operand,
+            conditions,
+            results,
+            else_result,
+        }) => {
+            for expr in operand.iter_mut().chain(else_result) {
+                take_expr(expr, stack);
+            }
+            take_exprs(conditions, stack);
+            take_exprs(results, stack);
+        }
+        Expr::Function(function) => {
+            for arg in &mut function.args {
+                match arg {
+                    FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg) => {
+                        take_expr(arg, stack)
+                    }
+                }
+            }
+            if let Some(WindowNameOrSpec::Spec(spec)) = &mut function.over {
+                take_window_spec(spec, stack);
+            }
+        }
+        Expr::Coalesce(exprs) | Expr::Greatest(exprs) | Expr::Least(exprs) => {
+            take_exprs(exprs, stack)
+        }
+        Expr::If(IfExpr {
+            condition,
+            then_result,
+            else_result,
+            ..
+        }) => {
+            take_expr(condition, stack);
+            take_expr(then_result, stack);
+            take_expr(else_result, stack);
+        }
+        Expr::Array(ArrayExpr { elements, .. }) => take_exprs(elements, stack),
+        Expr::Substring(SubstringExpr {
+            expr,
+            substring_from,
+            substring_for,
+        }) => {
+            take_expr(expr, stack);
+            for expr in substring_from.iter_mut().chain(substring_for) {
+                take_expr(expr, stack);
+            }
+        }
+        Expr::Trim(TrimExpr { expr, trim_where }) => {
+            take_expr(expr, stack);
+            if let Some((_, expr)) = trim_where {
+                take_expr(expr, stack);
+            }
+        }
+        Expr::Overlay(OverlayExpr {
+            expr,
+            overlay_what,
+            overlay_from,
+            overlay_for,
+        }) => {
+            take_expr(expr, stack);
+            take_expr(overlay_what, stack);
+            take_expr(overlay_from, stack);
+            if let Some(expr) = overlay_for {
+                take_expr(expr, stack);
+            }
+        }
+        Expr::ListAgg(ListAggExpr {
+            expr,
+            separator,
+            within_group,
+            ..
+        }) => {
+            take_expr(expr, stack);
+            if let Some(expr) = separator {
+                take_expr(expr, stack);
+            }
+            for order_by in within_group {
+                take_order_by(order_by, stack);
+            }
+        }
+    }
+}
+
+fn take_order_by(order_by: &mut OrderBy, stack: &mut Vec<Node>) {
+    for spec in &mut order_by.list {
+        take_expr(&mut spec.expr, stack);
+    }
+}
+
+fn take_window_spec(spec: &mut WindowSpec, stack: &mut Vec<Node>) {
+    if let Some(order_by) = &mut spec.order_by {
+        take_order_by(order_by, stack);
+    }
+}
+
+/// Moves the children of the query to the stack, the query itself is not moved, which is
+/// dropped with its owner.
+///
+/// The `OFFSET`, `FETCH` and `LIMIT` clauses only hold literals, so there's nothing to move.
+fn take_query_children(query: &mut Query, stack: &mut Vec<Node>) {
+    for cte in query.with.iter_mut().flat_map(|with| &mut with.ctes) {
+        if let CteBody::Query(query) = &mut cte.body {
+            take_body(&mut query.body, stack);
+        }
+    }
+    take_body(&mut query.body, stack);
+    if let Some(order_by) = &mut query.order_by {
+        take_order_by(order_by, stack);
+    }
+}
+
+fn take_body_children(body: &mut QueryBody, stack: &mut Vec<Node>) {
+    match body {
+        QueryBody::QuerySpec(spec) => {
+            for item in &mut spec.projection {
+                if let SelectItem::DerivedColumn { expr, .. } = item {
+                    take_expr(expr, stack);
+                }
+            }
+            for table in spec.from.iter_mut().flat_map(|from| &mut from.list) {
+                take_table_reference_children(table, stack);
+            }
+            if let Some(selection) = &mut spec.r#where {
+                take_expr(&mut selection.expr, stack);
+            }
+            if let Some(connect_by) = &mut spec.connect_by {
+                for expr in connect_by.start_with.iter_mut() {
+                    take_expr(expr, stack);
+                }
+                take_expr(&mut connect_by.condition, stack);
+            }
+            // the `GROUP BY` clause only holds column names and ordinals
+            if let Some(having) = &mut spec.having {
+                take_expr(&mut having.expr, stack);
+            }
+            for def in spec.window.iter_mut().flat_map(|window| &mut window.list) {
+                take_window_spec(&mut def.spec, stack);
+            }
+        }
+        QueryBody::Subquery(query) => take_query_children(query, stack),
+        QueryBody::Values(values) => {
+            for row in &mut values.list {
+                take_exprs(row, stack);
+            }
+        }
+        QueryBody::Table(_) => {}
+        QueryBody::Operation { left, right, .. } => {
+            take_body(left, stack);
+            take_body(right, stack);
+        }
+    }
+}
+
+fn take_table_reference_children(table: &mut TableReference, stack: &mut Vec<Node>) {
+    take_table_factor_children(&mut table.relation, stack);
+    for join in &mut table.joins {
+        match &mut join.join {
+            JoinOperator::InnerJoin(JoinSpec::On(expr))
+            | JoinOperator::LeftOuterJoin(JoinSpec::On(expr))
+            | JoinOperator::RightOuterJoin(JoinSpec::On(expr))
+            | JoinOperator::FullOuterJoin(JoinSpec::On(expr)) => take_expr(expr, stack),
+            _ => {}
+        }
+        take_table_factor_children(&mut join.relation, stack);
+    }
+}
+
+fn take_table_factor_children(relation: &mut TableFactor, stack: &mut Vec<Node>) {
+    match relation {
+        TableFactor::Derived { subquery, .. } => take_query_children(subquery, stack),
+        TableFactor::NestedJoin(table) => take_table_reference_children(table, stack),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ansi::AnsiDialect, parser::Parser};
+
+    #[test]
+    fn drop_deep_tree() {
+        // the expressions and queries are parsed without recursion
+        let dialect = AnsiDialect::default();
+        let sql = format!("SELECT {} FROM t", vec!["1"; 100_000].join(" + "));
+        let stmt = Parser::new_with_sql(&dialect, &sql)
+            .unwrap()
+            .parse_stmt()
+            .unwrap();
+        drop(stmt);
+        let sql = vec!["SELECT a FROM t"; 100_000].join(" UNION ");
+        let stmt = Parser::new_with_sql(&dialect, &sql)
+            .unwrap()
+            .parse_stmt()
+            .unwrap();
+        drop(stmt);
+
+        let mut expr = Expr::Identifier("a".into());
+        for _ in 0..100_000 {
+            expr = Expr::Subquery(Box::new(Query {
+                with: None,
+                body: QueryBody::QuerySpec(Box::new(QuerySpec {
+                    projection: vec![SelectItem::DerivedColumn {
+                        expr: Box::new(Expr::Nested(Box::new(expr))),
+                        alias: None,
+                    }],
+                    quantifier: None,
+                    from: None,
+                    r#where: None,
+                    connect_by: None,
+                    group_by: None,
+                    having: None,
+                    window: None,
+                })),
+                order_by: None,
+                offset: None,
+                fetch: None,
+                limit: None,
+                output: None,
+            }));
+        }
+        drop(expr);
+
+        // through the window specifications of the `OVER` and `WINDOW` clauses, in queries with
+        // a `GROUP BY` clause
+        let mut expr = Expr::Identifier("a".into());
+        for i in 0..100_000 {
+            let spec = WindowSpec::default().with_order_by(OrderBy::new(vec![SortSpec::new(expr)]));
+            let function = Function::new(ObjectName::new(vec!["rank"]), vec![]);
+            let group_by = GroupBy::new(vec![GroupingElement::OrdinarySet(GroupingSet::Column(
+                ObjectName::new(vec!["a"]),
+            ))]);
+            let spec = if i % 2 == 0 {
+                let function = function.with_over(WindowNameOrSpec::Spec(spec));
+                QuerySpec::new(vec![SelectItem::DerivedColumn {
+                    expr: Box::new(Expr::Function(function)),
+                    alias: None,
+                }])
+            } else {
+                let function = function.with_over(WindowNameOrSpec::Name("w".into()));
+                QuerySpec::new(vec![SelectItem::DerivedColumn {
+                    expr: Box::new(Expr::Function(function)),
+                    alias: None,
+                }])
+                .with_window(Window::new(vec![WindowDef::new("w".into(), spec)]))
+            };
+            let body = QueryBody::QuerySpec(Box::new(spec.with_group_by(group_by)));
+            expr = Expr::Subquery(Box::new(Query::new(body)));
+        }
+        drop(expr);
+    }
+}
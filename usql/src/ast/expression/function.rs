@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 use core::fmt;
 
 use crate::ast::{
-    expression::{Expr, WindowSpec},
+    expression::{Expr, WindowNameOrSpec},
     types::{Ident, ObjectName},
     utils::display_comma_separated,
 };
@@ -11,16 +11,63 @@ use crate::ast::{
 /// A function call.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Function {
     // aggregate functions may specify e.g. `COUNT(DISTINCT x)`
+    /// Flag indicates that the duplicate arguments are eliminated.
     #[doc(hidden)]
-    pub distinct: bool,
+    pub(crate) distinct: bool,
     /// The name of the function.
-    pub name: ObjectName,
+    pub(crate) name: ObjectName,
     /// The arguments of the function.
-    pub args: Vec<FunctionArg>,
+    pub(crate) args: Vec<FunctionArg>,
     /// The over clause.
-    pub over: Option<WindowSpec>,
+    pub(crate) over: Option<WindowNameOrSpec>,
+}
+
+impl Function {
+    /// Creates a `Function`, the optional fields are unset.
+    pub fn new(name: ObjectName, args: Vec<FunctionArg>) -> Self {
+        Self {
+            distinct: false,
+            name,
+            args,
+            over: None,
+        }
+    }
+
+    /// Sets the `distinct` flag.
+    pub fn with_distinct(mut self, distinct: bool) -> Self {
+        self.distinct = distinct;
+        self
+    }
+
+    /// Sets the `over`.
+    pub fn with_over(mut self, over: WindowNameOrSpec) -> Self {
+        self.over = Some(over);
+        self
+    }
+
+    /// Flag indicates that the duplicate arguments are eliminated.
+    pub fn distinct(&self) -> bool {
+        self.distinct
+    }
+
+    /// The name of the function.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// The arguments of the function.
+    pub fn args(&self) -> &[FunctionArg] {
+        &self.args
+    }
+
+    /// The over clause.
+    pub fn over(&self) -> Option<&WindowNameOrSpec> {
+        self.over.as_ref()
+    }
 }
 
 impl fmt::Display for Function {
@@ -33,7 +80,7 @@ impl fmt::Display for Function {
             display_comma_separated(&self.args),
         )?;
         if let Some(o) = &self.over {
-            write!(f, " OVER ({})", o)?;
+            write!(f, " OVER {}", o)?;
         }
         Ok(())
     }
@@ -42,6 +89,8 @@ impl fmt::Display for Function {
 /// The arguments of a function call.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum FunctionArg {
     /// Named argument.
     #[doc(hidden)]
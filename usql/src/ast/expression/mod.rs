@@ -14,16 +14,24 @@ pub use self::{
     query::*,
 };
 use crate::ast::{
-    types::{DataType, DateTimeField, Ident, Literal},
+    types::{DataType, DateTimeField, Ident, Literal, ObjectName},
     utils::{display_comma_separated, display_separated},
 };
 
 /// SQL expression type.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum Expr {
     /// A literal value, such as string, number, date.
     Literal(Literal),
+    /// A special value function without arguments, e.g. `CURRENT_TIMESTAMP` or `LOCALTIME(2)`
+    SpecialValue(SpecialValueExpr),
+    /// A pseudo-column of the hierarchical queries, e.g. `LEVEL` (Oracle)
+    PseudoColumn(PseudoColumn),
+    /// The next value of a sequence, e.g. `NEXT VALUE FOR s` (MariaDB)
+    NextValueFor(ObjectName),
 
     /// Identifier e.g. table name or column name
     Identifier(Ident),
@@ -38,6 +46,10 @@ pub enum Expr {
     /// Qualified wildcard, e.g. `alias.*` or `schema.table.*`.
     /// (Same caveats apply to `QualifiedWildcard` as to `Wildcard`.)
     QualifiedWildcard(Vec<Ident>),
+    /// Expansion of a composite value, e.g. `(t).*` or `(func()).*`. (PostgreSQL)
+    CompositeWildcard(Box<Expr>),
+    /// Field selection of a composite value, e.g. `(t).field` or `(func()).field`. (PostgreSQL)
+    CompositeField(CompositeFieldExpr),
     /// Multi-part identifier, e.g. `table_alias.column` or `schema.table.col`
     CompoundIdentifier(Vec<Ident>),
 
@@ -73,13 +85,33 @@ pub enum Expr {
     Case(CaseExpr),
 
     /// CAST / TRY_CAST an expression to a different data type,
-    /// e.g. `CAST(foo AS VARCHAR(123))`, `TRY_CAST(foo AS VARCHAR(123))`
+    /// e.g. `CAST(foo AS VARCHAR(123))`, `TRY_CAST(foo AS VARCHAR(123))`, `foo::VARCHAR(123)`
+    /// or `int4(foo)`
     //  TRY_CAST differs from CAST in the choice of how to implement invalid conversions
     Cast(CastExpr),
 
+    /// `<expr> COLLATE <collation name>`, e.g. `name COLLATE "de_DE"`
+    Collate(CollateExpr),
+
     /// Scalar function call e.g. `COUNT(DISTINCT x)`
     Function(Function),
 
+    /// NULLIF(<expr>, <expr>)
+    NullIf(NullIfExpr),
+    /// COALESCE(<expr> [, ...])
+    Coalesce(Vec<Expr>),
+    /// GREATEST(<expr> [, ...])
+    Greatest(Vec<Expr>),
+    /// LEAST(<expr> [, ...])
+    Least(Vec<Expr>),
+    /// IF(<condition>, <expr>, <expr>) (MySQL) or IIF(<condition>, <expr>, <expr>) (SQLite)
+    If(IfExpr),
+
+    /// An array value constructor, e.g. `ARRAY[1, 2, 3]` or `ARRAY[[1, 2], [3, 4]]`
+    Array(ArrayExpr),
+    /// An array value constructor by query, e.g. `ARRAY(SELECT ...)`
+    ArraySubquery(Box<Query>),
+
     /// An exists expression `EXISTS(SELECT ...)`, used in expressions like
     /// `WHERE EXISTS (SELECT ...)`.
     Exists(Box<Query>),
@@ -95,18 +127,61 @@ pub enum Expr {
     /// TRIM(<expr>)
     Trim(TrimExpr),
 
+    /// POSITION(<expr> IN <expr>)
+    Position(PositionExpr),
+
+    /// OVERLAY(<expr> PLACING <expr> FROM <expr> [FOR <expr>])
+    Overlay(OverlayExpr),
+
+    /// TRANSLATE(<expr> USING <transliteration name>)
+    Translate(TranslateExpr),
+
     /// LISTAGG( [ DISTINCT ] <expr> [, <separator> ] [ON OVERFLOW <on_overflow>] ) )
     /// [ WITHIN GROUP (ORDER BY <within_group1>[, ...] ) ]
     ListAgg(ListAggExpr),
 }
 
+impl Expr {
+    /// Creates a unary operation, e.g. `NOT foo`.
+    pub fn unary(op: UnaryOperator, expr: Expr) -> Self {
+        Self::UnaryOp(UnaryOpExpr {
+            op,
+            expr: Box::new(expr),
+        })
+    }
+
+    /// Creates a binary operation, e.g. `1 + 1` or `foo > bar`.
+    pub fn binary(left: Expr, op: BinaryOperator, right: Expr) -> Self {
+        Self::BinaryOp(BinaryOpExpr {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+        })
+    }
+
+    /// Creates a nested expression, e.g. `(foo > bar)`.
+    pub fn nested(expr: Expr) -> Self {
+        Self::Nested(Box::new(expr))
+    }
+
+    /// Checks if the expression is a binary operation with the given operator.
+    pub fn is_binary_op(&self, op: BinaryOperator) -> bool {
+        matches!(self, Expr::BinaryOp(expr) if expr.op == op)
+    }
+}
+
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Literal(v) => write!(f, "{}", v),
+            Self::SpecialValue(expr) => write!(f, "{}", expr),
+            Self::PseudoColumn(column) => write!(f, "{}", column),
+            Self::NextValueFor(sequence) => write!(f, "NEXT VALUE FOR {}", sequence),
             Self::Identifier(ident) => write!(f, "{}", ident),
             Self::Wildcard => f.write_str("*"),
             Self::QualifiedWildcard(idents) => write!(f, "{}.*", display_separated(idents, ".")),
+            Self::CompositeWildcard(expr) => write!(f, "{}.*", expr),
+            Self::CompositeField(expr) => write!(f, "{}", expr),
             Self::CompoundIdentifier(idents) => write!(f, "{}", display_separated(idents, ".")),
             Self::Nested(expr) => write!(f, "({})", expr),
             Self::Subquery(query) => write!(f, "({})", query),
@@ -119,23 +194,185 @@ impl fmt::Display for Expr {
             Self::Between(expr) => write!(f, "{}", expr),
             Self::Case(expr) => write!(f, "{}", expr),
             Self::Cast(expr) => write!(f, "{}", expr),
+            Self::Collate(expr) => write!(f, "{}", expr),
             Self::Function(func) => write!(f, "{}", func),
+            Self::NullIf(expr) => write!(f, "{}", expr),
+            Self::Coalesce(exprs) => write!(f, "COALESCE({})", display_comma_separated(exprs)),
+            Self::Greatest(exprs) => write!(f, "GREATEST({})", display_comma_separated(exprs)),
+            Self::Least(exprs) => write!(f, "LEAST({})", display_comma_separated(exprs)),
+            Self::If(expr) => write!(f, "{}", expr),
+            Self::Array(expr) => write!(f, "{}", expr),
+            Self::ArraySubquery(query) => write!(f, "ARRAY({})", query),
             Self::Exists(query) => write!(f, "EXISTS ({})", query),
             Self::Extract(expr) => write!(f, "{}", expr),
             Self::Substring(expr) => write!(f, "{}", expr),
             Self::Trim(expr) => write!(f, "{}", expr),
+            Self::Position(expr) => write!(f, "{}", expr),
+            Self::Overlay(expr) => write!(f, "{}", expr),
+            Self::Translate(expr) => write!(f, "{}", expr),
             Self::ListAgg(expr) => write!(f, "{}", expr),
         }
     }
 }
 
+/// A special value function, e.g. `CURRENT_TIMESTAMP` or `LOCALTIME(2)`.
+///
+/// ```txt
+/// <datetime value function> ::=
+///     CURRENT_DATE
+///     | CURRENT_TIME [ ( <time precision> ) ]
+///     | CURRENT_TIMESTAMP [ ( <timestamp precision> ) ]
+///     | LOCALTIME [ ( <time precision> ) ]
+///     | LOCALTIMESTAMP [ ( <timestamp precision> ) ]
+///
+/// <general value specification> ::= CURRENT_USER | SESSION_USER | CURRENT_ROLE
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SpecialValueExpr {
+    /// Special value.
+    pub(crate) value: SpecialValue,
+    /// The fractional seconds precision, only for the time and timestamp values.
+    pub(crate) precision: Option<u64>,
+}
+
+impl SpecialValueExpr {
+    /// Creates a `SpecialValueExpr`, the optional fields are unset.
+    pub fn new(value: SpecialValue) -> Self {
+        Self {
+            value,
+            precision: None,
+        }
+    }
+
+    /// Sets the `precision`.
+    pub fn with_precision(mut self, precision: u64) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Special value.
+    pub fn value(&self) -> SpecialValue {
+        self.value
+    }
+
+    /// The fractional seconds precision, only for the time and timestamp values.
+    pub fn precision(&self) -> Option<u64> {
+        self.precision
+    }
+}
+
+impl fmt::Display for SpecialValueExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)?;
+        if let Some(precision) = self.precision {
+            write!(f, "({})", precision)?;
+        }
+        Ok(())
+    }
+}
+
+/// The special values.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum SpecialValue {
+    CurrentDate,
+    CurrentTime,
+    CurrentTimestamp,
+    LocalTime,
+    LocalTimestamp,
+    CurrentUser,
+    SessionUser,
+    CurrentRole,
+}
+
+impl SpecialValue {
+    /// Returns true if the value accepts a fractional seconds precision.
+    pub fn has_precision(&self) -> bool {
+        matches!(
+            self,
+            Self::CurrentTime | Self::CurrentTimestamp | Self::LocalTime | Self::LocalTimestamp
+        )
+    }
+}
+
+impl fmt::Display for SpecialValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::CurrentDate => "CURRENT_DATE",
+            Self::CurrentTime => "CURRENT_TIME",
+            Self::CurrentTimestamp => "CURRENT_TIMESTAMP",
+            Self::LocalTime => "LOCALTIME",
+            Self::LocalTimestamp => "LOCALTIMESTAMP",
+            Self::CurrentUser => "CURRENT_USER",
+            Self::SessionUser => "SESSION_USER",
+            Self::CurrentRole => "CURRENT_ROLE",
+        })
+    }
+}
+
+/// The pseudo-columns of the hierarchical queries, see [`ConnectBy`].
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PseudoColumn {
+    /// The level of a row in the hierarchy, 1 for a root row.
+    Level,
+}
+
+impl fmt::Display for PseudoColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Level => "LEVEL",
+        })
+    }
+}
+
 /// `<expr> IS [NOT] NULL` operator.
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct IsNullExpr {
-    pub negated: bool,
-    pub expr: Box<Expr>,
+    /// Flag indicates that the predicate is negated.
+    pub(crate) negated: bool,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+}
+
+impl IsNullExpr {
+    /// Creates a `IsNullExpr`, the flags are unset.
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            negated: false,
+            expr: Box::new(expr),
+        }
+    }
+
+    /// Sets the `negated` flag.
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+
+    /// Flag indicates that the predicate is negated.
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
 }
 
 impl fmt::Display for IsNullExpr {
@@ -153,10 +390,47 @@ impl fmt::Display for IsNullExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct IsDistinctFromExpr {
-    pub negated: bool,
-    pub left: Box<Expr>,
-    pub right: Box<Expr>,
+    /// Flag indicates that the predicate is negated.
+    pub(crate) negated: bool,
+    /// Left operand.
+    pub(crate) left: Box<Expr>,
+    /// Right operand.
+    pub(crate) right: Box<Expr>,
+}
+
+impl IsDistinctFromExpr {
+    /// Creates a `IsDistinctFromExpr`, the flags are unset.
+    pub fn new(left: Expr, right: Expr) -> Self {
+        Self {
+            negated: false,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Sets the `negated` flag.
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+
+    /// Flag indicates that the predicate is negated.
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Left operand.
+    pub fn left(&self) -> &Expr {
+        &self.left
+    }
+
+    /// Right operand.
+    pub fn right(&self) -> &Expr {
+        &self.right
+    }
 }
 
 impl fmt::Display for IsDistinctFromExpr {
@@ -175,9 +449,33 @@ impl fmt::Display for IsDistinctFromExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct UnaryOpExpr {
-    pub op: UnaryOperator,
-    pub expr: Box<Expr>,
+    /// Operator.
+    pub(crate) op: UnaryOperator,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+}
+
+impl UnaryOpExpr {
+    /// Creates a `UnaryOpExpr`.
+    pub fn new(op: UnaryOperator, expr: Expr) -> Self {
+        Self {
+            op,
+            expr: Box::new(expr),
+        }
+    }
+
+    /// Operator.
+    pub fn op(&self) -> &UnaryOperator {
+        &self.op
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
 }
 
 impl fmt::Display for UnaryOpExpr {
@@ -190,10 +488,41 @@ impl fmt::Display for UnaryOpExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct BinaryOpExpr {
-    pub left: Box<Expr>,
-    pub op: BinaryOperator,
-    pub right: Box<Expr>,
+    /// Left operand.
+    pub(crate) left: Box<Expr>,
+    /// Operator.
+    pub(crate) op: BinaryOperator,
+    /// Right operand.
+    pub(crate) right: Box<Expr>,
+}
+
+impl BinaryOpExpr {
+    /// Creates a `BinaryOpExpr`.
+    pub fn new(left: Expr, op: BinaryOperator, right: Expr) -> Self {
+        Self {
+            left: Box::new(left),
+            op,
+            right: Box::new(right),
+        }
+    }
+
+    /// Left operand.
+    pub fn left(&self) -> &Expr {
+        &self.left
+    }
+
+    /// Operator.
+    pub fn op(&self) -> BinaryOperator {
+        self.op
+    }
+
+    /// Right operand.
+    pub fn right(&self) -> &Expr {
+        &self.right
+    }
 }
 
 impl fmt::Display for BinaryOpExpr {
@@ -206,10 +535,47 @@ impl fmt::Display for BinaryOpExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct InListExpr {
-    pub expr: Box<Expr>,
-    pub negated: bool,
-    pub list: Vec<Expr>,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Flag indicates that the predicate is negated.
+    pub(crate) negated: bool,
+    /// List of values.
+    pub(crate) list: Vec<Expr>,
+}
+
+impl InListExpr {
+    /// Creates a `InListExpr`, the flags are unset.
+    pub fn new(expr: Expr, list: Vec<Expr>) -> Self {
+        Self {
+            expr: Box::new(expr),
+            negated: false,
+            list,
+        }
+    }
+
+    /// Sets the `negated` flag.
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Flag indicates that the predicate is negated.
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    /// List of values.
+    pub fn list(&self) -> &[Expr] {
+        &self.list
+    }
 }
 
 impl fmt::Display for InListExpr {
@@ -228,10 +594,47 @@ impl fmt::Display for InListExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct InSubqueryExpr {
-    pub expr: Box<Expr>,
-    pub negated: bool,
-    pub subquery: Box<Query>,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Flag indicates that the predicate is negated.
+    pub(crate) negated: bool,
+    /// Subquery.
+    pub(crate) subquery: Box<Query>,
+}
+
+impl InSubqueryExpr {
+    /// Creates a `InSubqueryExpr`, the flags are unset.
+    pub fn new(expr: Expr, subquery: Query) -> Self {
+        Self {
+            expr: Box::new(expr),
+            negated: false,
+            subquery: Box::new(subquery),
+        }
+    }
+
+    /// Sets the `negated` flag.
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Flag indicates that the predicate is negated.
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Subquery.
+    pub fn subquery(&self) -> &Query {
+        &self.subquery
+    }
 }
 
 impl fmt::Display for InSubqueryExpr {
@@ -250,11 +653,55 @@ impl fmt::Display for InSubqueryExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct BetweenExpr {
-    pub expr: Box<Expr>,
-    pub negated: bool,
-    pub low: Box<Expr>,
-    pub high: Box<Expr>,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Flag indicates that the predicate is negated.
+    pub(crate) negated: bool,
+    /// Lower bound.
+    pub(crate) low: Box<Expr>,
+    /// Upper bound.
+    pub(crate) high: Box<Expr>,
+}
+
+impl BetweenExpr {
+    /// Creates a `BetweenExpr`, the flags are unset.
+    pub fn new(expr: Expr, low: Expr, high: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+            negated: false,
+            low: Box::new(low),
+            high: Box::new(high),
+        }
+    }
+
+    /// Sets the `negated` flag.
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Flag indicates that the predicate is negated.
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Lower bound.
+    pub fn low(&self) -> &Expr {
+        &self.low
+    }
+
+    /// Upper bound.
+    pub fn high(&self) -> &Expr {
+        &self.high
+    }
 }
 
 impl fmt::Display for BetweenExpr {
@@ -278,11 +725,61 @@ impl fmt::Display for BetweenExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct CaseExpr {
-    pub operand: Option<Box<Expr>>,
-    pub conditions: Vec<Expr>,
-    pub results: Vec<Expr>,
-    pub else_result: Option<Box<Expr>>,
+    /// Operand of the simple case expression.
+    pub(crate) operand: Option<Box<Expr>>,
+    /// Conditions of the WHEN clauses.
+    pub(crate) conditions: Vec<Expr>,
+    /// Results of the THEN clauses.
+    pub(crate) results: Vec<Expr>,
+    /// Result of the ELSE clause.
+    pub(crate) else_result: Option<Box<Expr>>,
+}
+
+impl CaseExpr {
+    /// Creates a `CaseExpr`, the optional fields are unset.
+    pub fn new(conditions: Vec<Expr>, results: Vec<Expr>) -> Self {
+        Self {
+            operand: None,
+            conditions,
+            results,
+            else_result: None,
+        }
+    }
+
+    /// Sets the `operand`.
+    pub fn with_operand(mut self, operand: Expr) -> Self {
+        self.operand = Some(Box::new(operand));
+        self
+    }
+
+    /// Sets the `else_result`.
+    pub fn with_else_result(mut self, else_result: Expr) -> Self {
+        self.else_result = Some(Box::new(else_result));
+        self
+    }
+
+    /// Operand of the simple case expression.
+    pub fn operand(&self) -> Option<&Expr> {
+        self.operand.as_deref()
+    }
+
+    /// Conditions of the WHEN clauses.
+    pub fn conditions(&self) -> &[Expr] {
+        &self.conditions
+    }
+
+    /// Results of the THEN clauses.
+    pub fn results(&self) -> &[Expr] {
+        &self.results
+    }
+
+    /// Result of the ELSE clause.
+    pub fn else_result(&self) -> Option<&Expr> {
+        self.else_result.as_deref()
+    }
 }
 
 impl fmt::Display for CaseExpr {
@@ -305,14 +802,308 @@ impl fmt::Display for CaseExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct CastExpr {
-    pub expr: Box<Expr>,
-    pub data_type: DataType,
+    /// Expression to cast.
+    pub(crate) expr: Box<Expr>,
+    /// Target data type.
+    pub(crate) data_type: DataType,
+    /// Cast style.
+    pub(crate) style: CastStyle,
+}
+
+impl CastExpr {
+    /// Creates a `CastExpr`.
+    pub fn new(expr: Expr, data_type: DataType, style: CastStyle) -> Self {
+        Self {
+            expr: Box::new(expr),
+            data_type,
+            style,
+        }
+    }
+
+    /// Expression to cast.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Target data type.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Cast style.
+    pub fn style(&self) -> CastStyle {
+        self.style
+    }
 }
 
 impl fmt::Display for CastExpr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "CAST({} AS {})", self.expr, self.data_type)
+        match self.style {
+            CastStyle::Cast => write!(f, "CAST({} AS {})", self.expr, self.data_type),
+            CastStyle::DoubleColon => write!(f, "{}::{}", self.expr, self.data_type),
+            CastStyle::Function => write!(f, "{}({})", self.data_type, self.expr),
+        }
+    }
+}
+
+/// The syntax of a cast expression.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum CastStyle {
+    /// `CAST(<expr> AS <data type>)`
+    Cast,
+    /// `<expr>::<data type>` (PostgreSQL)
+    DoubleColon,
+    /// `<type name>(<expr>)`, see [`DialectParserConf::is_type_name`]. (PostgreSQL)
+    ///
+    /// [`DialectParserConf::is_type_name`]: crate::dialect::DialectParserConf::is_type_name
+    Function,
+}
+
+/// Field selection of a composite value, e.g. `(t).field`. (PostgreSQL)
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CompositeFieldExpr {
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Field name.
+    pub(crate) field: Ident,
+}
+
+impl CompositeFieldExpr {
+    /// Creates a `CompositeFieldExpr`.
+    pub fn new(expr: Expr, field: Ident) -> Self {
+        Self {
+            expr: Box::new(expr),
+            field,
+        }
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Field name.
+    pub fn field(&self) -> &Ident {
+        &self.field
+    }
+}
+
+impl fmt::Display for CompositeFieldExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.expr, self.field)
+    }
+}
+
+/// An array value constructor, e.g. `ARRAY[1, 2, 3]`.
+///
+/// ```txt
+/// <array value constructor> ::= ARRAY [ <array element> [, ...] ]
+///
+/// // PostgreSQL, a multidimensional array
+/// ARRAY [ [ <array element> [, ...] ] [, ...] ]
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ArrayExpr {
+    /// Array elements.
+    pub(crate) elements: Vec<Expr>,
+    /// Whether the array is prefixed with the `ARRAY` keyword,
+    /// the nested arrays of a multidimensional array are not.
+    pub(crate) named: bool,
+}
+
+impl ArrayExpr {
+    /// Creates a `ArrayExpr`, the flags are unset.
+    pub fn new(elements: Vec<Expr>) -> Self {
+        Self {
+            elements,
+            named: false,
+        }
+    }
+
+    /// Sets the `named` flag.
+    pub fn with_named(mut self, named: bool) -> Self {
+        self.named = named;
+        self
+    }
+
+    /// Array elements.
+    pub fn elements(&self) -> &[Expr] {
+        &self.elements
+    }
+
+    /// Whether the array is prefixed with the `ARRAY` keyword,
+    /// the nested arrays of a multidimensional array are not.
+    pub fn named(&self) -> bool {
+        self.named
+    }
+}
+
+impl fmt::Display for ArrayExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.named {
+            f.write_str("ARRAY")?;
+        }
+        write!(f, "[{}]", display_comma_separated(&self.elements))
+    }
+}
+
+/// `<expr> COLLATE <collation name>` operator.
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CollateExpr {
+    /// Expression to collate.
+    pub(crate) expr: Box<Expr>,
+    /// Collation name.
+    pub(crate) collation: ObjectName,
+}
+
+impl CollateExpr {
+    /// Creates a `CollateExpr`.
+    pub fn new(expr: Expr, collation: ObjectName) -> Self {
+        Self {
+            expr: Box::new(expr),
+            collation,
+        }
+    }
+
+    /// Expression to collate.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Collation name.
+    pub fn collation(&self) -> &ObjectName {
+        &self.collation
+    }
+}
+
+impl fmt::Display for CollateExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} COLLATE {}", self.expr, self.collation)
+    }
+}
+
+/// `NULLIF(<expr1>, <expr2>)`
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct NullIfExpr {
+    /// Left operand.
+    pub(crate) left: Box<Expr>,
+    /// Right operand.
+    pub(crate) right: Box<Expr>,
+}
+
+impl NullIfExpr {
+    /// Creates a `NullIfExpr`.
+    pub fn new(left: Expr, right: Expr) -> Self {
+        Self {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    /// Left operand.
+    pub fn left(&self) -> &Expr {
+        &self.left
+    }
+
+    /// Right operand.
+    pub fn right(&self) -> &Expr {
+        &self.right
+    }
+}
+
+impl fmt::Display for NullIfExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NULLIF({}, {})", self.left, self.right)
+    }
+}
+
+/// `IF(<condition>, <then result>, <else result>)` or `IIF(...)`
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct IfExpr {
+    /// Whether the function is spelled `IIF`.
+    pub(crate) iif: bool,
+    /// Condition.
+    pub(crate) condition: Box<Expr>,
+    /// Result if the condition is true.
+    pub(crate) then_result: Box<Expr>,
+    /// Result if the condition is not true.
+    pub(crate) else_result: Box<Expr>,
+}
+
+impl IfExpr {
+    /// Creates a `IfExpr`, the flags are unset.
+    pub fn new(condition: Expr, then_result: Expr, else_result: Expr) -> Self {
+        Self {
+            iif: false,
+            condition: Box::new(condition),
+            then_result: Box::new(then_result),
+            else_result: Box::new(else_result),
+        }
+    }
+
+    /// Sets the `iif` flag.
+    pub fn with_iif(mut self, iif: bool) -> Self {
+        self.iif = iif;
+        self
+    }
+
+    /// Whether the function is spelled `IIF`.
+    pub fn iif(&self) -> bool {
+        self.iif
+    }
+
+    /// Condition.
+    pub fn condition(&self) -> &Expr {
+        &self.condition
+    }
+
+    /// Result if the condition is true.
+    pub fn then_result(&self) -> &Expr {
+        &self.then_result
+    }
+
+    /// Result if the condition is not true.
+    pub fn else_result(&self) -> &Expr {
+        &self.else_result
+    }
+}
+
+impl fmt::Display for IfExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}({}, {}, {})",
+            if self.iif { "IIF" } else { "IF" },
+            self.condition,
+            self.then_result,
+            self.else_result
+        )
     }
 }
 
@@ -320,9 +1111,33 @@ impl fmt::Display for CastExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct ExtractExpr {
-    pub field: DateTimeField,
-    pub expr: Box<Expr>,
+    /// Datetime field to extract.
+    pub(crate) field: DateTimeField,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+}
+
+impl ExtractExpr {
+    /// Creates a `ExtractExpr`.
+    pub fn new(field: DateTimeField, expr: Expr) -> Self {
+        Self {
+            field,
+            expr: Box::new(expr),
+        }
+    }
+
+    /// Datetime field to extract.
+    pub fn field(&self) -> DateTimeField {
+        self.field
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
 }
 
 impl fmt::Display for ExtractExpr {
@@ -335,10 +1150,53 @@ impl fmt::Display for ExtractExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct SubstringExpr {
-    pub expr: Box<Expr>,
-    pub substring_from: Option<Box<Expr>>,
-    pub substring_for: Option<Box<Expr>>,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Start position of the substring.
+    pub(crate) substring_from: Option<Box<Expr>>,
+    /// Length of the substring.
+    pub(crate) substring_for: Option<Box<Expr>>,
+}
+
+impl SubstringExpr {
+    /// Creates a `SubstringExpr`, the optional fields are unset.
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+            substring_from: None,
+            substring_for: None,
+        }
+    }
+
+    /// Sets the `substring_from`.
+    pub fn with_substring_from(mut self, substring_from: Expr) -> Self {
+        self.substring_from = Some(Box::new(substring_from));
+        self
+    }
+
+    /// Sets the `substring_for`.
+    pub fn with_substring_for(mut self, substring_for: Expr) -> Self {
+        self.substring_for = Some(Box::new(substring_for));
+        self
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Start position of the substring.
+    pub fn substring_from(&self) -> Option<&Expr> {
+        self.substring_from.as_deref()
+    }
+
+    /// Length of the substring.
+    pub fn substring_for(&self) -> Option<&Expr> {
+        self.substring_for.as_deref()
+    }
 }
 
 impl fmt::Display for SubstringExpr {
@@ -360,10 +1218,42 @@ impl fmt::Display for SubstringExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct TrimExpr {
-    pub expr: Box<Expr>,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
     // ([BOTH | LEADING | TRAILING], <expr>)
-    pub trim_where: Option<(TrimWhereField, Box<Expr>)>,
+    /// Trim specification and the characters to trim.
+    pub(crate) trim_where: Option<(TrimWhereField, Box<Expr>)>,
+}
+
+impl TrimExpr {
+    /// Creates a `TrimExpr`, the optional fields are unset.
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+            trim_where: None,
+        }
+    }
+
+    /// Sets the `trim_where`.
+    pub fn with_trim_where(mut self, trim_where: (TrimWhereField, Box<Expr>)) -> Self {
+        self.trim_where = Some(trim_where);
+        self
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Trim specification and the characters to trim.
+    pub fn trim_where(&self) -> Option<(TrimWhereField, &Expr)> {
+        self.trim_where
+            .as_ref()
+            .map(|(field, expr)| (*field, &**expr))
+    }
 }
 
 impl fmt::Display for TrimExpr {
@@ -382,6 +1272,8 @@ impl fmt::Display for TrimExpr {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum TrimWhereField {
     Both,
     Leading,
@@ -398,17 +1290,227 @@ impl fmt::Display for TrimWhereField {
     }
 }
 
+/// `POSITION(<expr> IN <expr>)`
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct PositionExpr {
+    /// Substring to search for.
+    pub(crate) expr: Box<Expr>,
+    /// String to search in.
+    pub(crate) r#in: Box<Expr>,
+}
+
+impl PositionExpr {
+    /// Creates a `PositionExpr`.
+    pub fn new(expr: Expr, r#in: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+            r#in: Box::new(r#in),
+        }
+    }
+
+    /// Substring to search for.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// String to search in.
+    pub fn r#in(&self) -> &Expr {
+        &self.r#in
+    }
+}
+
+impl fmt::Display for PositionExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "POSITION({} IN {})", self.expr, self.r#in)
+    }
+}
+
+/// `OVERLAY(<expr> PLACING <expr> FROM <expr> [FOR <expr>])`
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct OverlayExpr {
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Replacement string.
+    pub(crate) overlay_what: Box<Expr>,
+    /// Start position of the replaced substring.
+    pub(crate) overlay_from: Box<Expr>,
+    /// Length of the replaced substring.
+    pub(crate) overlay_for: Option<Box<Expr>>,
+}
+
+impl OverlayExpr {
+    /// Creates a `OverlayExpr`, the optional fields are unset.
+    pub fn new(expr: Expr, overlay_what: Expr, overlay_from: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+            overlay_what: Box::new(overlay_what),
+            overlay_from: Box::new(overlay_from),
+            overlay_for: None,
+        }
+    }
+
+    /// Sets the `overlay_for`.
+    pub fn with_overlay_for(mut self, overlay_for: Expr) -> Self {
+        self.overlay_for = Some(Box::new(overlay_for));
+        self
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Replacement string.
+    pub fn overlay_what(&self) -> &Expr {
+        &self.overlay_what
+    }
+
+    /// Start position of the replaced substring.
+    pub fn overlay_from(&self) -> &Expr {
+        &self.overlay_from
+    }
+
+    /// Length of the replaced substring.
+    pub fn overlay_for(&self) -> Option<&Expr> {
+        self.overlay_for.as_deref()
+    }
+}
+
+impl fmt::Display for OverlayExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "OVERLAY({} PLACING {} FROM {}",
+            self.expr, self.overlay_what, self.overlay_from
+        )?;
+        if let Some(overlay_for) = &self.overlay_for {
+            write!(f, " FOR {}", overlay_for)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// `TRANSLATE(<expr> USING <transliteration name>)`
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct TranslateExpr {
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Translation name.
+    pub(crate) using: ObjectName,
+}
+
+impl TranslateExpr {
+    /// Creates a `TranslateExpr`.
+    pub fn new(expr: Expr, using: ObjectName) -> Self {
+        Self {
+            expr: Box::new(expr),
+            using,
+        }
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Translation name.
+    pub fn using(&self) -> &ObjectName {
+        &self.using
+    }
+}
+
+impl fmt::Display for TranslateExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TRANSLATE({} USING {})", self.expr, self.using)
+    }
+}
+
 /// A `LISTAGG` invocation: LISTAGG( [ DISTINCT ] <expr> [, <separator> ] [ON OVERFLOW <on_overflow>] ) )
 /// [ WITHIN GROUP (ORDER BY <within_group1>[, ...] ) ]
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct ListAggExpr {
-    pub distinct: bool,
-    pub expr: Box<Expr>,
-    pub separator: Option<Box<Expr>>,
-    pub on_overflow: Option<ListAggOnOverflow>,
-    pub within_group: Vec<OrderBy>,
+    /// Flag indicates that the duplicate values are eliminated.
+    pub(crate) distinct: bool,
+    /// Operand expression.
+    pub(crate) expr: Box<Expr>,
+    /// Separator of the values.
+    pub(crate) separator: Option<Box<Expr>>,
+    /// Behavior on overflow.
+    pub(crate) on_overflow: Option<ListAggOnOverflow>,
+    /// Ordering of the WITHIN GROUP clause.
+    pub(crate) within_group: Vec<OrderBy>,
+}
+
+impl ListAggExpr {
+    /// Creates a `ListAggExpr`, the optional fields are unset.
+    pub fn new(expr: Expr, within_group: Vec<OrderBy>) -> Self {
+        Self {
+            distinct: false,
+            expr: Box::new(expr),
+            separator: None,
+            on_overflow: None,
+            within_group,
+        }
+    }
+
+    /// Sets the `distinct` flag.
+    pub fn with_distinct(mut self, distinct: bool) -> Self {
+        self.distinct = distinct;
+        self
+    }
+
+    /// Sets the `separator`.
+    pub fn with_separator(mut self, separator: Expr) -> Self {
+        self.separator = Some(Box::new(separator));
+        self
+    }
+
+    /// Sets the `on_overflow`.
+    pub fn with_on_overflow(mut self, on_overflow: ListAggOnOverflow) -> Self {
+        self.on_overflow = Some(on_overflow);
+        self
+    }
+
+    /// Flag indicates that the duplicate values are eliminated.
+    pub fn distinct(&self) -> bool {
+        self.distinct
+    }
+
+    /// Operand expression.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// Separator of the values.
+    pub fn separator(&self) -> Option<&Expr> {
+        self.separator.as_deref()
+    }
+
+    /// Behavior on overflow.
+    pub fn on_overflow(&self) -> Option<&ListAggOnOverflow> {
+        self.on_overflow.as_ref()
+    }
+
+    /// Ordering of the WITHIN GROUP clause.
+    pub fn within_group(&self) -> &[OrderBy] {
+        &self.within_group
+    }
 }
 
 impl fmt::Display for ListAggExpr {
@@ -441,6 +1543,8 @@ impl fmt::Display for ListAggExpr {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum ListAggOnOverflow {
     /// `ON OVERFLOW ERROR`
     Error,
@@ -4,10 +4,24 @@ use core::fmt;
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum UnaryOperator {
     Plus,
     Minus,
     Not,
+
+    // MySQL
+    LogicalNot,
+
+    // PostgreSQL
+    SquareRoot,
+    CubeRoot,
+    Length,
+    NumberOfPoints,
+
+    // Oracle
+    Prior,
 }
 
 impl fmt::Display for UnaryOperator {
@@ -16,6 +30,15 @@ impl fmt::Display for UnaryOperator {
             UnaryOperator::Plus => "+",
             UnaryOperator::Minus => "-",
             UnaryOperator::Not => "NOT",
+
+            UnaryOperator::LogicalNot => "!",
+
+            UnaryOperator::SquareRoot => "|/",
+            UnaryOperator::CubeRoot => "||/",
+            UnaryOperator::Length => "@-@",
+            UnaryOperator::NumberOfPoints => "#",
+
+            UnaryOperator::Prior => "PRIOR",
         })
     }
 }
@@ -24,6 +47,8 @@ impl fmt::Display for UnaryOperator {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum BinaryOperator {
     Plus,
     Minus,
@@ -54,6 +79,18 @@ pub enum BinaryOperator {
     NotLike,
     ILike,
     NotILike,
+
+    // MySQL
+    IntegerDivide,
+    NullSafeEqual,
+
+    // PostgreSQL
+    PgBitwiseXor,
+    Distance,
+    PgRegexMatch,
+    PgRegexNotMatch,
+    PgRegexIMatch,
+    PgRegexNotIMatch,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -88,6 +125,16 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::NotLike => "NOT LIKE",
             BinaryOperator::ILike => "ILIKE",
             BinaryOperator::NotILike => "NOT ILIKE",
+
+            BinaryOperator::IntegerDivide => "DIV",
+            BinaryOperator::NullSafeEqual => "<=>",
+
+            BinaryOperator::PgBitwiseXor => "#",
+            BinaryOperator::Distance => "<->",
+            BinaryOperator::PgRegexMatch => "~",
+            BinaryOperator::PgRegexNotMatch => "!~",
+            BinaryOperator::PgRegexIMatch => "~*",
+            BinaryOperator::PgRegexNotIMatch => "!~*",
         })
     }
 }
@@ -1,3 +1,5 @@
+// INTO OUTFILE and FOR XML/JSON
+mod output;
 // table expression
 mod table;
 
@@ -5,8 +7,13 @@ mod table;
 use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 
-pub use self::table::*;
-use crate::ast::{expression::*, types::*, utils::display_comma_separated};
+pub use self::{output::*, table::*};
+use crate::ast::{
+    expression::*,
+    statement::{DeleteStmt, InsertStmt, UpdateStmt},
+    types::*,
+    utils::display_comma_separated,
+};
 
 /// The most complete variant of a `SELECT` query expression, optionally
 /// including `WITH`, `UNION` / other set operations, and `ORDER BY`.
@@ -16,22 +23,117 @@ use crate::ast::{expression::*, types::*, utils::display_comma_separated};
 ///     [ <order by clause> ]
 ///     [ <result offset clause> ]
 ///     [ <fetch first clause> | <limit clause> ]
+///     [ <query output> ]
 /// ```
+///
+/// **NOTE**: the clauses can be written in any order, but it's displayed as
+/// `[ <limit clause> ] [ <result offset clause> ] [ <fetch first clause> ]`,
+/// a query never has both the `LIMIT` and the `FETCH FIRST` clause.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Query {
     /// WITH (common table expressions, or CTEs)
-    pub with: Option<With>,
+    pub(crate) with: Option<With>,
     /// SELECT or UNION / EXCEPT / INTERSECT
-    pub body: QueryBody,
+    pub(crate) body: QueryBody,
     /// `ORDER BY { <sort_key> [ ASC | DESC ] [ NULLS FIRST | NULLS LAST ] } [, ...]`
-    pub order_by: Option<OrderBy>,
+    pub(crate) order_by: Option<OrderBy>,
+    /// `OFFSET <N> [ { ROW | ROWS } ]`
+    pub(crate) offset: Option<Offset>,
+    /// `FETCH { FIRST | NEXT } <N> [ PERCENT ] { ROW | ROWS } | { ONLY | WITH TIES }`
+    pub(crate) fetch: Option<Fetch>,
+    /// `LIMIT { <N> | ALL }`
+    pub(crate) limit: Option<Limit>,
+    /// `INTO OUTFILE ...` (MySQL) or `FOR XML ...` (T-SQL)
+    pub(crate) output: Option<QueryOutput>,
+}
+
+impl Query {
+    /// Creates a `Query`, the optional fields are unset.
+    pub fn new(body: QueryBody) -> Self {
+        Self {
+            with: None,
+            body,
+            order_by: None,
+            offset: None,
+            fetch: None,
+            limit: None,
+            output: None,
+        }
+    }
+
+    /// Sets the `with`.
+    pub fn with_cte(mut self, with: With) -> Self {
+        self.with = Some(with);
+        self
+    }
+
+    /// Sets the `order_by`.
+    pub fn with_order_by(mut self, order_by: OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `offset`.
+    pub fn with_offset(mut self, offset: Offset) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the `fetch`.
+    pub fn with_fetch(mut self, fetch: Fetch) -> Self {
+        self.fetch = Some(fetch);
+        self
+    }
+
+    /// Sets the `limit`.
+    pub fn with_limit(mut self, limit: Limit) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `output`.
+    pub fn with_output(mut self, output: QueryOutput) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// WITH (common table expressions, or CTEs)
+    pub fn with(&self) -> Option<&With> {
+        self.with.as_ref()
+    }
+
+    /// SELECT or UNION / EXCEPT / INTERSECT
+    pub fn body(&self) -> &QueryBody {
+        &self.body
+    }
+
+    /// `ORDER BY { <sort_key> [ ASC | DESC ] [ NULLS FIRST | NULLS LAST ] } [, ...]`
+    pub fn order_by(&self) -> Option<&OrderBy> {
+        self.order_by.as_ref()
+    }
+
     /// `OFFSET <N> [ { ROW | ROWS } ]`
-    pub offset: Option<Offset>,
+    pub fn offset(&self) -> Option<&Offset> {
+        self.offset.as_ref()
+    }
+
     /// `FETCH { FIRST | NEXT } <N> [ PERCENT ] { ROW | ROWS } | { ONLY | WITH TIES }`
-    pub fetch: Option<Fetch>,
+    pub fn fetch(&self) -> Option<&Fetch> {
+        self.fetch.as_ref()
+    }
+
     /// `LIMIT { <N> | ALL }`
-    pub limit: Option<Limit>,
+    pub fn limit(&self) -> Option<&Limit> {
+        self.limit.as_ref()
+    }
+
+    /// `INTO OUTFILE ...` (MySQL) or `FOR XML ...` (T-SQL)
+    pub fn output(&self) -> Option<&QueryOutput> {
+        self.output.as_ref()
+    }
 }
 
 impl fmt::Display for Query {
@@ -43,14 +145,19 @@ impl fmt::Display for Query {
         if let Some(order_by) = &self.order_by {
             write!(f, " {}", order_by)?;
         }
+        // `LIMIT` is followed by `OFFSET`, while `FETCH FIRST` is preceded by `OFFSET`,
+        // which are the orders accepted by all dialects.
+        if let Some(limit) = &self.limit {
+            write!(f, " {}", limit)?;
+        }
         if let Some(offset) = &self.offset {
             write!(f, " {}", offset)?;
         }
         if let Some(fetch) = &self.fetch {
             write!(f, " {}", fetch)?;
         }
-        if let Some(limit) = &self.limit {
-            write!(f, " {}", limit)?;
+        if let Some(output) = &self.output {
+            write!(f, " {}", output)?;
         }
         Ok(())
     }
@@ -74,6 +181,8 @@ impl fmt::Display for Query {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum QueryBody {
     /// Query specification, like `SELECT ... FROM ... GROUP BY ... HAVING ... WINDOW ...`
     QuerySpec(Box<QuerySpec>),
@@ -125,32 +234,138 @@ impl fmt::Display for QueryBody {
 ///
 /// <table expression> ::= <from clause>
 ///     [ <where clause> ]
+///     [ <hierarchical query clause> ]
 ///     [ <group by clause> ]
 ///     [ <having clause> ]
 ///     [ <window clause> ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct QuerySpec {
     /// Set quantifier, `ALL` or `DISTINCT`
-    pub quantifier: Option<SetQuantifier>,
+    pub(crate) quantifier: Option<SetQuantifier>,
     /// projection expressions
-    pub projection: Vec<SelectItem>,
+    pub(crate) projection: Vec<SelectItem>,
 
     // <table expression>::= [ <from clause> ] [ <where clause> ] [ <group by clause> ] [ <having clause> ] [ <window clause> ]
     /// `FROM` clause
     ///
     /// NOTE: ANSI SQL table expression must contain <from clause>,
     /// but for most dialects, <from clause> is optional.
-    pub from: Option<From>,
+    pub(crate) from: Option<From>,
     /// `WHERE` clause
-    pub r#where: Option<Where>,
+    pub(crate) r#where: Option<Where>,
+    /// `START WITH ... CONNECT BY ...` clause (Oracle)
+    pub(crate) connect_by: Option<ConnectBy>,
     /// `GROUP BY` clause
-    pub group_by: Option<GroupBy>,
+    pub(crate) group_by: Option<GroupBy>,
     /// `HAVING` clause
-    pub having: Option<Having>,
+    pub(crate) having: Option<Having>,
     /// `WINDOW` clause
-    pub window: Option<Window>,
+    pub(crate) window: Option<Window>,
+}
+
+impl QuerySpec {
+    /// Creates a `QuerySpec`, the optional fields are unset.
+    pub fn new(projection: Vec<SelectItem>) -> Self {
+        Self {
+            quantifier: None,
+            projection,
+            from: None,
+            r#where: None,
+            connect_by: None,
+            group_by: None,
+            having: None,
+            window: None,
+        }
+    }
+
+    /// Sets the `quantifier`.
+    pub fn with_quantifier(mut self, quantifier: SetQuantifier) -> Self {
+        self.quantifier = Some(quantifier);
+        self
+    }
+
+    /// Sets the `from`.
+    pub fn with_from(mut self, from: From) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Sets the `where`.
+    pub fn with_where(mut self, r#where: Where) -> Self {
+        self.r#where = Some(r#where);
+        self
+    }
+
+    /// Sets the `connect_by`.
+    pub fn with_connect_by(mut self, connect_by: ConnectBy) -> Self {
+        self.connect_by = Some(connect_by);
+        self
+    }
+
+    /// Sets the `group_by`.
+    pub fn with_group_by(mut self, group_by: GroupBy) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Sets the `having`.
+    pub fn with_having(mut self, having: Having) -> Self {
+        self.having = Some(having);
+        self
+    }
+
+    /// Sets the `window`.
+    pub fn with_window(mut self, window: Window) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Set quantifier, `ALL` or `DISTINCT`
+    pub fn quantifier(&self) -> Option<SetQuantifier> {
+        self.quantifier
+    }
+
+    /// projection expressions
+    pub fn projection(&self) -> &[SelectItem] {
+        &self.projection
+    }
+
+    /// `FROM` clause
+    ///
+    /// NOTE: ANSI SQL table expression must contain <from clause>,
+    /// but for most dialects, <from clause> is optional.
+    pub fn from(&self) -> Option<&From> {
+        self.from.as_ref()
+    }
+
+    /// `WHERE` clause
+    pub fn r#where(&self) -> Option<&Where> {
+        self.r#where.as_ref()
+    }
+
+    /// `START WITH ... CONNECT BY ...` clause (Oracle)
+    pub fn connect_by(&self) -> Option<&ConnectBy> {
+        self.connect_by.as_ref()
+    }
+
+    /// `GROUP BY` clause
+    pub fn group_by(&self) -> Option<&GroupBy> {
+        self.group_by.as_ref()
+    }
+
+    /// `HAVING` clause
+    pub fn having(&self) -> Option<&Having> {
+        self.having.as_ref()
+    }
+
+    /// `WINDOW` clause
+    pub fn window(&self) -> Option<&Window> {
+        self.window.as_ref()
+    }
 }
 
 impl fmt::Display for QuerySpec {
@@ -168,6 +383,9 @@ impl fmt::Display for QuerySpec {
         if let Some(r#where) = &self.r#where {
             write!(f, " {}", r#where)?;
         }
+        if let Some(connect_by) = &self.connect_by {
+            write!(f, " {}", connect_by)?;
+        }
         if let Some(group_by) = &self.group_by {
             write!(f, " {}", group_by)?;
         }
@@ -192,6 +410,8 @@ impl fmt::Display for QuerySpec {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum SelectItem {
     /// An unqualified `*`
     Wildcard,
@@ -228,9 +448,23 @@ impl fmt::Display for SelectItem {
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Values {
     /// The list of row value expression.
-    pub list: Vec<Vec<Expr>>,
+    pub(crate) list: Vec<Vec<Expr>>,
+}
+
+impl Values {
+    /// Creates a `Values`.
+    pub fn new(list: Vec<Vec<Expr>>) -> Self {
+        Self { list }
+    }
+
+    /// The list of row value expression.
+    pub fn list(&self) -> &[Vec<Expr>] {
+        &self.list
+    }
 }
 
 impl fmt::Display for Values {
@@ -250,6 +484,8 @@ impl fmt::Display for Values {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum QueryBodyOperator {
     Union,
     Except,
@@ -270,6 +506,8 @@ impl fmt::Display for QueryBodyOperator {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum SetQuantifier {
     All,
     Distinct,
@@ -297,11 +535,40 @@ impl fmt::Display for SetQuantifier {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct With {
+    /// Flag indicates that the common table expressions are recursive.
     #[doc(hidden)]
-    pub recursive: bool,
+    pub(crate) recursive: bool,
+    /// Common table expressions.
+    pub(crate) ctes: Vec<Cte>,
+}
+
+impl With {
+    /// Creates a `With`, the flags are unset.
+    pub fn new(ctes: Vec<Cte>) -> Self {
+        Self {
+            recursive: false,
+            ctes,
+        }
+    }
+
+    /// Sets the `recursive` flag.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Flag indicates that the common table expressions are recursive.
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+
     /// Common table expressions.
-    pub ctes: Vec<Cte>,
+    pub fn ctes(&self) -> &[Cte] {
+        &self.ctes
+    }
 }
 
 impl fmt::Display for With {
@@ -321,17 +588,55 @@ impl fmt::Display for With {
 ///
 /// ```txt
 /// <with list element> ::= <query name> [ ( <column name> [, ...] ) ] AS ( <query expression> )
+///
+/// // PostgreSQL
+/// <with list element> ::= <query name> [ ( <column name> [, ...] ) ]
+///     AS ( { <query expression> | <insert statement> | <update statement> | <delete statement> } )
 /// ```
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Cte {
     /// Alias name.
-    pub name: Ident,
+    pub(crate) name: Ident,
     /// Columns.
-    pub columns: Option<Vec<Ident>>,
-    /// Query expression (no-with-clause).
-    pub query: Box<Query>,
+    pub(crate) columns: Option<Vec<Ident>>,
+    /// Query expression (no-with-clause) or data-modifying statement.
+    pub(crate) body: CteBody,
+}
+
+impl Cte {
+    /// Creates a `Cte`, the optional fields are unset.
+    pub fn new(name: Ident, body: CteBody) -> Self {
+        Self {
+            name,
+            columns: None,
+            body,
+        }
+    }
+
+    /// Sets the `columns`.
+    pub fn with_columns(mut self, columns: Vec<Ident>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Alias name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Columns.
+    pub fn columns(&self) -> Option<&[Ident]> {
+        self.columns.as_deref()
+    }
+
+    /// Query expression (no-with-clause) or data-modifying statement.
+    pub fn body(&self) -> &CteBody {
+        &self.body
+    }
 }
 
 impl fmt::Display for Cte {
@@ -342,10 +647,38 @@ impl fmt::Display for Cte {
                 "{} ({}) AS ({})",
                 self.name,
                 display_comma_separated(columns),
-                self.query
+                self.body
             )
         } else {
-            write!(f, "{} AS ({})", self.name, self.query)
+            write!(f, "{} AS ({})", self.name, self.body)
+        }
+    }
+}
+
+/// The body of a CTE.
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum CteBody {
+    /// Query expression (no-with-clause).
+    Query(Box<Query>),
+    /// `INSERT` statement. (PostgreSQL)
+    Insert(Box<InsertStmt>),
+    /// `UPDATE` statement. (PostgreSQL)
+    Update(Box<UpdateStmt>),
+    /// `DELETE` statement. (PostgreSQL)
+    Delete(Box<DeleteStmt>),
+}
+
+impl fmt::Display for CteBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Query(query) => write!(f, "{}", query),
+            Self::Insert(stmt) => write!(f, "{}", stmt),
+            Self::Update(stmt) => write!(f, "{}", stmt),
+            Self::Delete(stmt) => write!(f, "{}", stmt),
         }
     }
 }
@@ -358,17 +691,103 @@ impl fmt::Display for Cte {
 ///
 /// ```txt
 /// <order by clause> ::= ORDER BY <sort specification>  [, ...]
+///
+/// // DuckDB-style, see `DialectParserConf::supports_group_by_all`
+/// <order by clause> ::= ORDER BY ALL [ ASC | DESC ] [ NULLS FIRST | NULLS LAST ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct OrderBy {
-    /// The sort specification list.
-    pub list: Vec<SortSpec>,
+    /// The sort specification list, empty for `ORDER BY ALL`.
+    pub(crate) list: Vec<SortSpec>,
+    /// `ORDER BY ALL`, which sorts by all the select list items from left to right.
+    pub(crate) all: Option<OrderByAll>,
+}
+
+impl OrderBy {
+    /// Creates a `OrderBy`, the optional fields are unset.
+    pub fn new(list: Vec<SortSpec>) -> Self {
+        Self { list, all: None }
+    }
+
+    /// Sets the `all`.
+    pub fn with_all(mut self, all: OrderByAll) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    /// The sort specification list, empty for `ORDER BY ALL`.
+    pub fn list(&self) -> &[SortSpec] {
+        &self.list
+    }
+
+    /// `ORDER BY ALL`, which sorts by all the select list items from left to right.
+    pub fn all(&self) -> Option<&OrderByAll> {
+        self.all.as_ref()
+    }
 }
 
 impl fmt::Display for OrderBy {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ORDER BY {}", display_comma_separated(&self.list))
+        match &self.all {
+            Some(all) => write!(f, "ORDER BY {}", all),
+            None => write!(f, "ORDER BY {}", display_comma_separated(&self.list)),
+        }
+    }
+}
+
+/// The sort order of `ORDER BY ALL`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct OrderByAll {
+    /// Optional `ASC` or `DESC`
+    pub(crate) asc: Option<bool>,
+    /// Optional `NULLS FIRST` or `NULLS LAST`
+    pub(crate) nulls_first: Option<bool>,
+}
+
+impl OrderByAll {
+    /// Sets the `asc`.
+    pub fn with_asc(mut self, asc: bool) -> Self {
+        self.asc = Some(asc);
+        self
+    }
+
+    /// Sets the `nulls_first`.
+    pub fn with_nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = Some(nulls_first);
+        self
+    }
+
+    /// Optional `ASC` or `DESC`
+    pub fn asc(&self) -> Option<bool> {
+        self.asc
+    }
+
+    /// Optional `NULLS FIRST` or `NULLS LAST`
+    pub fn nulls_first(&self) -> Option<bool> {
+        self.nulls_first
+    }
+}
+
+impl fmt::Display for OrderByAll {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ALL")?;
+        match self.asc {
+            Some(true) => write!(f, " ASC")?,
+            Some(false) => write!(f, " DESC")?,
+            None => (),
+        }
+        match self.nulls_first {
+            Some(true) => write!(f, " NULLS FIRST")?,
+            Some(false) => write!(f, " NULLS LAST")?,
+            None => (),
+        }
+        Ok(())
     }
 }
 
@@ -376,16 +795,89 @@ impl fmt::Display for OrderBy {
 ///
 /// ```txt
 /// <sort specification> ::= <sort key>  [ ASC | DESC  ] [ NULLS FIRST | NULLS LAST  ]
+///
+/// // PostgreSQL
+/// <sort specification> ::= <sort key> [ ASC | DESC | USING <operator> ] [ NULLS FIRST | NULLS LAST ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct SortSpec {
     /// Sort key
-    pub expr: Box<Expr>,
+    pub(crate) expr: Box<Expr>,
+    /// The 1-based position in the select list, when the sort key is an ordinal like `ORDER BY 1`.
+    /// (`expr` still holds the number literal, and `ORDER BY 0` has no ordinal)
+    pub(crate) ordinal: Option<u64>,
     /// Optional `ASC` or `DESC`
-    pub asc: Option<bool>,
+    pub(crate) asc: Option<bool>,
+    /// Optional `USING <operator>` (PostgreSQL)
+    pub(crate) using: Option<BinaryOperator>,
     /// Optional `NULLS FIRST` or `NULLS LAST`
-    pub nulls_first: Option<bool>,
+    pub(crate) nulls_first: Option<bool>,
+}
+
+impl SortSpec {
+    /// Creates a `SortSpec`, the optional fields are unset.
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+            ordinal: None,
+            asc: None,
+            using: None,
+            nulls_first: None,
+        }
+    }
+
+    /// Sets the `ordinal`.
+    pub fn with_ordinal(mut self, ordinal: u64) -> Self {
+        self.ordinal = Some(ordinal);
+        self
+    }
+
+    /// Sets the `asc`.
+    pub fn with_asc(mut self, asc: bool) -> Self {
+        self.asc = Some(asc);
+        self
+    }
+
+    /// Sets the `using`.
+    pub fn with_using(mut self, using: BinaryOperator) -> Self {
+        self.using = Some(using);
+        self
+    }
+
+    /// Sets the `nulls_first`.
+    pub fn with_nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = Some(nulls_first);
+        self
+    }
+
+    /// Sort key
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    /// The 1-based position in the select list, when the sort key is an ordinal like `ORDER BY 1`.
+    /// (`expr` still holds the number literal, and `ORDER BY 0` has no ordinal)
+    pub fn ordinal(&self) -> Option<u64> {
+        self.ordinal
+    }
+
+    /// Optional `ASC` or `DESC`
+    pub fn asc(&self) -> Option<bool> {
+        self.asc
+    }
+
+    /// Optional `USING <operator>` (PostgreSQL)
+    pub fn using(&self) -> Option<BinaryOperator> {
+        self.using
+    }
+
+    /// Optional `NULLS FIRST` or `NULLS LAST`
+    pub fn nulls_first(&self) -> Option<bool> {
+        self.nulls_first
+    }
 }
 
 impl fmt::Display for SortSpec {
@@ -396,6 +888,9 @@ impl fmt::Display for SortSpec {
             Some(false) => write!(f, " DESC")?,
             None => (),
         }
+        if let Some(op) = &self.using {
+            write!(f, " USING {}", op)?;
+        }
         match self.nulls_first {
             Some(true) => write!(f, " NULLS FIRST")?,
             Some(false) => write!(f, " NULLS LAST")?,
@@ -417,9 +912,30 @@ impl fmt::Display for SortSpec {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Offset {
-    pub count: Literal,
-    pub rows: OffsetRows,
+    /// Number of the skipped rows.
+    pub(crate) count: Literal,
+    /// `ROW` or `ROWS` keyword.
+    pub(crate) rows: OffsetRows,
+}
+
+impl Offset {
+    /// Creates a `Offset`.
+    pub fn new(count: Literal, rows: OffsetRows) -> Self {
+        Self { count, rows }
+    }
+
+    /// Number of the skipped rows.
+    pub fn count(&self) -> &Literal {
+        &self.count
+    }
+
+    /// `ROW` or `ROWS` keyword.
+    pub fn rows(&self) -> &OffsetRows {
+        &self.rows
+    }
 }
 
 impl fmt::Display for Offset {
@@ -432,6 +948,8 @@ impl fmt::Display for Offset {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum OffsetRows {
     Row,
     Rows,
@@ -460,13 +978,52 @@ impl fmt::Display for OffsetRows {
 /// <fetched first quantity> ::= <quantity> [ PERCENT ]
 /// ```
 #[doc(hidden)]
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Fetch {
-    pub quantity: Option<Literal>,
+    /// Number of the fetched rows.
+    pub(crate) quantity: Option<Literal>,
+    /// Flag indicates that if the quantity is percentage.
+    pub(crate) percent: bool,
+    /// Flag indicates that the peers of the last fetched row are included.
+    pub(crate) with_ties: bool,
+}
+
+impl Fetch {
+    /// Sets the `quantity`.
+    pub fn with_quantity(mut self, quantity: Literal) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    /// Sets the `percent` flag.
+    pub fn with_percent(mut self, percent: bool) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// Sets the `with_ties` flag.
+    pub fn with_with_ties(mut self, with_ties: bool) -> Self {
+        self.with_ties = with_ties;
+        self
+    }
+
+    /// Number of the fetched rows.
+    pub fn quantity(&self) -> Option<&Literal> {
+        self.quantity.as_ref()
+    }
+
     /// Flag indicates that if the quantity is percentage.
-    pub percent: bool,
-    pub with_ties: bool,
+    pub fn percent(&self) -> bool {
+        self.percent
+    }
+
+    /// Flag indicates that the peers of the last fetched row are included.
+    pub fn with_ties(&self) -> bool {
+        self.with_ties
+    }
 }
 
 impl fmt::Display for Fetch {
@@ -494,9 +1051,23 @@ impl fmt::Display for Fetch {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Limit {
     /// The row count.
-    pub count: Literal,
+    pub(crate) count: Literal,
+}
+
+impl Limit {
+    /// Creates a `Limit`.
+    pub fn new(count: Literal) -> Self {
+        Self { count }
+    }
+
+    /// The row count.
+    pub fn count(&self) -> &Literal {
+        &self.count
+    }
 }
 
 impl fmt::Display for Limit {
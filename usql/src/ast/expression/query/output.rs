@@ -0,0 +1,475 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::ast::{
+    types::Ident,
+    utils::{display_comma_separated, escape_single_quote_string},
+};
+
+/// The destination or the format of the query result, which follows the other clauses of the
+/// query.
+///
+/// ```txt
+/// <query output> ::= <into outfile clause> | <into dumpfile clause> | <for clause>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum QueryOutput {
+    /// `INTO OUTFILE '<file name>' ...` (MySQL)
+    Outfile(IntoOutfile),
+    /// `INTO DUMPFILE '<file name>'` (MySQL)
+    Dumpfile(String),
+    /// `FOR XML ...` (T-SQL)
+    ForXml(ForXml),
+    /// `FOR JSON ...` (T-SQL)
+    ForJson(ForJson),
+}
+
+impl fmt::Display for QueryOutput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Outfile(outfile) => write!(f, "{}", outfile),
+            Self::Dumpfile(file) => {
+                write!(f, "INTO DUMPFILE '{}'", escape_single_quote_string(file))
+            }
+            Self::ForXml(xml) => write!(f, "{}", xml),
+            Self::ForJson(json) => write!(f, "{}", json),
+        }
+    }
+}
+
+// ============================================================================
+// into outfile clause (MySQL)
+// ============================================================================
+
+/// `INTO OUTFILE` clause, which writes the rows to a file.
+///
+/// ```txt
+/// <into outfile clause> ::= INTO OUTFILE '<file name>'
+///     [ CHARACTER SET <charset name> ]
+///     [ { FIELDS | COLUMNS } <fields option> [ ... ] ]
+///     [ LINES <lines option> [ ... ] ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct IntoOutfile {
+    /// The file name.
+    pub(crate) file: String,
+    /// The character set of the file.
+    pub(crate) charset: Option<Ident>,
+    /// The format of the columns.
+    pub(crate) fields: Option<FieldsFormat>,
+    /// The format of the lines.
+    pub(crate) lines: Option<LinesFormat>,
+}
+
+impl IntoOutfile {
+    /// Creates a `IntoOutfile`, the optional fields are unset.
+    pub fn new(file: String) -> Self {
+        Self {
+            file,
+            charset: None,
+            fields: None,
+            lines: None,
+        }
+    }
+
+    /// Sets the `charset`.
+    pub fn with_charset(mut self, charset: Ident) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
+    /// Sets the `fields`.
+    pub fn with_fields(mut self, fields: FieldsFormat) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Sets the `lines`.
+    pub fn with_lines(mut self, lines: LinesFormat) -> Self {
+        self.lines = Some(lines);
+        self
+    }
+
+    /// The file name.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// The character set of the file.
+    pub fn charset(&self) -> Option<&Ident> {
+        self.charset.as_ref()
+    }
+
+    /// The format of the columns.
+    pub fn fields(&self) -> Option<&FieldsFormat> {
+        self.fields.as_ref()
+    }
+
+    /// The format of the lines.
+    pub fn lines(&self) -> Option<&LinesFormat> {
+        self.lines.as_ref()
+    }
+}
+
+impl fmt::Display for IntoOutfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "INTO OUTFILE '{}'",
+            escape_single_quote_string(&self.file)
+        )?;
+        if let Some(charset) = &self.charset {
+            write!(f, " CHARACTER SET {}", charset)?;
+        }
+        if let Some(fields) = &self.fields {
+            write!(f, " {}", fields)?;
+        }
+        if let Some(lines) = &self.lines {
+            write!(f, " {}", lines)?;
+        }
+        Ok(())
+    }
+}
+
+/// The format of the columns of [`IntoOutfile`].
+///
+/// ```txt
+/// <fields option> ::=
+///     TERMINATED BY '<string>'
+///     | [ OPTIONALLY ] ENCLOSED BY '<char>'
+///     | ESCAPED BY '<char>'
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct FieldsFormat {
+    /// `TERMINATED BY '<string>'`
+    pub(crate) terminated_by: Option<String>,
+    /// `[ OPTIONALLY ] ENCLOSED BY '<char>'`
+    pub(crate) enclosed_by: Option<String>,
+    /// `OPTIONALLY`, only the string columns are enclosed.
+    pub(crate) optionally_enclosed: bool,
+    /// `ESCAPED BY '<char>'`
+    pub(crate) escaped_by: Option<String>,
+}
+
+impl FieldsFormat {
+    /// Sets the `terminated_by`.
+    pub fn with_terminated_by(mut self, terminated_by: String) -> Self {
+        self.terminated_by = Some(terminated_by);
+        self
+    }
+
+    /// Sets the `enclosed_by`.
+    pub fn with_enclosed_by(mut self, enclosed_by: String) -> Self {
+        self.enclosed_by = Some(enclosed_by);
+        self
+    }
+
+    /// Sets the `optionally_enclosed` flag.
+    pub fn with_optionally_enclosed(mut self, optionally_enclosed: bool) -> Self {
+        self.optionally_enclosed = optionally_enclosed;
+        self
+    }
+
+    /// Sets the `escaped_by`.
+    pub fn with_escaped_by(mut self, escaped_by: String) -> Self {
+        self.escaped_by = Some(escaped_by);
+        self
+    }
+
+    /// `TERMINATED BY '<string>'`
+    pub fn terminated_by(&self) -> Option<&str> {
+        self.terminated_by.as_deref()
+    }
+
+    /// `[ OPTIONALLY ] ENCLOSED BY '<char>'`
+    pub fn enclosed_by(&self) -> Option<&str> {
+        self.enclosed_by.as_deref()
+    }
+
+    /// `OPTIONALLY`, only the string columns are enclosed.
+    pub fn optionally_enclosed(&self) -> bool {
+        self.optionally_enclosed
+    }
+
+    /// `ESCAPED BY '<char>'`
+    pub fn escaped_by(&self) -> Option<&str> {
+        self.escaped_by.as_deref()
+    }
+}
+
+impl fmt::Display for FieldsFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("FIELDS")?;
+        if let Some(terminated_by) = &self.terminated_by {
+            write!(
+                f,
+                " TERMINATED BY '{}'",
+                escape_single_quote_string(terminated_by)
+            )?;
+        }
+        if let Some(enclosed_by) = &self.enclosed_by {
+            if self.optionally_enclosed {
+                f.write_str(" OPTIONALLY")?;
+            }
+            write!(
+                f,
+                " ENCLOSED BY '{}'",
+                escape_single_quote_string(enclosed_by)
+            )?;
+        }
+        if let Some(escaped_by) = &self.escaped_by {
+            write!(
+                f,
+                " ESCAPED BY '{}'",
+                escape_single_quote_string(escaped_by)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The format of the lines of [`IntoOutfile`].
+///
+/// ```txt
+/// <lines option> ::= STARTING BY '<string>' | TERMINATED BY '<string>'
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct LinesFormat {
+    /// `STARTING BY '<string>'`
+    pub(crate) starting_by: Option<String>,
+    /// `TERMINATED BY '<string>'`
+    pub(crate) terminated_by: Option<String>,
+}
+
+impl LinesFormat {
+    /// Sets the `starting_by`.
+    pub fn with_starting_by(mut self, starting_by: String) -> Self {
+        self.starting_by = Some(starting_by);
+        self
+    }
+
+    /// Sets the `terminated_by`.
+    pub fn with_terminated_by(mut self, terminated_by: String) -> Self {
+        self.terminated_by = Some(terminated_by);
+        self
+    }
+
+    /// `STARTING BY '<string>'`
+    pub fn starting_by(&self) -> Option<&str> {
+        self.starting_by.as_deref()
+    }
+
+    /// `TERMINATED BY '<string>'`
+    pub fn terminated_by(&self) -> Option<&str> {
+        self.terminated_by.as_deref()
+    }
+}
+
+impl fmt::Display for LinesFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("LINES")?;
+        if let Some(starting_by) = &self.starting_by {
+            write!(
+                f,
+                " STARTING BY '{}'",
+                escape_single_quote_string(starting_by)
+            )?;
+        }
+        if let Some(terminated_by) = &self.terminated_by {
+            write!(
+                f,
+                " TERMINATED BY '{}'",
+                escape_single_quote_string(terminated_by)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// for clause (T-SQL)
+// ============================================================================
+
+/// `FOR XML` clause, which returns the result as XML.
+///
+/// ```txt
+/// <for xml clause> ::= FOR XML <for xml mode> [ , <for option> [ ... ] ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ForXml {
+    /// The mode of the XML shaping.
+    pub(crate) mode: ForXmlMode,
+    /// The options, one of `BINARY BASE64`, `TYPE`, `ROOT` and `ELEMENTS`.
+    pub(crate) options: Vec<ForOption>,
+}
+
+impl ForXml {
+    /// Creates a `ForXml`.
+    pub fn new(mode: ForXmlMode, options: Vec<ForOption>) -> Self {
+        Self { mode, options }
+    }
+
+    /// The mode of the XML shaping.
+    pub fn mode(&self) -> &ForXmlMode {
+        &self.mode
+    }
+
+    /// The options, one of `BINARY BASE64`, `TYPE`, `ROOT` and `ELEMENTS`.
+    pub fn options(&self) -> &[ForOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for ForXml {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FOR XML {}", self.mode)?;
+        if !self.options.is_empty() {
+            write!(f, ", {}", display_comma_separated(&self.options))?;
+        }
+        Ok(())
+    }
+}
+
+/// The mode of [`ForXml`].
+///
+/// ```txt
+/// <for xml mode> ::= RAW [ ( '<element name>' ) ] | AUTO | EXPLICIT | PATH [ ( '<element name>' ) ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ForXmlMode {
+    /// `RAW [ ( '<element name>' ) ]`
+    Raw(Option<String>),
+    /// `AUTO`
+    Auto,
+    /// `EXPLICIT`
+    Explicit,
+    /// `PATH [ ( '<element name>' ) ]`
+    Path(Option<String>),
+}
+
+impl fmt::Display for ForXmlMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let element = match self {
+            Self::Raw(element) => {
+                f.write_str("RAW")?;
+                element
+            }
+            Self::Auto => return f.write_str("AUTO"),
+            Self::Explicit => return f.write_str("EXPLICIT"),
+            Self::Path(element) => {
+                f.write_str("PATH")?;
+                element
+            }
+        };
+        if let Some(element) = element {
+            write!(f, "('{}')", escape_single_quote_string(element))?;
+        }
+        Ok(())
+    }
+}
+
+/// `FOR JSON` clause, which returns the result as JSON.
+///
+/// ```txt
+/// <for json clause> ::= FOR JSON { AUTO | PATH } [ , <for option> [ ... ] ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ForJson {
+    /// `PATH` if true, otherwise `AUTO`.
+    pub(crate) path: bool,
+    /// The options, one of `ROOT`, `INCLUDE_NULL_VALUES` and `WITHOUT_ARRAY_WRAPPER`.
+    pub(crate) options: Vec<ForOption>,
+}
+
+impl ForJson {
+    /// Creates a `ForJson`, the flags are unset.
+    pub fn new(options: Vec<ForOption>) -> Self {
+        Self {
+            path: false,
+            options,
+        }
+    }
+
+    /// Sets the `path` flag.
+    pub fn with_path(mut self, path: bool) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// `PATH` if true, otherwise `AUTO`.
+    pub fn path(&self) -> bool {
+        self.path
+    }
+
+    /// The options, one of `ROOT`, `INCLUDE_NULL_VALUES` and `WITHOUT_ARRAY_WRAPPER`.
+    pub fn options(&self) -> &[ForOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for ForJson {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(if self.path { "FOR JSON PATH" } else { "FOR JSON AUTO" })?;
+        if !self.options.is_empty() {
+            write!(f, ", {}", display_comma_separated(&self.options))?;
+        }
+        Ok(())
+    }
+}
+
+/// An option of [`ForXml`] or [`ForJson`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ForOption {
+    /// `BINARY BASE64` (XML)
+    BinaryBase64,
+    /// `TYPE` (XML)
+    Type,
+    /// `ROOT [ ( '<root name>' ) ]`
+    Root(Option<String>),
+    /// `ELEMENTS [ XSINIL | ABSENT ]` (XML), `XSINIL` if true.
+    Elements(bool),
+    /// `INCLUDE_NULL_VALUES` (JSON)
+    IncludeNullValues,
+    /// `WITHOUT_ARRAY_WRAPPER` (JSON)
+    WithoutArrayWrapper,
+}
+
+impl fmt::Display for ForOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BinaryBase64 => f.write_str("BINARY BASE64"),
+            Self::Type => f.write_str("TYPE"),
+            Self::Root(None) => f.write_str("ROOT"),
+            Self::Root(Some(root)) => write!(f, "ROOT('{}')", escape_single_quote_string(root)),
+            Self::Elements(false) => f.write_str("ELEMENTS"),
+            Self::Elements(true) => f.write_str("ELEMENTS XSINIL"),
+            Self::IncludeNullValues => f.write_str("INCLUDE_NULL_VALUES"),
+            Self::WithoutArrayWrapper => f.write_str("WITHOUT_ARRAY_WRAPPER"),
+        }
+    }
+}
@@ -16,9 +16,23 @@ use crate::ast::{expression::*, types::*, utils::display_comma_separated};
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct From {
     /// The table reference list.
-    pub list: Vec<TableReference>,
+    pub(crate) list: Vec<TableReference>,
+}
+
+impl From {
+    /// Creates a `From`.
+    pub fn new(list: Vec<TableReference>) -> Self {
+        Self { list }
+    }
+
+    /// The table reference list.
+    pub fn list(&self) -> &[TableReference] {
+        &self.list
+    }
 }
 
 impl fmt::Display for From {
@@ -49,9 +63,30 @@ impl fmt::Display for From {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct TableReference {
-    pub relation: TableFactor,
-    pub joins: Vec<Join>,
+    /// Table factor.
+    pub(crate) relation: TableFactor,
+    /// Joined tables.
+    pub(crate) joins: Vec<Join>,
+}
+
+impl TableReference {
+    /// Creates a `TableReference`.
+    pub fn new(relation: TableFactor, joins: Vec<Join>) -> Self {
+        Self { relation, joins }
+    }
+
+    /// Table factor.
+    pub fn relation(&self) -> &TableFactor {
+        &self.relation
+    }
+
+    /// Joined tables.
+    pub fn joins(&self) -> &[Join] {
+        &self.joins
+    }
 }
 
 impl fmt::Display for TableReference {
@@ -67,25 +102,52 @@ impl fmt::Display for TableReference {
 /// A table name or a parenthesized subquery with an optional alias
 ///
 /// ```txt
-/// <table factor> ::= <table or query name> | [ LATERAL ] <derived table> | <parenthesized joined table>
+/// <table factor> ::=
+///     <table or query name>
+///     | [ LATERAL ] <derived table>
+///     | [ LATERAL ] <table function>
+///     | <parenthesized joined table>
 ///
 /// <table or query name> ::= <name> [ [ AS ] <alias name> [ ( column [, ...] ) ] ]
 /// <derived table> ::= ( <query expression> ) [ AS ] <alias name> [ ( column [, ...] ) ]
+/// <table function> ::= <function> [ [ AS ] <alias name> [ ( column [, ...] ) ] ]
+///
+/// // MySQL
+/// <table or query name> ::= <name> [ PARTITION ( <partition name> [, ...] ) ]
+///     [ [ AS ] <alias name> ] [ <index hint> [ ... ] ]
+///
+/// // MariaDB
+/// <table or query name> ::= <name> [ PARTITION ( <partition name> [, ...] ) ]
+///     [ <system time period> ] [ [ AS ] <alias name> ] [ <index hint> [ ... ] ]
 /// ```
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum TableFactor {
     Table {
         /// Table or query name.
         name: ObjectName,
+        /// Partition selection. (MySQL specific)
+        partitions: Option<Vec<Ident>>,
+        /// The period of a system-versioned table. (MariaDB specific)
+        system_time: Option<SystemTimePeriod>,
         alias: Option<TableAlias>,
+        /// Index hints. (MySQL specific)
+        index_hints: Vec<IndexHint>,
     },
     Derived {
         lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>, // must be some
     },
+    /// Represents a function call that returns a table, like `generate_series(1, 10) AS g (i)`.
+    Function {
+        lateral: bool,
+        function: Function,
+        alias: Option<TableAlias>,
+    },
     /// Represents a parenthesized joined table.
     /// The SQL spec only allows a join expression
     /// (`(foo <JOIN> bar [ <JOIN> baz ... ])`) to be nested, possibly several times.
@@ -95,11 +157,26 @@ pub enum TableFactor {
 impl fmt::Display for TableFactor {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::Table { name, alias } => {
+            Self::Table {
+                name,
+                partitions,
+                system_time,
+                alias,
+                index_hints,
+            } => {
                 write!(f, "{}", name)?;
+                if let Some(partitions) = partitions {
+                    write!(f, " PARTITION ({})", display_comma_separated(partitions))?;
+                }
+                if let Some(system_time) = system_time {
+                    write!(f, " {}", system_time)?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " {}", alias)?;
                 }
+                for hint in index_hints {
+                    write!(f, " {}", hint)?;
+                }
                 Ok(())
             }
             Self::Derived {
@@ -116,11 +193,169 @@ impl fmt::Display for TableFactor {
                 }
                 Ok(())
             }
+            Self::Function {
+                lateral,
+                function,
+                alias,
+            } => {
+                if *lateral {
+                    f.write_str("LATERAL ")?;
+                }
+                write!(f, "{}", function)?;
+                if let Some(alias) = alias {
+                    write!(f, " {}", alias)?;
+                }
+                Ok(())
+            }
             Self::NestedJoin(table) => write!(f, "({})", table),
         }
     }
 }
 
+/// The period of a system-versioned table to query. (MariaDB specific)
+///
+/// ```txt
+/// <system time period> ::=
+///     FOR SYSTEM_TIME AS OF <point in time>
+///     | FOR SYSTEM_TIME BETWEEN <point in time> AND <point in time>
+///     | FOR SYSTEM_TIME FROM <point in time> TO <point in time>
+///     | FOR SYSTEM_TIME ALL
+/// ```
+///
+/// **NOTE**: a point in time is an expression, like `TIMESTAMP '2016-10-09 08:07:06'`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum SystemTimePeriod {
+    /// The rows that were current at the point in time.
+    AsOf(Box<Expr>),
+    /// The rows that were current between the points in time, both inclusive.
+    Between(Box<Expr>, Box<Expr>),
+    /// The rows that were current from the first point in time to the second one (exclusive).
+    FromTo(Box<Expr>, Box<Expr>),
+    /// All the current and historical rows.
+    All,
+}
+
+impl fmt::Display for SystemTimePeriod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("FOR SYSTEM_TIME ")?;
+        match self {
+            Self::AsOf(time) => write!(f, "AS OF {}", time),
+            Self::Between(start, end) => write!(f, "BETWEEN {} AND {}", start, end),
+            Self::FromTo(start, end) => write!(f, "FROM {} TO {}", start, end),
+            Self::All => f.write_str("ALL"),
+        }
+    }
+}
+
+/// Index hint of the table. (MySQL specific)
+///
+/// ```txt
+/// <index hint> ::=
+///     USE { INDEX | KEY } [ FOR { JOIN | ORDER BY | GROUP BY } ] ( [ <index name> [, ...] ] )
+///     | { IGNORE | FORCE } { INDEX | KEY } [ FOR { JOIN | ORDER BY | GROUP BY } ] ( <index name> [, ...] )
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct IndexHint {
+    /// Hint type.
+    pub(crate) ty: IndexHintType,
+    /// Hint scope, the hint applies to all operations if not specified.
+    pub(crate) scope: Option<IndexHintScope>,
+    /// Index names, may be empty for `USE INDEX ()`.
+    pub(crate) indexes: Vec<Ident>,
+}
+
+impl IndexHint {
+    /// Creates a `IndexHint`, the optional fields are unset.
+    pub fn new(ty: IndexHintType, indexes: Vec<Ident>) -> Self {
+        Self {
+            ty,
+            scope: None,
+            indexes,
+        }
+    }
+
+    /// Sets the `scope`.
+    pub fn with_scope(mut self, scope: IndexHintScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Hint type.
+    pub fn ty(&self) -> IndexHintType {
+        self.ty
+    }
+
+    /// Hint scope, the hint applies to all operations if not specified.
+    pub fn scope(&self) -> Option<IndexHintScope> {
+        self.scope
+    }
+
+    /// Index names, may be empty for `USE INDEX ()`.
+    pub fn indexes(&self) -> &[Ident] {
+        &self.indexes
+    }
+}
+
+impl fmt::Display for IndexHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} INDEX", self.ty)?;
+        if let Some(scope) = &self.scope {
+            write!(f, " FOR {}", scope)?;
+        }
+        write!(f, " ({})", display_comma_separated(&self.indexes))
+    }
+}
+
+/// The type of index hint.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum IndexHintType {
+    Use,
+    Ignore,
+    Force,
+}
+
+impl fmt::Display for IndexHintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Use => "USE",
+            Self::Ignore => "IGNORE",
+            Self::Force => "FORCE",
+        })
+    }
+}
+
+/// The scope of index hint.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum IndexHintScope {
+    Join,
+    OrderBy,
+    GroupBy,
+}
+
+impl fmt::Display for IndexHintScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Join => "JOIN",
+            Self::OrderBy => "ORDER BY",
+            Self::GroupBy => "GROUP BY",
+        })
+    }
+}
+
 /// Table alias.
 ///
 /// ```txt
@@ -128,11 +363,39 @@ impl fmt::Display for TableFactor {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct TableAlias {
     /// Alias name.
-    pub name: Ident,
+    pub(crate) name: Ident,
     /// Columns.
-    pub columns: Option<Vec<Ident>>,
+    pub(crate) columns: Option<Vec<Ident>>,
+}
+
+impl TableAlias {
+    /// Creates a `TableAlias`, the optional fields are unset.
+    pub fn new(name: Ident) -> Self {
+        Self {
+            name,
+            columns: None,
+        }
+    }
+
+    /// Sets the `columns`.
+    pub fn with_columns(mut self, columns: Vec<Ident>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Alias name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Columns.
+    pub fn columns(&self) -> Option<&[Ident]> {
+        self.columns.as_deref()
+    }
 }
 
 impl fmt::Display for TableAlias {
@@ -158,9 +421,30 @@ impl fmt::Display for TableAlias {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Join {
-    pub join: JoinOperator,
-    pub relation: TableFactor,
+    /// Join operator.
+    pub(crate) join: JoinOperator,
+    /// Joined table factor.
+    pub(crate) relation: TableFactor,
+}
+
+impl Join {
+    /// Creates a `Join`.
+    pub fn new(join: JoinOperator, relation: TableFactor) -> Self {
+        Self { join, relation }
+    }
+
+    /// Join operator.
+    pub fn join(&self) -> &JoinOperator {
+        &self.join
+    }
+
+    /// Joined table factor.
+    pub fn relation(&self) -> &TableFactor {
+        &self.relation
+    }
 }
 
 impl fmt::Display for Join {
@@ -171,13 +455,13 @@ impl fmt::Display for Join {
                 write!(f, "INNER JOIN {}{}", self.relation, constraint)
             }
             JoinOperator::LeftOuterJoin(constraint) => {
-                write!(f, "LEFT JOIN {} {}", self.relation, constraint)
+                write!(f, "LEFT JOIN {}{}", self.relation, constraint)
             }
             JoinOperator::RightOuterJoin(constraint) => {
-                write!(f, "RIGHT JOIN {} {}", self.relation, constraint)
+                write!(f, "RIGHT JOIN {}{}", self.relation, constraint)
             }
             JoinOperator::FullOuterJoin(constraint) => {
-                write!(f, "FULL JOIN {} {}", self.relation, constraint)
+                write!(f, "FULL JOIN {}{}", self.relation, constraint)
             }
             JoinOperator::NaturalInnerJoin => write!(f, "NATURAL INNER JOIN {}", self.relation),
             JoinOperator::NaturalLeftOuterJoin => write!(f, "NATURAL LEFT JOIN {}", self.relation,),
@@ -193,6 +477,8 @@ impl fmt::Display for Join {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum JoinOperator {
     CrossJoin,
     // default join if no join type is specified
@@ -217,6 +503,8 @@ pub enum JoinOperator {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum JoinSpec {
     /// Join condition
     On(Box<Expr>),
@@ -258,9 +546,25 @@ impl fmt::Display for JoinSpec {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Where {
     /// The search condition.
-    pub expr: Box<Expr>,
+    pub(crate) expr: Box<Expr>,
+}
+
+impl Where {
+    /// Creates a `Where`.
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+        }
+    }
+
+    /// The search condition.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
 }
 
 impl fmt::Display for Where {
@@ -269,6 +573,83 @@ impl fmt::Display for Where {
     }
 }
 
+// ============================================================================
+// hierarchical query clause
+// ============================================================================
+
+/// Hierarchical query clause (Oracle).
+///
+/// ```txt
+/// <hierarchical query clause> ::=
+///     [ START WITH <search condition> ] CONNECT BY [ NOCYCLE ] <search condition>
+///     | CONNECT BY [ NOCYCLE ] <search condition> START WITH <search condition>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ConnectBy {
+    /// The condition of the root rows, all rows are roots if omitted.
+    pub(crate) start_with: Option<Box<Expr>>,
+    /// `NOCYCLE`, returns the rows even if a loop exists.
+    pub(crate) nocycle: bool,
+    /// The condition of the parent-child relationship, where the parent columns are
+    /// referenced with the `PRIOR` operator.
+    pub(crate) condition: Box<Expr>,
+}
+
+impl ConnectBy {
+    /// Creates a `ConnectBy`, the optional fields are unset.
+    pub fn new(condition: Expr) -> Self {
+        Self {
+            start_with: None,
+            nocycle: false,
+            condition: Box::new(condition),
+        }
+    }
+
+    /// Sets the `start_with`.
+    pub fn with_start_with(mut self, start_with: Expr) -> Self {
+        self.start_with = Some(Box::new(start_with));
+        self
+    }
+
+    /// Sets the `nocycle` flag.
+    pub fn with_nocycle(mut self, nocycle: bool) -> Self {
+        self.nocycle = nocycle;
+        self
+    }
+
+    /// The condition of the root rows, all rows are roots if omitted.
+    pub fn start_with(&self) -> Option<&Expr> {
+        self.start_with.as_deref()
+    }
+
+    /// `NOCYCLE`, returns the rows even if a loop exists.
+    pub fn nocycle(&self) -> bool {
+        self.nocycle
+    }
+
+    /// The condition of the parent-child relationship, where the parent columns are
+    /// referenced with the `PRIOR` operator.
+    pub fn condition(&self) -> &Expr {
+        &self.condition
+    }
+}
+
+impl fmt::Display for ConnectBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(start_with) = &self.start_with {
+            write!(f, "START WITH {} ", start_with)?;
+        }
+        f.write_str("CONNECT BY ")?;
+        if self.nocycle {
+            f.write_str("NOCYCLE ")?;
+        }
+        write!(f, "{}", self.condition)
+    }
+}
+
 // ============================================================================
 // group by clause
 // ============================================================================
@@ -280,11 +661,39 @@ impl fmt::Display for Where {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct GroupBy {
     /// Set quantifier.
-    pub quantifier: Option<SetQuantifier>,
+    pub(crate) quantifier: Option<SetQuantifier>,
     /// The list of grouping element.
-    pub list: Vec<GroupingElement>,
+    pub(crate) list: Vec<GroupingElement>,
+}
+
+impl GroupBy {
+    /// Creates a `GroupBy`, the optional fields are unset.
+    pub fn new(list: Vec<GroupingElement>) -> Self {
+        Self {
+            quantifier: None,
+            list,
+        }
+    }
+
+    /// Sets the `quantifier`.
+    pub fn with_quantifier(mut self, quantifier: SetQuantifier) -> Self {
+        self.quantifier = Some(quantifier);
+        self
+    }
+
+    /// Set quantifier.
+    pub fn quantifier(&self) -> Option<SetQuantifier> {
+        self.quantifier
+    }
+
+    /// The list of grouping element.
+    pub fn list(&self) -> &[GroupingElement] {
+        &self.list
+    }
 }
 
 impl fmt::Display for GroupBy {
@@ -312,11 +721,21 @@ impl fmt::Display for GroupBy {
 /// <rollup list> ::= ROLLUP ( { <column name> | ( <column name> [, ...] ) } [, ...] )
 /// <cube list> ::= CUBE  ( { <column name> | ( <column name> [, ...] ) } [, ...] )
 /// <grouping sets specification> ::= GROUPING SETS ( <grouping element> [, ...] )
+///
+/// // PostgreSQL, MySQL and SQLite
+/// <ordinary grouping set> ::= <column name> | <unsigned integer> | ( <column name> [, ...] )
+///
+/// // DuckDB-style, see `DialectParserConf::supports_group_by_all`
+/// <grouping element> ::= ALL
 /// ```
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum GroupingElement {
+    /// `GROUP BY ALL`, which groups by all the select list items without aggregate functions.
+    All,
     Empty,
     OrdinarySet(GroupingSet),
     Rollup(Vec<GroupingSet>),
@@ -327,6 +746,7 @@ pub enum GroupingElement {
 impl fmt::Display for GroupingElement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::All => f.write_str("ALL"),
             Self::Empty => f.write_str("()"),
             Self::OrdinarySet(name) => write!(f, "{}", name),
             Self::Rollup(list) => write!(f, "ROLLUP ({})", display_comma_separated(list)),
@@ -341,11 +761,15 @@ impl fmt::Display for GroupingElement {
 /// Ordinary grouping set, which is a kind of grouping element.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum GroupingSet {
     /// grouping column reference
     Column(ObjectName),
     /// grouping column reference list
     Columns(Vec<ObjectName>),
+    /// The 1-based position in the select list, like `GROUP BY 1`. (Non-standard)
+    Ordinal(u64),
 }
 
 impl fmt::Display for GroupingSet {
@@ -353,6 +777,7 @@ impl fmt::Display for GroupingSet {
         match self {
             Self::Column(name) => write!(f, "{}", name),
             Self::Columns(names) => write!(f, "({})", display_comma_separated(names)),
+            Self::Ordinal(ordinal) => write!(f, "{}", ordinal),
         }
     }
 }
@@ -368,9 +793,25 @@ impl fmt::Display for GroupingSet {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Having {
     /// The search condition.
-    pub expr: Box<Expr>,
+    pub(crate) expr: Box<Expr>,
+}
+
+impl Having {
+    /// Creates a `Having`.
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+        }
+    }
+
+    /// The search condition.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
 }
 
 impl fmt::Display for Having {
@@ -390,9 +831,23 @@ impl fmt::Display for Having {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Window {
     /// The window definition list.
-    pub list: Vec<WindowDef>,
+    pub(crate) list: Vec<WindowDef>,
+}
+
+impl Window {
+    /// Creates a `Window`.
+    pub fn new(list: Vec<WindowDef>) -> Self {
+        Self { list }
+    }
+
+    /// The window definition list.
+    pub fn list(&self) -> &[WindowDef] {
+        &self.list
+    }
 }
 
 impl fmt::Display for Window {
@@ -409,11 +864,30 @@ impl fmt::Display for Window {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct WindowDef {
     /// New window name.
-    pub name: Ident,
+    pub(crate) name: Ident,
+    /// Window specification details.
+    pub(crate) spec: WindowSpec,
+}
+
+impl WindowDef {
+    /// Creates a `WindowDef`.
+    pub fn new(name: Ident, spec: WindowSpec) -> Self {
+        Self { name, spec }
+    }
+
+    /// New window name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
     /// Window specification details.
-    pub spec: WindowSpec,
+    pub fn spec(&self) -> &WindowSpec {
+        &self.spec
+    }
 }
 
 impl fmt::Display for WindowDef {
@@ -422,6 +896,32 @@ impl fmt::Display for WindowDef {
     }
 }
 
+/// The window of a window function call, which is either a reference to a window defined in
+/// the `WINDOW` clause, or an in-line window specification.
+///
+/// ```txt
+/// <window name or specification> ::= <window name> | <in-line window specification>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum WindowNameOrSpec {
+    /// The name of a window defined in the `WINDOW` clause, e.g. `OVER w`.
+    Name(Ident),
+    /// In-line window specification, e.g. `OVER (PARTITION BY a)`.
+    Spec(WindowSpec),
+}
+
+impl fmt::Display for WindowNameOrSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Name(name) => write!(f, "{}", name),
+            Self::Spec(spec) => write!(f, "({})", spec),
+        }
+    }
+}
+
 /// Window specification details.
 ///
 /// ```txt
@@ -435,17 +935,65 @@ impl fmt::Display for WindowDef {
 /// <window partition clause> ::= PARTITION BY <window partition column> [, ...]
 /// <window order clause> ::= ORDER BY { <sort_key> [ ASC | DESC ] [ NULLS FIRST | NULLS LAST ] } [, ...]`
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct WindowSpec {
-    /// The existing window name.
-    pub name: Option<Ident>,
+    /// The existing window name, which refers to a previously defined window.
+    pub(crate) name: Option<Ident>,
     /// Window partition clauses.
-    pub partition_by: Option<Vec<ObjectName>>,
+    pub(crate) partition_by: Option<Vec<ObjectName>>,
     /// Window order clauses.
-    pub order_by: Option<OrderBy>,
+    pub(crate) order_by: Option<OrderBy>,
     /// Window frame clause.
-    pub window_frame: Option<WindowFrame>,
+    pub(crate) window_frame: Option<WindowFrame>,
+}
+
+impl WindowSpec {
+    /// Sets the `name`.
+    pub fn with_name(mut self, name: Ident) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the `partition_by`.
+    pub fn with_partition_by(mut self, partition_by: Vec<ObjectName>) -> Self {
+        self.partition_by = Some(partition_by);
+        self
+    }
+
+    /// Sets the `order_by`.
+    pub fn with_order_by(mut self, order_by: OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Sets the `window_frame`.
+    pub fn with_window_frame(mut self, window_frame: WindowFrame) -> Self {
+        self.window_frame = Some(window_frame);
+        self
+    }
+
+    /// The existing window name, which refers to a previously defined window.
+    pub fn name(&self) -> Option<&Ident> {
+        self.name.as_ref()
+    }
+
+    /// Window partition clauses.
+    pub fn partition_by(&self) -> Option<&[ObjectName]> {
+        self.partition_by.as_deref()
+    }
+
+    /// Window order clauses.
+    pub fn order_by(&self) -> Option<&OrderBy> {
+        self.order_by.as_ref()
+    }
+
+    /// Window frame clause.
+    pub fn window_frame(&self) -> Option<&WindowFrame> {
+        self.window_frame.as_ref()
+    }
 }
 
 impl fmt::Display for WindowSpec {
@@ -489,18 +1037,67 @@ impl fmt::Display for WindowSpec {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct WindowFrame {
     /// The frame type.
-    pub units: WindowFrameUnits,
+    pub(crate) units: WindowFrameUnits,
+    /// The starting frame boundary.
+    pub(crate) start_bound: WindowFrameBound,
+    /// The ending frame boundary.
+    /// The end bound of `Some` indicates the right bound of the `BETWEEN .. AND` clause.
+    /// The end bound of `None` indicates the shorthand form (e.g. `ROWS 1 PRECEDING`),
+    /// which must behave the same as `end_bound = WindowFrameBound::CurrentRow`.
+    pub(crate) end_bound: Option<WindowFrameBound>,
+    /// Exclude clause.
+    pub(crate) exclusion: Option<WindowFrameExclusion>,
+}
+
+impl WindowFrame {
+    /// Creates a `WindowFrame`, the optional fields are unset.
+    pub fn new(units: WindowFrameUnits, start_bound: WindowFrameBound) -> Self {
+        Self {
+            units,
+            start_bound,
+            end_bound: None,
+            exclusion: None,
+        }
+    }
+
+    /// Sets the `end_bound`.
+    pub fn with_end_bound(mut self, end_bound: WindowFrameBound) -> Self {
+        self.end_bound = Some(end_bound);
+        self
+    }
+
+    /// Sets the `exclusion`.
+    pub fn with_exclusion(mut self, exclusion: WindowFrameExclusion) -> Self {
+        self.exclusion = Some(exclusion);
+        self
+    }
+
+    /// The frame type.
+    pub fn units(&self) -> WindowFrameUnits {
+        self.units
+    }
+
     /// The starting frame boundary.
-    pub start_bound: WindowFrameBound,
+    pub fn start_bound(&self) -> WindowFrameBound {
+        self.start_bound
+    }
+
     /// The ending frame boundary.
     /// The end bound of `Some` indicates the right bound of the `BETWEEN .. AND` clause.
     /// The end bound of `None` indicates the shorthand form (e.g. `ROWS 1 PRECEDING`),
     /// which must behave the same as `end_bound = WindowFrameBound::CurrentRow`.
-    pub end_bound: Option<WindowFrameBound>,
+    pub fn end_bound(&self) -> Option<WindowFrameBound> {
+        self.end_bound
+    }
+
     /// Exclude clause.
-    pub exclusion: Option<WindowFrameExclusion>,
+    pub fn exclusion(&self) -> Option<WindowFrameExclusion> {
+        self.exclusion
+    }
 }
 
 impl fmt::Display for WindowFrame {
@@ -529,6 +1126,8 @@ impl fmt::Display for WindowFrame {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum WindowFrameUnits {
     Rows,
     Range,
@@ -553,6 +1152,8 @@ impl fmt::Display for WindowFrameUnits {
 /// ```
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum WindowFrameBound {
     /// `CURRENT ROW`.
     CurrentRow,
@@ -582,6 +1183,8 @@ impl fmt::Display for WindowFrameBound {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum WindowFrameExclusion {
     CurrentRow,
     Group,
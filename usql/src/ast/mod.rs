@@ -1,4 +1,6 @@
+mod drop;
 mod utils;
+pub(crate) mod visit;
 
 /// SQL expressions.
 pub mod expression;
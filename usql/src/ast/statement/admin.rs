@@ -0,0 +1,269 @@
+use core::fmt;
+
+use crate::ast::{expression::Expr, types::*};
+
+/// The `PRAGMA ...` statement. (SQLite specific)
+///
+/// ```txt
+/// PRAGMA [ <schema name> . ] <pragma name> [ = <pragma value> | ( <pragma value> ) ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct PragmaStmt {
+    /// Pragma name, optionally qualified by the schema name.
+    pub(crate) name: ObjectName,
+    /// Pragma value.
+    pub(crate) value: Option<Expr>,
+    /// Flag indicates that the value is specified in the `name(value)` form.
+    pub(crate) parenthesized: bool,
+}
+
+impl PragmaStmt {
+    /// Creates a `PragmaStmt`, the optional fields are unset.
+    pub fn new(name: ObjectName) -> Self {
+        Self {
+            name,
+            value: None,
+            parenthesized: false,
+        }
+    }
+
+    /// Sets the `value`.
+    pub fn with_value(mut self, value: Expr) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets the `parenthesized` flag.
+    pub fn with_parenthesized(mut self, parenthesized: bool) -> Self {
+        self.parenthesized = parenthesized;
+        self
+    }
+
+    /// Pragma name, optionally qualified by the schema name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Pragma value.
+    pub fn value(&self) -> Option<&Expr> {
+        self.value.as_ref()
+    }
+
+    /// Flag indicates that the value is specified in the `name(value)` form.
+    pub fn parenthesized(&self) -> bool {
+        self.parenthesized
+    }
+}
+
+impl fmt::Display for PragmaStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PRAGMA {}", self.name)?;
+        match &self.value {
+            Some(value) if self.parenthesized => write!(f, "({})", value)?,
+            Some(value) => write!(f, " = {}", value)?,
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+/// The `ATTACH DATABASE ...` statement. (SQLite specific)
+///
+/// ```txt
+/// ATTACH [ DATABASE ] <expr> AS <schema name>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AttachStmt {
+    /// Database file name.
+    pub(crate) database: Expr,
+    /// Schema name of the attached database.
+    pub(crate) schema: Ident,
+}
+
+impl AttachStmt {
+    /// Creates a `AttachStmt`.
+    pub fn new(database: Expr, schema: Ident) -> Self {
+        Self { database, schema }
+    }
+
+    /// Database file name.
+    pub fn database(&self) -> &Expr {
+        &self.database
+    }
+
+    /// Schema name of the attached database.
+    pub fn schema(&self) -> &Ident {
+        &self.schema
+    }
+}
+
+impl fmt::Display for AttachStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ATTACH DATABASE {} AS {}", self.database, self.schema)
+    }
+}
+
+/// The `DETACH DATABASE ...` statement. (SQLite specific)
+///
+/// ```txt
+/// DETACH [ DATABASE ] <schema name>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DetachStmt {
+    /// Schema name of the attached database.
+    pub(crate) schema: Ident,
+}
+
+impl DetachStmt {
+    /// Creates a `DetachStmt`.
+    pub fn new(schema: Ident) -> Self {
+        Self { schema }
+    }
+
+    /// Schema name of the attached database.
+    pub fn schema(&self) -> &Ident {
+        &self.schema
+    }
+}
+
+impl fmt::Display for DetachStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DETACH DATABASE {}", self.schema)
+    }
+}
+
+/// The `VACUUM ...` statement. (SQLite specific)
+///
+/// ```txt
+/// VACUUM [ <schema name> ] [ INTO <file name> ]
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct VacuumStmt {
+    /// Schema name.
+    pub(crate) schema: Option<Ident>,
+    /// File name of the vacuumed database.
+    pub(crate) into: Option<Expr>,
+}
+
+impl VacuumStmt {
+    /// Sets the `schema`.
+    pub fn with_schema(mut self, schema: Ident) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Sets the `into`.
+    pub fn with_into(mut self, into: Expr) -> Self {
+        self.into = Some(into);
+        self
+    }
+
+    /// Schema name.
+    pub fn schema(&self) -> Option<&Ident> {
+        self.schema.as_ref()
+    }
+
+    /// File name of the vacuumed database.
+    pub fn into(&self) -> Option<&Expr> {
+        self.into.as_ref()
+    }
+}
+
+impl fmt::Display for VacuumStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("VACUUM")?;
+        if let Some(schema) = &self.schema {
+            write!(f, " {}", schema)?;
+        }
+        if let Some(into) = &self.into {
+            write!(f, " INTO {}", into)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `ANALYZE ...` statement. (SQLite specific)
+///
+/// ```txt
+/// ANALYZE [ <schema name> | <table or index name> | <schema name> . <table or index name> ]
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AnalyzeStmt {
+    /// Name of the schema, table or index to analyze, analyze all databases if not specified.
+    pub(crate) name: Option<ObjectName>,
+}
+
+impl AnalyzeStmt {
+    /// Sets the `name`.
+    pub fn with_name(mut self, name: ObjectName) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Name of the schema, table or index to analyze, analyze all databases if not specified.
+    pub fn name(&self) -> Option<&ObjectName> {
+        self.name.as_ref()
+    }
+}
+
+impl fmt::Display for AnalyzeStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ANALYZE")?;
+        if let Some(name) = &self.name {
+            write!(f, " {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `REINDEX ...` statement. (SQLite specific)
+///
+/// ```txt
+/// REINDEX [ <collation name> | <table or index name> | <schema name> . <table or index name> ]
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ReindexStmt {
+    /// Name of the collation, table or index to reindex, reindex all indices if not specified.
+    pub(crate) name: Option<ObjectName>,
+}
+
+impl ReindexStmt {
+    /// Sets the `name`.
+    pub fn with_name(mut self, name: ObjectName) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Name of the collation, table or index to reindex, reindex all indices if not specified.
+    pub fn name(&self) -> Option<&ObjectName> {
+        self.name.as_ref()
+    }
+}
+
+impl fmt::Display for ReindexStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("REINDEX")?;
+        if let Some(name) = &self.name {
+            write!(f, " {}", name)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,447 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::ast::{
+    expression::Expr,
+    statement::UserName,
+    types::{Ident, ObjectName},
+    utils::{display_comma_separated, display_separated, escape_single_quote_string},
+};
+
+/// The `CREATE ROLE` or `CREATE USER` statement.
+///
+/// ```txt
+/// // PostgreSQL
+/// CREATE { ROLE | USER } <role name> [ [ WITH ] <role option> [...] ]
+///
+/// // MySQL
+/// CREATE { ROLE | USER } [ IF NOT EXISTS ] <user name> [ <auth option> ] [, ...]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateRoleStmt {
+    /// Role or user.
+    pub(crate) kind: RoleKind,
+    /// Flag indicates that check if the role does not exist. (MySQL specific)
+    pub(crate) if_not_exists: bool,
+    /// The roles to create.
+    pub(crate) roles: Vec<RoleSpec>,
+    /// Role options. (PostgreSQL specific)
+    pub(crate) options: Vec<RoleOption>,
+}
+
+impl CreateRoleStmt {
+    /// Creates a `CreateRoleStmt`, the flags are unset.
+    pub fn new(kind: RoleKind, roles: Vec<RoleSpec>, options: Vec<RoleOption>) -> Self {
+        Self {
+            kind,
+            if_not_exists: false,
+            roles,
+            options,
+        }
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Role or user.
+    pub fn kind(&self) -> RoleKind {
+        self.kind
+    }
+
+    /// Flag indicates that check if the role does not exist. (MySQL specific)
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// The roles to create.
+    pub fn roles(&self) -> &[RoleSpec] {
+        &self.roles
+    }
+
+    /// Role options. (PostgreSQL specific)
+    pub fn options(&self) -> &[RoleOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for CreateRoleStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CREATE {kind} {if_not_exists}{roles}",
+            kind = self.kind,
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            roles = display_comma_separated(&self.roles),
+        )?;
+        if !self.options.is_empty() {
+            write!(f, " WITH {}", display_separated(&self.options, " "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The kind of role statement.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum RoleKind {
+    Role,
+    User,
+}
+
+impl fmt::Display for RoleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Role => "ROLE",
+            Self::User => "USER",
+        })
+    }
+}
+
+/// The role to create, with its authentication option.
+///
+/// ```txt
+/// <user name> [ <auth option> ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct RoleSpec {
+    /// Role name.
+    pub(crate) name: UserName,
+    /// Authentication option. (MySQL specific)
+    pub(crate) auth: Option<AuthOption>,
+}
+
+impl RoleSpec {
+    /// Creates a `RoleSpec`, the optional fields are unset.
+    pub fn new(name: UserName) -> Self {
+        Self { name, auth: None }
+    }
+
+    /// Sets the `auth`.
+    pub fn with_auth(mut self, auth: AuthOption) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Role name.
+    pub fn name(&self) -> &UserName {
+        &self.name
+    }
+
+    /// Authentication option. (MySQL specific)
+    pub fn auth(&self) -> Option<&AuthOption> {
+        self.auth.as_ref()
+    }
+}
+
+impl fmt::Display for RoleSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(auth) = &self.auth {
+            write!(f, " {}", auth)?;
+        }
+        Ok(())
+    }
+}
+
+/// The authentication option of a user. (MySQL specific)
+///
+/// ```txt
+/// <auth option> ::=
+///     IDENTIFIED BY <password>
+///     | IDENTIFIED WITH <auth plugin> [ BY <password> | AS <auth string> ]
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AuthOption {
+    Password(Password),
+    Plugin {
+        /// Authentication plugin name.
+        plugin: Ident,
+        /// The password hashed by the plugin.
+        password: Option<Password>,
+    },
+    /// The authentication string is already hashed.
+    Hash {
+        /// Authentication plugin name.
+        plugin: Ident,
+        /// Hashed authentication string.
+        hash: String,
+    },
+}
+
+impl fmt::Display for AuthOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Password(password) => write!(f, "IDENTIFIED BY {}", password),
+            Self::Plugin { plugin, password } => {
+                write!(f, "IDENTIFIED WITH {}", plugin)?;
+                if let Some(password) = password {
+                    write!(f, " BY {}", password)?;
+                }
+                Ok(())
+            }
+            Self::Hash { plugin, hash } => write!(
+                f,
+                "IDENTIFIED WITH {} AS '{}'",
+                plugin,
+                escape_single_quote_string(hash)
+            ),
+        }
+    }
+}
+
+/// A password in plain text.
+///
+/// **NOTE**: the password is always displayed as a string literal,
+/// so that it's replaced by [`redact`](crate::analysis::redact).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Password(pub String);
+
+impl fmt::Display for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", escape_single_quote_string(&self.0))
+    }
+}
+
+/// The role option. (PostgreSQL specific)
+///
+/// ```txt
+/// <role option> ::=
+///     SUPERUSER | NOSUPERUSER
+///     | CREATEDB | NOCREATEDB
+///     | CREATEROLE | NOCREATEROLE
+///     | INHERIT | NOINHERIT
+///     | LOGIN | NOLOGIN
+///     | REPLICATION | NOREPLICATION
+///     | BYPASSRLS | NOBYPASSRLS
+///     | CONNECTION LIMIT <connlimit>
+///     | [ ENCRYPTED ] PASSWORD { <password> | NULL }
+///     | VALID UNTIL <timestamp>
+///     | IN ROLE <role name> [, ...]
+///     | ROLE <role name> [, ...]
+///     | ADMIN <role name> [, ...]
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum RoleOption {
+    /// The attributes without value, like `LOGIN` or `NOSUPERUSER`.
+    Attribute(Ident),
+    ConnectionLimit(Expr),
+    /// `None` means `PASSWORD NULL`
+    Password(Option<Password>),
+    ValidUntil(String),
+    InRole(Vec<Ident>),
+    Role(Vec<Ident>),
+    Admin(Vec<Ident>),
+}
+
+impl fmt::Display for RoleOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Attribute(attribute) => write!(f, "{}", attribute),
+            Self::ConnectionLimit(limit) => write!(f, "CONNECTION LIMIT {}", limit),
+            Self::Password(Some(password)) => write!(f, "PASSWORD {}", password),
+            Self::Password(None) => f.write_str("PASSWORD NULL"),
+            Self::ValidUntil(timestamp) => {
+                write!(f, "VALID UNTIL '{}'", escape_single_quote_string(timestamp))
+            }
+            Self::InRole(roles) => write!(f, "IN ROLE {}", display_comma_separated(roles)),
+            Self::Role(roles) => write!(f, "ROLE {}", display_comma_separated(roles)),
+            Self::Admin(roles) => write!(f, "ADMIN {}", display_comma_separated(roles)),
+        }
+    }
+}
+
+/// The `ALTER ROLE` or `ALTER USER` statement. (PostgreSQL specific)
+///
+/// ```txt
+/// ALTER { ROLE | USER } <role name> <alter role action>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AlterRoleStmt {
+    /// Role or user.
+    pub(crate) kind: RoleKind,
+    /// Role name.
+    pub(crate) name: UserName,
+    /// Alter action.
+    pub(crate) action: AlterRoleAction,
+}
+
+impl AlterRoleStmt {
+    /// Creates a `AlterRoleStmt`.
+    pub fn new(kind: RoleKind, name: UserName, action: AlterRoleAction) -> Self {
+        Self { kind, name, action }
+    }
+
+    /// Role or user.
+    pub fn kind(&self) -> RoleKind {
+        self.kind
+    }
+
+    /// Role name.
+    pub fn name(&self) -> &UserName {
+        &self.name
+    }
+
+    /// Alter action.
+    pub fn action(&self) -> &AlterRoleAction {
+        &self.action
+    }
+}
+
+impl fmt::Display for AlterRoleStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER {} {} {}", self.kind, self.name, self.action)
+    }
+}
+
+/// The alter action of `ALTER ROLE` statement. (PostgreSQL specific)
+///
+/// ```txt
+/// <alter role action> ::=
+///     [ WITH ] <role option> [...]
+///     | RENAME TO <new role name>
+///     | [ IN DATABASE <database name> ] SET <parameter> { TO | = } { <value> [, ...] | DEFAULT }
+///     | [ IN DATABASE <database name> ] RESET { <parameter> | ALL }
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AlterRoleAction {
+    Options(Vec<RoleOption>),
+    RenameTo(Ident),
+    Set {
+        /// The database in which the setting is applied.
+        database: Option<Ident>,
+        /// Configuration parameter name.
+        name: ObjectName,
+        /// Parameter values, `None` means `DEFAULT`.
+        values: Option<Vec<Expr>>,
+    },
+    Reset {
+        /// The database in which the setting is applied.
+        database: Option<Ident>,
+        /// Configuration parameter name, `None` means `ALL`.
+        name: Option<ObjectName>,
+    },
+}
+
+impl fmt::Display for AlterRoleAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Options(options) => write!(f, "WITH {}", display_separated(options, " ")),
+            Self::RenameTo(name) => write!(f, "RENAME TO {}", name),
+            Self::Set {
+                database,
+                name,
+                values,
+            } => {
+                if let Some(database) = database {
+                    write!(f, "IN DATABASE {} ", database)?;
+                }
+                write!(f, "SET {} TO ", name)?;
+                match values {
+                    Some(values) => write!(f, "{}", display_comma_separated(values)),
+                    None => f.write_str("DEFAULT"),
+                }
+            }
+            Self::Reset { database, name } => {
+                if let Some(database) = database {
+                    write!(f, "IN DATABASE {} ", database)?;
+                }
+                match name {
+                    Some(name) => write!(f, "RESET {}", name),
+                    None => f.write_str("RESET ALL"),
+                }
+            }
+        }
+    }
+}
+
+/// The `DROP ROLE` or `DROP USER` statement.
+///
+/// ```txt
+/// DROP { ROLE | USER } [ IF EXISTS ] <user name> [, ...]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DropRoleStmt {
+    /// Role or user.
+    pub(crate) kind: RoleKind,
+    /// Flag indicates that check if the roles exist.
+    pub(crate) if_exists: bool,
+    /// The roles to drop.
+    pub(crate) names: Vec<UserName>,
+}
+
+impl DropRoleStmt {
+    /// Creates a `DropRoleStmt`, the flags are unset.
+    pub fn new(kind: RoleKind, names: Vec<UserName>) -> Self {
+        Self {
+            kind,
+            if_exists: false,
+            names,
+        }
+    }
+
+    /// Sets the `if_exists` flag.
+    pub fn with_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    /// Role or user.
+    pub fn kind(&self) -> RoleKind {
+        self.kind
+    }
+
+    /// Flag indicates that check if the roles exist.
+    pub fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
+    /// The roles to drop.
+    pub fn names(&self) -> &[UserName] {
+        &self.names
+    }
+}
+
+impl fmt::Display for DropRoleStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DROP {kind} {if_exists}{names}",
+            kind = self.kind,
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            names = display_comma_separated(&self.names),
+        )
+    }
+}
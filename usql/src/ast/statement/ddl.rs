@@ -1,11 +1,12 @@
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt;
 
 use crate::ast::{
     expression::*,
+    statement::{Assignment, ConflictResolution},
     types::*,
-    utils::{display_comma_separated, display_separated},
+    utils::{display_comma_separated, display_separated, escape_single_quote_string},
 };
 
 // ============================================================================
@@ -17,6 +18,7 @@ use crate::ast::{
 /// ```txt
 /// <table definition> ::=
 ///     CREATE [ <table scope> ] TABLE [ IF NOT EXISTS ] <table name> <table contents source>
+///         [ <partition scheme> ] // PostgreSQL, MySQL
 ///         [ WITH SYSTEM VERSIONING ]
 ///         [ ON COMMIT { PRESERVE | DELETE } ROWS ]
 ///
@@ -31,16 +33,103 @@ use crate::ast::{
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct CreateTableStmt {
     /// Table scope.
-    pub scope: Option<TableScope>,
+    pub(crate) scope: Option<TableScope>,
     /// Flag indicates that check if the table does not exists.
-    pub if_not_exists: bool,
+    pub(crate) if_not_exists: bool,
     /// Table name.
-    pub name: ObjectName,
+    pub(crate) name: ObjectName,
     /// Table contents source.
-    pub content: TableContent,
-    pub on_commit: Option<OnCommit>,
+    pub(crate) content: TableContent,
+    /// The partitioning of the table.
+    pub(crate) partition_by: Option<PartitionScheme>,
+    /// Flag indicates that the table is a system-versioned table.
+    pub(crate) system_versioning: bool,
+    /// The action of temporary table at the end of transaction.
+    pub(crate) on_commit: Option<OnCommit>,
+}
+
+impl CreateTableStmt {
+    /// Creates a `CreateTableStmt`, the optional fields are unset.
+    pub fn new(name: ObjectName, content: TableContent) -> Self {
+        Self {
+            scope: None,
+            if_not_exists: false,
+            name,
+            content,
+            partition_by: None,
+            system_versioning: false,
+            on_commit: None,
+        }
+    }
+
+    /// Sets the `scope`.
+    pub fn with_scope(mut self, scope: TableScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Sets the `partition_by`.
+    pub fn with_partition_by(mut self, partition_by: PartitionScheme) -> Self {
+        self.partition_by = Some(partition_by);
+        self
+    }
+
+    /// Sets the `system_versioning` flag.
+    pub fn with_system_versioning(mut self, system_versioning: bool) -> Self {
+        self.system_versioning = system_versioning;
+        self
+    }
+
+    /// Sets the `on_commit`.
+    pub fn with_on_commit(mut self, on_commit: OnCommit) -> Self {
+        self.on_commit = Some(on_commit);
+        self
+    }
+
+    /// Table scope.
+    pub fn scope(&self) -> Option<TableScope> {
+        self.scope
+    }
+
+    /// Flag indicates that check if the table does not exists.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Table name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Table contents source.
+    pub fn content(&self) -> &TableContent {
+        &self.content
+    }
+
+    /// The partitioning of the table.
+    pub fn partition_by(&self) -> Option<&PartitionScheme> {
+        self.partition_by.as_ref()
+    }
+
+    /// Flag indicates that the table is a system-versioned table.
+    pub fn system_versioning(&self) -> bool {
+        self.system_versioning
+    }
+
+    /// The action of temporary table at the end of transaction.
+    pub fn on_commit(&self) -> Option<OnCommit> {
+        self.on_commit
+    }
 }
 
 impl fmt::Display for CreateTableStmt {
@@ -55,6 +144,12 @@ impl fmt::Display for CreateTableStmt {
         }
         write!(f, " {}", self.name)?;
         write!(f, " {}", self.content)?;
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " {}", partition_by)?;
+        }
+        if self.system_versioning {
+            f.write_str(" WITH SYSTEM VERSIONING")?;
+        }
         if let Some(on_commit) = &self.on_commit {
             write!(f, " {}", on_commit)?;
         }
@@ -70,6 +165,8 @@ impl fmt::Display for CreateTableStmt {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum TableScope {
     Local,
     Global,
@@ -91,10 +188,13 @@ impl fmt::Display for TableScope {
 ///     ( <column definition> [, ...] [, ] [ <table constraint definition> [, ...] ] )
 ///     | LIKE <table name> [ <like option> [, ...] ]
 ///     | AS { ( <query expression> ) | <query expression> }
+///     | PARTITION OF <parent table name> <partition bound> // PostgreSQL
 /// ```
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum TableContent {
     Definition {
         /// Columns.
@@ -104,6 +204,12 @@ pub enum TableContent {
     },
     Like(TableLike),
     SubQuery(Box<Query>),
+    PartitionOf {
+        /// The partitioned table.
+        parent: ObjectName,
+        /// Partition bound.
+        bound: PartitionBound,
+    },
 }
 
 impl fmt::Display for TableContent {
@@ -125,193 +231,644 @@ impl fmt::Display for TableContent {
             }
             Self::Like(like) => write!(f, "{}", like),
             Self::SubQuery(query) => write!(f, "AS {}", query),
+            Self::PartitionOf { parent, bound } => write!(f, "PARTITION OF {} {}", parent, bound),
         }
     }
 }
 
-/// SQL constraint definition.
+/// The partitioning of a table.
+///
+/// ```txt
+/// <partition scheme> ::=
+///     PARTITION BY <partition strategy> ( <expr> [, ...] )
+///         [ PARTITIONS <number> ] // MySQL
+///         [ SUBPARTITION BY <partition strategy> ( <expr> [, ...] ) [ SUBPARTITIONS <number> ] ] // MySQL
+///         [ ( <partition definition> [, ...] ) ] // MySQL
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ConstraintDef<C> {
-    /// Constraint name.
-    pub name: Option<ObjectName>,
-    /// Constraint kind.
-    pub constraint: C,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct PartitionScheme {
+    /// Partition strategy.
+    pub(crate) strategy: PartitionStrategy,
+    /// Partition key columns or expressions.
+    pub(crate) keys: Vec<Expr>,
+    /// The number of partitions. (MySQL specific)
+    pub(crate) partitions: Option<u64>,
+    /// The subpartitioning of each partition. (MySQL specific)
+    pub(crate) subpartition_by: Option<SubpartitionScheme>,
+    /// Partition definitions. (MySQL specific)
+    pub(crate) definitions: Vec<PartitionDef>,
 }
 
-impl<C: fmt::Display> fmt::Display for ConstraintDef<C> {
+impl PartitionScheme {
+    /// Creates a `PartitionScheme`, the optional fields are unset.
+    pub fn new(
+        strategy: PartitionStrategy,
+        keys: Vec<Expr>,
+        definitions: Vec<PartitionDef>,
+    ) -> Self {
+        Self {
+            strategy,
+            keys,
+            partitions: None,
+            subpartition_by: None,
+            definitions,
+        }
+    }
+
+    /// Sets the `partitions`.
+    pub fn with_partitions(mut self, partitions: u64) -> Self {
+        self.partitions = Some(partitions);
+        self
+    }
+
+    /// Sets the `subpartition_by`.
+    pub fn with_subpartition_by(mut self, subpartition_by: SubpartitionScheme) -> Self {
+        self.subpartition_by = Some(subpartition_by);
+        self
+    }
+
+    /// Partition strategy.
+    pub fn strategy(&self) -> PartitionStrategy {
+        self.strategy
+    }
+
+    /// Partition key columns or expressions.
+    pub fn keys(&self) -> &[Expr] {
+        &self.keys
+    }
+
+    /// The number of partitions. (MySQL specific)
+    pub fn partitions(&self) -> Option<u64> {
+        self.partitions
+    }
+
+    /// The subpartitioning of each partition. (MySQL specific)
+    pub fn subpartition_by(&self) -> Option<&SubpartitionScheme> {
+        self.subpartition_by.as_ref()
+    }
+
+    /// Partition definitions. (MySQL specific)
+    pub fn definitions(&self) -> &[PartitionDef] {
+        &self.definitions
+    }
+}
+
+impl fmt::Display for PartitionScheme {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}{}",
-            display_constraint_name(&self.name),
-            self.constraint
-        )
+            "PARTITION BY {} ({})",
+            self.strategy,
+            display_comma_separated(&self.keys)
+        )?;
+        if let Some(partitions) = self.partitions {
+            write!(f, " PARTITIONS {}", partitions)?;
+        }
+        if let Some(subpartition_by) = &self.subpartition_by {
+            write!(f, " {}", subpartition_by)?;
+        }
+        if !self.definitions.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.definitions))?;
+        }
+        Ok(())
     }
 }
 
-fn display_constraint_name(name: &'_ Option<ObjectName>) -> impl fmt::Display + '_ {
-    struct ConstraintName<'a>(&'a Option<ObjectName>);
-    impl<'a> fmt::Display for ConstraintName<'a> {
-        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            if let Some(name) = self.0 {
-                write!(f, "CONSTRAINT {} ", name)?;
+/// The partition strategy.
+///
+/// ```txt
+/// <partition strategy> ::=
+///     RANGE | LIST | HASH
+///     | RANGE COLUMNS | LIST COLUMNS | LINEAR HASH // MySQL
+///     | [ LINEAR ] KEY [ ALGORITHM = { 1 | 2 } ] // MySQL
+/// ```
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+    RangeColumns,
+    ListColumns,
+    LinearHash,
+    Key {
+        /// Flag indicates that the linear hashing is used.
+        linear: bool,
+        /// The key-hashing algorithm.
+        algorithm: Option<u64>,
+    },
+}
+
+impl fmt::Display for PartitionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Range => f.write_str("RANGE"),
+            Self::List => f.write_str("LIST"),
+            Self::Hash => f.write_str("HASH"),
+            Self::RangeColumns => f.write_str("RANGE COLUMNS"),
+            Self::ListColumns => f.write_str("LIST COLUMNS"),
+            Self::LinearHash => f.write_str("LINEAR HASH"),
+            Self::Key { linear, algorithm } => {
+                if *linear {
+                    f.write_str("LINEAR ")?;
+                }
+                f.write_str("KEY")?;
+                if let Some(algorithm) = algorithm {
+                    write!(f, " ALGORITHM = {}", algorithm)?;
+                }
+                Ok(())
             }
-            Ok(())
         }
     }
-    ConstraintName(name)
 }
 
-/// SQL column definition.
+/// The subpartitioning of a partitioned table. (MySQL specific)
 ///
 /// ```txt
-/// <column definition> ::= <column name> <data type> [ <column constraint definition> [, ...] ]
+/// SUBPARTITION BY <partition strategy> ( <expr> [, ...] ) [ SUBPARTITIONS <number> ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ColumnDef {
-    /// Column name.
-    pub name: Ident,
-    /// Column type.
-    pub data_type: DataType,
-    /// Column constraints.
-    pub constraints: Vec<ColumnConstraintDef>,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SubpartitionScheme {
+    /// Subpartition strategy, only `HASH` and `KEY` are allowed by MySQL.
+    pub(crate) strategy: PartitionStrategy,
+    /// Subpartition key columns or expressions.
+    pub(crate) keys: Vec<Expr>,
+    /// The number of subpartitions in each partition.
+    pub(crate) subpartitions: Option<u64>,
 }
 
-impl fmt::Display for ColumnDef {
+impl SubpartitionScheme {
+    /// Creates a `SubpartitionScheme`, the optional fields are unset.
+    pub fn new(strategy: PartitionStrategy, keys: Vec<Expr>) -> Self {
+        Self {
+            strategy,
+            keys,
+            subpartitions: None,
+        }
+    }
+
+    /// Sets the `subpartitions`.
+    pub fn with_subpartitions(mut self, subpartitions: u64) -> Self {
+        self.subpartitions = Some(subpartitions);
+        self
+    }
+
+    /// Subpartition strategy, only `HASH` and `KEY` are allowed by MySQL.
+    pub fn strategy(&self) -> PartitionStrategy {
+        self.strategy
+    }
+
+    /// Subpartition key columns or expressions.
+    pub fn keys(&self) -> &[Expr] {
+        &self.keys
+    }
+
+    /// The number of subpartitions in each partition.
+    pub fn subpartitions(&self) -> Option<u64> {
+        self.subpartitions
+    }
+}
+
+impl fmt::Display for SubpartitionScheme {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.name, self.data_type)?;
-        for constraint in &self.constraints {
-            write!(f, " {}", constraint)?;
+        write!(
+            f,
+            "SUBPARTITION BY {} ({})",
+            self.strategy,
+            display_comma_separated(&self.keys)
+        )?;
+        if let Some(subpartitions) = self.subpartitions {
+            write!(f, " SUBPARTITIONS {}", subpartitions)?;
         }
         Ok(())
     }
 }
 
-/// SQL column constraint definition.
+/// The definition of a partition. (MySQL specific)
 ///
 /// ```txt
-/// <column constraint definition> ::= [ CONSTRAINT <constraint name> ] <column constraint>
+/// <partition definition> ::=
+///     PARTITION <partition name> [ <partition values> ] [ <partition option> [...] ]
+///         [ ( <subpartition definition> [, ...] ) ]
 /// ```
-pub type ColumnConstraintDef = ConstraintDef<ColumnConstraint>;
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct PartitionDef {
+    /// Partition name.
+    pub(crate) name: Ident,
+    /// The values of the rows stored in the partition.
+    pub(crate) values: Option<PartitionValues>,
+    /// Partition options.
+    pub(crate) options: Vec<PartitionOption>,
+    /// Subpartition definitions.
+    pub(crate) subpartitions: Vec<SubpartitionDef>,
+}
 
-/// SQL column constraint kind.
+impl PartitionDef {
+    /// Creates a `PartitionDef`, the optional fields are unset.
+    pub fn new(
+        name: Ident,
+        options: Vec<PartitionOption>,
+        subpartitions: Vec<SubpartitionDef>,
+    ) -> Self {
+        Self {
+            name,
+            values: None,
+            options,
+            subpartitions,
+        }
+    }
+
+    /// Sets the `values`.
+    pub fn with_values(mut self, values: PartitionValues) -> Self {
+        self.values = Some(values);
+        self
+    }
+
+    /// Partition name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// The values of the rows stored in the partition.
+    pub fn values(&self) -> Option<&PartitionValues> {
+        self.values.as_ref()
+    }
+
+    /// Partition options.
+    pub fn options(&self) -> &[PartitionOption] {
+        &self.options
+    }
+
+    /// Subpartition definitions.
+    pub fn subpartitions(&self) -> &[SubpartitionDef] {
+        &self.subpartitions
+    }
+}
+
+impl fmt::Display for PartitionDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PARTITION {}", self.name)?;
+        if let Some(values) = &self.values {
+            write!(f, " {}", values)?;
+        }
+        if !self.options.is_empty() {
+            write!(f, " {}", display_separated(&self.options, " "))?;
+        }
+        if !self.subpartitions.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.subpartitions))?;
+        }
+        Ok(())
+    }
+}
+
+/// The values of a partition. (MySQL specific)
 ///
 /// ```txt
-/// <column constraint> ::=
-///     NULL
-///     | NOT NULL
-///     | <unique specification>
-///     | <check constraint definition>
-///     | <references specification>
-///     | <default specification>
-///     | <collation specification>
-///
-/// <unique specification> ::= UNIQUE | PRIMARY KEY
-///
-/// <check constraint definition> ::= CHECK ( <search condition> )
-///
-/// <references specification> ::= REFERENCES <table name> [ ( <column name> [, ...] ) ]
-///     [ MATCH { FULL | PARTIAL | SIMPLE } ]
-///     [ <referential triggered action> ]
-/// <referential triggered action> ::= <update rule> [ <delete rule> ] | <delete rule> [ <update rule> ]
-/// <update rule> ::= ON UPDATE <referential action>
-/// <delete rule> ::= ON DELETE <referential action>
-/// <referential action> ::= CASCADE | SET NULL | SET DEFAULT | RESTRICT | NO ACTION
+/// <partition values> ::=
+///     VALUES LESS THAN { ( <range value> [, ...] ) | MAXVALUE }
+///     | VALUES IN ( <expr> [, ...] )
 /// ```
+///
+/// **NOTE**: `VALUES LESS THAN MAXVALUE` is parsed as `VALUES LESS THAN (MAXVALUE)`.
+#[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum ColumnConstraint {
-    /// `NULL`
-    Null,
-    /// `NOT NULL`
-    NotNull,
-    /// Unique specification
-    #[doc(hidden)]
-    Unique { is_primary: bool },
-    /// Check constraint definition
-    Check(Box<Expr>),
-    /// Referential specification
-    References {
-        /// Foreign table name.
-        table: ObjectName,
-        /// Referenced column list.
-        referenced_columns: Option<Vec<Ident>>,
-        /// Match type.
-        match_type: Option<ReferentialMatchType>,
-        /// `ON UPDATE` referential triggered action.
-        on_update: Option<ReferentialAction>,
-        /// `ON DELETE` referential triggered action.
-        on_delete: Option<ReferentialAction>,
-    },
-    /// Default definition
-    Default(Literal),
-    /// Collation specification
-    Collation(ObjectName),
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PartitionValues {
+    LessThan(Vec<PartitionRangeValue>),
+    In(Vec<Expr>),
 }
 
-impl fmt::Display for ColumnConstraint {
+impl fmt::Display for PartitionValues {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Null => f.write_str("NULL"),
-            Self::NotNull => f.write_str("NOT NULL"),
-            Self::Unique { is_primary } => {
-                if *is_primary {
-                    f.write_str("PRIMARY KEY")
-                } else {
-                    f.write_str("UNIQUE")
-                }
-            }
-            Self::Check(expr) => write!(f, "CHECK ({})", expr),
-            Self::References {
-                table,
-                referenced_columns,
-                match_type,
-                on_update,
-                on_delete,
-            } => {
-                write!(f, "REFERENCES {}", table)?;
-                if let Some(referenced_columns) = referenced_columns {
-                    write!(f, " ({})", display_comma_separated(referenced_columns))?;
-                }
-                if let Some(match_type) = match_type {
-                    write!(f, " MATCH {}", match_type)?;
-                }
-                if let Some(action) = on_update {
-                    write!(f, " ON UPDATE {}", action)?;
-                }
-                if let Some(action) = on_delete {
-                    write!(f, " ON DELETE {}", action)?;
-                }
-                Ok(())
+            Self::LessThan(values) => {
+                write!(f, "VALUES LESS THAN ({})", display_comma_separated(values))
             }
-            Self::Default(default) => write!(f, "DEFAULT {}", default),
-            Self::Collation(collation) => write!(f, "COLLATE {}", collation),
+            Self::In(values) => write!(f, "VALUES IN ({})", display_comma_separated(values)),
         }
     }
 }
 
-/// SQL table constraint definition.
+/// The definition of a subpartition. (MySQL specific)
 ///
 /// ```txt
-/// <table constraint definition> ::= [ CONSTRAINT <constraint name> ] <table constraint>
+/// <subpartition definition> ::= SUBPARTITION <subpartition name> [ <partition option> [...] ]
 /// ```
-pub type TableConstraintDef = ConstraintDef<TableConstraint>;
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct SubpartitionDef {
+    /// Subpartition name.
+    pub(crate) name: Ident,
+    /// Subpartition options.
+    pub(crate) options: Vec<PartitionOption>,
+}
 
-/// SQL table constraint kind.
+impl SubpartitionDef {
+    /// Creates a `SubpartitionDef`.
+    pub fn new(name: Ident, options: Vec<PartitionOption>) -> Self {
+        Self { name, options }
+    }
+
+    /// Subpartition name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Subpartition options.
+    pub fn options(&self) -> &[PartitionOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for SubpartitionDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SUBPARTITION {}", self.name)?;
+        if !self.options.is_empty() {
+            write!(f, " {}", display_separated(&self.options, " "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The option of a partition or subpartition. (MySQL specific)
 ///
 /// ```txt
-/// <table constraint> ::=
-///     <unique constraint definition>
-///     | <check constraint definition>
-///     | <referential constraint definition>
-///
-/// <unique constraint definition> ::= { UNIQUE | PRIMARY KEY } ( <column name> [, ...] )
-///
-/// <check constraint definition> ::= CHECK ( <search condition> )
-///
-/// <referential constraint definition> ::= FOREIGN KEY ( <column name> [, ...] ) <references specification>
+/// <partition option> ::=
+///     [ STORAGE ] ENGINE [ = ] <engine name>
+///     | COMMENT [ = ] <string literal>
+///     | DATA DIRECTORY [ = ] <string literal>
+///     | INDEX DIRECTORY [ = ] <string literal>
+///     | MAX_ROWS [ = ] <number>
+///     | MIN_ROWS [ = ] <number>
+///     | TABLESPACE [ = ] <tablespace name>
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PartitionOption {
+    Engine(Ident),
+    Comment(String),
+    DataDirectory(String),
+    IndexDirectory(String),
+    MaxRows(u64),
+    MinRows(u64),
+    Tablespace(Ident),
+}
+
+impl fmt::Display for PartitionOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Engine(engine) => write!(f, "ENGINE = {}", engine),
+            Self::Comment(comment) => {
+                write!(f, "COMMENT = '{}'", escape_single_quote_string(comment))
+            }
+            Self::DataDirectory(dir) => {
+                write!(f, "DATA DIRECTORY = '{}'", escape_single_quote_string(dir))
+            }
+            Self::IndexDirectory(dir) => {
+                write!(f, "INDEX DIRECTORY = '{}'", escape_single_quote_string(dir))
+            }
+            Self::MaxRows(rows) => write!(f, "MAX_ROWS = {}", rows),
+            Self::MinRows(rows) => write!(f, "MIN_ROWS = {}", rows),
+            Self::Tablespace(tablespace) => write!(f, "TABLESPACE = {}", tablespace),
+        }
+    }
+}
+
+/// The bound of a partition. (PostgreSQL specific)
+///
+/// ```txt
+/// <partition bound> ::=
+///     FOR VALUES IN ( <expr> [, ...] )
+///     | FOR VALUES FROM ( <range value> [, ...] ) TO ( <range value> [, ...] )
+///     | FOR VALUES WITH ( MODULUS <numeric literal>, REMAINDER <numeric literal> )
+///     | DEFAULT
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PartitionBound {
+    In(Vec<Expr>),
+    Range {
+        /// The inclusive lower bound.
+        from: Vec<PartitionRangeValue>,
+        /// The exclusive upper bound.
+        to: Vec<PartitionRangeValue>,
+    },
+    Hash {
+        /// The modulus of the hash partitions.
+        modulus: u64,
+        /// The remainder of the hash values in the partition.
+        remainder: u64,
+    },
+    Default,
+}
+
+impl fmt::Display for PartitionBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::In(values) => write!(f, "FOR VALUES IN ({})", display_comma_separated(values)),
+            Self::Range { from, to } => write!(
+                f,
+                "FOR VALUES FROM ({}) TO ({})",
+                display_comma_separated(from),
+                display_comma_separated(to)
+            ),
+            Self::Hash { modulus, remainder } => write!(
+                f,
+                "FOR VALUES WITH (MODULUS {}, REMAINDER {})",
+                modulus, remainder
+            ),
+            Self::Default => f.write_str("DEFAULT"),
+        }
+    }
+}
+
+/// The value of a range partition bound. (PostgreSQL specific)
+///
+/// ```txt
+/// <range value> ::= <expr> | MINVALUE | MAXVALUE
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PartitionRangeValue {
+    Expr(Expr),
+    MinValue,
+    MaxValue,
+}
+
+impl fmt::Display for PartitionRangeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Expr(expr) => write!(f, "{}", expr),
+            Self::MinValue => f.write_str("MINVALUE"),
+            Self::MaxValue => f.write_str("MAXVALUE"),
+        }
+    }
+}
+
+/// SQL constraint definition.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ConstraintDef<C> {
+    /// Constraint name.
+    pub(crate) name: Option<ObjectName>,
+    /// Constraint kind.
+    pub(crate) constraint: C,
+}
+
+impl<C> ConstraintDef<C> {
+    /// Creates a `ConstraintDef`, the optional fields are unset.
+    pub fn new(constraint: C) -> Self {
+        Self {
+            name: None,
+            constraint,
+        }
+    }
+
+    /// Sets the `name`.
+    pub fn with_name(mut self, name: ObjectName) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Constraint name.
+    pub fn name(&self) -> Option<&ObjectName> {
+        self.name.as_ref()
+    }
+
+    /// Constraint kind.
+    pub fn constraint(&self) -> &C {
+        &self.constraint
+    }
+}
+
+impl<C: fmt::Display> fmt::Display for ConstraintDef<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            display_constraint_name(&self.name),
+            self.constraint
+        )
+    }
+}
+
+fn display_constraint_name(name: &'_ Option<ObjectName>) -> impl fmt::Display + '_ {
+    struct ConstraintName<'a>(&'a Option<ObjectName>);
+    impl<'a> fmt::Display for ConstraintName<'a> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            if let Some(name) = self.0 {
+                write!(f, "CONSTRAINT {} ", name)?;
+            }
+            Ok(())
+        }
+    }
+    ConstraintName(name)
+}
+
+/// SQL column definition.
+///
+/// ```txt
+/// <column definition> ::= <column name> <data type> [ <column constraint definition> [, ...] ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ColumnDef {
+    /// Column name.
+    pub(crate) name: Ident,
+    /// Column type.
+    pub(crate) data_type: DataType,
+    /// Column constraints.
+    pub(crate) constraints: Vec<ColumnConstraintDef>,
+}
+
+impl ColumnDef {
+    /// Creates a `ColumnDef`.
+    pub fn new(name: Ident, data_type: DataType, constraints: Vec<ColumnConstraintDef>) -> Self {
+        Self {
+            name,
+            data_type,
+            constraints,
+        }
+    }
+
+    /// Column name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Column type.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Column constraints.
+    pub fn constraints(&self) -> &[ColumnConstraintDef] {
+        &self.constraints
+    }
+}
+
+impl fmt::Display for ColumnDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+        for constraint in &self.constraints {
+            write!(f, " {}", constraint)?;
+        }
+        Ok(())
+    }
+}
+
+/// SQL column constraint definition.
+///
+/// ```txt
+/// <column constraint definition> ::= [ CONSTRAINT <constraint name> ] <column constraint>
+/// ```
+pub type ColumnConstraintDef = ConstraintDef<ColumnConstraint>;
+
+/// SQL column constraint kind.
+///
+/// ```txt
+/// <column constraint> ::=
+///     NULL
+///     | NOT NULL
+///     | <unique specification>
+///     | <check constraint definition>
+///     | <references specification>
+///     | <default specification>
+///     | <collation specification>
+///
+/// <unique specification> ::= UNIQUE | PRIMARY KEY
+///
+/// <check constraint definition> ::= CHECK ( <search condition> )
+///
 /// <references specification> ::= REFERENCES <table name> [ ( <column name> [, ...] ) ]
 ///     [ MATCH { FULL | PARTIAL | SIMPLE } ]
 ///     [ <referential triggered action> ]
@@ -319,22 +876,33 @@ pub type TableConstraintDef = ConstraintDef<TableConstraint>;
 /// <update rule> ::= ON UPDATE <referential action>
 /// <delete rule> ::= ON DELETE <referential action>
 /// <referential action> ::= CASCADE | SET NULL | SET DEFAULT | RESTRICT | NO ACTION
-/// ``
+///
+/// // SQLite
+/// NOT NULL [ ON CONFLICT <conflict resolution> ]
+/// <unique specification> [ ON CONFLICT <conflict resolution> ]
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum TableConstraint {
-    /// Unique constraint definition
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ColumnConstraint {
+    /// `NULL`
+    Null,
+    /// `NOT NULL`
+    NotNull {
+        /// `ON CONFLICT` clause. (SQLite specific)
+        on_conflict: Option<ConflictResolution>,
+    },
+    /// Unique specification
     #[doc(hidden)]
     Unique {
         is_primary: bool,
-        columns: Vec<Ident>,
+        on_conflict: Option<ConflictResolution>,
     },
     /// Check constraint definition
     Check(Box<Expr>),
-    /// Referential constraint definition
-    ForeignKey {
-        /// Referencing column list.
-        referencing_columns: Vec<Ident>,
+    /// Referential specification
+    References {
         /// Foreign table name.
         table: ObjectName,
         /// Referenced column list.
@@ -346,35 +914,40 @@ pub enum TableConstraint {
         /// `ON DELETE` referential triggered action.
         on_delete: Option<ReferentialAction>,
     },
+    /// Default definition
+    Default(Literal),
+    /// Collation specification
+    Collation(ObjectName),
 }
 
-impl fmt::Display for TableConstraint {
+impl fmt::Display for ColumnConstraint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Null => f.write_str("NULL"),
+            Self::NotNull { on_conflict } => {
+                f.write_str("NOT NULL")?;
+                display_on_conflict(f, on_conflict)
+            }
             Self::Unique {
                 is_primary,
-                columns,
-            } => write!(
-                f,
-                "{} ({})",
-                if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
-                display_comma_separated(columns)
-            ),
+                on_conflict,
+            } => {
+                if *is_primary {
+                    f.write_str("PRIMARY KEY")?;
+                } else {
+                    f.write_str("UNIQUE")?;
+                }
+                display_on_conflict(f, on_conflict)
+            }
             Self::Check(expr) => write!(f, "CHECK ({})", expr),
-            Self::ForeignKey {
-                referencing_columns,
+            Self::References {
                 table,
                 referenced_columns,
                 match_type,
                 on_update,
                 on_delete,
             } => {
-                write!(
-                    f,
-                    "FOREIGN KEY ({}) REFERENCES {}",
-                    display_comma_separated(referencing_columns),
-                    table,
-                )?;
+                write!(f, "REFERENCES {}", table)?;
                 if let Some(referenced_columns) = referenced_columns {
                     write!(f, " ({})", display_comma_separated(referenced_columns))?;
                 }
@@ -389,41 +962,168 @@ impl fmt::Display for TableConstraint {
                 }
                 Ok(())
             }
+            Self::Default(default) => write!(f, "DEFAULT {}", default),
+            Self::Collation(collation) => write!(f, "COLLATE {}", collation),
         }
     }
 }
 
-/// Used in references constraints.
-#[doc(hidden)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum ReferentialMatchType {
-    Full,
-    Partial,
-    Simple,
-}
-
-impl fmt::Display for ReferentialMatchType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Self::Full => "FULL",
-            Self::Partial => "PARTIAL",
-            Self::Simple => "SIMPLE",
-        })
-    }
-}
+/// SQL table constraint definition.
+///
+/// ```txt
+/// <table constraint definition> ::= [ CONSTRAINT <constraint name> ] <table constraint>
+/// ```
+pub type TableConstraintDef = ConstraintDef<TableConstraint>;
 
-/// Used in references constraints in `ON UPDATE` and `ON DELETE` rules.
-#[doc(hidden)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// SQL table constraint kind.
+///
+/// ```txt
+/// <table constraint> ::=
+///     <unique constraint definition>
+///     | <check constraint definition>
+///     | <referential constraint definition>
+///
+/// <unique constraint definition> ::= { UNIQUE | PRIMARY KEY } ( <column name> [, ...] )
+///
+/// <check constraint definition> ::= CHECK ( <search condition> )
+///
+/// <referential constraint definition> ::= FOREIGN KEY ( <column name> [, ...] ) <references specification>
+/// <references specification> ::= REFERENCES <table name> [ ( <column name> [, ...] ) ]
+///     [ MATCH { FULL | PARTIAL | SIMPLE } ]
+///     [ <referential triggered action> ]
+/// <referential triggered action> ::= <update rule> [ <delete rule> ] | <delete rule> [ <update rule> ]
+/// <update rule> ::= ON UPDATE <referential action>
+/// <delete rule> ::= ON DELETE <referential action>
+/// <referential action> ::= CASCADE | SET NULL | SET DEFAULT | RESTRICT | NO ACTION
+///
+/// // SQLite
+/// <unique constraint definition> [ ON CONFLICT <conflict resolution> ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum ReferentialAction {
-    Cascade,
-    Restrict,
-    SetNull,
-    SetDefault,
-    NoAction,
-}
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TableConstraint {
+    /// Unique constraint definition
+    #[doc(hidden)]
+    Unique {
+        is_primary: bool,
+        columns: Vec<Ident>,
+        on_conflict: Option<ConflictResolution>,
+    },
+    /// Check constraint definition
+    Check(Box<Expr>),
+    /// Referential constraint definition
+    ForeignKey {
+        /// Referencing column list.
+        referencing_columns: Vec<Ident>,
+        /// Foreign table name.
+        table: ObjectName,
+        /// Referenced column list.
+        referenced_columns: Option<Vec<Ident>>,
+        /// Match type.
+        match_type: Option<ReferentialMatchType>,
+        /// `ON UPDATE` referential triggered action.
+        on_update: Option<ReferentialAction>,
+        /// `ON DELETE` referential triggered action.
+        on_delete: Option<ReferentialAction>,
+    },
+}
+
+impl fmt::Display for TableConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unique {
+                is_primary,
+                columns,
+                on_conflict,
+            } => {
+                write!(
+                    f,
+                    "{} ({})",
+                    if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
+                    display_comma_separated(columns)
+                )?;
+                display_on_conflict(f, on_conflict)
+            }
+            Self::Check(expr) => write!(f, "CHECK ({})", expr),
+            Self::ForeignKey {
+                referencing_columns,
+                table,
+                referenced_columns,
+                match_type,
+                on_update,
+                on_delete,
+            } => {
+                write!(
+                    f,
+                    "FOREIGN KEY ({}) REFERENCES {}",
+                    display_comma_separated(referencing_columns),
+                    table,
+                )?;
+                if let Some(referenced_columns) = referenced_columns {
+                    write!(f, " ({})", display_comma_separated(referenced_columns))?;
+                }
+                if let Some(match_type) = match_type {
+                    write!(f, " MATCH {}", match_type)?;
+                }
+                if let Some(action) = on_update {
+                    write!(f, " ON UPDATE {}", action)?;
+                }
+                if let Some(action) = on_delete {
+                    write!(f, " ON DELETE {}", action)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn display_on_conflict(
+    f: &mut fmt::Formatter<'_>,
+    on_conflict: &Option<ConflictResolution>,
+) -> fmt::Result {
+    if let Some(resolution) = on_conflict {
+        write!(f, " ON CONFLICT {}", resolution)?;
+    }
+    Ok(())
+}
+
+/// Used in references constraints.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ReferentialMatchType {
+    Full,
+    Partial,
+    Simple,
+}
+
+impl fmt::Display for ReferentialMatchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Full => "FULL",
+            Self::Partial => "PARTIAL",
+            Self::Simple => "SIMPLE",
+        })
+    }
+}
+
+/// Used in references constraints in `ON UPDATE` and `ON DELETE` rules.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ReferentialAction {
+    Cascade,
+    Restrict,
+    SetNull,
+    SetDefault,
+    NoAction,
+}
 
 impl fmt::Display for ReferentialAction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -442,11 +1142,39 @@ impl fmt::Display for ReferentialAction {
 /// column names, their data types, and their not-null constraints.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct TableLike {
     /// Source table name.
-    pub table: ObjectName,
+    pub(crate) table: ObjectName,
+    /// Like options.
+    pub(crate) options: Option<Vec<LikeOption>>,
+}
+
+impl TableLike {
+    /// Creates a `TableLike`, the optional fields are unset.
+    pub fn new(table: ObjectName) -> Self {
+        Self {
+            table,
+            options: None,
+        }
+    }
+
+    /// Sets the `options`.
+    pub fn with_options(mut self, options: Vec<LikeOption>) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Source table name.
+    pub fn table(&self) -> &ObjectName {
+        &self.table
+    }
+
     /// Like options.
-    pub options: Option<Vec<LikeOption>>,
+    pub fn options(&self) -> Option<&[LikeOption]> {
+        self.options.as_deref()
+    }
 }
 
 impl fmt::Display for TableLike {
@@ -463,6 +1191,8 @@ impl fmt::Display for TableLike {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum LikeOption {
     IncludingIdentity,
     ExcludingIdentity,
@@ -489,6 +1219,8 @@ impl fmt::Display for LikeOption {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum OnCommit {
     PreserveRows,
     DeleteRows,
@@ -512,492 +1244,2378 @@ impl fmt::Display for OnCommit {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct AlterTableStmt {
     /// Flag indicates that check if the table exists. (Non-standard)
-    pub if_exists: bool,
+    pub(crate) if_exists: bool,
+    /// Table name.
+    pub(crate) name: ObjectName,
+    /// Alter action.
+    pub(crate) action: AlterTableAction,
+}
+
+impl AlterTableStmt {
+    /// Creates a `AlterTableStmt`, the flags are unset.
+    pub fn new(name: ObjectName, action: AlterTableAction) -> Self {
+        Self {
+            if_exists: false,
+            name,
+            action,
+        }
+    }
+
+    /// Sets the `if_exists` flag.
+    pub fn with_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    /// Flag indicates that check if the table exists. (Non-standard)
+    pub fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
     /// Table name.
-    pub name: ObjectName,
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
     /// Alter action.
-    pub action: AlterTableAction,
+    pub fn action(&self) -> &AlterTableAction {
+        &self.action
+    }
+}
+
+impl fmt::Display for AlterTableStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ALTER TABLE {if_exists}{table_name} {action}",
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            table_name = self.name,
+            action = self.action,
+        )
+    }
+}
+
+/// The alter action of table.
+///
+/// ```txt
+/// <alter table action> ::=
+///     <add column definition>
+///     | <alter column definition>
+///     | <drop column definition>
+///     | <add table constraint definition>
+///     | <alter table constraint definition>
+///     | <drop table constraint definition>
+///     | <add table period definition>
+///     | <drop table period definition>
+///     | <add system versioning clause>
+///     | <drop system versioning clause>
+///     | { ENABLE | DISABLE | FORCE | NO FORCE } ROW LEVEL SECURITY // PostgreSQL
+///     | ATTACH PARTITION <partition name> <partition bound> // PostgreSQL
+///     | DETACH PARTITION <partition name> [ CONCURRENTLY | FINALIZE ] // PostgreSQL
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AlterTableAction {
+    AddColumn {
+        /// Flag indicates that check if the column does not exist. (Non-standard)
+        if_not_exists: bool,
+        /// Column definition.
+        column: ColumnDef,
+    },
+    DropColumn {
+        /// Flag indicates that check if the column exists. (Non-standard)
+        if_exists: bool,
+        /// Column name.
+        name: Ident,
+        /// Drop behavior.
+        drop_behavior: Option<DropBehavior>,
+    },
+    /// Renames the table. (Non-standard)
+    RenameTo(ObjectName),
+    /// `ADD SYSTEM VERSIONING`
+    AddSystemVersioning,
+    /// `DROP SYSTEM VERSIONING`
+    DropSystemVersioning,
+    /// `ENABLE ROW LEVEL SECURITY` (PostgreSQL specific)
+    EnableRowLevelSecurity,
+    /// `DISABLE ROW LEVEL SECURITY` (PostgreSQL specific)
+    DisableRowLevelSecurity,
+    /// `FORCE ROW LEVEL SECURITY` (PostgreSQL specific)
+    ForceRowLevelSecurity,
+    /// `NO FORCE ROW LEVEL SECURITY` (PostgreSQL specific)
+    NoForceRowLevelSecurity,
+    /// `ATTACH PARTITION` (PostgreSQL specific)
+    AttachPartition {
+        /// Partition name.
+        name: ObjectName,
+        /// Partition bound.
+        bound: PartitionBound,
+    },
+    /// `DETACH PARTITION` (PostgreSQL specific)
+    DetachPartition {
+        /// Partition name.
+        name: ObjectName,
+        /// How the partition is detached.
+        mode: Option<DetachPartitionMode>,
+    },
+}
+
+impl fmt::Display for AlterTableAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddColumn {
+                if_not_exists,
+                column,
+            } => write!(
+                f,
+                "ADD COLUMN {if_not_exists}{column}",
+                if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                column = column,
+            ),
+            Self::DropColumn {
+                if_exists,
+                name,
+                drop_behavior,
+            } => {
+                write!(
+                    f,
+                    "DROP COLUMN {if_exists}{name}",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
+                    name = name,
+                )?;
+                if let Some(behavior) = drop_behavior {
+                    write!(f, " {}", behavior)?;
+                }
+                Ok(())
+            }
+            Self::RenameTo(name) => write!(f, "RENAME TO {}", name),
+            Self::AddSystemVersioning => f.write_str("ADD SYSTEM VERSIONING"),
+            Self::DropSystemVersioning => f.write_str("DROP SYSTEM VERSIONING"),
+            Self::EnableRowLevelSecurity => f.write_str("ENABLE ROW LEVEL SECURITY"),
+            Self::DisableRowLevelSecurity => f.write_str("DISABLE ROW LEVEL SECURITY"),
+            Self::ForceRowLevelSecurity => f.write_str("FORCE ROW LEVEL SECURITY"),
+            Self::NoForceRowLevelSecurity => f.write_str("NO FORCE ROW LEVEL SECURITY"),
+            Self::AttachPartition { name, bound } => {
+                write!(f, "ATTACH PARTITION {} {}", name, bound)
+            }
+            Self::DetachPartition { name, mode } => {
+                write!(f, "DETACH PARTITION {}", name)?;
+                if let Some(mode) = mode {
+                    write!(f, " {}", mode)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The mode of `DETACH PARTITION` action. (PostgreSQL specific)
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum DetachPartitionMode {
+    Concurrently,
+    Finalize,
+}
+
+impl fmt::Display for DetachPartitionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Concurrently => "CONCURRENTLY",
+            Self::Finalize => "FINALIZE",
+        })
+    }
+}
+
+/// The `RENAME TABLE` statement. (MySQL)
+///
+/// ```txt
+/// RENAME TABLE <table name> TO <new table name> [, <table name> TO <new table name> ...]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct RenameTableStmt {
+    /// The renamed tables, renaming is performed from left to right.
+    pub(crate) tables: Vec<RenameTable>,
+}
+
+impl RenameTableStmt {
+    /// Creates a `RenameTableStmt`.
+    pub fn new(tables: Vec<RenameTable>) -> Self {
+        Self { tables }
+    }
+
+    /// The renamed tables, renaming is performed from left to right.
+    pub fn tables(&self) -> &[RenameTable] {
+        &self.tables
+    }
+}
+
+impl RenameTableStmt {
+    /// Converts the statement into the equivalent `ALTER TABLE ... RENAME TO ...` statements.
+    pub fn to_alter_table_stmts(&self) -> Vec<AlterTableStmt> {
+        self.tables
+            .iter()
+            .map(|table| AlterTableStmt {
+                if_exists: false,
+                name: table.from.clone(),
+                action: AlterTableAction::RenameTo(table.to.clone()),
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for RenameTableStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RENAME TABLE {}", display_comma_separated(&self.tables))
+    }
+}
+
+/// A `<table name> TO <new table name>` pair of `RENAME TABLE` statement.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct RenameTable {
+    /// Old table name.
+    pub(crate) from: ObjectName,
+    /// New table name.
+    pub(crate) to: ObjectName,
+}
+
+impl RenameTable {
+    /// Creates a `RenameTable`.
+    pub fn new(from: ObjectName, to: ObjectName) -> Self {
+        Self { from, to }
+    }
+
+    /// Old table name.
+    pub fn from(&self) -> &ObjectName {
+        &self.from
+    }
+
+    /// New table name.
+    pub fn to(&self) -> &ObjectName {
+        &self.to
+    }
+}
+
+impl fmt::Display for RenameTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} TO {}", self.from, self.to)
+    }
+}
+
+// ============================================================================
+// View definition and manipulation
+// ============================================================================
+
+/// The `CREATE VIEW` statement.
+///
+/// ```txt
+/// <view definition> ::= CREATE [ RECURSIVE ] VIEW <table name> <view specification>
+///     AS <query expression>  [ WITH [ CASCADED | LOCAL ] CHECK OPTION ]
+///
+/// // MySQL
+/// CREATE [ OR REPLACE ] [ ALGORITHM = { UNDEFINED | MERGE | TEMPTABLE } ]
+///     [ DEFINER = <user name> ] [ SQL SECURITY { DEFINER | INVOKER } ]
+///     VIEW <view name> [ ( <column name> [, ...] ) ]
+///     AS <query expression> [ WITH [ CASCADED | LOCAL ] CHECK OPTION ]
+///
+/// // PostgreSQL
+/// CREATE [ OR REPLACE ] [ RECURSIVE ] VIEW <view name> [ ( <column name> [, ...] ) ]
+///     [ WITH ( <view option name> [ = <view option value> ] [, ...] ) ]
+///     AS <query expression> [ WITH [ CASCADED | LOCAL ] CHECK OPTION ]
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateViewStmt {
+    /// Flag indicates that if a view of the same name already exists, the old one will be replaced.
+    ///
+    /// **NOTE: PostgreSQL/MySQL specific**
+    pub(crate) or_replace: bool,
+    /// The algorithm to process the view.
+    ///
+    /// **NOTE: MySQL specific**
+    pub(crate) algorithm: Option<ViewAlgorithm>,
+    /// The account used to check the access privileges of the view.
+    ///
+    /// **NOTE: MySQL specific**
+    pub(crate) definer: Option<UserName>,
+    /// The security context of the view.
+    ///
+    /// **NOTE: MySQL specific**
+    pub(crate) sql_security: Option<SqlSecurity>,
+    /// Flag indicates that if the view is a recursive view.
+    ///
+    /// **NOTE: MySQL/SQLite not support**
+    pub(crate) recursive: bool,
+    /// Flag indicates that check if the view does not exists.
+    ///
+    /// **NOTE: SQLite specific**
+    pub(crate) if_not_exists: bool,
+    /// Viewed table name.
+    pub(crate) name: ObjectName,
+    /// Viewed columns.
+    pub(crate) columns: Option<Vec<Ident>>,
+    /// View options, like `security_barrier`.
+    ///
+    /// **NOTE: PostgreSQL specific**
+    pub(crate) options: Vec<ViewOption>,
+    /// A SQL query that specifies what to view.
+    pub(crate) query: Box<Query>,
+    /// Check option.
+    ///
+    /// **NOTE: SQLite not support**
+    pub(crate) check_option: Option<ViewCheckOption>,
+}
+
+impl CreateViewStmt {
+    /// Creates a `CreateViewStmt`, the optional fields are unset.
+    pub fn new(name: ObjectName, options: Vec<ViewOption>, query: Query) -> Self {
+        Self {
+            or_replace: false,
+            algorithm: None,
+            definer: None,
+            sql_security: None,
+            recursive: false,
+            if_not_exists: false,
+            name,
+            columns: None,
+            options,
+            query: Box::new(query),
+            check_option: None,
+        }
+    }
+
+    /// Sets the `or_replace` flag.
+    pub fn with_or_replace(mut self, or_replace: bool) -> Self {
+        self.or_replace = or_replace;
+        self
+    }
+
+    /// Sets the `algorithm`.
+    pub fn with_algorithm(mut self, algorithm: ViewAlgorithm) -> Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Sets the `definer`.
+    pub fn with_definer(mut self, definer: UserName) -> Self {
+        self.definer = Some(definer);
+        self
+    }
+
+    /// Sets the `sql_security`.
+    pub fn with_sql_security(mut self, sql_security: SqlSecurity) -> Self {
+        self.sql_security = Some(sql_security);
+        self
+    }
+
+    /// Sets the `recursive` flag.
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Sets the `columns`.
+    pub fn with_columns(mut self, columns: Vec<Ident>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Sets the `check_option`.
+    pub fn with_check_option(mut self, check_option: ViewCheckOption) -> Self {
+        self.check_option = Some(check_option);
+        self
+    }
+
+    /// Flag indicates that if a view of the same name already exists, the old one will be replaced.
+    ///
+    /// **NOTE: PostgreSQL/MySQL specific**
+    pub fn or_replace(&self) -> bool {
+        self.or_replace
+    }
+
+    /// The algorithm to process the view.
+    ///
+    /// **NOTE: MySQL specific**
+    pub fn algorithm(&self) -> Option<ViewAlgorithm> {
+        self.algorithm
+    }
+
+    /// The account used to check the access privileges of the view.
+    ///
+    /// **NOTE: MySQL specific**
+    pub fn definer(&self) -> Option<&UserName> {
+        self.definer.as_ref()
+    }
+
+    /// The security context of the view.
+    ///
+    /// **NOTE: MySQL specific**
+    pub fn sql_security(&self) -> Option<SqlSecurity> {
+        self.sql_security
+    }
+
+    /// Flag indicates that if the view is a recursive view.
+    ///
+    /// **NOTE: MySQL/SQLite not support**
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// Flag indicates that check if the view does not exists.
+    ///
+    /// **NOTE: SQLite specific**
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Viewed table name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Viewed columns.
+    pub fn columns(&self) -> Option<&[Ident]> {
+        self.columns.as_deref()
+    }
+
+    /// View options, like `security_barrier`.
+    ///
+    /// **NOTE: PostgreSQL specific**
+    pub fn options(&self) -> &[ViewOption] {
+        &self.options
+    }
+
+    /// A SQL query that specifies what to view.
+    pub fn query(&self) -> &Query {
+        &self.query
+    }
+
+    /// Check option.
+    ///
+    /// **NOTE: SQLite not support**
+    pub fn check_option(&self) -> Option<ViewCheckOption> {
+        self.check_option
+    }
+}
+
+impl fmt::Display for CreateViewStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CREATE ")?;
+        if self.or_replace {
+            f.write_str("OR REPLACE ")?;
+        }
+        if let Some(algorithm) = &self.algorithm {
+            write!(f, "ALGORITHM = {} ", algorithm)?;
+        }
+        if let Some(definer) = &self.definer {
+            write!(f, "DEFINER = {} ", definer)?;
+        }
+        if let Some(sql_security) = &self.sql_security {
+            write!(f, "SQL SECURITY {} ", sql_security)?;
+        }
+        write!(
+            f,
+            "{recursive}VIEW {if_not_exists}{view_name}",
+            recursive = if self.recursive { "RECURSIVE " } else { "" },
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            view_name = self.name
+        )?;
+        if let Some(columns) = &self.columns {
+            write!(f, " ({})", display_comma_separated(columns))?;
+        }
+        if !self.options.is_empty() {
+            write!(f, " WITH ({})", display_comma_separated(&self.options))?;
+        }
+        write!(f, " AS {}", self.query)?;
+        if let Some(option) = &self.check_option {
+            f.write_str(match option {
+                ViewCheckOption::Cascaded => " WITH CASCADED CHECK OPTION",
+                ViewCheckOption::Local => " WITH LOCAL CHECK OPTION",
+                ViewCheckOption::None => " WITH CHECK OPTION",
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// The algorithm to process a view. (MySQL specific)
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ViewAlgorithm {
+    Undefined,
+    Merge,
+    TempTable,
+}
+
+impl fmt::Display for ViewAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Undefined => "UNDEFINED",
+            Self::Merge => "MERGE",
+            Self::TempTable => "TEMPTABLE",
+        })
+    }
+}
+
+/// The security context of a view. (MySQL specific)
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum SqlSecurity {
+    Definer,
+    Invoker,
+}
+
+impl fmt::Display for SqlSecurity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Definer => "DEFINER",
+            Self::Invoker => "INVOKER",
+        })
+    }
+}
+
+/// The user name of an account. (MySQL specific)
+///
+/// ```txt
+/// <user name> ::= <user> [ @ <host> ] | CURRENT_USER [ ( ) ]
+/// ```
+///
+/// The string values are kept as single quoted identifiers.
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct UserName {
+    /// The user part of the name, or `CURRENT_USER`.
+    pub(crate) user: Ident,
+    /// The host part of the name.
+    pub(crate) host: Option<Ident>,
+}
+
+impl UserName {
+    /// Creates a `UserName`, the optional fields are unset.
+    pub fn new(user: Ident) -> Self {
+        Self { user, host: None }
+    }
+
+    /// Sets the `host`.
+    pub fn with_host(mut self, host: Ident) -> Self {
+        self.host = Some(host);
+        self
+    }
+
+    /// The user part of the name, or `CURRENT_USER`.
+    pub fn user(&self) -> &Ident {
+        &self.user
+    }
+
+    /// The host part of the name.
+    pub fn host(&self) -> Option<&Ident> {
+        self.host.as_ref()
+    }
+}
+
+impl fmt::Display for UserName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.user)?;
+        if let Some(host) = &self.host {
+            write!(f, "@{}", host)?;
+        }
+        Ok(())
+    }
+}
+
+/// The option of a view, like `security_barrier` or `check_option = local`. (PostgreSQL specific)
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ViewOption {
+    /// Option name.
+    pub(crate) name: Ident,
+    /// Option value.
+    pub(crate) value: Option<Box<Expr>>,
+}
+
+impl ViewOption {
+    /// Creates a `ViewOption`, the optional fields are unset.
+    pub fn new(name: Ident) -> Self {
+        Self { name, value: None }
+    }
+
+    /// Sets the `value`.
+    pub fn with_value(mut self, value: Expr) -> Self {
+        self.value = Some(Box::new(value));
+        self
+    }
+
+    /// Option name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Option value.
+    pub fn value(&self) -> Option<&Expr> {
+        self.value.as_deref()
+    }
+}
+
+impl fmt::Display for ViewOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(value) = &self.value {
+            write!(f, " = {}", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// This option controls the behavior of automatically updatable views.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ViewCheckOption {
+    Cascaded,
+    Local,
+    None,
+}
+
+impl fmt::Display for ViewCheckOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Cascaded => "CASCADED",
+            Self::Local => "LOCAL",
+            Self::None => "",
+        })
+    }
+}
+
+/// The `ALTER VIEW` statement. (Not ANSI SQL)
+///
+/// ```txt
+/// // MySQL
+/// ALTER VIEW <view name> [ ( <column name> [, ...] ) ] AS <query expression>
+/// // PostgreSQL
+/// ALTER VIEW [ IF EXISTS ] <view name> RENAME TO <new view name>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AlterViewStmt {
+    /// Flag indicates that check if the view exists.
+    pub(crate) if_exists: bool,
+    /// Viewed table name.
+    pub(crate) name: ObjectName,
+    /// Alter action.
+    pub(crate) action: AlterViewAction,
+}
+
+impl AlterViewStmt {
+    /// Creates a `AlterViewStmt`, the flags are unset.
+    pub fn new(name: ObjectName, action: AlterViewAction) -> Self {
+        Self {
+            if_exists: false,
+            name,
+            action,
+        }
+    }
+
+    /// Sets the `if_exists` flag.
+    pub fn with_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    /// Flag indicates that check if the view exists.
+    pub fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
+    /// Viewed table name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Alter action.
+    pub fn action(&self) -> &AlterViewAction {
+        &self.action
+    }
+}
+
+impl fmt::Display for AlterViewStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ALTER VIEW {if_exists}{name} {action}",
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            name = self.name,
+            action = self.action,
+        )
+    }
+}
+
+/// The alter action of `ALTER VIEW` statement.
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AlterViewAction {
+    /// Redefines the view.
+    As {
+        /// Viewed columns.
+        columns: Option<Vec<Ident>>,
+        /// A SQL query that specifies what to view.
+        query: Box<Query>,
+    },
+    /// Renames the view.
+    RenameTo(Ident),
+}
+
+impl fmt::Display for AlterViewAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::As { columns, query } => {
+                if let Some(columns) = columns {
+                    write!(f, "({}) ", display_comma_separated(columns))?;
+                }
+                write!(f, "AS {}", query)
+            }
+            Self::RenameTo(name) => write!(f, "RENAME TO {}", name),
+        }
+    }
+}
+
+// ============================================================================
+// Domain definition and manipulation
+// ============================================================================
+
+/// The `CREATE DOMAIN` statement.
+///
+/// ```txt
+/// <domain definition> ::= CREATE DOMAIN <domain name> [ AS ] <predefined type> [ <domain constraint> [, ...] ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateDomainStmt {
+    /// Domain name.
+    pub(crate) name: ObjectName,
+    /// Data type.
+    pub(crate) data_type: DataType,
+    /// Domain constraints.
+    pub(crate) constraints: Vec<DomainConstraintDef>,
+}
+
+impl CreateDomainStmt {
+    /// Creates a `CreateDomainStmt`.
+    pub fn new(
+        name: ObjectName,
+        data_type: DataType,
+        constraints: Vec<DomainConstraintDef>,
+    ) -> Self {
+        Self {
+            name,
+            data_type,
+            constraints,
+        }
+    }
+
+    /// Domain name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Data type.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Domain constraints.
+    pub fn constraints(&self) -> &[DomainConstraintDef] {
+        &self.constraints
+    }
+}
+
+impl fmt::Display for CreateDomainStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CREATE DOMAIN {domain_name} AS {data_type}",
+            domain_name = self.name,
+            data_type = self.data_type,
+        )?;
+        if !self.constraints.is_empty() {
+            write!(f, " {}", display_separated(&self.constraints, " "))?;
+        }
+        Ok(())
+    }
+}
+
+/// SQL domain constraint definition.
+///
+/// ```txt
+/// <domain constraint> ::= [ CONSTRAINT <constraint name> ] <domain constraint definition>
+/// ```
+pub type DomainConstraintDef = ConstraintDef<DomainConstraint>;
+
+/// SQL domain constraint kind.
+///
+/// ```txt
+/// <domain constraint definition> ::=
+///     NULL
+///     | NOT NULL
+///     | <check constraint definition>
+///     | <default definition>
+///     | <collation specification>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum DomainConstraint {
+    /// `NULL`
+    Null,
+    /// `NOT NULL`
+    NotNull,
+    /// `CHECK (<search condition>)`
+    Check(Box<Expr>),
+    /// `DEFAULT <literal>`
+    Default(Literal),
+    /// `COLLATE <collation name>`
+    Collation(ObjectName),
+}
+
+impl fmt::Display for DomainConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("NULL"),
+            Self::NotNull => f.write_str("NOT NULL"),
+            Self::Default(default) => write!(f, "DEFAULT {}", default),
+            Self::Collation(collation) => write!(f, "COLLATE {}", collation),
+            Self::Check(expr) => write!(f, "CHECK ({})", expr),
+        }
+    }
+}
+
+/// The `ALTER DOMAIN` statement.
+///
+/// ```txt
+/// ALTER DOMAIN <domain name> <action>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AlterDomainStmt {
+    /// Domain name.
+    pub(crate) name: ObjectName,
+    /// Alter action.
+    pub(crate) action: AlterDomainAction,
+}
+
+impl AlterDomainStmt {
+    /// Creates a `AlterDomainStmt`.
+    pub fn new(name: ObjectName, action: AlterDomainAction) -> Self {
+        Self { name, action }
+    }
+
+    /// Domain name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Alter action.
+    pub fn action(&self) -> &AlterDomainAction {
+        &self.action
+    }
+}
+
+impl fmt::Display for AlterDomainStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER DOMAIN {} {}", self.name, self.action)
+    }
+}
+
+/// The alter action of `ALTER DOMAIN` statement.
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AlterDomainAction {
+    SetDefault(Literal),
+    DropDefault,
+    AddConstraint(DomainConstraintDef),
+    DropConstraint(Ident),
+}
+
+impl fmt::Display for AlterDomainAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SetDefault(default) => write!(f, "SET DEFAULT {}", default),
+            Self::DropDefault => f.write_str("DROP DEFAULT"),
+            Self::AddConstraint(constraint) => write!(f, "ADD {}", constraint),
+            Self::DropConstraint(name) => write!(f, "DROP CONSTRAINT {}", name),
+        }
+    }
+}
+
+// ============================================================================
+// User-defined type definition and manipulation
+// ============================================================================
+
+/// The `CREATE TYPE` statement.
+///
+/// ```txt
+/// <user-defined type definition> ::= CREATE TYPE <user-defined type body>
+///
+/// <user-defined type body> ::= <user-defined type name>
+///     [ UNDER <super type name> ]
+///     [ AS <representation> ]
+///     [ <type option> [, ...] ]
+///     [ <method specification> [, ...] ]
+///
+/// // Not support now
+/// <method specification> ::= <original method specification> | <overriding method specification>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateTypeStmt {
+    /// Type name.
+    pub(crate) name: ObjectName,
+    /// Super type name.
+    pub(crate) super_name: Option<ObjectName>,
+    /// Type representation.
+    pub(crate) representation: Option<TypeRepresentation>,
+    /// Type options.
+    pub(crate) options: Option<Vec<TypeOption>>,
+}
+
+impl CreateTypeStmt {
+    /// Creates a `CreateTypeStmt`, the optional fields are unset.
+    pub fn new(name: ObjectName) -> Self {
+        Self {
+            name,
+            super_name: None,
+            representation: None,
+            options: None,
+        }
+    }
+
+    /// Sets the `super_name`.
+    pub fn with_super_name(mut self, super_name: ObjectName) -> Self {
+        self.super_name = Some(super_name);
+        self
+    }
+
+    /// Sets the `representation`.
+    pub fn with_representation(mut self, representation: TypeRepresentation) -> Self {
+        self.representation = Some(representation);
+        self
+    }
+
+    /// Sets the `options`.
+    pub fn with_options(mut self, options: Vec<TypeOption>) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Type name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Super type name.
+    pub fn super_name(&self) -> Option<&ObjectName> {
+        self.super_name.as_ref()
+    }
+
+    /// Type representation.
+    pub fn representation(&self) -> Option<&TypeRepresentation> {
+        self.representation.as_ref()
+    }
+
+    /// Type options.
+    pub fn options(&self) -> Option<&[TypeOption]> {
+        self.options.as_deref()
+    }
+}
+
+impl fmt::Display for CreateTypeStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CREATE TYPE {}", self.name)?;
+        if let Some(super_type) = &self.super_name {
+            write!(f, " UNDER {}", super_type)?;
+        }
+        if let Some(representation) = &self.representation {
+            write!(f, " AS {}", representation)?;
+        }
+        Ok(())
+    }
+}
+
+/// The representation of user-defined type
+///
+/// ```txt
+/// <representation> ::= <predefined type> | <collection type> | ( <attribution definition> [, ...] )
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TypeRepresentation {
+    DataType(DataType),
+    Attributes(Vec<TypeAttributeDef>),
+}
+
+impl fmt::Display for TypeRepresentation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DataType(ty) => write!(f, "{}", ty),
+            Self::Attributes(attrs) => write!(f, "{}", display_comma_separated(attrs)),
+        }
+    }
+}
+
+/// The user-defined type option.
+///
+/// ```txt
+/// <type option> ::=
+///     { INSTANTIABLE | NOT INSTANTIABLE }
+///     | { FINAL | NOT FINAL }
+///     | { REF USING <predefined type> | REF FROM ( <attribute name> [, ...] ) | REF IS SYSTEM GENERATED }
+///     | CAST ( SOURCE AS REF ) WITH <cast to ref identifier>
+///     | CAST ( REF AS SOURCE ) WITH <cast to type identifier>
+///     | CAST ( SOURCE AS DISTINCT ) WITH <cast to distinct identifier>
+///     | CAST ( DISTINCT AS SOURCE ) WITH <cast to source identifier>
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TypeOption {
+    Instantiable(bool),
+    Final(bool),
+    RefUsing(DataType),
+    RefFrom(Vec<Ident>),
+    RefIsSystemGenerated,
+    CastToRef(Ident),
+    CastToType(Ident),
+    CastToDistinct(Ident),
+    CastToSource(Ident),
+}
+
+impl fmt::Display for TypeOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Instantiable(negated) => {
+                write!(f, "{}INSTANTIABLE", if *negated { "NOT " } else { "" })
+            }
+            Self::Final(negated) => write!(f, "{}FINAL", if *negated { "NOT " } else { "" }),
+            Self::RefUsing(ty) => write!(f, "REF USING {}", ty),
+            Self::RefFrom(attrs) => write!(f, "REF FROM ({})", display_comma_separated(attrs)),
+            Self::RefIsSystemGenerated => write!(f, "REF IS SYSTEM GENERATED"),
+            Self::CastToRef(ident) => write!(f, "CAST ( SOURCE AS REF ) WITH {}", ident),
+            Self::CastToType(ident) => write!(f, "CAST ( REF AS SOURCE ) WITH {}", ident),
+            Self::CastToDistinct(ident) => write!(f, "CAST ( SOURCE AS DISTINCT ) WITH {}", ident),
+            Self::CastToSource(ident) => write!(f, "CAST ( DISTINCT AS SOURCE ) WITH {}", ident),
+        }
+    }
+}
+
+/// The attribute definition of user-defined type.
+///
+/// ```txt
+/// <attribute definition> ::= <attribute name> <data type> [ <default clause> ] [ <collate clause> ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct TypeAttributeDef {
+    /// Attribute name.
+    pub(crate) name: Ident,
+    /// Data type.
+    pub(crate) data_type: DataType,
+    /// Default definition.
+    pub(crate) default: Option<Literal>,
+    /// Collation specification.
+    pub(crate) collation: Option<ObjectName>,
+}
+
+impl TypeAttributeDef {
+    /// Creates a `TypeAttributeDef`, the optional fields are unset.
+    pub fn new(name: Ident, data_type: DataType) -> Self {
+        Self {
+            name,
+            data_type,
+            default: None,
+            collation: None,
+        }
+    }
+
+    /// Sets the `default`.
+    pub fn with_default(mut self, default: Literal) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Sets the `collation`.
+    pub fn with_collation(mut self, collation: ObjectName) -> Self {
+        self.collation = Some(collation);
+        self
+    }
+
+    /// Attribute name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Data type.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Default definition.
+    pub fn default(&self) -> Option<&Literal> {
+        self.default.as_ref()
+    }
+
+    /// Collation specification.
+    pub fn collation(&self) -> Option<&ObjectName> {
+        self.collation.as_ref()
+    }
+}
+
+impl fmt::Display for TypeAttributeDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT {}", default)?;
+        }
+        if let Some(collation) = &self.collation {
+            write!(f, " COLLATE {}", collation)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `ALTER TYPE` statement.
+///
+/// ```txt
+/// <alter type statement> ::= ALTER TYPE <user-defined type name> <alter type action>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AlterTypeStmt {
+    /// Type name.
+    pub(crate) name: ObjectName,
+    /// Alter action.
+    pub(crate) action: AlterTypeAction,
+}
+
+impl AlterTypeStmt {
+    /// Creates a `AlterTypeStmt`.
+    pub fn new(name: ObjectName, action: AlterTypeAction) -> Self {
+        Self { name, action }
+    }
+
+    /// Type name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Alter action.
+    pub fn action(&self) -> &AlterTypeAction {
+        &self.action
+    }
+}
+
+impl fmt::Display for AlterTypeStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER TYPE {} {}", self.name, self.action)
+    }
+}
+
+/// The alter action of `ALTER TYPE` statement.
+///
+/// ```txt
+/// <alter type action> ::=
+///     <add attribute definition>
+///     | <drop attribute definition>
+///     | <add original method specification>
+///     | <add overriding method specification>
+///     | <drop method specification>
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AlterTypeAction {
+    AddAttribute(TypeAttributeDef),
+    DropAttribute(Ident),
+}
+
+impl fmt::Display for AlterTypeAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddAttribute(attr) => write!(f, "ADD ATTRIBUTE {}", attr),
+            Self::DropAttribute(name) => write!(f, "DROP ATTRIBUTE {} RESTRICT", name),
+        }
+    }
+}
+
+// ============================================================================
+// Schema manipulation
+// ============================================================================
+
+/// The `ALTER SCHEMA` statement. (Not ANSI SQL)
+///
+/// ```txt
+/// ALTER SCHEMA <schema name> { RENAME TO <new schema name> | OWNER TO <new owner> }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AlterSchemaStmt {
+    /// Schema name.
+    pub(crate) name: ObjectName,
+    /// Alter action.
+    pub(crate) action: AlterSchemaAction,
+}
+
+impl AlterSchemaStmt {
+    /// Creates a `AlterSchemaStmt`.
+    pub fn new(name: ObjectName, action: AlterSchemaAction) -> Self {
+        Self { name, action }
+    }
+
+    /// Schema name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Alter action.
+    pub fn action(&self) -> &AlterSchemaAction {
+        &self.action
+    }
+}
+
+impl fmt::Display for AlterSchemaStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ALTER SCHEMA {} {}", self.name, self.action)
+    }
+}
+
+/// The alter action of `ALTER SCHEMA` statement.
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AlterSchemaAction {
+    /// Renames the schema.
+    RenameTo(Ident),
+    /// Changes the owner of the schema.
+    OwnerTo(Ident),
+}
+
+impl fmt::Display for AlterSchemaAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RenameTo(name) => write!(f, "RENAME TO {}", name),
+            Self::OwnerTo(owner) => write!(f, "OWNER TO {}", owner),
+        }
+    }
+}
+
+// ============================================================================
+// Index manipulation
+// ============================================================================
+
+/// The `ALTER INDEX` statement. (Not ANSI SQL)
+///
+/// ```txt
+/// ALTER INDEX [ IF EXISTS ] <index name> <alter index action>
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct AlterIndexStmt {
+    /// Flag indicates that check if the index exists.
+    pub(crate) if_exists: bool,
+    /// Index name.
+    pub(crate) name: ObjectName,
+    /// Alter action.
+    pub(crate) action: AlterIndexAction,
+}
+
+impl AlterIndexStmt {
+    /// Creates a `AlterIndexStmt`, the flags are unset.
+    pub fn new(name: ObjectName, action: AlterIndexAction) -> Self {
+        Self {
+            if_exists: false,
+            name,
+            action,
+        }
+    }
+
+    /// Sets the `if_exists` flag.
+    pub fn with_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    /// Flag indicates that check if the index exists.
+    pub fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
+    /// Index name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Alter action.
+    pub fn action(&self) -> &AlterIndexAction {
+        &self.action
+    }
+}
+
+impl fmt::Display for AlterIndexStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ALTER INDEX {if_exists}{name} {action}",
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            name = self.name,
+            action = self.action,
+        )
+    }
+}
+
+/// The alter action of `ALTER INDEX` statement.
+///
+/// ```txt
+/// <alter index action> ::=
+///     RENAME TO <new index name>
+///     | SET TABLESPACE <tablespace name>
+///     | SET ( <storage parameter> = <value> [, ...] )
+/// ```
+#[doc(hidden)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum AlterIndexAction {
+    /// Renames the index.
+    RenameTo(Ident),
+    /// Moves the index to the tablespace.
+    SetTablespace(Ident),
+    /// Changes the storage parameters of the index.
+    SetParameters(Vec<Assignment>),
+}
+
+impl fmt::Display for AlterIndexAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RenameTo(name) => write!(f, "RENAME TO {}", name),
+            Self::SetTablespace(name) => write!(f, "SET TABLESPACE {}", name),
+            Self::SetParameters(params) => write!(f, "SET ({})", display_comma_separated(params)),
+        }
+    }
+}
+
+// ============================================================================
+// Sequence generator definition
+// ============================================================================
+
+/// The `CREATE SEQUENCE` statement.
+///
+/// ```txt
+/// <sequence generator definition> ::=
+///     CREATE [ OR REPLACE ] SEQUENCE [ IF NOT EXISTS ] <sequence generator name>
+///         [ <sequence generator option> [...] ]
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateSequenceStmt {
+    /// Flag indicates that replace the sequence if it exists. (Non-standard)
+    pub(crate) or_replace: bool,
+    /// Flag indicates that check if the sequence does not exists. (Non-standard)
+    pub(crate) if_not_exists: bool,
+    /// Sequence name.
+    pub(crate) name: ObjectName,
+    /// Sequence generator options.
+    pub(crate) options: Vec<SequenceOption>,
+}
+
+impl CreateSequenceStmt {
+    /// Creates a `CreateSequenceStmt`, the flags are unset.
+    pub fn new(name: ObjectName, options: Vec<SequenceOption>) -> Self {
+        Self {
+            or_replace: false,
+            if_not_exists: false,
+            name,
+            options,
+        }
+    }
+
+    /// Sets the `or_replace` flag.
+    pub fn with_or_replace(mut self, or_replace: bool) -> Self {
+        self.or_replace = or_replace;
+        self
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Flag indicates that replace the sequence if it exists. (Non-standard)
+    pub fn or_replace(&self) -> bool {
+        self.or_replace
+    }
+
+    /// Flag indicates that check if the sequence does not exists. (Non-standard)
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Sequence name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Sequence generator options.
+    pub fn options(&self) -> &[SequenceOption] {
+        &self.options
+    }
 }
 
-impl fmt::Display for AlterTableStmt {
+impl fmt::Display for CreateSequenceStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "ALTER TABLE {if_exists}{table_name} {action}",
-            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
-            table_name = self.name,
-            action = self.action,
-        )
+            "CREATE {or_replace}SEQUENCE {if_not_exists}{name}",
+            or_replace = if self.or_replace { "OR REPLACE " } else { "" },
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            name = self.name,
+        )?;
+        if !self.options.is_empty() {
+            write!(f, " {}", display_separated(&self.options, " "))?;
+        }
+        Ok(())
     }
 }
 
-/// The alter action of table.
+/// The option of sequence generator.
 ///
 /// ```txt
-/// <alter table action> ::=
-///     <add column definition>
-///     | <alter column definition>
-///     | <drop column definition>
-///     | <add table constraint definition>
-///     | <alter table constraint definition>
-///     | <drop table constraint definition>
-///     | <add table period definition>
-///     | <drop table period definition>
-///     | <add system versioning clause>
-///     | <drop system versioning clause>
+/// <sequence generator option> ::=
+///     AS <data type>
+///     | START [ WITH ] <signed numeric literal>
+///     | INCREMENT [ BY ] <signed numeric literal>
+///     | MAXVALUE <signed numeric literal> | NO MAXVALUE | NOMAXVALUE
+///     | MINVALUE <signed numeric literal> | NO MINVALUE | NOMINVALUE
+///     | CYCLE | NO CYCLE | NOCYCLE
+///     | CACHE <unsigned integer> | NOCACHE
 /// ```
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum AlterTableAction {
-    AddColumn {
-        /// Flag indicates that check if the column does not exist. (Non-standard)
-        if_not_exists: bool,
-        /// Column definition.
-        column: ColumnDef,
-    },
-    DropColumn {
-        /// Flag indicates that check if the column exists. (Non-standard)
-        if_exists: bool,
-        /// Column name.
-        name: Ident,
-        /// Drop behavior.
-        drop_behavior: Option<DropBehavior>,
-    },
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum SequenceOption {
+    DataType(DataType),
+    StartWith(Expr),
+    IncrementBy(Expr),
+    /// `None` means `NO MAXVALUE`
+    MaxValue(Option<Expr>),
+    /// `None` means `NO MINVALUE`
+    MinValue(Option<Expr>),
+    Cycle(bool),
+    /// `None` means `NOCACHE` (Non-standard)
+    Cache(Option<Expr>),
 }
 
-impl fmt::Display for AlterTableAction {
+impl fmt::Display for SequenceOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::AddColumn {
-                if_not_exists,
-                column,
-            } => write!(
-                f,
-                "ADD COLUMN {if_not_exists}{column}",
-                if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
-                column = column,
-            ),
-            Self::DropColumn {
-                if_exists,
-                name,
-                drop_behavior,
-            } => {
-                write!(
-                    f,
-                    "DROP COLUMN {if_exists}{name}",
-                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
-                    name = name,
-                )?;
-                if let Some(behavior) = drop_behavior {
-                    write!(f, " {}", behavior)?;
-                }
-                Ok(())
-            }
+            Self::DataType(data_type) => write!(f, "AS {}", data_type),
+            Self::StartWith(value) => write!(f, "START WITH {}", value),
+            Self::IncrementBy(value) => write!(f, "INCREMENT BY {}", value),
+            Self::MaxValue(Some(value)) => write!(f, "MAXVALUE {}", value),
+            Self::MaxValue(None) => f.write_str("NO MAXVALUE"),
+            Self::MinValue(Some(value)) => write!(f, "MINVALUE {}", value),
+            Self::MinValue(None) => f.write_str("NO MINVALUE"),
+            Self::Cycle(true) => f.write_str("CYCLE"),
+            Self::Cycle(false) => f.write_str("NO CYCLE"),
+            Self::Cache(Some(value)) => write!(f, "CACHE {}", value),
+            Self::Cache(None) => f.write_str("NOCACHE"),
         }
     }
 }
 
 // ============================================================================
-// View definition and manipulation
+// Database definition (Not ANSI SQL)
 // ============================================================================
 
-/// The `CREATE VIEW` statement.
+/// The `CREATE DATABASE` statement.
 ///
 /// ```txt
-/// <view definition> ::= CREATE [ RECURSIVE ] VIEW <table name> <view specification>
-///     AS <query expression>  [ WITH [ CASCADED | LOCAL ] CHECK OPTION ]
+/// <database definition> ::= CREATE DATABASE [ IF NOT EXISTS ] <database name> [ <database option> [...] ]
 /// ```
-#[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct CreateViewStmt {
-    /// Flag indicates that if a view of the same name already exists, the old one will be replaced.
-    ///
-    /// **NOTE: PostgreSQL/MySQL specific**
-    pub or_replace: bool,
-    /// Flag indicates that if the view is a recursive view.
-    ///
-    /// **NOTE: MySQL/SQLite not support**
-    pub recursive: bool,
-    /// Flag indicates that check if the view does not exists.
-    ///
-    /// **NOTE: SQLite specific**
-    pub if_not_exists: bool,
-    /// Viewed table name.
-    pub name: ObjectName,
-    /// Viewed columns.
-    pub columns: Option<Vec<Ident>>,
-    /// A SQL query that specifies what to view.
-    pub query: Box<Query>,
-    /// Check option.
-    ///
-    /// **NOTE: SQLite not support**
-    pub check_option: Option<ViewCheckOption>,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateDatabaseStmt {
+    /// Flag indicates that check if the database does not exists.
+    pub(crate) if_not_exists: bool,
+    /// Database name.
+    pub(crate) name: ObjectName,
+    /// Database options.
+    pub(crate) options: Vec<DatabaseOption>,
 }
 
-impl fmt::Display for CreateViewStmt {
+impl CreateDatabaseStmt {
+    /// Creates a `CreateDatabaseStmt`, the flags are unset.
+    pub fn new(name: ObjectName, options: Vec<DatabaseOption>) -> Self {
+        Self {
+            if_not_exists: false,
+            name,
+            options,
+        }
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Flag indicates that check if the database does not exists.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Database name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Database options.
+    pub fn options(&self) -> &[DatabaseOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for CreateDatabaseStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "CREATE {or_replace}{recursive} VIEW {if_not_exists}{view_name}",
-            or_replace = if self.or_replace { "OR REPLACE " } else { "" },
-            recursive = if self.recursive { "RECURSIVE " } else { "" },
+            "CREATE DATABASE {if_not_exists}{name}",
             if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
-            view_name = self.name
+            name = self.name,
         )?;
-        if let Some(columns) = &self.columns {
-            write!(f, " ({})", display_comma_separated(columns))?;
-        }
-        write!(f, " AS {}", self.query)?;
-        if let Some(option) = &self.check_option {
-            f.write_str(match option {
-                ViewCheckOption::Cascaded => " WITH CASCADED CHECK OPTION",
-                ViewCheckOption::Local => " WITH LOCAL CHECK OPTION",
-                ViewCheckOption::None => " WITH CHECK OPTION",
-            })?;
+        if !self.options.is_empty() {
+            write!(f, " {}", display_separated(&self.options, " "))?;
         }
         Ok(())
     }
 }
 
-/// This option controls the behavior of automatically updatable views.
+/// The option of `CREATE DATABASE` statement.
+///
+/// ```txt
+/// // MySQL
+/// <database option> ::=
+///     [ DEFAULT ] { CHARACTER SET | CHARSET } [ = ] <charset name>
+///     | [ DEFAULT ] COLLATE [ = ] <collation name>
+///
+/// // PostgreSQL
+/// <database option> ::=
+///     [ WITH ] OWNER [ = ] <user name>
+///     | TEMPLATE [ = ] <template>
+///     | ENCODING [ = ] <encoding>
+///     | LC_COLLATE [ = ] <lc collate>
+///     | LC_CTYPE [ = ] <lc ctype>
+/// ```
+///
+/// The values are kept as identifiers, the string values are single quoted identifiers.
 #[doc(hidden)]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum ViewCheckOption {
-    Cascaded,
-    Local,
-    None,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum DatabaseOption {
+    CharacterSet(Ident),
+    Collate(Ident),
+    Owner(Ident),
+    Template(Ident),
+    Encoding(Ident),
+    LcCollate(Ident),
+    LcCtype(Ident),
 }
 
-impl fmt::Display for ViewCheckOption {
+impl fmt::Display for DatabaseOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Self::Cascaded => "CASCADED",
-            Self::Local => "LOCAL",
-            Self::None => "",
-        })
+        match self {
+            Self::CharacterSet(charset) => write!(f, "CHARACTER SET {}", charset),
+            Self::Collate(collation) => write!(f, "COLLATE {}", collation),
+            Self::Owner(owner) => write!(f, "OWNER {}", owner),
+            Self::Template(template) => write!(f, "TEMPLATE {}", template),
+            Self::Encoding(encoding) => write!(f, "ENCODING {}", encoding),
+            Self::LcCollate(collate) => write!(f, "LC_COLLATE {}", collate),
+            Self::LcCtype(ctype) => write!(f, "LC_CTYPE {}", ctype),
+        }
     }
 }
 
 // ============================================================================
-// Domain definition and manipulation
+// Foreign data definition (PostgreSQL specific)
 // ============================================================================
 
-/// The `CREATE DOMAIN` statement.
+/// The `CREATE SERVER` statement.
 ///
 /// ```txt
-/// <domain definition> ::= CREATE DOMAIN <domain name> [ AS ] <predefined type> [ <domain constraint> [, ...] ]
+/// CREATE SERVER [ IF NOT EXISTS ] <server name> [ TYPE <server type> ] [ VERSION <server version> ]
+///     FOREIGN DATA WRAPPER <fdw name>
+///     [ OPTIONS ( <option> [, ...] ) ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct CreateDomainStmt {
-    /// Domain name.
-    pub name: ObjectName,
-    /// Data type.
-    pub data_type: DataType,
-    /// Domain constraints.
-    pub constraints: Vec<DomainConstraintDef>,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateServerStmt {
+    /// Flag indicates that check if the server does not exist.
+    pub(crate) if_not_exists: bool,
+    /// Server name.
+    pub(crate) name: Ident,
+    /// Server type.
+    pub(crate) server_type: Option<String>,
+    /// Server version.
+    pub(crate) version: Option<String>,
+    /// The foreign-data wrapper that manages the server.
+    pub(crate) wrapper: Ident,
+    /// Server options.
+    pub(crate) options: Vec<FdwOption>,
 }
 
-impl fmt::Display for CreateDomainStmt {
+impl CreateServerStmt {
+    /// Creates a `CreateServerStmt`, the optional fields are unset.
+    pub fn new(name: Ident, wrapper: Ident, options: Vec<FdwOption>) -> Self {
+        Self {
+            if_not_exists: false,
+            name,
+            server_type: None,
+            version: None,
+            wrapper,
+            options,
+        }
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Sets the `server_type`.
+    pub fn with_server_type(mut self, server_type: String) -> Self {
+        self.server_type = Some(server_type);
+        self
+    }
+
+    /// Sets the `version`.
+    pub fn with_version(mut self, version: String) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Flag indicates that check if the server does not exist.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Server name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Server type.
+    pub fn server_type(&self) -> Option<&str> {
+        self.server_type.as_deref()
+    }
+
+    /// Server version.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// The foreign-data wrapper that manages the server.
+    pub fn wrapper(&self) -> &Ident {
+        &self.wrapper
+    }
+
+    /// Server options.
+    pub fn options(&self) -> &[FdwOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for CreateServerStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "CREATE DOMAIN {domain_name} AS {data_type}",
-            domain_name = self.name,
-            data_type = self.data_type,
+            "CREATE SERVER {if_not_exists}{name}",
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            name = self.name,
         )?;
-        if !self.constraints.is_empty() {
-            write!(f, " {}", display_separated(&self.constraints, " "))?;
+        if let Some(server_type) = &self.server_type {
+            write!(f, " TYPE '{}'", escape_single_quote_string(server_type))?;
+        }
+        if let Some(version) = &self.version {
+            write!(f, " VERSION '{}'", escape_single_quote_string(version))?;
+        }
+        write!(f, " FOREIGN DATA WRAPPER {}", self.wrapper)?;
+        if !self.options.is_empty() {
+            write!(f, " OPTIONS ({})", display_comma_separated(&self.options))?;
         }
         Ok(())
     }
 }
 
-/// SQL domain constraint definition.
+/// The `CREATE USER MAPPING` statement.
 ///
 /// ```txt
-/// <domain constraint> ::= [ CONSTRAINT <constraint name> ] <domain constraint definition>
+/// CREATE USER MAPPING [ IF NOT EXISTS ] FOR { <user name> | USER | CURRENT_USER | PUBLIC }
+///     SERVER <server name>
+///     [ OPTIONS ( <option> [, ...] ) ]
 /// ```
-pub type DomainConstraintDef = ConstraintDef<DomainConstraint>;
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateUserMappingStmt {
+    /// Flag indicates that check if the mapping does not exist.
+    pub(crate) if_not_exists: bool,
+    /// The mapped user, like `PUBLIC` or `CURRENT_USER`.
+    pub(crate) user: Ident,
+    /// Server name.
+    pub(crate) server: Ident,
+    /// User mapping options.
+    pub(crate) options: Vec<FdwOption>,
+}
 
-/// SQL domain constraint kind.
+impl CreateUserMappingStmt {
+    /// Creates a `CreateUserMappingStmt`, the flags are unset.
+    pub fn new(user: Ident, server: Ident, options: Vec<FdwOption>) -> Self {
+        Self {
+            if_not_exists: false,
+            user,
+            server,
+            options,
+        }
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Flag indicates that check if the mapping does not exist.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// The mapped user, like `PUBLIC` or `CURRENT_USER`.
+    pub fn user(&self) -> &Ident {
+        &self.user
+    }
+
+    /// Server name.
+    pub fn server(&self) -> &Ident {
+        &self.server
+    }
+
+    /// User mapping options.
+    pub fn options(&self) -> &[FdwOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for CreateUserMappingStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CREATE USER MAPPING {if_not_exists}FOR {user} SERVER {server}",
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            user = self.user,
+            server = self.server,
+        )?;
+        if !self.options.is_empty() {
+            write!(f, " OPTIONS ({})", display_comma_separated(&self.options))?;
+        }
+        Ok(())
+    }
+}
+
+/// The `CREATE FOREIGN TABLE` statement.
 ///
 /// ```txt
-/// <domain constraint definition> ::=
-///     NULL
-///     | NOT NULL
-///     | <check constraint definition>
-///     | <default definition>
-///     | <collation specification>
+/// CREATE FOREIGN TABLE [ IF NOT EXISTS ] <table name> ( [ <column definition> [, ...] ] )
+///     SERVER <server name>
+///     [ OPTIONS ( <option> [, ...] ) ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum DomainConstraint {
-    /// `NULL`
-    Null,
-    /// `NOT NULL`
-    NotNull,
-    /// `CHECK (<search condition>)`
-    Check(Box<Expr>),
-    /// `DEFAULT <literal>`
-    Default(Literal),
-    /// `COLLATE <collation name>`
-    Collation(ObjectName),
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateForeignTableStmt {
+    /// Flag indicates that check if the table does not exist.
+    pub(crate) if_not_exists: bool,
+    /// Table name.
+    pub(crate) name: ObjectName,
+    /// Columns.
+    pub(crate) columns: Vec<ColumnDef>,
+    /// Server name.
+    pub(crate) server: Ident,
+    /// Table options.
+    pub(crate) options: Vec<FdwOption>,
 }
 
-impl fmt::Display for DomainConstraint {
+impl CreateForeignTableStmt {
+    /// Creates a `CreateForeignTableStmt`, the flags are unset.
+    pub fn new(
+        name: ObjectName,
+        columns: Vec<ColumnDef>,
+        server: Ident,
+        options: Vec<FdwOption>,
+    ) -> Self {
+        Self {
+            if_not_exists: false,
+            name,
+            columns,
+            server,
+            options,
+        }
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Flag indicates that check if the table does not exist.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// Table name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Columns.
+    pub fn columns(&self) -> &[ColumnDef] {
+        &self.columns
+    }
+
+    /// Server name.
+    pub fn server(&self) -> &Ident {
+        &self.server
+    }
+
+    /// Table options.
+    pub fn options(&self) -> &[FdwOption] {
+        &self.options
+    }
+}
+
+impl fmt::Display for CreateForeignTableStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Null => f.write_str("NULL"),
-            Self::NotNull => f.write_str("NOT NULL"),
-            Self::Default(default) => write!(f, "DEFAULT {}", default),
-            Self::Collation(collation) => write!(f, "COLLATE {}", collation),
-            Self::Check(expr) => write!(f, "CHECK ({})", expr),
+        write!(
+            f,
+            "CREATE FOREIGN TABLE {if_not_exists}{name} ({columns}) SERVER {server}",
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            name = self.name,
+            columns = display_comma_separated(&self.columns),
+            server = self.server,
+        )?;
+        if !self.options.is_empty() {
+            write!(f, " OPTIONS ({})", display_comma_separated(&self.options))?;
         }
+        Ok(())
     }
 }
 
-/// The `ALTER DOMAIN` statement.
+/// The `IMPORT FOREIGN SCHEMA` statement.
 ///
 /// ```txt
-/// ALTER DOMAIN <domain name> <action>
+/// IMPORT FOREIGN SCHEMA <remote schema> [ { LIMIT TO | EXCEPT } ( <table name> [, ...] ) ]
+///     FROM SERVER <server name>
+///     INTO <local schema>
+///     [ OPTIONS ( <option> [, ...] ) ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct AlterDomainStmt {
-    /// Domain name.
-    pub name: ObjectName,
-    /// Alter action.
-    pub action: AlterDomainAction,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ImportForeignSchemaStmt {
+    /// The remote schema to import from.
+    pub(crate) remote_schema: Ident,
+    /// The foreign tables to import.
+    pub(crate) filter: Option<ImportFilter>,
+    /// Server name.
+    pub(crate) server: Ident,
+    /// The local schema to create the foreign tables in.
+    pub(crate) local_schema: Ident,
+    /// Import options.
+    pub(crate) options: Vec<FdwOption>,
 }
 
-impl fmt::Display for AlterDomainStmt {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ALTER DOMAIN {} {}", self.name, self.action)
+impl ImportForeignSchemaStmt {
+    /// Creates a `ImportForeignSchemaStmt`, the optional fields are unset.
+    pub fn new(
+        remote_schema: Ident,
+        server: Ident,
+        local_schema: Ident,
+        options: Vec<FdwOption>,
+    ) -> Self {
+        Self {
+            remote_schema,
+            filter: None,
+            server,
+            local_schema,
+            options,
+        }
     }
-}
 
-/// The alter action of `ALTER DOMAIN` statement.
-#[doc(hidden)]
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum AlterDomainAction {
-    SetDefault(Literal),
-    DropDefault,
-    AddConstraint(DomainConstraintDef),
-    DropConstraint(Ident),
-}
+    /// Sets the `filter`.
+    pub fn with_filter(mut self, filter: ImportFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
 
-impl fmt::Display for AlterDomainAction {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::SetDefault(default) => write!(f, "SET DEFAULT {}", default),
-            Self::DropDefault => f.write_str("DROP DEFAULT"),
-            Self::AddConstraint(constraint) => write!(f, "ADD {}", constraint),
-            Self::DropConstraint(name) => write!(f, "DROP CONSTRAINT {}", name),
-        }
+    /// The remote schema to import from.
+    pub fn remote_schema(&self) -> &Ident {
+        &self.remote_schema
     }
-}
 
-// ============================================================================
-// User-defined type definition and manipulation
-// ============================================================================
+    /// The foreign tables to import.
+    pub fn filter(&self) -> Option<&ImportFilter> {
+        self.filter.as_ref()
+    }
 
-/// The `CREATE TYPE` statement.
-///
-/// ```txt
-/// <user-defined type definition> ::= CREATE TYPE <user-defined type body>
-///
-/// <user-defined type body> ::= <user-defined type name>
-///     [ UNDER <super type name> ]
-///     [ AS <representation> ]
-///     [ <type option> [, ...] ]
-///     [ <method specification> [, ...] ]
-///
-/// // Not support now
-/// <method specification> ::= <original method specification> | <overriding method specification>
-/// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct CreateTypeStmt {
-    /// Type name.
-    pub name: ObjectName,
-    /// Super type name.
-    pub super_name: Option<ObjectName>,
-    /// Type representation.
-    pub representation: Option<TypeRepresentation>,
-    /// Type options.
-    pub options: Option<Vec<TypeOption>>,
+    /// Server name.
+    pub fn server(&self) -> &Ident {
+        &self.server
+    }
+
+    /// The local schema to create the foreign tables in.
+    pub fn local_schema(&self) -> &Ident {
+        &self.local_schema
+    }
+
+    /// Import options.
+    pub fn options(&self) -> &[FdwOption] {
+        &self.options
+    }
 }
 
-impl fmt::Display for CreateTypeStmt {
+impl fmt::Display for ImportForeignSchemaStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "CREATE TYPE {}", self.name)?;
-        if let Some(super_type) = &self.super_name {
-            write!(f, " UNDER {}", super_type)?;
+        write!(f, "IMPORT FOREIGN SCHEMA {}", self.remote_schema)?;
+        if let Some(filter) = &self.filter {
+            write!(f, " {}", filter)?;
         }
-        if let Some(representation) = &self.representation {
-            write!(f, " AS {}", representation)?;
+        write!(f, " FROM SERVER {} INTO {}", self.server, self.local_schema)?;
+        if !self.options.is_empty() {
+            write!(f, " OPTIONS ({})", display_comma_separated(&self.options))?;
         }
         Ok(())
     }
 }
 
-/// The representation of user-defined type
-///
-/// ```txt
-/// <representation> ::= <predefined type> | <collection type> | ( <attribution definition> [, ...] )
-/// ```
+/// The foreign tables to import by `IMPORT FOREIGN SCHEMA` statement.
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum TypeRepresentation {
-    DataType(DataType),
-    Attributes(Vec<TypeAttributeDef>),
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ImportFilter {
+    LimitTo(Vec<Ident>),
+    Except(Vec<Ident>),
 }
 
-impl fmt::Display for TypeRepresentation {
+impl fmt::Display for ImportFilter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::DataType(ty) => write!(f, "{}", ty),
-            Self::Attributes(attrs) => write!(f, "{}", display_comma_separated(attrs)),
+            Self::LimitTo(tables) => write!(f, "LIMIT TO ({})", display_comma_separated(tables)),
+            Self::Except(tables) => write!(f, "EXCEPT ({})", display_comma_separated(tables)),
         }
     }
 }
 
-/// The user-defined type option.
+/// The generic option of foreign-data objects, like `host 'localhost'`.
 ///
 /// ```txt
-/// <type option> ::=
-///     { INSTANTIABLE | NOT INSTANTIABLE }
-///     | { FINAL | NOT FINAL }
-///     | { REF USING <predefined type> | REF FROM ( <attribute name> [, ...] ) | REF IS SYSTEM GENERATED }
-///     | CAST ( SOURCE AS REF ) WITH <cast to ref identifier>
-///     | CAST ( REF AS SOURCE ) WITH <cast to type identifier>
-///     | CAST ( SOURCE AS DISTINCT ) WITH <cast to distinct identifier>
-///     | CAST ( DISTINCT AS SOURCE ) WITH <cast to source identifier>
+/// <option> ::= <option name> <option value>
 /// ```
-#[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum TypeOption {
-    Instantiable(bool),
-    Final(bool),
-    RefUsing(DataType),
-    RefFrom(Vec<Ident>),
-    RefIsSystemGenerated,
-    CastToRef(Ident),
-    CastToType(Ident),
-    CastToDistinct(Ident),
-    CastToSource(Ident),
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct FdwOption {
+    /// Option name.
+    pub(crate) name: Ident,
+    /// Option value.
+    pub(crate) value: String,
 }
 
-impl fmt::Display for TypeOption {
+impl FdwOption {
+    /// Creates a `FdwOption`.
+    pub fn new(name: Ident, value: String) -> Self {
+        Self { name, value }
+    }
+
+    /// Option name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Option value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for FdwOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Instantiable(negated) => {
-                write!(f, "{}INSTANTIABLE", if *negated { "NOT " } else { "" })
-            }
-            Self::Final(negated) => write!(f, "{}FINAL", if *negated { "NOT " } else { "" }),
-            Self::RefUsing(ty) => write!(f, "REF USING {}", ty),
-            Self::RefFrom(attrs) => write!(f, "REF FROM ({})", display_comma_separated(attrs)),
-            Self::RefIsSystemGenerated => write!(f, "REF IS SYSTEM GENERATED"),
-            Self::CastToRef(ident) => write!(f, "CAST ( SOURCE AS REF ) WITH {}", ident),
-            Self::CastToType(ident) => write!(f, "CAST ( REF AS SOURCE ) WITH {}", ident),
-            Self::CastToDistinct(ident) => write!(f, "CAST ( SOURCE AS DISTINCT ) WITH {}", ident),
-            Self::CastToSource(ident) => write!(f, "CAST ( DISTINCT AS SOURCE ) WITH {}", ident),
-        }
+        write!(
+            f,
+            "{} '{}'",
+            self.name,
+            escape_single_quote_string(&self.value)
+        )
     }
 }
 
-/// The attribute definition of user-defined type.
+// ============================================================================
+// Row-level security policy definition (PostgreSQL specific)
+// ============================================================================
+
+/// The `CREATE POLICY` statement. (PostgreSQL specific)
 ///
 /// ```txt
-/// <attribute definition> ::= <attribute name> <data type> [ <default clause> ] [ <collate clause> ]
+/// CREATE POLICY <policy name> ON <table name>
+///     [ AS { PERMISSIVE | RESTRICTIVE } ]
+///     [ FOR { ALL | SELECT | INSERT | UPDATE | DELETE } ]
+///     [ TO <role name> [, ...] ]
+///     [ USING ( <expr> ) ]
+///     [ WITH CHECK ( <expr> ) ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct TypeAttributeDef {
-    /// Attribute name.
-    pub name: Ident,
-    /// Data type.
-    pub data_type: DataType,
-    /// Default definition.
-    pub default: Option<Literal>,
-    /// Collation specification.
-    pub collation: Option<ObjectName>,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreatePolicyStmt {
+    /// Policy name.
+    pub(crate) name: Ident,
+    /// Table name.
+    pub(crate) table: ObjectName,
+    /// How the policy is combined with the other policies.
+    pub(crate) kind: Option<PolicyKind>,
+    /// The command the policy applies to.
+    pub(crate) command: Option<PolicyCommand>,
+    /// The roles the policy applies to, like `PUBLIC` or `CURRENT_USER`.
+    pub(crate) roles: Vec<Ident>,
+    /// The condition of the visible rows.
+    pub(crate) using: Option<Box<Expr>>,
+    /// The condition of the added or updated rows.
+    pub(crate) with_check: Option<Box<Expr>>,
 }
 
-impl fmt::Display for TypeAttributeDef {
+impl CreatePolicyStmt {
+    /// Creates a `CreatePolicyStmt`, the optional fields are unset.
+    pub fn new(name: Ident, table: ObjectName, roles: Vec<Ident>) -> Self {
+        Self {
+            name,
+            table,
+            kind: None,
+            command: None,
+            roles,
+            using: None,
+            with_check: None,
+        }
+    }
+
+    /// Sets the `kind`.
+    pub fn with_kind(mut self, kind: PolicyKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Sets the `command`.
+    pub fn with_command(mut self, command: PolicyCommand) -> Self {
+        self.command = Some(command);
+        self
+    }
+
+    /// Sets the `using`.
+    pub fn with_using(mut self, using: Expr) -> Self {
+        self.using = Some(Box::new(using));
+        self
+    }
+
+    /// Sets the `with_check`.
+    pub fn with_with_check(mut self, with_check: Expr) -> Self {
+        self.with_check = Some(Box::new(with_check));
+        self
+    }
+
+    /// Policy name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Table name.
+    pub fn table(&self) -> &ObjectName {
+        &self.table
+    }
+
+    /// How the policy is combined with the other policies.
+    pub fn kind(&self) -> Option<PolicyKind> {
+        self.kind
+    }
+
+    /// The command the policy applies to.
+    pub fn command(&self) -> Option<PolicyCommand> {
+        self.command
+    }
+
+    /// The roles the policy applies to, like `PUBLIC` or `CURRENT_USER`.
+    pub fn roles(&self) -> &[Ident] {
+        &self.roles
+    }
+
+    /// The condition of the visible rows.
+    pub fn using(&self) -> Option<&Expr> {
+        self.using.as_deref()
+    }
+
+    /// The condition of the added or updated rows.
+    pub fn with_check(&self) -> Option<&Expr> {
+        self.with_check.as_deref()
+    }
+}
+
+impl fmt::Display for CreatePolicyStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.name, self.data_type)?;
-        if let Some(default) = &self.default {
-            write!(f, " DEFAULT {}", default)?;
+        write!(f, "CREATE POLICY {} ON {}", self.name, self.table)?;
+        if let Some(kind) = &self.kind {
+            write!(f, " AS {}", kind)?;
         }
-        if let Some(collation) = &self.collation {
-            write!(f, " COLLATE {}", collation)?;
+        if let Some(command) = &self.command {
+            write!(f, " FOR {}", command)?;
+        }
+        if !self.roles.is_empty() {
+            write!(f, " TO {}", display_comma_separated(&self.roles))?;
+        }
+        if let Some(using) = &self.using {
+            write!(f, " USING ({})", using)?;
+        }
+        if let Some(with_check) = &self.with_check {
+            write!(f, " WITH CHECK ({})", with_check)?;
         }
         Ok(())
     }
 }
 
-/// The `ALTER TYPE` statement.
-///
-/// ```txt
-/// <alter type statement> ::= ALTER TYPE <user-defined type name> <alter type action>
-/// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+/// The kind of policy. (PostgreSQL specific)
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct AlterTypeStmt {
-    /// Type name.
-    pub name: ObjectName,
-    /// Alter action.
-    pub action: AlterTypeAction,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PolicyKind {
+    Permissive,
+    Restrictive,
 }
 
-impl fmt::Display for AlterTypeStmt {
+impl fmt::Display for PolicyKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "ALTER TYPE {} {}", self.name, self.action)
+        f.write_str(match self {
+            Self::Permissive => "PERMISSIVE",
+            Self::Restrictive => "RESTRICTIVE",
+        })
     }
 }
 
-/// The alter action of `ALTER TYPE` statement.
+/// The command a policy applies to. (PostgreSQL specific)
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum PolicyCommand {
+    All,
+    Select,
+    Insert,
+    Update,
+    Delete,
+}
+
+impl fmt::Display for PolicyCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::All => "ALL",
+            Self::Select => "SELECT",
+            Self::Insert => "INSERT",
+            Self::Update => "UPDATE",
+            Self::Delete => "DELETE",
+        })
+    }
+}
+
+/// The `DROP POLICY` statement. (PostgreSQL specific)
 ///
 /// ```txt
-/// <alter type action> ::=
-///     <add attribute definition>
-///     | <drop attribute definition>
-///     | <add original method specification>
-///     | <add overriding method specification>
-///     | <drop method specification>
+/// DROP POLICY [ IF EXISTS ] <policy name> ON <table name> [ CASCADE | RESTRICT ]
 /// ```
-#[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum AlterTypeAction {
-    AddAttribute(TypeAttributeDef),
-    DropAttribute(Ident),
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DropPolicyStmt {
+    /// Flag indicates that check if the policy exists.
+    pub(crate) if_exists: bool,
+    /// Policy name.
+    pub(crate) name: Ident,
+    /// Table name.
+    pub(crate) table: ObjectName,
+    /// Drop behavior.
+    pub(crate) behavior: Option<DropBehavior>,
 }
 
-impl fmt::Display for AlterTypeAction {
+impl DropPolicyStmt {
+    /// Creates a `DropPolicyStmt`, the optional fields are unset.
+    pub fn new(name: Ident, table: ObjectName) -> Self {
+        Self {
+            if_exists: false,
+            name,
+            table,
+            behavior: None,
+        }
+    }
+
+    /// Sets the `if_exists` flag.
+    pub fn with_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    /// Sets the `behavior`.
+    pub fn with_behavior(mut self, behavior: DropBehavior) -> Self {
+        self.behavior = Some(behavior);
+        self
+    }
+
+    /// Flag indicates that check if the policy exists.
+    pub fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
+    /// Policy name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// Table name.
+    pub fn table(&self) -> &ObjectName {
+        &self.table
+    }
+
+    /// Drop behavior.
+    pub fn behavior(&self) -> Option<DropBehavior> {
+        self.behavior
+    }
+}
+
+impl fmt::Display for DropPolicyStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::AddAttribute(attr) => write!(f, "ADD ATTRIBUTE {}", attr),
-            Self::DropAttribute(name) => write!(f, "DROP ATTRIBUTE {} RESTRICT", name),
+        write!(
+            f,
+            "DROP POLICY {if_exists}{name} ON {table}",
+            if_exists = if self.if_exists { "IF EXISTS " } else { "" },
+            name = self.name,
+            table = self.table,
+        )?;
+        if let Some(behavior) = &self.behavior {
+            write!(f, " {}", behavior)?;
         }
+        Ok(())
     }
 }
 
 // ============================================================================
-//  Drop manipulation of Schema/Table/View/Domain/Type/Index
+//  Drop manipulation of Schema/Table/View/Domain/Type/Index/Sequence
 // ============================================================================
 
-/// The `DROP { SCHEMA | TABLE | VIEW | DOMAIN | TYPE | DATABASE | INDEX } <name> ...` statement
+/// The `DROP { SCHEMA | TABLE | VIEW | DOMAIN | TYPE | DATABASE | INDEX | SEQUENCE } <name> ...` statement
 ///
 /// ```txt
 /// <drop schema statement> ::= DROP SCHEMA <schema name> [ IF EXISTS ] <drop behavior>
@@ -1005,6 +3623,7 @@ impl fmt::Display for AlterTypeAction {
 /// <drop view statement> ::= DROP VIEW <table name> [ IF EXISTS ] <drop behavior>
 /// <drop domain statement> ::= DROP DOMAIN <domain name> [ IF EXISTS ] <drop behavior>
 /// <drop data type statement> ::= DROP TYPE <type name> [ IF EXISTS ] <drop behavior>
+/// <drop sequence generator statement> ::= DROP SEQUENCE <sequence generator name> [ IF EXISTS ] <drop behavior>
 ///
 /// // Not ANSI SQL
 /// <drop database statement> ::= DROP DATABASE <database name> [ IF EXISTS ] <drop behavior>
@@ -1012,15 +3631,61 @@ impl fmt::Display for AlterTypeAction {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct DropStmt {
     /// Object type.
-    pub ty: ObjectType,
+    pub(crate) ty: ObjectType,
+    /// Flag indicates that check if the `schema/table/view/domain/type/database/index` exists. (Non-standard)
+    pub(crate) if_exists: bool,
+    /// One or more object names to drop. (ANSI SQL requires exactly one)
+    pub(crate) names: Vec<ObjectName>,
+    /// Drop behavior.
+    pub(crate) behavior: Option<DropBehavior>,
+}
+
+impl DropStmt {
+    /// Creates a `DropStmt`, the optional fields are unset.
+    pub fn new(ty: ObjectType, names: Vec<ObjectName>) -> Self {
+        Self {
+            ty,
+            if_exists: false,
+            names,
+            behavior: None,
+        }
+    }
+
+    /// Sets the `if_exists` flag.
+    pub fn with_if_exists(mut self, if_exists: bool) -> Self {
+        self.if_exists = if_exists;
+        self
+    }
+
+    /// Sets the `behavior`.
+    pub fn with_behavior(mut self, behavior: DropBehavior) -> Self {
+        self.behavior = Some(behavior);
+        self
+    }
+
+    /// Object type.
+    pub fn ty(&self) -> ObjectType {
+        self.ty
+    }
+
     /// Flag indicates that check if the `schema/table/view/domain/type/database/index` exists. (Non-standard)
-    pub if_exists: bool,
+    pub fn if_exists(&self) -> bool {
+        self.if_exists
+    }
+
     /// One or more object names to drop. (ANSI SQL requires exactly one)
-    pub names: Vec<ObjectName>,
+    pub fn names(&self) -> &[ObjectName] {
+        &self.names
+    }
+
     /// Drop behavior.
-    pub behavior: Option<DropBehavior>,
+    pub fn behavior(&self) -> Option<DropBehavior> {
+        self.behavior
+    }
 }
 
 impl fmt::Display for DropStmt {
@@ -1043,6 +3708,8 @@ impl fmt::Display for DropStmt {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum ObjectType {
     Schema,
     Table,
@@ -1051,6 +3718,7 @@ pub enum ObjectType {
     Type,
     Database,
     Index,
+    Sequence,
 }
 
 impl fmt::Display for ObjectType {
@@ -1063,6 +3731,7 @@ impl fmt::Display for ObjectType {
             Self::Type => "TYPE",
             Self::Database => "DATABASE",
             Self::Index => "INDEX",
+            Self::Sequence => "SEQUENCE",
         })
     }
 }
@@ -1071,6 +3740,8 @@ impl fmt::Display for ObjectType {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum DropBehavior {
     Cascade,
     Restrict,
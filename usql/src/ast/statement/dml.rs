@@ -7,27 +7,123 @@ use crate::ast::{expression::*, types::*, utils::display_comma_separated};
 /// The `INSERT INTO ...` statement.
 ///
 /// ```txt
-/// <insert statement> ::= INERT INTO <table name> <insert columns and source>
+/// <insert statement> ::= [ <with clause> ] INSERT INTO <table name> <insert columns and source>
 ///
 /// 1. INSERT INTO <table name> [ (column1, column2, ...) ]
 ///     [ OVERRIDING { SYSTEM | USER } VALUE ] <query expression>
 /// 2. INSERT INTO <table name> [ (column1, column2, ...) ]
 ///     [ OVERRIDING { SYSTEM | USER } VALUE ] VALUES (value1, value2, ...)
 /// 3. INSERT INTO <table name> DEFAULT VALUES
+///
+/// // SQLite
+/// INSERT [ OR <conflict resolution> ] INTO ...
+///
+/// // MySQL
+/// REPLACE INTO ...
+/// INSERT INTO <table name> SET <column name> = <expr> [, ...]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct InsertStmt {
+    /// With clause.
+    pub(crate) with: Option<With>,
+    /// Flag indicates that it's a `REPLACE INTO ...` statement. (MySQL specific)
+    pub(crate) replace: bool,
+    /// Conflict resolution algorithm. (SQLite specific)
+    pub(crate) or: Option<ConflictResolution>,
+    /// Table name.
+    pub(crate) table: ObjectName,
+    /// Columns and source.
+    pub(crate) source: InsertSource,
+    /// Returning clause. (Non-standard)
+    pub(crate) returning: Option<Vec<SelectItem>>,
+}
+
+impl InsertStmt {
+    /// Creates a `InsertStmt`, the optional fields are unset.
+    pub fn new(table: ObjectName, source: InsertSource) -> Self {
+        Self {
+            with: None,
+            replace: false,
+            or: None,
+            table,
+            source,
+            returning: None,
+        }
+    }
+
+    /// Sets the `with`.
+    pub fn with_cte(mut self, with: With) -> Self {
+        self.with = Some(with);
+        self
+    }
+
+    /// Sets the `replace` flag.
+    pub fn with_replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+
+    /// Sets the `or`.
+    pub fn with_or(mut self, or: ConflictResolution) -> Self {
+        self.or = Some(or);
+        self
+    }
+
+    /// Sets the `returning`.
+    pub fn with_returning(mut self, returning: Vec<SelectItem>) -> Self {
+        self.returning = Some(returning);
+        self
+    }
+
+    /// With clause.
+    pub fn with(&self) -> Option<&With> {
+        self.with.as_ref()
+    }
+
+    /// Flag indicates that it's a `REPLACE INTO ...` statement. (MySQL specific)
+    pub fn replace(&self) -> bool {
+        self.replace
+    }
+
+    /// Conflict resolution algorithm. (SQLite specific)
+    pub fn or(&self) -> Option<ConflictResolution> {
+        self.or
+    }
+
     /// Table name.
-    pub table: ObjectName,
+    pub fn table(&self) -> &ObjectName {
+        &self.table
+    }
+
     /// Columns and source.
-    pub source: InsertSource,
+    pub fn source(&self) -> &InsertSource {
+        &self.source
+    }
+
+    /// Returning clause. (Non-standard)
+    pub fn returning(&self) -> Option<&[SelectItem]> {
+        self.returning.as_deref()
+    }
 }
 
 impl fmt::Display for InsertStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "INSERT INTO {}", self.table)?;
-        write!(f, " {}", self.source)
+        if let Some(with) = &self.with {
+            write!(f, "{} ", with)?;
+        }
+        f.write_str(if self.replace { "REPLACE" } else { "INSERT" })?;
+        if let Some(or) = &self.or {
+            write!(f, " OR {}", or)?;
+        }
+        write!(f, " INTO {}", self.table)?;
+        write!(f, " {}", self.source)?;
+        if let Some(returning) = &self.returning {
+            write!(f, " RETURNING {}", display_comma_separated(returning))?;
+        }
+        Ok(())
     }
 }
 
@@ -45,9 +141,14 @@ impl fmt::Display for InsertStmt {
 ///     [ ( <column name> [, ...] ) ]
 ///     [ OVERRIDING { SYSTEM | USER } VALUE ]
 ///     <query expression>
+///
+/// // MySQL
+/// <from assignments> ::= SET <column name> = <expr> [, ...]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum InsertSource {
     /// From default
     Default,
@@ -69,6 +170,8 @@ pub enum InsertSource {
         /// Subquery.
         subquery: Box<Query>,
     },
+    /// From assignments (MySQL specific)
+    Set(Vec<Assignment>),
 }
 
 impl fmt::Display for InsertSource {
@@ -80,12 +183,12 @@ impl fmt::Display for InsertSource {
                 subquery,
             } => {
                 if let Some(columns) = columns {
-                    write!(f, "({})", display_comma_separated(columns))?;
+                    write!(f, "({}) ", display_comma_separated(columns))?;
                 }
                 if let Some(overriding) = overriding {
-                    write!(f, " {}", overriding)?;
+                    write!(f, "{} ", overriding)?;
                 }
-                write!(f, " {}", subquery)
+                write!(f, "{}", subquery)
             }
             Self::Values {
                 columns,
@@ -93,13 +196,14 @@ impl fmt::Display for InsertSource {
                 values,
             } => {
                 if let Some(columns) = columns {
-                    write!(f, "({})", display_comma_separated(columns))?;
+                    write!(f, "({}) ", display_comma_separated(columns))?;
                 }
                 if let Some(overriding) = overriding {
-                    write!(f, " {}", overriding)?;
+                    write!(f, "{} ", overriding)?;
                 }
-                write!(f, " {}", values)
+                write!(f, "{}", values)
             }
+            Self::Set(assignments) => write!(f, "SET {}", display_comma_separated(assignments)),
             Self::Default => f.write_str("DEFAULT VALUES"),
         }
     }
@@ -108,6 +212,8 @@ impl fmt::Display for InsertSource {
 /// The overriding clause of the `INSERT INTO ...` statement.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum InsertOverriding {
     /// Overriding the system value
     System,
@@ -124,24 +230,130 @@ impl fmt::Display for InsertOverriding {
     }
 }
 
+/// The conflict resolution algorithm, used in the `INSERT OR ...` statement and the
+/// `ON CONFLICT` clause of constraints. (SQLite specific)
+///
+/// ```txt
+/// <conflict resolution> ::= ROLLBACK | ABORT | FAIL | IGNORE | REPLACE
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ConflictResolution {
+    /// Rollback the current transaction.
+    Rollback,
+    /// Abort the current statement, the default algorithm.
+    Abort,
+    /// Abort the current statement, but keep the changes made by prior rows.
+    Fail,
+    /// Skip the row that violates the constraint.
+    Ignore,
+    /// Delete the pre-existing rows that cause the constraint violation.
+    Replace,
+}
+
+impl fmt::Display for ConflictResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Rollback => "ROLLBACK",
+            Self::Abort => "ABORT",
+            Self::Fail => "FAIL",
+            Self::Ignore => "IGNORE",
+            Self::Replace => "REPLACE",
+        })
+    }
+}
+
 /// The `DELETE FROM ...` statement.
 ///
 /// ```txt
-/// DELETE FROM <table> [ WHERE <search condition> ]
+/// [ <with clause> ] DELETE FROM <table> [ WHERE <search condition> ] [ RETURNING <select list> ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct DeleteStmt {
+    /// With clause.
+    pub(crate) with: Option<With>,
+    /// Table name.
+    pub(crate) table: ObjectName,
+    /// Table alias.
+    pub(crate) alias: Option<Ident>,
+    /// Search condition.
+    pub(crate) selection: Option<Where>,
+    /// Returning clause. (Non-standard)
+    pub(crate) returning: Option<Vec<SelectItem>>,
+}
+
+impl DeleteStmt {
+    /// Creates a `DeleteStmt`, the optional fields are unset.
+    pub fn new(table: ObjectName) -> Self {
+        Self {
+            with: None,
+            table,
+            alias: None,
+            selection: None,
+            returning: None,
+        }
+    }
+
+    /// Sets the `with`.
+    pub fn with_cte(mut self, with: With) -> Self {
+        self.with = Some(with);
+        self
+    }
+
+    /// Sets the `alias`.
+    pub fn with_alias(mut self, alias: Ident) -> Self {
+        self.alias = Some(alias);
+        self
+    }
+
+    /// Sets the `selection`.
+    pub fn with_selection(mut self, selection: Where) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Sets the `returning`.
+    pub fn with_returning(mut self, returning: Vec<SelectItem>) -> Self {
+        self.returning = Some(returning);
+        self
+    }
+
+    /// With clause.
+    pub fn with(&self) -> Option<&With> {
+        self.with.as_ref()
+    }
+
     /// Table name.
-    pub table: ObjectName,
+    pub fn table(&self) -> &ObjectName {
+        &self.table
+    }
+
     /// Table alias.
-    pub alias: Option<Ident>,
+    pub fn alias(&self) -> Option<&Ident> {
+        self.alias.as_ref()
+    }
+
     /// Search condition.
-    pub selection: Option<Where>,
+    pub fn selection(&self) -> Option<&Where> {
+        self.selection.as_ref()
+    }
+
+    /// Returning clause. (Non-standard)
+    pub fn returning(&self) -> Option<&[SelectItem]> {
+        self.returning.as_deref()
+    }
 }
 
 impl fmt::Display for DeleteStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(with) = &self.with {
+            write!(f, "{} ", with)?;
+        }
         write!(f, "DELETE FROM {}", self.table)?;
         if let Some(alias) = &self.alias {
             write!(f, " AS {}", alias)?;
@@ -149,6 +361,9 @@ impl fmt::Display for DeleteStmt {
         if let Some(selection) = &self.selection {
             write!(f, " {}", selection)?;
         }
+        if let Some(returning) = &self.returning {
+            write!(f, " RETURNING {}", display_comma_separated(returning))?;
+        }
         Ok(())
     }
 }
@@ -156,23 +371,101 @@ impl fmt::Display for DeleteStmt {
 /// The `UPDATE ... SET ...` statement.
 ///
 /// ```txt
-/// UPDATE <table> SET <assignments> [ WHERE <search condition> ]
+/// [ <with clause> ] UPDATE <table> SET <assignments> [ WHERE <search condition> ]
+///     [ RETURNING <select list> ]
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct UpdateStmt {
+    /// With clause.
+    pub(crate) with: Option<With>,
     /// Table name.
-    pub table: ObjectName,
+    pub(crate) table: ObjectName,
     /// Table alias.
-    pub alias: Option<Ident>,
+    pub(crate) alias: Option<Ident>,
     /// Column assignments.
-    pub assignments: Vec<Assignment>,
+    pub(crate) assignments: Vec<Assignment>,
     /// Search condition.
-    pub selection: Option<Where>,
+    pub(crate) selection: Option<Where>,
+    /// Returning clause. (Non-standard)
+    pub(crate) returning: Option<Vec<SelectItem>>,
+}
+
+impl UpdateStmt {
+    /// Creates a `UpdateStmt`, the optional fields are unset.
+    pub fn new(table: ObjectName, assignments: Vec<Assignment>) -> Self {
+        Self {
+            with: None,
+            table,
+            alias: None,
+            assignments,
+            selection: None,
+            returning: None,
+        }
+    }
+
+    /// Sets the `with`.
+    pub fn with_cte(mut self, with: With) -> Self {
+        self.with = Some(with);
+        self
+    }
+
+    /// Sets the `alias`.
+    pub fn with_alias(mut self, alias: Ident) -> Self {
+        self.alias = Some(alias);
+        self
+    }
+
+    /// Sets the `selection`.
+    pub fn with_selection(mut self, selection: Where) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Sets the `returning`.
+    pub fn with_returning(mut self, returning: Vec<SelectItem>) -> Self {
+        self.returning = Some(returning);
+        self
+    }
+
+    /// With clause.
+    pub fn with(&self) -> Option<&With> {
+        self.with.as_ref()
+    }
+
+    /// Table name.
+    pub fn table(&self) -> &ObjectName {
+        &self.table
+    }
+
+    /// Table alias.
+    pub fn alias(&self) -> Option<&Ident> {
+        self.alias.as_ref()
+    }
+
+    /// Column assignments.
+    pub fn assignments(&self) -> &[Assignment] {
+        &self.assignments
+    }
+
+    /// Search condition.
+    pub fn selection(&self) -> Option<&Where> {
+        self.selection.as_ref()
+    }
+
+    /// Returning clause. (Non-standard)
+    pub fn returning(&self) -> Option<&[SelectItem]> {
+        self.returning.as_deref()
+    }
 }
 
 impl fmt::Display for UpdateStmt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(with) = &self.with {
+            write!(f, "{} ", with)?;
+        }
         write!(f, "UPDATE {}", self.table)?;
         if let Some(alias) = &self.alias {
             write!(f, " AS {}", alias)?;
@@ -181,6 +474,9 @@ impl fmt::Display for UpdateStmt {
         if let Some(selection) = &self.selection {
             write!(f, " {}", selection)?;
         }
+        if let Some(returning) = &self.returning {
+            write!(f, " RETURNING {}", display_comma_separated(returning))?;
+        }
         Ok(())
     }
 }
@@ -188,11 +484,33 @@ impl fmt::Display for UpdateStmt {
 /// SQL assignment `foo = expr` as used in `Update` statement.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Assignment {
     /// Set target.
-    pub target: Ident,
+    pub(crate) target: Ident,
     /// Update source.
-    pub value: Box<Expr>,
+    pub(crate) value: Box<Expr>,
+}
+
+impl Assignment {
+    /// Creates a `Assignment`.
+    pub fn new(target: Ident, value: Expr) -> Self {
+        Self {
+            target,
+            value: Box::new(value),
+        }
+    }
+
+    /// Set target.
+    pub fn target(&self) -> &Ident {
+        &self.target
+    }
+
+    /// Update source.
+    pub fn value(&self) -> &Expr {
+        &self.value
+    }
 }
 
 impl fmt::Display for Assignment {
@@ -206,6 +524,7 @@ impl fmt::Display for Assignment {
 /// See query expression for details.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SelectStmt(pub Box<Query>);
 
 impl fmt::Display for SelectStmt {
@@ -0,0 +1,48 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt;
+
+/// The psql meta-command, e.g. `\connect db` or `\i file.sql`. (PostgreSQL specific)
+///
+/// ```txt
+/// \<command> [ <arguments> ]
+/// ```
+///
+/// **NOTE**: the arguments are kept as raw text, it's up to the client to interpret them.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct MetaCommandStmt {
+    /// The command name, without the leading backslash.
+    pub(crate) name: String,
+    /// The raw arguments of the command, may be empty.
+    pub(crate) args: String,
+}
+
+impl MetaCommandStmt {
+    /// Creates a `MetaCommandStmt`.
+    pub fn new(name: String, args: String) -> Self {
+        Self { name, args }
+    }
+
+    /// The command name, without the leading backslash.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The raw arguments of the command, may be empty.
+    pub fn args(&self) -> &str {
+        &self.args
+    }
+}
+
+impl fmt::Display for MetaCommandStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\\{}", self.name)?;
+        if !self.args.is_empty() {
+            write!(f, " {}", self.args)?;
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,22 @@
+mod admin;
+mod dcl;
 mod ddl;
 mod dml;
+mod meta;
+mod procedural;
 mod transaction;
 
 use core::fmt;
 
-pub use self::{ddl::*, dml::*, transaction::*};
+pub use self::{admin::*, dcl::*, ddl::*, dml::*, meta::*, procedural::*, transaction::*};
+use crate::ast::visit::{walk_stmt, NodeCounter};
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum Stmt {
     // ========================================================================
     // Data definition
@@ -19,8 +26,12 @@ pub enum Stmt {
     /// The `ALTER TABLE ...` statement
     AlterTable(AlterTableStmt),
 
+    /// The `RENAME TABLE ...` statement
+    RenameTable(RenameTableStmt),
     /// The `CREATE VIEW ...` statement
     CreateView(CreateViewStmt),
+    /// The `ALTER VIEW ...` statement
+    AlterView(AlterViewStmt),
 
     /// The `CREATE DOMAIN ...` statement
     CreateDomain(CreateDomainStmt),
@@ -32,7 +43,33 @@ pub enum Stmt {
     /// The `ALTER TYPE ...` statement
     AlterType(AlterTypeStmt),
 
-    /// The `DROP { SCHEMA | TABLE | VIEW | DOMAIN | TYPE | DATABASE | INDEX } ...` statement
+    /// The `ALTER SCHEMA ...` statement
+    AlterSchema(AlterSchemaStmt),
+
+    /// The `ALTER INDEX ...` statement
+    AlterIndex(AlterIndexStmt),
+
+    /// The `CREATE SEQUENCE ...` statement
+    CreateSequence(CreateSequenceStmt),
+
+    /// The `CREATE DATABASE ...` statement
+    CreateDatabase(CreateDatabaseStmt),
+
+    /// The `CREATE SERVER ...` statement
+    CreateServer(CreateServerStmt),
+    /// The `CREATE USER MAPPING ...` statement
+    CreateUserMapping(CreateUserMappingStmt),
+    /// The `CREATE FOREIGN TABLE ...` statement
+    CreateForeignTable(CreateForeignTableStmt),
+    /// The `IMPORT FOREIGN SCHEMA ...` statement
+    ImportForeignSchema(ImportForeignSchemaStmt),
+
+    /// The `CREATE POLICY ...` statement
+    CreatePolicy(CreatePolicyStmt),
+    /// The `DROP POLICY ...` statement
+    DropPolicy(DropPolicyStmt),
+
+    /// The `DROP { SCHEMA | TABLE | VIEW | DOMAIN | TYPE | DATABASE | INDEX | SEQUENCE } ...` statement
     Drop(DropStmt),
 
     // ========================================================================
@@ -58,6 +95,69 @@ pub enum Stmt {
     CommitTransaction(CommitTransactionStmt),
     /// The `ROLLBACK ...` statement
     RollbackTransaction(RollbackTransactionStmt),
+
+    // ========================================================================
+    // Access control
+    // ========================================================================
+    /// The `CREATE { ROLE | USER } ...` statement
+    CreateRole(CreateRoleStmt),
+    /// The `ALTER { ROLE | USER } ...` statement
+    AlterRole(AlterRoleStmt),
+    /// The `DROP { ROLE | USER } ...` statement
+    DropRole(DropRoleStmt),
+
+    // ========================================================================
+    // Database administration
+    // ========================================================================
+    /// The `PRAGMA ...` statement
+    Pragma(PragmaStmt),
+    /// The `ATTACH DATABASE ...` statement
+    Attach(AttachStmt),
+    /// The `DETACH DATABASE ...` statement
+    Detach(DetachStmt),
+    /// The `VACUUM ...` statement
+    Vacuum(VacuumStmt),
+    /// The `ANALYZE ...` statement
+    Analyze(AnalyzeStmt),
+    /// The `REINDEX ...` statement
+    Reindex(ReindexStmt),
+
+    // ========================================================================
+    // Procedural
+    // ========================================================================
+    /// The `DO ...` statement
+    Do(DoStmt),
+    /// The `CREATE { PROCEDURE | FUNCTION } ...` statement
+    #[cfg(feature = "procedural")]
+    CreateRoutine(CreateRoutineStmt),
+    /// The `CREATE TRIGGER ...` statement
+    #[cfg(feature = "procedural")]
+    CreateTrigger(CreateTriggerStmt),
+
+    // ========================================================================
+    // Client commands
+    // ========================================================================
+    /// The psql meta-command `\<command> ...`
+    MetaCommand(MetaCommandStmt),
+}
+
+impl Stmt {
+    /// Returns the number of the expressions and queries in the statement, including the
+    /// nested ones.
+    pub fn node_count(&self) -> usize {
+        let mut counter = NodeCounter::default();
+        walk_stmt(&mut counter, self);
+        counter.count
+    }
+
+    /// Returns the maximum nesting depth of the expressions and queries in the statement,
+    /// e.g. `SELECT a + 1` has a depth of 3, and a statement without any of them has a depth
+    /// of 0.
+    pub fn max_depth(&self) -> usize {
+        let mut counter = NodeCounter::default();
+        walk_stmt(&mut counter, self);
+        counter.max_depth
+    }
 }
 
 impl fmt::Display for Stmt {
@@ -65,11 +165,23 @@ impl fmt::Display for Stmt {
         match self {
             Self::CreateTable(stmt) => write!(f, "{}", stmt),
             Self::AlterTable(stmt) => write!(f, "{}", stmt),
+            Self::RenameTable(stmt) => write!(f, "{}", stmt),
             Self::CreateView(stmt) => write!(f, "{}", stmt),
+            Self::AlterView(stmt) => write!(f, "{}", stmt),
             Self::CreateDomain(stmt) => write!(f, "{}", stmt),
             Self::AlterDomain(stmt) => write!(f, "{}", stmt),
             Self::CreateType(stmt) => write!(f, "{}", stmt),
             Self::AlterType(stmt) => write!(f, "{}", stmt),
+            Self::AlterSchema(stmt) => write!(f, "{}", stmt),
+            Self::AlterIndex(stmt) => write!(f, "{}", stmt),
+            Self::CreateSequence(stmt) => write!(f, "{}", stmt),
+            Self::CreateDatabase(stmt) => write!(f, "{}", stmt),
+            Self::CreateServer(stmt) => write!(f, "{}", stmt),
+            Self::CreateUserMapping(stmt) => write!(f, "{}", stmt),
+            Self::CreateForeignTable(stmt) => write!(f, "{}", stmt),
+            Self::ImportForeignSchema(stmt) => write!(f, "{}", stmt),
+            Self::CreatePolicy(stmt) => write!(f, "{}", stmt),
+            Self::DropPolicy(stmt) => write!(f, "{}", stmt),
             Self::Drop(stmt) => write!(f, "{}", stmt),
 
             Self::Insert(stmt) => write!(f, "{}", stmt),
@@ -81,6 +193,25 @@ impl fmt::Display for Stmt {
             Self::SetTransaction(stmt) => write!(f, "{}", stmt),
             Self::CommitTransaction(stmt) => write!(f, "{}", stmt),
             Self::RollbackTransaction(stmt) => write!(f, "{}", stmt),
+
+            Self::CreateRole(stmt) => write!(f, "{}", stmt),
+            Self::AlterRole(stmt) => write!(f, "{}", stmt),
+            Self::DropRole(stmt) => write!(f, "{}", stmt),
+
+            Self::Pragma(stmt) => write!(f, "{}", stmt),
+            Self::Attach(stmt) => write!(f, "{}", stmt),
+            Self::Detach(stmt) => write!(f, "{}", stmt),
+            Self::Vacuum(stmt) => write!(f, "{}", stmt),
+            Self::Analyze(stmt) => write!(f, "{}", stmt),
+            Self::Reindex(stmt) => write!(f, "{}", stmt),
+
+            Self::Do(stmt) => write!(f, "{}", stmt),
+            #[cfg(feature = "procedural")]
+            Self::CreateRoutine(stmt) => write!(f, "{}", stmt),
+            #[cfg(feature = "procedural")]
+            Self::CreateTrigger(stmt) => write!(f, "{}", stmt),
+
+            Self::MetaCommand(stmt) => write!(f, "{}", stmt),
         }
     }
 }
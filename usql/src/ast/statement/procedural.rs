@@ -0,0 +1,963 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(all(feature = "procedural", not(feature = "std")))]
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "procedural")]
+use crate::ast::{
+    expression::Expr,
+    statement::{SqlSecurity, Stmt, UserName},
+    types::{DataType, ObjectName},
+    utils::{display_comma_separated, display_separated},
+};
+use crate::ast::{types::Ident, utils::escape_single_quote_string};
+
+/// The `DO` statement, which executes an anonymous code block. (PostgreSQL specific)
+///
+/// ```txt
+/// DO [ LANGUAGE <lang name> ] <code>
+/// ```
+///
+/// **NOTE**: the code is kept verbatim, it's up to the client to interpret it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DoStmt {
+    /// The language of the code.
+    pub(crate) language: Option<Ident>,
+    /// The code to be executed.
+    pub(crate) code: String,
+    /// The tag of the dollar-quoted code, or `None` if the code is a regular string literal.
+    pub(crate) tag: Option<String>,
+}
+
+impl DoStmt {
+    /// Creates a `DoStmt`, the optional fields are unset.
+    pub fn new(code: String) -> Self {
+        Self {
+            language: None,
+            code,
+            tag: None,
+        }
+    }
+
+    /// Sets the `language`.
+    pub fn with_language(mut self, language: Ident) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Sets the `tag`.
+    pub fn with_tag(mut self, tag: String) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    /// The language of the code.
+    pub fn language(&self) -> Option<&Ident> {
+        self.language.as_ref()
+    }
+
+    /// The code to be executed.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// The tag of the dollar-quoted code, or `None` if the code is a regular string literal.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+}
+
+impl fmt::Display for DoStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DO ")?;
+        if let Some(language) = &self.language {
+            write!(f, "LANGUAGE {} ", language)?;
+        }
+        match &self.tag {
+            Some(tag) => write!(f, "${}${}${}$", tag, self.code, tag),
+            None => write!(f, "'{}'", escape_single_quote_string(&self.code)),
+        }
+    }
+}
+
+/// A statement of a stored program body, like the body of a procedure or a trigger.
+/// (MySQL specific)
+///
+/// ```txt
+/// <procedural statement> ::=
+///     <compound statement>
+///     | <declare statement>
+///     | <if statement>
+///     | <while statement>
+///     | <return statement>
+///     | <SQL statement>
+///
+/// <statement list> ::= <procedural statement> ; [ ... ]
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ProceduralStmt {
+    /// The `BEGIN ... END` statement
+    Compound(CompoundStmt),
+    /// The `DECLARE ...` statement
+    Declare(DeclareStmt),
+    /// The `IF ... END IF` statement
+    If(IfStmt),
+    /// The `WHILE ... END WHILE` statement
+    While(WhileStmt),
+    /// The `RETURN ...` statement
+    Return(ReturnStmt),
+    /// A SQL statement
+    Sql(Box<Stmt>),
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for ProceduralStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compound(stmt) => write!(f, "{}", stmt),
+            Self::Declare(stmt) => write!(f, "{}", stmt),
+            Self::If(stmt) => write!(f, "{}", stmt),
+            Self::While(stmt) => write!(f, "{}", stmt),
+            Self::Return(stmt) => write!(f, "{}", stmt),
+            Self::Sql(stmt) => write!(f, "{}", stmt),
+        }
+    }
+}
+
+/// Displays the statements of a statement list, every statement is terminated by `;`.
+#[cfg(feature = "procedural")]
+struct DisplayStmtList<'a>(&'a [ProceduralStmt]);
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for DisplayStmtList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for stmt in self.0 {
+            write!(f, " {};", stmt)?;
+        }
+        Ok(())
+    }
+}
+
+/// The compound statement. (MySQL specific)
+///
+/// ```txt
+/// <compound statement> ::= BEGIN [ <statement list> ] END
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CompoundStmt {
+    /// The statements of the block.
+    pub(crate) stmts: Vec<ProceduralStmt>,
+}
+
+#[cfg(feature = "procedural")]
+impl CompoundStmt {
+    /// Creates a `CompoundStmt`.
+    pub fn new(stmts: Vec<ProceduralStmt>) -> Self {
+        Self { stmts }
+    }
+
+    /// The statements of the block.
+    pub fn stmts(&self) -> &[ProceduralStmt] {
+        &self.stmts
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for CompoundStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BEGIN{} END", DisplayStmtList(&self.stmts))
+    }
+}
+
+/// The local variable declaration. (MySQL specific)
+///
+/// ```txt
+/// <declare statement> ::= DECLARE <variable name> [, ...] <data type> [ DEFAULT <expr> ]
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct DeclareStmt {
+    /// The variable names.
+    pub(crate) names: Vec<Ident>,
+    /// The data type of the variables.
+    pub(crate) data_type: DataType,
+    /// The default value of the variables.
+    pub(crate) default: Option<Box<Expr>>,
+}
+
+#[cfg(feature = "procedural")]
+impl DeclareStmt {
+    /// Creates a `DeclareStmt`, the optional fields are unset.
+    pub fn new(names: Vec<Ident>, data_type: DataType) -> Self {
+        Self {
+            names,
+            data_type,
+            default: None,
+        }
+    }
+
+    /// Sets the `default`.
+    pub fn with_default(mut self, default: Expr) -> Self {
+        self.default = Some(Box::new(default));
+        self
+    }
+
+    /// The variable names.
+    pub fn names(&self) -> &[Ident] {
+        &self.names
+    }
+
+    /// The data type of the variables.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The default value of the variables.
+    pub fn default(&self) -> Option<&Expr> {
+        self.default.as_deref()
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for DeclareStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DECLARE {} {}",
+            display_comma_separated(&self.names),
+            self.data_type
+        )?;
+        if let Some(default) = &self.default {
+            write!(f, " DEFAULT {}", default)?;
+        }
+        Ok(())
+    }
+}
+
+/// The `IF` statement. (MySQL specific)
+///
+/// ```txt
+/// <if statement> ::= IF <search condition> THEN <statement list>
+///     [ ELSEIF <search condition> THEN <statement list> ] [...]
+///     [ ELSE <statement list> ]
+///     END IF
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct IfStmt {
+    /// The `IF` branch and the `ELSEIF` branches.
+    pub(crate) branches: Vec<ConditionalStmts>,
+    /// The statements of the `ELSE` branch.
+    pub(crate) else_stmts: Option<Vec<ProceduralStmt>>,
+}
+
+#[cfg(feature = "procedural")]
+impl IfStmt {
+    /// Creates a `IfStmt`, the optional fields are unset.
+    pub fn new(branches: Vec<ConditionalStmts>) -> Self {
+        Self {
+            branches,
+            else_stmts: None,
+        }
+    }
+
+    /// Sets the `else_stmts`.
+    pub fn with_else_stmts(mut self, else_stmts: Vec<ProceduralStmt>) -> Self {
+        self.else_stmts = Some(else_stmts);
+        self
+    }
+
+    /// The `IF` branch and the `ELSEIF` branches.
+    pub fn branches(&self) -> &[ConditionalStmts] {
+        &self.branches
+    }
+
+    /// The statements of the `ELSE` branch.
+    pub fn else_stmts(&self) -> Option<&[ProceduralStmt]> {
+        self.else_stmts.as_deref()
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for IfStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "IF {}", display_separated(&self.branches, " ELSEIF "))?;
+        if let Some(stmts) = &self.else_stmts {
+            write!(f, " ELSE{}", DisplayStmtList(stmts))?;
+        }
+        f.write_str(" END IF")
+    }
+}
+
+/// A branch of the `IF` statement. (MySQL specific)
+///
+/// ```txt
+/// <search condition> THEN <statement list>
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ConditionalStmts {
+    /// The search condition.
+    pub(crate) condition: Box<Expr>,
+    /// The statements executed if the condition is true.
+    pub(crate) stmts: Vec<ProceduralStmt>,
+}
+
+#[cfg(feature = "procedural")]
+impl ConditionalStmts {
+    /// Creates a `ConditionalStmts`.
+    pub fn new(condition: Expr, stmts: Vec<ProceduralStmt>) -> Self {
+        Self {
+            condition: Box::new(condition),
+            stmts,
+        }
+    }
+
+    /// The search condition.
+    pub fn condition(&self) -> &Expr {
+        &self.condition
+    }
+
+    /// The statements executed if the condition is true.
+    pub fn stmts(&self) -> &[ProceduralStmt] {
+        &self.stmts
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for ConditionalStmts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} THEN{}", self.condition, DisplayStmtList(&self.stmts))
+    }
+}
+
+/// The `WHILE` statement. (MySQL specific)
+///
+/// ```txt
+/// <while statement> ::= WHILE <search condition> DO <statement list> END WHILE
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct WhileStmt {
+    /// The search condition.
+    pub(crate) condition: Box<Expr>,
+    /// The statements executed while the condition is true.
+    pub(crate) stmts: Vec<ProceduralStmt>,
+}
+
+#[cfg(feature = "procedural")]
+impl WhileStmt {
+    /// Creates a `WhileStmt`.
+    pub fn new(condition: Expr, stmts: Vec<ProceduralStmt>) -> Self {
+        Self {
+            condition: Box::new(condition),
+            stmts,
+        }
+    }
+
+    /// The search condition.
+    pub fn condition(&self) -> &Expr {
+        &self.condition
+    }
+
+    /// The statements executed while the condition is true.
+    pub fn stmts(&self) -> &[ProceduralStmt] {
+        &self.stmts
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for WhileStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "WHILE {} DO{} END WHILE",
+            self.condition,
+            DisplayStmtList(&self.stmts)
+        )
+    }
+}
+
+/// The `RETURN` statement. (MySQL specific)
+///
+/// ```txt
+/// <return statement> ::= RETURN <expr>
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct ReturnStmt {
+    /// The returned value.
+    pub(crate) expr: Box<Expr>,
+}
+
+#[cfg(feature = "procedural")]
+impl ReturnStmt {
+    /// Creates a `ReturnStmt`.
+    pub fn new(expr: Expr) -> Self {
+        Self {
+            expr: Box::new(expr),
+        }
+    }
+
+    /// The returned value.
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for ReturnStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RETURN {}", self.expr)
+    }
+}
+
+/// The `CREATE PROCEDURE` or `CREATE FUNCTION` statement. (MySQL specific)
+///
+/// ```txt
+/// CREATE [ DEFINER = <user name> ] PROCEDURE [ IF NOT EXISTS ] <routine name>
+///     ( [ <routine parameter> [, ...] ] ) [ <routine characteristic> [...] ]
+///     <procedural statement>
+///
+/// CREATE [ DEFINER = <user name> ] FUNCTION [ IF NOT EXISTS ] <routine name>
+///     ( [ <routine parameter> [, ...] ] ) RETURNS <data type> [ <routine characteristic> [...] ]
+///     <procedural statement>
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateRoutineStmt {
+    /// The account used to check the access privileges of the routine.
+    pub(crate) definer: Option<UserName>,
+    /// Whether the routine is a procedure or a function.
+    pub(crate) kind: RoutineKind,
+    /// Flag indicates that check if the routine does not exists.
+    pub(crate) if_not_exists: bool,
+    /// The routine name.
+    pub(crate) name: ObjectName,
+    /// The parameters of the routine.
+    pub(crate) params: Vec<RoutineParam>,
+    /// The return type of a function, `None` for a procedure.
+    pub(crate) returns: Option<DataType>,
+    /// The characteristics of the routine.
+    pub(crate) characteristics: Vec<RoutineCharacteristic>,
+    /// The routine body.
+    pub(crate) body: ProceduralStmt,
+}
+
+#[cfg(feature = "procedural")]
+impl CreateRoutineStmt {
+    /// Creates a `CreateRoutineStmt` of a procedure, the optional fields are unset.
+    pub fn procedure(name: ObjectName, params: Vec<RoutineParam>, body: ProceduralStmt) -> Self {
+        Self {
+            definer: None,
+            kind: RoutineKind::Procedure,
+            if_not_exists: false,
+            name,
+            params,
+            returns: None,
+            characteristics: vec![],
+            body,
+        }
+    }
+
+    /// Creates a `CreateRoutineStmt` of a function, the optional fields are unset.
+    pub fn function(
+        name: ObjectName,
+        params: Vec<RoutineParam>,
+        returns: DataType,
+        body: ProceduralStmt,
+    ) -> Self {
+        Self {
+            kind: RoutineKind::Function,
+            returns: Some(returns),
+            ..Self::procedure(name, params, body)
+        }
+    }
+
+    /// Sets the `definer`.
+    pub fn with_definer(mut self, definer: UserName) -> Self {
+        self.definer = Some(definer);
+        self
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Sets the `characteristics`.
+    pub fn with_characteristics(mut self, characteristics: Vec<RoutineCharacteristic>) -> Self {
+        self.characteristics = characteristics;
+        self
+    }
+
+    /// The account used to check the access privileges of the routine.
+    pub fn definer(&self) -> Option<&UserName> {
+        self.definer.as_ref()
+    }
+
+    /// Whether the routine is a procedure or a function.
+    pub fn kind(&self) -> RoutineKind {
+        self.kind
+    }
+
+    /// Flag indicates that check if the routine does not exists.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// The routine name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// The parameters of the routine.
+    pub fn params(&self) -> &[RoutineParam] {
+        &self.params
+    }
+
+    /// The return type of a function, `None` for a procedure.
+    pub fn returns(&self) -> Option<&DataType> {
+        self.returns.as_ref()
+    }
+
+    /// The characteristics of the routine.
+    pub fn characteristics(&self) -> &[RoutineCharacteristic] {
+        &self.characteristics
+    }
+
+    /// The routine body.
+    pub fn body(&self) -> &ProceduralStmt {
+        &self.body
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for CreateRoutineStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CREATE ")?;
+        if let Some(definer) = &self.definer {
+            write!(f, "DEFINER = {} ", definer)?;
+        }
+        write!(
+            f,
+            "{kind} {if_not_exists}{name}({params})",
+            kind = self.kind,
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            name = self.name,
+            params = display_comma_separated(&self.params)
+        )?;
+        if let Some(returns) = &self.returns {
+            write!(f, " RETURNS {}", returns)?;
+        }
+        for characteristic in &self.characteristics {
+            write!(f, " {}", characteristic)?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
+
+/// The kind of a stored routine. (MySQL specific)
+#[cfg(feature = "procedural")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum RoutineKind {
+    /// `PROCEDURE`
+    Procedure,
+    /// `FUNCTION`
+    Function,
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for RoutineKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Procedure => "PROCEDURE",
+            Self::Function => "FUNCTION",
+        })
+    }
+}
+
+/// A parameter of a stored routine. (MySQL specific)
+///
+/// ```txt
+/// <routine parameter> ::= [ IN | OUT | INOUT ] <parameter name> <data type>
+/// ```
+///
+/// **NOTE**: the parameters of a function are always `IN` parameters, the mode is only
+/// allowed for a procedure.
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct RoutineParam {
+    /// The parameter mode.
+    pub(crate) mode: Option<ParamMode>,
+    /// The parameter name.
+    pub(crate) name: Ident,
+    /// The data type of the parameter.
+    pub(crate) data_type: DataType,
+}
+
+#[cfg(feature = "procedural")]
+impl RoutineParam {
+    /// Creates a `RoutineParam`, the optional fields are unset.
+    pub fn new(name: Ident, data_type: DataType) -> Self {
+        Self {
+            mode: None,
+            name,
+            data_type,
+        }
+    }
+
+    /// Sets the `mode`.
+    pub fn with_mode(mut self, mode: ParamMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// The parameter mode.
+    pub fn mode(&self) -> Option<ParamMode> {
+        self.mode
+    }
+
+    /// The parameter name.
+    pub fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    /// The data type of the parameter.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for RoutineParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(mode) = &self.mode {
+            write!(f, "{} ", mode)?;
+        }
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
+/// The mode of a procedure parameter. (MySQL specific)
+#[cfg(feature = "procedural")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ParamMode {
+    /// `IN`
+    In,
+    /// `OUT`
+    Out,
+    /// `INOUT`
+    InOut,
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for ParamMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::In => "IN",
+            Self::Out => "OUT",
+            Self::InOut => "INOUT",
+        })
+    }
+}
+
+/// A characteristic of a stored routine. (MySQL specific)
+///
+/// ```txt
+/// <routine characteristic> ::=
+///     COMMENT <string>
+///     | LANGUAGE SQL
+///     | [ NOT ] DETERMINISTIC
+///     | { CONTAINS SQL | NO SQL | READS SQL DATA | MODIFIES SQL DATA }
+///     | SQL SECURITY { DEFINER | INVOKER }
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum RoutineCharacteristic {
+    /// `COMMENT <string>`
+    Comment(String),
+    /// `LANGUAGE SQL`
+    LanguageSql,
+    /// `[ NOT ] DETERMINISTIC`, `false` if `NOT` is specified
+    Deterministic(bool),
+    /// `CONTAINS SQL`
+    ContainsSql,
+    /// `NO SQL`
+    NoSql,
+    /// `READS SQL DATA`
+    ReadsSqlData,
+    /// `MODIFIES SQL DATA`
+    ModifiesSqlData,
+    /// `SQL SECURITY { DEFINER | INVOKER }`
+    SqlSecurity(SqlSecurity),
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for RoutineCharacteristic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Comment(comment) => {
+                write!(f, "COMMENT '{}'", escape_single_quote_string(comment))
+            }
+            Self::LanguageSql => f.write_str("LANGUAGE SQL"),
+            Self::Deterministic(true) => f.write_str("DETERMINISTIC"),
+            Self::Deterministic(false) => f.write_str("NOT DETERMINISTIC"),
+            Self::ContainsSql => f.write_str("CONTAINS SQL"),
+            Self::NoSql => f.write_str("NO SQL"),
+            Self::ReadsSqlData => f.write_str("READS SQL DATA"),
+            Self::ModifiesSqlData => f.write_str("MODIFIES SQL DATA"),
+            Self::SqlSecurity(security) => write!(f, "SQL SECURITY {}", security),
+        }
+    }
+}
+
+/// The `CREATE TRIGGER` statement. (MySQL specific)
+///
+/// ```txt
+/// CREATE [ DEFINER = <user name> ] TRIGGER [ IF NOT EXISTS ] <trigger name>
+///     { BEFORE | AFTER } { INSERT | UPDATE | DELETE } ON <table name> FOR EACH ROW
+///     [ { FOLLOWS | PRECEDES } <trigger name> ] <procedural statement>
+/// ```
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CreateTriggerStmt {
+    /// The account used to check the access privileges of the trigger.
+    pub(crate) definer: Option<UserName>,
+    /// Flag indicates that check if the trigger does not exists.
+    pub(crate) if_not_exists: bool,
+    /// The trigger name.
+    pub(crate) name: ObjectName,
+    /// Whether the trigger fires before or after the event.
+    pub(crate) timing: TriggerTiming,
+    /// The event that fires the trigger.
+    pub(crate) event: TriggerEvent,
+    /// The table of the trigger.
+    pub(crate) table: ObjectName,
+    /// The order relative to another trigger of the same table, event and timing.
+    pub(crate) order: Option<TriggerOrder>,
+    /// The trigger body.
+    pub(crate) body: ProceduralStmt,
+}
+
+#[cfg(feature = "procedural")]
+impl CreateTriggerStmt {
+    /// Creates a `CreateTriggerStmt`, the optional fields are unset.
+    pub fn new(
+        name: ObjectName,
+        timing: TriggerTiming,
+        event: TriggerEvent,
+        table: ObjectName,
+        body: ProceduralStmt,
+    ) -> Self {
+        Self {
+            definer: None,
+            if_not_exists: false,
+            name,
+            timing,
+            event,
+            table,
+            order: None,
+            body,
+        }
+    }
+
+    /// Sets the `definer`.
+    pub fn with_definer(mut self, definer: UserName) -> Self {
+        self.definer = Some(definer);
+        self
+    }
+
+    /// Sets the `if_not_exists` flag.
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Sets the `order`.
+    pub fn with_order(mut self, order: TriggerOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// The account used to check the access privileges of the trigger.
+    pub fn definer(&self) -> Option<&UserName> {
+        self.definer.as_ref()
+    }
+
+    /// Flag indicates that check if the trigger does not exists.
+    pub fn if_not_exists(&self) -> bool {
+        self.if_not_exists
+    }
+
+    /// The trigger name.
+    pub fn name(&self) -> &ObjectName {
+        &self.name
+    }
+
+    /// Whether the trigger fires before or after the event.
+    pub fn timing(&self) -> TriggerTiming {
+        self.timing
+    }
+
+    /// The event that fires the trigger.
+    pub fn event(&self) -> TriggerEvent {
+        self.event
+    }
+
+    /// The table of the trigger.
+    pub fn table(&self) -> &ObjectName {
+        &self.table
+    }
+
+    /// The order relative to another trigger of the same table, event and timing.
+    pub fn order(&self) -> Option<&TriggerOrder> {
+        self.order.as_ref()
+    }
+
+    /// The trigger body.
+    pub fn body(&self) -> &ProceduralStmt {
+        &self.body
+    }
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for CreateTriggerStmt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CREATE ")?;
+        if let Some(definer) = &self.definer {
+            write!(f, "DEFINER = {} ", definer)?;
+        }
+        write!(
+            f,
+            "TRIGGER {if_not_exists}{name} {timing} {event} ON {table} FOR EACH ROW",
+            if_not_exists = if self.if_not_exists { "IF NOT EXISTS " } else { "" },
+            name = self.name,
+            timing = self.timing,
+            event = self.event,
+            table = self.table
+        )?;
+        if let Some(order) = &self.order {
+            write!(f, " {}", order)?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
+
+/// The time when a trigger fires. (MySQL specific)
+#[cfg(feature = "procedural")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TriggerTiming {
+    /// `BEFORE`
+    Before,
+    /// `AFTER`
+    After,
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for TriggerTiming {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Before => "BEFORE",
+            Self::After => "AFTER",
+        })
+    }
+}
+
+/// The event that fires a trigger. (MySQL specific)
+#[cfg(feature = "procedural")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TriggerEvent {
+    /// `INSERT`
+    Insert,
+    /// `UPDATE`
+    Update,
+    /// `DELETE`
+    Delete,
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for TriggerEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Insert => "INSERT",
+            Self::Update => "UPDATE",
+            Self::Delete => "DELETE",
+        })
+    }
+}
+
+/// The order of a trigger relative to another trigger. (MySQL specific)
+#[cfg(feature = "procedural")]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum TriggerOrder {
+    /// `FOLLOWS <trigger name>`
+    Follows(Ident),
+    /// `PRECEDES <trigger name>`
+    Precedes(Ident),
+}
+
+#[cfg(feature = "procedural")]
+impl fmt::Display for TriggerOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Follows(name) => write!(f, "FOLLOWS {}", name),
+            Self::Precedes(name) => write!(f, "PRECEDES {}", name),
+        }
+    }
+}
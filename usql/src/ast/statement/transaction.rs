@@ -11,9 +11,23 @@ use crate::ast::utils::display_comma_separated;
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct StartTransactionStmt {
     /// The transaction characteristics.
-    pub characteristics: Vec<TransactionCharacteristic>,
+    pub(crate) characteristics: Vec<TransactionCharacteristic>,
+}
+
+impl StartTransactionStmt {
+    /// Creates a `StartTransactionStmt`.
+    pub fn new(characteristics: Vec<TransactionCharacteristic>) -> Self {
+        Self { characteristics }
+    }
+
+    /// The transaction characteristics.
+    pub fn characteristics(&self) -> &[TransactionCharacteristic] {
+        &self.characteristics
+    }
 }
 
 impl fmt::Display for StartTransactionStmt {
@@ -33,9 +47,23 @@ impl fmt::Display for StartTransactionStmt {
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct SetTransactionStmt {
     /// The transaction characteristics.
-    pub characteristics: Vec<TransactionCharacteristic>,
+    pub(crate) characteristics: Vec<TransactionCharacteristic>,
+}
+
+impl SetTransactionStmt {
+    /// Creates a `SetTransactionStmt`.
+    pub fn new(characteristics: Vec<TransactionCharacteristic>) -> Self {
+        Self { characteristics }
+    }
+
+    /// The transaction characteristics.
+    pub fn characteristics(&self) -> &[TransactionCharacteristic] {
+        &self.characteristics
+    }
 }
 
 impl fmt::Display for SetTransactionStmt {
@@ -52,6 +80,8 @@ impl fmt::Display for SetTransactionStmt {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum TransactionCharacteristic {
     AccessMode(TransactionAccessMode),
     IsolationLevel(TransactionIsolationLevel),
@@ -82,6 +112,8 @@ impl From<TransactionIsolationLevel> for TransactionCharacteristic {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum TransactionAccessMode {
     ReadOnly,
     ReadWrite,
@@ -100,6 +132,8 @@ impl fmt::Display for TransactionAccessMode {
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum TransactionIsolationLevel {
     ReadUncommitted,
     ReadCommitted,
@@ -123,12 +157,28 @@ impl fmt::Display for TransactionIsolationLevel {
 /// ```txt
 /// COMMIT [ TRANSACTION | WORK ] [ AND [ NO ] CHAIN ]
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct CommitTransactionStmt {
     /// Flag to indicate whether a new transaction is immediately started with
     /// the same transaction characteristics as the just finished one.
-    pub and_chain: bool,
+    pub(crate) and_chain: bool,
+}
+
+impl CommitTransactionStmt {
+    /// Sets the `and_chain` flag.
+    pub fn with_and_chain(mut self, and_chain: bool) -> Self {
+        self.and_chain = and_chain;
+        self
+    }
+
+    /// Flag to indicate whether a new transaction is immediately started with
+    /// the same transaction characteristics as the just finished one.
+    pub fn and_chain(&self) -> bool {
+        self.and_chain
+    }
 }
 
 impl fmt::Display for CommitTransactionStmt {
@@ -146,12 +196,28 @@ impl fmt::Display for CommitTransactionStmt {
 /// ```txt
 /// ROLLBACK [ TRANSACTION | WORK ] [ AND [ NO ] CHAIN ]
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct RollbackTransactionStmt {
     /// Flag to indicate whether a new transaction is immediately started with
     /// the same transaction characteristics as the just finished one.
-    pub and_chain: bool,
+    pub(crate) and_chain: bool,
+}
+
+impl RollbackTransactionStmt {
+    /// Sets the `and_chain` flag.
+    pub fn with_and_chain(mut self, and_chain: bool) -> Self {
+        self.and_chain = and_chain;
+        self
+    }
+
+    /// Flag to indicate whether a new transaction is immediately started with
+    /// the same transaction characteristics as the just finished one.
+    pub fn and_chain(&self) -> bool {
+        self.and_chain
+    }
 }
 
 impl fmt::Display for RollbackTransactionStmt {
@@ -1,12 +1,14 @@
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt;
 
-use crate::ast::types::ObjectName;
+use crate::ast::{types::ObjectName, utils::escape_single_quote_string};
 
 /// SQL data types
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum DataType {
     /// Boolean
     Boolean,
@@ -22,6 +24,24 @@ pub enum DataType {
     Int(Option<u64>),
     /// Big integer (-2^63 ~ 2^63 - 1) with optional display width e.g. BIGINT or BIGINT(19)
     BigInt(Option<u64>),
+    /// Medium integer (-2^23 ~ 2^23 - 1) with optional display width e.g. MEDIUMINT or MEDIUMINT(8)
+    /// (MySQL specific)
+    MediumInt(Option<u64>),
+    /// Unsigned numeric type, optionally padded with zeros when displayed,
+    /// e.g. INT UNSIGNED or INT(10) UNSIGNED ZEROFILL (MySQL specific)
+    Unsigned {
+        /// The numeric type
+        data_type: Box<DataType>,
+        /// Whether the displayed value is padded with zeros up to the display width
+        zerofill: bool,
+    },
+    /// Auto-incrementing small integer e.g. SMALLSERIAL (PostgreSQL)
+    SmallSerial,
+    /// Auto-incrementing integer e.g. SERIAL, which is an alias for
+    /// `BIGINT UNSIGNED NOT NULL AUTO_INCREMENT UNIQUE` in MySQL
+    Serial,
+    /// Auto-incrementing big integer e.g. BIGSERIAL (PostgreSQL)
+    BigSerial,
 
     // ========================================================================
     // Arbitrary Precision Numbers
@@ -50,18 +70,47 @@ pub enum DataType {
     Real,
     /// Double e.g. DOUBLE PRECISION
     Double,
+    /// Currency amount with a fixed fractional precision e.g. MONEY (PostgreSQL)
+    Money,
 
     // ========================================================================
     // Character String Types
     // ========================================================================
-    /// Fixed-length character type e.g. CHAR(10)
-    Char(Option<u64>),
-    /// Variable-length character type e.g. VARCHAR(10)
-    Varchar(u64),
+    /// Fixed-length character type e.g. CHAR(10) or CHAR(10) CHARACTER SET latin1
+    Char {
+        /// The length of the characters
+        length: Option<u64>,
+        /// The character set e.g. CHARACTER SET latin1
+        charset: Option<ObjectName>,
+        /// The collation e.g. COLLATE latin1_bin
+        collation: Option<ObjectName>,
+    },
+    /// Variable-length character type e.g. VARCHAR(10) or
+    /// VARCHAR(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin
+    Varchar {
+        /// The maximum length of the characters
+        length: u64,
+        /// The character set e.g. CHARACTER SET utf8mb4
+        charset: Option<ObjectName>,
+        /// The collation e.g. COLLATE utf8mb4_bin
+        collation: Option<ObjectName>,
+    },
     /// Character large object e.g. CLOB(1000)
-    Clob(Option<u64>),
+    Clob {
+        /// The maximum length of the characters
+        length: Option<u64>,
+        /// The character set e.g. CHARACTER SET utf8
+        charset: Option<ObjectName>,
+        /// The collation e.g. COLLATE utf8_bin
+        collation: Option<ObjectName>,
+    },
     /// Text type, variable unlimited length characters. (Not ANSI SQL)
-    Text,
+    Text {
+        /// The character set e.g. CHARACTER SET utf8mb4 (MySQL specific)
+        charset: Option<ObjectName>,
+        /// The collation e.g. COLLATE utf8mb4_bin
+        collation: Option<ObjectName>,
+    },
 
     // ========================================================================
     // Binary String Types
@@ -80,13 +129,40 @@ pub enum DataType {
     // ========================================================================
     /// Date
     Date,
-    /// Time
-    Time,
-    /// Timestamp
-    Timestamp,
+    /// Time with optional fractional seconds precision and time zone e.g. TIME(3) WITH TIME ZONE
+    Time {
+        /// The fractional seconds precision
+        precision: Option<u64>,
+        /// Whether the time zone is included
+        with_time_zone: bool,
+    },
+    /// Timestamp with optional fractional seconds precision and time zone
+    /// e.g. TIMESTAMP(6) WITH TIME ZONE
+    Timestamp {
+        /// The fractional seconds precision
+        precision: Option<u64>,
+        /// Whether the time zone is included
+        with_time_zone: bool,
+    },
     /// Interval
     Interval,
 
+    // ========================================================================
+    // Other Types
+    // ========================================================================
+    /// Universally unique identifier e.g. UUID (PostgreSQL)
+    Uuid,
+    /// IPv4 or IPv6 host address e.g. INET (PostgreSQL)
+    Inet,
+    /// JSON document e.g. JSON
+    Json,
+    /// Decomposed binary JSON document e.g. JSONB (PostgreSQL)
+    Jsonb,
+    /// Enumeration of string values e.g. ENUM('a', 'b') (MySQL specific)
+    Enum(Vec<String>),
+    /// Set of string values e.g. SET('a', 'b') (MySQL specific)
+    Set(Vec<String>),
+
     // ========================================================================
     // Collection Types
     // ========================================================================
@@ -113,17 +189,31 @@ impl fmt::Display for DataType {
             }
             DataType::Int(zerofill) => format_type_with_optional_length(f, "INT", zerofill),
             DataType::BigInt(zerofill) => format_type_with_optional_length(f, "BIGINT", zerofill),
+            DataType::MediumInt(width) => format_type_with_optional_length(f, "MEDIUMINT", width),
+            DataType::Unsigned {
+                data_type,
+                zerofill,
+            } => {
+                write!(f, "{} UNSIGNED", data_type)?;
+                if *zerofill {
+                    write!(f, " ZEROFILL")?;
+                }
+                Ok(())
+            }
+            DataType::SmallSerial => write!(f, "SMALLSERIAL"),
+            DataType::Serial => write!(f, "SERIAL"),
+            DataType::BigSerial => write!(f, "BIGSERIAL"),
 
             DataType::Numeric { precision, scale } => {
-                if let Some(scale) = scale {
-                    write!(f, "NUMERIC({},{})", precision.unwrap(), scale)
+                if let (Some(precision), Some(scale)) = (precision, scale) {
+                    write!(f, "NUMERIC({},{})", precision, scale)
                 } else {
                     format_type_with_optional_length(f, "NUMERIC", precision)
                 }
             }
             DataType::Decimal { precision, scale } => {
-                if let Some(scale) = scale {
-                    write!(f, "DECIMAL({},{})", precision.unwrap(), scale)
+                if let (Some(precision), Some(scale)) = (precision, scale) {
+                    write!(f, "DECIMAL({},{})", precision, scale)
                 } else {
                     format_type_with_optional_length(f, "DECIMAL", precision)
                 }
@@ -132,22 +222,66 @@ impl fmt::Display for DataType {
             DataType::Float(size) => format_type_with_optional_length(f, "FLOAT", size),
             DataType::Real => write!(f, "REAL"),
             DataType::Double => write!(f, "DOUBLE PRECISION"),
+            DataType::Money => write!(f, "MONEY"),
 
-            DataType::Char(size) => format_type_with_optional_length(f, "CHAR", size),
-            DataType::Varchar(size) => write!(f, "VARCHAR({})", size),
-            DataType::Clob(size) => format_type_with_optional_length(f, "CLOB({})", size),
-            DataType::Text => write!(f, "TEXT"),
+            DataType::Char {
+                length,
+                charset,
+                collation,
+            } => {
+                format_type_with_optional_length(f, "CHAR", length)?;
+                format_charset_collation(f, charset, collation)
+            }
+            DataType::Varchar {
+                length,
+                charset,
+                collation,
+            } => {
+                write!(f, "VARCHAR({})", length)?;
+                format_charset_collation(f, charset, collation)
+            }
+            DataType::Clob {
+                length,
+                charset,
+                collation,
+            } => {
+                format_type_with_optional_length(f, "CLOB", length)?;
+                format_charset_collation(f, charset, collation)
+            }
+            DataType::Text { charset, collation } => {
+                write!(f, "TEXT")?;
+                format_charset_collation(f, charset, collation)
+            }
 
-            DataType::Binary(size) => format_type_with_optional_length(f, "BINARY({})", size),
+            DataType::Binary(size) => format_type_with_optional_length(f, "BINARY", size),
             DataType::Varbinary(size) => write!(f, "VARBINARY({})", size),
-            DataType::Blob(size) => format_type_with_optional_length(f, "BLOB({})", size),
+            DataType::Blob(size) => format_type_with_optional_length(f, "BLOB", size),
             DataType::Bytea => write!(f, "BYTEA"),
 
             DataType::Date => write!(f, "DATE"),
-            DataType::Time => write!(f, "TIME"),
-            DataType::Timestamp => write!(f, "TIMESTAMP"),
+            DataType::Time {
+                precision,
+                with_time_zone,
+            } => {
+                format_type_with_optional_length(f, "TIME", precision)?;
+                format_time_zone(f, *with_time_zone)
+            }
+            DataType::Timestamp {
+                precision,
+                with_time_zone,
+            } => {
+                format_type_with_optional_length(f, "TIMESTAMP", precision)?;
+                format_time_zone(f, *with_time_zone)
+            }
             DataType::Interval => write!(f, "INTERVAL"),
 
+            DataType::Uuid => write!(f, "UUID"),
+            DataType::Inet => write!(f, "INET"),
+            DataType::Json => write!(f, "JSON"),
+            DataType::Jsonb => write!(f, "JSONB"),
+            DataType::Enum(values) => format_type_with_values(f, "ENUM", values),
+            DataType::Set(values) => format_type_with_values(f, "SET", values),
+
             DataType::Array(ty, length) => {
                 if let Some(length) = length {
                     write!(f, "{}[{}]", ty, length)
@@ -173,3 +307,39 @@ fn format_type_with_optional_length(
     }
     Ok(())
 }
+
+fn format_time_zone(f: &mut fmt::Formatter, with_time_zone: bool) -> fmt::Result {
+    if with_time_zone {
+        write!(f, " WITH TIME ZONE")?;
+    }
+    Ok(())
+}
+
+fn format_charset_collation(
+    f: &mut fmt::Formatter,
+    charset: &Option<ObjectName>,
+    collation: &Option<ObjectName>,
+) -> fmt::Result {
+    if let Some(charset) = charset {
+        write!(f, " CHARACTER SET {}", charset)?;
+    }
+    if let Some(collation) = collation {
+        write!(f, " COLLATE {}", collation)?;
+    }
+    Ok(())
+}
+
+fn format_type_with_values(
+    f: &mut fmt::Formatter,
+    sql_type: &'static str,
+    values: &[String],
+) -> fmt::Result {
+    write!(f, "{}(", sql_type)?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "'{}'", escape_single_quote_string(value))?;
+    }
+    write!(f, ")")
+}
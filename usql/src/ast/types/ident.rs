@@ -1,21 +1,29 @@
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
-use core::fmt;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Write};
 
-use crate::ast::utils::display_separated;
+use crate::{
+    ast::utils::display_separated,
+    dialect::{Dialect, DialectLexerConf},
+    keywords::KeywordDef,
+};
 
 /// An identifier, decomposed into its value or character data and the quote style.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct Ident {
     /// The value of the identifier without quotes.
-    pub value: String,
+    pub(crate) value: String,
     /// An identifier can be "quoted" (<delimited identifier> in ANSI parlance).
     /// The standard and most implementations allow using double quotes for this,
     /// but some implementations support other quoting styles as well.
     /// Valid quote characters are the single quote, double quote, backtick, and
     /// opening square bracket.
-    pub quote: Option<char>,
+    pub(crate) quote: Option<char>,
 }
 
 impl Ident {
@@ -42,6 +50,82 @@ impl Ident {
             quote: Some(quote),
         }
     }
+
+    /// Returns the value of the identifier in Unicode Normalization Form C, so that the
+    /// identifiers that look the same but are encoded differently (like `é` and `e` followed by
+    /// a combining acute accent) have the same normalized value. The identifier itself is kept
+    /// as written, and is rendered as written.
+    ///
+    /// **NOTE**: The value is normalized only if the `unicode-normalization` feature is enabled,
+    /// otherwise it's returned as it is.
+    pub fn normalized_value(&self) -> String {
+        #[cfg(feature = "unicode-normalization")]
+        {
+            use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+            if is_nfc_quick(self.value.chars()) != IsNormalized::Yes {
+                return self.value.nfc().collect();
+            }
+        }
+        self.value.clone()
+    }
+
+    /// Renders the identifier as SQL of the given dialect.
+    ///
+    /// An unquoted identifier is quoted if it is a reserved keyword of the dialect, contains
+    /// characters that are not allowed in unquoted identifiers, or would be changed by the case
+    /// folding of the dialect.
+    /// A quoted identifier keeps its quote style if the dialect supports it, otherwise it's
+    /// quoted with the quote character of the dialect, e.g. `` `foo` `` is rendered as `"foo"`
+    /// in PostgreSQL.
+    pub fn to_sql<D: Dialect>(&self, dialect: &D) -> String {
+        let conf = dialect.lexer_conf();
+        match self.quote {
+            Some(quote) if conf.is_delimited_identifier_start(quote) => self.to_string(),
+            None if !self.requires_quote(dialect) => self.value.clone(),
+            _ => {
+                let quote = ['"', '`', '[']
+                    .iter()
+                    .copied()
+                    .find(|&ch| conf.is_delimited_identifier_start(ch))
+                    .unwrap_or('"');
+                Ident::with_quote(quote, self.value.as_str()).to_string()
+            }
+        }
+    }
+
+    fn requires_quote<D: Dialect>(&self, dialect: &D) -> bool {
+        let conf = dialect.lexer_conf();
+        let mut chars = self.value.chars();
+        match chars.next() {
+            Some(ch) if conf.is_identifier_start(ch) => {}
+            _ => return true,
+        }
+        if !chars.all(|ch| conf.is_identifier_part(ch)) {
+            return true;
+        }
+        if !conf.identifier_case_folding().is_folded(&self.value) {
+            return true;
+        }
+        let uppercase = self.value.to_uppercase();
+        D::Keyword::KEYWORDS_STRING
+            .binary_search(&uppercase.as_str())
+            .map(|idx| D::Keyword::RESERVED_KEYWORDS.contains(&D::Keyword::KEYWORDS[idx]))
+            .unwrap_or(false)
+    }
+
+    /// The value of the identifier without quotes.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// An identifier can be "quoted" (<delimited identifier> in ANSI parlance).
+    /// The standard and most implementations allow using double quotes for this,
+    /// but some implementations support other quoting styles as well.
+    /// Valid quote characters are the single quote, double quote, backtick, and
+    /// opening square bracket.
+    pub fn quote(&self) -> Option<char> {
+        self.quote
+    }
 }
 
 impl From<&str> for Ident {
@@ -53,20 +137,43 @@ impl From<&str> for Ident {
     }
 }
 
+// Only the valid quote characters are generated, see `Ident::with_quote`.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Ident {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Ident {
+            value: String::arbitrary(u)?,
+            quote: *u.choose(&[None, Some('\''), Some('"'), Some('`'), Some('[')])?,
+        })
+    }
+}
+
 impl fmt::Display for Ident {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.quote {
-            None => f.write_str(&self.value),
-            Some(q) if q == '"' || q == '\'' || q == '`' => write!(f, "{}{}{}", q, self.value, q),
-            Some(q) if q == '[' => write!(f, "[{}]", self.value),
+        let (open_quote, close_quote) = match self.quote {
+            None => return f.write_str(&self.value),
+            Some(q) if q == '"' || q == '\'' || q == '`' => (q, q),
+            // The close bracket can't be escaped, so the identifier is quoted with double quotes.
+            Some('[') if self.value.contains(']') => ('"', '"'),
+            Some('[') => ('[', ']'),
             Some(q) => panic!("Unsupported quote character {} for SQL identifier!", q),
+        };
+        // The close quote in the identifier is escaped by doubling it.
+        f.write_char(open_quote)?;
+        for ch in self.value.chars() {
+            if ch == close_quote {
+                f.write_char(ch)?;
+            }
+            f.write_char(ch)?;
         }
+        f.write_char(close_quote)
     }
 }
 
 /// A name of a table, view, custom type, etc. (possibly multi-part, i.e. db.schema.obj)
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ObjectName(pub Vec<Ident>);
 
 impl fmt::Display for ObjectName {
@@ -80,4 +187,78 @@ impl ObjectName {
     pub fn new<T: IntoIterator<Item = S>, S: Into<String>>(parts: T) -> Self {
         ObjectName(parts.into_iter().map(|s| Ident::new(s)).collect())
     }
+
+    /// Renders the object name as SQL of the given dialect, see [`Ident::to_sql`].
+    pub fn to_sql<D: Dialect>(&self, dialect: &D) -> String {
+        self.0
+            .iter()
+            .map(|ident| ident.to_sql(dialect))
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ident_display() {
+        assert_eq!(Ident::new("foo").to_string(), "foo");
+        assert_eq!(Ident::with_quote('"', "a\"b").to_string(), "\"a\"\"b\"");
+        assert_eq!(Ident::with_quote('`', "a`b").to_string(), "`a``b`");
+        assert_eq!(Ident::with_quote('[', "a\"b").to_string(), "[a\"b]");
+        assert_eq!(Ident::with_quote('[', "a]\"b").to_string(), "\"a]\"\"b\"");
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-normalization")]
+    fn ident_normalized_value() {
+        let composed = Ident::with_quote('"', "caf\u{e9}");
+        let decomposed = Ident::with_quote('"', "cafe\u{301}");
+        assert_ne!(composed, decomposed);
+        assert_eq!(composed.normalized_value(), composed.value);
+        assert_eq!(decomposed.normalized_value(), composed.value);
+        // the identifier is rendered as written
+        assert_eq!(decomposed.to_string(), "\"cafe\u{301}\"");
+    }
+
+    #[test]
+    fn ident_to_sql() {
+        let postgres = crate::postgres::PostgresDialect::default();
+        assert_eq!(Ident::new("foo").to_sql(&postgres), "foo");
+        assert_eq!(Ident::new("order").to_sql(&postgres), "\"order\"");
+        assert_eq!(Ident::new("Foo").to_sql(&postgres), "\"Foo\"");
+        assert_eq!(Ident::new("foo bar").to_sql(&postgres), "\"foo bar\"");
+        assert_eq!(Ident::new("1foo").to_sql(&postgres), "\"1foo\"");
+        assert_eq!(Ident::new("a\"b").to_sql(&postgres), "\"a\"\"b\"");
+        assert_eq!(Ident::with_quote('"', "foo").to_sql(&postgres), "\"foo\"");
+
+        let ansi = crate::ansi::AnsiDialect::default();
+        assert_eq!(Ident::new("FOO").to_sql(&ansi), "FOO");
+        assert_eq!(Ident::new("foo").to_sql(&ansi), "\"foo\"");
+
+        let mysql = crate::mysql::MysqlDialect::default();
+        assert_eq!(Ident::new("Foo").to_sql(&mysql), "Foo");
+        assert_eq!(Ident::new("order").to_sql(&mysql), "\"order\"");
+        let mysql = crate::mysql::MysqlDialect::new(
+            crate::mysql::MySqlLexerConfig {
+                ansi_quotes_mode: false,
+            },
+            Default::default(),
+        );
+        assert_eq!(Ident::new("order").to_sql(&mysql), "`order`");
+        assert_eq!(Ident::new("a`b").to_sql(&mysql), "`a``b`");
+
+        let sqlite = crate::sqlite::SqliteDialect::default();
+        assert_eq!(Ident::new("a]b c").to_sql(&sqlite), "\"a]b c\"");
+        assert_eq!(Ident::with_quote('[', "a").to_sql(&sqlite), "[a]");
+
+        // the quote style is converted if the dialect doesn't support it
+        assert_eq!(Ident::with_quote('`', "a").to_sql(&postgres), "\"a\"");
+        assert_eq!(Ident::with_quote('[', "a").to_sql(&mysql), "`a`");
+
+        let name = ObjectName::new(vec!["public", "user"]);
+        assert_eq!(name.to_sql(&postgres), "public.\"user\"");
+    }
 }
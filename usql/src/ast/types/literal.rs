@@ -1,5 +1,5 @@
 #[cfg(not(feature = "std"))]
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::fmt;
 
 use crate::ast::utils::escape_single_quote_string;
@@ -7,6 +7,8 @@ use crate::ast::utils::escape_single_quote_string;
 /// SQL literal values such as null, boolean, number, string, datetime and interval.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum Literal {
     /// `NULL` value
     Null,
@@ -14,13 +16,17 @@ pub enum Literal {
     /// Boolean literal, TRUE or FALSE
     Boolean(bool),
 
-    /// Numeric literal
+    /// Numeric literal, the exact lexeme as written, e.g. `1.50`
     Number(String),
 
     /// String literal (single quoted), e.g. 'string'
     String(String),
+    /// String literal with a character set introducer, e.g. _utf8mb4'string'
+    CharsetString(CharsetString),
     /// National string literal, e.g. N'string'
     NationalString(String),
+    /// String literal with C-style escape sequences, e.g. E'it\'s' (PostgreSQL specific)
+    EscapeString(EscapeString),
     /// Hex string literal, e.g. X'0123456789abcdef'
     HexString(String),
     /// Bit string literal, e.g. B'010101'
@@ -50,7 +56,9 @@ impl fmt::Display for Literal {
             }
             Self::Number(v) => v.fmt(f),
             Self::String(v) => write!(f, "'{}'", escape_single_quote_string(v)),
+            Self::CharsetString(v) => v.fmt(f),
             Self::NationalString(v) => write!(f, "N'{}'", v),
+            Self::EscapeString(v) => v.fmt(f),
             Self::BitString(v) => write!(f, "B'{}'", v),
             Self::HexString(v) => write!(f, "X'{}'", v),
             Self::Date(v) => write!(f, "DATE '{}'", v),
@@ -61,15 +69,254 @@ impl fmt::Display for Literal {
     }
 }
 
+impl Literal {
+    /// Returns the value of a numeric literal as `i64`, if it is an integer in range.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Number(v) => v.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a numeric literal as `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(v) => v.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the exact value of a numeric literal as a decimal.
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        match self {
+            Self::Number(v) => Decimal::parse(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a string literal, with or without a character set introducer.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) | Self::NationalString(v) => Some(v),
+            Self::CharsetString(v) => Some(&v.value),
+            Self::EscapeString(v) => Some(&v.value),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes of a hex string literal, e.g. `[0x0a, 0xff]` for `X'0AFF'`.
+    /// Returns `None` if the literal contains an odd number of digits or an invalid digit.
+    pub fn as_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::HexString(v) => {
+                let digits = v
+                    .chars()
+                    .map(|ch| ch.to_digit(16))
+                    .collect::<Option<Vec<_>>>()?;
+                if digits.len() % 2 == 1 {
+                    return None;
+                }
+                let bytes = digits.chunks(2).map(|pair| (pair[0] << 4 | pair[1]) as u8);
+                Some(bytes.collect())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the bits of a bit string literal or a hex string literal, the most significant
+    /// bit first, e.g. `X'A'` is the same as `B'1010'`.
+    /// Returns `None` if the literal contains an invalid digit.
+    pub fn as_bitvec(&self) -> Option<Vec<bool>> {
+        match self {
+            Self::BitString(v) => v
+                .chars()
+                .map(|ch| match ch {
+                    '0' => Some(false),
+                    '1' => Some(true),
+                    _ => None,
+                })
+                .collect(),
+            Self::HexString(v) => {
+                let mut bits = Vec::with_capacity(v.len() * 4);
+                for ch in v.chars() {
+                    let digit = ch.to_digit(16)?;
+                    bits.extend((0..4).rev().map(|i| (digit >> i) & 1 == 1));
+                }
+                Some(bits)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An exact decimal number, whose value is `mantissa * 10^-scale`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Decimal {
+    /// The digits of the number without the decimal point.
+    pub(crate) mantissa: i128,
+    /// The number of digits after the decimal point.
+    pub(crate) scale: u32,
+}
+
+impl Decimal {
+    /// Creates a `Decimal` whose value is `mantissa * 10^-scale`.
+    pub fn new(mantissa: i128, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// The digits of the number without the decimal point.
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Parses a decimal from digits with an optional decimal point, e.g. `123.45`.
+    /// Returns `None` if the number is malformed or doesn't fit in the mantissa.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (int, frac) = match s.find('.') {
+            Some(idx) => (&s[..idx], &s[idx + 1..]),
+            None => (s, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return None;
+        }
+        let mut mantissa = 0i128;
+        for ch in int.chars().chain(frac.chars()) {
+            let digit = ch.to_digit(10)?;
+            mantissa = mantissa.checked_mul(10)?.checked_add(digit as i128)?;
+        }
+        Some(Self {
+            mantissa,
+            scale: frac.len() as u32,
+        })
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+        let divisor = 10i128.pow(self.scale);
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let int = (self.mantissa / divisor).abs();
+        let frac = (self.mantissa % divisor).abs();
+        write!(
+            f,
+            "{}{}.{:0width$}",
+            sign,
+            int,
+            frac,
+            width = self.scale as usize
+        )
+    }
+}
+
+/// String literal with a character set introducer, format: `_<charset>'<value>'`,
+/// e.g. `_utf8mb4'string'` (MySQL).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct CharsetString {
+    /// The character set name, without the leading underscore.
+    pub(crate) charset: String,
+    /// The value of the string.
+    pub(crate) value: String,
+}
+
+impl CharsetString {
+    /// Creates a `CharsetString`.
+    pub fn new(charset: String, value: String) -> Self {
+        Self { charset, value }
+    }
+
+    /// The character set name, without the leading underscore.
+    pub fn charset(&self) -> &str {
+        &self.charset
+    }
+
+    /// The value of the string.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for CharsetString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "_{}'{}'",
+            self.charset,
+            escape_single_quote_string(&self.value)
+        )
+    }
+}
+
+/// String literal with C-style escape sequences, format: `E'<raw>'`, e.g. `E'it\'s\n'`
+/// (PostgreSQL specific).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub struct EscapeString {
+    /// The string between the quotes as written, with the escape sequences.
+    pub(crate) raw: String,
+    /// The value of the string, with the escape sequences decoded.
+    pub(crate) value: String,
+}
+
+impl EscapeString {
+    /// Creates a `EscapeString`.
+    pub fn new(raw: String, value: String) -> Self {
+        Self { raw, value }
+    }
+
+    /// The string between the quotes as written, with the escape sequences.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// The value of the string, with the escape sequences decoded.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for EscapeString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "E'{}'", self.raw)
+    }
+}
+
 /// Date literal, format: `DATE '<years>-<months>-<days>', e.g. `DATE '2021-11-09'`.
 ///
 /// **NOTE**: the parser does not validate the `<value>` as required by the SQL specification.
 /// Downstream consumers are responsible for rejecting date with invalid value.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Date {
     /// The raw `<value>` that was present in `DATE '<value>'`.
-    pub value: String,
+    pub(crate) value: String,
+}
+
+impl Date {
+    /// Creates a `Date`.
+    pub fn new(value: String) -> Self {
+        Self { value }
+    }
+
+    /// The raw `<value>` that was present in `DATE '<value>'`.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
 }
 
 impl fmt::Display for Date {
@@ -89,9 +336,23 @@ impl fmt::Display for Date {
 /// Downstream consumers are responsible for rejecting time with invalid value.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Time {
     /// The raw `<value>` that was present in `TIME '<value>'`.
-    pub value: String,
+    pub(crate) value: String,
+}
+
+impl Time {
+    /// Creates a `Time`.
+    pub fn new(value: String) -> Self {
+        Self { value }
+    }
+
+    /// The raw `<value>` that was present in `TIME '<value>'`.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
 }
 
 impl fmt::Display for Time {
@@ -110,9 +371,23 @@ impl fmt::Display for Time {
 /// Downstream consumers are responsible for rejecting timestamp with invalid value.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub struct Timestamp {
     /// The raw `<value>` that was present in `TIMESTAMP '<value>'`.
-    pub value: String,
+    pub(crate) value: String,
+}
+
+impl Timestamp {
+    /// Creates a `Timestamp`.
+    pub fn new(value: String) -> Self {
+        Self { value }
+    }
+
+    /// The raw `<value>` that was present in `TIMESTAMP '<value>'`.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
 }
 
 impl fmt::Display for Timestamp {
@@ -135,32 +410,122 @@ impl fmt::Display for Timestamp {
 /// **Note**:
 ///
 /// 1. The SQL standard allows an optional sign before the value string, but
-/// it is not clear if any implementations support that syntax, so we
-/// don't currently try to parse it. (The sign can instead be included
-/// inside the value string.)
+///    it is not clear if any implementations support that syntax, so we
+///    don't currently try to parse it. (The sign can instead be included
+///    inside the value string.)
 ///
 /// 2. The parser does not validate the `<value>`, nor does it ensure that the
-/// `<leading_field>` units are coarser than the units in `<tailing_field>`,
-/// as required by the SQL specification. Downstream consumers are responsible
-/// for rejecting intervals with invalid values, like `'foobar'`, and invalid
-/// unit specifications, like `HOUR TO YEAR`.
+///    `<leading_field>` units are coarser than the units in `<tailing_field>`,
+///    as required by the SQL specification. Downstream consumers are responsible
+///    for rejecting intervals with invalid values, like `'foobar'`, and invalid
+///    unit specifications, like `HOUR TO YEAR`.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct Interval {
     /// The raw `<value>` that was present in `INTERVAL '<value>'`.
-    pub value: String,
+    pub(crate) value: String,
+    /// The unit of the first field in the interval.
+    /// For example, `INTERVAL 'T' MINUTE` means `T` is in minutes.
+    ///
+    /// **Note**: PostgreSQL allows omitting the qualifier, so we provide
+    /// this more general implementation.
+    pub(crate) leading_field: Option<DateTimeField>,
+    /// How many digits the leading field is allowed to occupy.
+    ///
+    /// Note that, according to the SQL specification, the interval `INTERVAL '1234' MINUTE(3)`
+    /// is invalid, but `INTERVAL '123' MINUTE(3)` is valid.
+    /// At present, such validation is left to downstream consumers.
+    pub(crate) leading_precision: Option<u64>,
+    /// How much precision to keep track of.
+    ///
+    /// If this is omitted, then clients should ignore all but the leading field.
+    /// If it is less precise than the tailing field, clients should ignore the
+    /// tailing field.
+    ///
+    /// For the following specifications:
+    ///
+    /// * in `INTERVAL '1:1:1' HOUR TO SECOND`, `tailing_field` will be
+    ///   `Some(DateTimeField::Second)`, and clients should compute an
+    ///   interval of 3661 seconds;
+    /// * in `INTERVAL '1:1:1' HOUR TO MINUTE`, `tailing_field` will be
+    ///   `Some(DateTimeField::Minute)`, and clients should compute an
+    ///   interval of 3660 seconds;
+    /// * in `INTERVAL '1:1:1' HOUR`, `tailing_field` will be `None`, and
+    ///   clients should compute an interval of 3600 seconds.
+    ///
+    pub(crate) tailing_field: Option<DateTimeField>,
+    /// If the tailing field is `SECOND`, the SQL standard permits the user to
+    /// specify the fractional precision of the seconds. This specification can
+    /// occur in either of two syntactic forms, depending on whether the
+    /// interval's leading field is also `SECOND`.
+    ///
+    /// If both the leading and tailing fields are `SECOND`, then the fractional
+    /// seconds precision is specified with the syntax `INTERVAL '_' SECOND (_, frac_prec)`.
+    /// If only the tailing field is `SECOND`, then the fractional seconds precision
+    /// is specified with the syntax `INTERVAL '_' {HOUR|MINUTE} TO SECOND (frac_prec)`.
+    pub(crate) fractional_seconds_precision: Option<u64>,
+}
+
+impl Interval {
+    /// Creates a `Interval`, the optional fields are unset.
+    pub fn new(value: String) -> Self {
+        Self {
+            value,
+            leading_field: None,
+            leading_precision: None,
+            tailing_field: None,
+            fractional_seconds_precision: None,
+        }
+    }
+
+    /// Sets the `leading_field`.
+    pub fn with_leading_field(mut self, leading_field: DateTimeField) -> Self {
+        self.leading_field = Some(leading_field);
+        self
+    }
+
+    /// Sets the `leading_precision`.
+    pub fn with_leading_precision(mut self, leading_precision: u64) -> Self {
+        self.leading_precision = Some(leading_precision);
+        self
+    }
+
+    /// Sets the `tailing_field`.
+    pub fn with_tailing_field(mut self, tailing_field: DateTimeField) -> Self {
+        self.tailing_field = Some(tailing_field);
+        self
+    }
+
+    /// Sets the `fractional_seconds_precision`.
+    pub fn with_fractional_seconds_precision(mut self, fractional_seconds_precision: u64) -> Self {
+        self.fractional_seconds_precision = Some(fractional_seconds_precision);
+        self
+    }
+
+    /// The raw `<value>` that was present in `INTERVAL '<value>'`.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
     /// The unit of the first field in the interval.
     /// For example, `INTERVAL 'T' MINUTE` means `T` is in minutes.
     ///
     /// **Note**: PostgreSQL allows omitting the qualifier, so we provide
     /// this more general implementation.
-    pub leading_field: Option<DateTimeField>,
+    pub fn leading_field(&self) -> Option<DateTimeField> {
+        self.leading_field
+    }
+
     /// How many digits the leading field is allowed to occupy.
     ///
     /// Note that, according to the SQL specification, the interval `INTERVAL '1234' MINUTE(3)`
     /// is invalid, but `INTERVAL '123' MINUTE(3)` is valid.
     /// At present, such validation is left to downstream consumers.
-    pub leading_precision: Option<u64>,
+    pub fn leading_precision(&self) -> Option<u64> {
+        self.leading_precision
+    }
+
     /// How much precision to keep track of.
     ///
     /// If this is omitted, then clients should ignore all but the leading field.
@@ -178,7 +543,10 @@ pub struct Interval {
     /// * in `INTERVAL '1:1:1' HOUR`, `tailing_field` will be `None`, and
     ///   clients should compute an interval of 3600 seconds.
     ///
-    pub tailing_field: Option<DateTimeField>,
+    pub fn tailing_field(&self) -> Option<DateTimeField> {
+        self.tailing_field
+    }
+
     /// If the tailing field is `SECOND`, the SQL standard permits the user to
     /// specify the fractional precision of the seconds. This specification can
     /// occur in either of two syntactic forms, depending on whether the
@@ -188,7 +556,28 @@ pub struct Interval {
     /// seconds precision is specified with the syntax `INTERVAL '_' SECOND (_, frac_prec)`.
     /// If only the tailing field is `SECOND`, then the fractional seconds precision
     /// is specified with the syntax `INTERVAL '_' {HOUR|MINUTE} TO SECOND (frac_prec)`.
-    pub fractional_seconds_precision: Option<u64>,
+    pub fn fractional_seconds_precision(&self) -> Option<u64> {
+        self.fractional_seconds_precision
+    }
+}
+
+// There is no tailing field if the leading field is `SECOND`, like the parser guarantees.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Interval {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let leading_field = Option::<DateTimeField>::arbitrary(u)?;
+        let tailing_field = match leading_field {
+            Some(DateTimeField::Second) => None,
+            _ => Option::<DateTimeField>::arbitrary(u)?,
+        };
+        Ok(Interval {
+            value: String::arbitrary(u)?,
+            leading_field,
+            leading_precision: Option::<u64>::arbitrary(u)?,
+            tailing_field,
+            fractional_seconds_precision: Option::<u64>::arbitrary(u)?,
+        })
+    }
 }
 
 impl fmt::Display for Interval {
@@ -238,6 +627,8 @@ impl fmt::Display for Interval {
 #[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
 pub enum DateTimeField {
     Year,
     Month,
@@ -277,6 +668,62 @@ mod tests {
 
         let hex = Literal::HexString("1234567890abcdf".into());
         assert_eq!(hex.to_string(), "X'1234567890abcdf'");
+        assert_eq!(hex.as_bytes(), None);
+
+        let charset = Literal::CharsetString(CharsetString {
+            charset: "utf8mb4".into(),
+            value: "it's".into(),
+        });
+        assert_eq!(charset.to_string(), "_utf8mb4'it''s'");
+        assert_eq!(charset.as_str(), Some("it's"));
+    }
+
+    #[test]
+    fn binary_literal_value() {
+        let hex = Literal::HexString("0aFF".into());
+        assert_eq!(hex.as_bytes(), Some(vec![0x0a, 0xff]));
+        assert_eq!(hex.as_bitvec().map(|bits| bits.len()), Some(16));
+        assert_eq!(Literal::HexString("zz".into()).as_bytes(), None);
+
+        let bit = Literal::BitString("1010".into());
+        assert_eq!(bit.as_bitvec(), Some(vec![true, false, true, false]));
+        assert_eq!(Literal::HexString("A".into()).as_bitvec(), bit.as_bitvec());
+        assert_eq!(bit.as_bytes(), None);
+        assert_eq!(Literal::BitString("012".into()).as_bitvec(), None);
+    }
+
+    #[test]
+    fn number_literal_value() {
+        let number = Literal::Number("1234".into());
+        assert_eq!(number.as_i64(), Some(1234));
+        assert_eq!(number.as_f64(), Some(1234.0));
+
+        let number = Literal::Number("12.50".into());
+        assert_eq!(number.as_i64(), None);
+        assert_eq!(number.as_f64(), Some(12.5));
+        let decimal = number.as_decimal().unwrap();
+        assert_eq!(decimal, Decimal::new(1250, 2));
+        assert_eq!((decimal.mantissa(), decimal.scale()), (1250, 2));
+        assert_eq!(decimal.to_string(), "12.50");
+        assert_eq!(number.to_string(), "12.50");
+
+        assert_eq!(
+            Literal::Number(".5".into())
+                .as_decimal()
+                .unwrap()
+                .to_string(),
+            "0.5"
+        );
+        assert_eq!(
+            Literal::Number("99999999999999999999".into()).as_i64(),
+            None
+        );
+        assert_eq!(Literal::String("1".into()).as_i64(), None);
+
+        let number = Literal::Number("1.5E-2".into());
+        assert_eq!(number.as_i64(), None);
+        assert_eq!(number.as_f64(), Some(0.015));
+        assert_eq!(number.as_decimal(), None);
     }
 
     #[test]
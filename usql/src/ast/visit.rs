@@ -0,0 +1,569 @@
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::ast::{expression::*, statement::*, types::ObjectName};
+
+/// A visitor of the expressions and queries of a statement, see [`walk_stmt`].
+///
+/// The `pre_*` methods are called before visiting the children of a node, and the `post_*`
+/// methods are called after that.
+pub(crate) trait Visitor {
+    fn pre_visit_query(&mut self, _query: &Query) {}
+    fn post_visit_query(&mut self, _query: &Query) {}
+    fn pre_visit_expr(&mut self, _expr: &Expr) {}
+    fn post_visit_expr(&mut self, _expr: &Expr) {}
+    /// Called for the table (or query) names referenced by the queries.
+    fn visit_table_name(&mut self, _name: &ObjectName) {}
+}
+
+/// Counts the expressions and queries, and tracks the maximum nesting depth of them.
+#[derive(Default)]
+pub(crate) struct NodeCounter {
+    pub(crate) count: usize,
+    pub(crate) max_depth: usize,
+    depth: usize,
+}
+
+impl NodeCounter {
+    fn enter(&mut self) {
+        self.count += 1;
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+    }
+
+    fn exit(&mut self) {
+        self.depth -= 1;
+    }
+}
+
+impl Visitor for NodeCounter {
+    fn pre_visit_query(&mut self, _query: &Query) {
+        self.enter();
+    }
+
+    fn post_visit_query(&mut self, _query: &Query) {
+        self.exit();
+    }
+
+    fn pre_visit_expr(&mut self, _expr: &Expr) {
+        self.enter();
+    }
+
+    fn post_visit_expr(&mut self, _expr: &Expr) {
+        self.exit();
+    }
+}
+
+pub(crate) fn walk_stmt<V: Visitor>(visitor: &mut V, stmt: &Stmt) {
+    match stmt {
+        Stmt::CreateTable(create) => {
+            match &create.content {
+                TableContent::Definition {
+                    columns,
+                    constraints,
+                } => {
+                    for column in columns {
+                        walk_column_def(visitor, column);
+                    }
+                    for constraint in constraints {
+                        if let TableConstraint::Check(expr) = constraint {
+                            walk_expr(visitor, expr);
+                        }
+                    }
+                }
+                TableContent::SubQuery(query) => walk_query(visitor, query),
+                TableContent::PartitionOf { bound, .. } => walk_partition_bound(visitor, bound),
+                TableContent::Like(_) => {}
+            }
+            if let Some(partition_by) = &create.partition_by {
+                walk_exprs(visitor, &partition_by.keys);
+                if let Some(subpartition_by) = &partition_by.subpartition_by {
+                    walk_exprs(visitor, &subpartition_by.keys);
+                }
+                for definition in &partition_by.definitions {
+                    match &definition.values {
+                        Some(PartitionValues::LessThan(values)) => {
+                            walk_partition_range_values(visitor, values)
+                        }
+                        Some(PartitionValues::In(exprs)) => walk_exprs(visitor, exprs),
+                        None => {}
+                    }
+                }
+            }
+        }
+        Stmt::AlterTable(alter) => match &alter.action {
+            AlterTableAction::AddColumn { column, .. } => walk_column_def(visitor, column),
+            AlterTableAction::AttachPartition { bound, .. } => walk_partition_bound(visitor, bound),
+            _ => {}
+        },
+        Stmt::CreateView(create) => {
+            for option in &create.options {
+                if let Some(value) = &option.value {
+                    walk_expr(visitor, value);
+                }
+            }
+            walk_query(visitor, &create.query);
+        }
+        Stmt::AlterView(alter) => {
+            if let AlterViewAction::As { query, .. } = &alter.action {
+                walk_query(visitor, query);
+            }
+        }
+        Stmt::CreateDomain(create) => {
+            for constraint in &create.constraints {
+                walk_domain_constraint(visitor, &constraint.constraint);
+            }
+        }
+        Stmt::AlterDomain(alter) => {
+            if let AlterDomainAction::AddConstraint(constraint) = &alter.action {
+                walk_domain_constraint(visitor, &constraint.constraint);
+            }
+        }
+        Stmt::AlterIndex(alter) => {
+            if let AlterIndexAction::SetParameters(assignments) = &alter.action {
+                walk_assignments(visitor, assignments);
+            }
+        }
+        Stmt::CreateSequence(create) => {
+            for option in &create.options {
+                match option {
+                    SequenceOption::StartWith(expr)
+                    | SequenceOption::IncrementBy(expr)
+                    | SequenceOption::MaxValue(Some(expr))
+                    | SequenceOption::MinValue(Some(expr))
+                    | SequenceOption::Cache(Some(expr)) => walk_expr(visitor, expr),
+                    _ => {}
+                }
+            }
+        }
+        Stmt::CreateForeignTable(create) => {
+            for column in &create.columns {
+                walk_column_def(visitor, column);
+            }
+        }
+        Stmt::CreatePolicy(create) => {
+            if let Some(using) = &create.using {
+                walk_expr(visitor, using);
+            }
+            if let Some(with_check) = &create.with_check {
+                walk_expr(visitor, with_check);
+            }
+        }
+        Stmt::Insert(insert) => walk_insert(visitor, insert),
+        Stmt::Delete(delete) => walk_delete(visitor, delete),
+        Stmt::Update(update) => walk_update(visitor, update),
+        Stmt::Select(SelectStmt(query)) => walk_query(visitor, query),
+        Stmt::CreateRole(create) => walk_role_options(visitor, &create.options),
+        Stmt::AlterRole(alter) => match &alter.action {
+            AlterRoleAction::Options(options) => walk_role_options(visitor, options),
+            AlterRoleAction::Set {
+                values: Some(values),
+                ..
+            } => walk_exprs(visitor, values),
+            _ => {}
+        },
+        Stmt::Pragma(PragmaStmt {
+            value: Some(expr), ..
+        })
+        | Stmt::Attach(AttachStmt { database: expr, .. })
+        | Stmt::Vacuum(VacuumStmt {
+            into: Some(expr), ..
+        }) => walk_expr(visitor, expr),
+        #[cfg(feature = "procedural")]
+        Stmt::CreateRoutine(create) => walk_procedural_stmt(visitor, &create.body),
+        #[cfg(feature = "procedural")]
+        Stmt::CreateTrigger(create) => walk_procedural_stmt(visitor, &create.body),
+        _ => {}
+    }
+}
+
+#[cfg(feature = "procedural")]
+fn walk_procedural_stmt<V: Visitor>(visitor: &mut V, stmt: &ProceduralStmt) {
+    match stmt {
+        ProceduralStmt::Compound(compound) => walk_procedural_stmts(visitor, &compound.stmts),
+        ProceduralStmt::Declare(declare) => {
+            if let Some(default) = &declare.default {
+                walk_expr(visitor, default);
+            }
+        }
+        ProceduralStmt::If(stmt) => {
+            for branch in &stmt.branches {
+                walk_expr(visitor, &branch.condition);
+                walk_procedural_stmts(visitor, &branch.stmts);
+            }
+            if let Some(stmts) = &stmt.else_stmts {
+                walk_procedural_stmts(visitor, stmts);
+            }
+        }
+        ProceduralStmt::While(stmt) => {
+            walk_expr(visitor, &stmt.condition);
+            walk_procedural_stmts(visitor, &stmt.stmts);
+        }
+        ProceduralStmt::Return(stmt) => walk_expr(visitor, &stmt.expr),
+        ProceduralStmt::Sql(stmt) => walk_stmt(visitor, stmt),
+    }
+}
+
+#[cfg(feature = "procedural")]
+fn walk_procedural_stmts<V: Visitor>(visitor: &mut V, stmts: &[ProceduralStmt]) {
+    for stmt in stmts {
+        walk_procedural_stmt(visitor, stmt);
+    }
+}
+
+fn walk_column_def<V: Visitor>(visitor: &mut V, column: &ColumnDef) {
+    for constraint in &column.constraints {
+        if let ColumnConstraint::Check(expr) = &constraint.constraint {
+            walk_expr(visitor, expr);
+        }
+    }
+}
+
+fn walk_domain_constraint<V: Visitor>(visitor: &mut V, constraint: &DomainConstraint) {
+    if let DomainConstraint::Check(expr) = constraint {
+        walk_expr(visitor, expr);
+    }
+}
+
+fn walk_partition_bound<V: Visitor>(visitor: &mut V, bound: &PartitionBound) {
+    match bound {
+        PartitionBound::In(exprs) => walk_exprs(visitor, exprs),
+        PartitionBound::Range { from, to } => {
+            walk_partition_range_values(visitor, from);
+            walk_partition_range_values(visitor, to);
+        }
+        _ => {}
+    }
+}
+
+fn walk_partition_range_values<V: Visitor>(visitor: &mut V, values: &[PartitionRangeValue]) {
+    for value in values {
+        if let PartitionRangeValue::Expr(expr) = value {
+            walk_expr(visitor, expr);
+        }
+    }
+}
+
+fn walk_role_options<V: Visitor>(visitor: &mut V, options: &[RoleOption]) {
+    for option in options {
+        if let RoleOption::ConnectionLimit(expr) = option {
+            walk_expr(visitor, expr);
+        }
+    }
+}
+
+fn walk_insert<V: Visitor>(visitor: &mut V, insert: &InsertStmt) {
+    walk_with(visitor, &insert.with);
+    match &insert.source {
+        InsertSource::Default => {}
+        InsertSource::Values { values, .. } => walk_values(visitor, values),
+        InsertSource::Subquery { subquery, .. } => walk_query(visitor, subquery),
+        InsertSource::Set(assignments) => walk_assignments(visitor, assignments),
+    }
+    walk_returning(visitor, &insert.returning);
+}
+
+fn walk_delete<V: Visitor>(visitor: &mut V, delete: &DeleteStmt) {
+    walk_with(visitor, &delete.with);
+    if let Some(selection) = &delete.selection {
+        walk_expr(visitor, &selection.expr);
+    }
+    walk_returning(visitor, &delete.returning);
+}
+
+fn walk_update<V: Visitor>(visitor: &mut V, update: &UpdateStmt) {
+    walk_with(visitor, &update.with);
+    walk_assignments(visitor, &update.assignments);
+    if let Some(selection) = &update.selection {
+        walk_expr(visitor, &selection.expr);
+    }
+    walk_returning(visitor, &update.returning);
+}
+
+fn walk_assignments<V: Visitor>(visitor: &mut V, assignments: &[Assignment]) {
+    for assignment in assignments {
+        walk_expr(visitor, &assignment.value);
+    }
+}
+
+fn walk_returning<V: Visitor>(visitor: &mut V, returning: &Option<Vec<SelectItem>>) {
+    for item in returning.iter().flatten() {
+        walk_select_item(visitor, item);
+    }
+}
+
+fn walk_with<V: Visitor>(visitor: &mut V, with: &Option<With>) {
+    for cte in with.iter().flat_map(|with| &with.ctes) {
+        match &cte.body {
+            CteBody::Query(query) => walk_query(visitor, query),
+            CteBody::Insert(insert) => walk_insert(visitor, insert),
+            CteBody::Update(update) => walk_update(visitor, update),
+            CteBody::Delete(delete) => walk_delete(visitor, delete),
+        }
+    }
+}
+
+pub(crate) fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
+    visitor.pre_visit_query(query);
+    walk_with(visitor, &query.with);
+    walk_query_body(visitor, &query.body);
+    if let Some(order_by) = &query.order_by {
+        walk_order_by(visitor, order_by);
+    }
+    visitor.post_visit_query(query);
+}
+
+fn walk_query_body<V: Visitor>(visitor: &mut V, body: &QueryBody) {
+    match body {
+        QueryBody::QuerySpec(spec) => walk_query_spec(visitor, spec),
+        QueryBody::Subquery(query) => walk_query(visitor, query),
+        QueryBody::Values(values) => walk_values(visitor, values),
+        QueryBody::Table(name) => visitor.visit_table_name(name),
+        QueryBody::Operation { left, right, .. } => {
+            walk_query_body(visitor, left);
+            walk_query_body(visitor, right);
+        }
+    }
+}
+
+fn walk_query_spec<V: Visitor>(visitor: &mut V, spec: &QuerySpec) {
+    for item in &spec.projection {
+        walk_select_item(visitor, item);
+    }
+    for table in spec.from.iter().flat_map(|from| &from.list) {
+        walk_table_reference(visitor, table);
+    }
+    if let Some(selection) = &spec.r#where {
+        walk_expr(visitor, &selection.expr);
+    }
+    if let Some(connect_by) = &spec.connect_by {
+        walk_optional_expr(visitor, &connect_by.start_with);
+        walk_expr(visitor, &connect_by.condition);
+    }
+    if let Some(having) = &spec.having {
+        walk_expr(visitor, &having.expr);
+    }
+    for window in spec.window.iter().flat_map(|window| &window.list) {
+        walk_window_spec(visitor, &window.spec);
+    }
+}
+
+fn walk_select_item<V: Visitor>(visitor: &mut V, item: &SelectItem) {
+    if let SelectItem::DerivedColumn { expr, .. } = item {
+        walk_expr(visitor, expr);
+    }
+}
+
+fn walk_values<V: Visitor>(visitor: &mut V, values: &Values) {
+    for row in &values.list {
+        walk_exprs(visitor, row);
+    }
+}
+
+fn walk_order_by<V: Visitor>(visitor: &mut V, order_by: &OrderBy) {
+    for spec in &order_by.list {
+        walk_expr(visitor, &spec.expr);
+    }
+}
+
+fn walk_window_spec<V: Visitor>(visitor: &mut V, spec: &WindowSpec) {
+    if let Some(order_by) = &spec.order_by {
+        walk_order_by(visitor, order_by);
+    }
+}
+
+fn walk_table_reference<V: Visitor>(visitor: &mut V, table: &TableReference) {
+    walk_table_factor(visitor, &table.relation);
+    for join in &table.joins {
+        walk_table_factor(visitor, &join.relation);
+        match &join.join {
+            JoinOperator::InnerJoin(JoinSpec::On(expr))
+            | JoinOperator::LeftOuterJoin(JoinSpec::On(expr))
+            | JoinOperator::RightOuterJoin(JoinSpec::On(expr))
+            | JoinOperator::FullOuterJoin(JoinSpec::On(expr)) => walk_expr(visitor, expr),
+            _ => {}
+        }
+    }
+}
+
+fn walk_table_factor<V: Visitor>(visitor: &mut V, factor: &TableFactor) {
+    match factor {
+        TableFactor::Table {
+            name, system_time, ..
+        } => {
+            visitor.visit_table_name(name);
+            match system_time {
+                Some(SystemTimePeriod::AsOf(time)) => walk_expr(visitor, time),
+                Some(
+                    SystemTimePeriod::Between(start, end) | SystemTimePeriod::FromTo(start, end),
+                ) => {
+                    walk_expr(visitor, start);
+                    walk_expr(visitor, end);
+                }
+                _ => {}
+            }
+        }
+        TableFactor::Derived { subquery, .. } => walk_query(visitor, subquery),
+        TableFactor::Function { function, .. } => walk_function(visitor, function),
+        TableFactor::NestedJoin(table) => walk_table_reference(visitor, table),
+    }
+}
+
+fn walk_function<V: Visitor>(visitor: &mut V, function: &Function) {
+    for arg in &function.args {
+        match arg {
+            FunctionArg::Named { arg, .. } => walk_expr(visitor, arg),
+            FunctionArg::Unnamed(arg) => walk_expr(visitor, arg),
+        }
+    }
+    if let Some(WindowNameOrSpec::Spec(spec)) = &function.over {
+        walk_window_spec(visitor, spec);
+    }
+}
+
+fn walk_exprs<V: Visitor>(visitor: &mut V, exprs: &[Expr]) {
+    for expr in exprs {
+        walk_expr(visitor, expr);
+    }
+}
+
+fn walk_optional_expr<V: Visitor>(visitor: &mut V, expr: &Option<Box<Expr>>) {
+    if let Some(expr) = expr {
+        walk_expr(visitor, expr);
+    }
+}
+
+pub(crate) fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr) {
+    visitor.pre_visit_expr(expr);
+    match expr {
+        Expr::Literal(_)
+        | Expr::SpecialValue(_)
+        | Expr::PseudoColumn(_)
+        | Expr::NextValueFor(_)
+        | Expr::Identifier(_)
+        | Expr::Wildcard
+        | Expr::QualifiedWildcard(_)
+        | Expr::CompoundIdentifier(_) => {}
+        Expr::CompositeWildcard(expr) | Expr::Nested(expr) => walk_expr(visitor, expr),
+        Expr::CompositeField(field) => walk_expr(visitor, &field.expr),
+        Expr::Subquery(query) | Expr::ArraySubquery(query) | Expr::Exists(query) => {
+            walk_query(visitor, query)
+        }
+        Expr::IsNull(is_null) => walk_expr(visitor, &is_null.expr),
+        Expr::IsDistinctFrom(is_distinct) => {
+            walk_expr(visitor, &is_distinct.left);
+            walk_expr(visitor, &is_distinct.right);
+        }
+        Expr::UnaryOp(unary) => walk_expr(visitor, &unary.expr),
+        Expr::BinaryOp(binary) => {
+            walk_expr(visitor, &binary.left);
+            walk_expr(visitor, &binary.right);
+        }
+        Expr::InList(in_list) => {
+            walk_expr(visitor, &in_list.expr);
+            walk_exprs(visitor, &in_list.list);
+        }
+        Expr::InSubquery(in_subquery) => {
+            walk_expr(visitor, &in_subquery.expr);
+            walk_query(visitor, &in_subquery.subquery);
+        }
+        Expr::Between(between) => {
+            walk_expr(visitor, &between.expr);
+            walk_expr(visitor, &between.low);
+            walk_expr(visitor, &between.high);
+        }
+        Expr::Case(case) => {
+            walk_optional_expr(visitor, &case.operand);
+            for (condition, result) in case.conditions.iter().zip(&case.results) {
+                walk_expr(visitor, condition);
+                walk_expr(visitor, result);
+            }
+            walk_optional_expr(visitor, &case.else_result);
+        }
+        Expr::Cast(cast) => walk_expr(visitor, &cast.expr),
+        Expr::Collate(collate) => walk_expr(visitor, &collate.expr),
+        Expr::Function(function) => walk_function(visitor, function),
+        Expr::NullIf(null_if) => {
+            walk_expr(visitor, &null_if.left);
+            walk_expr(visitor, &null_if.right);
+        }
+        Expr::Coalesce(exprs) | Expr::Greatest(exprs) | Expr::Least(exprs) => {
+            walk_exprs(visitor, exprs)
+        }
+        Expr::If(r#if) => {
+            walk_expr(visitor, &r#if.condition);
+            walk_expr(visitor, &r#if.then_result);
+            walk_expr(visitor, &r#if.else_result);
+        }
+        Expr::Array(array) => walk_exprs(visitor, &array.elements),
+        Expr::Extract(extract) => walk_expr(visitor, &extract.expr),
+        Expr::Substring(substring) => {
+            walk_expr(visitor, &substring.expr);
+            walk_optional_expr(visitor, &substring.substring_from);
+            walk_optional_expr(visitor, &substring.substring_for);
+        }
+        Expr::Trim(trim) => {
+            if let Some((_, expr)) = &trim.trim_where {
+                walk_expr(visitor, expr);
+            }
+            walk_expr(visitor, &trim.expr);
+        }
+        Expr::Position(position) => {
+            walk_expr(visitor, &position.expr);
+            walk_expr(visitor, &position.r#in);
+        }
+        Expr::Overlay(overlay) => {
+            walk_expr(visitor, &overlay.expr);
+            walk_expr(visitor, &overlay.overlay_what);
+            walk_expr(visitor, &overlay.overlay_from);
+            walk_optional_expr(visitor, &overlay.overlay_for);
+        }
+        Expr::Translate(translate) => walk_expr(visitor, &translate.expr),
+        Expr::ListAgg(list_agg) => {
+            walk_expr(visitor, &list_agg.expr);
+            walk_optional_expr(visitor, &list_agg.separator);
+            if let Some(ListAggOnOverflow::Truncate {
+                filler: Some(filler),
+                ..
+            }) = &list_agg.on_overflow
+            {
+                walk_expr(visitor, filler);
+            }
+            for order_by in &list_agg.within_group {
+                walk_order_by(visitor, order_by);
+            }
+        }
+    }
+    visitor.post_visit_expr(expr);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::ParserError, parser::Parser, postgres::PostgresDialect};
+
+    fn count(sql: &str) -> Result<(usize, usize), ParserError> {
+        let dialect = PostgresDialect::default();
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        Ok((stmt.node_count(), stmt.max_depth()))
+    }
+
+    #[test]
+    fn stmt_node_count_and_max_depth() -> Result<(), ParserError> {
+        assert_eq!(count("SELECT 1")?, (2, 2));
+        assert_eq!(count("SELECT a + 1 FROM t WHERE b")?, (5, 3));
+        assert_eq!(
+            count("SELECT * FROM t WHERE a IN (SELECT max(b) FROM u)")?,
+            (6, 5)
+        );
+        assert_eq!(
+            count("WITH c AS (SELECT 1) UPDATE t SET a = (a) WHERE b = 2")?,
+            (7, 2)
+        );
+        assert_eq!(count("CREATE TABLE t (a INT CHECK (a > 0))")?, (3, 2));
+        assert_eq!(count("DROP TABLE t")?, (0, 0));
+
+        let nested = format!("SELECT {}1{}", "(".repeat(10), ")".repeat(10));
+        assert_eq!(count(&nested)?, (12, 12));
+        Ok(())
+    }
+}
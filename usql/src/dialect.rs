@@ -1,6 +1,14 @@
-use core::{fmt::Debug, marker::PhantomData};
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
 
-use crate::keywords::KeywordDef;
+use crate::{
+    ast::types::Ident,
+    keywords::{Keyword, KeywordDef},
+};
 
 /// A simple customizable SQL dialect structure.
 #[derive(Clone, Debug)]
@@ -61,6 +69,40 @@ pub trait Dialect: Debug {
 
     /// Returns the parser configuration.
     fn parser_conf(&self) -> &Self::ParserConf;
+
+    /// Returns the optional SQL features that the parser accepts in the dialect.
+    fn capabilities(&self) -> Capabilities {
+        let conf = self.parser_conf();
+        let keyword = |keyword| Self::Keyword::KEYWORDS.contains(&keyword);
+        Capabilities {
+            window_functions: keyword(Keyword::OVER),
+            fetch_first: keyword(Keyword::FETCH),
+            limit_clause: keyword(Keyword::LIMIT),
+            select_without_from: conf.supports_select_without_from(),
+            returning_clause: conf.supports_returning_clause(),
+            update_returning: conf.supports_update_returning(),
+            data_modifying_cte: conf.supports_data_modifying_cte(),
+            insert_set: conf.supports_insert_set(),
+            empty_insert_values: conf.supports_empty_insert_values(),
+            partition_definitions: conf.supports_partition_definitions(),
+            group_by_select_list: conf.supports_group_by_select_list(),
+            group_by_all: conf.supports_group_by_all(),
+            connect_by: conf.supports_connect_by(),
+            into_outfile: conf.supports_into_outfile(),
+            for_xml_json: conf.supports_for_xml_json(),
+            mysql_database_options: conf.supports_mysql_database_options(),
+            postgres_database_options: conf.supports_postgres_database_options(),
+            system_versioning: conf.supports_system_versioning(),
+            next_value_for: conf.supports_next_value_for(),
+            rename_table: conf.supports_rename_table(),
+            conflict_clause: conf.supports_conflict_clause(),
+            index_hints: conf.supports_index_hints(),
+            charset_introducer: conf.supports_charset_introducer(),
+            sqlite_statements: conf.supports_sqlite_statements(),
+            mysql_operators: conf.supports_mysql_operators(),
+            postgres_operators: conf.supports_postgres_operators(),
+        }
+    }
 }
 
 /// The configuration of the lexer part of dialect.
@@ -90,7 +132,627 @@ pub trait DialectLexerConf: Clone + Debug {
     fn is_identifier_part(&self, ch: char) -> bool {
         ch.is_ascii_alphanumeric() || ch == '_'
     }
+
+    /// Determine if an unquoted identifier can begin with digits, like `1d` or `123abc`,
+    /// an identifier can't consist solely of digits, which is a numeric literal.
+    /// The default implementation is false.
+    fn supports_digit_leading_identifier(&self) -> bool {
+        false
+    }
+
+    /// Determine if a backslash at the start of a line begins a psql meta-command
+    /// (like `\connect db` or `\i file.sql`), which runs until the end of the line.
+    /// The default implementation is false.
+    fn supports_meta_command(&self) -> bool {
+        false
+    }
+
+    /// Determine if a string literal can be dollar-quoted, like `$$it's$$` or `$tag$body$tag$`.
+    /// The default implementation is false.
+    fn supports_dollar_quoted_string(&self) -> bool {
+        false
+    }
+
+    /// Determine if a string literal prefixed with `E` can contain the C-style escape sequences,
+    /// like `E'it\'s\n'`.
+    /// The default implementation is false.
+    fn supports_escape_string(&self) -> bool {
+        false
+    }
+
+    /// Determine if a multi-line comment can be nested, like `/* a /* b */ c */`.
+    /// The default implementation is ANSI SQL, which allows nested comments.
+    fn supports_nested_comments(&self) -> bool {
+        true
+    }
+
+    /// Determine if the `--` must be followed by a whitespace or control character to begin a
+    /// single-line comment, otherwise `--x` is two minus operators and `x`.
+    /// The default implementation is false.
+    fn requires_whitespace_after_double_dash(&self) -> bool {
+        false
+    }
+
+    /// Determine if a hex string literal must contain an even number of hexadecimal digits,
+    /// like `X'0A'`, so that it's a sequence of bytes.
+    /// The default implementation is ANSI SQL, which requires an even number of digits.
+    fn requires_even_hex_digits(&self) -> bool {
+        true
+    }
+
+    /// Returns how unquoted identifiers are folded.
+    /// The default implementation is ANSI SQL, which folds unquoted identifiers to upper case.
+    fn identifier_case_folding(&self) -> CaseFolding {
+        CaseFolding::Upper
+    }
+
+    /// Returns how the adjacent string literals separated only by whitespace are concatenated,
+    /// like `'foo'\n'bar'` which is the same as `'foobar'`.
+    /// The default implementation is ANSI SQL, which requires the whitespace to contain a newline.
+    fn string_literal_concatenation(&self) -> StringConcatenation {
+        StringConcatenation::Newline
+    }
+
+    /// Validates an identifier after it's tokenized, the value is without quotes and `quoted`
+    /// is true for a delimited identifier.
+    /// The default implementation accepts all identifiers.
+    fn validate_identifier(&self, _value: &str, _quoted: bool) -> IdentifierValidity {
+        IdentifierValidity::Valid
+    }
+}
+
+/// The validity of an identifier, see [`DialectLexerConf::validate_identifier`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum IdentifierValidity {
+    /// The identifier is valid.
+    Valid,
+    /// The identifier is accepted, but the database may not treat it as written,
+    /// like a PostgreSQL identifier that is truncated to 63 bytes.
+    Warning(String),
+    /// The identifier is rejected by the database.
+    Invalid(String),
+}
+
+/// The case folding of unquoted identifiers, see [`DialectLexerConf::identifier_case_folding`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CaseFolding {
+    /// Unquoted identifiers are folded to upper case.
+    Upper,
+    /// Unquoted identifiers are folded to lower case.
+    Lower,
+    /// Unquoted identifiers are kept as written.
+    None,
+}
+
+impl CaseFolding {
+    /// Returns true if folding the identifier doesn't change it.
+    pub fn is_folded(&self, ident: &str) -> bool {
+        match self {
+            CaseFolding::Upper => !ident.chars().any(char::is_lowercase),
+            CaseFolding::Lower => !ident.chars().any(char::is_uppercase),
+            CaseFolding::None => true,
+        }
+    }
+}
+
+/// The concatenation of adjacent string literals,
+/// see [`DialectLexerConf::string_literal_concatenation`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StringConcatenation {
+    /// The string literals are concatenated if the whitespace between them contains a newline.
+    Newline,
+    /// The string literals are always concatenated.
+    Always,
+    /// The string literals are never concatenated.
+    Never,
+}
+
+/// The precedence classes of operators, see [`DialectParserConf::prec_value`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Precedence {
+    /// `OR`
+    Or,
+    /// `XOR`
+    Xor,
+    /// `AND`
+    And,
+    /// The operand of prefix `NOT`
+    UnaryNot,
+    /// `IS [ NOT ] { NULL | DISTINCT FROM }`
+    Is,
+    /// `=`, `<>`, `!=` and `<=>`
+    Comparison,
+    /// `<`, `<=`, `>` and `>=`
+    LessGreater,
+    /// `[ NOT ] { IN | LIKE | ILIKE }`
+    Like,
+    /// `[ NOT ] BETWEEN`
+    Between,
+    /// `|`
+    BitwiseOr,
+    /// `#`
+    BitwiseXor,
+    /// `&`
+    BitwiseAnd,
+    /// `<<` and `>>`
+    BitwiseShift,
+    /// Other symbolic operators, like `~`, `~~`, `!~~` and `<->`
+    Other,
+    /// `+` and `-`, also the operand of prefix `+` and `-`
+    PlusMinus,
+    /// `*`, `/`, `%`, `DIV` and `MOD`
+    MulDivMod,
+    /// `||`
+    Concat,
+    /// `^`, bitwise XOR in MySQL and exponentiation in PostgreSQL
+    Caret,
+    /// `COLLATE`
+    Collate,
+    /// `::`
+    DoubleColon,
 }
 
+/// The keywords that start a clause or a join, which can't be used as an implicit alias.
+const RESERVED_FOR_ALIAS: &[Keyword] = &[
+    Keyword::CROSS,
+    Keyword::EXCEPT,
+    Keyword::FETCH,
+    Keyword::FROM,
+    Keyword::FULL,
+    Keyword::GROUP,
+    Keyword::HAVING,
+    Keyword::INNER,
+    Keyword::INTERSECT,
+    Keyword::INTO,
+    Keyword::JOIN,
+    Keyword::LEFT,
+    Keyword::LIMIT,
+    Keyword::NATURAL,
+    Keyword::OFFSET,
+    Keyword::ON,
+    Keyword::ORDER,
+    Keyword::OUTER,
+    Keyword::RETURNING,
+    Keyword::RIGHT,
+    Keyword::SET,
+    Keyword::UNION,
+    Keyword::USING,
+    Keyword::WHERE,
+    Keyword::WINDOW,
+];
+
 /// The configuration of the parser part of dialect.
-pub trait DialectParserConf: Clone + Debug {}
+pub trait DialectParserConf: Clone + Debug {
+    /// Returns the precedence value of the operator class, a higher value binds tighter.
+    /// The default implementation is mostly ANSI SQL.
+    fn prec_value(&self, prec: Precedence) -> u8 {
+        match prec {
+            Precedence::Or => 5,
+            Precedence::And => 10,
+            Precedence::UnaryNot => 15,
+            Precedence::Is => 17,
+            Precedence::Comparison
+            | Precedence::LessGreater
+            | Precedence::Like
+            | Precedence::Between
+            | Precedence::Other => 20,
+            Precedence::BitwiseOr => 21,
+            Precedence::BitwiseXor | Precedence::BitwiseShift | Precedence::Caret => 22,
+            Precedence::BitwiseAnd => 23,
+            Precedence::Xor => 24,
+            Precedence::PlusMinus => 30,
+            Precedence::MulDivMod | Precedence::Concat => 40,
+            Precedence::Collate => 45,
+            Precedence::DoubleColon => 50,
+        }
+    }
+
+    /// Determine if the MySQL operators `DIV`, `MOD`, `<=>` and the prefix `!` are supported.
+    /// The default implementation is false.
+    fn supports_mysql_operators(&self) -> bool {
+        false
+    }
+
+    /// Determine if the PostgreSQL operators `~~`, `!~~`, `#`, `|/`, `||/`, `@-@` and `<->`
+    /// are supported.
+    /// The default implementation is false.
+    fn supports_postgres_operators(&self) -> bool {
+        false
+    }
+
+    /// Determine if `||` is the string concatenation operator, otherwise it's the logical `OR`
+    /// operator like in MySQL.
+    /// The default implementation is true.
+    fn pipes_as_concat(&self) -> bool {
+        true
+    }
+
+    /// Determine if a string literal can be preceded by a character set introducer,
+    /// like `_utf8mb4'string'`.
+    /// The default implementation is false.
+    fn supports_charset_introducer(&self) -> bool {
+        false
+    }
+
+    /// Determine if a query specification may omit the `FROM` clause, like `SELECT 1`.
+    /// The default implementation is false (ANSI SQL requires the `FROM` clause).
+    fn supports_select_without_from(&self) -> bool {
+        false
+    }
+
+    /// Determine if the `INSERT` and `DELETE` statements can be followed by a `RETURNING` clause,
+    /// like `DELETE FROM t RETURNING id`.
+    /// The default implementation is false.
+    fn supports_returning_clause(&self) -> bool {
+        false
+    }
+
+    /// Determine if the `UPDATE` statement can be followed by a `RETURNING` clause too,
+    /// like `UPDATE t SET a = 1 RETURNING id`, see also [`DialectParserConf::supports_returning_clause`].
+    /// The default implementation is false.
+    fn supports_update_returning(&self) -> bool {
+        false
+    }
+
+    /// Determine if the body of a common table expression can be a data-modifying statement,
+    /// like `WITH moved AS (DELETE FROM a RETURNING *) INSERT INTO b SELECT * FROM moved`.
+    /// The default implementation is false.
+    fn supports_data_modifying_cte(&self) -> bool {
+        false
+    }
+
+    /// Determine if the SQLite statements `PRAGMA`, `ATTACH`, `DETACH`, `VACUUM`, `ANALYZE` and
+    /// `REINDEX` are supported.
+    /// The default implementation is false.
+    fn supports_sqlite_statements(&self) -> bool {
+        false
+    }
+
+    /// Determine if the conflict clauses `INSERT OR <conflict resolution>` and
+    /// `ON CONFLICT <conflict resolution>` of constraints are supported, like
+    /// `INSERT OR REPLACE INTO t VALUES (1)` or `UNIQUE (a) ON CONFLICT IGNORE`.
+    /// The default implementation is false.
+    fn supports_conflict_clause(&self) -> bool {
+        false
+    }
+
+    /// Determine if a table name can be followed by a partition selection and index hints,
+    /// like `t PARTITION (p0, p1) USE INDEX (idx)`.
+    /// The default implementation is false.
+    fn supports_index_hints(&self) -> bool {
+        false
+    }
+
+    /// Determine if the `REPLACE INTO ...` statement and the `INSERT INTO ... SET <assignments>`
+    /// form are supported, like `INSERT INTO t SET a = 1, b = 2`.
+    /// The default implementation is false.
+    fn supports_insert_set(&self) -> bool {
+        false
+    }
+
+    /// Determine if the column list and the rows of `INSERT INTO ... VALUES` can be empty,
+    /// like `INSERT INTO t () VALUES ()`, which inserts a row of the default values.
+    /// The default implementation is false.
+    fn supports_empty_insert_values(&self) -> bool {
+        false
+    }
+
+    /// Determine if the `PARTITION BY` clause of `CREATE TABLE` can be followed by the partition
+    /// definitions, like `PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10))`, the
+    /// `KEY`, `LINEAR` and `COLUMNS` partition strategies are supported as well.
+    /// The default implementation is false.
+    fn supports_partition_definitions(&self) -> bool {
+        false
+    }
+
+    /// Determine if the `GROUP BY` clause can reference the select list by an output column
+    /// alias or a 1-based position, like `SELECT a + b AS c FROM t GROUP BY c` or `GROUP BY 1`.
+    /// The default implementation is false.
+    fn supports_group_by_select_list(&self) -> bool {
+        false
+    }
+
+    /// Determine if the DuckDB-style `GROUP BY ALL` and `ORDER BY ALL` are supported, which
+    /// group by all the select list items without aggregate functions and sort by all the select
+    /// list items, like `SELECT a, b, sum(c) FROM t GROUP BY ALL ORDER BY ALL`.
+    /// The default implementation is false.
+    fn supports_group_by_all(&self) -> bool {
+        false
+    }
+
+    /// Determine if the Oracle hierarchical query clause `START WITH ... CONNECT BY ...` is
+    /// supported, like `SELECT id, LEVEL FROM t CONNECT BY PRIOR id = parent`, the `PRIOR`
+    /// operator and the `LEVEL` pseudo-column are recognized as well.
+    /// The default implementation is false.
+    fn supports_connect_by(&self) -> bool {
+        false
+    }
+
+    /// Determine if a query can be followed by the `INTO OUTFILE` or `INTO DUMPFILE` clause,
+    /// like `SELECT * FROM t INTO OUTFILE '/tmp/t.csv' FIELDS TERMINATED BY ','`.
+    /// The default implementation is false.
+    fn supports_into_outfile(&self) -> bool {
+        false
+    }
+
+    /// Determine if a query can be followed by the T-SQL `FOR XML` or `FOR JSON` clause,
+    /// like `SELECT * FROM t FOR JSON PATH, ROOT('rows')`.
+    /// The default implementation is false.
+    fn supports_for_xml_json(&self) -> bool {
+        false
+    }
+
+    /// Determine if `CREATE DATABASE` accepts the MySQL options `[ DEFAULT ] CHARACTER SET` and
+    /// `[ DEFAULT ] COLLATE`, like `CREATE DATABASE db DEFAULT CHARACTER SET utf8mb4`.
+    /// The default implementation is false.
+    fn supports_mysql_database_options(&self) -> bool {
+        false
+    }
+
+    /// Determine if `CREATE DATABASE` accepts the PostgreSQL options `OWNER`, `TEMPLATE`, `ENCODING`,
+    /// `LC_COLLATE` and `LC_CTYPE` after an optional `WITH`, like
+    /// `CREATE DATABASE db WITH OWNER = u ENCODING 'UTF8'`.
+    /// The default implementation is false.
+    fn supports_postgres_database_options(&self) -> bool {
+        false
+    }
+
+    /// Determine if a table can be system-versioned, like `CREATE TABLE t (x INT) WITH SYSTEM VERSIONING`
+    /// and `ALTER TABLE t { ADD | DROP } SYSTEM VERSIONING`.
+    /// The default implementation is false.
+    fn supports_system_versioning(&self) -> bool {
+        false
+    }
+
+    /// Determine if the next value of a sequence can be generated by `NEXT VALUE FOR <sequence>`,
+    /// like `SELECT NEXT VALUE FOR s`.
+    /// The default implementation is false.
+    fn supports_next_value_for(&self) -> bool {
+        false
+    }
+
+    /// Determine if the MySQL `RENAME TABLE` statement is supported,
+    /// like `RENAME TABLE a TO b, c TO d`.
+    /// The default implementation is false.
+    fn supports_rename_table(&self) -> bool {
+        false
+    }
+
+    /// Returns the keywords that can't be used as an implicit alias (an alias without `AS`),
+    /// besides the reserved keywords of the dialect, so that they terminate a select item or a
+    /// table factor, like `LIMIT` in `SELECT a FROM t LIMIT 1`.
+    /// The default implementation contains the keywords that start a clause or a join.
+    fn reserved_for_alias(&self) -> &[Keyword] {
+        RESERVED_FOR_ALIAS
+    }
+
+    /// Determine if the name is a type name, so that a call like `int4(x)` or `"timestamp"(x)`
+    /// is parsed as a function-style cast instead of a function call.
+    /// The default implementation is false.
+    fn is_type_name(&self, _name: &Ident) -> bool {
+        false
+    }
+}
+
+/// An optional SQL feature, which is supported by some dialects only.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Capability {
+    /// Window functions, like `ROW_NUMBER() OVER (ORDER BY a)`.
+    WindowFunctions,
+    /// The `FETCH FIRST` clause, like `FETCH FIRST 10 ROWS ONLY`.
+    FetchFirst,
+    /// The `LIMIT` clause, like `LIMIT 10`.
+    LimitClause,
+    /// See [`DialectParserConf::supports_select_without_from`].
+    SelectWithoutFrom,
+    /// See [`DialectParserConf::supports_returning_clause`].
+    ReturningClause,
+    /// See [`DialectParserConf::supports_update_returning`].
+    UpdateReturning,
+    /// See [`DialectParserConf::supports_data_modifying_cte`].
+    DataModifyingCte,
+    /// See [`DialectParserConf::supports_insert_set`].
+    InsertSet,
+    /// See [`DialectParserConf::supports_empty_insert_values`].
+    EmptyInsertValues,
+    /// See [`DialectParserConf::supports_partition_definitions`].
+    PartitionDefinitions,
+    /// See [`DialectParserConf::supports_group_by_select_list`].
+    GroupBySelectList,
+    /// See [`DialectParserConf::supports_group_by_all`].
+    GroupByAll,
+    /// See [`DialectParserConf::supports_connect_by`].
+    ConnectBy,
+    /// See [`DialectParserConf::supports_into_outfile`].
+    IntoOutfile,
+    /// See [`DialectParserConf::supports_for_xml_json`].
+    ForXmlJson,
+    /// See [`DialectParserConf::supports_mysql_database_options`].
+    MysqlDatabaseOptions,
+    /// See [`DialectParserConf::supports_postgres_database_options`].
+    PostgresDatabaseOptions,
+    /// See [`DialectParserConf::supports_system_versioning`].
+    SystemVersioning,
+    /// See [`DialectParserConf::supports_next_value_for`].
+    NextValueFor,
+    /// See [`DialectParserConf::supports_rename_table`].
+    RenameTable,
+    /// See [`DialectParserConf::supports_conflict_clause`].
+    ConflictClause,
+    /// See [`DialectParserConf::supports_index_hints`].
+    IndexHints,
+    /// See [`DialectParserConf::supports_charset_introducer`].
+    CharsetIntroducer,
+    /// See [`DialectParserConf::supports_sqlite_statements`].
+    SqliteStatements,
+    /// See [`DialectParserConf::supports_mysql_operators`].
+    MysqlOperators,
+    /// See [`DialectParserConf::supports_postgres_operators`].
+    PostgresOperators,
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Capability::WindowFunctions => "window functions",
+            Capability::FetchFirst => "FETCH FIRST clause",
+            Capability::LimitClause => "LIMIT clause",
+            Capability::SelectWithoutFrom => "SELECT without FROM",
+            Capability::ReturningClause => "RETURNING clause",
+            Capability::UpdateReturning => "RETURNING clause of UPDATE",
+            Capability::DataModifyingCte => "data-modifying statement in WITH",
+            Capability::InsertSet => "INSERT ... SET and REPLACE statement",
+            Capability::EmptyInsertValues => "empty column list and row of INSERT",
+            Capability::PartitionDefinitions => "partition definitions",
+            Capability::GroupBySelectList => "GROUP BY select list reference",
+            Capability::GroupByAll => "GROUP BY ALL and ORDER BY ALL",
+            Capability::ConnectBy => "CONNECT BY clause",
+            Capability::IntoOutfile => "INTO OUTFILE and INTO DUMPFILE clause",
+            Capability::ForXmlJson => "FOR XML and FOR JSON clause",
+            Capability::MysqlDatabaseOptions => "MySQL database options",
+            Capability::PostgresDatabaseOptions => "PostgreSQL database options",
+            Capability::SystemVersioning => "system-versioned tables",
+            Capability::NextValueFor => "NEXT VALUE FOR sequence expressions",
+            Capability::RenameTable => "RENAME TABLE statement",
+            Capability::ConflictClause => "conflict clause",
+            Capability::IndexHints => "index hints",
+            Capability::CharsetIntroducer => "character set introducer",
+            Capability::SqliteStatements => "SQLite statements",
+            Capability::MysqlOperators => "MySQL operators",
+            Capability::PostgresOperators => "PostgreSQL operators",
+        })
+    }
+}
+
+/// The optional SQL features that the parser accepts in a dialect, see [`Dialect::capabilities`],
+/// so that a tool can check whether a feature is available before parsing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// See [`Capability::WindowFunctions`].
+    pub window_functions: bool,
+    /// See [`Capability::FetchFirst`].
+    pub fetch_first: bool,
+    /// See [`Capability::LimitClause`].
+    pub limit_clause: bool,
+    /// See [`Capability::SelectWithoutFrom`].
+    pub select_without_from: bool,
+    /// See [`Capability::ReturningClause`].
+    pub returning_clause: bool,
+    /// See [`Capability::UpdateReturning`].
+    pub update_returning: bool,
+    /// See [`Capability::DataModifyingCte`].
+    pub data_modifying_cte: bool,
+    /// See [`Capability::InsertSet`].
+    pub insert_set: bool,
+    /// See [`Capability::EmptyInsertValues`].
+    pub empty_insert_values: bool,
+    /// See [`Capability::PartitionDefinitions`].
+    pub partition_definitions: bool,
+    /// See [`Capability::GroupBySelectList`].
+    pub group_by_select_list: bool,
+    /// See [`Capability::GroupByAll`].
+    pub group_by_all: bool,
+    /// See [`Capability::ConnectBy`].
+    pub connect_by: bool,
+    /// See [`Capability::IntoOutfile`].
+    pub into_outfile: bool,
+    /// See [`Capability::ForXmlJson`].
+    pub for_xml_json: bool,
+    /// See [`Capability::MysqlDatabaseOptions`].
+    pub mysql_database_options: bool,
+    /// See [`Capability::PostgresDatabaseOptions`].
+    pub postgres_database_options: bool,
+    /// See [`Capability::SystemVersioning`].
+    pub system_versioning: bool,
+    /// See [`Capability::NextValueFor`].
+    pub next_value_for: bool,
+    /// See [`Capability::RenameTable`].
+    pub rename_table: bool,
+    /// See [`Capability::ConflictClause`].
+    pub conflict_clause: bool,
+    /// See [`Capability::IndexHints`].
+    pub index_hints: bool,
+    /// See [`Capability::CharsetIntroducer`].
+    pub charset_introducer: bool,
+    /// See [`Capability::SqliteStatements`].
+    pub sqlite_statements: bool,
+    /// See [`Capability::MysqlOperators`].
+    pub mysql_operators: bool,
+    /// See [`Capability::PostgresOperators`].
+    pub postgres_operators: bool,
+}
+
+impl Capabilities {
+    /// Returns true if the capability is supported.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::WindowFunctions => self.window_functions,
+            Capability::FetchFirst => self.fetch_first,
+            Capability::LimitClause => self.limit_clause,
+            Capability::SelectWithoutFrom => self.select_without_from,
+            Capability::ReturningClause => self.returning_clause,
+            Capability::UpdateReturning => self.update_returning,
+            Capability::DataModifyingCte => self.data_modifying_cte,
+            Capability::InsertSet => self.insert_set,
+            Capability::EmptyInsertValues => self.empty_insert_values,
+            Capability::PartitionDefinitions => self.partition_definitions,
+            Capability::GroupBySelectList => self.group_by_select_list,
+            Capability::GroupByAll => self.group_by_all,
+            Capability::ConnectBy => self.connect_by,
+            Capability::IntoOutfile => self.into_outfile,
+            Capability::ForXmlJson => self.for_xml_json,
+            Capability::MysqlDatabaseOptions => self.mysql_database_options,
+            Capability::PostgresDatabaseOptions => self.postgres_database_options,
+            Capability::SystemVersioning => self.system_versioning,
+            Capability::NextValueFor => self.next_value_for,
+            Capability::RenameTable => self.rename_table,
+            Capability::ConflictClause => self.conflict_clause,
+            Capability::IndexHints => self.index_hints,
+            Capability::CharsetIntroducer => self.charset_introducer,
+            Capability::SqliteStatements => self.sqlite_statements,
+            Capability::MysqlOperators => self.mysql_operators,
+            Capability::PostgresOperators => self.postgres_operators,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::ParserError, parser::Parser};
+
+    #[test]
+    fn dialect_capabilities() {
+        let ansi = crate::ansi::AnsiDialect::default().capabilities();
+        assert!(ansi.fetch_first && !ansi.limit_clause && !ansi.select_without_from);
+        assert!(!ansi.group_by_select_list && !ansi.group_by_all);
+        let postgres = crate::postgres::PostgresDialect::default().capabilities();
+        assert!(postgres.returning_clause && postgres.data_modifying_cte && !postgres.insert_set);
+        let mysql = crate::mysql::MysqlDialect::default().capabilities();
+        assert!(mysql.supports(Capability::InsertSet));
+        assert!(!mysql.supports(Capability::ReturningClause));
+        assert!(mysql.partition_definitions && !postgres.partition_definitions);
+        assert!(mysql.empty_insert_values && !postgres.empty_insert_values);
+        assert!(mysql.mysql_database_options && !mysql.postgres_database_options);
+        assert!(postgres.postgres_database_options && !postgres.mysql_database_options);
+        let mariadb = crate::mariadb::MariadbDialect::default().capabilities();
+        assert!(mariadb.system_versioning && !mysql.system_versioning);
+        assert!(mysql.rename_table && mariadb.rename_table && !postgres.rename_table);
+        let sqlite = crate::sqlite::SqliteDialect::default().capabilities();
+        assert!(sqlite.sqlite_statements && sqlite.conflict_clause && !sqlite.fetch_first);
+
+        // the parse error of an unsupported construct references the capability
+        let dialect = crate::ansi::AnsiDialect::default();
+        let err = Parser::parse_script(&dialect, "SELECT 1 WHERE TRUE").unwrap_err();
+        assert_eq!(
+            err,
+            ParserError::UnsupportedFeature {
+                feature: "SELECT without FROM".into(),
+                capability: Some(Capability::SelectWithoutFrom),
+            }
+        );
+        assert_eq!(err.to_string(), "Unsupported feature: SELECT without FROM");
+    }
+}
@@ -1,8 +1,18 @@
 #[cfg(not(feature = "std"))]
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use core::fmt;
 
-/// Location info for input.
+use crate::dialect::Capability;
+
+/// Location info for input, the line is 1-based and the column is the 0-based number of
+/// characters (Unicode scalar values) before the location in the line.
+///
+/// Use [`LineColumn::convert`] to get the line and column in other conventions,
+/// like the 0-based lines and UTF-16 columns of the Language Server Protocol.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct LineColumn {
     pub(crate) line: usize,
@@ -27,6 +37,70 @@ impl LineColumn {
         LineColumn { line, column }
     }
 
+    /// Returns the 1-based line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the 0-based column number, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the location of the byte offset in the source, e.g. the start of a [`Span`].
+    /// `\n`, `\r\n` and `\r` are line breaks.
+    ///
+    /// **NOTE**: panics if the offset is not on a character boundary of the source.
+    pub fn from_offset(source: &str, offset: usize) -> LineColumn {
+        let mut location = LineColumn::default();
+        let mut chars = source[..offset.min(source.len())].chars().peekable();
+        while let Some(ch) = chars.next() {
+            location.advance_in_source(ch, chars.peek());
+        }
+        location
+    }
+
+    /// Returns the byte offset of the location in the source, a column beyond the end of the
+    /// line is clamped to the end of the line.
+    pub fn to_offset(&self, source: &str) -> usize {
+        let mut location = LineColumn::default();
+        let mut chars = source.char_indices().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            if location.line > self.line
+                || (location.line == self.line
+                    && (location.column >= self.column || ch == '\n' || ch == '\r'))
+            {
+                return offset;
+            }
+            location.advance_in_source(ch, chars.peek().map(|(_, ch)| ch));
+        }
+        source.len()
+    }
+
+    /// Converts the location in the source to the line and column numbers of the convention.
+    pub fn convert(&self, source: &str, convention: LineColumnConvention) -> (usize, usize) {
+        let line_start = LineColumn::new(self.line, 0).to_offset(source);
+        let text = &source[line_start..self.to_offset(source)];
+        let column = match convention.column_unit {
+            ColumnUnit::Byte => text.len(),
+            ColumnUnit::Char => text.chars().count(),
+            ColumnUnit::Utf16 => text.encode_utf16().count(),
+        };
+        (
+            self.line - 1 + convention.line_base,
+            column + convention.column_base,
+        )
+    }
+
+    fn advance_in_source(&mut self, ch: char, next: Option<&char>) {
+        // `\r` is a line break unless it's followed by `\n`
+        if ch == '\r' && next != Some(&'\n') {
+            self.advance('\n');
+        } else {
+            self.advance(ch);
+        }
+    }
+
     pub(crate) fn advance(&mut self, ch: char) {
         if ch == '\n' {
             self.column = 0;
@@ -44,6 +118,81 @@ impl LineColumn {
     }
 }
 
+/// The unit in which the columns are counted.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColumnUnit {
+    /// Bytes of the UTF-8 encoding.
+    Byte,
+    /// Characters (Unicode scalar values), the unit of [`LineColumn`].
+    Char,
+    /// UTF-16 code units, the unit of the Language Server Protocol.
+    Utf16,
+}
+
+/// The convention of the line and column numbers, see [`LineColumn::convert`].
+///
+/// The default convention is the one of [`LineColumn`], the Language Server Protocol uses
+/// `{ line_base: 0, column_base: 0, column_unit: ColumnUnit::Utf16 }`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineColumnConvention {
+    /// The number of the first line.
+    pub line_base: usize,
+    /// The number of the first column.
+    pub column_base: usize,
+    /// The unit in which the columns are counted.
+    pub column_unit: ColumnUnit,
+}
+
+impl Default for LineColumnConvention {
+    fn default() -> Self {
+        Self {
+            line_base: 1,
+            column_base: 0,
+            column_unit: ColumnUnit::Char,
+        }
+    }
+}
+
+/// Byte range `[start, end)` of some text in the original input.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// The byte offset of the first character.
+    pub start: usize,
+    /// The byte offset right after the last character.
+    pub end: usize,
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl Span {
+    /// Creates a new `Span` with the given start and end offsets.
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// Returns the length of the span in bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns true if the span covers no input.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns true if the given byte offset lies within the span.
+    pub fn contains(&self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+}
+
 /// Lexer error
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct LexerError {
@@ -62,27 +211,171 @@ impl fmt::Display for LexerError {
 #[cfg(feature = "std")]
 impl std::error::Error for LexerError {}
 
-/// Parser error
+/// Parser error, the variants are the categories of the error, so that callers can branch on
+/// the kind of the error instead of its message.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum ParserError {
     /// Tokenize error.
     TokenizeError(String),
-    /// Parse error.
+    /// A token is found where something else is expected.
+    #[non_exhaustive]
+    UnexpectedToken {
+        /// What the parser expected.
+        expected: String,
+        /// The unexpected token.
+        found: String,
+        /// The keywords that the parser expected, see [`ParserError::suggestion`].
+        keywords: ExpectedKeywords,
+    },
+    /// The input ends where something else is expected.
+    #[non_exhaustive]
+    UnexpectedEof {
+        /// What the parser expected.
+        expected: String,
+    },
+    /// The input is valid SQL, but the construct is not supported by the parser.
+    #[non_exhaustive]
+    UnsupportedFeature {
+        /// The name of the unsupported construct.
+        feature: String,
+        /// The capability of the construct, if it's supported by other dialects.
+        capability: Option<Capability>,
+    },
+    /// The expressions or queries of the input are nested too deeply.
+    RecursionLimit,
+    /// A literal can't be interpreted, e.g. an out of range number.
+    InvalidLiteral(String),
+    /// Other parse error.
     ParseError(String),
 }
 
 impl fmt::Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            ParserError::TokenizeError(s) => s,
-            ParserError::ParseError(s) => s,
-        })
+        match self {
+            ParserError::TokenizeError(s) => f.write_str(s),
+            ParserError::UnexpectedToken {
+                expected, found, ..
+            } => {
+                write!(f, "Expected: {}, found: {}", expected, found)?;
+                if let Some(suggestion) = self.suggestion() {
+                    write!(f, ", did you mean {}?", suggestion)?;
+                }
+                Ok(())
+            }
+            ParserError::UnexpectedEof { expected } => {
+                write!(f, "Expected: {}, but not found", expected)
+            }
+            ParserError::UnsupportedFeature { feature, .. } => {
+                write!(f, "Unsupported feature: {}", feature)
+            }
+            ParserError::RecursionLimit => f.write_str("Recursion limit exceeded"),
+            ParserError::InvalidLiteral(s) => f.write_str(s),
+            ParserError::ParseError(s) => f.write_str(s),
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for ParserError {}
 
+impl ParserError {
+    /// Returns the expected keyword that the unexpected word is probably a misspelling of,
+    /// like `SELECT` for `SELCT`, which is computed from the expected keywords on demand.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        match self {
+            ParserError::UnexpectedToken {
+                found, keywords, ..
+            } => keywords.suggest(found),
+            _ => None,
+        }
+    }
+}
+
+/// The keywords that the parser expected when an unexpected token is found, so that a misspelled
+/// keyword can be reported with a "did you mean" suggestion.
+///
+/// **NOTE**: a word that is a keyword of the dialect isn't a misspelling, so no keyword is
+/// expected in that case.
+#[derive(Clone, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ExpectedKeywords {
+    /// Something other than a keyword is expected.
+    #[default]
+    None,
+    /// One of the listed keywords is expected.
+    OneOf(Vec<&'static str>),
+    /// Any keyword of the dialect is expected, like at the start of a statement.
+    Any(&'static [&'static str]),
+}
+
+impl fmt::Debug for ExpectedKeywords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpectedKeywords::None => f.write_str("None"),
+            ExpectedKeywords::OneOf(keywords) => f.debug_tuple("OneOf").field(keywords).finish(),
+            // the keywords of the dialect are too many to print
+            ExpectedKeywords::Any(_) => f.write_str("Any"),
+        }
+    }
+}
+
+impl ExpectedKeywords {
+    /// Returns the expected keyword that is closest to the unknown word by the edit distance.
+    fn suggest(&self, word: &str) -> Option<&'static str> {
+        let keywords = match self {
+            ExpectedKeywords::None => return None,
+            ExpectedKeywords::OneOf(keywords) => keywords.as_slice(),
+            ExpectedKeywords::Any(keywords) => keywords,
+        };
+        // only the unquoted words are misspelled keywords
+        if word.len() < 4
+            || word.starts_with(|ch: char| ch.is_ascii_digit())
+            || !word
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        {
+            return None;
+        }
+        let word = word.to_ascii_uppercase();
+        let max_distance = if word.len() <= 4 { 1 } else { 2 };
+        keywords
+            .iter()
+            .filter(|keyword| {
+                keyword.len() + max_distance >= word.len()
+                    && word.len() + max_distance >= keyword.len()
+            })
+            .map(|keyword| (edit_distance(&word, keyword), *keyword))
+            .filter(|(distance, _)| *distance <= max_distance)
+            // a keyword of the same length is preferred, like `FROM` rather than `FOR` for `FORM`
+            .min_by_key(|(distance, keyword)| (*distance, keyword.len() != word.len()))
+            .map(|(_, keyword)| keyword)
+    }
+}
+
+/// Computes the optimal string alignment distance of two ASCII strings, i.e. the Levenshtein
+/// distance in which the transposition of two adjacent characters counts as one edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    // the last two rows of the distance matrix
+    let mut prev2 = vec![0; b.len() + 1];
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                curr[j] = curr[j].min(prev2[j - 2] + 1);
+            }
+        }
+        core::mem::swap(&mut prev2, &mut prev);
+        core::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
 impl From<LexerError> for ParserError {
     fn from(err: LexerError) -> Self {
         Self::TokenizeError(err.to_string())
@@ -106,8 +399,65 @@ pub(crate) fn parse_error<R>(message: impl Into<String>) -> Result<R, ParserErro
     Err(ParserError::ParseError(message.into()))
 }
 
-/// A help function to create a parse error that indicates unexpected EOF.
-#[allow(unused)]
-pub(crate) fn unexpected_eof<R>() -> Result<R, ParserError> {
-    Err(ParserError::ParseError("Unexpected EOF".into()))
+/// A help function to create a parse error that indicates an unsupported construct.
+pub(crate) fn unsupported<R>(feature: impl Into<String>) -> Result<R, ParserError> {
+    Err(ParserError::UnsupportedFeature {
+        feature: feature.into(),
+        capability: None,
+    })
+}
+
+/// A help function to create a parse error that indicates a capability the dialect doesn't have.
+pub(crate) fn unsupported_capability<R>(capability: Capability) -> Result<R, ParserError> {
+    Err(ParserError::UnsupportedFeature {
+        feature: capability.to_string(),
+        capability: Some(capability),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_column_conventions() {
+        let source = "SELECT 'é😀'\r\nFROM t\rWHERE\n  a";
+        let location = LineColumn::from_offset(source, 14);
+        assert_eq!(location, LineColumn::new(1, 10));
+        assert_eq!(location.to_offset(source), 14);
+        assert_eq!(LineColumn::from_offset(source, 17), LineColumn::new(2, 0));
+        assert_eq!(LineColumn::from_offset(source, 24), LineColumn::new(3, 0));
+        assert_eq!(LineColumn::from_offset(source, 32), LineColumn::new(4, 2));
+        // the column beyond the end of the line is clamped
+        assert_eq!(LineColumn::new(2, 100).to_offset(source), 23);
+        assert_eq!(LineColumn::new(9, 0).to_offset(source), source.len());
+
+        let lsp = LineColumnConvention {
+            line_base: 0,
+            column_base: 0,
+            column_unit: ColumnUnit::Utf16,
+        };
+        assert_eq!(location.convert(source, lsp), (0, 11));
+        let editor = LineColumnConvention {
+            line_base: 1,
+            column_base: 1,
+            column_unit: ColumnUnit::Byte,
+        };
+        assert_eq!(location.convert(source, editor), (1, 15));
+        assert_eq!(
+            location.convert(source, LineColumnConvention::default()),
+            (1, 10)
+        );
+    }
+
+    #[test]
+    fn keyword_edit_distance() {
+        assert_eq!(edit_distance("SELECT", "SELECT"), 0);
+        assert_eq!(edit_distance("SELCT", "SELECT"), 1);
+        assert_eq!(edit_distance("FORM", "FROM"), 1);
+        assert_eq!(edit_distance("FORM", "FOR"), 1);
+        assert_eq!(edit_distance("UPDAET", "UPDATE"), 1);
+        assert_eq!(edit_distance("ABC", ""), 3);
+        assert_eq!(edit_distance("KITTEN", "SITTING"), 3);
+    }
 }
@@ -13,6 +13,49 @@ where
 
     /// All sorted reserved keywords for the definition.
     const RESERVED_KEYWORDS: &'static [Keyword];
+
+    /// Returns the keyword of the word, which is matched case-insensitively (ASCII only),
+    /// without allocating an uppercase copy of the word.
+    fn lookup(word: &str) -> Option<Keyword> {
+        let word = word.as_bytes();
+        Self::KEYWORDS_STRING
+            .binary_search_by(|keyword| {
+                keyword
+                    .bytes()
+                    .cmp(word.iter().map(|byte| byte.to_ascii_uppercase()))
+            })
+            .map(|idx| Self::KEYWORDS[idx])
+            .ok()
+    }
+}
+
+/// Merges the sorted keywords of a base definition and the sorted extra keywords at compile
+/// time, which is used by `define_keyword!` to extend the keywords of another dialect.
+///
+/// The variants of [`Keyword`] are declared in the order of their strings, so the keywords are
+/// compared by the variants, `N` must be the total number of the keywords.
+#[doc(hidden)]
+pub const fn merge_keywords<const N: usize>(
+    base: &[Keyword],
+    base_strings: &[&'static str],
+    extra: &[Keyword],
+    extra_strings: &[&'static str],
+) -> ([Keyword; N], [&'static str; N]) {
+    let mut keywords = [Keyword::A; N];
+    let mut strings = [""; N];
+    let (mut i, mut j) = (0, 0);
+    while i + j < N {
+        if j == extra.len() || (i < base.len() && (base[i] as usize) < (extra[j] as usize)) {
+            keywords[i + j] = base[i];
+            strings[i + j] = base_strings[i];
+            i += 1;
+        } else {
+            keywords[i + j] = extra[j];
+            strings[i + j] = extra_strings[j];
+            j += 1;
+        }
+    }
+    (keywords, strings)
 }
 
 define_all_keywords! {
@@ -514,6 +557,8 @@ define_all_keywords! {
     LAST,
     LAST_VALUE,
     LATERAL,
+    LC_COLLATE,
+    LC_CTYPE,
     LEAD,
     LEADING,
     LEAKPROOF,
@@ -653,7 +698,11 @@ define_all_keywords! {
     NFKC,
     NFKD,
     NO,
+    NOCACHE,
+    NOCYCLE,
     NODEGROUP,
+    NOMAXVALUE,
+    NOMINVALUE,
     NONE,
     NORMALIZE,
     NORMALIZED,
@@ -1230,3 +1279,34 @@ define_all_keywords! {
     ZEROFILL,
     ZONE
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_keywords<K: KeywordDef>() {
+        // the binary search of `lookup` requires the keywords sorted by bytes
+        assert!(K::KEYWORDS_STRING.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(K::KEYWORDS.len(), K::KEYWORDS_STRING.len());
+        for (keyword, string) in K::KEYWORDS.iter().zip(K::KEYWORDS_STRING) {
+            assert_eq!(K::lookup(string), Some(*keyword));
+            assert_eq!(K::lookup(&string.to_ascii_lowercase()), Some(*keyword));
+        }
+    }
+
+    #[test]
+    fn lookup_keyword() {
+        check_keywords::<crate::ansi::AnsiKeyword>();
+        check_keywords::<crate::mariadb::MariadbKeyword>();
+        check_keywords::<crate::mysql::MysqlKeyword>();
+        check_keywords::<crate::postgres::PostgresKeyword>();
+        check_keywords::<crate::sqlite::SqliteKeyword>();
+
+        type K = crate::ansi::AnsiKeyword;
+        assert_eq!(K::lookup("SeLeCt"), Some(Keyword::SELECT));
+        assert_eq!(K::lookup("selects"), None);
+        assert_eq!(K::lookup(""), None);
+        // only the ASCII letters are case-insensitive
+        assert_eq!(K::lookup("ſelect"), None);
+    }
+}
@@ -5,28 +5,97 @@ use alloc::{
     vec,
     vec::Vec,
 };
-use core::{iter::Peekable, str::Chars};
+use core::{
+    iter::Peekable,
+    str::{self, Chars},
+};
 
 use crate::{
-    dialect::{Dialect, DialectLexerConf},
-    error::{LexerError, LineColumn},
-    tokens::{Comment, Token, Whitespace},
+    dialect::{
+        Dialect, DialectLexerConf, DialectParserConf, IdentifierValidity, StringConcatenation,
+    },
+    error::{LexerError, LineColumn, Span},
+    tokens::{Comment, Delimiter, Group, Token, TokenStream, TokenTree, Whitespace},
 };
 
+/// The size limits of lexer, which guard against adversarial inputs.
+/// `None` means unlimited.
+///
+/// The lengths are checked while the characters are scanned, so an oversized token is rejected
+/// before it's buffered entirely.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LexerLimits {
+    /// The maximum number of characters of an identifier.
+    pub max_identifier_length: Option<usize>,
+    /// The maximum number of characters of a string literal.
+    pub max_string_length: Option<usize>,
+    /// The maximum number of characters of a comment.
+    pub max_comment_length: Option<usize>,
+    /// The maximum number of tokens, including whitespaces and comments.
+    pub max_tokens: Option<usize>,
+}
+
+/// The kinds of tokens whose lengths are limited, see [`LexerLimits`].
+#[derive(Copy, Clone, Debug)]
+enum LengthLimit {
+    Identifier,
+    String,
+    Comment,
+}
+
+impl LengthLimit {
+    fn max(self, limits: &LexerLimits) -> Option<usize> {
+        match self {
+            Self::Identifier => limits.max_identifier_length,
+            Self::String => limits.max_string_length,
+            Self::Comment => limits.max_comment_length,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Identifier => "Identifier",
+            Self::String => "String literal",
+            Self::Comment => "Comment",
+        }
+    }
+}
+
 /// SQL Lexer
 pub struct Lexer<'a, D: Dialect> {
     dialect: &'a D,
+    input: &'a str,
     iter: Peekable<Chars<'a>>,
     location: LineColumn,
+    offset: usize,
+    line_start: bool,
+    limits: LexerLimits,
+    token_count: usize,
+    warnings: Vec<LexerError>,
+    /// Whether a lookahead beyond the current token reached the end of the input.
+    lookahead_eof: bool,
 }
 
 impl<'a, D: Dialect> Lexer<'a, D> {
     /// Creates a new SQL lexer for the given input string.
     pub fn new(dialect: &'a D, input: &'a str) -> Self {
+        Self::new_with_limits(dialect, input, LexerLimits::default())
+    }
+
+    /// Creates a new SQL lexer for the given input string with the size limits.
+    pub fn new_with_limits(dialect: &'a D, input: &'a str, limits: LexerLimits) -> Self {
         Self {
             dialect,
+            input,
             iter: input.chars().peekable(),
             location: LineColumn::default(),
+            offset: 0,
+            line_start: true,
+            limits,
+            token_count: 0,
+            warnings: vec![],
+            lookahead_eof: false,
         }
     }
 
@@ -39,11 +108,271 @@ impl<'a, D: Dialect> Lexer<'a, D> {
         Ok(tokens)
     }
 
+    /// Tokenizes the statement and produce a sequence of tokens, each token
+    /// is paired with its byte range in the input.
+    pub fn tokenize_with_spans(mut self) -> Result<Vec<(Token, Span)>, LexerError> {
+        let mut tokens = vec![];
+        while let Some(token) = self.next_token_with_span()? {
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+
+    /// Tokenizes the statement like [`Lexer::tokenize_with_spans`], and appends the warnings
+    /// like [`Lexer::tokenize_with_warnings`] to `warnings`.
+    pub(crate) fn tokenize_with_spans_and_warnings(
+        mut self,
+        warnings: &mut Vec<LexerError>,
+    ) -> Result<Vec<(Token, Span)>, LexerError> {
+        let mut tokens = vec![];
+        while let Some(token) = self.next_token_with_span()? {
+            tokens.push(token);
+        }
+        warnings.append(&mut self.warnings);
+        Ok(tokens)
+    }
+
+    /// Appends the warnings of the tokens scanned so far to `warnings`.
+    pub(crate) fn append_warnings(&mut self, warnings: &mut Vec<LexerError>) {
+        warnings.append(&mut self.warnings);
+    }
+
+    /// Tokenizes the statement and produce a sequence of tokens, together with the warnings of
+    /// the identifiers that are accepted but may not be treated as written by the database,
+    /// see [`DialectLexerConf::validate_identifier`].
+    pub fn tokenize_with_warnings(mut self) -> Result<(Vec<Token>, Vec<LexerError>), LexerError> {
+        let mut tokens = vec![];
+        while let Some(token) = self.next_token()? {
+            tokens.push(token);
+        }
+        Ok((tokens, self.warnings))
+    }
+
+    /// Tokenizes the bytes that are expected to be UTF-8 but may contain invalid sequences, like
+    /// a dump file with a string literal in another encoding, each token is paired with its byte
+    /// range in the bytes.
+    ///
+    /// A leading UTF-8 byte order mark is skipped, and every invalid UTF-8 sequence is replaced
+    /// with `U+FFFD REPLACEMENT CHARACTER` and appended to `warnings`, together with the warnings
+    /// like [`Lexer::tokenize_with_warnings`]. The locations of the errors and the warnings are
+    /// the ones in the replaced text.
+    pub fn tokenize_bytes_lossy(
+        dialect: &D,
+        input: &[u8],
+        warnings: &mut Vec<LexerError>,
+    ) -> Result<Vec<(Token, Span)>, LexerError> {
+        const BOM: &[u8] = b"\xEF\xBB\xBF";
+        let bom = if input.starts_with(BOM) { BOM.len() } else { 0 };
+
+        // the replaced offset and the input offset after each replacement
+        let mut replacements = vec![];
+        let mut utf8_warnings = vec![];
+        let mut location = LineColumn::default();
+        let mut text = String::with_capacity(input.len() - bom);
+        let mut rest = &input[bom..];
+        loop {
+            match str::from_utf8(rest) {
+                Ok(valid) => {
+                    text.push_str(valid);
+                    break;
+                }
+                Err(err) => {
+                    let (valid, invalid) = rest.split_at(err.valid_up_to());
+                    // the bytes up to `valid_up_to` are always valid
+                    let valid = str::from_utf8(valid).unwrap_or_default();
+                    let delta = LineColumn::from_offset(valid, valid.len());
+                    location = if delta.line() == 1 {
+                        LineColumn::new(location.line(), location.column() + delta.column())
+                    } else {
+                        LineColumn::new(location.line() + delta.line() - 1, delta.column())
+                    };
+                    utf8_warnings.push(location.into_error("Invalid UTF-8 sequence"));
+                    location.advance(char::REPLACEMENT_CHARACTER);
+                    text.push_str(valid);
+                    text.push(char::REPLACEMENT_CHARACTER);
+                    // an incomplete sequence at the end of the input
+                    let len = err.error_len().unwrap_or(invalid.len());
+                    rest = &invalid[len..];
+                    replacements.push((text.len(), input.len() - rest.len()));
+                }
+            }
+        }
+
+        let tokens =
+            Lexer::new(dialect, &text).tokenize_with_spans_and_warnings(&mut utf8_warnings)?;
+        utf8_warnings.sort_by_key(|warning| (warning.location.line(), warning.location.column()));
+        warnings.append(&mut utf8_warnings);
+
+        let to_input_offset = |offset: usize| {
+            let index = replacements.partition_point(|&(replaced, _)| replaced <= offset);
+            match index.checked_sub(1).map(|i| replacements[i]) {
+                Some((replaced, original)) => original + (offset - replaced),
+                None => bom + offset,
+            }
+        };
+        Ok(tokens
+            .into_iter()
+            .map(|(token, span)| {
+                let span = Span::new(to_input_offset(span.start), to_input_offset(span.end));
+                (token, span)
+            })
+            .collect())
+    }
+
+    /// Tokenizes the statement and produce a token stream that can be cached
+    /// and parsed multiple times.
+    pub fn tokenize_stream(self) -> Result<TokenStream, LexerError> {
+        self.tokenize_with_spans().map(TokenStream::new)
+    }
+
+    /// Tokenizes the statement and produce a sequence of token trees, the tokens
+    /// enclosed by balanced `()`, `[]` or `{}` are nested into a group.
+    pub fn tokenize_trees(mut self) -> Result<Vec<TokenTree>, LexerError> {
+        // the unclosed groups, with the location of their opening delimiters
+        let mut stack: Vec<(Group, LineColumn)> = vec![];
+        let mut trees = vec![];
+        loop {
+            let (start, location) = (self.offset, self.location);
+            let token = match self.next_token()? {
+                Some(token) => token,
+                None => break,
+            };
+            let span = Span::new(start, self.offset);
+            if let Some(delimiter) = Delimiter::from_open_token(&token) {
+                let group = Group {
+                    delimiter,
+                    trees: vec![],
+                    span,
+                };
+                stack.push((group, location));
+                continue;
+            }
+            let tree = match Delimiter::from_close_token(&token) {
+                Some(delimiter) => match stack.pop() {
+                    Some((mut group, _)) if group.delimiter == delimiter => {
+                        group.span.end = span.end;
+                        TokenTree::Group(group)
+                    }
+                    _ => return Err(location.into_error(format!("Unmatched closing `{}`", token))),
+                },
+                None => TokenTree::Token(token, span),
+            };
+            match stack.last_mut() {
+                Some((group, _)) => group.trees.push(tree),
+                None => trees.push(tree),
+            }
+        }
+        match stack.pop() {
+            Some((group, location)) => Err(location.into_error(format!(
+                "Unclosed delimiter `{}`",
+                group.delimiter.open_token()
+            ))),
+            None => Ok(trees),
+        }
+    }
+
+    /// Returns the next token paired with its byte range in the input.
+    pub(crate) fn next_token_with_span(&mut self) -> Result<Option<(Token, Span)>, LexerError> {
+        let start = self.offset;
+        let token = self.next_token()?;
+        Ok(token.map(|token| (token, Span::new(start, self.offset))))
+    }
+
+    /// Returns the location of the next character.
+    pub(crate) fn location(&self) -> LineColumn {
+        self.location
+    }
+
+    /// Moves the lexer back to the byte `offset` of the input, `location` must be the location
+    /// of the character at the offset, like the one recorded before a token.
+    pub(crate) fn rewind(&mut self, offset: usize, location: LineColumn) {
+        self.iter = self.input[offset..].chars().peekable();
+        self.offset = offset;
+        self.location = location;
+        self.line_start = false;
+    }
+
+    /// Skips the characters up to the byte `offset` of the input without tokenizing them.
+    pub(crate) fn skip_to(&mut self, offset: usize) {
+        while self.offset < offset && self.next_char().is_some() {}
+        self.line_start = false;
+    }
+
     fn next_token(&mut self) -> Result<Option<Token>, LexerError> {
+        let location = self.location;
+        let token = self.scan_token()?;
+        if let Some(token) = &token {
+            self.check_limits(token)?;
+            self.validate_identifier(token, location)?;
+        }
+        // Only whitespace and comments may appear between the line start and the token.
+        self.line_start = match &token {
+            Some(Token::Whitespace(Whitespace::Newline)) => true,
+            Some(Token::Comment(Comment::SingleLine { .. })) => true,
+            Some(Token::Whitespace(_)) => self.line_start,
+            _ => false,
+        };
+        Ok(token)
+    }
+
+    fn validate_identifier(
+        &mut self,
+        token: &Token,
+        location: LineColumn,
+    ) -> Result<(), LexerError> {
+        if let Token::Word(word) = token {
+            let conf = self.dialect.lexer_conf();
+            match conf.validate_identifier(&word.value, word.quote.is_some()) {
+                IdentifierValidity::Valid => {}
+                IdentifierValidity::Warning(message) => {
+                    self.warnings.push(location.into_error(message))
+                }
+                IdentifierValidity::Invalid(message) => return Err(location.into_error(message)),
+            }
+        }
+        Ok(())
+    }
+
+    fn check_limits(&mut self, token: &Token) -> Result<(), LexerError> {
+        self.token_count += 1;
+        if let Some(max) = self.limits.max_tokens {
+            if self.token_count > max {
+                return self.tokenize_error(format!("Exceeded the maximum of {} tokens", max));
+            }
+        }
+        // The lengths are checked while scanning the tokens, except the identifiers beginning
+        // with digits, whose digits are scanned as a number first.
+        match token {
+            Token::Word(word) => {
+                self.check_length(LengthLimit::Identifier, word.value.chars().count())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if the `len` characters of a token exceed the length limit.
+    fn check_length(&self, limit: LengthLimit, len: usize) -> Result<(), LexerError> {
+        match limit.max(&self.limits) {
+            Some(max) if len > max => self.tokenize_error(format!(
+                "{} exceeds the maximum length of {} characters",
+                limit.name(),
+                max
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn scan_token(&mut self) -> Result<Option<Token>, LexerError> {
         match self.iter.peek() {
             Some(&ch) => match ch {
                 // whitespace
                 ' ' | '\t' | '\n' | '\r' => Ok(self.tokenize_whitespace().map(Token::Whitespace)),
+                // psql meta-command
+                '\\' if self.line_start && self.dialect.lexer_conf().supports_meta_command() => {
+                    self.next_char(); // consume the backslash
+                    let command = self.next_while(|&ch| ch != '\n' && ch != '\r');
+                    Ok(Some(Token::MetaCommand(command)))
+                }
                 // national string literal
                 // The spec only allows an uppercase 'N' to introduce a national string literal,
                 // but PostgreSQL/MySQL, at least, allow a lowercase 'n' too.
@@ -56,7 +385,21 @@ impl<'a, D: Dialect> Lexer<'a, D> {
                         Ok(Some(Token::NationalString(s)))
                     } else {
                         // regular identifier starting with an "N" or "n"
-                        let ident = self.tokenize_ident(n);
+                        let ident = self.tokenize_ident(n)?;
+                        Ok(Some(Token::word::<D::Keyword, _>(ident, None)))
+                    }
+                }
+                // escape string literal
+                e @ 'E' | e @ 'e' if self.dialect.lexer_conf().supports_escape_string() => {
+                    self.next_char(); // consume the character and check the next one
+                    if self.next_if_is('\'') {
+                        // E'...' - escape string literal
+                        // open quote has been consumed
+                        let (raw, value) = self.tokenize_escape_string()?;
+                        Ok(Some(Token::EscapeString { raw, value }))
+                    } else {
+                        // regular identifier starting with an "E" or "e"
+                        let ident = self.tokenize_ident(e)?;
                         Ok(Some(Token::word::<D::Keyword, _>(ident, None)))
                     }
                 }
@@ -68,11 +411,13 @@ impl<'a, D: Dialect> Lexer<'a, D> {
                     if self.next_if_is('\'') {
                         // X'...' - <hexadecimal character string literal>
                         // open quote has been consumed
+                        let location = self.location;
                         let s = self.tokenize_string_literal('\'')?;
+                        self.validate_hex_string(&s, location)?;
                         Ok(Some(Token::HexString(s)))
                     } else {
                         // regular identifier starting with an "X" or "x"
-                        let ident = self.tokenize_ident(x);
+                        let ident = self.tokenize_ident(x)?;
                         Ok(Some(Token::word::<D::Keyword, _>(ident, None)))
                     }
                 }
@@ -84,18 +429,26 @@ impl<'a, D: Dialect> Lexer<'a, D> {
                     if self.next_if_is('\'') {
                         // B'...' - <binary character string literal>
                         // open quote has been consumed
+                        let location = self.location;
                         let s = self.tokenize_string_literal('\'')?;
+                        validate_digits(&s, location, 2, "binary")?;
                         Ok(Some(Token::BitString(s)))
                     } else {
                         // regular identifier starting with an "B" or "b"
-                        let ident = self.tokenize_ident(b);
+                        let ident = self.tokenize_ident(b)?;
                         Ok(Some(Token::word::<D::Keyword, _>(ident, None)))
                     }
                 }
                 // string literal
                 quote if self.dialect.lexer_conf().is_string_literal_quotation(quote) => {
                     self.next_char(); // consume the open quotation mark of string literal
-                    let s = self.tokenize_string_literal(quote)?;
+                    let mut s = self.tokenize_string_literal(quote)?;
+                    let mut len = s.chars().count();
+                    while let Some(next) = self.tokenize_adjacent_string_literal(quote)? {
+                        len += next.chars().count();
+                        self.check_length(LengthLimit::String, len)?;
+                        s.push_str(&next);
+                    }
                     Ok(Some(Token::String(s)))
                 }
                 // delimited (quoted) identifier
@@ -112,11 +465,15 @@ impl<'a, D: Dialect> Lexer<'a, D> {
                 // identifier or keyword
                 ch if self.dialect.lexer_conf().is_identifier_start(ch) => {
                     self.next_char(); // consume the identifier start character
-                    let ident = self.tokenize_ident(ch);
+                    let ident = self.tokenize_ident(ch)?;
                     Ok(Some(Token::word::<D::Keyword, _>(ident, None)))
                 }
                 // number or period
                 ch if ch.is_ascii_digit() || ch == '.' => self.tokenize_number(),
+                // dollar-quoted string literal
+                '$' if self.dialect.lexer_conf().supports_dollar_quoted_string() => {
+                    self.tokenize_dollar_quoted_string()
+                }
                 _ => self.tokenize_symbol(),
             },
             None => Ok(None),
@@ -124,7 +481,9 @@ impl<'a, D: Dialect> Lexer<'a, D> {
     }
 
     fn tokenize_whitespace(&mut self) -> Option<Whitespace> {
-        self.iter.next().map(|ch| match ch {
+        let ch = self.iter.next()?;
+        self.offset += 1;
+        Some(match ch {
             ' ' => {
                 self.location.column += 1;
                 Whitespace::Space
@@ -135,14 +494,16 @@ impl<'a, D: Dialect> Lexer<'a, D> {
             }
             '\n' => {
                 self.location.line += 1;
-                self.location.column = 1;
+                self.location.column = 0;
                 Whitespace::Newline
             }
             '\r' => {
                 // Emit a single Whitespace::Newline token for \r and \r\n
-                self.iter.next_if_eq(&'\n');
+                if self.iter.next_if_eq(&'\n').is_some() {
+                    self.offset += 1;
+                }
                 self.location.line += 1;
-                self.location.column = 1;
+                self.location.column = 0;
                 Whitespace::Newline
             }
             _ => unreachable!(),
@@ -150,7 +511,7 @@ impl<'a, D: Dialect> Lexer<'a, D> {
     }
 
     fn tokenize_string_literal(&mut self, quote: char) -> Result<String, LexerError> {
-        let s = self.next_while(|&ch| ch != quote);
+        let s = self.next_while_limited(|&ch| ch != quote, LengthLimit::String, 0)?;
         // consume the close quote.
         if self.next_char() == Some(quote) {
             Ok(s)
@@ -159,37 +520,291 @@ impl<'a, D: Dialect> Lexer<'a, D> {
         }
     }
 
+    /// Validates the digits of a hex string literal, `location` is the location of the first
+    /// digit.
+    fn validate_hex_string(&self, s: &str, location: LineColumn) -> Result<(), LexerError> {
+        validate_digits(s, location, 16, "hexadecimal")?;
+        if s.len() % 2 == 1 && self.dialect.lexer_conf().requires_even_hex_digits() {
+            return self.tokenize_error(
+                "The hex string literal must contain an even number of hexadecimal digits",
+            );
+        }
+        Ok(())
+    }
+
+    /// Tokenizes an escape string literal whose open quote has been consumed, and returns the
+    /// string as written and the string with the escape sequences decoded.
+    fn tokenize_escape_string(&mut self) -> Result<(String, String), LexerError> {
+        let start = self.location;
+        let mut raw = String::new();
+        let mut bytes = Vec::new();
+        // the number of the decoded characters
+        let mut len = 0;
+        loop {
+            self.check_length(LengthLimit::String, len)?;
+            let location = self.location;
+            let ch = match self.next_char() {
+                Some('\'') if self.next_if_is('\'') => {
+                    raw.push_str("''");
+                    '\''
+                }
+                Some('\'') => break,
+                Some('\\') => {
+                    raw.push('\\');
+                    let escaped = match self.next_char() {
+                        Some(escaped) => escaped,
+                        None => return self.tokenize_error("Unterminated string literal"),
+                    };
+                    raw.push(escaped);
+                    match escaped {
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        // octal byte value `\o`, `\oo` or `\ooo`
+                        '0'..='7' => {
+                            let first = escaped as u32 - '0' as u32;
+                            let (value, _) = self.next_digits(8, 2, first, &mut raw);
+                            let byte = escaped_byte(value, location)?;
+                            len += is_char_boundary(byte) as usize;
+                            bytes.push(byte);
+                            continue;
+                        }
+                        // hexadecimal byte value `\xh` or `\xhh`, `\x` alone is `x`
+                        'x' => match self.next_digits(16, 2, 0, &mut raw) {
+                            (_, 0) => 'x',
+                            (value, _) => {
+                                let byte = escaped_byte(value, location)?;
+                                len += is_char_boundary(byte) as usize;
+                                bytes.push(byte);
+                                continue;
+                            }
+                        },
+                        // Unicode character `\uxxxx` or `\Uxxxxxxxx`
+                        'u' => self.tokenize_unicode_escape(4, &mut raw, location)?,
+                        'U' => self.tokenize_unicode_escape(8, &mut raw, location)?,
+                        // any other character is taken literally, like `\\` and `\'`
+                        escaped => escaped,
+                    }
+                }
+                Some(ch) => {
+                    raw.push(ch);
+                    ch
+                }
+                None => return self.tokenize_error("Unterminated string literal"),
+            };
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            len += 1;
+        }
+        match String::from_utf8(bytes) {
+            Ok(value) => Ok((raw, value)),
+            Err(_) => Err(start.into_error("Invalid byte sequence in the escape string literal")),
+        }
+    }
+
+    /// Tokenizes the hexadecimal digits of a Unicode escape whose `\u` or `\U` has been
+    /// consumed, a character outside the Basic Multilingual Plane can be written as a UTF-16
+    /// surrogate pair of two `\uxxxx` escapes.
+    fn tokenize_unicode_escape(
+        &mut self,
+        len: usize,
+        raw: &mut String,
+        location: LineColumn,
+    ) -> Result<char, LexerError> {
+        let mut value = self.next_unicode_digits(len, raw, location)?;
+        if (0xd800..0xdc00).contains(&value) {
+            let low = if self.next_if_are("\\u") {
+                raw.push_str("\\u");
+                self.next_unicode_digits(4, raw, location)?
+            } else {
+                0
+            };
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(location.into_error("Invalid Unicode surrogate pair"));
+            }
+            value = 0x10000 + ((value - 0xd800) << 10) + (low - 0xdc00);
+        }
+        match char::from_u32(value) {
+            Some(ch) if ch != '\0' => Ok(ch),
+            _ => Err(location.into_error("Invalid Unicode escape value")),
+        }
+    }
+
+    fn next_unicode_digits(
+        &mut self,
+        len: usize,
+        raw: &mut String,
+        location: LineColumn,
+    ) -> Result<u32, LexerError> {
+        match self.next_digits(16, len, 0, raw) {
+            (value, count) if count == len => Ok(value),
+            _ => Err(location.into_error(format!(
+                "Invalid Unicode escape, {} hexadecimal digits are required",
+                len
+            ))),
+        }
+    }
+
+    /// Consumes at most `max` digits of the radix and appends them to `raw`, returns the value
+    /// of the digits following the `value` and the number of the digits.
+    fn next_digits(
+        &mut self,
+        radix: u32,
+        max: usize,
+        value: u32,
+        raw: &mut String,
+    ) -> (u32, usize) {
+        let mut value = value;
+        let mut count = 0;
+        while count < max {
+            let digit = match self.iter.peek().and_then(|ch| ch.to_digit(radix)) {
+                Some(digit) => digit,
+                None => break,
+            };
+            if let Some(ch) = self.next_char() {
+                raw.push(ch);
+            }
+            value = value * radix + digit;
+            count += 1;
+        }
+        (value, count)
+    }
+
+    /// Tokenizes the string literal following the whitespace after a string literal, if they are
+    /// concatenated in the dialect, see [`DialectLexerConf::string_literal_concatenation`].
+    fn tokenize_adjacent_string_literal(
+        &mut self,
+        quote: char,
+    ) -> Result<Option<String>, LexerError> {
+        let mut iter = self.iter.clone();
+        let mut whitespace = false;
+        let mut newline = false;
+        while let Some(ch) = iter.next_if(|ch| matches!(ch, ' ' | '\t' | '\n' | '\r')) {
+            whitespace = true;
+            newline |= ch == '\n' || ch == '\r';
+        }
+        let concatenated = match self.dialect.lexer_conf().string_literal_concatenation() {
+            StringConcatenation::Newline => newline,
+            StringConcatenation::Always => whitespace,
+            StringConcatenation::Never => return Ok(None),
+        };
+        self.lookahead_eof |= iter.peek().is_none();
+        if !concatenated || iter.peek() != Some(&quote) {
+            return Ok(None);
+        }
+        while self.iter.peek() != Some(&quote) {
+            self.tokenize_whitespace();
+        }
+        self.next_char(); // consume the open quotation mark of string literal
+        self.tokenize_string_literal(quote).map(Some)
+    }
+
+    /// Tokenizes a dollar-quoted string literal, the tag follows the rules of an unquoted
+    /// identifier, except that it can't contain a dollar sign.
+    /// A dollar sign that doesn't start a dollar quote (like `$1`) is tokenized as a symbol.
+    fn tokenize_dollar_quoted_string(&mut self) -> Result<Option<Token>, LexerError> {
+        let mut iter = self.iter.clone();
+        iter.next(); // skip the open dollar sign
+        let mut tag = String::new();
+        while let Some(ch) = iter.next_if(|ch| ch.is_alphanumeric() || *ch == '_') {
+            tag.push(ch);
+        }
+        self.lookahead_eof |= iter.peek().is_none();
+        if tag.starts_with(|ch: char| ch.is_ascii_digit()) || iter.next() != Some('$') {
+            return self.tokenize_symbol();
+        }
+
+        let delimiter = format!("${}$", tag);
+        self.next_if_are(&delimiter);
+        let mut value = String::new();
+        let mut len = 0;
+        loop {
+            if self.next_if_are(&delimiter) {
+                return Ok(Some(Token::DollarString { tag, value }));
+            }
+            match self.next_char() {
+                Some(ch) => value.push(ch),
+                None => return self.tokenize_error("Unterminated dollar-quoted string literal"),
+            }
+            len += 1;
+            self.check_length(LengthLimit::String, len)?;
+        }
+    }
+
     fn tokenize_delimited_ident(&mut self, open_quote: char) -> Result<String, LexerError> {
         let close_quote = match open_quote {
             '"' => '"', // ANSI and most dialects
             '`' => '`', // MySQL
+            '[' => ']', // MS Access, SQL Server and SQLite
             _ => return self.tokenize_error("Unexpected quoting style"),
         };
-        let s = self.next_while(|&ch| ch != close_quote);
-        // consume the close quote.
-        if self.next_if_is(close_quote) {
-            Ok(s)
-        } else {
-            self.tokenize_error(format!(
-                "Expected close delimiter '{}' before EOF",
-                close_quote
-            ))
+        let mut s = String::new();
+        let mut len = 0;
+        loop {
+            let part =
+                self.next_while_limited(|&ch| ch != close_quote, LengthLimit::Identifier, len)?;
+            len += part.chars().count();
+            s += &part;
+            // consume the close quote.
+            if !self.next_if_is(close_quote) {
+                return self.tokenize_error(format!(
+                    "Expected close delimiter '{}' before EOF",
+                    close_quote
+                ));
+            }
+            // A doubled close quote is an escaped quote in the identifier, except for the
+            // brackets, which end at the first close bracket.
+            if open_quote != '[' && self.next_if_is(close_quote) {
+                s.push(close_quote);
+                len += 1;
+            } else {
+                return Ok(s);
+            }
         }
     }
 
-    fn tokenize_ident(&mut self, first: char) -> String {
+    fn tokenize_ident(&mut self, first: char) -> Result<String, LexerError> {
         let mut ident = first.to_string();
-        let predicate = |ch: &char| self.dialect.lexer_conf().is_identifier_part(*ch);
-        let rest = next_while(&mut self.location, &mut self.iter, predicate);
-        ident.push_str(&rest);
-        ident
+        let conf = self.dialect.lexer_conf();
+        let predicate = |ch: &char| conf.is_identifier_part(*ch);
+        ident += &self.next_while_limited(predicate, LengthLimit::Identifier, 1)?;
+        Ok(ident)
     }
 
     fn tokenize_number(&mut self) -> Result<Option<Token>, LexerError> {
         let mut s = self.next_while(|ch| ch.is_ascii_digit());
 
+        // identifier beginning with digits, like `1d`
+        if !s.is_empty() && self.is_digit_leading_identifier(&s) {
+            let first = self.next_char().unwrap();
+            s.push_str(&self.tokenize_ident(first)?);
+            return Ok(Some(Token::word::<D::Keyword, _>(s, None)));
+        }
+
         // We don't support 0xvalue syntax, which is a MySQL/MariaDB extension for hex hybrids
-        // and behaves as a string or as a number depending on context.
+        // and behaves as a string or as a number depending on context, so reject it instead of
+        // splitting it into a number and an identifier.
+        if s == "0" {
+            let mut iter = self.iter.clone();
+            let radix = match iter.next() {
+                Some('x') => Some((16, "hexadecimal")),
+                Some('b') => Some((2, "binary")),
+                _ => None,
+            };
+            if let Some((radix, name)) = radix {
+                let next = iter.next();
+                self.lookahead_eof |= next.is_none();
+                if next.is_some_and(|ch| ch.is_digit(radix)) {
+                    return self.tokenize_error(format!(
+                        "The {} number literal beginning with `0{}` is not supported",
+                        name,
+                        if radix == 16 { 'x' } else { 'b' }
+                    ));
+                }
+            }
+        }
 
         // match one period
         if self.next_if_is('.') {
@@ -201,9 +816,56 @@ impl<'a, D: Dialect> Lexer<'a, D> {
         if s == "." {
             return Ok(Some(Token::Period));
         }
+
+        // match the exponent, like `1e5` or `1.5E-2`
+        if self.is_exponent() {
+            s.push(self.next_char().unwrap());
+            if self.next_if_is('+') {
+                s.push('+');
+            } else if self.next_if_is('-') {
+                s.push('-');
+            }
+            s += &self.next_while(|ch| ch.is_ascii_digit());
+        }
         Ok(Some(Token::Number(s)))
     }
 
+    /// Returns true if the digits of a number are followed by an exponent, `e` or `E` followed
+    /// by the optionally signed digits.
+    fn is_exponent(&mut self) -> bool {
+        let mut iter = self.iter.clone();
+        if !matches!(iter.next(), Some('e') | Some('E')) {
+            return false;
+        }
+        iter.next_if(|&ch| ch == '+' || ch == '-');
+        let next = iter.next();
+        self.lookahead_eof |= next.is_none();
+        next.is_some_and(|ch| ch.is_ascii_digit())
+    }
+
+    /// Returns true if the digits that have been consumed are followed by the characters of an
+    /// identifier, and the dialect supports the identifiers beginning with digits.
+    fn is_digit_leading_identifier(&mut self, digits: &str) -> bool {
+        let conf = self.dialect.lexer_conf();
+        if !conf.supports_digit_leading_identifier() {
+            return false;
+        }
+        let mut iter = self.iter.clone();
+        let ch = match iter.next() {
+            Some(ch) if conf.is_identifier_part(ch) => ch,
+            _ => return false,
+        };
+        let next = iter.next();
+        self.lookahead_eof |= next.is_none();
+        // `1e5`, `0x1F` and `0b01` are numeric literals
+        match (ch, next) {
+            ('e' | 'E', Some(next)) => !(next.is_ascii_digit() || next == '+' || next == '-'),
+            ('x', Some(next)) if digits == "0" => !next.is_ascii_hexdigit(),
+            ('b', Some(next)) if digits == "0" => next != '0' && next != '1',
+            _ => true,
+        }
+    }
+
     fn tokenize_symbol(&mut self) -> Result<Option<Token>, LexerError> {
         let token = self.next_if_token(|ch| {
             Some(match ch {
@@ -240,23 +902,53 @@ impl<'a, D: Dialect> Lexer<'a, D> {
                 _ => Token::Char(ch),
             })
         });
+        // The PostgreSQL operators are only tokenized if the dialect supports them, otherwise
+        // they are tokenized as the separate characters, e.g. `<->` is `<`, `-` and `>`.
+        let postgres = self.dialect.parser_conf().supports_postgres_operators();
         if let Some(token) = token {
             Ok(Some(match token {
                 Token::Colon if self.next_if_is(':') => Token::DoubleColon,
                 Token::Less if self.next_if_is('>') => Token::NotEqual,
-                Token::Less if self.next_if_is('=') => Token::LessOrEqual,
+                Token::Less if self.next_if_is('=') => {
+                    if self.next_if_is('>') {
+                        Token::Spaceship
+                    } else {
+                        Token::LessOrEqual
+                    }
+                }
                 Token::Less if self.next_if_is('<') => Token::LeftShift,
+                Token::Less if postgres && self.next_if_are("->") => Token::LessMinusGreater,
                 Token::Greater if self.next_if_is('=') => Token::GreaterOrEqual,
                 Token::Greater if self.next_if_is('>') => Token::RightShift,
-                Token::Minus if self.next_if_is('-') => {
-                    Token::Comment(self.tokenize_single_line_comment("--"))
+                Token::Minus if self.is_double_dash_comment() => {
+                    self.next_char();
+                    Token::Comment(self.tokenize_single_line_comment("--")?)
                 }
                 Token::Slash if self.next_if_is('*') => {
                     Token::Comment(self.tokenize_multi_line_comment()?)
                 }
                 Token::Exclamation if self.next_if_is('=') => Token::NotEqual,
                 Token::Exclamation if self.next_if_is('!') => Token::DoubleExclamation,
-                Token::Pipe if self.next_if_is('|') => Token::Concat,
+                Token::Exclamation if postgres && self.next_if_is('~') => {
+                    if self.next_if_is('~') {
+                        Token::ExclamationDoubleTilde
+                    } else if self.next_if_is('*') {
+                        Token::ExclamationTildeAsterisk
+                    } else {
+                        Token::ExclamationTilde
+                    }
+                }
+                Token::Tilde if postgres && self.next_if_is('~') => Token::DoubleTilde,
+                Token::Tilde if postgres && self.next_if_is('*') => Token::TildeAsterisk,
+                Token::Pipe if self.next_if_is('|') => {
+                    if postgres && self.next_if_is_slash_operator() {
+                        Token::DoublePipeSlash
+                    } else {
+                        Token::Concat
+                    }
+                }
+                Token::Pipe if postgres && self.next_if_is_slash_operator() => Token::PipeSlash,
+                Token::At if postgres && self.next_if_are("-@") => Token::AtMinusAt,
                 token => token,
             }))
         } else {
@@ -264,22 +956,46 @@ impl<'a, D: Dialect> Lexer<'a, D> {
         }
     }
 
+    /// Returns true if the next characters, after a `-`, begin a `--` comment.
+    fn is_double_dash_comment(&mut self) -> bool {
+        let mut iter = self.iter.clone();
+        match iter.next() {
+            Some('-')
+                if self
+                    .dialect
+                    .lexer_conf()
+                    .requires_whitespace_after_double_dash() =>
+            {
+                self.lookahead_eof |= iter.peek().is_none();
+                iter.next()
+                    .is_none_or(|ch| ch.is_whitespace() || ch.is_control())
+            }
+            Some('-') => true,
+            _ => false,
+        }
+    }
+
     /// Tokenizes single-line comment and returns the comment.
-    fn tokenize_single_line_comment(&mut self, prefix: impl Into<String>) -> Comment {
-        let mut comment = self.next_while(|c| c != &'\n');
+    fn tokenize_single_line_comment(
+        &mut self,
+        prefix: impl Into<String>,
+    ) -> Result<Comment, LexerError> {
+        let mut comment = self.next_while_limited(|c| c != &'\n', LengthLimit::Comment, 0)?;
         if let Some(ch) = self.next_char() {
             assert_eq!(ch, '\n');
             comment.push(ch);
         }
-        Comment::SingleLine {
+        Ok(Comment::SingleLine {
             prefix: prefix.into(),
             comment,
-        }
+        })
     }
 
     /// Tokenize multi-line comment and returns the comment.
     fn tokenize_multi_line_comment(&mut self) -> Result<Comment, LexerError> {
+        let nested_comments = self.dialect.lexer_conf().supports_nested_comments();
         let mut comment = String::new();
+        let mut len = 0;
         let mut nested = 1;
         loop {
             match self.next_char() {
@@ -291,13 +1007,17 @@ impl<'a, D: Dialect> Lexer<'a, D> {
                         } else {
                             nested -= 1;
                             comment.push_str("*/");
+                            len += 2;
                         }
-                    } else if ch == '/' && self.next_if_is('*') {
+                    } else if ch == '/' && nested_comments && self.next_if_is('*') {
                         nested += 1;
                         comment.push_str("/*");
+                        len += 2;
                     } else {
                         comment.push(ch);
+                        len += 1;
                     }
+                    self.check_length(LengthLimit::Comment, len)?;
                 }
                 None => {
                     return self.tokenize_error("Unexpected EOF while in a multi-line comment");
@@ -321,6 +1041,7 @@ impl<'a, D: Dialect> Lexer<'a, D> {
     fn next_char(&mut self) -> Option<char> {
         if let Some(ch) = self.iter.next() {
             self.location.advance(ch);
+            self.offset += ch.len_utf8();
             Some(ch)
         } else {
             None
@@ -333,16 +1054,239 @@ impl<'a, D: Dialect> Lexer<'a, D> {
     fn next_if_is(&mut self, ch: char) -> bool {
         if self.iter.next_if_eq(&ch).is_some() {
             self.location.advance(ch);
+            self.offset += ch.len_utf8();
             true
         } else {
             false
         }
     }
 
+    /// Consumes the next characters and records the current location
+    /// if they match the string `s`, and returns true if they match.
+    fn next_if_are(&mut self, s: &str) -> bool {
+        let mut iter = self.iter.clone();
+        for ch in s.chars() {
+            match iter.next() {
+                Some(next) if next == ch => {}
+                next => {
+                    self.lookahead_eof |= next.is_none();
+                    return false;
+                }
+            }
+        }
+        s.chars().for_each(|ch| {
+            self.next_if_is(ch);
+        });
+        true
+    }
+
+    /// Consumes the next `/` of the `|/` and `||/` operators, unless it opens a multi-line
+    /// comment, e.g. `a||/*c*/b` is `a || b`.
+    fn next_if_is_slash_operator(&mut self) -> bool {
+        let mut iter = self.iter.clone();
+        if iter.next() != Some('/') {
+            return false;
+        }
+        self.lookahead_eof |= iter.peek().is_none();
+        iter.next() != Some('*') && self.next_if_is('/')
+    }
+
     /// Grabs the next characters that match the predicate, as a string
     fn next_while<F: Fn(&char) -> bool>(&mut self, predicate: F) -> String {
-        next_while(&mut self.location, &mut self.iter, predicate)
+        let value = next_while(&mut self.location, &mut self.iter, predicate);
+        self.offset += value.len();
+        value
+    }
+
+    /// Grabs the next characters that match the predicate like `next_while`, but returns an
+    /// error once the characters following the `len` characters of the token exceed the limit.
+    fn next_while_limited<F: Fn(&char) -> bool>(
+        &mut self,
+        predicate: F,
+        limit: LengthLimit,
+        mut len: usize,
+    ) -> Result<String, LexerError> {
+        let mut value = String::new();
+        while let Some(ch) = self.iter.next_if(&predicate) {
+            self.location.advance(ch);
+            self.offset += ch.len_utf8();
+            value.push(ch);
+            len += 1;
+            self.check_length(limit, len)?;
+        }
+        Ok(value)
+    }
+}
+
+/// A push-based SQL lexer, which is fed the input in chunks (like the packets received by a
+/// network proxy) and produces the tokens as soon as they are complete.
+///
+/// A token that may be continued by the next chunk (like an identifier or a string literal
+/// cut off at the end of the chunk) is retained until more input is fed or the lexer is
+/// finished, so that the tokens are the same as the ones of [`Lexer::tokenize`].
+pub struct PushLexer<'a, D: Dialect> {
+    dialect: &'a D,
+    limits: LexerLimits,
+    /// The text fed but not tokenized yet.
+    pending: String,
+    /// The bytes of an incomplete UTF-8 sequence at the end of the fed input.
+    partial: Vec<u8>,
+    /// The location of the pending text.
+    location: LineColumn,
+    line_start: bool,
+    token_count: usize,
+    /// The length of the pending text when it was tokenized last time.
+    scanned: usize,
+}
+
+/// The length of the retained text above which it's rescanned only after it's doubled.
+const MIN_RESCAN_LENGTH: usize = 1024;
+
+impl<'a, D: Dialect> PushLexer<'a, D> {
+    /// Creates a new push-based SQL lexer.
+    pub fn new(dialect: &'a D) -> Self {
+        Self::new_with_limits(dialect, LexerLimits::default())
+    }
+
+    /// Creates a new push-based SQL lexer with the size limits.
+    pub fn new_with_limits(dialect: &'a D, limits: LexerLimits) -> Self {
+        Self {
+            dialect,
+            limits,
+            pending: String::new(),
+            partial: vec![],
+            location: LineColumn::default(),
+            line_start: true,
+            token_count: 0,
+            scanned: 0,
+        }
+    }
+
+    /// Feeds the next chunk of the input, and returns the tokens completed by it.
+    /// A UTF-8 sequence may be split across the chunks.
+    ///
+    /// **NOTE**: an invalid token that reaches the end of the fed input (like an unterminated
+    /// string literal) is retained too, its error is returned once it can't be completed.
+    ///
+    /// The retained text is rescanned from the start of its first token, a long retained text is
+    /// rescanned only after it's doubled to keep the total scanning linear, so the tokens completed
+    /// by a chunk may be returned by a later call.
+    pub fn feed(&mut self, input: &[u8]) -> Result<Vec<Token>, LexerError> {
+        self.partial.extend_from_slice(input);
+        let valid_up_to = match str::from_utf8(&self.partial) {
+            Ok(_) => self.partial.len(),
+            // an incomplete UTF-8 sequence at the end of the input
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => return Err(self.invalid_utf8(err.valid_up_to())),
+        };
+        // the bytes up to `valid_up_to` are always valid
+        let valid = str::from_utf8(&self.partial[..valid_up_to]).unwrap_or_default();
+        self.pending.push_str(valid);
+        self.partial.drain(..valid_up_to);
+        if self.scanned > MIN_RESCAN_LENGTH && self.pending.len() < self.scanned * 2 {
+            return Ok(vec![]);
+        }
+        self.tokenize(false)
     }
+
+    /// Tokenizes the rest of the input after the last chunk is fed.
+    pub fn finish(mut self) -> Result<Vec<Token>, LexerError> {
+        if !self.partial.is_empty() {
+            return Err(self.invalid_utf8(0));
+        }
+        self.tokenize(true)
+    }
+
+    /// Tokenizes the pending text, and retains the text of the tokens that may be continued by
+    /// the next chunk unless it's the end of the input.
+    fn tokenize(&mut self, eof: bool) -> Result<Vec<Token>, LexerError> {
+        let mut lexer = Lexer::new_with_limits(self.dialect, &self.pending, self.limits);
+        lexer.location = self.location;
+        lexer.line_start = self.line_start;
+        lexer.token_count = self.token_count;
+
+        let mut tokens = vec![];
+        // the state of the lexer before the retained token
+        let retained = loop {
+            let state = (
+                lexer.offset,
+                lexer.location,
+                lexer.line_start,
+                lexer.token_count,
+            );
+            lexer.lookahead_eof = false;
+            match lexer.next_token() {
+                Ok(Some(_))
+                    if !eof && (lexer.offset == self.pending.len() || lexer.lookahead_eof) =>
+                {
+                    break state
+                }
+                Ok(Some(token)) => tokens.push(token),
+                Ok(None) => break state,
+                Err(_) if !eof && (lexer.iter.peek().is_none() || lexer.lookahead_eof) => {
+                    break state
+                }
+                Err(err) => return Err(err),
+            }
+        };
+
+        let (offset, location, line_start, token_count) = retained;
+        self.pending.drain(..offset);
+        self.scanned = self.pending.len();
+        self.location = location;
+        self.line_start = line_start;
+        self.token_count = token_count;
+        Ok(tokens)
+    }
+
+    /// Returns the error of an invalid UTF-8 sequence after the first `len` bytes of the
+    /// partial input.
+    fn invalid_utf8(&self, len: usize) -> LexerError {
+        let mut text = self.pending.clone();
+        // the bytes up to `len` are always valid
+        text.push_str(str::from_utf8(&self.partial[..len]).unwrap_or_default());
+        let delta = LineColumn::from_offset(&text, text.len());
+        let location = if delta.line() == 1 {
+            LineColumn::new(
+                self.location.line(),
+                self.location.column() + delta.column(),
+            )
+        } else {
+            LineColumn::new(self.location.line() + delta.line() - 1, delta.column())
+        };
+        location.into_error("Invalid UTF-8 sequence")
+    }
+}
+
+/// Returns the byte of an octal or hexadecimal escape, `location` is the location of the escape.
+/// Returns true if the byte starts a character in UTF-8, i.e. it's not a continuation byte.
+fn is_char_boundary(byte: u8) -> bool {
+    byte & 0xc0 != 0x80
+}
+
+fn escaped_byte(value: u32, location: LineColumn) -> Result<u8, LexerError> {
+    // the octal escapes beyond `\377` are truncated, like PostgreSQL
+    match (value & 0xff) as u8 {
+        0 => Err(location.into_error("Invalid null character in the escape string literal")),
+        byte => Ok(byte),
+    }
+}
+
+/// Checks that all the characters of the literal are digits of the radix, `location` is the
+/// location of the first character.
+fn validate_digits(
+    s: &str,
+    mut location: LineColumn,
+    radix: u32,
+    name: &str,
+) -> Result<(), LexerError> {
+    for ch in s.chars() {
+        if !ch.is_digit(radix) {
+            return Err(location.into_error(format!("Invalid {} digit '{}'", name, ch)));
+        }
+        location.advance(ch);
+    }
+    Ok(())
 }
 
 fn next_while<F: Fn(&char) -> bool>(
@@ -428,6 +1372,28 @@ mod tests {
                 }),
             ])
         );
+
+        // `--` must be followed by a whitespace or control character in MySQL
+        let dialect = crate::mysql::MysqlDialect::default();
+        tokenize!(
+            "5--7 --\t1\n--",
+            Ok(vec![
+                Token::Number("5".into()),
+                Token::Minus,
+                Token::Minus,
+                Token::Number("7".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Comment(Comment::SingleLine {
+                    prefix: "--".into(),
+                    comment: "\t1\n".into()
+                }),
+                Token::Comment(Comment::SingleLine {
+                    prefix: "--".into(),
+                    comment: "".into()
+                }),
+            ]),
+            &dialect
+        );
     }
 
     #[test]
@@ -483,6 +1449,21 @@ mod tests {
                 "/*line2*/*".into()
             ]))])
         );
+
+        // nested comments are not supported in MySQL
+        let dialect = crate::mysql::MysqlDialect::default();
+        tokenize!(
+            "/* a /* b */ c */",
+            Ok(vec![
+                Token::Comment(Comment::MultiLine(vec![" a /* b ".into()])),
+                Token::Whitespace(Whitespace::Space),
+                Token::word::<crate::mysql::MysqlKeyword, _>("c", None),
+                Token::Whitespace(Whitespace::Space),
+                Token::Asterisk,
+                Token::Slash,
+            ]),
+            &dialect
+        );
     }
 
     #[test]
@@ -496,6 +1477,20 @@ mod tests {
             ])
         );
 
+        tokenize!(
+            "1e5 1E-2 .5e+10 1e",
+            Ok(vec![
+                Token::Number("1e5".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1E-2".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number(".5e+10".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1".into()),
+                Token::word::<crate::ansi::AnsiKeyword, _>("e", None),
+            ])
+        );
+
         tokenize!(
             ".1 12345.6789 0. .",
             Ok(vec![
@@ -526,6 +1521,41 @@ mod tests {
         tokenize!("B'01010101'", Ok(vec![Token::BitString("01010101".into())]));
         tokenize!("b'01010101'", Ok(vec![Token::BitString("01010101".into())]));
 
+        // invalid hex and bit string literal
+        tokenize!(
+            "X'abzz'",
+            Err(LineColumn::new(1, 4).into_error("Invalid hexadecimal digit 'z'"))
+        );
+        tokenize!(
+            "X'abc'",
+            Err(LineColumn::new(1, 6).into_error(
+                "The hex string literal must contain an even number of hexadecimal digits"
+            ))
+        );
+        tokenize!(
+            "B'0120'",
+            Err(LineColumn::new(1, 4).into_error("Invalid binary digit '2'"))
+        );
+        let dialect = crate::postgres::PostgresDialect::default();
+        tokenize!("X'abc'", Ok(vec![Token::HexString("abc".into())]), &dialect);
+
+        // double quoted string literal (MySQL without ANSI_QUOTES)
+        let dialect = crate::mysql::MysqlDialect::new(
+            crate::mysql::MySqlLexerConfig {
+                ansi_quotes_mode: false,
+            },
+            Default::default(),
+        );
+        tokenize!(
+            "'foo',\"bar\"",
+            Ok(vec![
+                Token::String("foo".into()),
+                Token::Comma,
+                Token::String("bar".into()),
+            ]),
+            &dialect
+        );
+
         // newline in string literal
         tokenize!(
             "'foo\r\nbar\nbaz'",
@@ -562,6 +1592,22 @@ mod tests {
             Ok(vec![Token::word::<AnsiKeyword, _>("foo", Some('\"'))])
         );
 
+        // escaped quotes
+        tokenize!(
+            "\"foo\"\"bar\"",
+            Ok(vec![Token::word::<AnsiKeyword, _>("foo\"bar", Some('\"'))])
+        );
+
+        // bracket quotes (SQLite), which can't be escaped
+        tokenize!(
+            "[foo\"bar]]",
+            Ok(vec![
+                Token::word::<crate::sqlite::SqliteKeyword, _>("foo\"bar", Some('[')),
+                Token::RightBracket,
+            ]),
+            &crate::sqlite::SqliteDialect::default()
+        );
+
         // mismatch quotes
         tokenize!(
             "\"foo",
@@ -569,6 +1615,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_digit_leading_ident() {
+        use crate::mysql::{MysqlDialect, MysqlKeyword};
+
+        let dialect = MysqlDialect::default();
+        tokenize!(
+            "1d 123abc `123` 1e5 1.5E-2 1e 0x 0xg",
+            Ok(vec![
+                Token::word::<MysqlKeyword, _>("1d", None),
+                Token::Whitespace(Whitespace::Space),
+                Token::word::<MysqlKeyword, _>("123abc", None),
+                Token::Whitespace(Whitespace::Space),
+                Token::word::<MysqlKeyword, _>("123", Some('`')),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1e5".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1.5E-2".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::word::<MysqlKeyword, _>("1e", None),
+                Token::Whitespace(Whitespace::Space),
+                Token::word::<MysqlKeyword, _>("0x", None),
+                Token::Whitespace(Whitespace::Space),
+                Token::word::<MysqlKeyword, _>("0xg", None),
+            ]),
+            &dialect
+        );
+        tokenize!(
+            "SELECT 0x1F",
+            Err(LineColumn::new(1, 8)
+                .into_error("The hexadecimal number literal beginning with `0x` is not supported")),
+            &dialect
+        );
+        tokenize!(
+            "0b01",
+            Err(LineColumn::new(1, 1)
+                .into_error("The binary number literal beginning with `0b` is not supported")),
+            &dialect
+        );
+
+        // the identifiers can't begin with digits unless supported by the dialect
+        tokenize!(
+            "1d \"123\"",
+            Ok(vec![
+                Token::Number("1".into()),
+                Token::word::<crate::ansi::AnsiKeyword, _>("d", None),
+                Token::Whitespace(Whitespace::Space),
+                Token::word::<crate::ansi::AnsiKeyword, _>("123", Some('"')),
+            ])
+        );
+    }
+
     #[test]
     fn tokenize_string_concat() {
         use crate::ansi::AnsiKeyword;
@@ -586,6 +1683,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_adjacent_string_literals() {
+        // concatenated only if separated by a newline
+        tokenize!(
+            "'a'\n  'b'\r\n'c' 'd'",
+            Ok(vec![
+                Token::String("abc".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::String("d".into()),
+            ])
+        );
+
+        let dialect = crate::mysql::MysqlDialect::default();
+        tokenize!(
+            "'a' 'b'\t'c''d'",
+            Ok(vec![Token::String("abc".into()), Token::String("d".into())]),
+            &dialect
+        );
+
+        let dialect = crate::sqlite::SqliteDialect::default();
+        tokenize!(
+            "'a'\n'b'",
+            Ok(vec![
+                Token::String("a".into()),
+                Token::Whitespace(Whitespace::Newline),
+                Token::String("b".into()),
+            ]),
+            &dialect
+        );
+    }
+
     #[test]
     fn tokenize_bitwise_op() {
         use crate::ansi::AnsiKeyword;
@@ -607,6 +1735,77 @@ mod tests {
         )
     }
 
+    #[test]
+    fn tokenize_operators() {
+        let postgres = crate::postgres::PostgresDialect::default();
+        tokenize!(
+            "<=><-><-1!~~!~=~~||/|/@-@@-1",
+            Ok(vec![
+                Token::Spaceship,
+                Token::LessMinusGreater,
+                Token::Less,
+                Token::Minus,
+                Token::Number("1".into()),
+                Token::ExclamationDoubleTilde,
+                Token::ExclamationTilde,
+                Token::Equal,
+                Token::DoubleTilde,
+                Token::DoublePipeSlash,
+                Token::PipeSlash,
+                Token::AtMinusAt,
+                Token::At,
+                Token::Minus,
+                Token::Number("1".into()),
+            ]),
+            &postgres
+        );
+
+        tokenize!(
+            "~*!~*~ *",
+            Ok(vec![
+                Token::TildeAsterisk,
+                Token::ExclamationTildeAsterisk,
+                Token::Tilde,
+                Token::Whitespace(Whitespace::Space),
+                Token::Asterisk,
+            ]),
+            &postgres
+        );
+
+        // the `/` opening a comment is not a part of the operators
+        tokenize!(
+            "||/*c*/|/**/",
+            Ok(vec![
+                Token::Concat,
+                Token::Comment(Comment::MultiLine(vec!["c".into()])),
+                Token::Pipe,
+                Token::Comment(Comment::MultiLine(vec!["".into()])),
+            ]),
+            &postgres
+        );
+
+        // the PostgreSQL operators are separate characters in other dialects
+        tokenize!(
+            "<->~~!~|/@-@~*",
+            Ok(vec![
+                Token::Less,
+                Token::Minus,
+                Token::Greater,
+                Token::Tilde,
+                Token::Tilde,
+                Token::Exclamation,
+                Token::Tilde,
+                Token::Pipe,
+                Token::Slash,
+                Token::At,
+                Token::Minus,
+                Token::At,
+                Token::Tilde,
+                Token::Asterisk,
+            ])
+        );
+    }
+
     #[test]
     fn tokenize_mysql_logical_xor() {
         use crate::mysql::{MysqlDialect, MysqlKeyword};
@@ -716,4 +1915,437 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn tokenize_with_spans() {
+        use crate::ansi::AnsiKeyword;
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        let got = Lexer::new(&dialect, "SELECT 'é'\r\n;").tokenize_with_spans();
+        assert_eq!(
+            got,
+            Ok(vec![
+                (
+                    Token::keyword::<AnsiKeyword, _>("SELECT").unwrap(),
+                    Span::new(0, 6)
+                ),
+                (Token::Whitespace(Whitespace::Space), Span::new(6, 7)),
+                (Token::String("é".into()), Span::new(7, 11)),
+                (Token::Whitespace(Whitespace::Newline), Span::new(11, 13)),
+                (Token::SemiColon, Span::new(13, 14)),
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_bytes_lossy() {
+        use crate::postgres::{PostgresDialect, PostgresKeyword};
+
+        let dialect = PostgresDialect::default();
+        let input = b"\xEF\xBB\xBFSELECT 'caf\xE9',\n\"a\xFF\xFEb\"";
+        let mut warnings = vec![];
+        let tokens = Lexer::tokenize_bytes_lossy(&dialect, input, &mut warnings).unwrap();
+        let tokens = tokens
+            .into_iter()
+            .map(|(token, span)| (token, &input[span.start..span.end]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                (
+                    Token::keyword::<PostgresKeyword, _>("SELECT").unwrap(),
+                    &b"SELECT"[..]
+                ),
+                (Token::Whitespace(Whitespace::Space), b" "),
+                (Token::String("caf\u{fffd}".into()), b"'caf\xE9'"),
+                (Token::Comma, b","),
+                (Token::Whitespace(Whitespace::Newline), b"\n"),
+                (
+                    Token::word::<PostgresKeyword, _>("a\u{fffd}\u{fffd}b", Some('"')),
+                    b"\"a\xFF\xFEb\""
+                ),
+            ]
+        );
+        assert_eq!(
+            warnings,
+            vec![
+                LineColumn::new(1, 11).into_error("Invalid UTF-8 sequence"),
+                LineColumn::new(2, 2).into_error("Invalid UTF-8 sequence"),
+                LineColumn::new(2, 3).into_error("Invalid UTF-8 sequence"),
+            ]
+        );
+
+        // an incomplete sequence at the end of the input
+        let mut warnings = vec![];
+        let tokens = Lexer::tokenize_bytes_lossy(&dialect, b"1 \xE4\xBD", &mut warnings);
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                (Token::Number("1".into()), Span::new(0, 1)),
+                (Token::Whitespace(Whitespace::Space), Span::new(1, 2)),
+                (Token::Char('\u{fffd}'), Span::new(2, 4)),
+            ])
+        );
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn tokenize_trees() {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let got = Lexer::new(&dialect, "(a[1]){}").tokenize_trees();
+        assert_eq!(
+            got,
+            Ok(vec![
+                TokenTree::Group(Group {
+                    delimiter: Delimiter::Parenthesis,
+                    trees: vec![
+                        TokenTree::Token(
+                            Token::word::<crate::ansi::AnsiKeyword, _>("a", None),
+                            Span::new(1, 2)
+                        ),
+                        TokenTree::Group(Group {
+                            delimiter: Delimiter::Bracket,
+                            trees: vec![TokenTree::Token(
+                                Token::Number("1".into()),
+                                Span::new(3, 4)
+                            )],
+                            span: Span::new(2, 5),
+                        }),
+                    ],
+                    span: Span::new(0, 6),
+                }),
+                TokenTree::Group(Group {
+                    delimiter: Delimiter::Brace,
+                    trees: vec![],
+                    span: Span::new(6, 8),
+                }),
+            ])
+        );
+
+        let got = Lexer::new(&dialect, "SELECT (1]").tokenize_trees();
+        assert_eq!(
+            got,
+            Err(LineColumn::new(1, 9).into_error("Unmatched closing `]`"))
+        );
+        let got = Lexer::new(&dialect, "SELECT\n ((1)").tokenize_trees();
+        assert_eq!(
+            got,
+            Err(LineColumn::new(2, 1).into_error("Unclosed delimiter `(`"))
+        );
+        // the location agrees with the one of the byte offset in the source
+        assert_eq!(
+            LineColumn::from_offset("SELECT\n ((1)", 8),
+            LineColumn::new(2, 1)
+        );
+    }
+
+    #[test]
+    fn tokenize_with_limits() {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let limits = LexerLimits {
+            max_identifier_length: Some(3),
+            max_string_length: Some(4),
+            max_comment_length: Some(6),
+            max_tokens: Some(5),
+        };
+        let tokenize = |input| Lexer::new_with_limits(&dialect, input, limits).tokenize();
+        assert!(tokenize("foo 'abcd'").is_ok());
+        assert_eq!(
+            tokenize("fooo").unwrap_err().message,
+            "Identifier exceeds the maximum length of 3 characters"
+        );
+        assert_eq!(
+            tokenize("\"quo\" N'abcde'").unwrap_err().message,
+            "String literal exceeds the maximum length of 4 characters"
+        );
+        // checked while scanning, before the missing close quote is found
+        assert_eq!(
+            tokenize("'abcde"),
+            Err(LineColumn::new(1, 6)
+                .into_error("String literal exceeds the maximum length of 4 characters"))
+        );
+        assert_eq!(
+            tokenize("'ab'\n'cde'").unwrap_err().message,
+            "String literal exceeds the maximum length of 4 characters"
+        );
+        assert!(tokenize("--abcd\n/*abcdef*/").is_ok());
+        assert_eq!(
+            tokenize("--abcdefg").unwrap_err().message,
+            "Comment exceeds the maximum length of 6 characters"
+        );
+        assert_eq!(
+            tokenize("/*abcdefg").unwrap_err().message,
+            "Comment exceeds the maximum length of 6 characters"
+        );
+        assert_eq!(tokenize("a,b,c").map(|tokens| tokens.len()), Ok(5));
+        assert_eq!(
+            tokenize("a, b, c").unwrap_err().message,
+            "Exceeded the maximum of 5 tokens"
+        );
+    }
+
+    #[test]
+    fn tokenize_with_identifier_validation() {
+        use crate::{mysql::MysqlDialect, postgres::PostgresDialect};
+
+        let long = "a".repeat(65);
+        let dialect = MysqlDialect::default();
+        let tokenize = |input: &str| Lexer::new(&dialect, input).tokenize_with_warnings();
+        assert_eq!(
+            tokenize(&long[..64]).map(|(_, warnings)| warnings),
+            Ok(vec![])
+        );
+        // a long alias is valid, the length of it is limited to 256 characters
+        let (_, warnings) = tokenize(&format!("SELECT 1 AS {}", long)).unwrap();
+        assert_eq!(
+            warnings,
+            vec![LineColumn::new(1, 12).into_error(
+                "Identifier exceeds the maximum length of 64 characters, which is only permitted \
+                 for aliases"
+            )]
+        );
+        assert_eq!(
+            tokenize(&format!("SELECT {}", "a".repeat(257))),
+            Err(LineColumn::new(1, 7)
+                .into_error("Identifier exceeds the maximum length of 256 characters"))
+        );
+        assert_eq!(
+            tokenize("SELECT\n  `a\u{1f600}`"),
+            Err(LineColumn::new(2, 2)
+                .into_error("Identifier contains a character that is not permitted"))
+        );
+        let (_, warnings) = tokenize("SELECT `a ` FROM t").unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].location, LineColumn::new(1, 7));
+
+        let dialect = PostgresDialect::default();
+        let (tokens, warnings) = Lexer::new(&dialect, &format!("SELECT {}", long))
+            .tokenize_with_warnings()
+            .unwrap();
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(
+            warnings,
+            vec![LineColumn::new(1, 7).into_error(
+                "Identifier exceeds the maximum length of 63 bytes and will be truncated"
+            )]
+        );
+        assert!(Lexer::new(&dialect, &long[..63]).tokenize().is_ok());
+    }
+
+    #[test]
+    fn tokenize_meta_command() {
+        use crate::postgres::{PostgresDialect, PostgresKeyword, PostgresLexerConfig};
+
+        let dialect =
+            PostgresDialect::new(PostgresLexerConfig { psql_mode: true }, Default::default());
+        tokenize!(
+            "\\connect db\n  \\i file.sql\nSELECT \\",
+            Ok(vec![
+                Token::MetaCommand("connect db".into()),
+                Token::Whitespace(Whitespace::Newline),
+                Token::Whitespace(Whitespace::Space),
+                Token::Whitespace(Whitespace::Space),
+                Token::MetaCommand("i file.sql".into()),
+                Token::Whitespace(Whitespace::Newline),
+                Token::keyword::<PostgresKeyword, _>("SELECT").unwrap(),
+                Token::Whitespace(Whitespace::Space),
+                Token::Backslash,
+            ]),
+            &dialect
+        );
+
+        let dialect = PostgresDialect::default();
+        tokenize!(
+            "\\x",
+            Ok(vec![
+                Token::Backslash,
+                Token::word::<PostgresKeyword, _>("x", None),
+            ]),
+            &dialect
+        );
+    }
+
+    #[test]
+    fn tokenize_escape_string() {
+        use crate::postgres::{PostgresDialect, PostgresKeyword};
+
+        let dialect = PostgresDialect::default();
+        let escape = |raw: &str, value: &str| Token::EscapeString {
+            raw: raw.into(),
+            value: value.into(),
+        };
+        tokenize!(
+            r"E'it\'s\n' e'a''b\\'",
+            Ok(vec![
+                escape(r"it\'s\n", "it's\n"),
+                Token::Whitespace(Whitespace::Space),
+                escape(r"a''b\\", "a'b\\"),
+            ]),
+            &dialect
+        );
+        tokenize!(
+            r"E'\x41\101é\U0001F600\uD83D\uDE00\x\q'",
+            Ok(vec![escape(
+                r"\x41\101é\U0001F600\uD83D\uDE00\x\q",
+                "AAé😀😀xq"
+            )]),
+            &dialect
+        );
+        tokenize!(
+            "Eq",
+            Ok(vec![Token::word::<PostgresKeyword, _>("Eq", None)]),
+            &dialect
+        );
+
+        // invalid escapes
+        let cases = [
+            (
+                r"E'\xe9'",
+                1,
+                2,
+                "Invalid byte sequence in the escape string literal",
+            ),
+            (
+                r"E'a\0'",
+                1,
+                3,
+                "Invalid null character in the escape string literal",
+            ),
+            (
+                r"E'\u00g'",
+                1,
+                2,
+                "Invalid Unicode escape, 4 hexadecimal digits are required",
+            ),
+            (r"E'\uD83Da'", 1, 2, "Invalid Unicode surrogate pair"),
+            (r"E'\U00110000'", 1, 2, "Invalid Unicode escape value"),
+            (r"E'\'", 1, 4, "Unterminated string literal"),
+        ];
+        for (sql, line, column, message) in cases {
+            tokenize!(
+                sql,
+                Err(LineColumn::new(line, column).into_error(message)),
+                &dialect
+            );
+        }
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        tokenize!(
+            "E'a'",
+            Ok(vec![
+                Token::word::<crate::ansi::AnsiKeyword, _>("E", None),
+                Token::String("a".into()),
+            ]),
+            &dialect
+        );
+    }
+
+    #[test]
+    fn tokenize_dollar_quoted_string() {
+        use crate::postgres::PostgresDialect;
+
+        let dialect = PostgresDialect::default();
+        let dollar = |tag: &str, value: &str| Token::DollarString {
+            tag: tag.into(),
+            value: value.into(),
+        };
+        tokenize!("$$it's$$", Ok(vec![dollar("", "it's")]), &dialect);
+        tokenize!(
+            "$fn$ $$ $f$ $fn$$a$ $a$",
+            Ok(vec![dollar("fn", " $$ $f$ "), dollar("a", " "),]),
+            &dialect
+        );
+        // positional parameter
+        tokenize!(
+            "$1",
+            Ok(vec![Token::Char('$'), Token::Number("1".into())]),
+            &dialect
+        );
+        tokenize!(
+            "$tag$ abc",
+            Err(LexerError {
+                message: "Unterminated dollar-quoted string literal".into(),
+                location: LineColumn { line: 1, column: 9 }
+            }),
+            &dialect
+        );
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        tokenize!("$$", Ok(vec![Token::Char('$'), Token::Char('$')]), &dialect);
+    }
+
+    #[test]
+    fn push_lexer() {
+        fn push_tokenize<D: Dialect>(
+            dialect: &D,
+            chunks: &[&[u8]],
+        ) -> Result<Vec<Token>, LexerError> {
+            let mut lexer = PushLexer::new(dialect);
+            let mut tokens = vec![];
+            for chunk in chunks {
+                tokens.append(&mut lexer.feed(chunk)?);
+            }
+            tokens.append(&mut lexer.finish()?);
+            Ok(tokens)
+        }
+
+        fn check<D: Dialect>(dialect: &D, input: &str) {
+            let expected = Lexer::new(dialect, input).tokenize();
+            let bytes = input.as_bytes();
+            for i in 0..=bytes.len() {
+                let (a, b) = bytes.split_at(i);
+                assert_eq!(push_tokenize(dialect, &[a, b]), expected, "{:?}", (a, b));
+            }
+            let chunks = bytes.chunks(1).collect::<Vec<_>>();
+            assert_eq!(push_tokenize(dialect, &chunks), expected);
+        }
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        check(
+            &dialect,
+            "SELECT foo, 'a'\n  'b' <= 1.5e3 FROM \"t\" -- é\r\n/* ü */",
+        );
+        check(&dialect, "SELECT 'a' 'b'");
+        check(&dialect, "SELECT 'unterminated");
+        check(&dialect, "SELECT 0x'zz'");
+        let dialect = crate::mysql::MysqlDialect::default();
+        check(&dialect, "SELECT 1 --2, 3 -- c\n, `é`");
+        check(&dialect, "SELECT 1d, 1e5, 0b01, 1e, 0x");
+        let dialect = crate::postgres::PostgresDialect::default();
+        check(&dialect, "\\d t\nSELECT $tag$a$ta$tag$, $1, E'\\n'\n\\x");
+
+        // an error is returned as soon as the token can't be completed
+        let mut lexer = PushLexer::new(&dialect);
+        assert_eq!(
+            lexer.feed(b"SELECT 'a"),
+            Ok(vec![
+                Token::word::<crate::postgres::PostgresKeyword, _>("SELECT", None),
+                Token::Whitespace(Whitespace::Space),
+            ])
+        );
+        assert_eq!(lexer.feed(b"b"), Ok(vec![]));
+        assert_eq!(
+            lexer.finish().unwrap_err().message,
+            "Unterminated string literal"
+        );
+
+        // a long token fed byte by byte isn't rescanned on every chunk
+        let input = format!("SELECT '{}', 1", "a".repeat(200_000));
+        let chunks = input.as_bytes().chunks(1).collect::<Vec<_>>();
+        assert_eq!(
+            push_tokenize(&dialect, &chunks),
+            Lexer::new(&dialect, &input).tokenize()
+        );
+
+        // a UTF-8 sequence may be split across the chunks
+        assert_eq!(
+            push_tokenize(&dialect, &[b"'\xC3", b"\xA9'"]),
+            Ok(vec![Token::String("é".into())])
+        );
+        let err = push_tokenize(&dialect, &[b"a\nb\xFF"]).unwrap_err();
+        assert_eq!(err.message, "Invalid UTF-8 sequence");
+        assert_eq!(err.location, LineColumn::new(2, 1));
+        let err = push_tokenize(&dialect, &[b"ab\xC3"]).unwrap_err();
+        assert_eq!(err.location, LineColumn::new(1, 2));
+    }
 }
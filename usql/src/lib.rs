@@ -9,6 +9,10 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+// the code generated by `derive(Arbitrary)` refers to `std`
+#[cfg(all(feature = "arbitrary", not(feature = "std")))]
+compile_error!("the `arbitrary` feature requires the `std` feature");
+
 #[macro_use]
 mod macros;
 mod dialect;
@@ -16,7 +20,15 @@ mod error;
 mod keywords;
 mod tokens;
 
+/// Analysis and rewriting utilities of the SQL AST.
+pub mod analysis;
 /// Universal SQL AST types.
+///
+/// **NOTE**: The AST structs are `#[non_exhaustive]` and their fields are private, so new fields
+/// can be added without breaking the downstream code. The structs are created with the `new`
+/// constructors and the `with_*` builder methods instead of struct expressions,
+/// e.g. `QuerySpec::new(projection).with_where(selection)`, and the fields are read with the
+/// accessor methods of the same names, e.g. `query.order_by()`.
 pub mod ast;
 /// Universal SQL lexer.
 mod lexer;
@@ -26,6 +38,9 @@ mod parser;
 /// ANSI SQL-2016.
 #[cfg(feature = "ansi")]
 pub mod ansi;
+/// MariaDB 10.6.
+#[cfg(feature = "mariadb")]
+pub mod mariadb;
 /// MySQL 8.0.
 #[cfg(feature = "mysql")]
 pub mod mysql;
@@ -36,11 +51,24 @@ pub mod postgres;
 #[cfg(feature = "sqlite")]
 pub mod sqlite;
 
+#[doc(hidden)]
+pub use self::keywords::merge_keywords;
+#[cfg(feature = "std")]
+pub use self::parser::StmtStream;
 pub use self::{
-    dialect::{CustomDialect, Dialect, DialectLexerConf, DialectParserConf},
-    error::{LexerError, LineColumn, ParserError},
+    analysis::{lint, validate, Catalog, Diagnostic, Severity},
+    dialect::{
+        Capabilities, Capability, CaseFolding, CustomDialect, Dialect, DialectLexerConf,
+        DialectParserConf, IdentifierValidity, Precedence, StringConcatenation,
+    },
+    error::{
+        ColumnUnit, ExpectedKeywords, LexerError, LineColumn, LineColumnConvention, ParserError,
+        Span,
+    },
     keywords::{Keyword, KeywordDef},
-    lexer::Lexer,
-    parser::Parser,
-    tokens::{Comment, Token, Whitespace, Word},
+    lexer::{Lexer, LexerLimits, PushLexer},
+    parser::{BatchSeparators, Checkpoint, ParsedScript, Parser},
+    tokens::{
+        Comment, Delimiter, Group, Token, TokenKind, TokenStream, TokenTree, Whitespace, Word,
+    },
 };
@@ -27,6 +27,7 @@ macro_rules! define_all_keywords {
         #[allow(non_camel_case_types)]
         #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
         #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[non_exhaustive]
         pub enum Keyword {
             $($keyword),*
         }
@@ -77,6 +78,54 @@ macro_rules! define_keyword {
         }
     };
 
+    (
+        $(#[$doc:meta])*
+        $name:ident: $base:path => {
+            $(
+                $keyword:ident $(= $string_keyword:expr)?
+            ),*
+        }
+    ) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub struct $name;
+
+        mod __private {
+            use super::$name;
+            use $crate::KeywordDef;
+
+            $( $crate::kw_def!($keyword $(= $string_keyword)?); )*
+
+            const EXTRA_KEYWORDS: &[$crate::Keyword] = &[$($crate::Keyword::$keyword),*];
+            const EXTRA_KEYWORDS_STRING: &[&str] = &[$($keyword),*];
+
+            const LEN: usize = <$base>::KEYWORDS.len() + EXTRA_KEYWORDS.len();
+            const MERGED: ([$crate::Keyword; LEN], [&str; LEN]) = $crate::merge_keywords(
+                <$base>::KEYWORDS,
+                <$base>::KEYWORDS_STRING,
+                EXTRA_KEYWORDS,
+                EXTRA_KEYWORDS_STRING,
+            );
+
+            const RESERVED_LEN: usize = <$base>::RESERVED_KEYWORDS.len() + EXTRA_KEYWORDS.len();
+            const RESERVED: ([$crate::Keyword; RESERVED_LEN], [&str; RESERVED_LEN]) =
+                $crate::merge_keywords(
+                    <$base>::RESERVED_KEYWORDS,
+                    &[""; <$base>::RESERVED_KEYWORDS.len()],
+                    EXTRA_KEYWORDS,
+                    EXTRA_KEYWORDS_STRING,
+                );
+
+            impl KeywordDef for $name {
+                const KEYWORDS: &'static [$crate::Keyword] = &MERGED.0;
+
+                const KEYWORDS_STRING: &'static [&'static str] = &MERGED.1;
+
+                const RESERVED_KEYWORDS: &'static [$crate::Keyword] = &RESERVED.0;
+            }
+        }
+    };
+
     (
         $(#[$doc:meta])*
         $name:ident => {
@@ -92,10 +141,6 @@ macro_rules! define_keyword {
         #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
         pub struct $name;
 
-        const _: () = {
-            struct $reserved;
-        };
-
         mod __private {
             use super::$name;
 
@@ -0,0 +1,20 @@
+define_keyword! {
+    /// MariaDB keywords, which extend the [`MysqlKeyword`](crate::mysql::MysqlKeyword)s.
+    ///
+    /// See [MariaDB] documentation for details.
+    ///
+    /// [MariaDB]: https://mariadb.com/kb/en/reserved-words/
+    MariadbKeyword: crate::mysql::MysqlKeyword => {
+        CYCLE,
+        INCREMENT,
+        MINVALUE,
+        NOCACHE,
+        NOCYCLE,
+        NOMAXVALUE,
+        NOMINVALUE,
+        PERIOD,
+        SEQUENCE,
+        SYSTEM_TIME,
+        VERSIONING
+    }
+}
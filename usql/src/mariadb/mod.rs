@@ -0,0 +1,90 @@
+mod keyword;
+
+pub use self::keyword::MariadbKeyword;
+use crate::{
+    dialect::{CustomDialect, DialectParserConf, Precedence},
+    mysql::{MySqlLexerConfig, MysqlParserConfig},
+};
+
+/// The MariaDB dialect.
+pub type MariadbDialect = CustomDialect<MariadbKeyword, MariadbLexerConfig, MariadbParserConfig>;
+
+/// The lexer configuration of MariaDB dialect, which is the same as MySQL.
+pub type MariadbLexerConfig = MySqlLexerConfig;
+
+/// The parser configuration of MariaDB dialect, which extends the MySQL one.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MariadbParserConfig {
+    mysql: MysqlParserConfig,
+}
+
+impl DialectParserConf for MariadbParserConfig {
+    // See https://mariadb.com/kb/en/operator-precedence/
+    fn prec_value(&self, prec: Precedence) -> u8 {
+        self.mysql.prec_value(prec)
+    }
+
+    fn pipes_as_concat(&self) -> bool {
+        self.mysql.pipes_as_concat()
+    }
+
+    fn supports_mysql_operators(&self) -> bool {
+        self.mysql.supports_mysql_operators()
+    }
+
+    fn supports_charset_introducer(&self) -> bool {
+        self.mysql.supports_charset_introducer()
+    }
+
+    fn supports_select_without_from(&self) -> bool {
+        self.mysql.supports_select_without_from()
+    }
+
+    fn supports_index_hints(&self) -> bool {
+        self.mysql.supports_index_hints()
+    }
+
+    fn supports_insert_set(&self) -> bool {
+        self.mysql.supports_insert_set()
+    }
+
+    fn supports_empty_insert_values(&self) -> bool {
+        self.mysql.supports_empty_insert_values()
+    }
+
+    fn supports_partition_definitions(&self) -> bool {
+        self.mysql.supports_partition_definitions()
+    }
+
+    fn supports_group_by_select_list(&self) -> bool {
+        self.mysql.supports_group_by_select_list()
+    }
+
+    fn supports_into_outfile(&self) -> bool {
+        self.mysql.supports_into_outfile()
+    }
+
+    fn supports_mysql_database_options(&self) -> bool {
+        self.mysql.supports_mysql_database_options()
+    }
+
+    fn supports_rename_table(&self) -> bool {
+        self.mysql.supports_rename_table()
+    }
+
+    // See https://mariadb.com/kb/en/insertreturning/ and https://mariadb.com/kb/en/delete/
+    fn supports_returning_clause(&self) -> bool {
+        true
+    }
+
+    // See https://mariadb.com/kb/en/system-versioned-tables/
+    fn supports_system_versioning(&self) -> bool {
+        true
+    }
+
+    // See https://mariadb.com/kb/en/next-value-for-sequence_name/
+    fn supports_next_value_for(&self) -> bool {
+        true
+    }
+}
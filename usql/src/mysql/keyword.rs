@@ -56,6 +56,7 @@ define_keyword! {
         CASCADE,
         CASCADED,
         CASE,
+        CAST,
         CATALOG_NAME,
         CHAIN,
         CHALLENGE_RESPONSE,
@@ -188,6 +189,7 @@ define_keyword! {
         EXPORT,
         EXTENDED,
         EXTENT_SIZE,
+        EXTRACT,
         FACTOR,
         FAILED_LOGIN_ATTEMPTS,
         FALSE,
@@ -461,6 +463,7 @@ define_keyword! {
         POINT,
         POLYGON,
         PORT,
+        POSITION,
         PRECEDES,
         PRECEDING,
         PRECISION,
@@ -661,6 +664,7 @@ define_keyword! {
         SUBJECT,
         SUBPARTITION,
         SUBPARTITIONS,
+        SUBSTRING,
         SUPER,
         SUSPEND,
         SWAPS,
@@ -692,6 +696,7 @@ define_keyword! {
         TRANSACTION,
         TRIGGER,
         TRIGGERS,
+        TRIM,
         TRUE,
         TRUNCATE,
         TYPE,
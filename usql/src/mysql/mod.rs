@@ -1,7 +1,10 @@
 mod keyword;
 
 pub use self::keyword::MysqlKeyword;
-use crate::dialect::{CustomDialect, DialectLexerConf, DialectParserConf};
+use crate::dialect::{
+    CaseFolding, CustomDialect, DialectLexerConf, DialectParserConf, IdentifierValidity,
+    Precedence, StringConcatenation,
+};
 
 /// The MySQL dialect.
 pub type MysqlDialect = CustomDialect<MysqlKeyword, MySqlLexerConfig, MysqlParserConfig>;
@@ -29,11 +32,7 @@ impl Default for MySqlLexerConfig {
 
 impl DialectLexerConf for MySqlLexerConfig {
     fn is_string_literal_quotation(&self, ch: char) -> bool {
-        if self.ansi_quotes_mode {
-            ch == '\''
-        } else {
-            ch == '"'
-        }
+        ch == '\'' || (!self.ansi_quotes_mode && ch == '"')
     }
 
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
@@ -46,8 +45,8 @@ impl DialectLexerConf for MySqlLexerConfig {
 
     // See https://dev.mysql.com/doc/refman/8.0/en/identifiers.html
     fn is_identifier_start(&self, ch: char) -> bool {
-        // Identifiers may begin with a digit but unless quoted may not consist solely of digits,
-        // but we don't support that, as that makes it hard to distinguish numeric literals.
+        // The identifiers beginning with digits are tokenized with the numeric literals,
+        // see `supports_digit_leading_identifier`.
         ch.is_ascii_alphabetic()
             || ch == '_'
             || ch == '$'
@@ -60,11 +59,167 @@ impl DialectLexerConf for MySqlLexerConfig {
             || ch == '$'
             || ('\u{0080}'..='\u{ffff}').contains(&ch)
     }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/identifiers.html
+    //
+    // Identifiers may begin with a digit but unless quoted may not consist solely of digits.
+    fn supports_digit_leading_identifier(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/comments.html
+    //
+    // The `--` comment style requires the second dash to be followed by at least one whitespace
+    // or control character, so that `5--7` is `5 - (-7)`.
+    fn requires_whitespace_after_double_dash(&self) -> bool {
+        true
+    }
+
+    // Nested comments are not supported.
+    fn supports_nested_comments(&self) -> bool {
+        false
+    }
+
+    // Column, index and alias names are case insensitive, the case sensitivity of database and
+    // table names depends on the file system, so the case of unquoted identifiers is preserved.
+    fn identifier_case_folding(&self) -> CaseFolding {
+        CaseFolding::None
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/string-literals.html
+    //
+    // Quoted strings placed next to each other are concatenated to a single string.
+    fn string_literal_concatenation(&self) -> StringConcatenation {
+        StringConcatenation::Always
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/identifiers.html
+    //
+    // Permitted characters in quoted identifiers include the full Unicode Basic Multilingual
+    // Plane (BMP), except U+0000. The maximum length of most identifiers is 64 characters, but
+    // aliases can be up to 256 characters, so the longer identifiers only get a warning.
+    // Database, table, and column names cannot end with space characters.
+    fn validate_identifier(&self, value: &str, quoted: bool) -> IdentifierValidity {
+        if quoted && value.chars().any(|ch| ch == '\0' || ch > '\u{ffff}') {
+            IdentifierValidity::Invalid(
+                "Identifier contains a character that is not permitted".into(),
+            )
+        } else if value.chars().count() > 256 {
+            IdentifierValidity::Invalid(
+                "Identifier exceeds the maximum length of 256 characters".into(),
+            )
+        } else if value.chars().count() > 64 {
+            IdentifierValidity::Warning(
+                "Identifier exceeds the maximum length of 64 characters, which is only permitted \
+                 for aliases"
+                    .into(),
+            )
+        } else if value.ends_with(' ') {
+            IdentifierValidity::Warning(
+                "Identifier ends with space characters, which is not permitted for database, \
+                 table and column names"
+                    .into(),
+            )
+        } else {
+            IdentifierValidity::Valid
+        }
+    }
 }
 
 /// The parser configuration of MySQL dialect.
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct MysqlParserConfig {}
+pub struct MysqlParserConfig {
+    /// If the PIPES_AS_CONCAT SQL mode is enabled, `||` is the string concatenation operator
+    /// rather than a synonym for `OR`.
+    pub pipes_as_concat: bool,
+}
+
+impl DialectParserConf for MysqlParserConfig {
+    // See https://dev.mysql.com/doc/refman/8.0/en/operator-precedence.html
+    fn prec_value(&self, prec: Precedence) -> u8 {
+        match prec {
+            Precedence::Or => 5,
+            Precedence::Xor => 7,
+            Precedence::And => 10,
+            Precedence::UnaryNot => 15,
+            Precedence::Between => 17,
+            Precedence::Is
+            | Precedence::Comparison
+            | Precedence::LessGreater
+            | Precedence::Like
+            | Precedence::Other => 20,
+            Precedence::BitwiseOr => 21,
+            Precedence::BitwiseAnd => 22,
+            Precedence::BitwiseShift => 23,
+            Precedence::PlusMinus => 30,
+            Precedence::MulDivMod => 40,
+            Precedence::Caret => 42,
+            // only if PIPES_AS_CONCAT is enabled, otherwise `||` is `OR`
+            Precedence::Concat => 43,
+            Precedence::Collate => 45,
+            Precedence::DoubleColon => 50,
+            // `#` begins a comment.
+            Precedence::BitwiseXor => 0,
+        }
+    }
 
-impl DialectParserConf for MysqlParserConfig {}
+    fn pipes_as_concat(&self) -> bool {
+        self.pipes_as_concat
+    }
+
+    fn supports_mysql_operators(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/charset-introducer.html
+    fn supports_charset_introducer(&self) -> bool {
+        true
+    }
+
+    fn supports_select_without_from(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/index-hints.html and
+    // https://dev.mysql.com/doc/refman/8.0/en/partitioning-selection.html
+    fn supports_index_hints(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/insert.html and
+    // https://dev.mysql.com/doc/refman/8.0/en/replace.html
+    fn supports_insert_set(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/insert.html
+    fn supports_empty_insert_values(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/create-table.html#create-table-partitioning
+    fn supports_partition_definitions(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/select.html
+    fn supports_group_by_select_list(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/select-into.html
+    fn supports_into_outfile(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/create-database.html
+    fn supports_mysql_database_options(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/rename-table.html
+    fn supports_rename_table(&self) -> bool {
+        true
+    }
+}
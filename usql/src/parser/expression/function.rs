@@ -1 +1,742 @@
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, vec};
 
+use crate::{
+    ast::{expression::*, types::*},
+    dialect::{Dialect, Precedence},
+    error::ParserError,
+    keywords::Keyword,
+    parser::Parser,
+    tokens::Token,
+};
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses a function call, assuming the function name have already been consumed.
+    ///
+    /// ```txt
+    /// <function> ::= <function name> ( [ DISTINCT ] [ <argument> [, ...] ] ) [ OVER <window name or specification> ]
+    /// ```
+    pub fn parse_function(&mut self, name: ObjectName) -> Result<Function, ParserError> {
+        self.expect_token(&Token::LeftParen)?;
+        let distinct = self.parse_keyword(Keyword::DISTINCT);
+        let args = if self.next_token_if_is(&Token::RightParen) {
+            vec![]
+        } else {
+            let args = self.parse_comma_separated(Self::parse_function_arg)?;
+            self.expect_token(&Token::RightParen)?;
+            args
+        };
+        let over = self.parse_keyword_then(Keyword::OVER, Self::parse_window_name_or_spec)?;
+        Ok(Function {
+            distinct,
+            name,
+            args,
+            over,
+        })
+    }
+
+    /// Parses an argument of function call.
+    pub fn parse_function_arg(&mut self) -> Result<FunctionArg, ParserError> {
+        Ok(FunctionArg::Unnamed(self.parse_expr()?))
+    }
+
+    /// Parses the conditional functions `NULLIF`, `COALESCE`, `GREATEST`, `LEAST`, `IF` and
+    /// `IIF` with the given name, assuming the function name have already been consumed.
+    /// Returns `None` if the name isn't one of them.
+    ///
+    /// ```txt
+    /// NULLIF ( <expr> , <expr> )
+    /// { COALESCE | GREATEST | LEAST } ( <expr> [, ...] )
+    /// { IF | IIF } ( <condition> , <expr> , <expr> )
+    /// ```
+    pub fn parse_conditional_function(&mut self, name: &str) -> Result<Option<Expr>, ParserError> {
+        let name = name.to_uppercase();
+        let expected_args = match name.as_str() {
+            "NULLIF" => Some(2),
+            "IF" | "IIF" => Some(3),
+            "COALESCE" | "GREATEST" | "LEAST" => None,
+            _ => return Ok(None),
+        };
+        let mut args = self
+            .parse_parenthesized_comma_separated(Self::parse_expr, false)?
+            .unwrap_or_default();
+        if let Some(expected) = expected_args {
+            if args.len() != expected {
+                return self.expected(
+                    format!("{} arguments for {}", expected, name),
+                    Some(args.len()),
+                );
+            }
+        }
+        let expr = match name.as_str() {
+            "NULLIF" => {
+                let right = args.pop().expect("NULLIF has 2 arguments");
+                let left = args.pop().expect("NULLIF has 2 arguments");
+                Expr::NullIf(NullIfExpr {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            }
+            "IF" | "IIF" => {
+                let else_result = args.pop().expect("IF has 3 arguments");
+                let then_result = args.pop().expect("IF has 3 arguments");
+                let condition = args.pop().expect("IF has 3 arguments");
+                Expr::If(IfExpr {
+                    iif: name == "IIF",
+                    condition: Box::new(condition),
+                    then_result: Box::new(then_result),
+                    else_result: Box::new(else_result),
+                })
+            }
+            "COALESCE" => Expr::Coalesce(args),
+            "GREATEST" => Expr::Greatest(args),
+            _ => Expr::Least(args),
+        };
+        Ok(Some(expr))
+    }
+
+    /// Parses a `CASE` expression.
+    ///
+    /// ```txt
+    /// <case expression> ::= CASE [ <operand> ] { WHEN <expr> THEN <result> } [...] [ ELSE <result> ] END
+    /// ```
+    pub fn parse_case_expr(&mut self) -> Result<CaseExpr, ParserError> {
+        self.expect_keyword(Keyword::CASE)?;
+        let operand = if !matches!(self.peek_token(), Some(token) if token.is_keyword(Keyword::WHEN))
+        {
+            Some(Box::new(self.parse_expr()?))
+        } else {
+            None
+        };
+        let mut conditions = vec![];
+        let mut results = vec![];
+        self.expect_keyword(Keyword::WHEN)?;
+        loop {
+            conditions.push(self.parse_expr()?);
+            self.expect_keyword(Keyword::THEN)?;
+            results.push(self.parse_expr()?);
+            if !self.parse_keyword(Keyword::WHEN) {
+                break;
+            }
+        }
+        let else_result = self
+            .parse_keyword_then(Keyword::ELSE, Self::parse_expr)?
+            .map(Box::new);
+        self.expect_keyword(Keyword::END)?;
+        Ok(CaseExpr {
+            operand,
+            conditions,
+            results,
+            else_result,
+        })
+    }
+
+    /// Parses a special value function.
+    ///
+    /// ```txt
+    /// <datetime value function> ::=
+    ///     CURRENT_DATE
+    ///     | { CURRENT_TIME | CURRENT_TIMESTAMP | LOCALTIME | LOCALTIMESTAMP } [ ( <precision> ) ]
+    ///
+    /// <general value specification> ::= CURRENT_USER | SESSION_USER | CURRENT_ROLE
+    /// ```
+    ///
+    /// MySQL also accepts empty parentheses, like `CURRENT_DATE()` or `CURRENT_USER()`.
+    pub fn parse_special_value_expr(&mut self) -> Result<SpecialValueExpr, ParserError> {
+        let value = match self.parse_one_of_keywords(&[
+            Keyword::CURRENT_DATE,
+            Keyword::CURRENT_TIME,
+            Keyword::CURRENT_TIMESTAMP,
+            Keyword::LOCALTIME,
+            Keyword::LOCALTIMESTAMP,
+            Keyword::CURRENT_USER,
+            Keyword::SESSION_USER,
+            Keyword::CURRENT_ROLE,
+        ]) {
+            Some(Keyword::CURRENT_DATE) => SpecialValue::CurrentDate,
+            Some(Keyword::CURRENT_TIME) => SpecialValue::CurrentTime,
+            Some(Keyword::CURRENT_TIMESTAMP) => SpecialValue::CurrentTimestamp,
+            Some(Keyword::LOCALTIME) => SpecialValue::LocalTime,
+            Some(Keyword::LOCALTIMESTAMP) => SpecialValue::LocalTimestamp,
+            Some(Keyword::CURRENT_USER) => SpecialValue::CurrentUser,
+            Some(Keyword::SESSION_USER) => SpecialValue::SessionUser,
+            Some(Keyword::CURRENT_ROLE) => SpecialValue::CurrentRole,
+            _ => {
+                let found = self.peek_token().cloned();
+                return self.expected("special value", found);
+            }
+        };
+        let precision = if self.next_token_if_is(&Token::LeftParen) {
+            if self.next_token_if_is(&Token::RightParen) {
+                None
+            } else if value.has_precision() {
+                let precision = self.parse_literal_uint()?;
+                self.expect_token(&Token::RightParen)?;
+                Some(precision)
+            } else {
+                let found = self.peek_token().cloned();
+                return self.expected(")", found);
+            }
+        } else {
+            None
+        };
+        Ok(SpecialValueExpr { value, precision })
+    }
+
+    /// Parses a `CAST` expression.
+    ///
+    /// ```txt
+    /// <cast specification> ::= CAST ( <expr> AS <data type> )
+    /// ```
+    pub fn parse_cast_expr(&mut self) -> Result<CastExpr, ParserError> {
+        self.expect_keyword(Keyword::CAST)?;
+        self.expect_token(&Token::LeftParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_keyword(Keyword::AS)?;
+        let data_type = self.parse_data_type()?;
+        self.expect_token(&Token::RightParen)?;
+        Ok(CastExpr {
+            expr: Box::new(expr),
+            data_type,
+            style: CastStyle::Cast,
+        })
+    }
+
+    /// Parses an array value constructor, assuming the `ARRAY` keyword has been consumed
+    /// if the array is named.
+    ///
+    /// ```txt
+    /// <array value constructor> ::= ARRAY [ <array element> [, ...] ]
+    /// ```
+    pub fn parse_array_expr(&mut self, named: bool) -> Result<ArrayExpr, ParserError> {
+        self.expect_token(&Token::LeftBracket)?;
+        if self.next_token_if_is(&Token::RightBracket) {
+            return Ok(ArrayExpr {
+                elements: vec![],
+                named,
+            });
+        }
+        let elements = self.parse_comma_separated(|parser| {
+            // The nested arrays of a multidimensional array can omit the `ARRAY` keyword.
+            if parser.peek_token() == Some(&Token::LeftBracket) {
+                Ok(Expr::Array(parser.parse_array_expr(false)?))
+            } else {
+                parser.parse_expr()
+            }
+        })?;
+        self.expect_token(&Token::RightBracket)?;
+        Ok(ArrayExpr { elements, named })
+    }
+
+    /// Parses an `EXISTS` predicate.
+    ///
+    /// ```txt
+    /// <exists predicate> ::= EXISTS ( <query expression> )
+    /// ```
+    pub fn parse_exists_expr(&mut self) -> Result<Query, ParserError> {
+        self.expect_keyword(Keyword::EXISTS)?;
+        self.parse_delimited(
+            &Token::LeftParen,
+            |parser| parser.parse_query_expr(false),
+            &Token::RightParen,
+        )
+    }
+
+    /// Parses an `EXTRACT` expression.
+    ///
+    /// ```txt
+    /// <extract expression> ::= EXTRACT ( <date time field> FROM <expr> )
+    /// ```
+    pub fn parse_extract_expr(&mut self) -> Result<ExtractExpr, ParserError> {
+        self.expect_keyword(Keyword::EXTRACT)?;
+        self.expect_token(&Token::LeftParen)?;
+        let field = self.parse_date_time_field()?;
+        self.expect_keyword(Keyword::FROM)?;
+        let expr = self.parse_expr()?;
+        self.expect_token(&Token::RightParen)?;
+        Ok(ExtractExpr {
+            field,
+            expr: Box::new(expr),
+        })
+    }
+
+    /// Parses a `SUBSTRING` expression, the comma-separated form (MySQL) is also accepted,
+    /// but the keywords and the commas can't be mixed.
+    ///
+    /// ```txt
+    /// <substring function> ::= SUBSTRING ( <expr> [ FROM <start position> ] [ FOR <string length> ] )
+    ///
+    /// // MySQL
+    /// <substring function> ::= SUBSTRING ( <expr> , <start position> [ , <string length> ] )
+    /// ```
+    pub fn parse_substring_expr(&mut self) -> Result<SubstringExpr, ParserError> {
+        self.expect_keyword(Keyword::SUBSTRING)?;
+        self.expect_token(&Token::LeftParen)?;
+        let expr = self.parse_expr()?;
+        let mut substring_from = None;
+        let mut substring_for = None;
+        if self.next_token_if_is(&Token::Comma) {
+            substring_from = Some(Box::new(self.parse_expr()?));
+            if self.next_token_if_is(&Token::Comma) {
+                substring_for = Some(Box::new(self.parse_expr()?));
+            }
+        } else {
+            if self.parse_keyword(Keyword::FROM) {
+                substring_from = Some(Box::new(self.parse_expr()?));
+            }
+            if self.parse_keyword(Keyword::FOR) {
+                substring_for = Some(Box::new(self.parse_expr()?));
+            }
+        }
+        self.expect_token(&Token::RightParen)?;
+        Ok(SubstringExpr {
+            expr: Box::new(expr),
+            substring_from,
+            substring_for,
+        })
+    }
+
+    /// Parses a `TRIM` expression.
+    ///
+    /// ```txt
+    /// <trim function> ::= TRIM ( [ { BOTH | LEADING | TRAILING } <trim character> FROM ] <expr> )
+    /// ```
+    pub fn parse_trim_expr(&mut self) -> Result<TrimExpr, ParserError> {
+        self.expect_keyword(Keyword::TRIM)?;
+        self.expect_token(&Token::LeftParen)?;
+        let field =
+            match self.parse_one_of_keywords(&[Keyword::BOTH, Keyword::LEADING, Keyword::TRAILING])
+            {
+                Some(Keyword::BOTH) => Some(TrimWhereField::Both),
+                Some(Keyword::LEADING) => Some(TrimWhereField::Leading),
+                Some(Keyword::TRAILING) => Some(TrimWhereField::Trailing),
+                _ => None,
+            };
+        let trim_where = match field {
+            Some(field) => {
+                let trim_char = self.parse_expr()?;
+                self.expect_keyword(Keyword::FROM)?;
+                Some((field, Box::new(trim_char)))
+            }
+            None => None,
+        };
+        let expr = self.parse_expr()?;
+        self.expect_token(&Token::RightParen)?;
+        Ok(TrimExpr {
+            expr: Box::new(expr),
+            trim_where,
+        })
+    }
+
+    /// Parses a `POSITION` expression.
+    ///
+    /// ```txt
+    /// <position expression> ::= POSITION ( <expr> IN <expr> )
+    /// ```
+    pub fn parse_position_expr(&mut self) -> Result<PositionExpr, ParserError> {
+        self.expect_keyword(Keyword::POSITION)?;
+        self.expect_token(&Token::LeftParen)?;
+        // The first operand can't contain the `IN` predicate.
+        let expr = self.parse_subexpr(self.prec_value(Precedence::Like))?;
+        self.expect_keyword(Keyword::IN)?;
+        let r#in = self.parse_expr()?;
+        self.expect_token(&Token::RightParen)?;
+        Ok(PositionExpr {
+            expr: Box::new(expr),
+            r#in: Box::new(r#in),
+        })
+    }
+
+    /// Parses an `OVERLAY` expression.
+    ///
+    /// ```txt
+    /// <overlay function> ::= OVERLAY ( <expr> PLACING <expr> FROM <start position> [ FOR <string length> ] )
+    /// ```
+    pub fn parse_overlay_expr(&mut self) -> Result<OverlayExpr, ParserError> {
+        self.expect_keyword(Keyword::OVERLAY)?;
+        self.expect_token(&Token::LeftParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_keyword(Keyword::PLACING)?;
+        let overlay_what = self.parse_expr()?;
+        self.expect_keyword(Keyword::FROM)?;
+        let overlay_from = self.parse_expr()?;
+        let overlay_for = self
+            .parse_keyword_then(Keyword::FOR, Self::parse_expr)?
+            .map(Box::new);
+        self.expect_token(&Token::RightParen)?;
+        Ok(OverlayExpr {
+            expr: Box::new(expr),
+            overlay_what: Box::new(overlay_what),
+            overlay_from: Box::new(overlay_from),
+            overlay_for,
+        })
+    }
+
+    /// Parses a `TRANSLATE` expression, or a plain `TRANSLATE(<expr>, ...)` function call
+    /// if the first argument isn't followed by `USING`.
+    ///
+    /// ```txt
+    /// <transliteration> ::= TRANSLATE ( <expr> USING <transliteration name> )
+    /// ```
+    pub fn parse_translate_expr(&mut self) -> Result<Expr, ParserError> {
+        let name = self.parse_identifier()?;
+        self.expect_token(&Token::LeftParen)?;
+        let expr = self.parse_expr()?;
+        if self.parse_keyword(Keyword::USING) {
+            let using = self.parse_object_name()?;
+            self.expect_token(&Token::RightParen)?;
+            return Ok(Expr::Translate(TranslateExpr {
+                expr: Box::new(expr),
+                using,
+            }));
+        }
+        let mut args = vec![FunctionArg::Unnamed(expr)];
+        while self.next_token_if_is(&Token::Comma) {
+            args.push(self.parse_function_arg()?);
+        }
+        self.expect_token(&Token::RightParen)?;
+        Ok(Expr::Function(Function {
+            distinct: false,
+            name: ObjectName(vec![name]),
+            args,
+            over: None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ExpectedKeywords;
+
+    #[test]
+    fn parse_function() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "COUNT(DISTINCT t.*)")?.parse_expr()?,
+            Expr::Function(Function {
+                distinct: true,
+                name: ObjectName::new(vec!["COUNT"]),
+                args: vec![FunctionArg::Unnamed(Expr::QualifiedWildcard(vec![
+                    Ident::new("t")
+                ]))],
+                over: None,
+            })
+        );
+
+        let sqls = [
+            "COUNT(*)",
+            "s.f(a.b.*, 1)",
+            "NOW()",
+            "SUM(x) OVER (PARTITION BY y)",
+            "SUM(x) OVER w",
+            "SUM(x) OVER (w ORDER BY y)",
+        ];
+        for sql in sqls {
+            let ansi = crate::ansi::AnsiDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&ansi, sql)?.parse_expr()?.to_string(),
+                sql
+            );
+            let mysql = crate::mysql::MysqlDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&mysql, sql)?.parse_expr()?.to_string(),
+                sql
+            );
+            let postgres = crate::postgres::PostgresDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&postgres, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+            let sqlite = crate::sqlite::SqliteDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&sqlite, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_conditional_aggregate() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let sql = "SUM(CASE WHEN a > 0 THEN 1 ELSE 0 END) OVER (PARTITION BY b ORDER BY c)";
+        let expr = Parser::new_with_sql(&dialect, sql)?.parse_expr()?;
+        match &expr {
+            Expr::Function(function) => {
+                assert!(matches!(
+                    function.args[0],
+                    FunctionArg::Unnamed(Expr::Case(_))
+                ));
+                assert!(matches!(function.over, Some(WindowNameOrSpec::Spec(_))));
+            }
+            _ => unreachable!(),
+        }
+
+        let sqls = [
+            "SUM(CASE WHEN a > 0 THEN 1 ELSE 0 END) OVER (PARTITION BY b ORDER BY c)",
+            "MAX(CASE b WHEN 1 THEN a WHEN 2 THEN - a END) OVER (ORDER BY c DESC)",
+            "SUM(CASE WHEN a THEN 1 END) OVER (ORDER BY b ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)",
+            "COUNT(DISTINCT CASE WHEN a THEN b END)",
+            "AVG(CASE WHEN a THEN b END) OVER w",
+            "SUM(COALESCE(a, 0)) OVER (PARTITION BY b)",
+            "AVG(NULLIF(a, 0)) OVER ()",
+            "COALESCE(SUM(a) OVER (PARTITION BY b), 0)",
+            "SUM(CASE WHEN a THEN 1 ELSE 0 END) / COUNT(*) OVER ()",
+        ];
+        for sql in sqls {
+            let ansi = crate::ansi::AnsiDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&ansi, sql)?.parse_expr()?.to_string(),
+                sql
+            );
+            let mysql = crate::mysql::MysqlDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&mysql, sql)?.parse_expr()?.to_string(),
+                sql
+            );
+            let postgres = crate::postgres::PostgresDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&postgres, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+            let sqlite = crate::sqlite::SqliteDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&sqlite, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_keyword_function() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_expr();
+
+        for sql in [
+            "LEFT(a, 3)",
+            "RIGHT(a, 3)",
+            "REPLACE(a, 'b', 'c')",
+            "MOD(a, 2)",
+        ] {
+            let expr = parse(sql)?;
+            assert!(matches!(expr, Expr::Function(_)), "{}", sql);
+            assert_eq!(expr.to_string(), sql);
+        }
+        assert!(matches!(parse("CAST(a AS INT)")?, Expr::Cast(_)));
+        assert!(matches!(parse("EXISTS (SELECT 1)")?, Expr::Exists(_)));
+        assert!(matches!(parse("EXTRACT(YEAR FROM a)")?, Expr::Extract(_)));
+        assert!(matches!(parse("TRIM(a)")?, Expr::Trim(_)));
+        assert!(matches!(parse("CASE WHEN a THEN 1 END")?, Expr::Case(_)));
+        // special forms are identifiers if not followed by `(`
+        assert_eq!(parse("trim")?, Expr::Identifier(Ident::new("trim")));
+        assert_eq!(
+            parse("SUBSTRING(a, 1, 2)")?.to_string(),
+            "SUBSTRING(a FROM 1 FOR 2)"
+        );
+        assert_eq!(parse("SUBSTRING(a, 1)")?.to_string(), "SUBSTRING(a FROM 1)");
+        assert_eq!(
+            parse("SUBSTRING(a FOR 2)")?.to_string(),
+            "SUBSTRING(a FOR 2)"
+        );
+        // the keywords and the commas can't be mixed
+        for sql in [
+            "SUBSTRING(a FROM 1, 2)",
+            "SUBSTRING(a, 1 FOR 2)",
+            "SUBSTRING(a FOR 2, 1)",
+            "SUBSTRING(a, FROM 1)",
+        ] {
+            assert!(parse(sql).is_err(), "{}", sql);
+        }
+
+        let sqls = [
+            "CAST(a AS DECIMAL(10,2))",
+            "EXTRACT(YEAR FROM a)",
+            "SUBSTRING(a FROM 1 FOR 2)",
+            "TRIM(BOTH 'x' FROM a)",
+            "CASE a WHEN 1 THEN 'x' ELSE 'y' END",
+            "CASE WHEN a > 1 THEN 1 WHEN a < 0 THEN 2 END + 1",
+            "NOT EXISTS (SELECT 1 FROM t)",
+        ];
+        for sql in sqls {
+            assert_eq!(parse(sql)?.to_string(), sql);
+            let postgres = crate::postgres::PostgresDialect::default();
+            assert_eq!(
+                Parser::new_with_sql(&postgres, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // `TRIM` is a plain function in SQLite
+        let sqlite = crate::sqlite::SqliteDialect::default();
+        assert!(matches!(
+            Parser::new_with_sql(&sqlite, "TRIM(a, 'x')")?.parse_expr()?,
+            Expr::Function(_)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_special_value_expr() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_expr();
+
+        assert_eq!(
+            parse("LOCALTIME(2)")?,
+            Expr::SpecialValue(SpecialValueExpr {
+                value: SpecialValue::LocalTime,
+                precision: Some(2),
+            })
+        );
+        let sqls = [
+            "CURRENT_DATE",
+            "CURRENT_TIME(3)",
+            "CURRENT_TIMESTAMP - INTERVAL '1' DAY",
+            "LOCALTIMESTAMP",
+            "CURRENT_USER = SESSION_USER",
+            "CURRENT_ROLE",
+        ];
+        for sql in sqls {
+            assert_eq!(parse(sql)?.to_string(), sql);
+        }
+        assert!(parse("CURRENT_DATE(2)").is_err());
+
+        // MySQL accepts empty parentheses
+        let mysql = crate::mysql::MysqlDialect::default();
+        for (sql, expected) in [
+            ("CURRENT_TIMESTAMP()", "CURRENT_TIMESTAMP"),
+            ("current_user()", "CURRENT_USER"),
+            ("CURRENT_TIMESTAMP(6)", "CURRENT_TIMESTAMP(6)"),
+        ] {
+            assert_eq!(
+                Parser::new_with_sql(&mysql, sql)?.parse_expr()?.to_string(),
+                expected
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_conditional_function() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_expr();
+
+        assert_eq!(
+            parse("nullif(a, 0)")?,
+            Expr::NullIf(NullIfExpr {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                right: Box::new(Expr::Literal(Literal::Number("0".into()))),
+            })
+        );
+        assert_eq!(
+            parse("IF(a > 0, 'x', NULL)")?,
+            Expr::If(IfExpr {
+                iif: false,
+                condition: Box::new(Expr::BinaryOp(BinaryOpExpr {
+                    left: Box::new(Expr::Identifier(Ident::new("a"))),
+                    op: BinaryOperator::Greater,
+                    right: Box::new(Expr::Literal(Literal::Number("0".into()))),
+                })),
+                then_result: Box::new(Expr::Literal(Literal::String("x".into()))),
+                else_result: Box::new(Expr::Literal(Literal::Null)),
+            })
+        );
+        let sqls = [
+            "NULLIF(a, b)",
+            "COALESCE(a, b, 0)",
+            "GREATEST(a, 1)",
+            "LEAST(a)",
+            "IF(a, 1, 2)",
+            "IIF(a, 1, 2)",
+        ];
+        for sql in sqls {
+            assert_eq!(parse(sql)?.to_string(), sql);
+        }
+        // quoted or qualified names are plain function calls
+        assert!(matches!(parse("`if`(a, 1, 2)")?, Expr::Function(_)));
+        assert!(matches!(parse("s.coalesce(a)")?, Expr::Function(_)));
+
+        assert_eq!(
+            parse("NULLIF(a)").unwrap_err(),
+            ParserError::UnexpectedToken {
+                expected: "2 arguments for NULLIF".into(),
+                found: "1".into(),
+                keywords: ExpectedKeywords::None
+            }
+        );
+        assert!(parse("COALESCE()").is_err());
+
+        // the `IF` keyword of DDL isn't affected
+        let postgres = crate::postgres::PostgresDialect::default();
+        assert!(
+            Parser::new_with_sql(&postgres, "CREATE TABLE IF NOT EXISTS t (a INT)")?
+                .parse_stmt()
+                .is_ok()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_string_function_with_keyword_args() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_expr();
+
+        assert_eq!(
+            parse("POSITION('b' IN a)")?,
+            Expr::Position(PositionExpr {
+                expr: Box::new(Expr::Literal(Literal::String("b".into()))),
+                r#in: Box::new(Expr::Identifier(Ident::new("a"))),
+            })
+        );
+        assert_eq!(
+            parse("TRANSLATE(a USING s.latin1_to_utf8)")?,
+            Expr::Translate(TranslateExpr {
+                expr: Box::new(Expr::Identifier(Ident::new("a"))),
+                using: ObjectName::new(vec!["s", "latin1_to_utf8"]),
+            })
+        );
+        assert!(matches!(
+            parse("TRANSLATE(a, 'ab', 'cd')")?,
+            Expr::Function(_)
+        ));
+
+        let sqls = [
+            "POSITION('b' IN a || 'c')",
+            "OVERLAY(a PLACING 'xx' FROM 2)",
+            "OVERLAY(a PLACING 'xx' FROM 2 FOR 3)",
+            "TRANSLATE(a USING latin1_to_utf8)",
+            "TRANSLATE(a, 'ab', 'cd')",
+        ];
+        for sql in sqls {
+            assert_eq!(parse(sql)?.to_string(), sql);
+        }
+
+        let postgres = crate::postgres::PostgresDialect::default();
+        let sql = "OVERLAY(a PLACING 'xx' FROM POSITION('y' IN a))";
+        assert_eq!(
+            Parser::new_with_sql(&postgres, sql)?
+                .parse_expr()?
+                .to_string(),
+            sql
+        );
+        let mysql = crate::mysql::MysqlDialect::default();
+        let sql = "POSITION('b' IN a)";
+        assert_eq!(
+            Parser::new_with_sql(&mysql, sql)?.parse_expr()?.to_string(),
+            sql
+        );
+        assert!(parse("POSITION(a, b)").is_err());
+        Ok(())
+    }
+}
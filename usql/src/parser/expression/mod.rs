@@ -2,15 +2,15 @@ mod function;
 mod query;
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, format, vec, vec::Vec};
+use alloc::{boxed::Box, format, vec};
 
 use crate::{
     ast::{expression::*, types::*},
-    dialect::Dialect,
+    dialect::{Dialect, DialectParserConf, Precedence},
     error::{parse_error, ParserError},
-    keywords::Keyword,
+    keywords::{Keyword, KeywordDef},
     parser::Parser,
-    tokens::{Token, Word},
+    tokens::{Token, TokenKind, Word},
 };
 
 impl<'a, D: Dialect> Parser<'a, D> {
@@ -21,157 +21,381 @@ impl<'a, D: Dialect> Parser<'a, D> {
 
     /// Parses tokens until the precedence changes.
     pub fn parse_subexpr(&mut self, precedence: u8) -> Result<Expr, ParserError> {
-        let mut expr = self.parse_prefix()?;
-        loop {
-            let next_precedence = self.next_precedence()?;
-            if precedence >= next_precedence {
-                break;
+        self.recurse(|parser| {
+            let mut expr = parser.parse_prefix()?;
+            loop {
+                let next_precedence = parser.next_precedence()?;
+                if precedence >= next_precedence {
+                    break;
+                }
+                expr = parser.parse_infix(Box::new(expr), next_precedence)?;
             }
-            expr = self.parse_infix(Box::new(expr), next_precedence)?;
-        }
-        Ok(expr)
+            Ok(expr)
+        })
     }
 
-    const UNARY_NOT_PREC: u8 = 15;
-    const BETWEEN_PREC: u8 = 20;
-    const PLUS_MINUS_PREC: u8 = 30;
+    /// Returns the precedence value of the operator class in the current dialect.
+    fn prec_value(&self, prec: Precedence) -> u8 {
+        self.dialect.parser_conf().prec_value(prec)
+    }
 
     /// Parses an expression prefix.
     pub fn parse_prefix(&mut self) -> Result<Expr, ParserError> {
-        let token = self.peek_next_token().cloned();
-        if let Some(token) = token {
-            match token {
-                Token::Number(_)
-                | Token::String(_)
-                | Token::NationalString(_)
-                | Token::HexString(_)
-                | Token::BitString(_) => Ok(Expr::Literal(self.parse_literal()?)),
-                Token::Word(word) => match word.keyword {
-                    Some(Keyword::NULL)
-                    | Some(Keyword::TRUE)
-                    | Some(Keyword::FALSE)
-                    | Some(Keyword::DATE)
+        if let Some(kind) = self.peek_next_kind() {
+            match kind {
+                TokenKind::Number
+                | TokenKind::String
+                | TokenKind::NationalString
+                | TokenKind::EscapeString
+                | TokenKind::HexString
+                | TokenKind::BitString => Ok(Expr::Literal(self.parse_literal()?)),
+                TokenKind::Word { keyword, quote } => match keyword {
+                    Some(Keyword::NULL) | Some(Keyword::TRUE) | Some(Keyword::FALSE) => {
+                        Ok(Expr::Literal(self.parse_literal()?))
+                    }
+                    // `DATE`, `TIME`, `TIMESTAMP` and `INTERVAL` are only literals if followed by
+                    // a string, otherwise they are identifiers like `SELECT date FROM t`.
+                    Some(Keyword::DATE)
                     | Some(Keyword::TIME)
-                    | Some(Keyword::DATETIME)
-                    | Some(Keyword::INTERVAL) => Ok(Expr::Literal(self.parse_literal()?)),
+                    | Some(Keyword::TIMESTAMP)
+                    | Some(Keyword::INTERVAL)
+                        if matches!(self.peek_next_token(), Some(Token::String(_))) =>
+                    {
+                        Ok(Expr::Literal(self.parse_literal()?))
+                    }
+                    None if quote.is_none()
+                        && self.next_word_matches(|value| value.starts_with('_'))
+                        && self.dialect.parser_conf().supports_charset_introducer()
+                        && matches!(self.peek_next_token(), Some(Token::String(_))) =>
+                    {
+                        Ok(Expr::Literal(self.parse_literal()?))
+                    }
+                    // The `PRIOR` operator and the `LEVEL` pseudo-column of the hierarchical
+                    // queries, which are not keywords in the other dialects.
+                    _ if self.next_word_matches(|value| value.eq_ignore_ascii_case("PRIOR"))
+                        && self.dialect.parser_conf().supports_connect_by() =>
+                    {
+                        self.next_token(); // consume `PRIOR`
+                        Ok(Expr::UnaryOp(UnaryOpExpr {
+                            op: UnaryOperator::Prior,
+                            expr: Box::new(
+                                self.parse_subexpr(self.prec_value(Precedence::PlusMinus))?,
+                            ),
+                        }))
+                    }
+                    _ if self.next_word_matches(|value| value.eq_ignore_ascii_case("LEVEL"))
+                        && self.dialect.parser_conf().supports_connect_by() =>
+                    {
+                        self.next_token(); // consume `LEVEL`
+                        Ok(Expr::PseudoColumn(PseudoColumn::Level))
+                    }
+                    Some(
+                        Keyword::CURRENT_DATE
+                        | Keyword::CURRENT_TIME
+                        | Keyword::CURRENT_TIMESTAMP
+                        | Keyword::LOCALTIME
+                        | Keyword::LOCALTIMESTAMP
+                        | Keyword::CURRENT_USER
+                        | Keyword::SESSION_USER
+                        | Keyword::CURRENT_ROLE,
+                    ) => {
+                        self.reset_peek_cursor();
+                        Ok(Expr::SpecialValue(self.parse_special_value_expr()?))
+                    }
+                    // `NEXT VALUE FOR <sequence>`, otherwise `NEXT` is an identifier
+                    Some(Keyword::NEXT)
+                        if self.dialect.parser_conf().supports_next_value_for()
+                            && matches!(self.peek_next_token(), Some(token) if token.is_keyword(Keyword::VALUE))
+                            && matches!(self.peek_next_token(), Some(token) if token.is_keyword(Keyword::FOR)) =>
+                    {
+                        self.reset_peek_cursor();
+                        self.expect_keywords(&[Keyword::NEXT, Keyword::VALUE, Keyword::FOR])?;
+                        Ok(Expr::NextValueFor(self.parse_object_name()?))
+                    }
                     Some(Keyword::NOT) => {
                         self.next_token(); // consume the `NOT` keyword
                         Ok(Expr::UnaryOp(UnaryOpExpr {
                             op: UnaryOperator::Not,
-                            expr: Box::new(self.parse_subexpr(Self::UNARY_NOT_PREC)?),
+                            expr: Box::new(
+                                self.parse_subexpr(self.prec_value(Precedence::UnaryNot))?,
+                            ),
                         }))
                     }
-                    // Keyword::CASE => self.parse_case_expr(),
-                    // Keyword::CAST => self.parse_cast_expr(),
-                    // Keyword::EXISTS => self.parse_exists_expr(),
-                    // Keyword::EXTRACT => self.parse_extract_expr(),
-                    // Keyword::SUBSTRING => self.parse_substring_expr(),
-                    // Keyword::TRIM => self.parse_trim_expr(),
-                    // Keyword::LISTAGG => self.parse_listagg_expr(),
-                    _ if self.peek_next_token() == Some(&Token::Period) => {
-                        self.next_token(); // consume the token word.
-                        let mut id_parts: Vec<Ident> = vec![Ident {
-                            value: word.value,
-                            quote: word.quote,
-                        }];
-                        let mut ends_with_wildcard = false;
-                        while self.next_token_if_is(&Token::Period) {
-                            match self.next_token() {
-                                Some(Token::Word(w)) => id_parts.push(Ident {
-                                    value: w.value,
-                                    quote: w.quote,
-                                }),
-                                Some(Token::Asterisk) => {
-                                    ends_with_wildcard = true;
-                                    break;
-                                }
-                                unexpected => {
-                                    return self
-                                        .expected("an identifier or a '*' after '.'", unexpected)
-                                }
-                            }
-                        }
-                        if ends_with_wildcard {
-                            Ok(Expr::QualifiedWildcard(id_parts))
+                    Some(Keyword::CASE) => {
+                        self.reset_peek_cursor();
+                        Ok(Expr::Case(self.parse_case_expr()?))
+                    }
+                    // `ARRAY` is only an array value constructor if followed by `[` or `(`.
+                    Some(Keyword::ARRAY)
+                        if matches!(
+                            self.peek_next_token(),
+                            Some(Token::LeftBracket | Token::LeftParen)
+                        ) =>
+                    {
+                        self.reset_peek_cursor();
+                        self.next_token(); // consume the `ARRAY` keyword
+                        if self.peek_token() == Some(&Token::LeftParen) {
+                            let query = self.parse_delimited(
+                                &Token::LeftParen,
+                                |parser| parser.parse_query_expr(false),
+                                &Token::RightParen,
+                            )?;
+                            Ok(Expr::ArraySubquery(Box::new(query)))
                         } else {
-                            Ok(Expr::CompoundIdentifier(id_parts))
+                            Ok(Expr::Array(self.parse_array_expr(true)?))
                         }
                     }
-                    _ => Ok(Expr::Identifier(self.parse_identifier()?)),
+                    // The special forms with keyword arguments, they are only recognized if
+                    // followed by `(`, otherwise they are identifiers.
+                    Some(
+                        keyword @ (Keyword::CAST
+                        | Keyword::EXISTS
+                        | Keyword::EXTRACT
+                        | Keyword::SUBSTRING
+                        | Keyword::TRIM
+                        | Keyword::POSITION
+                        | Keyword::OVERLAY
+                        | Keyword::TRANSLATE),
+                    ) if self.peek_next_token() == Some(&Token::LeftParen) => {
+                        self.reset_peek_cursor();
+                        match keyword {
+                            Keyword::CAST => Ok(Expr::Cast(self.parse_cast_expr()?)),
+                            Keyword::EXISTS => {
+                                Ok(Expr::Exists(Box::new(self.parse_exists_expr()?)))
+                            }
+                            Keyword::EXTRACT => Ok(Expr::Extract(self.parse_extract_expr()?)),
+                            Keyword::SUBSTRING => Ok(Expr::Substring(self.parse_substring_expr()?)),
+                            Keyword::TRIM => Ok(Expr::Trim(self.parse_trim_expr()?)),
+                            Keyword::POSITION => Ok(Expr::Position(self.parse_position_expr()?)),
+                            Keyword::OVERLAY => Ok(Expr::Overlay(self.parse_overlay_expr()?)),
+                            _ => self.parse_translate_expr(),
+                        }
+                    }
+                    // Keyword::LISTAGG => self.parse_listagg_expr(),
+                    // A reserved keyword that starts a clause or a join is not an identifier,
+                    // like the `FROM` of `SELECT a * FROM t`, except the functions `LEFT(s, 3)`
+                    // and `RIGHT(s, 3)`.
+                    Some(keyword)
+                        if D::Keyword::RESERVED_KEYWORDS.contains(&keyword)
+                            && self
+                                .dialect
+                                .parser_conf()
+                                .reserved_for_alias()
+                                .contains(&keyword)
+                            && !matches!(keyword, Keyword::LEFT | Keyword::RIGHT) =>
+                    {
+                        let found = self.peek_nth_token(0).cloned();
+                        self.expected("an expression", found)
+                    }
+                    // Other keywords followed by `(` are plain function calls, like `LEFT(s, 3)`
+                    // or `REPLACE(s, 'a', 'b')`, otherwise they are identifiers.
+                    _ => self.parse_identifier_expr(),
                 },
-                Token::Minus => {
+                TokenKind::Minus => {
                     self.next_token(); // consume `-`
                     Ok(Expr::UnaryOp(UnaryOpExpr {
                         op: UnaryOperator::Minus,
-                        expr: Box::new(self.parse_subexpr(Self::PLUS_MINUS_PREC)?),
+                        expr: Box::new(self.parse_subexpr(self.prec_value(Precedence::PlusMinus))?),
                     }))
                 }
-                Token::Plus => {
+                TokenKind::Plus => {
                     self.next_token(); // consume `+`
                     Ok(Expr::UnaryOp(UnaryOpExpr {
                         op: UnaryOperator::Plus,
-                        expr: Box::new(self.parse_subexpr(Self::PLUS_MINUS_PREC)?),
+                        expr: Box::new(self.parse_subexpr(self.prec_value(Precedence::PlusMinus))?),
+                    }))
+                }
+                TokenKind::Exclamation if self.dialect.parser_conf().supports_mysql_operators() => {
+                    self.next_token(); // consume `!`
+                    Ok(Expr::UnaryOp(UnaryOpExpr {
+                        op: UnaryOperator::LogicalNot,
+                        expr: Box::new(self.parse_subexpr(self.prec_value(Precedence::Caret))?),
                     }))
                 }
-                Token::Asterisk => {
+                TokenKind::PipeSlash
+                | TokenKind::DoublePipeSlash
+                | TokenKind::AtMinusAt
+                | TokenKind::Sharp
+                    if self.dialect.parser_conf().supports_postgres_operators() =>
+                {
+                    let op = match self.next_token() {
+                        Some(Token::PipeSlash) => UnaryOperator::SquareRoot,
+                        Some(Token::DoublePipeSlash) => UnaryOperator::CubeRoot,
+                        Some(Token::Sharp) => UnaryOperator::NumberOfPoints,
+                        _ => UnaryOperator::Length,
+                    };
+                    Ok(Expr::UnaryOp(UnaryOpExpr {
+                        op,
+                        expr: Box::new(self.parse_subexpr(self.prec_value(Precedence::PlusMinus))?),
+                    }))
+                }
+                TokenKind::Asterisk => {
                     self.next_token(); // consume `*`
                     Ok(Expr::Wildcard)
                 }
-                Token::LeftParen => {
+                TokenKind::LeftParen => {
                     self.next_token(); // consume `(`
-                    let expr = if self.next_is_query() {
-                        Expr::Subquery(Box::new(self.parse_query_expr(true)?))
-                    } else {
-                        Expr::Nested(Box::new(self.parse_expr()?))
+                                       // a scalar subquery or a parenthesized expression
+                    let expr = match self.parse_optional_subquery()? {
+                        Some(subquery) => Expr::Subquery(Box::new(subquery)),
+                        None => Expr::Nested(Box::new(self.parse_expr()?)),
                     };
                     self.expect_token(&Token::RightParen)?;
-                    Ok(expr)
+                    self.parse_composite_selection(expr)
+                }
+                _ => {
+                    let found = self.peek_nth_token(0).cloned();
+                    self.expected("an expression infix", found)
                 }
-                unexpected => self.expected("an expression infix", Some(unexpected)),
             }
         } else {
             self.expected("an expression prefix", Option::<Token>::None)
         }
     }
 
+    /// Checks if the next token is an unquoted word whose value matches the predicate, without
+    /// consuming the token or moving the peeking "cursor".
+    fn next_word_matches(&mut self, f: impl FnOnce(&str) -> bool) -> bool {
+        matches!(self.peek_nth_token(0), Some(Token::Word(w)) if w.quote.is_none() && f(&w.value))
+    }
+
+    /// Parses the field selections following a parenthesized expression.
+    ///
+    /// ```txt
+    /// <composite wildcard> ::= ( <expr> ) . *
+    /// <composite field> ::= ( <expr> ) . <field name> [ . <field name> ... ]
+    /// ```
+    fn parse_composite_selection(&mut self, mut expr: Expr) -> Result<Expr, ParserError> {
+        while self.next_token_if_is(&Token::Period) {
+            match self.next_token() {
+                Some(Token::Asterisk) => return Ok(Expr::CompositeWildcard(Box::new(expr))),
+                Some(Token::Word(w)) => {
+                    expr = Expr::CompositeField(CompositeFieldExpr {
+                        expr: Box::new(expr),
+                        field: Ident {
+                            value: w.value,
+                            quote: w.quote,
+                        },
+                    })
+                }
+                unexpected => return self.expected("a field name or a '*' after '.'", unexpected),
+            }
+        }
+        Ok(expr)
+    }
+
+    /// Parses an identifier, a compound identifier, a qualified wildcard or a function call.
+    ///
+    /// ```txt
+    /// <identifier chain> ::= <ident> [ . <ident> ... ]
+    /// <qualified asterisk> ::= <ident> [ . <ident> ... ] . *
+    /// <function> ::= <ident> [ . <ident> ... ] ( ... )
+    /// ```
+    fn parse_identifier_expr(&mut self) -> Result<Expr, ParserError> {
+        let mut id_parts = vec![self.parse_identifier()?];
+        while self.next_token_if_is(&Token::Period) {
+            match self.next_token() {
+                Some(Token::Word(w)) => id_parts.push(Ident {
+                    value: w.value,
+                    quote: w.quote,
+                }),
+                Some(Token::Asterisk) => return Ok(Expr::QualifiedWildcard(id_parts)),
+                unexpected => return self.expected("an identifier or a '*' after '.'", unexpected),
+            }
+        }
+        if self.peek_token() == Some(&Token::LeftParen) {
+            if let [name] = id_parts.as_slice() {
+                if self.dialect.parser_conf().is_type_name(name) {
+                    // <type name> ( <expr> )
+                    let expr = self.parse_delimited(
+                        &Token::LeftParen,
+                        Self::parse_expr,
+                        &Token::RightParen,
+                    )?;
+                    return Ok(Expr::Cast(CastExpr {
+                        expr: Box::new(expr),
+                        data_type: DataType::Custom(ObjectName(id_parts)),
+                        style: CastStyle::Function,
+                    }));
+                }
+            }
+            if let [Ident { value, quote: None }] = id_parts.as_slice() {
+                if let Some(expr) = self.parse_conditional_function(value)? {
+                    return Ok(expr);
+                }
+            }
+            Ok(Expr::Function(self.parse_function(ObjectName(id_parts))?))
+        } else if id_parts.len() == 1 {
+            Ok(Expr::Identifier(id_parts.remove(0)))
+        } else {
+            Ok(Expr::CompoundIdentifier(id_parts))
+        }
+    }
+
     /// Gets the precedence of the next token.
     pub fn next_precedence(&mut self) -> Result<u8, ParserError> {
+        let conf = self.dialect.parser_conf();
+        let mysql = conf.supports_mysql_operators();
+        let postgres = conf.supports_postgres_operators();
+        let prec = |prec| Ok(conf.prec_value(prec));
         let precedence = if let Some(token) = self.peek_next_token() {
             match token {
-                token if token.is_keyword(Keyword::OR) => Ok(5),
-                token if token.is_keyword(Keyword::AND) => Ok(10),
-                token if token.is_keyword(Keyword::XOR) => Ok(24),
+                token if token.is_keyword(Keyword::OR) => prec(Precedence::Or),
+                token if token.is_keyword(Keyword::AND) => prec(Precedence::And),
+                token if token.is_keyword(Keyword::XOR) => prec(Precedence::Xor),
                 Token::Word(w) if w.keyword == Some(Keyword::NOT) => match self.peek_next_token() {
                     // The precedence of NOT varies depending on keyword that
                     // follows it. If it is followed by IN, BETWEEN, or LIKE,
                     // it takes on the precedence of those tokens. Otherwise it
                     // is not an infix operator, and therefore has zero precedence.
-                    Some(token) if token.is_keyword(Keyword::IN) => Ok(Self::BETWEEN_PREC),
-                    Some(token) if token.is_keyword(Keyword::BETWEEN) => Ok(Self::BETWEEN_PREC),
-                    Some(token) if token.is_keyword(Keyword::LIKE) => Ok(Self::BETWEEN_PREC),
-                    Some(token) if token.is_keyword(Keyword::ILIKE) => Ok(Self::BETWEEN_PREC),
+                    Some(token) if token.is_keyword(Keyword::IN) => prec(Precedence::Like),
+                    Some(token) if token.is_keyword(Keyword::BETWEEN) => prec(Precedence::Between),
+                    Some(token) if token.is_keyword(Keyword::LIKE) => prec(Precedence::Like),
+                    Some(token) if token.is_keyword(Keyword::ILIKE) => prec(Precedence::Like),
                     _ => Ok(0),
                 },
-                token if token.is_keyword(Keyword::IS) => Ok(17),
-                token if token.is_keyword(Keyword::IN) => Ok(Self::BETWEEN_PREC),
-                token if token.is_keyword(Keyword::BETWEEN) => Ok(Self::BETWEEN_PREC),
-                token if token.is_keyword(Keyword::LIKE) => Ok(Self::BETWEEN_PREC),
-                token if token.is_keyword(Keyword::ILIKE) => Ok(Self::BETWEEN_PREC),
-                Token::Equal
-                | Token::Less
-                | Token::LessOrEqual
-                | Token::NotEqual
-                | Token::Greater
-                | Token::GreaterOrEqual
-                | Token::Tilde => Ok(20),
-                Token::Pipe => Ok(21),
-                Token::Caret | Token::Sharp | Token::LeftShift | Token::RightShift => Ok(22),
-                Token::Ampersand => Ok(23),
-                Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
-                Token::Asterisk | Token::Slash | Token::Percent | Token::Concat => Ok(40),
-                Token::DoubleColon => Ok(50),
+                token if token.is_keyword(Keyword::IS) => prec(Precedence::Is),
+                token if token.is_keyword(Keyword::IN) => prec(Precedence::Like),
+                token if token.is_keyword(Keyword::BETWEEN) => prec(Precedence::Between),
+                token if token.is_keyword(Keyword::LIKE) => prec(Precedence::Like),
+                token if token.is_keyword(Keyword::ILIKE) => prec(Precedence::Like),
+                Token::Equal | Token::NotEqual => prec(Precedence::Comparison),
+                Token::Less | Token::LessOrEqual | Token::Greater | Token::GreaterOrEqual => {
+                    prec(Precedence::LessGreater)
+                }
+                Token::Spaceship if mysql => prec(Precedence::Comparison),
+                Token::Tilde
+                | Token::ExclamationTilde
+                | Token::TildeAsterisk
+                | Token::ExclamationTildeAsterisk
+                | Token::DoubleTilde
+                | Token::ExclamationDoubleTilde
+                | Token::LessMinusGreater
+                    if postgres =>
+                {
+                    prec(Precedence::Other)
+                }
+                Token::Pipe => prec(Precedence::BitwiseOr),
+                Token::Sharp if postgres => prec(Precedence::BitwiseXor),
+                Token::Ampersand => prec(Precedence::BitwiseAnd),
+                Token::LeftShift | Token::RightShift => prec(Precedence::BitwiseShift),
+                Token::Plus | Token::Minus => prec(Precedence::PlusMinus),
+                Token::Asterisk | Token::Slash | Token::Percent => prec(Precedence::MulDivMod),
+                token
+                    if mysql
+                        && token
+                            .is_one_of_keywords(&[Keyword::DIV, Keyword::MOD])
+                            .is_some() =>
+                {
+                    prec(Precedence::MulDivMod)
+                }
+                Token::Concat if conf.pipes_as_concat() => prec(Precedence::Concat),
+                Token::Concat => prec(Precedence::Or),
+                Token::Caret => prec(Precedence::Caret),
+                token if token.is_keyword(Keyword::COLLATE) => prec(Precedence::Collate),
+                Token::DoubleColon => prec(Precedence::DoubleColon),
                 Token::Exclamation => Ok(50),
-                Token::LeftBracket | Token::RightBracket => Ok(10),
+                Token::LeftBracket => Ok(10),
                 _ => Ok(0),
             }
         } else {
@@ -186,33 +410,14 @@ impl<'a, D: Dialect> Parser<'a, D> {
         let token = self.next_token();
         if let Some(token) = &token {
             let regular_binary_operator = match token {
-                Token::Plus => Some(BinaryOperator::Plus),
-                Token::Minus => Some(BinaryOperator::Minus),
-                Token::Asterisk => Some(BinaryOperator::Multiply),
-                Token::Slash => Some(BinaryOperator::Divide),
-                Token::Percent => Some(BinaryOperator::Modulo),
-
-                Token::Greater => Some(BinaryOperator::Greater),
-                Token::Less => Some(BinaryOperator::Less),
-                Token::GreaterOrEqual => Some(BinaryOperator::GreaterOrEqual),
-                Token::LessOrEqual => Some(BinaryOperator::LessOrEqual),
-                Token::Equal => Some(BinaryOperator::Equal),
-                Token::NotEqual => Some(BinaryOperator::NotEqual),
-
-                Token::Concat => Some(BinaryOperator::StringConcat),
-
-                Token::Ampersand => Some(BinaryOperator::BitwiseAnd),
-                Token::Pipe => Some(BinaryOperator::BitwiseOr),
-                Token::Caret => Some(BinaryOperator::BitwiseXor),
-                Token::LeftShift => Some(BinaryOperator::BitwiseLeftShift),
-                Token::RightShift => Some(BinaryOperator::BitwiseRightShift),
-
                 Token::Word(word) => match word.keyword {
                     Some(Keyword::AND) => Some(BinaryOperator::And),
                     Some(Keyword::OR) => Some(BinaryOperator::Or),
                     Some(Keyword::XOR) => Some(BinaryOperator::Xor),
                     Some(Keyword::LIKE) => Some(BinaryOperator::Like),
                     Some(Keyword::ILIKE) => Some(BinaryOperator::ILike),
+                    Some(Keyword::DIV) => Some(BinaryOperator::IntegerDivide),
+                    Some(Keyword::MOD) => Some(BinaryOperator::Modulo),
                     Some(Keyword::NOT) if self.parse_keyword(Keyword::LIKE) => {
                         Some(BinaryOperator::NotLike)
                     }
@@ -221,15 +426,20 @@ impl<'a, D: Dialect> Parser<'a, D> {
                     }
                     _ => None,
                 },
-                _ => None,
+                Token::Concat if !self.dialect.parser_conf().pipes_as_concat() => {
+                    Some(BinaryOperator::Or)
+                }
+                token => Self::binary_operator(token),
             };
 
             if let Some(op) = regular_binary_operator {
                 let right = self.parse_subexpr(precedence)?;
-                Ok(Expr::BinaryOp(BinaryOpExpr {
-                    left: expr,
-                    op,
-                    right: Box::new(right),
+                Ok(Expr::binary(*expr, op, right))
+            } else if token == &Token::DoubleColon {
+                Ok(Expr::Cast(CastExpr {
+                    expr,
+                    data_type: self.parse_data_type()?,
+                    style: CastStyle::DoubleColon,
                 }))
             } else if let Token::Word(Word {
                 keyword: Some(keyword),
@@ -264,6 +474,10 @@ impl<'a, D: Dialect> Parser<'a, D> {
                     }
                     Keyword::IN => self.parse_in(expr, false),
                     Keyword::BETWEEN => self.parse_between(expr, false),
+                    Keyword::COLLATE => Ok(Expr::Collate(CollateExpr {
+                        expr,
+                        collation: self.parse_object_name()?,
+                    })),
                     // Can only happen if `next_precedence` got out of sync with this function
                     _ => parse_error(format!("No infix parser for token {:?}", token)),
                 }
@@ -275,23 +489,58 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }
     }
 
+    /// Returns the binary operator of a symbol token, like `+` or `<=`.
+    pub(crate) fn binary_operator(token: &Token) -> Option<BinaryOperator> {
+        match token {
+            Token::Plus => Some(BinaryOperator::Plus),
+            Token::Minus => Some(BinaryOperator::Minus),
+            Token::Asterisk => Some(BinaryOperator::Multiply),
+            Token::Slash => Some(BinaryOperator::Divide),
+            Token::Percent => Some(BinaryOperator::Modulo),
+
+            Token::Greater => Some(BinaryOperator::Greater),
+            Token::Less => Some(BinaryOperator::Less),
+            Token::GreaterOrEqual => Some(BinaryOperator::GreaterOrEqual),
+            Token::LessOrEqual => Some(BinaryOperator::LessOrEqual),
+            Token::Equal => Some(BinaryOperator::Equal),
+            Token::NotEqual => Some(BinaryOperator::NotEqual),
+
+            Token::Concat => Some(BinaryOperator::StringConcat),
+
+            Token::Spaceship => Some(BinaryOperator::NullSafeEqual),
+            Token::DoubleTilde => Some(BinaryOperator::Like),
+            Token::ExclamationDoubleTilde => Some(BinaryOperator::NotLike),
+            Token::Sharp => Some(BinaryOperator::PgBitwiseXor),
+            Token::LessMinusGreater => Some(BinaryOperator::Distance),
+            Token::Tilde => Some(BinaryOperator::PgRegexMatch),
+            Token::ExclamationTilde => Some(BinaryOperator::PgRegexNotMatch),
+            Token::TildeAsterisk => Some(BinaryOperator::PgRegexIMatch),
+            Token::ExclamationTildeAsterisk => Some(BinaryOperator::PgRegexNotIMatch),
+
+            Token::Ampersand => Some(BinaryOperator::BitwiseAnd),
+            Token::Pipe => Some(BinaryOperator::BitwiseOr),
+            Token::Caret => Some(BinaryOperator::BitwiseXor),
+            Token::LeftShift => Some(BinaryOperator::BitwiseLeftShift),
+            Token::RightShift => Some(BinaryOperator::BitwiseRightShift),
+            _ => None,
+        }
+    }
+
     /// Parses the parens following the `[ NOT ] IN (...)` operator,
     /// assuming the `[NOT] IN` keyword have already been consumed.
     fn parse_in(&mut self, expr: Box<Expr>, negated: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LeftParen)?;
-        let in_op = if self.next_is_query() {
-            // don't consume the `SELECT` or `WITH` keyword.
-            Expr::InSubquery(InSubqueryExpr {
+        let in_op = match self.parse_optional_subquery()? {
+            Some(subquery) => Expr::InSubquery(InSubqueryExpr {
                 expr,
                 negated,
-                subquery: Box::new(self.parse_query_expr(true)?),
-            })
-        } else {
-            Expr::InList(InListExpr {
+                subquery: Box::new(subquery),
+            }),
+            None => Expr::InList(InListExpr {
                 expr,
                 negated,
                 list: self.parse_comma_separated(Parser::parse_expr)?,
-            })
+            }),
         };
         self.expect_token(&Token::RightParen)?;
         Ok(in_op)
@@ -302,9 +551,9 @@ impl<'a, D: Dialect> Parser<'a, D> {
     fn parse_between(&mut self, expr: Box<Expr>, negated: bool) -> Result<Expr, ParserError> {
         // Stop parsing subexpressions for <low> and <high> on tokens with
         // precedence lower than that of `BETWEEN`, such as `AND`, `IS`, etc.
-        let low = self.parse_subexpr(Self::BETWEEN_PREC)?;
+        let low = self.parse_subexpr(self.prec_value(Precedence::Between))?;
         self.expect_keyword(Keyword::AND)?;
-        let high = self.parse_subexpr(Self::BETWEEN_PREC)?;
+        let high = self.parse_subexpr(self.prec_value(Precedence::Between))?;
         Ok(Expr::Between(BetweenExpr {
             expr,
             negated,
@@ -313,9 +562,493 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }))
     }
 
-    fn next_is_query(&mut self) -> bool {
-        self.peek_token()
-            .and_then(|token| token.is_one_of_keywords(&[Keyword::SELECT, Keyword::WITH]))
+    /// Parses the query expression of a subquery if the next tokens start one, assuming the `(`
+    /// of the subquery has already been consumed.
+    ///
+    /// The query may begin with parens and be followed by set operations, e.g.
+    /// `(SELECT a FROM t) UNION (SELECT b FROM u)`.
+    fn parse_optional_subquery(&mut self) -> Result<Option<Query>, ParserError> {
+        if !self.next_is_query() {
+            return Ok(None);
+        }
+        let keywords = [Keyword::SELECT, Keyword::WITH];
+        if self
+            .peek_token()
+            .and_then(|token| token.is_one_of_keywords(&keywords))
             .is_some()
+        {
+            return self.parse_query_expr(false).map(Some);
+        }
+        // The leading parens, `VALUES` or `TABLE` may also begin an expression,
+        // e.g. `((SELECT 1) + 1)` or `((SELECT 1), 2)`, which can only be told apart at the end.
+        Ok(self.parse_optional(|parser| {
+            let query = parser.parse_query_expr(false)?;
+            match parser.peek_token() {
+                Some(Token::RightParen) => Ok(query),
+                found => {
+                    let found = found.cloned();
+                    parser.expected(")", found)
+                }
+            }
+        }))
+    }
+
+    /// Returns true if the next tokens are left parens followed by a keyword that starts a query.
+    fn next_is_query(&mut self) -> bool {
+        let mut n = 0;
+        while self.peek_nth_token(n) == Some(&Token::LeftParen) {
+            n += 1;
+        }
+        let keywords = [
+            Keyword::SELECT,
+            Keyword::VALUES,
+            Keyword::WITH,
+            Keyword::TABLE,
+        ];
+        self.peek_nth_token(n)
+            .is_some_and(|token| token.is_one_of_keywords(&keywords).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Displays the expression with every binary operation parenthesized.
+    fn parenthesize(expr: &Expr) -> String {
+        match expr {
+            Expr::BinaryOp(BinaryOpExpr { left, op, right }) => {
+                format!("({} {} {})", parenthesize(left), op, parenthesize(right))
+            }
+            Expr::UnaryOp(UnaryOpExpr { op, expr }) => format!("({} {})", op, parenthesize(expr)),
+            expr => expr.to_string(),
+        }
+    }
+
+    /// Parses an expression, returns it with the next token that is not consumed.
+    fn parse_partially<D: Dialect>(
+        dialect: &D,
+        sql: &str,
+    ) -> Result<(String, Option<String>), String> {
+        let mut parser = Parser::new_with_sql(dialect, sql).map_err(|err| err.to_string())?;
+        let expr = parser.parse_expr().map_err(|err| err.to_string())?;
+        Ok((
+            expr.to_string(),
+            parser.peek_token().map(ToString::to_string),
+        ))
+    }
+
+    #[test]
+    fn parse_operator_precedence() -> Result<(), ParserError> {
+        macro_rules! assert_precedence {
+            ($dialect:expr, $sql:expr, $expected:expr) => {{
+                let expr = Parser::new_with_sql($dialect, $sql)?.parse_expr()?;
+                assert_eq!(parenthesize(&expr), $expected);
+            }};
+        }
+
+        // https://dev.mysql.com/doc/refman/8.0/en/operator-precedence.html
+        let mysql = crate::mysql::MysqlDialect::default();
+        assert_precedence!(&mysql, "a ^ b * c", "((a ^ b) * c)");
+        assert_precedence!(&mysql, "a * b ^ c", "(a * (b ^ c))");
+        assert_precedence!(&mysql, "a | b & c", "(a | (b & c))");
+        assert_precedence!(&mysql, "a & b << c", "(a & (b << c))");
+        assert_precedence!(&mysql, "a << b + c", "(a << (b + c))");
+        assert_precedence!(&mysql, "a = b | c", "(a = (b | c))");
+        assert_precedence!(&mysql, "a OR b XOR c AND d", "(a OR (b XOR (c AND d)))");
+        assert_precedence!(&mysql, "a = b XOR c", "((a = b) XOR c)");
+        assert_precedence!(&mysql, "! a ^ b", "((! a) ^ b)");
+
+        // https://www.postgresql.org/docs/13/sql-syntax-lexical.html#SQL-PRECEDENCE
+        let postgres = crate::postgres::PostgresDialect::default();
+        assert_precedence!(&postgres, "a * b ^ c", "(a * (b ^ c))");
+        assert_precedence!(&postgres, "a || b + c", "(a || (b + c))");
+        assert_precedence!(&postgres, "a | b & c", "((a | b) & c)");
+        assert_precedence!(&postgres, "a # b << c", "((a # b) << c)");
+        assert_precedence!(&postgres, "a LIKE b || c", "(a LIKE (b || c))");
+        assert_precedence!(&postgres, "a LIKE b = c", "((a LIKE b) = c)");
+        assert_precedence!(&postgres, "a <-> b < c", "((a <-> b) < c)");
+
+        // the default precedence
+        let ansi = crate::ansi::AnsiDialect::default();
+        assert_precedence!(&ansi, "a || b + c", "((a || b) + c)");
+        assert_precedence!(&ansi, "a = b OR c AND d", "((a = b) OR (c AND d))");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_temporal_arithmetic() -> Result<(), ParserError> {
+        let postgres = crate::postgres::PostgresDialect::default();
+        let mysql = crate::mysql::MysqlDialect::default();
+        let ansi = crate::ansi::AnsiDialect::default();
+        let sqls = [
+            "ts BETWEEN now() - INTERVAL '7' DAY AND now()",
+            "ts NOT BETWEEN INTERVAL '1' DAY AND INTERVAL '2' DAY(3) + ts",
+            "ts - INTERVAL '1' HOUR TO MINUTE >= TIMESTAMP '2021-01-01 00:00:00'",
+            "DATE '2021-01-01' + INTERVAL '1' MONTH > TIME '12:00:00'",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&postgres, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+            assert_eq!(
+                Parser::new_with_sql(&mysql, sql)?.parse_expr()?.to_string(),
+                sql
+            );
+            assert_eq!(
+                Parser::new_with_sql(&ansi, sql)?.parse_expr()?.to_string(),
+                sql
+            );
+        }
+
+        let expr = Parser::new_with_sql(&postgres, "ts BETWEEN a - INTERVAL '7' DAY AND b")?
+            .parse_expr()?;
+        match &expr {
+            Expr::Between(BetweenExpr { low, high, .. }) => {
+                assert_eq!(parenthesize(low), "(a - INTERVAL '7' DAY)");
+                assert_eq!(**high, Expr::Identifier(Ident::new("b")));
+            }
+            _ => unreachable!(),
+        }
+
+        // temporal keywords that are not followed by a string are identifiers
+        let expr = Parser::new_with_sql(&postgres, "date = timestamp")?.parse_expr()?;
+        assert_eq!(parenthesize(&expr), "(date = timestamp)");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_operators() -> Result<(), ParserError> {
+        let mysql = crate::mysql::MysqlDialect::default();
+        let postgres = crate::postgres::PostgresDialect::default();
+        let ansi = crate::ansi::AnsiDialect::default();
+
+        let expr = Parser::new_with_sql(&mysql, "a DIV 2 <=> b MOD 3")?.parse_expr()?;
+        assert_eq!(
+            expr,
+            Expr::BinaryOp(BinaryOpExpr {
+                left: Box::new(Expr::BinaryOp(BinaryOpExpr {
+                    left: Box::new(Expr::Identifier(Ident::new("a"))),
+                    op: BinaryOperator::IntegerDivide,
+                    right: Box::new(Expr::Literal(Literal::Number("2".into()))),
+                })),
+                op: BinaryOperator::NullSafeEqual,
+                right: Box::new(Expr::BinaryOp(BinaryOpExpr {
+                    left: Box::new(Expr::Identifier(Ident::new("b"))),
+                    op: BinaryOperator::Modulo,
+                    right: Box::new(Expr::Literal(Literal::Number("3".into()))),
+                })),
+            })
+        );
+        let expr = Parser::new_with_sql(&mysql, "NOT (a AND b)")?.parse_expr()?;
+        let and = Expr::binary(
+            Expr::Identifier(Ident::new("a")),
+            BinaryOperator::And,
+            Expr::Identifier(Ident::new("b")),
+        );
+        assert!(and.is_binary_op(BinaryOperator::And));
+        assert_eq!(expr, Expr::unary(UnaryOperator::Not, Expr::nested(and)));
+        let expr = Parser::new_with_sql(&mysql, "!a")?.parse_expr()?;
+        assert_eq!(expr.to_string(), "! a");
+
+        let expr = Parser::new_with_sql(&postgres, "a ~~ 'x%' AND b !~~ 'y%'")?.parse_expr()?;
+        assert_eq!(expr.to_string(), "a LIKE 'x%' AND b NOT LIKE 'y%'");
+        let expr = Parser::new_with_sql(&postgres, "|/ a # ||/ b")?.parse_expr()?;
+        assert_eq!(expr.to_string(), "|/ a # ||/ b");
+        let expr = Parser::new_with_sql(&postgres, "@-@ a <-> b = 1")?.parse_expr()?;
+        assert_eq!(
+            expr,
+            Expr::BinaryOp(BinaryOpExpr {
+                left: Box::new(Expr::BinaryOp(BinaryOpExpr {
+                    left: Box::new(Expr::UnaryOp(UnaryOpExpr {
+                        op: UnaryOperator::Length,
+                        expr: Box::new(Expr::Identifier(Ident::new("a"))),
+                    })),
+                    op: BinaryOperator::Distance,
+                    right: Box::new(Expr::Identifier(Ident::new("b"))),
+                })),
+                op: BinaryOperator::Equal,
+                right: Box::new(Expr::Literal(Literal::Number("1".into()))),
+            })
+        );
+
+        let expr = Parser::new_with_sql(&postgres, "a ~ 'x' OR b !~ 'y'")?.parse_expr()?;
+        assert_eq!(parenthesize(&expr), "((a ~ 'x') OR (b !~ 'y'))");
+        let expr = Parser::new_with_sql(&postgres, "a ~* 'x' AND b !~* 'y'")?.parse_expr()?;
+        assert_eq!(parenthesize(&expr), "((a ~* 'x') AND (b !~* 'y'))");
+        let expr = Parser::new_with_sql(&postgres, "# a + 1")?.parse_expr()?;
+        assert_eq!(parenthesize(&expr), "((# a) + 1)");
+
+        // the `/` opening a comment is not a part of the `|/` and `||/` operators
+        for (sql, expected) in [("a||/*c*/b", "a || b"), ("a|/*c*/b", "a | b")] {
+            let expected = Ok((expected.into(), None));
+            assert_eq!(parse_partially(&postgres, sql), expected);
+            assert_eq!(parse_partially(&ansi, sql), expected);
+        }
+        let expected = Ok(("a OR b".into(), None));
+        assert_eq!(parse_partially(&mysql, "a||/*c*/b"), expected);
+
+        // `||` is `OR` in MySQL, unless the PIPES_AS_CONCAT SQL mode is enabled
+        let expr = Parser::new_with_sql(&mysql, "a || b AND c")?.parse_expr()?;
+        assert_eq!(parenthesize(&expr), "(a OR (b AND c))");
+        let pipes_as_concat = crate::mysql::MysqlDialect::new(
+            Default::default(),
+            crate::mysql::MysqlParserConfig {
+                pipes_as_concat: true,
+            },
+        );
+        let expr = Parser::new_with_sql(&pipes_as_concat, "a || b ^ c")?.parse_expr()?;
+        assert_eq!(parenthesize(&expr), "((a || b) ^ c)");
+
+        // dialect-specific operators are not accepted by other dialects
+        let infix = |found: &str| Err(format!("Expected: expression infix, found: {}", found));
+        let prefix = |found: &str| Err(format!("Expected: an expression infix, found: {}", found));
+        let rest = |op: &str| Ok(("a".into(), Some(op.into())));
+        assert_eq!(parse_partially(&postgres, "a <=> b"), rest("<=>"));
+        assert_eq!(parse_partially(&postgres, "a MOD b"), rest("MOD"));
+        assert_eq!(parse_partially(&postgres, "!a"), prefix("!"));
+        for (sql, expected) in [
+            ("a ~~ b", rest("~")),
+            ("a # b", rest("#")),
+            ("a !~ b", infix("!")),
+            ("a !~~ b", infix("!")),
+            ("a ~* b", rest("~")),
+            ("a !~* b", infix("!")),
+            ("a <-> b", prefix(">")),
+            ("|/ a", prefix("|")),
+            ("@-@ a", prefix("@")),
+        ] {
+            assert_eq!(parse_partially(&mysql, sql), expected, "{}", sql);
+            assert_eq!(parse_partially(&ansi, sql), expected, "{}", sql);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_array_expr() -> Result<(), ParserError> {
+        let postgres = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&postgres, "ARRAY[1, a]")?.parse_expr()?,
+            Expr::Array(ArrayExpr {
+                elements: vec![
+                    Expr::Literal(Literal::Number("1".into())),
+                    Expr::Identifier(Ident::new("a")),
+                ],
+                named: true,
+            })
+        );
+        let sqls = [
+            "ARRAY[]",
+            "ARRAY[[1, 2], [3, 4]]",
+            "ARRAY[1 + 2, ARRAY[3]]",
+            "ARRAY(SELECT a FROM t)",
+            "'{1,2,3}'::INT[]",
+            "a::INT + 1",
+            "ARRAY[1, 2]::BIGINT[]",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&postgres, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        let expr = Parser::new_with_sql(&postgres, "'{1,2,3}'::INT[]")?.parse_expr()?;
+        assert_eq!(
+            expr,
+            Expr::Cast(CastExpr {
+                expr: Box::new(Expr::Literal(Literal::String("{1,2,3}".into()))),
+                data_type: DataType::Array(Box::new(DataType::Int(None)), None),
+                style: CastStyle::DoubleColon,
+            })
+        );
+
+        // `array` is an identifier if not followed by `[` or `(`
+        let expr = Parser::new_with_sql(&postgres, "array + 1")?.parse_expr()?;
+        assert_eq!(parenthesize(&expr), "(array + 1)");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_composite_field_and_function_cast() -> Result<(), ParserError> {
+        use crate::postgres::{PostgresDialect, PostgresParserConfig};
+
+        let dialect = PostgresDialect::new(
+            Default::default(),
+            PostgresParserConfig {
+                type_names: vec!["int4".into(), "timestamp".into()],
+            },
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "(t).a.b")?.parse_expr()?,
+            Expr::CompositeField(CompositeFieldExpr {
+                expr: Box::new(Expr::CompositeField(CompositeFieldExpr {
+                    expr: Box::new(Expr::nested(Expr::Identifier(Ident::new("t")))),
+                    field: Ident::new("a"),
+                })),
+                field: Ident::new("b"),
+            })
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "INT4(x)")?.parse_expr()?,
+            Expr::Cast(CastExpr {
+                expr: Box::new(Expr::Identifier(Ident::new("x"))),
+                data_type: DataType::Custom(ObjectName::new(vec!["INT4"])),
+                style: CastStyle::Function,
+            })
+        );
+        let sqls = [
+            "(f(x)).field + 1",
+            "(t).*",
+            "int4(x) + 1",
+            "\"timestamp\"('2021-01-01')",
+            "CAST(x AS INT)",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // the type names are only matched if configured, quoted names are case-sensitive
+        let expr = Parser::new_with_sql(&dialect, "\"TIMESTAMP\"(x)")?.parse_expr()?;
+        assert!(matches!(expr, Expr::Function(_)));
+        let postgres = crate::postgres::PostgresDialect::default();
+        let expr = Parser::new_with_sql(&postgres, "int4(x)")?.parse_expr()?;
+        assert!(matches!(expr, Expr::Function(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_subquery_expr() -> Result<(), ParserError> {
+        let postgres = crate::postgres::PostgresDialect::default();
+        let sqls = [
+            "x IN (SELECT a FROM t UNION SELECT b FROM u)",
+            "x NOT IN ((SELECT a FROM t) EXCEPT (SELECT b FROM u))",
+            "x IN (WITH w AS (SELECT 1) SELECT * FROM w)",
+            "EXISTS ((SELECT 1) UNION (SELECT 2))",
+            "((SELECT 1) UNION ALL (SELECT 2) ORDER BY 1)",
+            "(VALUES (1), (2))",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&postgres, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+        }
+        let expr = Parser::new_with_sql(&postgres, "x IN ((SELECT a FROM t) UNION (SELECT 1))")?
+            .parse_expr()?;
+        assert!(matches!(expr, Expr::InSubquery(_)));
+        let expr =
+            Parser::new_with_sql(&postgres, "((SELECT 1) UNION (SELECT 2))")?.parse_expr()?;
+        assert!(matches!(expr, Expr::Subquery(_)));
+
+        // the leading parens may also begin an expression
+        let expr = Parser::new_with_sql(&postgres, "x IN ((SELECT 1), 2)")?.parse_expr()?;
+        match &expr {
+            Expr::InList(InListExpr { list, .. }) => assert_eq!(list.len(), 2),
+            _ => unreachable!(),
+        }
+        let expr = Parser::new_with_sql(&postgres, "((SELECT 1) + 1)")?.parse_expr()?;
+        match &expr {
+            Expr::Nested(expr) => assert_eq!(parenthesize(expr), "((SELECT 1) + 1)"),
+            _ => unreachable!(),
+        }
+        assert!(
+            Parser::new_with_sql(&postgres, "x IN ((SELECT 1) UNION 2)")?
+                .parse_expr()
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_next_value_for() -> Result<(), ParserError> {
+        let mariadb = crate::mariadb::MariadbDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&mariadb, "NEXT VALUE FOR db.s + 1")?.parse_expr()?,
+            Expr::binary(
+                Expr::NextValueFor(ObjectName::new(vec!["db", "s"])),
+                BinaryOperator::Plus,
+                Expr::Literal(Literal::Number("1".into())),
+            )
+        );
+        assert_eq!(
+            Parser::new_with_sql(&mariadb, "next value for s")?
+                .parse_expr()?
+                .to_string(),
+            "NEXT VALUE FOR s"
+        );
+        // `NEXT` is still an identifier
+        assert_eq!(
+            Parser::new_with_sql(&mariadb, "next + 1")?.parse_expr()?,
+            Expr::binary(
+                Expr::Identifier(Ident::new("next")),
+                BinaryOperator::Plus,
+                Expr::Literal(Literal::Number("1".into())),
+            )
+        );
+
+        // the sequences of MySQL are not supported
+        let mysql = crate::mysql::MysqlDialect::default();
+        let expr = Parser::new_with_sql(&mysql, "NEXT VALUE FOR s")?.parse_expr();
+        assert!(!matches!(expr, Ok(Expr::NextValueFor(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_scalar_subquery() -> Result<(), ParserError> {
+        let postgres = crate::postgres::PostgresDialect::default();
+        let expr = Parser::new_with_sql(&postgres, "(SELECT max(x) FROM t) + 1")?.parse_expr()?;
+        match &expr {
+            Expr::BinaryOp(BinaryOpExpr { left, .. }) => {
+                assert!(matches!(**left, Expr::Subquery(_)))
+            }
+            _ => unreachable!(),
+        }
+        let expr = Parser::new_with_sql(&postgres, "f((SELECT 1), 2)")?.parse_expr()?;
+        match &expr {
+            Expr::Function(function) => assert!(matches!(
+                function.args[0],
+                FunctionArg::Unnamed(Expr::Subquery(_))
+            )),
+            _ => unreachable!(),
+        }
+
+        let sqls = [
+            "1 - (SELECT min(x) FROM t) * 2",
+            "- (SELECT 1)",
+            "NOT (SELECT TRUE)",
+            "(SELECT 1) IS NULL",
+            "(SELECT 'a') || 'b' LIKE (SELECT 'a%')",
+            "x BETWEEN (SELECT 1) AND (SELECT 2) + 1",
+            "count(DISTINCT (SELECT 1))",
+            "COALESCE((SELECT a FROM t), 0)",
+            "CAST((SELECT 1) AS INT)",
+            "(SELECT 1)::INT",
+            "CASE (SELECT 1) WHEN 1 THEN (SELECT 2) ELSE (SELECT 3) END",
+            "SUBSTRING((SELECT 'abc') FROM (SELECT 1))",
+            "ARRAY[(SELECT 1), 2]",
+            "ARRAY(WITH w AS (SELECT 1) SELECT * FROM w)",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&postgres, sql)?
+                    .parse_expr()?
+                    .to_string(),
+                sql
+            );
+        }
+        Ok(())
     }
 }
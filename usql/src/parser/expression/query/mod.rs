@@ -1,15 +1,17 @@
+mod output;
 mod table;
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::mem;
 
 use crate::{
     ast::{expression::*, types::*},
-    dialect::Dialect,
-    error::ParserError,
+    dialect::{Capability, Dialect, DialectParserConf},
+    error::{unsupported_capability, ParserError},
     keywords::Keyword,
     parser::Parser,
-    tokens::Token,
+    tokens::{Token, TokenKind},
 };
 
 impl<'a, D: Dialect> Parser<'a, D> {
@@ -20,19 +22,19 @@ impl<'a, D: Dialect> Parser<'a, D> {
     ///     [ <order by clause> ]
     ///     [ <result offset clause> ]
     ///     [ <fetch first clause> | <limit clause> ]
+    ///     [ <query output> ]
     /// ```
     pub fn parse_query_expr(&mut self, skip_with: bool) -> Result<Query, ParserError> {
         let with = if skip_with { None } else { self.parse_with_clause()? };
-        let body = self.parse_query_body(0)?;
+        let body = self.recurse(|parser| parser.parse_query_body(0))?;
         let order_by = self.parse_order_by_clause()?;
 
         let mut offset = None;
         let mut fetch = None;
         let mut limit = None;
         loop {
-            let token = self.peek_token().cloned();
-            match token {
-                Some(token) if token.is_keyword(Keyword::OFFSET) => {
+            match self.peek_kind() {
+                Some(kind) if kind.is_keyword(Keyword::OFFSET) => {
                     offset = if offset.is_none() {
                         self.parse_offset_clause()?
                     } else {
@@ -40,14 +42,14 @@ impl<'a, D: Dialect> Parser<'a, D> {
                             .expected("LIMIT or FETCH clause", Some("Duplicated OFFSET clause"));
                     };
                 }
-                Some(token) if token.is_keyword(Keyword::LIMIT) => {
+                Some(kind) if kind.is_keyword(Keyword::LIMIT) => {
                     limit = if limit.is_none() && fetch.is_none() {
                         self.parse_limit_clause()?
                     } else {
                         return self.expected("OFFSET clause", Some("LIMIT or FETCH clause"));
                     };
                 }
-                Some(token) if token.is_keyword(Keyword::FETCH) => {
+                Some(kind) if kind.is_keyword(Keyword::FETCH) => {
                     fetch = if fetch.is_none() && limit.is_none() {
                         self.parse_fetch_clause()?
                     } else {
@@ -57,6 +59,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
                 _ => break,
             }
         }
+        let output = self.parse_query_output()?;
 
         Ok(Query {
             with,
@@ -65,6 +68,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
             offset,
             fetch,
             limit,
+            output,
         })
     }
 
@@ -88,36 +92,37 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// <explicit table> ::= TABLE <table name>
     /// ```
     fn parse_query_body(&mut self, precedence: u8) -> Result<QueryBody, ParserError> {
-        let mut body = match self.peek_token().cloned() {
-            Some(token) if token.is_keyword(Keyword::SELECT) => {
+        let mut body = match self.peek_kind() {
+            Some(kind) if kind.is_keyword(Keyword::SELECT) => {
                 let select = self.parse_query_spec()?;
                 QueryBody::QuerySpec(Box::new(select))
             }
-            Some(token) if token == Token::LeftParen => {
+            Some(TokenKind::LeftParen) => {
                 // with clause are not allowed here
                 self.next_token(); // consume the `(`
                 let subquery = self.parse_query_expr(true)?;
                 self.expect_token(&Token::RightParen)?;
                 QueryBody::Subquery(Box::new(subquery))
             }
-            Some(token) if token.is_keyword(Keyword::VALUES) => {
+            Some(kind) if kind.is_keyword(Keyword::VALUES) => {
                 let values = self.parse_table_values()?;
                 QueryBody::Values(values)
             }
-            Some(token) if token.is_keyword(Keyword::TABLE) => {
+            Some(kind) if kind.is_keyword(Keyword::TABLE) => {
                 self.next_token(); // consume the keyword `TABLE`
                 let name = self.parse_object_name()?;
                 QueryBody::Table(name)
             }
-            unexpected => {
-                return self.expected("SELECT, Subquery, VALUES or TABLE", unexpected);
+            _ => {
+                let found = self.peek_token().cloned();
+                return self.expected("SELECT, Subquery, VALUES or TABLE", found);
             }
         };
 
         loop {
             // The query can be optionally followed by a set operator
-            let token = self.peek_token().cloned();
-            let op = self.parse_query_body_operator(token);
+            let kind = self.peek_kind();
+            let op = self.parse_query_body_operator(kind);
             let next_precedence = match op {
                 // UNION and EXCEPT have the same binding power and evaluate left-to-right
                 Some(QueryBodyOperator::Union) | Some(QueryBodyOperator::Except) => 10,
@@ -141,13 +146,11 @@ impl<'a, D: Dialect> Parser<'a, D> {
         Ok(body)
     }
 
-    fn parse_query_body_operator(&mut self, token: Option<Token>) -> Option<QueryBodyOperator> {
-        match token {
-            Some(token) if token.is_keyword(Keyword::UNION) => Some(QueryBodyOperator::Union),
-            Some(token) if token.is_keyword(Keyword::EXCEPT) => Some(QueryBodyOperator::Except),
-            Some(token) if token.is_keyword(Keyword::INTERSECT) => {
-                Some(QueryBodyOperator::Intersect)
-            }
+    fn parse_query_body_operator(&mut self, kind: Option<TokenKind>) -> Option<QueryBodyOperator> {
+        match kind {
+            Some(kind) if kind.is_keyword(Keyword::UNION) => Some(QueryBodyOperator::Union),
+            Some(kind) if kind.is_keyword(Keyword::EXCEPT) => Some(QueryBodyOperator::Except),
+            Some(kind) if kind.is_keyword(Keyword::INTERSECT) => Some(QueryBodyOperator::Intersect),
             _ => None,
         }
     }
@@ -159,6 +162,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
     ///
     /// <table expression> ::= <from clause>
     ///     [ <where clause> ]
+    ///     [ <hierarchical query clause> ]
     ///     [ <group by clause> ]
     ///     [ <having clause> ]
     ///     [ <window clause> ]
@@ -170,7 +174,14 @@ impl<'a, D: Dialect> Parser<'a, D> {
 
         // table expression
         let from = self.parse_from_clause()?;
+        if from.is_none() && !self.dialect.parser_conf().supports_select_without_from() {
+            return match self.peek_kind() {
+                Some(_) => unsupported_capability(Capability::SelectWithoutFrom),
+                None => self.expected("FROM clause", Option::<Token>::None),
+            };
+        }
         let r#where = self.parse_where_clause()?;
+        let connect_by = self.parse_connect_by_clause()?;
         let group_by = self.parse_group_by_clause()?;
         let having = self.parse_having_clause()?;
         let window = self.parse_window_clause()?;
@@ -180,6 +191,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
             projection,
             from,
             r#where,
+            connect_by,
             group_by,
             having,
             window,
@@ -217,16 +229,12 @@ impl<'a, D: Dialect> Parser<'a, D> {
     pub fn parse_select_item(&mut self) -> Result<SelectItem, ParserError> {
         match self.parse_expr()? {
             Expr::Wildcard => Ok(SelectItem::Wildcard),
-            Expr::QualifiedWildcard(prefix) => {
-                let name = ObjectName(prefix);
+            Expr::QualifiedWildcard(ref mut prefix) => {
+                let name = ObjectName(mem::take(prefix));
                 Ok(SelectItem::QualifiedWildcard(name))
             }
             expr => {
-                let alias = if self.parse_keyword(Keyword::AS) {
-                    Some(self.parse_identifier()?)
-                } else {
-                    None
-                };
+                let alias = self.parse_optional_alias()?;
                 Ok(SelectItem::DerivedColumn {
                     expr: Box::new(expr),
                     alias,
@@ -289,20 +297,43 @@ impl<'a, D: Dialect> Parser<'a, D> {
     ///
     /// ```txt
     /// <with list element> ::= <query name> [ ( <column name> [, ...] ) ] AS ( <query expression> )
+    ///
+    /// // PostgreSQL
+    /// <with list element> ::= <query name> [ ( <column name> [, ...] ) ]
+    ///     AS ( { <query expression> | <insert statement> | <update statement> | <delete statement> } )
     /// ```
     pub fn parse_cte(&mut self) -> Result<Cte, ParserError> {
         // `<name> [ col1 [, ...] ]`
         let name = self.parse_identifier()?;
         let columns = self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?;
-        // `AS ( <no-with-clause query> )`
+        // `AS ( <no-with-clause query> | <data-modifying statement> )`
         self.expect_keyword(Keyword::AS)?;
         self.expect_token(&Token::LeftParen)?;
-        let query = Box::new(self.parse_query_expr(true)?);
+        let body = self.parse_cte_body()?;
         self.expect_token(&Token::RightParen)?;
         Ok(Cte {
             name,
             columns,
-            query,
+            body,
+        })
+    }
+
+    fn parse_cte_body(&mut self) -> Result<CteBody, ParserError> {
+        let keyword = match self.peek_token() {
+            Some(Token::Word(w)) => w.keyword,
+            _ => None,
+        };
+        if !self.dialect.parser_conf().supports_data_modifying_cte() {
+            if let Some(Keyword::INSERT | Keyword::UPDATE | Keyword::DELETE) = keyword {
+                return unsupported_capability(Capability::DataModifyingCte);
+            }
+            return Ok(CteBody::Query(Box::new(self.parse_query_expr(true)?)));
+        }
+        Ok(match keyword {
+            Some(Keyword::INSERT) => CteBody::Insert(Box::new(self.parse_insert_stmt()?)),
+            Some(Keyword::UPDATE) => CteBody::Update(Box::new(self.parse_update_stmt()?)),
+            Some(Keyword::DELETE) => CteBody::Delete(Box::new(self.parse_delete_stmt()?)),
+            _ => CteBody::Query(Box::new(self.parse_query_expr(true)?)),
         })
     }
 
@@ -314,29 +345,69 @@ impl<'a, D: Dialect> Parser<'a, D> {
     ///
     /// ```txt
     /// <order by clause> ::= ORDER BY <sort specification> [, ...]
+    ///
+    /// // DuckDB-style
+    /// <order by clause> ::= ORDER BY ALL [ ASC | DESC ] [ NULLS FIRST | NULLS LAST ]
     /// ```
     pub fn parse_order_by_clause(&mut self) -> Result<Option<OrderBy>, ParserError> {
-        if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
-            let list = self.parse_comma_separated(Self::parse_sort_spec)?;
-            Ok(Some(OrderBy { list }))
-        } else {
-            Ok(None)
+        if !self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+            return Ok(None);
         }
+        if self.parse_keyword(Keyword::ALL) {
+            if !self.dialect.parser_conf().supports_group_by_all() {
+                return unsupported_capability(Capability::GroupByAll);
+            }
+            let asc = if self.parse_keyword(Keyword::ASC) {
+                Some(true)
+            } else if self.parse_keyword(Keyword::DESC) {
+                Some(false)
+            } else {
+                None
+            };
+            let nulls_first = if self.parse_keywords(&[Keyword::NULLS, Keyword::FIRST]) {
+                Some(true)
+            } else if self.parse_keywords(&[Keyword::NULLS, Keyword::LAST]) {
+                Some(false)
+            } else {
+                None
+            };
+            return Ok(Some(OrderBy {
+                list: vec![],
+                all: Some(OrderByAll { asc, nulls_first }),
+            }));
+        }
+        let list = self.parse_comma_separated(Self::parse_sort_spec)?;
+        Ok(Some(OrderBy { list, all: None }))
     }
 
     /// Parses a sort specification.
     ///
     /// ```txt
     /// <sort specification> ::= <sort key> [ ASC | DESC ] [ NULLS FIRST | NULLS LAST ]
+    ///
+    /// // PostgreSQL
+    /// <sort specification> ::= <sort key> [ ASC | DESC | USING <operator> ] [ NULLS FIRST | NULLS LAST ]
     /// ```
     pub fn parse_sort_spec(&mut self) -> Result<SortSpec, ParserError> {
         let expr = self.parse_expr()?;
+        // the positions are 1-based, so `ORDER BY 0` is just a constant
+        let ordinal = match &expr {
+            Expr::Literal(Literal::Number(n)) => n.parse::<u64>().ok().filter(|n| *n > 0),
+            _ => None,
+        };
 
+        let mut using = None;
         let asc = if self.parse_keyword(Keyword::ASC) {
             Some(true)
         } else if self.parse_keyword(Keyword::DESC) {
             Some(false)
         } else {
+            if self.parse_keyword(Keyword::USING) {
+                if !self.dialect.parser_conf().supports_postgres_operators() {
+                    return unsupported_capability(Capability::PostgresOperators);
+                }
+                using = Some(self.parse_sort_operator()?);
+            }
             None
         };
 
@@ -350,11 +421,23 @@ impl<'a, D: Dialect> Parser<'a, D> {
 
         Ok(SortSpec {
             expr: Box::new(expr),
+            ordinal,
             asc,
+            using,
             nulls_first,
         })
     }
 
+    /// Parses the ordering operator of `USING <operator>`, which can be any operator, and whether
+    /// it's an ordering operator is checked when the query is executed.
+    fn parse_sort_operator(&mut self) -> Result<BinaryOperator, ParserError> {
+        let token = self.next_token();
+        match token.as_ref().and_then(Self::binary_operator) {
+            Some(op) => Ok(op),
+            None => self.expected("an operator after USING", token),
+        }
+    }
+
     // ========================================================================
     // result offset clause
     // ========================================================================
@@ -465,15 +548,27 @@ mod tests {
     fn parse_query_body() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
         assert_eq!(
-            Parser::new_with_sql(&dialect, "SELECT 1")?.parse_query_body(0)?,
+            Parser::new_with_sql(&dialect, "SELECT 1 FROM t")?.parse_query_body(0)?,
             QueryBody::QuerySpec(Box::new(QuerySpec {
                 quantifier: None,
                 projection: vec![SelectItem::DerivedColumn {
                     expr: Box::new(Expr::Literal(Literal::Number("1".into()))),
                     alias: None,
                 }],
-                from: None,
+                from: Some(From {
+                    list: vec![TableReference {
+                        relation: TableFactor::Table {
+                            name: ObjectName::new(vec!["t"]),
+                            partitions: None,
+                            system_time: None,
+                            alias: None,
+                            index_hints: vec![],
+                        },
+                        joins: vec![],
+                    }],
+                }),
                 r#where: None,
+                connect_by: None,
                 group_by: None,
                 having: None,
                 window: None
@@ -510,6 +605,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_select_without_from() -> Result<(), ParserError> {
+        fn parse<D: Dialect>(dialect: &D, sql: &str) -> Result<Query, ParserError> {
+            Parser::new_with_sql(dialect, sql)?.parse_query_expr(false)
+        }
+
+        let sql = "SELECT 1 + 1 WHERE TRUE";
+        let ansi = crate::ansi::AnsiDialect::default();
+        assert_eq!(
+            parse(&ansi, sql).unwrap_err(),
+            ParserError::UnsupportedFeature {
+                feature: "SELECT without FROM".into(),
+                capability: Some(Capability::SelectWithoutFrom)
+            }
+        );
+        assert!(parse(&ansi, "SELECT 1").is_err());
+        assert!(parse(&ansi, "SELECT 1 FROM t").is_ok());
+
+        let mysql = crate::mysql::MysqlDialect::default();
+        let postgres = crate::postgres::PostgresDialect::default();
+        let sqlite = crate::sqlite::SqliteDialect::default();
+        for query in [
+            parse(&mysql, sql)?,
+            parse(&postgres, sql)?,
+            parse(&sqlite, sql)?,
+        ] {
+            assert_eq!(query.to_string(), sql);
+        }
+        Ok(())
+    }
+
     #[test]
     fn parse_with() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -531,12 +657,16 @@ mod tests {
                     list: vec![TableReference {
                         relation: TableFactor::Table {
                             name: ObjectName::new(vec!["table1"]),
+                            partitions: None,
+                            system_time: None,
                             alias: None,
+                            index_hints: vec![],
                         },
                         joins: vec![],
                     }],
                 }),
                 r#where: None,
+                connect_by: None,
                 group_by: None,
                 having: None,
                 window: None,
@@ -545,6 +675,7 @@ mod tests {
             limit: None,
             offset: None,
             fetch: None,
+            output: None,
         });
         let sql = "x AS (SELECT id1, id2 FROM table1)";
         assert_eq!(
@@ -552,7 +683,7 @@ mod tests {
             Cte {
                 name: Ident::new("x"),
                 columns: None,
-                query: query.clone(),
+                body: CteBody::Query(query.clone()),
             },
         );
         let sql = "WITH RECURSIVE x AS (SELECT id1, id2 FROM table1), y (col1, col2) AS (SELECT id1, id2 FROM table1)";
@@ -564,12 +695,12 @@ mod tests {
                     Cte {
                         name: Ident::new("x"),
                         columns: None,
-                        query: query.clone(),
+                        body: CteBody::Query(query.clone()),
                     },
                     Cte {
                         name: Ident::new("y"),
                         columns: Some(vec![Ident::new("col1"), Ident::new("col2")]),
-                        query,
+                        body: CteBody::Query(query),
                     },
                 ]
             })
@@ -577,6 +708,111 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_data_modifying_cte() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "moved AS (DELETE FROM a RETURNING *)";
+        let cte = Parser::new_with_sql(&dialect, sql)?.parse_cte()?;
+        assert!(matches!(cte.body, CteBody::Delete(_)));
+        assert_eq!(cte.to_string(), sql);
+
+        let sqls = [
+            "WITH moved AS (DELETE FROM a RETURNING *) INSERT INTO b SELECT * FROM moved",
+            "WITH i AS (INSERT INTO t (a) VALUES (1) RETURNING id), u AS (UPDATE t2 SET a = 1) SELECT * FROM i",
+            "WITH d AS (DELETE FROM t WHERE a = 1) SELECT 1",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // data-modifying statements in WITH are only supported by PostgreSQL
+        let dialect = crate::sqlite::SqliteDialect::default();
+        assert!(Parser::new_with_sql(&dialect, sql)?.parse_cte().is_err());
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "moved AS (DELETE FROM a)")?.parse_cte(),
+            unsupported_capability(Capability::DataModifyingCte)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_select_item() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "s.t.*")?.parse_select_item()?,
+            SelectItem::QualifiedWildcard(ObjectName::new(vec!["s", "t"]))
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "(t).*")?.parse_select_item()?,
+            SelectItem::DerivedColumn {
+                expr: Box::new(Expr::CompositeWildcard(Box::new(Expr::Nested(Box::new(
+                    Expr::Identifier(Ident::new("t"))
+                ))))),
+                alias: None,
+            }
+        );
+        let item = Parser::new_with_sql(&dialect, "COUNT(t.*) AS c")?.parse_select_item()?;
+        assert_eq!(item.to_string(), "COUNT(t.*) AS c");
+        assert!(Parser::new_with_sql(&dialect, "t.+")?
+            .parse_select_item()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_implicit_alias() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let cases = [
+            (
+                "SELECT a b, c + 1 total FROM t1 x JOIN t2 y ON x.id = y.id",
+                "SELECT a AS b, c + 1 AS total FROM t1 AS x INNER JOIN t2 AS y ON x.id = y.id",
+            ),
+            // `year` is a non-reserved keyword
+            ("SELECT a year FROM t", "SELECT a AS year FROM t"),
+            (
+                "SELECT a FROM t1 LEFT JOIN t2 USING (id)",
+                "SELECT a FROM t1 LEFT JOIN t2 USING (id)",
+            ),
+            (
+                "SELECT a FROM t WHERE a = 1 LIMIT 1",
+                "SELECT a FROM t WHERE a = 1 LIMIT 1",
+            ),
+            (
+                "SELECT a, b FROM t UNION SELECT 1, 2",
+                "SELECT a, b FROM t UNION SELECT 1, 2",
+            ),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_query_expr(false)?
+                    .to_string(),
+                expected
+            );
+        }
+        assert!(Parser::new_with_sql(&dialect, "SELECT a * FROM t")?
+            .parse_query_expr(false)
+            .is_err());
+
+        // `OFFSET` is a non-reserved keyword of MySQL, but can't be used as an implicit alias
+        let dialect = crate::mysql::MysqlDialect::default();
+        assert!(Parser::new_with_sql(&dialect, "SELECT a offset FROM t")?
+            .parse_query_expr(false)
+            .is_err());
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "SELECT a `offset` FROM t")?
+                .parse_query_expr(false)?
+                .to_string(),
+            "SELECT a AS `offset` FROM t"
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_order_by() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -585,9 +821,12 @@ mod tests {
             Some(OrderBy {
                 list: vec![SortSpec {
                     expr: Box::new(Expr::Identifier(Ident::new("id1"))),
+                    ordinal: None,
                     asc: None,
+                    using: None,
                     nulls_first: None,
-                }]
+                }],
+                all: None
             })
         );
         assert_eq!(
@@ -596,9 +835,12 @@ mod tests {
             Some(OrderBy {
                 list: vec![SortSpec {
                     expr: Box::new(Expr::Identifier(Ident::new("id1"))),
+                    ordinal: None,
                     asc: Some(false),
+                    using: None,
                     nulls_first: Some(false),
-                }]
+                }],
+                all: None
             })
         );
         assert_eq!(
@@ -608,17 +850,166 @@ mod tests {
                 list: vec![
                     SortSpec {
                         expr: Box::new(Expr::Identifier(Ident::new("id1"))),
+                        ordinal: None,
                         asc: Some(false),
+                        using: None,
                         nulls_first: Some(false),
                     },
                     SortSpec {
                         expr: Box::new(Expr::Identifier(Ident::new("id2"))),
+                        ordinal: None,
                         asc: Some(true),
+                        using: None,
                         nulls_first: None,
                     }
-                ]
+                ],
+                all: None
             })
         );
+
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ORDER BY name COLLATE \"de_DE\" DESC")?
+                .parse_order_by_clause()?,
+            Some(OrderBy {
+                list: vec![SortSpec {
+                    expr: Box::new(Expr::Collate(CollateExpr {
+                        expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                        collation: ObjectName(vec![Ident::with_quote('"', "de_DE")]),
+                    })),
+                    ordinal: None,
+                    asc: Some(false),
+                    using: None,
+                    nulls_first: None,
+                }],
+                all: None
+            })
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ORDER BY 2, x USING > NULLS FIRST")?
+                .parse_order_by_clause()?,
+            Some(OrderBy {
+                list: vec![
+                    SortSpec {
+                        expr: Box::new(Expr::Literal(Literal::Number("2".into()))),
+                        ordinal: Some(2),
+                        asc: None,
+                        using: None,
+                        nulls_first: None,
+                    },
+                    SortSpec {
+                        expr: Box::new(Expr::Identifier(Ident::new("x"))),
+                        ordinal: None,
+                        asc: None,
+                        using: Some(BinaryOperator::Greater),
+                        nulls_first: Some(true),
+                    }
+                ],
+                all: None
+            })
+        );
+        for (sql, op) in [
+            ("ORDER BY x USING +", BinaryOperator::Plus),
+            ("ORDER BY x USING ~", BinaryOperator::PgRegexMatch),
+        ] {
+            let order_by = Parser::new_with_sql(&dialect, sql)?.parse_order_by_clause()?;
+            assert_eq!(order_by.unwrap().list[0].using, Some(op));
+        }
+        assert!(Parser::new_with_sql(&dialect, "ORDER BY x USING AND")?
+            .parse_order_by_clause()
+            .is_err());
+        // the positions are 1-based
+        let order_by = Parser::new_with_sql(&dialect, "ORDER BY 0")?.parse_order_by_clause()?;
+        assert_eq!(order_by.unwrap().list[0].ordinal, None);
+
+        // the USING operator is only supported by PostgreSQL
+        let dialect = crate::mysql::MysqlDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ORDER BY x USING <")?.parse_order_by_clause(),
+            unsupported_capability(Capability::PostgresOperators)
+        );
+        let dialect = crate::postgres::PostgresDialect::default();
+
+        // COLLATE binds tighter than the concatenation and comparison operators.
+        let expr = Parser::new_with_sql(&dialect, "a || b COLLATE \"C\" = c")?.parse_expr()?;
+        assert_eq!(expr.to_string(), "a || b COLLATE \"C\" = c");
+        match &expr {
+            Expr::BinaryOp(BinaryOpExpr { left, .. }) => match &**left {
+                Expr::BinaryOp(BinaryOpExpr { right, .. }) => {
+                    assert!(matches!(**right, Expr::Collate(_)))
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_group_by_all_and_order_by_all() -> Result<(), ParserError> {
+        use crate::{
+            ansi::{AnsiKeyword, AnsiLexerConfig},
+            dialect::CustomDialect,
+        };
+
+        #[derive(Clone, Debug, Default)]
+        struct GroupByAllConfig;
+
+        impl DialectParserConf for GroupByAllConfig {
+            fn supports_group_by_all(&self) -> bool {
+                true
+            }
+        }
+
+        let dialect = CustomDialect::<AnsiKeyword, AnsiLexerConfig, GroupByAllConfig>::default();
+        let query = Parser::new_with_sql(
+            &dialect,
+            "SELECT a, sum(b) FROM t GROUP BY ALL ORDER BY ALL DESC NULLS LAST",
+        )?
+        .parse_query_expr(false)?;
+        match &query.body {
+            QueryBody::QuerySpec(spec) => assert_eq!(
+                spec.group_by,
+                Some(GroupBy {
+                    quantifier: None,
+                    list: vec![GroupingElement::All],
+                })
+            ),
+            _ => unreachable!(),
+        }
+        assert_eq!(
+            query.order_by,
+            Some(OrderBy {
+                list: vec![],
+                all: Some(OrderByAll {
+                    asc: Some(false),
+                    nulls_first: Some(false),
+                }),
+            })
+        );
+        assert_eq!(
+            query.to_string(),
+            "SELECT a, sum(b) FROM t GROUP BY ALL ORDER BY ALL DESC NULLS LAST"
+        );
+
+        // `ALL` followed by a grouping element is the set quantifier
+        let group_by =
+            Parser::new_with_sql(&dialect, "GROUP BY ALL a, b")?.parse_group_by_clause()?;
+        assert_eq!(
+            group_by.map(|g| g.quantifier),
+            Some(Some(SetQuantifier::All))
+        );
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        for sql in ["GROUP BY ALL", "ORDER BY ALL"] {
+            let mut parser = Parser::new_with_sql(&dialect, sql)?;
+            let result = if sql.starts_with("GROUP") {
+                parser.parse_group_by_clause().map(|_| ())
+            } else {
+                parser.parse_order_by_clause().map(|_| ())
+            };
+            assert_eq!(result, unsupported_capability(Capability::GroupByAll));
+        }
         Ok(())
     }
 
@@ -0,0 +1,342 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec};
+
+use crate::{
+    ast::expression::*,
+    dialect::{Capability, Dialect, DialectParserConf},
+    error::{unsupported_capability, ParserError},
+    keywords::Keyword,
+    parser::Parser,
+    tokens::Token,
+};
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses the output clause which follows the other clauses of a query.
+    ///
+    /// ```txt
+    /// <query output> ::= <into outfile clause> | <into dumpfile clause> | <for clause>
+    /// ```
+    ///
+    /// **NOTE**: `INTO OUTFILE` and `INTO DUMPFILE` are supported only at the end of the query,
+    /// not before the `FROM` clause.
+    pub fn parse_query_output(&mut self) -> Result<Option<QueryOutput>, ParserError> {
+        if self.is_next_keyword_and_word(Keyword::INTO, &["OUTFILE", "DUMPFILE"]) {
+            if !self.dialect.parser_conf().supports_into_outfile() {
+                return unsupported_capability(Capability::IntoOutfile);
+            }
+            self.next_token(); // consume `INTO`
+            if self.parse_word("DUMPFILE") {
+                let file = self.parse_literal_string("file name")?;
+                return Ok(Some(QueryOutput::Dumpfile(file)));
+            }
+            self.expect_word("OUTFILE")?;
+            return Ok(Some(QueryOutput::Outfile(self.parse_into_outfile()?)));
+        }
+        if self.is_next_keyword_and_word(Keyword::FOR, &["XML", "JSON"]) {
+            if !self.dialect.parser_conf().supports_for_xml_json() {
+                return unsupported_capability(Capability::ForXmlJson);
+            }
+            self.next_token(); // consume `FOR`
+            return if self.parse_word("XML") {
+                self.parse_for_xml()
+                    .map(|xml| Some(QueryOutput::ForXml(xml)))
+            } else {
+                self.expect_word("JSON")?;
+                self.parse_for_json()
+                    .map(|json| Some(QueryOutput::ForJson(json)))
+            };
+        }
+        Ok(None)
+    }
+
+    /// Checks if the next tokens are the keyword and one of the unquoted words.
+    fn is_next_keyword_and_word(&mut self, keyword: Keyword, words: &[&str]) -> bool {
+        matches!(self.peek_token(), Some(token) if token.is_keyword(keyword))
+            && matches!(self.peek_nth_token(1), Some(Token::Word(w))
+                if w.quote.is_none() && words.iter().any(|word| w.value.eq_ignore_ascii_case(word)))
+    }
+
+    /// Parses the `INTO OUTFILE` clause after the `INTO OUTFILE` keywords.
+    ///
+    /// ```txt
+    /// <into outfile clause> ::= INTO OUTFILE '<file name>'
+    ///     [ CHARACTER SET <charset name> ]
+    ///     [ { FIELDS | COLUMNS } <fields option> [ ... ] ]
+    ///     [ LINES <lines option> [ ... ] ]
+    /// ```
+    fn parse_into_outfile(&mut self) -> Result<IntoOutfile, ParserError> {
+        let file = self.parse_literal_string("file name")?;
+        let charset = if self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+            || self.parse_keyword(Keyword::CHARSET)
+        {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+
+        let fields = if self.parse_word("FIELDS") || self.parse_word("COLUMNS") {
+            let mut fields = FieldsFormat::default();
+            loop {
+                if self.parse_word("TERMINATED") {
+                    self.expect_keyword(Keyword::BY)?;
+                    fields.terminated_by = Some(self.parse_literal_string("string literal")?);
+                } else if self.parse_word("OPTIONALLY") {
+                    self.expect_word("ENCLOSED")?;
+                    self.expect_keyword(Keyword::BY)?;
+                    fields.enclosed_by = Some(self.parse_literal_string("string literal")?);
+                    fields.optionally_enclosed = true;
+                } else if self.parse_word("ENCLOSED") {
+                    self.expect_keyword(Keyword::BY)?;
+                    fields.enclosed_by = Some(self.parse_literal_string("string literal")?);
+                } else if self.parse_word("ESCAPED") {
+                    self.expect_keyword(Keyword::BY)?;
+                    fields.escaped_by = Some(self.parse_literal_string("string literal")?);
+                } else if fields == FieldsFormat::default() {
+                    let found = self.peek_token().cloned();
+                    return self.expected("TERMINATED, ENCLOSED or ESCAPED", found);
+                } else {
+                    break;
+                }
+            }
+            Some(fields)
+        } else {
+            None
+        };
+
+        let lines = if self.parse_word("LINES") {
+            let mut lines = LinesFormat::default();
+            loop {
+                if self.parse_word("STARTING") {
+                    self.expect_keyword(Keyword::BY)?;
+                    lines.starting_by = Some(self.parse_literal_string("string literal")?);
+                } else if self.parse_word("TERMINATED") {
+                    self.expect_keyword(Keyword::BY)?;
+                    lines.terminated_by = Some(self.parse_literal_string("string literal")?);
+                } else if lines == LinesFormat::default() {
+                    let found = self.peek_token().cloned();
+                    return self.expected("STARTING or TERMINATED", found);
+                } else {
+                    break;
+                }
+            }
+            Some(lines)
+        } else {
+            None
+        };
+
+        Ok(IntoOutfile {
+            file,
+            charset,
+            fields,
+            lines,
+        })
+    }
+
+    /// Parses the `FOR XML` clause after the `FOR XML` keywords.
+    ///
+    /// ```txt
+    /// <for xml clause> ::= FOR XML <for xml mode> [ , <for option> [ ... ] ]
+    /// <for xml mode> ::= RAW [ ( '<element name>' ) ] | AUTO | EXPLICIT | PATH [ ( '<element name>' ) ]
+    /// <for option> ::= BINARY BASE64 | TYPE | ROOT [ ( '<root name>' ) ] | ELEMENTS [ XSINIL | ABSENT ]
+    /// ```
+    fn parse_for_xml(&mut self) -> Result<ForXml, ParserError> {
+        let mode = if self.parse_word("RAW") {
+            ForXmlMode::Raw(self.parse_for_name()?)
+        } else if self.parse_word("AUTO") {
+            ForXmlMode::Auto
+        } else if self.parse_word("EXPLICIT") {
+            ForXmlMode::Explicit
+        } else if self.parse_word("PATH") {
+            ForXmlMode::Path(self.parse_for_name()?)
+        } else {
+            let found = self.peek_token().cloned();
+            return self.expected("RAW, AUTO, EXPLICIT or PATH", found);
+        };
+
+        let mut options = vec![];
+        while self.next_token_if_is(&Token::Comma) {
+            let option = if self.parse_word("BINARY") {
+                self.expect_word("BASE64")?;
+                ForOption::BinaryBase64
+            } else if self.parse_word("TYPE") {
+                ForOption::Type
+            } else if self.parse_word("ROOT") {
+                ForOption::Root(self.parse_for_name()?)
+            } else if self.parse_word("ELEMENTS") {
+                let xsinil = self.parse_word("XSINIL");
+                if !xsinil {
+                    self.parse_word("ABSENT");
+                }
+                ForOption::Elements(xsinil)
+            } else {
+                let found = self.peek_token().cloned();
+                return self.expected("BINARY BASE64, TYPE, ROOT or ELEMENTS", found);
+            };
+            options.push(option);
+        }
+        Ok(ForXml { mode, options })
+    }
+
+    /// Parses the `FOR JSON` clause after the `FOR JSON` keywords.
+    ///
+    /// ```txt
+    /// <for json clause> ::= FOR JSON { AUTO | PATH } [ , <for option> [ ... ] ]
+    /// <for option> ::= ROOT [ ( '<root name>' ) ] | INCLUDE_NULL_VALUES | WITHOUT_ARRAY_WRAPPER
+    /// ```
+    fn parse_for_json(&mut self) -> Result<ForJson, ParserError> {
+        let path = if self.parse_word("PATH") {
+            true
+        } else if self.parse_word("AUTO") {
+            false
+        } else {
+            let found = self.peek_token().cloned();
+            return self.expected("AUTO or PATH", found);
+        };
+
+        let mut options = vec![];
+        while self.next_token_if_is(&Token::Comma) {
+            let option = if self.parse_word("ROOT") {
+                ForOption::Root(self.parse_for_name()?)
+            } else if self.parse_word("INCLUDE_NULL_VALUES") {
+                ForOption::IncludeNullValues
+            } else if self.parse_word("WITHOUT_ARRAY_WRAPPER") {
+                ForOption::WithoutArrayWrapper
+            } else {
+                let found = self.peek_token().cloned();
+                return self.expected("ROOT, INCLUDE_NULL_VALUES or WITHOUT_ARRAY_WRAPPER", found);
+            };
+            options.push(option);
+        }
+        Ok(ForJson { path, options })
+    }
+
+    /// Parses the optional element or root name, like `('row')`.
+    fn parse_for_name(&mut self) -> Result<Option<String>, ParserError> {
+        if self.next_token_if_is(&Token::LeftParen) {
+            let name = self.parse_literal_string("string literal")?;
+            self.expect_token(&Token::RightParen)?;
+            Ok(Some(name))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ansi::{AnsiKeyword, AnsiLexerConfig},
+        ast::types::Ident,
+        dialect::CustomDialect,
+        mysql::MysqlDialect,
+    };
+
+    #[test]
+    fn parse_into_outfile() -> Result<(), ParserError> {
+        let dialect = MysqlDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(
+                &dialect,
+                "INTO OUTFILE '/tmp/t.csv' CHARACTER SET utf8mb4 \
+                 COLUMNS ESCAPED BY '#' OPTIONALLY ENCLOSED BY '\"' TERMINATED BY ',' \
+                 LINES TERMINATED BY ';'"
+            )?
+            .parse_query_output()?,
+            Some(QueryOutput::Outfile(IntoOutfile {
+                file: "/tmp/t.csv".into(),
+                charset: Some(Ident::new("utf8mb4")),
+                fields: Some(FieldsFormat {
+                    terminated_by: Some(",".into()),
+                    enclosed_by: Some("\"".into()),
+                    optionally_enclosed: true,
+                    escaped_by: Some("#".into()),
+                }),
+                lines: Some(LinesFormat {
+                    starting_by: None,
+                    terminated_by: Some(";".into()),
+                }),
+            }))
+        );
+
+        let sql = "SELECT a, b FROM t WHERE a > 1 ORDER BY b LIMIT 10 \
+                   INTO OUTFILE '/tmp/t.csv' FIELDS TERMINATED BY ',' LINES STARTING BY 'x'";
+        let query = Parser::new_with_sql(&dialect, sql)?.parse_query_expr(false)?;
+        assert_eq!(query.to_string(), sql);
+        let sql = "SELECT a FROM t INTO DUMPFILE '/tmp/t.bin'";
+        let query = Parser::new_with_sql(&dialect, sql)?.parse_query_expr(false)?;
+        assert_eq!(
+            query.output,
+            Some(QueryOutput::Dumpfile("/tmp/t.bin".into()))
+        );
+
+        assert!(Parser::new_with_sql(&dialect, "INTO OUTFILE 'a' FIELDS")?
+            .parse_query_output()
+            .is_err());
+        let dialect = crate::ansi::AnsiDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "INTO OUTFILE 'a'")?.parse_query_output(),
+            unsupported_capability(Capability::IntoOutfile)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_for_xml_json() -> Result<(), ParserError> {
+        #[derive(Clone, Debug, Default)]
+        struct ForXmlJsonConfig;
+
+        impl DialectParserConf for ForXmlJsonConfig {
+            fn supports_for_xml_json(&self) -> bool {
+                true
+            }
+        }
+
+        let dialect = CustomDialect::<AnsiKeyword, AnsiLexerConfig, ForXmlJsonConfig>::default();
+        assert_eq!(
+            Parser::new_with_sql(
+                &dialect,
+                "FOR XML PATH('row'), ROOT('rows'), ELEMENTS XSINIL"
+            )?
+            .parse_query_output()?,
+            Some(QueryOutput::ForXml(ForXml {
+                mode: ForXmlMode::Path(Some("row".into())),
+                options: vec![
+                    ForOption::Root(Some("rows".into())),
+                    ForOption::Elements(true)
+                ],
+            }))
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "FOR JSON AUTO, INCLUDE_NULL_VALUES")?
+                .parse_query_output()?,
+            Some(QueryOutput::ForJson(ForJson {
+                path: false,
+                options: vec![ForOption::IncludeNullValues],
+            }))
+        );
+
+        for sql in [
+            "SELECT a FROM t FOR XML RAW, BINARY BASE64, TYPE",
+            "SELECT a FROM t ORDER BY a FOR XML EXPLICIT",
+            "SELECT a FROM t FOR JSON PATH, ROOT, WITHOUT_ARRAY_WRAPPER",
+        ] {
+            let query = Parser::new_with_sql(&dialect, sql)?.parse_query_expr(false)?;
+            assert_eq!(query.to_string(), sql);
+        }
+
+        // the options of XML are not allowed for JSON
+        assert!(Parser::new_with_sql(&dialect, "FOR JSON PATH, TYPE")?
+            .parse_query_output()
+            .is_err());
+        assert!(Parser::new_with_sql(&dialect, "FOR JSON EXPLICIT")?
+            .parse_query_output()
+            .is_err());
+        let dialect = crate::ansi::AnsiDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "FOR JSON PATH")?.parse_query_output(),
+            unsupported_capability(Capability::ForXmlJson)
+        );
+        Ok(())
+    }
+}
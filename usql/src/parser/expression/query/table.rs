@@ -3,8 +3,8 @@ use alloc::{boxed::Box, vec, vec::Vec};
 
 use crate::{
     ast::{expression::*, types::ObjectName},
-    dialect::Dialect,
-    error::ParserError,
+    dialect::{Capability, Dialect, DialectParserConf, Precedence},
+    error::{unsupported_capability, ParserError},
     keywords::Keyword,
     parser::Parser,
     tokens::Token,
@@ -58,27 +58,174 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// Parses a table factor.
     ///
     /// ```txt
-    /// <table factor> ::= <table or query name> | [ LATERAL ] <derived table> | <parenthesized joined table>
+    /// <table factor> ::=
+    ///     <table or query name>
+    ///     | [ LATERAL ] <derived table>
+    ///     | [ LATERAL ] <table function>
+    ///     | <parenthesized joined table>
     ///
     /// <table or query name> ::= <name> [ [ AS ] <alias name> [ ( <column name> [, ... ] ) ] ]
     /// <derived table> ::= ( <query expression> ) [ AS ] <alias name> [ ( <column name> [, ... ] ) ]
+    /// <table function> ::= <function> [ [ AS ] <alias name> [ ( <column name> [, ... ] ) ] ]
+    ///
+    /// // MySQL
+    /// <table or query name> ::= <name> [ PARTITION ( <partition name> [, ...] ) ]
+    ///     [ [ AS ] <alias name> ] [ <index hint> [ ... ] ]
+    ///
+    /// // MariaDB
+    /// <table or query name> ::= <name> [ PARTITION ( <partition name> [, ...] ) ]
+    ///     [ <system time period> ] [ [ AS ] <alias name> ] [ <index hint> [ ... ] ]
     /// ```
     pub fn parse_table_factor(&mut self) -> Result<TableFactor, ParserError> {
-        // [ LATERAL ] <derived table>
+        // [ LATERAL ] { <derived table> | <table function> }
         if self.parse_keyword(Keyword::LATERAL) {
-            self.parse_derived_table_factor(true)
+            if self.peek_token() == Some(&Token::LeftParen) {
+                self.parse_derived_table_factor(true)
+            } else {
+                let name = self.parse_object_name()?;
+                self.parse_table_function_factor(true, name)
+            }
         } else if self.peek_token() == Some(&Token::LeftParen) {
-            // A left paren introduces either a derived table (i.e., a subquery) or a nested join.
-            self.parse_derived_table_factor(false)
-            // TODO: support nested join
+            // A left paren introduces either a derived table (i.e., a subquery) or a nested join,
+            // e.g. `((SELECT a FROM t) AS x JOIN u ON ...)` can only be told apart at the end.
+            match self.try_parse(|parser| parser.parse_derived_table_factor(false)) {
+                Ok(factor) => Ok(factor),
+                Err(err) => match self.try_parse(Self::parse_nested_join_factor) {
+                    Ok(factor) => Ok(factor),
+                    // report the error of the subquery if it looks like one
+                    Err(_) if self.next_is_query() => Err(err),
+                    Err(nested_err) => Err(nested_err),
+                },
+            }
         } else {
             // <name> [ [ AS ] <alias name> [ ( <column name> [, ...] ) ] ]
             let name = self.parse_object_name()?;
-            let alias = self.parse_table_alias(true)?;
-            Ok(TableFactor::Table { name, alias })
+            if self.peek_token() == Some(&Token::LeftParen) {
+                self.parse_table_function_factor(false, name)
+            } else if self.dialect.parser_conf().supports_index_hints() {
+                let partitions = self.parse_keyword_then(Keyword::PARTITION, |parser| {
+                    parser.parse_parenthesized_comma_separated(Self::parse_identifier, false)
+                })?;
+                let system_time = self.parse_system_time_period()?;
+                let alias = self.parse_table_alias(true)?;
+                let mut index_hints = vec![];
+                while let Some(hint) = self.parse_index_hint()? {
+                    index_hints.push(hint);
+                }
+                Ok(TableFactor::Table {
+                    name,
+                    partitions: partitions.flatten(),
+                    system_time,
+                    alias,
+                    index_hints,
+                })
+            } else {
+                let system_time = self.parse_system_time_period()?;
+                let alias = self.parse_table_alias(true)?;
+                Ok(TableFactor::Table {
+                    name,
+                    partitions: None,
+                    system_time,
+                    alias,
+                    index_hints: vec![],
+                })
+            }
         }
     }
 
+    /// Parses an optional period of a system-versioned table.
+    ///
+    /// ```txt
+    /// <system time period> ::=
+    ///     FOR SYSTEM_TIME { AS OF <expr> | BETWEEN <expr> AND <expr> | FROM <expr> TO <expr> | ALL }
+    /// ```
+    fn parse_system_time_period(&mut self) -> Result<Option<SystemTimePeriod>, ParserError> {
+        if !self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME]) {
+            return Ok(None);
+        }
+        if !self.dialect.parser_conf().supports_system_versioning() {
+            return unsupported_capability(Capability::SystemVersioning);
+        }
+        let keywords = [Keyword::AS, Keyword::BETWEEN, Keyword::FROM, Keyword::ALL];
+        let period = match self.expect_one_of_keywords(&keywords)? {
+            Keyword::AS => {
+                self.expect_keyword(Keyword::OF)?;
+                SystemTimePeriod::AsOf(Box::new(self.parse_expr()?))
+            }
+            Keyword::BETWEEN => {
+                // the start is terminated by `AND` like the lower bound of `BETWEEN`
+                let start = self.parse_subexpr(self.prec_value(Precedence::Between))?;
+                self.expect_keyword(Keyword::AND)?;
+                let end = self.parse_subexpr(self.prec_value(Precedence::Between))?;
+                SystemTimePeriod::Between(Box::new(start), Box::new(end))
+            }
+            Keyword::FROM => {
+                let start = self.parse_expr()?;
+                self.expect_keyword(Keyword::TO)?;
+                let end = self.parse_expr()?;
+                SystemTimePeriod::FromTo(Box::new(start), Box::new(end))
+            }
+            _ => SystemTimePeriod::All,
+        };
+        Ok(Some(period))
+    }
+
+    /// Parses an optional index hint.
+    ///
+    /// ```txt
+    /// <index hint> ::=
+    ///     USE { INDEX | KEY } [ FOR { JOIN | ORDER BY | GROUP BY } ] ( [ <index name> [, ...] ] )
+    ///     | { IGNORE | FORCE } { INDEX | KEY } [ FOR { JOIN | ORDER BY | GROUP BY } ] ( <index name> [, ...] )
+    /// ```
+    pub fn parse_index_hint(&mut self) -> Result<Option<IndexHint>, ParserError> {
+        let ty = match self.parse_one_of_keywords(&[Keyword::USE, Keyword::IGNORE, Keyword::FORCE])
+        {
+            Some(Keyword::USE) => IndexHintType::Use,
+            Some(Keyword::IGNORE) => IndexHintType::Ignore,
+            Some(Keyword::FORCE) => IndexHintType::Force,
+            _ => return Ok(None),
+        };
+        if self
+            .parse_one_of_keywords(&[Keyword::INDEX, Keyword::KEY])
+            .is_none()
+        {
+            let found = self.peek_token().cloned();
+            return self.expected("INDEX or KEY", found);
+        }
+        let scope = if self.parse_keyword(Keyword::FOR) {
+            if self.parse_keyword(Keyword::JOIN) {
+                Some(IndexHintScope::Join)
+            } else if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+                Some(IndexHintScope::OrderBy)
+            } else if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
+                Some(IndexHintScope::GroupBy)
+            } else {
+                let found = self.peek_token().cloned();
+                return self.expected("JOIN, ORDER BY or GROUP BY after FOR", found);
+            }
+        } else {
+            None
+        };
+        // Only `USE INDEX` accepts an empty index list.
+        let indexes = if ty == IndexHintType::Use {
+            self.expect_token(&Token::LeftParen)?;
+            if self.next_token_if_is(&Token::RightParen) {
+                vec![]
+            } else {
+                let indexes = self.parse_comma_separated(Self::parse_identifier)?;
+                self.expect_token(&Token::RightParen)?;
+                indexes
+            }
+        } else {
+            self.parse_delimited(
+                &Token::LeftParen,
+                |parser| parser.parse_comma_separated(Self::parse_identifier),
+                &Token::RightParen,
+            )?
+        };
+        Ok(Some(IndexHint { ty, scope, indexes }))
+    }
+
     fn parse_derived_table_factor(&mut self, lateral: bool) -> Result<TableFactor, ParserError> {
         // ( <no-with-clause query expression> ) [ AS ] <alias name> [ ( <column name> [, ...] ) ]
         self.expect_token(&Token::LeftParen)?;
@@ -92,27 +239,43 @@ impl<'a, D: Dialect> Parser<'a, D> {
         })
     }
 
+    fn parse_nested_join_factor(&mut self) -> Result<TableFactor, ParserError> {
+        // ( <table reference> )
+        self.expect_token(&Token::LeftParen)?;
+        let table = self.recurse(Self::parse_table_reference)?;
+        self.expect_token(&Token::RightParen)?;
+        Ok(TableFactor::NestedJoin(Box::new(table)))
+    }
+
+    fn parse_table_function_factor(
+        &mut self,
+        lateral: bool,
+        name: ObjectName,
+    ) -> Result<TableFactor, ParserError> {
+        // <function> [ [ AS ] <alias name> [ ( <column name> [, ...] ) ] ]
+        let function = self.parse_function(name)?;
+        let alias = self.parse_table_alias(true)?;
+        Ok(TableFactor::Function {
+            lateral,
+            function,
+            alias,
+        })
+    }
+
     /// Parses an optional table alias.
     ///
     /// ```txt
     /// <table alias> ::= [ AS ] <alias name> [ ( <column name> [, ...] ) ]
     /// ```
     pub fn parse_table_alias(&mut self, optional: bool) -> Result<Option<TableAlias>, ParserError> {
-        if self.parse_keyword(Keyword::AS) {
-            let name = self.parse_identifier()?;
-            let columns = self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?;
-            Ok(Some(TableAlias { name, columns }))
-        } else {
-            match self.peek_token() {
-                Some(Token::Word(w)) if w.keyword.is_none() => {
-                    let name = self.parse_identifier()?;
-                    let columns =
-                        self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?;
-                    Ok(Some(TableAlias { name, columns }))
-                }
-                Some(_) | None if optional => Ok(None),
-                _ => self.expected("alias name", Some("not identifier")),
+        match self.parse_optional_alias()? {
+            Some(name) => {
+                let columns =
+                    self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?;
+                Ok(Some(TableAlias { name, columns }))
             }
+            None if optional => Ok(None),
+            None => self.expected("alias name", Some("not identifier")),
         }
     }
 
@@ -221,11 +384,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
             self.expect_token(&Token::LeftParen)?;
             let columns = self.parse_comma_separated(Self::parse_identifier)?;
             self.expect_token(&Token::RightParen)?;
-            let alias = if self.parse_keyword(Keyword::AS) {
-                Some(self.parse_identifier()?)
-            } else {
-                None
-            };
+            let alias = self.parse_keyword_then(Keyword::AS, Self::parse_identifier)?;
             Ok(JoinSpec::Using { columns, alias })
         } else {
             let found = self.peek_token().cloned();
@@ -251,6 +410,62 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }
     }
 
+    // ========================================================================
+    // hierarchical query clause
+    // ========================================================================
+
+    /// Parses a hierarchical query clause (Oracle).
+    ///
+    /// ```txt
+    /// <hierarchical query clause> ::=
+    ///     [ START WITH <search condition> ] CONNECT BY [ NOCYCLE ] <search condition>
+    ///     | CONNECT BY [ NOCYCLE ] <search condition> START WITH <search condition>
+    /// ```
+    pub fn parse_connect_by_clause(&mut self) -> Result<Option<ConnectBy>, ParserError> {
+        let mut start_with = self.parse_start_with()?;
+        if !self.is_connect_by() {
+            if start_with.is_none() {
+                return Ok(None);
+            }
+            let found = self.peek_token().cloned();
+            return self.expected("CONNECT BY", found);
+        }
+        if !self.dialect.parser_conf().supports_connect_by() {
+            return unsupported_capability(Capability::ConnectBy);
+        }
+        self.next_token(); // consume `CONNECT`
+        self.next_token(); // consume `BY`
+        let nocycle = self.parse_word("NOCYCLE");
+        let condition = Box::new(self.parse_expr()?);
+        if start_with.is_none() {
+            start_with = self.parse_start_with()?;
+        }
+        Ok(Some(ConnectBy {
+            start_with,
+            nocycle,
+            condition,
+        }))
+    }
+
+    fn parse_start_with(&mut self) -> Result<Option<Box<Expr>>, ParserError> {
+        let is_start_with = matches!(self.peek_token(), Some(Token::Word(w)) if w.quote.is_none() && w.value.eq_ignore_ascii_case("START"))
+            && matches!(self.peek_nth_token(1), Some(token) if token.is_keyword(Keyword::WITH));
+        if !is_start_with {
+            return Ok(None);
+        }
+        if !self.dialect.parser_conf().supports_connect_by() {
+            return unsupported_capability(Capability::ConnectBy);
+        }
+        self.next_token(); // consume `START`
+        self.next_token(); // consume `WITH`
+        Ok(Some(Box::new(self.parse_expr()?)))
+    }
+
+    fn is_connect_by(&mut self) -> bool {
+        matches!(self.peek_token(), Some(Token::Word(w)) if w.quote.is_none() && w.value.eq_ignore_ascii_case("CONNECT"))
+            && matches!(self.peek_nth_token(1), Some(token) if token.is_keyword(Keyword::BY))
+    }
+
     // ========================================================================
     // group by clause
     // ========================================================================
@@ -259,9 +474,22 @@ impl<'a, D: Dialect> Parser<'a, D> {
     ///
     /// ```txt
     /// <group by clause> ::= GROUP BY [ DISTINCT | ALL ] <group element> [, ...]
+    ///
+    /// // DuckDB-style
+    /// <group by clause> ::= GROUP BY ALL
     /// ```
     pub fn parse_group_by_clause(&mut self) -> Result<Option<GroupBy>, ParserError> {
         if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
+            if self.is_group_by_all() {
+                if !self.dialect.parser_conf().supports_group_by_all() {
+                    return unsupported_capability(Capability::GroupByAll);
+                }
+                self.next_token(); // consume the `ALL` keyword
+                return Ok(Some(GroupBy {
+                    quantifier: None,
+                    list: vec![GroupingElement::All],
+                }));
+            }
             let quantifier = self.parse_set_quantifier();
             let list = self.parse_comma_separated(Self::parse_grouping_element)?;
             Ok(Some(GroupBy { quantifier, list }))
@@ -270,6 +498,23 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }
     }
 
+    /// Determine if the next `ALL` keyword is the whole grouping element list instead of the
+    /// set quantifier, i.e. it's not followed by a grouping element.
+    fn is_group_by_all(&mut self) -> bool {
+        match self.peek_token() {
+            Some(token) if token.is_keyword(Keyword::ALL) => {}
+            _ => return false,
+        }
+        let reserved = self.dialect.parser_conf().reserved_for_alias();
+        match self.peek_nth_token(1) {
+            None | Some(Token::SemiColon) | Some(Token::RightParen) => true,
+            Some(Token::Word(word)) => {
+                matches!(word.keyword, Some(keyword) if reserved.contains(&keyword))
+            }
+            _ => false,
+        }
+    }
+
     /// Parses a grouping element.
     ///
     /// ```txt
@@ -285,6 +530,9 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// <rollup list> ::= ROLLUP ( { <column name> | ( <column name> [, ...] ) } [, ...] )
     /// <cube list> ::= CUBE  ( { <column name> | ( <column name> [, ...] ) } [, ...] )
     /// <grouping sets specification> ::= GROUPING SETS ( <grouping element> [, ...] )
+    ///
+    /// // PostgreSQL, MySQL and SQLite
+    /// <ordinary grouping set> ::= <column name> | <unsigned integer> | ( <column name> [, ...] )
     /// ```
     pub fn parse_grouping_element(&mut self) -> Result<GroupingElement, ParserError> {
         if self.parse_keyword(Keyword::ROLLUP) {
@@ -308,8 +556,8 @@ impl<'a, D: Dialect> Parser<'a, D> {
                 Ok(GroupingElement::OrdinarySet(self.parse_grouping_set()?))
             }
         } else {
-            let column = self.parse_object_name()?;
-            Ok(GroupingElement::OrdinarySet(GroupingSet::Column(column)))
+            self.reset_peek_cursor();
+            Ok(GroupingElement::OrdinarySet(self.parse_grouping_set()?))
         }
     }
 
@@ -319,6 +567,11 @@ impl<'a, D: Dialect> Parser<'a, D> {
             let columns = self.parse_comma_separated(Self::parse_object_name)?;
             self.expect_token(&Token::RightParen)?;
             Ok(GroupingSet::Columns(columns))
+        } else if let Some(Token::Number(_)) = self.peek_token() {
+            if !self.dialect.parser_conf().supports_group_by_select_list() {
+                return unsupported_capability(Capability::GroupBySelectList);
+            }
+            Ok(GroupingSet::Ordinal(self.parse_literal_uint()?))
         } else {
             let column = self.parse_object_name()?;
             Ok(GroupingSet::Column(column))
@@ -391,7 +644,24 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// ```
     pub fn parse_window_spec(&mut self) -> Result<WindowSpec, ParserError> {
         self.expect_token(&Token::LeftParen)?;
-        // NOTE: we don't support the existing window name
+        // existing window name
+        let name = match self.peek_token() {
+            Some(Token::Word(word))
+                if !matches!(
+                    word.keyword,
+                    Some(
+                        Keyword::PARTITION
+                            | Keyword::ORDER
+                            | Keyword::ROWS
+                            | Keyword::RANGE
+                            | Keyword::GROUPS
+                    )
+                ) =>
+            {
+                Some(self.parse_identifier()?)
+            }
+            _ => None,
+        };
         // window partition clause
         let partition_by = self.parse_window_partition_clause()?;
         // window order clause
@@ -400,13 +670,26 @@ impl<'a, D: Dialect> Parser<'a, D> {
         let window_frame = self.parse_window_frame_clause()?;
         self.expect_token(&Token::RightParen)?;
         Ok(WindowSpec {
-            name: None,
+            name,
             partition_by,
             order_by,
             window_frame,
         })
     }
 
+    /// Parses a window name or an in-line window specification of a window function call.
+    ///
+    /// ```txt
+    /// <window name or specification> ::= <window name> | <in-line window specification>
+    /// ```
+    pub fn parse_window_name_or_spec(&mut self) -> Result<WindowNameOrSpec, ParserError> {
+        if self.peek_token() == Some(&Token::LeftParen) {
+            Ok(WindowNameOrSpec::Spec(self.parse_window_spec()?))
+        } else {
+            Ok(WindowNameOrSpec::Name(self.parse_identifier()?))
+        }
+    }
+
     /// Parses a window partition clause.
     ///
     /// ```txt
@@ -547,17 +830,23 @@ mod tests {
             Parser::new_with_sql(&dialect, "table1")?.parse_table_factor()?,
             TableFactor::Table {
                 name: ObjectName::new(vec!["table1"]),
+                partitions: None,
+                system_time: None,
                 alias: None,
+                index_hints: vec![],
             }
         );
         assert_eq!(
             Parser::new_with_sql(&dialect, "table1 AS t1 (id1, id2)")?.parse_table_factor()?,
             TableFactor::Table {
                 name: ObjectName::new(vec!["table1"]),
+                partitions: None,
+                system_time: None,
                 alias: Some(TableAlias {
                     name: Ident::new("t1"),
                     columns: Some(vec![Ident::new("id1"), Ident::new("id2")]),
                 }),
+                index_hints: vec![],
             }
         );
         assert_eq!(
@@ -586,12 +875,16 @@ mod tests {
                             list: vec![TableReference {
                                 relation: TableFactor::Table {
                                     name: ObjectName::new(vec!["table1"]),
+                                    partitions: None,
+                                    system_time: None,
                                     alias: None,
+                                    index_hints: vec![],
                                 },
                                 joins: vec![],
                             },]
                         }),
                         r#where: None,
+                        connect_by: None,
                         group_by: None,
                         having: None,
                         window: None,
@@ -600,6 +893,7 @@ mod tests {
                     offset: None,
                     limit: None,
                     fetch: None,
+                    output: None,
                 }),
                 alias: Some(TableAlias {
                     name: Ident::new("t1"),
@@ -610,15 +904,160 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_table_function() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "generate_series(1, 3) g (i)")?.parse_table_factor()?,
+            TableFactor::Function {
+                lateral: false,
+                function: Function {
+                    distinct: false,
+                    name: ObjectName::new(vec!["generate_series"]),
+                    args: vec![
+                        FunctionArg::Unnamed(Expr::Literal(Literal::Number("1".into()))),
+                        FunctionArg::Unnamed(Expr::Literal(Literal::Number("3".into()))),
+                    ],
+                    over: None,
+                },
+                alias: Some(TableAlias {
+                    name: Ident::new("g"),
+                    columns: Some(vec![Ident::new("i")]),
+                }),
+            }
+        );
+        let cases = [
+            ("unnest(a)", "unnest(a)"),
+            (
+                "LATERAL unnest(t.a) AS u (x)",
+                "LATERAL unnest(t.a) AS u (x)",
+            ),
+            (
+                "(VALUES (1, 'a')) v (id, name)",
+                "(VALUES (1, 'a')) AS v (id, name)",
+            ),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_table_factor()?
+                    .to_string(),
+                expected
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_index_hints() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(
+                &dialect,
+                "t1 PARTITION (p0, p1) AS t USE INDEX (i1) IGNORE KEY FOR GROUP BY (i2, i3)"
+            )?
+            .parse_table_factor()?,
+            TableFactor::Table {
+                name: ObjectName::new(vec!["t1"]),
+                partitions: Some(vec![Ident::new("p0"), Ident::new("p1")]),
+                system_time: None,
+                alias: Some(TableAlias {
+                    name: Ident::new("t"),
+                    columns: None,
+                }),
+                index_hints: vec![
+                    IndexHint {
+                        ty: IndexHintType::Use,
+                        scope: None,
+                        indexes: vec![Ident::new("i1")],
+                    },
+                    IndexHint {
+                        ty: IndexHintType::Ignore,
+                        scope: Some(IndexHintScope::GroupBy),
+                        indexes: vec![Ident::new("i2"), Ident::new("i3")],
+                    },
+                ],
+            }
+        );
+        let sqls = [
+            "t1 USE INDEX ()",
+            "t1 FORCE INDEX FOR JOIN (i1)",
+            "t1 PARTITION (p0) USE INDEX FOR ORDER BY (i1)",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_table_factor()?
+                    .to_string(),
+                sql
+            );
+        }
+        assert!(Parser::new_with_sql(&dialect, "t1 FORCE INDEX ()")?
+            .parse_table_factor()
+            .is_err());
+
+        // index hints are only supported by MySQL and MariaDB
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "SELECT * FROM t1 USE INDEX (i1)";
+        assert!(Parser::parse_script(&dialect, sql).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_system_time_period() -> Result<(), ParserError> {
+        let dialect = crate::mariadb::MariadbDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "t1 FOR SYSTEM_TIME AS OF '2016-10-09' AS t")?
+                .parse_table_factor()?,
+            TableFactor::Table {
+                name: ObjectName::new(vec!["t1"]),
+                partitions: None,
+                system_time: Some(SystemTimePeriod::AsOf(Box::new(Expr::Literal(
+                    Literal::String("2016-10-09".into())
+                )))),
+                alias: Some(TableAlias {
+                    name: Ident::new("t"),
+                    columns: None,
+                }),
+                index_hints: vec![],
+            }
+        );
+        let sqls = [
+            "t1 FOR SYSTEM_TIME AS OF TIMESTAMP '2016-10-09 08:07:06'",
+            "t1 PARTITION (p0) FOR SYSTEM_TIME BETWEEN a AND b + 1 AS t USE INDEX (i1)",
+            "t1 FOR SYSTEM_TIME FROM '2016-01-01' TO NOW()",
+            "t1 FOR SYSTEM_TIME ALL",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_table_factor()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // the system-versioned tables are only supported by MariaDB
+        let dialect = crate::ansi::AnsiDialect::default();
+        assert_eq!(
+            Parser::parse_script(&dialect, "SELECT * FROM t1 FOR SYSTEM_TIME ALL"),
+            unsupported_capability(Capability::SystemVersioning)
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_joined_table() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
         let relation = TableFactor::Table {
             name: ObjectName::new(vec!["table1"]),
+            partitions: None,
+            system_time: None,
             alias: Some(TableAlias {
                 name: Ident::new("t1"),
                 columns: None,
             }),
+            index_hints: vec![],
         };
         let join_spec1 = JoinSpec::On(Box::new(Expr::BinaryOp(BinaryOpExpr {
             left: Box::new(Expr::CompoundIdentifier(vec![
@@ -716,6 +1155,87 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_nested_join() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let table = |name: &str| TableFactor::Table {
+            name: ObjectName::new(vec![name]),
+            partitions: None,
+            system_time: None,
+            alias: None,
+            index_hints: vec![],
+        };
+        let on = || JoinSpec::On(Box::new(Expr::Literal(Literal::Boolean(true))));
+
+        // the explicit grouping is kept
+        let sql = "FROM (a JOIN b ON TRUE) JOIN c ON TRUE";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?.parse_from_clause()?,
+            Some(From {
+                list: vec![TableReference {
+                    relation: TableFactor::NestedJoin(Box::new(TableReference {
+                        relation: table("a"),
+                        joins: vec![Join {
+                            join: JoinOperator::InnerJoin(on()),
+                            relation: table("b"),
+                        }],
+                    })),
+                    joins: vec![Join {
+                        join: JoinOperator::InnerJoin(on()),
+                        relation: table("c"),
+                    }],
+                }],
+            })
+        );
+
+        // the comma binds looser than the joins
+        let sql = "FROM a, b JOIN c ON TRUE";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?.parse_from_clause()?,
+            Some(From {
+                list: vec![
+                    TableReference {
+                        relation: table("a"),
+                        joins: vec![],
+                    },
+                    TableReference {
+                        relation: table("b"),
+                        joins: vec![Join {
+                            join: JoinOperator::InnerJoin(on()),
+                            relation: table("c"),
+                        }],
+                    },
+                ],
+            })
+        );
+
+        let sqls = [
+            "SELECT * FROM a INNER JOIN (b INNER JOIN c ON TRUE) ON TRUE",
+            "SELECT * FROM ((a INNER JOIN b ON TRUE) LEFT JOIN c ON TRUE), d",
+            "SELECT * FROM ((SELECT x FROM t) AS x CROSS JOIN b)",
+            "SELECT * FROM ((SELECT x FROM t) UNION (SELECT y FROM u)) AS x",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // the error of a malformed subquery is reported as it is
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "(SELECT x FROM) AS x")?
+                .parse_table_factor()
+                .unwrap_err(),
+            Parser::new_with_sql(&dialect, "SELECT x FROM)")?
+                .parse_query_expr(true)
+                .unwrap_err()
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_join_specification() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -775,6 +1295,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_connect_by_clause() -> Result<(), ParserError> {
+        use crate::{
+            ansi::{AnsiKeyword, AnsiLexerConfig},
+            dialect::CustomDialect,
+        };
+
+        #[derive(Clone, Debug, Default)]
+        struct ConnectByConfig;
+
+        impl DialectParserConf for ConnectByConfig {
+            fn supports_connect_by(&self) -> bool {
+                true
+            }
+        }
+
+        let dialect = CustomDialect::<AnsiKeyword, AnsiLexerConfig, ConnectByConfig>::default();
+        assert_eq!(
+            Parser::new_with_sql(
+                &dialect,
+                "CONNECT BY NOCYCLE PRIOR id = parent START WITH id = 1"
+            )?
+            .parse_connect_by_clause()?,
+            Some(ConnectBy {
+                start_with: Some(Box::new(Expr::BinaryOp(BinaryOpExpr {
+                    left: Box::new(Expr::Identifier(Ident::new("id"))),
+                    op: BinaryOperator::Equal,
+                    right: Box::new(Expr::Literal(Literal::Number("1".into()))),
+                }))),
+                nocycle: true,
+                condition: Box::new(Expr::BinaryOp(BinaryOpExpr {
+                    left: Box::new(Expr::UnaryOp(UnaryOpExpr {
+                        op: UnaryOperator::Prior,
+                        expr: Box::new(Expr::Identifier(Ident::new("id"))),
+                    })),
+                    op: BinaryOperator::Equal,
+                    right: Box::new(Expr::Identifier(Ident::new("parent"))),
+                })),
+            })
+        );
+
+        let sql = "SELECT id, LEVEL FROM emp AS e START WITH parent IS NULL \
+                   CONNECT BY parent = PRIOR id ORDER BY LEVEL";
+        let query = Parser::new_with_sql(&dialect, sql)?.parse_query_expr(false)?;
+        assert_eq!(query.to_string(), sql);
+
+        assert!(Parser::new_with_sql(&dialect, "START WITH a = 1")?
+            .parse_connect_by_clause()
+            .is_err());
+        let dialect = crate::ansi::AnsiDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "CONNECT BY a = b")?.parse_connect_by_clause(),
+            unsupported_capability(Capability::ConnectBy)
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_group_by_clause() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -849,6 +1426,22 @@ mod tests {
                 ),])],
             })
         );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "GROUP BY 1").map(|mut p| p.parse_group_by_clause()),
+            Ok(unsupported_capability(Capability::GroupBySelectList))
+        );
+
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "GROUP BY 1, id")?.parse_group_by_clause()?,
+            Some(GroupBy {
+                quantifier: None,
+                list: vec![
+                    GroupingElement::OrdinarySet(GroupingSet::Ordinal(1)),
+                    GroupingElement::OrdinarySet(GroupingSet::Column(ObjectName::new(vec!["id"]))),
+                ],
+            })
+        );
         Ok(())
     }
 
@@ -898,15 +1491,20 @@ mod tests {
                                 list: vec![
                                     SortSpec {
                                         expr: Box::new(Expr::Identifier(Ident::new("id1"))),
+                                        ordinal: None,
                                         asc: None,
+                                        using: None,
                                         nulls_first: None,
                                     },
                                     SortSpec {
                                         expr: Box::new(Expr::Identifier(Ident::new("id2"))),
+                                        ordinal: None,
                                         asc: None,
+                                        using: None,
                                         nulls_first: None,
                                     }
-                                ]
+                                ],
+                                all: None
                             }),
                             window_frame: None,
                         }
@@ -919,9 +1517,12 @@ mod tests {
                             order_by: Some(OrderBy {
                                 list: vec![SortSpec {
                                     expr: Box::new(Expr::Identifier(Ident::new("id2"))),
+                                    ordinal: None,
                                     asc: Some(false),
+                                    using: None,
                                     nulls_first: Some(false),
-                                }]
+                                }],
+                                all: None
                             }),
                             window_frame: Some(WindowFrame {
                                 units: WindowFrameUnits::Rows,
@@ -937,6 +1538,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_named_window() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let sql = "WINDOW w AS (PARTITION BY a), w2 AS (w ORDER BY b ROWS CURRENT ROW)";
+        let window = Parser::new_with_sql(&dialect, sql)?
+            .parse_window_clause()?
+            .unwrap();
+        assert_eq!(window.list[0].spec.name, None);
+        assert_eq!(window.list[1].spec.name, Some(Ident::new("w")));
+        assert_eq!(window.list[1].spec.partition_by, None);
+        assert_eq!(window.to_string(), sql);
+
+        let sql = "SELECT SUM(x) OVER w2, RANK() OVER (w) FROM t WINDOW w AS (PARTITION BY a), \
+                   w2 AS (w ORDER BY b)";
+        let query = Parser::new_with_sql(&dialect, sql)?.parse_query_expr(false)?;
+        assert_eq!(query.to_string(), sql);
+        let select = match &query.body {
+            QueryBody::QuerySpec(select) => select,
+            _ => unreachable!(),
+        };
+        let overs = select
+            .projection
+            .iter()
+            .map(|item| match item {
+                SelectItem::DerivedColumn { expr, .. } => match expr.as_ref() {
+                    Expr::Function(function) => function.over.clone(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            overs,
+            vec![
+                Some(WindowNameOrSpec::Name(Ident::new("w2"))),
+                Some(WindowNameOrSpec::Spec(WindowSpec {
+                    name: Some(Ident::new("w")),
+                    partition_by: None,
+                    order_by: None,
+                    window_frame: None,
+                })),
+            ]
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_window_frame() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
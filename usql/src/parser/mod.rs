@@ -1,26 +1,60 @@
 mod peek;
 
 mod expression;
+mod script;
 mod statement;
+#[cfg(feature = "std")]
+mod stream;
 mod types;
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, format, vec, vec::Vec};
+use alloc::{boxed::Box, format, string::ToString, vec, vec::Vec};
 use core::fmt::Display;
 
 use self::peek::{MultiPeek, PeekIteratorExt};
+pub(crate) use self::script::{split_stmt_tokens, stmt_span, SplitError};
+pub use self::script::{BatchSeparators, ParsedScript};
+#[cfg(feature = "std")]
+pub use self::stream::StmtStream;
 use crate::{
     dialect::Dialect,
-    error::{parse_error, ParserError},
-    keywords::Keyword,
+    error::{ExpectedKeywords, ParserError},
+    keywords::{Keyword, KeywordDef},
     lexer::Lexer,
-    tokens::Token,
+    tokens::{Token, TokenKind, TokenStream},
 };
 
+/// The maximum nesting depth of expressions and queries, which prevents a deeply nested input
+/// from overflowing the stack.
+pub(crate) const RECURSION_LIMIT: usize = 50;
+
 /// SQL Parser
 pub struct Parser<'a, D: Dialect> {
-    _dialect: &'a D,
+    dialect: &'a D,
     iter: MultiPeek<Box<dyn Iterator<Item = Token> + 'static>>,
+    /// The consumed tokens, used to rollback the parser to a checkpoint.
+    ///
+    /// The tokens are only recorded while a checkpoint may be restored, i.e. during a speculative
+    /// parsing or after an explicit [`Parser::checkpoint`], so that the common parsing paths
+    /// don't clone every token.
+    consumed: Vec<Token>,
+    /// The number of the consumed tokens which are dropped from `consumed`.
+    dropped: usize,
+    /// The nesting depth of the speculative parsing functions, see [`Parser::try_parse`].
+    speculating: usize,
+    /// Whether an explicit checkpoint has been saved, which may be restored at any time.
+    checkpointed: bool,
+    /// The current nesting depth of expressions and queries.
+    depth: usize,
+}
+
+/// A parsing function of the parser.
+type ParseFn<'a, D, T> = fn(&mut Parser<'a, D>) -> Result<T, ParserError>;
+
+/// A saved position of the parser, see [`Parser::checkpoint`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    consumed: usize,
 }
 
 impl<'a, D: Dialect> Parser<'a, D> {
@@ -31,17 +65,147 @@ impl<'a, D: Dialect> Parser<'a, D> {
             .into_iter()
             .filter(|token| !token.is_whitespace() && !token.is_comment());
         Self {
-            _dialect: dialect,
+            dialect,
             iter: (Box::new(filter) as Box<dyn Iterator<Item = Token>>).multipeek(),
+            consumed: vec![],
+            dropped: 0,
+            speculating: 0,
+            checkpointed: false,
+            depth: 0,
         }
     }
 
+    /// Creates a new SQL parser with the given token stream, the token stream is left intact
+    /// so that it can be parsed again (e.g. with another dialect configuration).
+    pub fn new_with_token_stream(dialect: &'a D, stream: &TokenStream) -> Self {
+        let tokens = stream.iter().map(|(token, _)| token.clone()).collect();
+        Self::new_with_tokens(dialect, tokens)
+    }
+
     /// Creates a new SQL parser with the given sql string.
     pub fn new_with_sql(dialect: &'a D, sql: &str) -> Result<Self, ParserError> {
         let tokens = Lexer::new(dialect, sql).tokenize()?;
         Ok(Self::new_with_tokens(dialect, tokens))
     }
 
+    /// Saves the current position of the parser, the parser can be rolled back
+    /// to the position with [`Parser::restore`].
+    ///
+    /// **NOTE**: all the tokens consumed after the first checkpoint are kept by the parser.
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        self.checkpointed = true;
+        self.position()
+    }
+
+    fn position(&self) -> Checkpoint {
+        Checkpoint {
+            consumed: self.dropped + self.consumed.len(),
+        }
+    }
+
+    /// Rolls back the parser to the checkpoint, the tokens consumed since the
+    /// checkpoint will be returned again by the following `next_token()` calls.
+    ///
+    /// Restoring to a checkpoint that is ahead of the current position does nothing.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        if checkpoint.consumed < self.dropped + self.consumed.len() {
+            let tokens = self.consumed.split_off(checkpoint.consumed - self.dropped);
+            self.iter.unread(tokens.into_iter());
+        }
+        self.iter.reset_cursor();
+    }
+
+    /// Runs the speculative parsing function `F`, and rolls back the tokens consumed
+    /// by `F` if it returns an error.
+    pub fn try_parse<T, F>(&mut self, f: F) -> Result<T, ParserError>
+    where
+        F: FnOnce(&mut Parser<'a, D>) -> Result<T, ParserError>,
+    {
+        let checkpoint = self.position();
+        self.speculating += 1;
+        let result = f(self);
+        self.speculating -= 1;
+        if result.is_err() {
+            self.restore(checkpoint);
+        }
+        // no checkpoint can be restored anymore
+        if self.speculating == 0 && !self.checkpointed {
+            self.dropped += self.consumed.len();
+            self.consumed.clear();
+        }
+        result
+    }
+
+    /// Runs the parsing function `F` one level deeper in the nesting of expressions and
+    /// queries, returns an error if the nesting exceeds the [`RECURSION_LIMIT`].
+    pub(crate) fn recurse<T, F>(&mut self, f: F) -> Result<T, ParserError>
+    where
+        F: FnOnce(&mut Parser<'a, D>) -> Result<T, ParserError>,
+    {
+        if self.depth >= RECURSION_LIMIT {
+            return Err(ParserError::RecursionLimit);
+        }
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Runs the parsing function `F` and returns `None` (with the consumed tokens
+    /// rolled back) if it fails.
+    pub fn parse_optional<T, F>(&mut self, f: F) -> Option<T>
+    where
+        F: FnOnce(&mut Parser<'a, D>) -> Result<T, ParserError>,
+    {
+        self.try_parse(f).ok()
+    }
+
+    /// Parses an item accepted by `F` which is enclosed by the `open` and `close` tokens.
+    pub fn parse_delimited<T, F>(
+        &mut self,
+        open: &Token,
+        f: F,
+        close: &Token,
+    ) -> Result<T, ParserError>
+    where
+        F: FnOnce(&mut Parser<'a, D>) -> Result<T, ParserError>,
+    {
+        self.expect_token(open)?;
+        let value = f(self)?;
+        self.expect_token(close)?;
+        Ok(value)
+    }
+
+    /// Parses an item accepted by `F` if the next token is the keyword, e.g. `[ AS <alias> ]`.
+    pub fn parse_keyword_then<T, F>(
+        &mut self,
+        keyword: Keyword,
+        f: F,
+    ) -> Result<Option<T>, ParserError>
+    where
+        F: FnOnce(&mut Parser<'a, D>) -> Result<T, ParserError>,
+    {
+        if self.parse_keyword(keyword) {
+            f(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Consumes the next keyword token if it's one of the keywords of `handlers`,
+    /// and parses the rest with the handler of the keyword.
+    pub fn parse_one_of<T>(
+        &mut self,
+        handlers: &[(Keyword, ParseFn<'a, D, T>)],
+    ) -> Result<Option<T>, ParserError> {
+        for (keyword, handler) in handlers {
+            if self.parse_keyword(*keyword) {
+                return handler(self).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
     /// Parses a comma-separated list of 1+ items accepted by `F`.
     pub fn parse_comma_separated<T, F>(&mut self, mut f: F) -> Result<Vec<T>, ParserError>
     where
@@ -113,10 +277,60 @@ impl<'a, D: Dialect> Parser<'a, D> {
         expected: impl Display,
         found: Option<impl Display>,
     ) -> Result<R, ParserError> {
+        self.unexpected(expected, ExpectedKeywords::None, found)
+    }
+
+    /// Report unexpected token where one of the keywords is expected, so that a misspelled
+    /// keyword can be suggested.
+    pub(crate) fn expected_one_of_keywords<R>(
+        &self,
+        expected: impl Display,
+        keywords: &[Keyword],
+        found: Option<impl Display>,
+    ) -> Result<R, ParserError> {
+        let keywords = keywords
+            .iter()
+            .filter_map(|keyword| {
+                let idx = D::Keyword::KEYWORDS.iter().position(|kw| kw == keyword)?;
+                Some(D::Keyword::KEYWORDS_STRING[idx])
+            })
+            .collect();
+        self.unexpected(expected, ExpectedKeywords::OneOf(keywords), found)
+    }
+
+    /// Report unexpected token where any keyword of the dialect is expected, like the start of
+    /// a statement, so that a misspelled keyword can be suggested.
+    pub(crate) fn expected_any_keyword<R>(
+        &self,
+        expected: impl Display,
+        found: Option<impl Display>,
+    ) -> Result<R, ParserError> {
+        let keywords = ExpectedKeywords::Any(D::Keyword::KEYWORDS_STRING);
+        self.unexpected(expected, keywords, found)
+    }
+
+    fn unexpected<R>(
+        &self,
+        expected: impl Display,
+        keywords: ExpectedKeywords,
+        found: Option<impl Display>,
+    ) -> Result<R, ParserError> {
+        let expected = expected.to_string();
         if let Some(found) = found {
-            parse_error(format!("Expected: {}, found: {}", expected, found))
+            let found = found.to_string();
+            // a keyword of the dialect isn't a misspelling of another keyword
+            let keywords = if D::Keyword::lookup(&found).is_some() {
+                ExpectedKeywords::None
+            } else {
+                keywords
+            };
+            Err(ParserError::UnexpectedToken {
+                expected,
+                found,
+                keywords,
+            })
         } else {
-            parse_error(format!("Expected: {}, but not found", expected))
+            Err(ParserError::UnexpectedEof { expected })
         }
     }
 
@@ -127,7 +341,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
             Ok(())
         } else {
             let found = self.peek_token().cloned();
-            self.expected(expected, found)
+            self.expected_one_of_keywords(expected, &[expected], found)
         }
     }
 
@@ -145,7 +359,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
             Ok(keyword)
         } else {
             let found = self.peek_token().cloned();
-            self.expected(format!("one of {:?}", keywords), found)
+            self.expected_one_of_keywords(format!("one of {:?}", keywords), keywords, found)
         }
     }
 
@@ -193,6 +407,26 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }
     }
 
+    /// Consumes the next token and return true if it's an unquoted word that matches the
+    /// expected word case-insensitively, for the words that are not keywords of the dialect.
+    pub fn parse_word(&mut self, expected: &str) -> bool {
+        self.next_token_if(|token| {
+            matches!(token, Token::Word(w) if w.quote.is_none() && w.value.eq_ignore_ascii_case(expected))
+        })
+        .is_some()
+    }
+
+    /// Consumes the next token if it's an unquoted word that matches the expected word
+    /// case-insensitively, otherwise return error.
+    pub fn expect_word(&mut self, expected: &str) -> Result<(), ParserError> {
+        if self.parse_word(expected) {
+            Ok(())
+        } else {
+            let found = self.peek_token().cloned();
+            self.expected(expected, found)
+        }
+    }
+
     /// Consumes the next token and return ok if it matches the expected token,
     /// otherwise return error.
     pub fn expect_token(&mut self, expected: &Token) -> Result<(), ParserError> {
@@ -220,6 +454,30 @@ impl<'a, D: Dialect> Parser<'a, D> {
         self.iter.peek_next()
     }
 
+    /// Returns a reference to the `n`th token (zero-based) after the current position,
+    /// without advancing the iterator or moving the peeking "cursor".
+    pub fn peek_nth_token(&mut self, n: usize) -> Option<&Token> {
+        self.iter.peek_nth(n)
+    }
+
+    /// Returns the kind of the next token without advancing the iterator, like
+    /// [`Parser::peek_token`] but without borrowing the parser.
+    pub fn peek_kind(&mut self) -> Option<TokenKind> {
+        self.iter.peek().map(Token::kind)
+    }
+
+    /// Returns the kind of the token at the peeking "cursor" and advances the cursor, like
+    /// [`Parser::peek_next_token`].
+    pub fn peek_next_kind(&mut self) -> Option<TokenKind> {
+        self.iter.peek_next().map(Token::kind)
+    }
+
+    /// Returns the kind of the `n`th token (zero-based) after the current position, like
+    /// [`Parser::peek_nth_token`].
+    pub fn peek_nth_kind(&mut self, n: usize) -> Option<TokenKind> {
+        self.iter.peek_nth(n).map(Token::kind)
+    }
+
     /// Returns the peeking "cursor" of tokens.
     pub fn peek_cursor(&mut self) -> usize {
         self.iter.peek_cursor()
@@ -232,24 +490,229 @@ impl<'a, D: Dialect> Parser<'a, D> {
 
     /// Consumes the next token and return the token.
     pub fn next_token(&mut self) -> Option<Token> {
-        self.iter.next()
+        let token = self.iter.next();
+        self.record(token)
     }
 
     /// Consumes the next token and return the token if it `func` return true,
     /// otherwise return None.
     pub fn next_token_if(&mut self, func: impl FnOnce(&Token) -> bool) -> Option<Token> {
-        self.iter.next_if(func)
+        let token = self.iter.next_if(func);
+        self.record(token)
     }
 
     /// Consumes the next token and return the token if it matches the expected
     /// token, otherwise return None.
     pub fn next_token_if_eq(&mut self, expected: &Token) -> Option<Token> {
-        self.iter.next_if_eq(expected)
+        let token = self.iter.next_if_eq(expected);
+        self.record(token)
     }
 
     /// Consumes the next token and return true if it matches the expected token,
     /// otherwise return false.
     pub fn next_token_if_is(&mut self, expected: &Token) -> bool {
-        self.iter.next_if_eq(expected).is_some()
+        self.next_token_if_eq(expected).is_some()
+    }
+
+    fn record(&mut self, token: Option<Token>) -> Option<Token> {
+        if let Some(token) = &token {
+            if self.speculating > 0 || self.checkpointed {
+                self.consumed.push(token.clone());
+            }
+        }
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_with_checkpoint() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let mut parser = Parser::new_with_sql(&dialect, "a + b, c")?;
+
+        assert_eq!(parser.peek_nth_token(3), Some(&Token::Comma));
+        assert_eq!(parser.peek_nth_kind(3), Some(TokenKind::Comma));
+        let checkpoint = parser.checkpoint();
+        parser.parse_expr()?;
+        assert_eq!(parser.peek_token(), Some(&Token::Comma));
+        parser.restore(checkpoint);
+        assert_eq!(parser.parse_expr()?.to_string(), "a + b");
+
+        // rollback the consumed tokens if the speculative parsing fails
+        let checkpoint = parser.checkpoint();
+        let result = parser.try_parse(|parser| {
+            parser.expect_token(&Token::Comma)?;
+            parser.expect_token(&Token::Comma)
+        });
+        assert!(result.is_err());
+        assert_eq!(parser.checkpoint(), checkpoint);
+        assert_eq!(parser.next_token(), Some(Token::Comma));
+
+        let ident = parser.try_parse(|parser| parser.parse_identifier())?;
+        assert_eq!(ident.value, "c");
+        assert_eq!(parser.peek_token(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn keep_consumed_tokens_for_checkpoints() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let list = (0..1000).map(|i| i.to_string()).collect::<Vec<_>>();
+        let mut parser = Parser::new_with_sql(&dialect, &list.join(", "))?;
+
+        // the tokens are only kept during the speculative parsing
+        let exprs = parser.try_parse(|parser| parser.parse_comma_separated(Parser::parse_expr))?;
+        assert_eq!(exprs.len(), 1000);
+        assert!(parser.consumed.is_empty());
+        assert_eq!(parser.checkpoint().consumed, 1999);
+
+        let mut parser = Parser::new_with_sql(&dialect, "a, b, c")?;
+        parser.next_token();
+        let checkpoint = parser.checkpoint();
+        parser.parse_optional(|parser| parser.expect_token(&Token::Comma));
+        parser.next_token();
+        parser.restore(checkpoint);
+        assert_eq!(parser.next_token(), Some(Token::Comma));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_with_combinators() -> Result<(), ParserError> {
+        use crate::ast::{expression::Expr, types::Ident};
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        let mut parser = Parser::new_with_sql(&dialect, "(a) AS b c")?;
+        let expr =
+            parser.parse_delimited(&Token::LeftParen, Parser::parse_expr, &Token::RightParen)?;
+        assert_eq!(expr, Expr::Identifier(Ident::new("a")));
+        assert_eq!(
+            parser.parse_keyword_then(Keyword::AS, Parser::parse_identifier)?,
+            Some(Ident::new("b"))
+        );
+        assert_eq!(
+            parser.parse_keyword_then(Keyword::AS, Parser::parse_identifier)?,
+            None
+        );
+        assert_eq!(
+            parser.parse_optional(|parser| parser.expect_keyword(Keyword::AS)),
+            None
+        );
+        assert_eq!(
+            parser.parse_optional(Parser::parse_identifier),
+            Some(Ident::new("c"))
+        );
+
+        let mut parser = Parser::new_with_sql(&dialect, "ASC DESC")?;
+        for expected in [Some(true), Some(false), None] {
+            let asc = parser
+                .parse_one_of(&[(Keyword::ASC, |_| Ok(true)), (Keyword::DESC, |_| Ok(false))])?;
+            assert_eq!(asc, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error_kinds() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_stmt();
+        assert_eq!(
+            parse("SELECT 1 FROM"),
+            Err(ParserError::UnexpectedEof {
+                expected: "identifier".into()
+            })
+        );
+        assert_eq!(
+            parse("SELECT a FROM ,"),
+            Err(ParserError::UnexpectedToken {
+                expected: "identifier".into(),
+                found: ",".into(),
+                keywords: ExpectedKeywords::None
+            })
+        );
+        assert_eq!(
+            parse("CREATE TABLE t (a my_type)"),
+            Err(ParserError::UnsupportedFeature {
+                feature: "custom data type".into(),
+                capability: None
+            })
+        );
+        assert!(matches!(
+            parse("CREATE TABLE t (a VARCHAR(99999999999999999999))"),
+            Err(ParserError::InvalidLiteral(_))
+        ));
+        assert!(matches!(
+            parse("SELECT 'a"),
+            Err(ParserError::TokenizeError(_))
+        ));
+
+        // the outermost statement takes up 2 levels of the nesting
+        let nested = |depth| format!("SELECT {}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse(&nested(RECURSION_LIMIT - 2)).is_ok());
+        assert_eq!(
+            parse(&nested(RECURSION_LIMIT - 1)),
+            Err(ParserError::RecursionLimit)
+        );
+        let nested = |depth| {
+            format!(
+                "SELECT * FROM {}t{}",
+                "(SELECT * FROM ".repeat(depth),
+                ") AS s".repeat(depth)
+            )
+        };
+        assert!(parse(&nested(RECURSION_LIMIT - 2)).is_ok());
+        assert_eq!(
+            parse(&nested(RECURSION_LIMIT - 1)),
+            Err(ParserError::RecursionLimit)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn keyword_suggestions() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_stmt();
+        let err = parse("SELCT 1").unwrap_err();
+        assert!(matches!(
+            &err,
+            ParserError::UnexpectedToken {
+                expected,
+                found,
+                keywords: ExpectedKeywords::Any(_),
+            } if expected == "statement" && found == "SELCT"
+        ));
+        assert_eq!(err.suggestion(), Some("SELECT"));
+        assert_eq!(
+            err.to_string(),
+            "Expected: statement, found: SELCT, did you mean SELECT?"
+        );
+        let suggestion = |sql: &str| {
+            Parser::parse_script(&dialect, sql)
+                .unwrap_err()
+                .suggestion()
+        };
+        assert_eq!(suggestion("SELECT * FORM t"), Some("FROM"));
+        assert_eq!(suggestion("updaet t SET a = 1"), Some("UPDATE"));
+        // only the expected keywords are suggested
+        assert_eq!(suggestion("DELETE FORM t"), Some("FROM"));
+        assert_eq!(
+            suggestion("CREATE TABLE t (a INT) DEFAULT CHARST utf8"),
+            None
+        );
+        assert_eq!(suggestion("SELECT a FROM t WHERE a IN seletc"), None);
+        // keywords, short words and quoted identifiers are not suggested against
+        assert_eq!(suggestion("SELECT 1 UNION SELECT 2 SELECT"), None);
+        assert_eq!(suggestion("DELETE FORMAT t"), None);
+        assert_eq!(suggestion("CREATE TABLE t (a INT) foo"), None);
+        assert_eq!(suggestion("\"SELCT\" 1"), None);
+
+        // the suggestions depend on the keywords of the dialect
+        assert_eq!(suggestion("PRAGM foreign_keys"), None);
+        let sqlite = crate::sqlite::SqliteDialect::default();
+        let err = Parser::parse_script(&sqlite, "PRAGM foreign_keys").unwrap_err();
+        assert_eq!(err.suggestion(), Some("PRAGMA"));
+        Ok(())
     }
 }
@@ -89,6 +89,27 @@ impl<I: Iterator> MultiPeek<I> {
         ret
     }
 
+    /// Returns a reference to the `n`th value (zero-based) without advancing
+    /// the iterator or moving the peeking "cursor".
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        while self.buf.len() <= n {
+            match self.iter.next() {
+                Some(x) => self.buf.push_back(x),
+                None => return None,
+            }
+        }
+        Some(&self.buf[n])
+    }
+
+    /// Puts the values back in front of the iterator, so that they will be
+    /// returned again by the following `.next()` calls, and reset the peeking "cursor".
+    pub(crate) fn unread(&mut self, items: impl DoubleEndedIterator<Item = I::Item>) {
+        for item in items.rev() {
+            self.buf.push_front(item);
+        }
+        self.index = 0;
+    }
+
     /// Reset the peeking "cursor".
     #[inline]
     pub fn reset_cursor(&mut self) {
@@ -189,6 +210,21 @@ mod tests {
         assert_eq!(iter.peek_cursor(), 0);
     }
 
+    #[test]
+    fn multipeek_peek_nth_and_unread() {
+        let mut iter = (0..5).multipeek();
+        assert_eq!(iter.peek_nth(2), Some(&2));
+        assert_eq!(iter.peek_cursor(), 0);
+        assert_eq!(iter.peek_nth(5), None);
+
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.peek_next(), Some(&2));
+        iter.unread(vec![0, 1].into_iter());
+        assert_eq!(iter.peek_cursor(), 0);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
     #[test]
     fn multipeek_next_if() {
         let mut iter = (0..5).multipeek();
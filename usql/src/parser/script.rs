@@ -0,0 +1,603 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::mem;
+
+use crate::{
+    ast::statement::Stmt,
+    dialect::Dialect,
+    error::{LexerError, ParserError, Span},
+    keywords::Keyword,
+    lexer::Lexer,
+    parser::Parser,
+    tokens::{Comment, Token, TokenKind, Whitespace},
+};
+
+/// A parsed multi-statement SQL script.
+///
+/// Every statement is paired with its byte range in the original input,
+/// the range covers the statement text without the trailing `;`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ParsedScript {
+    /// The statements and their source ranges, in source order.
+    stmts: Vec<(Stmt, Span)>,
+}
+
+impl ParsedScript {
+    /// The statements and their source ranges, in source order.
+    pub fn stmts(&self) -> &[(Stmt, Span)] {
+        &self.stmts
+    }
+
+    /// Consumes the script and returns the statements and their source ranges.
+    pub fn into_stmts(self) -> Vec<(Stmt, Span)> {
+        self.stmts
+    }
+
+    /// Returns an iterator over the statements and their source ranges.
+    pub fn iter(&self) -> impl Iterator<Item = &(Stmt, Span)> {
+        self.stmts.iter()
+    }
+
+    /// Returns the statement that covers the given byte offset of the input.
+    pub fn stmt_at(&self, offset: usize) -> Option<&(Stmt, Span)> {
+        self.stmts.iter().find(|(_, span)| span.contains(offset))
+    }
+}
+
+/// The batch separators of the client tools, which terminate the statements of a SQL script
+/// besides `;`, see [`Parser::parse_script_with_separators`].
+///
+/// The separators are directives of the tools rather than SQL statements, so they are not
+/// included in the parsed script.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchSeparators {
+    /// Whether a line of `GO`, optionally followed by a repeat count (which is ignored),
+    /// terminates the statement, like the T-SQL tools (`sqlcmd` and SSMS).
+    pub go: bool,
+    /// Whether a `DELIMITER <delimiter>` line at the start of a statement changes the statement
+    /// terminator, like the MySQL client.
+    ///
+    /// A custom terminator is recognized anywhere except in string literals, quoted identifiers
+    /// and comments (e.g. `END$$`), and `;` is a part of the statement until `DELIMITER ;`.
+    pub delimiter: bool,
+}
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses a SQL script that consists of `;` separated statements.
+    pub fn parse_script(dialect: &'a D, sql: &str) -> Result<ParsedScript, ParserError> {
+        Self::parse_script_with_separators(dialect, sql, BatchSeparators::default())
+    }
+
+    /// Parses a SQL script like [`Parser::parse_script`], the statements may also be terminated
+    /// by the batch separators.
+    pub fn parse_script_with_separators(
+        dialect: &'a D,
+        sql: &str,
+        separators: BatchSeparators,
+    ) -> Result<ParsedScript, ParserError> {
+        let mut script = ParsedScript::default();
+        for (tokens, span) in split_stmts(dialect, sql, separators)? {
+            let stmt = Self::parse_single_stmt(dialect, tokens)?;
+            script.stmts.push((stmt, span));
+        }
+        Ok(script)
+    }
+
+    /// Splits a SQL script into the statements without parsing them, and returns the byte
+    /// ranges of the statements, like the ones of [`Parser::parse_script_with_separators`].
+    ///
+    /// It's useful for the scripts whose statements are not supported by the parser, like
+    /// the stored procedures defined between `DELIMITER` directives.
+    pub fn split_script(
+        dialect: &'a D,
+        sql: &str,
+        separators: BatchSeparators,
+    ) -> Result<Vec<Span>, ParserError> {
+        let stmts = split_stmts(dialect, sql, separators)?;
+        Ok(stmts.into_iter().map(|(_, span)| span).collect())
+    }
+
+    /// Parses the next statement of a multi-statement input, the statement terminator `;`
+    /// (and the empty statements after it) is consumed too, so that the same parser can be used
+    /// to parse the statements in a loop until [`Parser::is_eof`]:
+    ///
+    /// ```
+    /// # use usql::{ansi::AnsiDialect, Parser, ParserError};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = AnsiDialect::default();
+    /// let mut parser = Parser::new_with_sql(&dialect, "SELECT 1 FROM t; DELETE FROM t;")?;
+    /// let mut stmts = vec![];
+    /// while !parser.is_eof() {
+    ///     stmts.push(parser.parse_next_stmt()?);
+    /// }
+    /// assert_eq!(stmts.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **NOTE**: a meta-command is terminated by the end of line, which is not followed by `;`.
+    pub fn parse_next_stmt(&mut self) -> Result<Stmt, ParserError> {
+        while self.next_token_if_is(&Token::SemiColon) {}
+        let stmt = self.parse_stmt()?;
+        if !matches!(stmt, Stmt::MetaCommand(_))
+            && !matches!(self.peek_kind(), Some(TokenKind::SemiColon) | None)
+        {
+            let found = self.peek_token().cloned();
+            return self.expected_any_keyword("end of statement", found);
+        }
+        while self.next_token_if_is(&Token::SemiColon) {}
+        Ok(stmt)
+    }
+
+    /// Returns true if there is no statement left to parse, the empty statements are skipped.
+    pub fn is_eof(&mut self) -> bool {
+        while self.next_token_if_is(&Token::SemiColon) {}
+        self.peek_token().is_none()
+    }
+
+    /// Parses the tokens of a single statement, all the tokens must be consumed.
+    pub(crate) fn parse_single_stmt(
+        dialect: &'a D,
+        tokens: Vec<Token>,
+    ) -> Result<Stmt, ParserError> {
+        let mut parser = Parser::new_with_tokens(dialect, tokens);
+        let stmt = parser.parse_stmt()?;
+        if parser.peek_kind().is_some() {
+            let found = parser.peek_token().cloned();
+            return parser.expected_any_keyword("end of statement", found);
+        }
+        Ok(stmt)
+    }
+}
+
+/// Splits the script into the tokens of the statements and their byte ranges.
+fn split_stmts<D: Dialect>(
+    dialect: &D,
+    sql: &str,
+    separators: BatchSeparators,
+) -> Result<Vec<(Vec<Token>, Span)>, ParserError> {
+    let stmts = split_stmt_tokens(dialect, sql, separators, &mut vec![])?;
+    Ok(stmts
+        .into_iter()
+        .map(|tokens| {
+            let span = stmt_span(&tokens);
+            (tokens.into_iter().map(|(token, _)| token).collect(), span)
+        })
+        .collect())
+}
+
+/// An error that stops splitting a script into the statements.
+pub(crate) enum SplitError {
+    /// The script can't be tokenized.
+    Lexer(LexerError),
+    /// A batch separator is invalid, like a `DELIMITER` without a delimiter.
+    Separator(String, Span),
+}
+
+impl From<SplitError> for ParserError {
+    fn from(err: SplitError) -> Self {
+        match err {
+            SplitError::Lexer(err) => err.into(),
+            SplitError::Separator(message, _) => ParserError::ParseError(message),
+        }
+    }
+}
+
+impl From<LexerError> for SplitError {
+    fn from(err: LexerError) -> Self {
+        SplitError::Lexer(err)
+    }
+}
+
+/// Splits the script into the tokens of the statements, each token is paired with its byte
+/// range in the input, and appends the lexer warnings to `warnings`.
+///
+/// The statements are terminated by `;` (except in the blocks of a stored program body), the
+/// meta-commands and the batch separators. The whitespaces, the comments and the empty
+/// statements are skipped.
+pub(crate) fn split_stmt_tokens<D: Dialect>(
+    dialect: &D,
+    sql: &str,
+    separators: BatchSeparators,
+    warnings: &mut Vec<LexerError>,
+) -> Result<Vec<Vec<(Token, Span)>>, SplitError> {
+    let mut lexer = Lexer::new(dialect, sql);
+    let mut stmts = vec![];
+    let mut stmt = vec![];
+    // the statement terminator changed by the `DELIMITER` directive
+    let mut delimiter: Option<String> = None;
+    // whether there are only whitespaces and comments before the token in its line
+    let mut line_start = true;
+    let mut blocks = BlockTracker::default();
+    loop {
+        let location = lexer.location();
+        let (token, span) = match lexer.next_token_with_span()? {
+            Some(token) => token,
+            None => break,
+        };
+        if let Some(delimiter) = &delimiter {
+            if let Some(offset) = find_delimiter(sql, &token, span, delimiter) {
+                // the delimiter may be in the middle of a token, like `END$$`
+                let prefix = Lexer::new(dialect, &sql[span.start..offset]).tokenize_with_spans()?;
+                stmt.extend(prefix.into_iter().map(|(token, prefix_span)| {
+                    let prefix_span =
+                        Span::new(span.start + prefix_span.start, span.start + prefix_span.end);
+                    (token, prefix_span)
+                }));
+                finish_stmt(&mut stmt, &mut stmts);
+                lexer.rewind(span.start, location);
+                lexer.skip_to(offset + delimiter.len());
+                line_start = false;
+                continue;
+            }
+        }
+        match token {
+            Token::Whitespace(Whitespace::Newline) | Token::Comment(Comment::SingleLine { .. }) => {
+                line_start = true;
+                continue;
+            }
+            _ if token.is_whitespace() || token.is_comment() => continue,
+            Token::Word(word) if line_start && word.quote.is_none() => {
+                let line_end = sql[span.end..]
+                    .find(['\n', '\r'])
+                    .map_or(sql.len(), |len| span.end + len);
+                let args = &sql[span.end..line_end];
+                if separators.go && is_go(&word.value, args) {
+                    finish_stmt(&mut stmt, &mut stmts);
+                    lexer.skip_to(line_end);
+                } else if separators.delimiter
+                    && stmt.is_empty()
+                    && word.value.eq_ignore_ascii_case("DELIMITER")
+                {
+                    delimiter = match args.split_whitespace().next() {
+                        Some(";") => None,
+                        Some(value) => Some(value.to_string()),
+                        None => {
+                            return Err(SplitError::Separator(
+                                "DELIMITER must be followed by a delimiter".into(),
+                                span,
+                            ))
+                        }
+                    };
+                    lexer.skip_to(line_end);
+                } else {
+                    let token = Token::Word(word);
+                    blocks.push(&token, stmt.is_empty());
+                    stmt.push((token, span));
+                }
+            }
+            Token::SemiColon if delimiter.is_none() && !blocks.in_block() => {
+                finish_stmt(&mut stmt, &mut stmts)
+            }
+            Token::MetaCommand(_) => {
+                // meta-commands are terminated by the end of line instead of `;`
+                finish_stmt(&mut stmt, &mut stmts);
+                stmt.push((token, span));
+                finish_stmt(&mut stmt, &mut stmts);
+            }
+            token => {
+                blocks.push(&token, stmt.is_empty());
+                stmt.push((token, span));
+            }
+        }
+        line_start = false;
+    }
+    finish_stmt(&mut stmt, &mut stmts);
+    lexer.append_warnings(warnings);
+    Ok(stmts)
+}
+
+/// Tracks the blocks of a stored program body, like `BEGIN ... END` and `IF ... END IF`,
+/// in which `;` terminates the statements of the body instead of the whole statement.
+///
+/// The blocks are only tracked in the `CREATE { PROCEDURE | FUNCTION | TRIGGER }` statements,
+/// since a top-level `BEGIN` starts a transaction.
+///
+/// **NOTE**: `IF` starts a block only at the start of a statement of the body, otherwise it's
+/// the `IF()` function of MySQL.
+#[derive(Debug, Default)]
+pub(crate) struct BlockTracker {
+    /// Whether the statement is a `CREATE` statement.
+    create: bool,
+    /// Whether the statement creates a stored program, `None` if the object is not known yet.
+    routine: Option<bool>,
+    /// The nesting depth of the blocks.
+    depth: usize,
+    /// Whether the previous token may be followed by the start of a statement of the body.
+    stmt_start: bool,
+    /// Whether the previous token is `END`, which may be followed by the block keyword.
+    after_end: bool,
+}
+
+impl BlockTracker {
+    /// Tracks the next token of the statement, the whitespaces and the comments are skipped,
+    /// `first` indicates that it's the first token of a new statement.
+    pub(crate) fn push(&mut self, token: &Token, first: bool) {
+        if first {
+            *self = Self::default();
+        }
+        let keyword = match token {
+            Token::Word(word) if word.quote.is_none() => word.keyword,
+            _ => None,
+        };
+        if first {
+            self.create = keyword == Some(Keyword::CREATE);
+        } else if self.create && self.routine.is_none() {
+            self.routine = match keyword {
+                Some(Keyword::PROCEDURE | Keyword::FUNCTION | Keyword::TRIGGER) => Some(true),
+                Some(
+                    Keyword::TABLE
+                    | Keyword::VIEW
+                    | Keyword::INDEX
+                    | Keyword::SEQUENCE
+                    | Keyword::DOMAIN
+                    | Keyword::TYPE
+                    | Keyword::DATABASE
+                    | Keyword::SCHEMA
+                    | Keyword::ROLE
+                    | Keyword::USER
+                    | Keyword::SERVER
+                    | Keyword::POLICY
+                    | Keyword::FOREIGN,
+                ) => Some(false),
+                _ => None,
+            };
+        }
+        if self.routine != Some(true) {
+            return;
+        }
+
+        match keyword {
+            Some(Keyword::BEGIN) => self.depth += 1,
+            Some(Keyword::CASE | Keyword::WHILE) if !self.after_end => self.depth += 1,
+            Some(Keyword::IF) if !self.after_end && self.stmt_start => self.depth += 1,
+            Some(Keyword::END) => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+        self.after_end = keyword == Some(Keyword::END);
+        self.stmt_start = match token {
+            Token::SemiColon | Token::Colon | Token::RightParen | Token::String(_) => true,
+            _ => matches!(
+                keyword,
+                Some(
+                    Keyword::BEGIN
+                        | Keyword::THEN
+                        | Keyword::ELSE
+                        | Keyword::DO
+                        | Keyword::ROW
+                        | Keyword::DETERMINISTIC
+                        | Keyword::SQL
+                        | Keyword::DATA
+                        | Keyword::DEFINER
+                        | Keyword::INVOKER
+                )
+            ),
+        };
+    }
+
+    /// Returns true if the tracked tokens end in a block, so `;` doesn't terminate the statement.
+    pub(crate) fn in_block(&self) -> bool {
+        self.depth > 0
+    }
+}
+
+/// Moves the tokens of a statement to the split statements, unless the statement is empty.
+fn finish_stmt(tokens: &mut Vec<(Token, Span)>, stmts: &mut Vec<Vec<(Token, Span)>>) {
+    if !tokens.is_empty() {
+        stmts.push(mem::take(tokens));
+    }
+}
+
+/// Returns the byte range of the statement tokens.
+pub(crate) fn stmt_span(tokens: &[(Token, Span)]) -> Span {
+    match (tokens.first(), tokens.last()) {
+        (Some((_, first)), Some((_, last))) => Span::new(first.start, last.end),
+        _ => Span::default(),
+    }
+}
+
+/// Returns true if the word and the rest of its line are a `GO [count]` separator.
+fn is_go(word: &str, args: &str) -> bool {
+    let args = args.split("--").next().unwrap_or_default().trim();
+    word.eq_ignore_ascii_case("GO") && args.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Returns the byte offset of the first custom delimiter that starts in the token, the
+/// delimiters in string literals, quoted identifiers and comments are ignored.
+fn find_delimiter(sql: &str, token: &Token, span: Span, delimiter: &str) -> Option<usize> {
+    match token {
+        Token::Word(word) if word.quote.is_some() => return None,
+        Token::String(_)
+        | Token::NationalString(_)
+        | Token::HexString(_)
+        | Token::BitString(_)
+        | Token::EscapeString { .. }
+        | Token::DollarString { .. } => return None,
+        token if token.is_whitespace() || token.is_comment() => return None,
+        _ => {}
+    }
+    sql[span.start..span.end]
+        .char_indices()
+        .map(|(index, _)| span.start + index)
+        .find(|&offset| sql[offset..].starts_with(delimiter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ExpectedKeywords;
+
+    #[test]
+    fn parse_script() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let sql = "SELECT * FROM foo;\n  -- comment\n  DELETE FROM foo WHERE a = 1 ; ; COMMIT";
+        let script = Parser::parse_script(&dialect, sql)?;
+        let spans = script.iter().map(|(_, span)| *span).collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            vec![Span::new(0, 17), Span::new(34, 61), Span::new(66, 72)]
+        );
+        assert_eq!(&sql[34..61], "DELETE FROM foo WHERE a = 1");
+        assert!(matches!(script.stmts()[0].0, Stmt::Select(_)));
+        assert!(matches!(script.stmts()[1].0, Stmt::Delete(_)));
+        assert!(matches!(script.stmts()[2].0, Stmt::CommitTransaction(_)));
+
+        assert_eq!(
+            script.stmt_at(40).map(|(_, span)| *span),
+            Some(Span::new(34, 61))
+        );
+        assert_eq!(script.stmt_at(20), None);
+
+        let dialect = crate::postgres::PostgresDialect::new(
+            crate::postgres::PostgresLexerConfig { psql_mode: true },
+            Default::default(),
+        );
+        let sql = "\\connect db\nSELECT 1;\n\\q";
+        let script = Parser::parse_script(&dialect, sql)?;
+        let spans = script.iter().map(|(_, span)| *span).collect::<Vec<_>>();
+        assert_eq!(
+            spans,
+            vec![Span::new(0, 11), Span::new(12, 20), Span::new(22, 24)]
+        );
+        assert!(matches!(script.stmts()[0].0, Stmt::MetaCommand(_)));
+        assert!(matches!(script.stmts()[1].0, Stmt::Select(_)));
+        assert!(matches!(script.stmts()[2].0, Stmt::MetaCommand(_)));
+
+        assert!(Parser::parse_script(&dialect, "COMMIT COMMIT").is_err());
+        assert_eq!(
+            Parser::parse_script(&dialect, " ; ")?,
+            ParsedScript::default()
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "procedural")]
+    #[test]
+    fn parse_script_with_stored_programs() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "BEGIN; \
+            CREATE PROCEDURE p(IN a INT) BEGIN \
+                DECLARE b INT DEFAULT IF(a > 0, a, 0); \
+                IF b > 1 THEN UPDATE t SET c = CASE WHEN b > 2 THEN 1 ELSE 2 END; END IF; \
+                WHILE b > 0 DO DELETE FROM t WHERE c = b; END WHILE; \
+            END; \
+            CREATE TRIGGER tr BEFORE INSERT ON t FOR EACH ROW \
+                IF a < 0 THEN INSERT INTO log VALUES (1); DELETE FROM t; END IF; \
+            CREATE FUNCTION f() RETURNS INT RETURN IF(1, 2, 3); \
+            COMMIT";
+        let script = Parser::parse_script(&dialect, sql)?;
+        let stmts = script.iter().map(|(stmt, _)| stmt).collect::<Vec<_>>();
+        assert_eq!(stmts.len(), 5);
+        assert!(matches!(stmts[0], Stmt::StartTransaction(_)));
+        assert!(matches!(stmts[1], Stmt::CreateRoutine(_)));
+        assert!(matches!(stmts[2], Stmt::CreateTrigger(_)));
+        assert!(matches!(stmts[3], Stmt::CreateRoutine(_)));
+        assert!(matches!(stmts[4], Stmt::CommitTransaction(_)));
+        let (_, span) = &script.stmts()[1];
+        assert!(sql[span.start..span.end].ends_with("END WHILE; END"));
+
+        let stmts = Parser::parse_stream(sql.as_bytes(), &dialect).collect::<Vec<_>>();
+        assert_eq!(stmts.len(), 5);
+        assert!(stmts.iter().all(Result::is_ok));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_next_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::new(
+            crate::postgres::PostgresLexerConfig { psql_mode: true },
+            Default::default(),
+        );
+        let sql = "; SELECT 1;;\n\\connect db\nDELETE FROM foo ; COMMIT ;";
+        let mut parser = Parser::new_with_sql(&dialect, sql)?;
+        let mut stmts = vec![];
+        while !parser.is_eof() {
+            stmts.push(parser.parse_next_stmt()?);
+        }
+        assert_eq!(stmts.len(), 4);
+        assert!(matches!(stmts[0], Stmt::Select(_)));
+        assert!(matches!(stmts[1], Stmt::MetaCommand(_)));
+        assert!(matches!(stmts[2], Stmt::Delete(_)));
+        assert!(matches!(stmts[3], Stmt::CommitTransaction(_)));
+        assert!(parser.parse_next_stmt().is_err());
+
+        let mut parser = Parser::new_with_sql(&dialect, "COMMIT COMMIT")?;
+        assert_eq!(
+            parser.parse_next_stmt(),
+            Err(ParserError::UnexpectedToken {
+                expected: "end of statement".into(),
+                found: "COMMIT".into(),
+                keywords: ExpectedKeywords::None
+            })
+        );
+        let mut parser = Parser::new_with_sql(&dialect, " ; ")?;
+        assert!(parser.is_eof());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_script_with_separators() -> Result<(), ParserError> {
+        let dialect = crate::ansi::AnsiDialect::default();
+        let go = BatchSeparators {
+            go: true,
+            ..Default::default()
+        };
+        let sql = "SELECT a FROM t\nGO\nSELECT go FROM t;\n  go 5 -- twice\r\nDELETE FROM t\ngo";
+        let script = Parser::parse_script_with_separators(&dialect, sql, go)?;
+        let stmts = script.iter().map(|(_, span)| &sql[span.start..span.end]);
+        assert_eq!(
+            stmts.collect::<Vec<_>>(),
+            vec!["SELECT a FROM t", "SELECT go FROM t", "DELETE FROM t"]
+        );
+        // `GO` is a word (like the table alias here) unless the separator is enabled
+        assert_eq!(
+            Parser::split_script(&dialect, "SELECT a FROM t\nGO", Default::default())?,
+            vec![Span::new(0, 18)]
+        );
+        assert_eq!(
+            Parser::split_script(&dialect, "SELECT 1\nGO x", go)?,
+            vec![Span::new(0, 13)]
+        );
+
+        let dialect = crate::mysql::MysqlDialect::default();
+        let delimiter = BatchSeparators {
+            delimiter: true,
+            ..Default::default()
+        };
+        let sql = "delimiter //\nSELECT 1 // SELECT `a//b`, '//' FROM t //\nDELIMITER ;\nSELECT 2;";
+        let script = Parser::parse_script_with_separators(&dialect, sql, delimiter)?;
+        let stmts = script.iter().map(|(_, span)| &sql[span.start..span.end]);
+        assert_eq!(
+            stmts.collect::<Vec<_>>(),
+            vec!["SELECT 1", "SELECT `a//b`, '//' FROM t", "SELECT 2"]
+        );
+
+        // the delimiter may be a part of a token, and `;` doesn't terminate the statement
+        let sql = "DELIMITER $$\nCREATE PROCEDURE p() BEGIN SELECT 1; END$$\nDELIMITER ;\nSELECT 2";
+        let spans = Parser::split_script(&dialect, sql, delimiter)?;
+        let stmts = spans.iter().map(|span| &sql[span.start..span.end]);
+        assert_eq!(
+            stmts.collect::<Vec<_>>(),
+            vec!["CREATE PROCEDURE p() BEGIN SELECT 1; END", "SELECT 2"]
+        );
+
+        // a directive must be at the start of a statement
+        let sql = "SELECT\ndelimiter\nFROM t";
+        assert_eq!(
+            Parser::split_script(&dialect, sql, delimiter)?,
+            vec![Span::new(0, sql.len())]
+        );
+        assert_eq!(
+            Parser::split_script(&dialect, "DELIMITER \nSELECT 1", delimiter),
+            Err(ParserError::ParseError(
+                "DELIMITER must be followed by a delimiter".into()
+            ))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    ast::{expression::Expr, statement::*, types::*},
+    dialect::Dialect,
+    error::ParserError,
+    keywords::Keyword,
+    parser::Parser,
+    tokens::Token,
+};
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses a `PRAGMA` statement.
+    pub fn parse_pragma_stmt(&mut self) -> Result<PragmaStmt, ParserError> {
+        self.expect_keyword(Keyword::PRAGMA)?;
+        let name = self.parse_object_name()?;
+        let parenthesized = self.peek_token() == Some(&Token::LeftParen);
+        let value = if self.next_token_if_is(&Token::Equal) {
+            Some(self.parse_pragma_value()?)
+        } else if parenthesized {
+            Some(self.parse_delimited(
+                &Token::LeftParen,
+                Self::parse_pragma_value,
+                &Token::RightParen,
+            )?)
+        } else {
+            None
+        };
+        Ok(PragmaStmt {
+            name,
+            value,
+            parenthesized,
+        })
+    }
+
+    /// Parses a pragma value, which is a signed number, a string literal or a name.
+    ///
+    /// A name may be a keyword, like `ON` or `DELETE`.
+    fn parse_pragma_value(&mut self) -> Result<Expr, ParserError> {
+        match self.peek_token() {
+            Some(Token::Word(_)) => Ok(Expr::Identifier(self.parse_identifier()?)),
+            _ => self.parse_expr(),
+        }
+    }
+
+    /// Parses a `ATTACH DATABASE` statement.
+    pub fn parse_attach_stmt(&mut self) -> Result<AttachStmt, ParserError> {
+        self.expect_keyword(Keyword::ATTACH)?;
+        self.parse_keyword(Keyword::DATABASE);
+        let database = self.parse_expr()?;
+        self.expect_keyword(Keyword::AS)?;
+        let schema = self.parse_identifier()?;
+        Ok(AttachStmt { database, schema })
+    }
+
+    /// Parses a `DETACH DATABASE` statement.
+    pub fn parse_detach_stmt(&mut self) -> Result<DetachStmt, ParserError> {
+        self.expect_keyword(Keyword::DETACH)?;
+        self.parse_keyword(Keyword::DATABASE);
+        let schema = self.parse_identifier()?;
+        Ok(DetachStmt { schema })
+    }
+
+    /// Parses a `VACUUM` statement.
+    pub fn parse_vacuum_stmt(&mut self) -> Result<VacuumStmt, ParserError> {
+        self.expect_keyword(Keyword::VACUUM)?;
+        let schema = match self.peek_token() {
+            Some(token) if token.is_keyword(Keyword::INTO) => None,
+            Some(Token::Word(_)) => Some(self.parse_identifier()?),
+            _ => None,
+        };
+        let into = self.parse_keyword_then(Keyword::INTO, Self::parse_expr)?;
+        Ok(VacuumStmt { schema, into })
+    }
+
+    /// Parses a `ANALYZE` statement.
+    pub fn parse_analyze_stmt(&mut self) -> Result<AnalyzeStmt, ParserError> {
+        self.expect_keyword(Keyword::ANALYZE)?;
+        Ok(AnalyzeStmt {
+            name: self.parse_optional_object_name()?,
+        })
+    }
+
+    /// Parses a `REINDEX` statement.
+    pub fn parse_reindex_stmt(&mut self) -> Result<ReindexStmt, ParserError> {
+        self.expect_keyword(Keyword::REINDEX)?;
+        Ok(ReindexStmt {
+            name: self.parse_optional_object_name()?,
+        })
+    }
+
+    /// Parses an object name if the next token is a word.
+    fn parse_optional_object_name(&mut self) -> Result<Option<ObjectName>, ParserError> {
+        match self.peek_token() {
+            Some(Token::Word(_)) => self.parse_object_name().map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_admin_stmt() -> Result<(), ParserError> {
+        let dialect = crate::sqlite::SqliteDialect::default();
+        let cases = [
+            ("PRAGMA foreign_keys = ON", "PRAGMA foreign_keys = ON"),
+            (
+                "PRAGMA main.journal_mode = 'wal'",
+                "PRAGMA main.journal_mode = 'wal'",
+            ),
+            ("PRAGMA cache_size = -2000", "PRAGMA cache_size = - 2000"),
+            ("PRAGMA table_info(t1)", "PRAGMA table_info(t1)"),
+            (
+                "PRAGMA main.cache_size(-2000)",
+                "PRAGMA main.cache_size(- 2000)",
+            ),
+            ("PRAGMA user_version", "PRAGMA user_version"),
+            ("ATTACH 'aux.db' AS aux", "ATTACH DATABASE 'aux.db' AS aux"),
+            ("DETACH DATABASE aux", "DETACH DATABASE aux"),
+            ("VACUUM", "VACUUM"),
+            (
+                "VACUUM main INTO 'backup.db'",
+                "VACUUM main INTO 'backup.db'",
+            ),
+            ("VACUUM INTO 'backup.db'", "VACUUM INTO 'backup.db'"),
+            ("ANALYZE", "ANALYZE"),
+            ("ANALYZE main.t1", "ANALYZE main.t1"),
+            ("REINDEX", "REINDEX"),
+            ("REINDEX nocase", "REINDEX nocase"),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                expected
+            );
+        }
+
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "PRAGMA foreign_keys = 1")?.parse_pragma_stmt()?,
+            PragmaStmt {
+                name: ObjectName::new(vec!["foreign_keys"]),
+                value: Some(Expr::Literal(Literal::Number("1".into()))),
+                parenthesized: false,
+            }
+        );
+
+        // these statements are only supported by SQLite
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert!(Parser::parse_script(&dialect, "VACUUM").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    ast::{statement::*, types::Ident},
+    dialect::Dialect,
+    error::ParserError,
+    keywords::Keyword,
+    parser::Parser,
+    tokens::{Token, Word},
+};
+
+/// The role attributes of PostgreSQL, most of them are not keywords.
+const ROLE_ATTRIBUTES: &[&str] = &[
+    "SUPERUSER",
+    "NOSUPERUSER",
+    "CREATEDB",
+    "NOCREATEDB",
+    "CREATEROLE",
+    "NOCREATEROLE",
+    "INHERIT",
+    "NOINHERIT",
+    "LOGIN",
+    "NOLOGIN",
+    "REPLICATION",
+    "NOREPLICATION",
+    "BYPASSRLS",
+    "NOBYPASSRLS",
+];
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses a `CREATE ROLE` or `CREATE USER` statement.
+    ///
+    /// ```txt
+    /// // PostgreSQL
+    /// CREATE { ROLE | USER } <role name> [ [ WITH ] <role option> [...] ]
+    ///
+    /// // MySQL
+    /// CREATE { ROLE | USER } [ IF NOT EXISTS ] <user name> [ <auth option> ] [, ...]
+    /// ```
+    pub fn parse_create_role_stmt(&mut self) -> Result<CreateRoleStmt, ParserError> {
+        self.expect_keyword(Keyword::CREATE)?;
+        let kind = self.parse_role_kind()?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let roles = self.parse_comma_separated(Self::parse_role_spec)?;
+        let options = self.parse_role_options()?;
+        Ok(CreateRoleStmt {
+            kind,
+            if_not_exists,
+            roles,
+            options,
+        })
+    }
+
+    fn parse_role_kind(&mut self) -> Result<RoleKind, ParserError> {
+        match self.expect_one_of_keywords(&[Keyword::ROLE, Keyword::USER])? {
+            Keyword::ROLE => Ok(RoleKind::Role),
+            Keyword::USER => Ok(RoleKind::User),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Parses a role name with its authentication option.
+    ///
+    /// ```txt
+    /// <user name> [ <auth option> ]
+    ///
+    /// <auth option> ::=
+    ///     IDENTIFIED BY <password>
+    ///     | IDENTIFIED WITH <auth plugin> [ BY <password> | AS <auth string> ]
+    /// ```
+    fn parse_role_spec(&mut self) -> Result<RoleSpec, ParserError> {
+        let name = self.parse_user_name()?;
+        let auth = if self.parse_keywords(&[Keyword::IDENTIFIED, Keyword::BY]) {
+            Some(AuthOption::Password(self.parse_password()?))
+        } else if self.parse_keywords(&[Keyword::IDENTIFIED, Keyword::WITH]) {
+            let plugin = self.parse_user_name_part()?;
+            if self.parse_keyword(Keyword::AS) {
+                let hash = self.parse_literal_string("authentication string")?;
+                Some(AuthOption::Hash { plugin, hash })
+            } else {
+                let password = self.parse_keyword_then(Keyword::BY, Self::parse_password)?;
+                Some(AuthOption::Plugin { plugin, password })
+            }
+        } else {
+            None
+        };
+        Ok(RoleSpec { name, auth })
+    }
+
+    fn parse_password(&mut self) -> Result<Password, ParserError> {
+        self.parse_literal_string("password").map(Password)
+    }
+
+    /// Parses the role options.
+    ///
+    /// ```txt
+    /// [ WITH ] <role option> [...]
+    /// ```
+    fn parse_role_options(&mut self) -> Result<Vec<RoleOption>, ParserError> {
+        self.parse_keyword(Keyword::WITH);
+        let mut options = vec![];
+        while let Some(option) = self.parse_role_option()? {
+            options.push(option);
+        }
+        Ok(options)
+    }
+
+    /// Parses a role option.
+    ///
+    /// ```txt
+    /// <role option> ::=
+    ///     SUPERUSER | NOSUPERUSER
+    ///     | CREATEDB | NOCREATEDB
+    ///     | CREATEROLE | NOCREATEROLE
+    ///     | INHERIT | NOINHERIT
+    ///     | LOGIN | NOLOGIN
+    ///     | REPLICATION | NOREPLICATION
+    ///     | BYPASSRLS | NOBYPASSRLS
+    ///     | CONNECTION LIMIT <connlimit>
+    ///     | [ ENCRYPTED ] PASSWORD { <password> | NULL }
+    ///     | VALID UNTIL <timestamp>
+    ///     | IN ROLE <role name> [, ...]
+    ///     | ROLE <role name> [, ...]
+    ///     | ADMIN <role name> [, ...]
+    /// ```
+    fn parse_role_option(&mut self) -> Result<Option<RoleOption>, ParserError> {
+        if let Some(Token::Word(Word {
+            value, quote: None, ..
+        })) = self.next_token_if(|token| match token {
+            Token::Word(Word {
+                value, quote: None, ..
+            }) => ROLE_ATTRIBUTES
+                .iter()
+                .any(|attribute| value.eq_ignore_ascii_case(attribute)),
+            _ => false,
+        }) {
+            return Ok(Some(RoleOption::Attribute(Ident::new(value))));
+        }
+        let option = if self.parse_keywords(&[Keyword::CONNECTION, Keyword::LIMIT]) {
+            RoleOption::ConnectionLimit(self.parse_expr()?)
+        } else if self.parse_keywords(&[Keyword::ENCRYPTED, Keyword::PASSWORD])
+            || self.parse_keyword(Keyword::PASSWORD)
+        {
+            if self.parse_keyword(Keyword::NULL) {
+                RoleOption::Password(None)
+            } else {
+                RoleOption::Password(Some(self.parse_password()?))
+            }
+        } else if self.parse_keywords(&[Keyword::VALID, Keyword::UNTIL]) {
+            RoleOption::ValidUntil(self.parse_literal_string("timestamp string")?)
+        } else if self.parse_keywords(&[Keyword::IN, Keyword::ROLE]) {
+            RoleOption::InRole(self.parse_comma_separated(Self::parse_identifier)?)
+        } else if self.parse_keyword(Keyword::ROLE) {
+            RoleOption::Role(self.parse_comma_separated(Self::parse_identifier)?)
+        } else if self.parse_keyword(Keyword::ADMIN) {
+            RoleOption::Admin(self.parse_comma_separated(Self::parse_identifier)?)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(option))
+    }
+
+    /// Parses an `ALTER ROLE` or `ALTER USER` statement.
+    ///
+    /// ```txt
+    /// ALTER { ROLE | USER } <role name> <alter role action>
+    /// ```
+    pub fn parse_alter_role_stmt(&mut self) -> Result<AlterRoleStmt, ParserError> {
+        self.expect_keyword(Keyword::ALTER)?;
+        let kind = self.parse_role_kind()?;
+        let name = self.parse_user_name()?;
+        let action = self.parse_alter_role_action()?;
+        Ok(AlterRoleStmt { kind, name, action })
+    }
+
+    /// Parses an alter role action.
+    ///
+    /// ```txt
+    /// <alter role action> ::=
+    ///     [ WITH ] <role option> [...]
+    ///     | RENAME TO <new role name>
+    ///     | [ IN DATABASE <database name> ] SET <parameter> { TO | = } { <value> [, ...] | DEFAULT }
+    ///     | [ IN DATABASE <database name> ] RESET { <parameter> | ALL }
+    /// ```
+    fn parse_alter_role_action(&mut self) -> Result<AlterRoleAction, ParserError> {
+        if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            return Ok(AlterRoleAction::RenameTo(self.parse_identifier()?));
+        }
+        let database = if self.parse_keywords(&[Keyword::IN, Keyword::DATABASE]) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+        if self.parse_keyword(Keyword::SET) {
+            let name = self.parse_object_name()?;
+            if !self.parse_keyword(Keyword::TO) && !self.next_token_if_is(&Token::Equal) {
+                let found = self.peek_token().cloned();
+                return self.expected("TO or =", found);
+            }
+            let values = if self.parse_keyword(Keyword::DEFAULT) {
+                None
+            } else {
+                Some(self.parse_comma_separated(Self::parse_expr)?)
+            };
+            Ok(AlterRoleAction::Set {
+                database,
+                name,
+                values,
+            })
+        } else if self.parse_keyword(Keyword::RESET) {
+            let name = if self.parse_keyword(Keyword::ALL) {
+                None
+            } else {
+                Some(self.parse_object_name()?)
+            };
+            Ok(AlterRoleAction::Reset { database, name })
+        } else if database.is_some() {
+            let found = self.peek_token().cloned();
+            self.expected("SET or RESET", found)
+        } else {
+            let options = self.parse_role_options()?;
+            if options.is_empty() {
+                let found = self.peek_token().cloned();
+                return self.expected("role option, RENAME TO, SET or RESET", found);
+            }
+            Ok(AlterRoleAction::Options(options))
+        }
+    }
+
+    /// Parses a `DROP ROLE` or `DROP USER` statement.
+    ///
+    /// ```txt
+    /// DROP { ROLE | USER } [ IF EXISTS ] <user name> [, ...]
+    /// ```
+    pub fn parse_drop_role_stmt(&mut self) -> Result<DropRoleStmt, ParserError> {
+        self.expect_keyword(Keyword::DROP)?;
+        let kind = self.parse_role_kind()?;
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let names = self.parse_comma_separated(Self::parse_user_name)?;
+        Ok(DropRoleStmt {
+            kind,
+            if_exists,
+            names,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{expression::Expr, types::Literal};
+
+    #[test]
+    fn parse_create_role_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "CREATE ROLE admin WITH login NOSUPERUSER CONNECTION LIMIT 10 \
+            ENCRYPTED PASSWORD 'secret' VALID UNTIL '2030-01-01' IN ROLE staff, ops";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        assert_eq!(
+            stmt,
+            Stmt::CreateRole(CreateRoleStmt {
+                kind: RoleKind::Role,
+                if_not_exists: false,
+                roles: vec![RoleSpec {
+                    name: UserName {
+                        user: Ident::new("admin"),
+                        host: None,
+                    },
+                    auth: None,
+                }],
+                options: vec![
+                    RoleOption::Attribute(Ident::new("login")),
+                    RoleOption::Attribute(Ident::new("NOSUPERUSER")),
+                    RoleOption::ConnectionLimit(Expr::Literal(Literal::Number("10".into()))),
+                    RoleOption::Password(Some(Password("secret".into()))),
+                    RoleOption::ValidUntil("2030-01-01".into()),
+                    RoleOption::InRole(vec![Ident::new("staff"), Ident::new("ops")]),
+                ],
+            })
+        );
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE ROLE admin WITH login NOSUPERUSER CONNECTION LIMIT 10 \
+            PASSWORD 'secret' VALID UNTIL '2030-01-01' IN ROLE staff, ops"
+        );
+        let sql = "CREATE USER bob PASSWORD NULL";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?
+                .parse_stmt()?
+                .to_string(),
+            "CREATE USER bob WITH PASSWORD NULL"
+        );
+
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "CREATE USER IF NOT EXISTS 'u'@'%' IDENTIFIED BY 'pw', \
+            v IDENTIFIED WITH caching_sha2_password BY 'pw', \
+            'w'@'localhost' IDENTIFIED WITH 'mysql_native_password' AS '*ABC', x";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_create_role_stmt()?;
+        assert!(stmt.if_not_exists);
+        assert_eq!(
+            stmt.roles[0],
+            RoleSpec {
+                name: UserName {
+                    user: Ident::with_quote('\'', "u"),
+                    host: Some(Ident::with_quote('\'', "%")),
+                },
+                auth: Some(AuthOption::Password(Password("pw".into()))),
+            }
+        );
+        assert_eq!(stmt.roles[3].auth, None);
+        assert_eq!(stmt.to_string(), sql);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_alter_role_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let cases = [
+            (
+                "ALTER ROLE admin NOLOGIN PASSWORD 'x'",
+                "ALTER ROLE admin WITH NOLOGIN PASSWORD 'x'",
+            ),
+            (
+                "ALTER ROLE admin RENAME TO root",
+                "ALTER ROLE admin RENAME TO root",
+            ),
+            (
+                "ALTER ROLE admin IN DATABASE db SET search_path = public, \"$user\"",
+                "ALTER ROLE admin IN DATABASE db SET search_path TO public, \"$user\"",
+            ),
+            (
+                "ALTER USER CURRENT_USER SET work_mem TO DEFAULT",
+                "ALTER USER CURRENT_USER SET work_mem TO DEFAULT",
+            ),
+            ("ALTER ROLE admin RESET ALL", "ALTER ROLE admin RESET ALL"),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                expected
+            );
+        }
+        for sql in [
+            "ALTER ROLE admin",
+            "ALTER ROLE admin IN DATABASE db NOLOGIN",
+            "ALTER ROLE admin SET work_mem 1",
+        ] {
+            assert!(Parser::new_with_sql(&dialect, sql)?.parse_stmt().is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_drop_role_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "DROP USER IF EXISTS 'u'@'%', v";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        assert_eq!(
+            stmt,
+            Stmt::DropRole(DropRoleStmt {
+                kind: RoleKind::User,
+                if_exists: true,
+                names: vec![
+                    UserName {
+                        user: Ident::with_quote('\'', "u"),
+                        host: Some(Ident::with_quote('\'', "%")),
+                    },
+                    UserName {
+                        user: Ident::new("v"),
+                        host: None,
+                    },
+                ],
+            })
+        );
+        assert_eq!(stmt.to_string(), sql);
+        Ok(())
+    }
+}
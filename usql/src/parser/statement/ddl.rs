@@ -2,7 +2,11 @@
 use alloc::{boxed::Box, vec, vec::Vec};
 
 use crate::{
-    ast::statement::*, dialect::Dialect, error::ParserError, keywords::Keyword, parser::Parser,
+    ast::{expression::Expr, statement::*, types::Ident},
+    dialect::{Capability, Dialect, DialectParserConf},
+    error::{unsupported_capability, ParserError},
+    keywords::Keyword,
+    parser::Parser,
     tokens::Token,
 };
 
@@ -34,6 +38,12 @@ impl<'a, D: Dialect> Parser<'a, D> {
 
         let name = self.parse_object_name()?;
         let content = self.parse_table_content()?;
+        let partition_by = self.parse_partition_scheme()?;
+        let system_versioning =
+            self.parse_keywords(&[Keyword::WITH, Keyword::SYSTEM, Keyword::VERSIONING]);
+        if system_versioning && !self.dialect.parser_conf().supports_system_versioning() {
+            return unsupported_capability(Capability::SystemVersioning);
+        }
 
         let on_commit = if self.parse_keywords(&[Keyword::ON, Keyword::COMMIT]) {
             match self.expect_one_of_keywords(&[
@@ -61,6 +71,8 @@ impl<'a, D: Dialect> Parser<'a, D> {
             if_not_exists,
             name,
             content,
+            partition_by,
+            system_versioning,
             on_commit,
         })
     }
@@ -91,6 +103,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
     ///     ( <column definition> [, ...] [, ] [ <table constraint definition> [, ...] ] )
     ///     | LIKE <table name> [ <like option> [, ...] ]
     ///     | AS { ( <query expression> ) | <query expression> }
+    ///     | PARTITION OF <parent table name> <partition bound> // PostgreSQL
     /// ```
     fn parse_table_content(&mut self) -> Result<TableContent, ParserError> {
         if self.next_token_if_is(&Token::LeftParen) {
@@ -128,12 +141,316 @@ impl<'a, D: Dialect> Parser<'a, D> {
             let query = self.parse_query_expr(true)?;
             self.next_token_if_is(&Token::RightParen);
             Ok(TableContent::SubQuery(Box::new(query)))
+        } else if self.parse_keywords(&[Keyword::PARTITION, Keyword::OF]) {
+            let parent = self.parse_object_name()?;
+            let bound = self.parse_partition_bound()?;
+            Ok(TableContent::PartitionOf { parent, bound })
         } else {
             let found = self.peek_token().cloned();
             self.expected("table content", found)
         }
     }
 
+    /// Parses the partitioning of a table.
+    ///
+    /// ```txt
+    /// <partition scheme> ::=
+    ///     PARTITION BY <partition strategy> ( <expr> [, ...] )
+    ///         [ PARTITIONS <number> ] // MySQL
+    ///         [ SUBPARTITION BY <partition strategy> ( <expr> [, ...] ) [ SUBPARTITIONS <number> ] ] // MySQL
+    ///         [ ( <partition definition> [, ...] ) ] // MySQL
+    /// ```
+    fn parse_partition_scheme(&mut self) -> Result<Option<PartitionScheme>, ParserError> {
+        if !self.parse_keywords(&[Keyword::PARTITION, Keyword::BY]) {
+            return Ok(None);
+        }
+        let strategy = self.parse_partition_strategy()?;
+        let keys = self.parse_partition_keys(strategy)?;
+        // `PARTITIONS`, `SUBPARTITION` and `SUBPARTITIONS` are keywords of MySQL only
+        let partitions = self.parse_keyword_then(Keyword::PARTITIONS, Self::parse_literal_uint)?;
+        let subpartition_by = if self.parse_keywords(&[Keyword::SUBPARTITION, Keyword::BY]) {
+            let strategy = self.parse_partition_strategy()?;
+            let keys = self.parse_partition_keys(strategy)?;
+            let subpartitions =
+                self.parse_keyword_then(Keyword::SUBPARTITIONS, Self::parse_literal_uint)?;
+            Some(SubpartitionScheme {
+                strategy,
+                keys,
+                subpartitions,
+            })
+        } else {
+            None
+        };
+        let definitions = if self.peek_token() == Some(&Token::LeftParen) {
+            if !self.dialect.parser_conf().supports_partition_definitions() {
+                return unsupported_capability(Capability::PartitionDefinitions);
+            }
+            self.parse_parenthesized_comma_separated(Self::parse_partition_def, false)?
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
+        Ok(Some(PartitionScheme {
+            strategy,
+            keys,
+            partitions,
+            subpartition_by,
+            definitions,
+        }))
+    }
+
+    /// Parses a partition strategy.
+    ///
+    /// ```txt
+    /// <partition strategy> ::=
+    ///     RANGE | LIST | HASH
+    ///     | RANGE COLUMNS | LIST COLUMNS | LINEAR HASH // MySQL
+    ///     | [ LINEAR ] KEY [ ALGORITHM = { 1 | 2 } ] // MySQL
+    /// ```
+    fn parse_partition_strategy(&mut self) -> Result<PartitionStrategy, ParserError> {
+        let mysql = self.dialect.parser_conf().supports_partition_definitions();
+        // `LIST` and `HASH` are not keywords of PostgreSQL, `LINEAR` is a keyword of MySQL only
+        if self.parse_keyword(Keyword::RANGE) {
+            if mysql && self.parse_keyword(Keyword::COLUMNS) {
+                Ok(PartitionStrategy::RangeColumns)
+            } else {
+                Ok(PartitionStrategy::Range)
+            }
+        } else if self.parse_word("LIST") {
+            if mysql && self.parse_keyword(Keyword::COLUMNS) {
+                Ok(PartitionStrategy::ListColumns)
+            } else {
+                Ok(PartitionStrategy::List)
+            }
+        } else if self.parse_word("HASH") {
+            Ok(PartitionStrategy::Hash)
+        } else if self.parse_keyword(Keyword::LINEAR) {
+            if self.parse_word("HASH") {
+                Ok(PartitionStrategy::LinearHash)
+            } else {
+                self.expect_keyword(Keyword::KEY)?;
+                self.parse_key_partition_strategy(true)
+            }
+        } else if mysql && self.parse_keyword(Keyword::KEY) {
+            self.parse_key_partition_strategy(false)
+        } else {
+            let found = self.peek_token().cloned();
+            self.expected("RANGE, LIST or HASH", found)
+        }
+    }
+
+    fn parse_key_partition_strategy(
+        &mut self,
+        linear: bool,
+    ) -> Result<PartitionStrategy, ParserError> {
+        let algorithm = if self.parse_keyword(Keyword::ALGORITHM) {
+            self.expect_token(&Token::Equal)?;
+            Some(self.parse_literal_uint()?)
+        } else {
+            None
+        };
+        Ok(PartitionStrategy::Key { linear, algorithm })
+    }
+
+    /// Parses the partition keys, which can be empty for the `KEY` strategy (the primary key
+    /// is used).
+    fn parse_partition_keys(
+        &mut self,
+        strategy: PartitionStrategy,
+    ) -> Result<Vec<Expr>, ParserError> {
+        self.expect_token(&Token::LeftParen)?;
+        if matches!(strategy, PartitionStrategy::Key { .. })
+            && self.next_token_if_is(&Token::RightParen)
+        {
+            return Ok(vec![]);
+        }
+        let keys = self.parse_comma_separated(Self::parse_expr)?;
+        self.expect_token(&Token::RightParen)?;
+        Ok(keys)
+    }
+
+    /// Parses a partition definition.
+    ///
+    /// ```txt
+    /// <partition definition> ::=
+    ///     PARTITION <partition name>
+    ///         [ VALUES { LESS THAN { ( <range value> [, ...] ) | MAXVALUE } | IN ( <expr> [, ...] ) } ]
+    ///         [ <partition option> [...] ]
+    ///         [ ( <subpartition definition> [, ...] ) ]
+    /// ```
+    fn parse_partition_def(&mut self) -> Result<PartitionDef, ParserError> {
+        self.expect_keyword(Keyword::PARTITION)?;
+        let name = self.parse_identifier()?;
+        let values = if self.parse_keyword(Keyword::VALUES) {
+            if self.parse_keywords(&[Keyword::LESS, Keyword::THAN]) {
+                if self.parse_keyword(Keyword::MAXVALUE) {
+                    Some(PartitionValues::LessThan(vec![
+                        PartitionRangeValue::MaxValue,
+                    ]))
+                } else {
+                    let values = self.parse_parenthesized_comma_separated(
+                        Self::parse_partition_range_value,
+                        false,
+                    )?;
+                    Some(PartitionValues::LessThan(values.unwrap_or_default()))
+                }
+            } else {
+                self.expect_keyword(Keyword::IN)?;
+                let values = self.parse_parenthesized_comma_separated(Self::parse_expr, false)?;
+                Some(PartitionValues::In(values.unwrap_or_default()))
+            }
+        } else {
+            None
+        };
+        let options = self.parse_partition_options()?;
+        let subpartitions = self
+            .parse_parenthesized_comma_separated(Self::parse_subpartition_def, true)?
+            .unwrap_or_default();
+        Ok(PartitionDef {
+            name,
+            values,
+            options,
+            subpartitions,
+        })
+    }
+
+    /// Parses a subpartition definition.
+    ///
+    /// ```txt
+    /// <subpartition definition> ::= SUBPARTITION <subpartition name> [ <partition option> [...] ]
+    /// ```
+    fn parse_subpartition_def(&mut self) -> Result<SubpartitionDef, ParserError> {
+        self.expect_keyword(Keyword::SUBPARTITION)?;
+        let name = self.parse_identifier()?;
+        let options = self.parse_partition_options()?;
+        Ok(SubpartitionDef { name, options })
+    }
+
+    /// Parses the options of a partition or subpartition.
+    ///
+    /// ```txt
+    /// <partition option> ::=
+    ///     [ STORAGE ] ENGINE [ = ] <engine name>
+    ///     | COMMENT [ = ] <string literal>
+    ///     | DATA DIRECTORY [ = ] <string literal>
+    ///     | INDEX DIRECTORY [ = ] <string literal>
+    ///     | MAX_ROWS [ = ] <number>
+    ///     | MIN_ROWS [ = ] <number>
+    ///     | TABLESPACE [ = ] <tablespace name>
+    /// ```
+    fn parse_partition_options(&mut self) -> Result<Vec<PartitionOption>, ParserError> {
+        let mut options = vec![];
+        loop {
+            let option = match self.parse_one_of_keywords(&[
+                Keyword::STORAGE,
+                Keyword::ENGINE,
+                Keyword::COMMENT,
+                Keyword::DATA,
+                Keyword::INDEX,
+                Keyword::MAX_ROWS,
+                Keyword::MIN_ROWS,
+                Keyword::TABLESPACE,
+            ]) {
+                Some(Keyword::STORAGE) => {
+                    self.expect_keyword(Keyword::ENGINE)?;
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::Engine(self.parse_identifier()?)
+                }
+                Some(Keyword::ENGINE) => {
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::Engine(self.parse_identifier()?)
+                }
+                Some(Keyword::COMMENT) => {
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::Comment(self.parse_literal_string("comment")?)
+                }
+                Some(Keyword::DATA) => {
+                    self.expect_keyword(Keyword::DIRECTORY)?;
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::DataDirectory(self.parse_literal_string("directory")?)
+                }
+                Some(Keyword::INDEX) => {
+                    self.expect_keyword(Keyword::DIRECTORY)?;
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::IndexDirectory(self.parse_literal_string("directory")?)
+                }
+                Some(Keyword::MAX_ROWS) => {
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::MaxRows(self.parse_literal_uint()?)
+                }
+                Some(Keyword::MIN_ROWS) => {
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::MinRows(self.parse_literal_uint()?)
+                }
+                Some(Keyword::TABLESPACE) => {
+                    self.next_token_if_is(&Token::Equal);
+                    PartitionOption::Tablespace(self.parse_identifier()?)
+                }
+                _ => return Ok(options),
+            };
+            options.push(option);
+        }
+    }
+
+    /// Parses a partition bound.
+    ///
+    /// ```txt
+    /// <partition bound> ::=
+    ///     FOR VALUES IN ( <expr> [, ...] )
+    ///     | FOR VALUES FROM ( <range value> [, ...] ) TO ( <range value> [, ...] )
+    ///     | FOR VALUES WITH ( MODULUS <numeric literal>, REMAINDER <numeric literal> )
+    ///     | DEFAULT
+    /// ```
+    fn parse_partition_bound(&mut self) -> Result<PartitionBound, ParserError> {
+        if self.parse_keyword(Keyword::DEFAULT) {
+            return Ok(PartitionBound::Default);
+        }
+        self.expect_keywords(&[Keyword::FOR, Keyword::VALUES])?;
+        match self.expect_one_of_keywords(&[Keyword::IN, Keyword::FROM, Keyword::WITH])? {
+            Keyword::IN => {
+                let values = self.parse_parenthesized_comma_separated(Self::parse_expr, false)?;
+                Ok(PartitionBound::In(values.unwrap_or_default()))
+            }
+            Keyword::FROM => {
+                let from = self.parse_parenthesized_comma_separated(
+                    Self::parse_partition_range_value,
+                    false,
+                )?;
+                self.expect_keyword(Keyword::TO)?;
+                let to = self.parse_parenthesized_comma_separated(
+                    Self::parse_partition_range_value,
+                    false,
+                )?;
+                Ok(PartitionBound::Range {
+                    from: from.unwrap_or_default(),
+                    to: to.unwrap_or_default(),
+                })
+            }
+            Keyword::WITH => {
+                // `MODULUS` and `REMAINDER` are not keywords of PostgreSQL
+                self.expect_token(&Token::LeftParen)?;
+                self.expect_word("MODULUS")?;
+                let modulus = self.parse_literal_uint()?;
+                self.expect_token(&Token::Comma)?;
+                self.expect_word("REMAINDER")?;
+                let remainder = self.parse_literal_uint()?;
+                self.expect_token(&Token::RightParen)?;
+                Ok(PartitionBound::Hash { modulus, remainder })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_partition_range_value(&mut self) -> Result<PartitionRangeValue, ParserError> {
+        if self.parse_keyword(Keyword::MINVALUE) {
+            Ok(PartitionRangeValue::MinValue)
+        } else if self.parse_keyword(Keyword::MAXVALUE) {
+            Ok(PartitionRangeValue::MaxValue)
+        } else {
+            Ok(PartitionRangeValue::Expr(self.parse_expr()?))
+        }
+    }
+
     /// Parses a column definition.
     ///
     /// ```txt
@@ -248,11 +565,19 @@ impl<'a, D: Dialect> Parser<'a, D> {
         if self.parse_keyword(Keyword::NULL) {
             Ok(Some(ColumnConstraint::Null))
         } else if self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
-            Ok(Some(ColumnConstraint::NotNull))
+            Ok(Some(ColumnConstraint::NotNull {
+                on_conflict: self.parse_on_conflict_clause()?,
+            }))
         } else if self.parse_keyword(Keyword::UNIQUE) {
-            Ok(Some(ColumnConstraint::Unique { is_primary: false }))
+            Ok(Some(ColumnConstraint::Unique {
+                is_primary: false,
+                on_conflict: self.parse_on_conflict_clause()?,
+            }))
         } else if self.parse_keywords(&[Keyword::PRIMARY, Keyword::KEY]) {
-            Ok(Some(ColumnConstraint::Unique { is_primary: true }))
+            Ok(Some(ColumnConstraint::Unique {
+                is_primary: true,
+                on_conflict: self.parse_on_conflict_clause()?,
+            }))
         } else if self.parse_keyword(Keyword::CHECK) {
             self.expect_token(&Token::LeftParen)?;
             let expr = Box::new(self.parse_expr()?);
@@ -310,6 +635,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
             Ok(Some(TableConstraint::Unique {
                 is_primary: false,
                 columns,
+                on_conflict: self.parse_on_conflict_clause()?,
             }))
         } else if self.parse_keywords(&[Keyword::PRIMARY, Keyword::KEY]) {
             self.expect_token(&Token::LeftParen)?;
@@ -318,6 +644,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
             Ok(Some(TableConstraint::Unique {
                 is_primary: true,
                 columns,
+                on_conflict: self.parse_on_conflict_clause()?,
             }))
         } else if self.parse_keyword(Keyword::CHECK) {
             self.expect_token(&Token::LeftParen)?;
@@ -347,6 +674,21 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }
     }
 
+    /// Parses an optional `ON CONFLICT` clause of constraints if the dialect supports it.
+    ///
+    /// ```txt
+    /// ON CONFLICT <conflict resolution>
+    /// ```
+    fn parse_on_conflict_clause(&mut self) -> Result<Option<ConflictResolution>, ParserError> {
+        if self.dialect.parser_conf().supports_conflict_clause()
+            && self.parse_keywords(&[Keyword::ON, Keyword::CONFLICT])
+        {
+            Ok(Some(self.parse_conflict_resolution()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn parse_referential_match_type(
         &mut self,
     ) -> Result<Option<ReferentialMatchType>, ParserError> {
@@ -463,10 +805,25 @@ impl<'a, D: Dialect> Parser<'a, D> {
     ///     | <drop table period definition>
     ///     | <add system versioning clause>
     ///     | <drop system versioning clause>
+    ///     | RENAME TO <new table name> // Non-standard
+    ///     | { ENABLE | DISABLE | FORCE | NO FORCE } ROW LEVEL SECURITY // PostgreSQL
+    ///     | ATTACH PARTITION <partition name> <partition bound> // PostgreSQL
+    ///     | DETACH PARTITION <partition name> [ CONCURRENTLY | FINALIZE ] // PostgreSQL
     /// ```
     fn parse_alter_table_action(&mut self) -> Result<AlterTableAction, ParserError> {
         // we support <add column> and <drop column> now yet
-        if self.parse_keyword(Keyword::ADD) {
+        let system_versioning = self.dialect.parser_conf().supports_system_versioning();
+        if self.parse_keywords(&[Keyword::ADD, Keyword::SYSTEM, Keyword::VERSIONING]) {
+            if !system_versioning {
+                return unsupported_capability(Capability::SystemVersioning);
+            }
+            Ok(AlterTableAction::AddSystemVersioning)
+        } else if self.parse_keywords(&[Keyword::DROP, Keyword::SYSTEM, Keyword::VERSIONING]) {
+            if !system_versioning {
+                return unsupported_capability(Capability::SystemVersioning);
+            }
+            Ok(AlterTableAction::DropSystemVersioning)
+        } else if self.parse_keyword(Keyword::ADD) {
             self.parse_keyword(Keyword::COLUMN);
             let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
             let column = self.parse_column_def()?;
@@ -484,12 +841,57 @@ impl<'a, D: Dialect> Parser<'a, D> {
                 name,
                 drop_behavior,
             })
+        } else if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            Ok(AlterTableAction::RenameTo(self.parse_object_name()?))
+        } else if self.parse_keywords(&[Keyword::ENABLE, Keyword::ROW, Keyword::LEVEL]) {
+            self.expect_keyword(Keyword::SECURITY)?;
+            Ok(AlterTableAction::EnableRowLevelSecurity)
+        } else if self.parse_keywords(&[Keyword::DISABLE, Keyword::ROW, Keyword::LEVEL]) {
+            self.expect_keyword(Keyword::SECURITY)?;
+            Ok(AlterTableAction::DisableRowLevelSecurity)
+        } else if self.parse_keywords(&[Keyword::FORCE, Keyword::ROW, Keyword::LEVEL]) {
+            self.expect_keyword(Keyword::SECURITY)?;
+            Ok(AlterTableAction::ForceRowLevelSecurity)
+        } else if self.parse_keywords(&[Keyword::NO, Keyword::FORCE, Keyword::ROW]) {
+            self.expect_keywords(&[Keyword::LEVEL, Keyword::SECURITY])?;
+            Ok(AlterTableAction::NoForceRowLevelSecurity)
+        } else if self.parse_keywords(&[Keyword::ATTACH, Keyword::PARTITION]) {
+            let name = self.parse_object_name()?;
+            let bound = self.parse_partition_bound()?;
+            Ok(AlterTableAction::AttachPartition { name, bound })
+        } else if self.parse_keywords(&[Keyword::DETACH, Keyword::PARTITION]) {
+            let name = self.parse_object_name()?;
+            // `FINALIZE` is not a keyword of PostgreSQL
+            let mode = if self.parse_keyword(Keyword::CONCURRENTLY) {
+                Some(DetachPartitionMode::Concurrently)
+            } else if self.parse_word("FINALIZE") {
+                Some(DetachPartitionMode::Finalize)
+            } else {
+                None
+            };
+            Ok(AlterTableAction::DetachPartition { name, mode })
         } else {
             let found = self.peek_token().cloned();
-            self.expected("ADD COLUMN or DROP COLUMN", found)
+            self.expected("ADD COLUMN, DROP COLUMN or RENAME TO", found)
         }
     }
 
+    /// Parses a `RENAME TABLE` statement.
+    ///
+    /// ```txt
+    /// RENAME TABLE <table name> TO <new table name> [, <table name> TO <new table name> ...]
+    /// ```
+    pub fn parse_rename_table_stmt(&mut self) -> Result<RenameTableStmt, ParserError> {
+        self.expect_keywords(&[Keyword::RENAME, Keyword::TABLE])?;
+        let tables = self.parse_comma_separated(|parser| {
+            let from = parser.parse_object_name()?;
+            parser.expect_keyword(Keyword::TO)?;
+            let to = parser.parse_object_name()?;
+            Ok(RenameTable { from, to })
+        })?;
+        Ok(RenameTableStmt { tables })
+    }
+
     // ========================================================================
     // view definition
     // ========================================================================
@@ -507,30 +909,144 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// // Not support now
     /// <referencable view specification> ::= OF <user-defined type name> [ UNDER <table name> ] [ ( <view element> [, ...] ) ]
     /// <view element> ::= <self-referencing column specification> | <view column option>
+    ///
+    /// // MySQL
+    /// CREATE [ OR REPLACE ] [ ALGORITHM = { UNDEFINED | MERGE | TEMPTABLE } ]
+    ///     [ DEFINER = <user name> ] [ SQL SECURITY { DEFINER | INVOKER } ]
+    ///     VIEW <view name> [ ( <column name> [, ...] ) ]
+    ///     AS <query expression> [ WITH [ CASCADED | LOCAL ] CHECK OPTION ]
+    ///
+    /// // PostgreSQL
+    /// CREATE [ OR REPLACE ] [ RECURSIVE ] VIEW <view name> [ ( <column name> [, ...] ) ]
+    ///     [ WITH ( <view option name> [ = <view option value> ] [, ...] ) ]
+    ///     AS <query expression> [ WITH [ CASCADED | LOCAL ] CHECK OPTION ]
     /// ```
     pub fn parse_create_view_stmt(&mut self) -> Result<CreateViewStmt, ParserError> {
         self.expect_keyword(Keyword::CREATE)?;
         let or_replace = self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+        let algorithm = if self.parse_keyword(Keyword::ALGORITHM) {
+            self.expect_token(&Token::Equal)?;
+            Some(self.parse_view_algorithm()?)
+        } else {
+            None
+        };
+        let definer = if self.parse_keyword(Keyword::DEFINER) {
+            self.expect_token(&Token::Equal)?;
+            Some(self.parse_user_name()?)
+        } else {
+            None
+        };
+        let sql_security = if self.parse_keywords(&[Keyword::SQL, Keyword::SECURITY]) {
+            Some(self.parse_sql_security()?)
+        } else {
+            None
+        };
         let recursive = self.parse_keyword(Keyword::RECURSIVE);
         self.expect_keyword(Keyword::VIEW)?;
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
 
         let name = self.parse_object_name()?;
         let columns = self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?;
+        let options = if self.parse_keyword(Keyword::WITH) {
+            self.expect_token(&Token::LeftParen)?;
+            let options = self.parse_comma_separated(Self::parse_view_option)?;
+            self.expect_token(&Token::RightParen)?;
+            options
+        } else {
+            vec![]
+        };
         self.expect_keyword(Keyword::AS)?;
         let query = Box::new(self.parse_query_expr(true)?);
         let check_option = self.parse_view_check_option()?;
         Ok(CreateViewStmt {
             or_replace,
+            algorithm,
+            definer,
+            sql_security,
             recursive,
             if_not_exists,
             name,
             columns,
+            options,
             query,
             check_option,
         })
     }
 
+    fn parse_view_algorithm(&mut self) -> Result<ViewAlgorithm, ParserError> {
+        match self.parse_one_of_keywords(&[Keyword::UNDEFINED, Keyword::MERGE, Keyword::TEMPTABLE])
+        {
+            Some(Keyword::UNDEFINED) => Ok(ViewAlgorithm::Undefined),
+            Some(Keyword::MERGE) => Ok(ViewAlgorithm::Merge),
+            Some(Keyword::TEMPTABLE) => Ok(ViewAlgorithm::TempTable),
+            _ => {
+                let found = self.peek_token().cloned();
+                self.expected("UNDEFINED, MERGE or TEMPTABLE", found)
+            }
+        }
+    }
+
+    pub(crate) fn parse_sql_security(&mut self) -> Result<SqlSecurity, ParserError> {
+        match self.parse_one_of_keywords(&[Keyword::DEFINER, Keyword::INVOKER]) {
+            Some(Keyword::DEFINER) => Ok(SqlSecurity::Definer),
+            Some(Keyword::INVOKER) => Ok(SqlSecurity::Invoker),
+            _ => {
+                let found = self.peek_token().cloned();
+                self.expected("DEFINER or INVOKER", found)
+            }
+        }
+    }
+
+    /// Parses a user name.
+    ///
+    /// ```txt
+    /// <user name> ::= <user> [ @ <host> ] | CURRENT_USER [ ( ) ]
+    /// ```
+    pub fn parse_user_name(&mut self) -> Result<UserName, ParserError> {
+        if self.parse_keyword(Keyword::CURRENT_USER) {
+            if self.next_token_if_is(&Token::LeftParen) {
+                self.expect_token(&Token::RightParen)?;
+            }
+            return Ok(UserName {
+                user: Ident::new("CURRENT_USER"),
+                host: None,
+            });
+        }
+        let user = self.parse_user_name_part()?;
+        let host = if self.next_token_if_is(&Token::At) {
+            Some(self.parse_user_name_part()?)
+        } else {
+            None
+        };
+        Ok(UserName { user, host })
+    }
+
+    pub(crate) fn parse_user_name_part(&mut self) -> Result<Ident, ParserError> {
+        match self.next_token() {
+            Some(Token::String(s)) => Ok(Ident::with_quote('\'', s)),
+            Some(Token::Word(w)) => Ok(Ident {
+                value: w.value,
+                quote: w.quote,
+            }),
+            unexpected => self.expected("user name", unexpected),
+        }
+    }
+
+    /// Parses a view option.
+    ///
+    /// ```txt
+    /// <view option> ::= <view option name> [ = <view option value> ]
+    /// ```
+    fn parse_view_option(&mut self) -> Result<ViewOption, ParserError> {
+        let name = self.parse_identifier()?;
+        let value = if self.next_token_if_is(&Token::Equal) {
+            Some(Box::new(self.parse_expr()?))
+        } else {
+            None
+        };
+        Ok(ViewOption { name, value })
+    }
+
     /// Parses `WITH [ CASCADED | LOCAL ] CHECK OPTION`
     fn parse_view_check_option(&mut self) -> Result<Option<ViewCheckOption>, ParserError> {
         if self.parse_keyword(Keyword::WITH) {
@@ -551,6 +1067,33 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }
     }
 
+    /// Parses a `ALTER VIEW` statement.
+    ///
+    /// ```txt
+    /// // MySQL
+    /// ALTER VIEW <view name> [ ( <column name> [, ...] ) ] AS <query expression>
+    /// // PostgreSQL
+    /// ALTER VIEW [ IF EXISTS ] <view name> RENAME TO <new view name>
+    /// ```
+    pub fn parse_alter_view_stmt(&mut self) -> Result<AlterViewStmt, ParserError> {
+        self.expect_keywords(&[Keyword::ALTER, Keyword::VIEW])?;
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        let action = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterViewAction::RenameTo(self.parse_identifier()?)
+        } else {
+            let columns = self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?;
+            self.expect_keyword(Keyword::AS)?;
+            let query = Box::new(self.parse_query_expr(true)?);
+            AlterViewAction::As { columns, query }
+        };
+        Ok(AlterViewStmt {
+            if_exists,
+            name,
+            action,
+        })
+    }
+
     // ========================================================================
     // domain definition
     // ========================================================================
@@ -683,11 +1226,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
     pub fn parse_create_type_stmt(&mut self) -> Result<CreateTypeStmt, ParserError> {
         self.expect_keywords(&[Keyword::CREATE, Keyword::TYPE])?;
         let name = self.parse_object_name()?;
-        let super_name = if self.parse_keyword(Keyword::UNDER) {
-            Some(self.parse_object_name()?)
-        } else {
-            None
-        };
+        let super_name = self.parse_keyword_then(Keyword::UNDER, Self::parse_object_name)?;
         let representation = self.parse_type_representation()?;
         let options =
             self.parse_optional_comma_separated(Self::parse_type_option, "type option")?;
@@ -778,16 +1317,8 @@ impl<'a, D: Dialect> Parser<'a, D> {
     fn parse_type_attribute_def(&mut self) -> Result<TypeAttributeDef, ParserError> {
         let name = self.parse_identifier()?;
         let data_type = self.parse_data_type()?;
-        let default = if self.parse_keyword(Keyword::DEFAULT) {
-            Some(self.parse_literal()?)
-        } else {
-            None
-        };
-        let collation = if self.parse_keyword(Keyword::COLLATE) {
-            Some(self.parse_object_name()?)
-        } else {
-            None
-        };
+        let default = self.parse_keyword_then(Keyword::DEFAULT, Self::parse_literal)?;
+        let collation = self.parse_keyword_then(Keyword::COLLATE, Self::parse_object_name)?;
         Ok(TypeAttributeDef {
             name,
             data_type,
@@ -832,80 +1363,588 @@ impl<'a, D: Dialect> Parser<'a, D> {
     }
 
     // ========================================================================
-    // drop statement
+    // schema manipulation
     // ========================================================================
 
-    /// Parses a `DROP { SCHEMA | TABLE | VIEW | DOMAIN | TYPE | DATABASE | INDEX }` statement.
+    /// Parses a `ALTER SCHEMA` statement.
     ///
     /// ```txt
-    /// <drop schema statement> ::= DROP SCHEMA <schema name> [ IF EXISTS ] <drop behavior>
-    /// <drop table statement> ::= DROP TABLE <table name> [ IF EXISTS ] <drop behavior>
-    /// <drop view statement> ::= DROP VIEW <table name> [ IF EXISTS ] <drop behavior>
-    /// <drop domain statement> ::= DROP DOMAIN <domain name> [ IF EXISTS ] <drop behavior>
-    /// <drop data type statement> ::= DROP TYPE <type name> [ IF EXISTS ] <drop behavior>
-    ///
-    /// // Not ANSI SQL
-    /// <drop database statement> ::= DROP DATABASE <database name> [ IF EXISTS ] <drop behavior>
-    /// <drop index statement> ::= DROP INDEX <index name> [ IF EXISTS ] <drop behavior>
+    /// ALTER SCHEMA <schema name> { RENAME TO <new schema name> | OWNER TO <new owner> }
     /// ```
-    pub fn parse_drop_stmt(&mut self) -> Result<DropStmt, ParserError> {
-        self.expect_keyword(Keyword::DROP)?;
-        let ty = self.parse_drop_type()?;
-        // Many dialects support the non standard `IF EXISTS` clause and allow
-        // specifying multiple objects to delete in a single statement
-        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
-        let names = self.parse_comma_separated(Self::parse_object_name)?;
-        let behavior = self.parse_drop_behavior()?;
-        Ok(DropStmt {
-            ty,
-            if_exists,
-            names,
-            behavior,
-        })
+    pub fn parse_alter_schema_stmt(&mut self) -> Result<AlterSchemaStmt, ParserError> {
+        self.expect_keywords(&[Keyword::ALTER, Keyword::SCHEMA])?;
+        let name = self.parse_object_name()?;
+        let action = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterSchemaAction::RenameTo(self.parse_identifier()?)
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            AlterSchemaAction::OwnerTo(self.parse_identifier()?)
+        } else {
+            let found = self.peek_token().cloned();
+            return self.expected("RENAME TO or OWNER TO", found);
+        };
+        Ok(AlterSchemaStmt { name, action })
     }
 
-    /// Parses drop type.
-    pub fn parse_drop_type(&mut self) -> Result<ObjectType, ParserError> {
-        match self.parse_one_of_keywords(&[
-            Keyword::SCHEMA,
-            Keyword::TABLE,
-            Keyword::VIEW,
-            Keyword::DOMAIN,
-            Keyword::TYPE,
-            Keyword::DATABASE,
-            Keyword::INDEX,
-        ]) {
-            Some(keyword) => Ok(match keyword {
-                Keyword::SCHEMA => ObjectType::Schema,
-                Keyword::TABLE => ObjectType::Table,
-                Keyword::VIEW => ObjectType::View,
-                Keyword::DOMAIN => ObjectType::Domain,
-                Keyword::TYPE => ObjectType::Type,
-                Keyword::DATABASE => ObjectType::Database,
-                Keyword::INDEX => ObjectType::Index,
-                _ => unreachable!(),
-            }),
-            None => {
-                let found = self.peek_token().cloned();
-                self.expected(
-                    "SCHEMA, TABLE, VIEW, DOMAIN, TYPE, DATABASE or INDEX after DROP",
-                    found,
-                )
-            }
-        }
-    }
+    // ========================================================================
+    // index manipulation
+    // ========================================================================
 
-    /// Parses a drop behavior.
+    /// Parses a `ALTER INDEX` statement.
     ///
     /// ```txt
-    /// <drop behavior> ::= CASCADE | RESTRICT
+    /// ALTER INDEX [ IF EXISTS ] <index name> <alter index action>
+    ///
+    /// <alter index action> ::=
+    ///     RENAME TO <new index name>
+    ///     | SET TABLESPACE <tablespace name>
+    ///     | SET ( <storage parameter> = <value> [, ...] )
     /// ```
-    fn parse_drop_behavior(&mut self) -> Result<Option<DropBehavior>, ParserError> {
-        match self.parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]) {
-            Some(Keyword::CASCADE) => Ok(Some(DropBehavior::Cascade)),
-            Some(Keyword::RESTRICT) => Ok(Some(DropBehavior::Restrict)),
-            Some(_) => {
-                let found = self.peek_token().cloned();
+    pub fn parse_alter_index_stmt(&mut self) -> Result<AlterIndexStmt, ParserError> {
+        self.expect_keywords(&[Keyword::ALTER, Keyword::INDEX])?;
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        let action = if self.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterIndexAction::RenameTo(self.parse_identifier()?)
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::TABLESPACE]) {
+            AlterIndexAction::SetTablespace(self.parse_identifier()?)
+        } else if self.parse_keyword(Keyword::SET) {
+            self.expect_token(&Token::LeftParen)?;
+            let params = self.parse_comma_separated(Self::parse_assignment)?;
+            self.expect_token(&Token::RightParen)?;
+            AlterIndexAction::SetParameters(params)
+        } else {
+            let found = self.peek_token().cloned();
+            return self.expected("RENAME TO, SET TABLESPACE or SET", found);
+        };
+        Ok(AlterIndexStmt {
+            if_exists,
+            name,
+            action,
+        })
+    }
+
+    // ========================================================================
+    // sequence generator definition
+    // ========================================================================
+
+    /// Parses a `CREATE SEQUENCE` statement.
+    ///
+    /// ```txt
+    /// <sequence generator definition> ::=
+    ///     CREATE [ OR REPLACE ] SEQUENCE [ IF NOT EXISTS ] <sequence generator name>
+    ///         [ <sequence generator option> [...] ]
+    /// ```
+    pub fn parse_create_sequence_stmt(&mut self) -> Result<CreateSequenceStmt, ParserError> {
+        self.expect_keyword(Keyword::CREATE)?;
+        let or_replace = self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+        self.expect_keyword(Keyword::SEQUENCE)?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        let mut options = vec![];
+        while let Some(option) = self.parse_sequence_option()? {
+            options.push(option);
+        }
+        Ok(CreateSequenceStmt {
+            or_replace,
+            if_not_exists,
+            name,
+            options,
+        })
+    }
+
+    /// Parses a sequence generator option.
+    ///
+    /// ```txt
+    /// <sequence generator option> ::=
+    ///     AS <data type>
+    ///     | START [ WITH ] <signed numeric literal>
+    ///     | INCREMENT [ BY ] <signed numeric literal>
+    ///     | MAXVALUE <signed numeric literal> | NO MAXVALUE | NOMAXVALUE
+    ///     | MINVALUE <signed numeric literal> | NO MINVALUE | NOMINVALUE
+    ///     | CYCLE | NO CYCLE | NOCYCLE
+    ///     | CACHE <unsigned integer> | NOCACHE
+    /// ```
+    fn parse_sequence_option(&mut self) -> Result<Option<SequenceOption>, ParserError> {
+        let keyword = match self.parse_one_of_keywords(&[
+            Keyword::AS,
+            Keyword::START,
+            Keyword::INCREMENT,
+            Keyword::MAXVALUE,
+            Keyword::MINVALUE,
+            Keyword::CYCLE,
+            Keyword::CACHE,
+            Keyword::NO,
+            Keyword::NOMAXVALUE,
+            Keyword::NOMINVALUE,
+            Keyword::NOCYCLE,
+            Keyword::NOCACHE,
+        ]) {
+            Some(keyword) => keyword,
+            None => return Ok(None),
+        };
+        let option = match keyword {
+            Keyword::AS => SequenceOption::DataType(self.parse_data_type()?),
+            Keyword::START => {
+                self.parse_keyword(Keyword::WITH);
+                SequenceOption::StartWith(self.parse_expr()?)
+            }
+            Keyword::INCREMENT => {
+                self.parse_keyword(Keyword::BY);
+                SequenceOption::IncrementBy(self.parse_expr()?)
+            }
+            Keyword::MAXVALUE => SequenceOption::MaxValue(Some(self.parse_expr()?)),
+            Keyword::MINVALUE => SequenceOption::MinValue(Some(self.parse_expr()?)),
+            Keyword::CYCLE => SequenceOption::Cycle(true),
+            Keyword::CACHE => SequenceOption::Cache(Some(self.parse_expr()?)),
+            Keyword::NO => match self.expect_one_of_keywords(&[
+                Keyword::MAXVALUE,
+                Keyword::MINVALUE,
+                Keyword::CYCLE,
+            ])? {
+                Keyword::MAXVALUE => SequenceOption::MaxValue(None),
+                Keyword::MINVALUE => SequenceOption::MinValue(None),
+                Keyword::CYCLE => SequenceOption::Cycle(false),
+                _ => unreachable!(),
+            },
+            Keyword::NOMAXVALUE => SequenceOption::MaxValue(None),
+            Keyword::NOMINVALUE => SequenceOption::MinValue(None),
+            Keyword::NOCYCLE => SequenceOption::Cycle(false),
+            Keyword::NOCACHE => SequenceOption::Cache(None),
+            _ => unreachable!(),
+        };
+        Ok(Some(option))
+    }
+
+    // ========================================================================
+    // database definition
+    // ========================================================================
+
+    /// Parses a `CREATE DATABASE` statement.
+    ///
+    /// ```txt
+    /// <database definition> ::= CREATE DATABASE [ IF NOT EXISTS ] <database name> [ <database option> [...] ]
+    /// ```
+    pub fn parse_create_database_stmt(&mut self) -> Result<CreateDatabaseStmt, ParserError> {
+        self.expect_keywords(&[Keyword::CREATE, Keyword::DATABASE])?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        // PostgreSQL: CREATE DATABASE <name> [ WITH ] [ <option> [...] ]
+        if self.parse_keyword(Keyword::WITH)
+            && !self
+                .dialect
+                .parser_conf()
+                .supports_postgres_database_options()
+        {
+            return unsupported_capability(Capability::PostgresDatabaseOptions);
+        }
+        let mut options = vec![];
+        while let Some(option) = self.parse_database_option()? {
+            options.push(option);
+        }
+        Ok(CreateDatabaseStmt {
+            if_not_exists,
+            name,
+            options,
+        })
+    }
+
+    /// Parses a database option, the MySQL and PostgreSQL options are only accepted by the
+    /// dialects which support them.
+    ///
+    /// ```txt
+    /// // MySQL
+    /// <database option> ::=
+    ///     [ DEFAULT ] { CHARACTER SET | CHARSET } [ = ] <charset name>
+    ///     | [ DEFAULT ] COLLATE [ = ] <collation name>
+    ///
+    /// // PostgreSQL
+    /// <database option> ::=
+    ///     OWNER [ = ] <user name>
+    ///     | TEMPLATE [ = ] <template>
+    ///     | ENCODING [ = ] <encoding>
+    ///     | LC_COLLATE [ = ] <lc collate>
+    ///     | LC_CTYPE [ = ] <lc ctype>
+    /// ```
+    fn parse_database_option(&mut self) -> Result<Option<DatabaseOption>, ParserError> {
+        // The options are matched by words, so that an option of another dialect is reported
+        // even if the words aren't keywords of the dialect.
+        let word = match self.peek_token() {
+            Some(Token::Word(w)) if w.quote.is_none() => w.value.to_uppercase(),
+            _ => return Ok(None),
+        };
+        let conf = self.dialect.parser_conf();
+        let (supported, capability) = match word.as_str() {
+            "DEFAULT" | "CHARACTER" | "CHARSET" | "COLLATE" => (
+                conf.supports_mysql_database_options(),
+                Capability::MysqlDatabaseOptions,
+            ),
+            "OWNER" | "TEMPLATE" | "ENCODING" | "LC_COLLATE" | "LC_CTYPE" => (
+                conf.supports_postgres_database_options(),
+                Capability::PostgresDatabaseOptions,
+            ),
+            _ => return Ok(None),
+        };
+        if !supported {
+            return unsupported_capability(capability);
+        }
+
+        // MySQL: the leading `DEFAULT` is a noise word
+        let option: fn(Ident) -> DatabaseOption =
+            if self.parse_keywords(&[Keyword::DEFAULT, Keyword::CHARACTER, Keyword::SET])
+                || self.parse_keywords(&[Keyword::DEFAULT, Keyword::CHARSET])
+                || self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+                || self.parse_keyword(Keyword::CHARSET)
+            {
+                DatabaseOption::CharacterSet
+            } else if self.parse_keywords(&[Keyword::DEFAULT, Keyword::COLLATE])
+                || self.parse_keyword(Keyword::COLLATE)
+            {
+                DatabaseOption::Collate
+            } else if self.parse_keyword(Keyword::OWNER) {
+                DatabaseOption::Owner
+            } else if self.parse_keyword(Keyword::TEMPLATE) {
+                DatabaseOption::Template
+            } else if self.parse_keyword(Keyword::ENCODING) {
+                DatabaseOption::Encoding
+            } else if self.parse_keyword(Keyword::LC_COLLATE) {
+                DatabaseOption::LcCollate
+            } else if self.parse_keyword(Keyword::LC_CTYPE) {
+                DatabaseOption::LcCtype
+            } else {
+                return Ok(None);
+            };
+        self.next_token_if_is(&Token::Equal);
+        let value = match self.next_token() {
+            Some(Token::String(s)) => Ident::with_quote('\'', s),
+            Some(Token::Word(w)) => Ident {
+                value: w.value,
+                quote: w.quote,
+            },
+            unexpected => return self.expected("database option value", unexpected),
+        };
+        Ok(Some(option(value)))
+    }
+
+    // ========================================================================
+    // foreign data definition
+    // ========================================================================
+
+    /// Parses a `CREATE SERVER` statement.
+    ///
+    /// ```txt
+    /// CREATE SERVER [ IF NOT EXISTS ] <server name> [ TYPE <server type> ] [ VERSION <server version> ]
+    ///     FOREIGN DATA WRAPPER <fdw name>
+    ///     [ OPTIONS ( <option> [, ...] ) ]
+    /// ```
+    pub fn parse_create_server_stmt(&mut self) -> Result<CreateServerStmt, ParserError> {
+        self.expect_keywords(&[Keyword::CREATE, Keyword::SERVER])?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_identifier()?;
+        let server_type = self.parse_keyword_then(Keyword::TYPE, |parser| {
+            parser.parse_literal_string("server type")
+        })?;
+        let version = self.parse_keyword_then(Keyword::VERSION, |parser| {
+            parser.parse_literal_string("server version")
+        })?;
+        self.expect_keywords(&[Keyword::FOREIGN, Keyword::DATA, Keyword::WRAPPER])?;
+        let wrapper = self.parse_identifier()?;
+        let options = self.parse_fdw_options()?;
+        Ok(CreateServerStmt {
+            if_not_exists,
+            name,
+            server_type,
+            version,
+            wrapper,
+            options,
+        })
+    }
+
+    /// Parses a `CREATE USER MAPPING` statement.
+    ///
+    /// ```txt
+    /// CREATE USER MAPPING [ IF NOT EXISTS ] FOR { <user name> | USER | CURRENT_USER | PUBLIC }
+    ///     SERVER <server name>
+    ///     [ OPTIONS ( <option> [, ...] ) ]
+    /// ```
+    pub fn parse_create_user_mapping_stmt(&mut self) -> Result<CreateUserMappingStmt, ParserError> {
+        self.expect_keywords(&[Keyword::CREATE, Keyword::USER, Keyword::MAPPING])?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        self.expect_keyword(Keyword::FOR)?;
+        let user = self.parse_identifier()?;
+        self.expect_keyword(Keyword::SERVER)?;
+        let server = self.parse_identifier()?;
+        let options = self.parse_fdw_options()?;
+        Ok(CreateUserMappingStmt {
+            if_not_exists,
+            user,
+            server,
+            options,
+        })
+    }
+
+    /// Parses a `CREATE FOREIGN TABLE` statement.
+    ///
+    /// ```txt
+    /// CREATE FOREIGN TABLE [ IF NOT EXISTS ] <table name> ( [ <column definition> [, ...] ] )
+    ///     SERVER <server name>
+    ///     [ OPTIONS ( <option> [, ...] ) ]
+    /// ```
+    pub fn parse_create_foreign_table_stmt(
+        &mut self,
+    ) -> Result<CreateForeignTableStmt, ParserError> {
+        self.expect_keywords(&[Keyword::CREATE, Keyword::FOREIGN, Keyword::TABLE])?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        self.expect_token(&Token::LeftParen)?;
+        let columns = if self.next_token_if_is(&Token::RightParen) {
+            vec![]
+        } else {
+            let columns = self.parse_comma_separated(Self::parse_column_def)?;
+            self.expect_token(&Token::RightParen)?;
+            columns
+        };
+        self.expect_keyword(Keyword::SERVER)?;
+        let server = self.parse_identifier()?;
+        let options = self.parse_fdw_options()?;
+        Ok(CreateForeignTableStmt {
+            if_not_exists,
+            name,
+            columns,
+            server,
+            options,
+        })
+    }
+
+    /// Parses an `IMPORT FOREIGN SCHEMA` statement.
+    ///
+    /// ```txt
+    /// IMPORT FOREIGN SCHEMA <remote schema> [ { LIMIT TO | EXCEPT } ( <table name> [, ...] ) ]
+    ///     FROM SERVER <server name>
+    ///     INTO <local schema>
+    ///     [ OPTIONS ( <option> [, ...] ) ]
+    /// ```
+    pub fn parse_import_foreign_schema_stmt(
+        &mut self,
+    ) -> Result<ImportForeignSchemaStmt, ParserError> {
+        self.expect_keywords(&[Keyword::IMPORT, Keyword::FOREIGN, Keyword::SCHEMA])?;
+        let remote_schema = self.parse_identifier()?;
+        let filter = if self.parse_keywords(&[Keyword::LIMIT, Keyword::TO]) {
+            let tables = self.parse_parenthesized_comma_separated(Self::parse_identifier, false)?;
+            tables.map(ImportFilter::LimitTo)
+        } else if self.parse_keyword(Keyword::EXCEPT) {
+            let tables = self.parse_parenthesized_comma_separated(Self::parse_identifier, false)?;
+            tables.map(ImportFilter::Except)
+        } else {
+            None
+        };
+        self.expect_keywords(&[Keyword::FROM, Keyword::SERVER])?;
+        let server = self.parse_identifier()?;
+        self.expect_keyword(Keyword::INTO)?;
+        let local_schema = self.parse_identifier()?;
+        let options = self.parse_fdw_options()?;
+        Ok(ImportForeignSchemaStmt {
+            remote_schema,
+            filter,
+            server,
+            local_schema,
+            options,
+        })
+    }
+
+    /// Parses the generic options of foreign-data objects.
+    ///
+    /// ```txt
+    /// [ OPTIONS ( <option name> <option value> [, ...] ) ]
+    /// ```
+    fn parse_fdw_options(&mut self) -> Result<Vec<FdwOption>, ParserError> {
+        if !self.parse_keyword(Keyword::OPTIONS) {
+            return Ok(vec![]);
+        }
+        let options = self.parse_parenthesized_comma_separated(
+            |parser| {
+                let name = parser.parse_identifier()?;
+                let value = parser.parse_literal_string("option value")?;
+                Ok(FdwOption { name, value })
+            },
+            false,
+        )?;
+        Ok(options.unwrap_or_default())
+    }
+
+    // ========================================================================
+    // row-level security policy definition
+    // ========================================================================
+
+    /// Parses a `CREATE POLICY` statement.
+    ///
+    /// ```txt
+    /// CREATE POLICY <policy name> ON <table name>
+    ///     [ AS { PERMISSIVE | RESTRICTIVE } ]
+    ///     [ FOR { ALL | SELECT | INSERT | UPDATE | DELETE } ]
+    ///     [ TO <role name> [, ...] ]
+    ///     [ USING ( <expr> ) ]
+    ///     [ WITH CHECK ( <expr> ) ]
+    /// ```
+    pub fn parse_create_policy_stmt(&mut self) -> Result<CreatePolicyStmt, ParserError> {
+        self.expect_keywords(&[Keyword::CREATE, Keyword::POLICY])?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(Keyword::ON)?;
+        let table = self.parse_object_name()?;
+        let kind = self.parse_keyword_then(Keyword::AS, Self::parse_policy_kind)?;
+        let command = self.parse_keyword_then(Keyword::FOR, Self::parse_policy_command)?;
+        let roles = if self.parse_keyword(Keyword::TO) {
+            self.parse_comma_separated(Self::parse_identifier)?
+        } else {
+            vec![]
+        };
+        let using = self.parse_keyword_then(Keyword::USING, Self::parse_policy_expr)?;
+        let with_check = if self.parse_keywords(&[Keyword::WITH, Keyword::CHECK]) {
+            Some(self.parse_policy_expr()?)
+        } else {
+            None
+        };
+        Ok(CreatePolicyStmt {
+            name,
+            table,
+            kind,
+            command,
+            roles,
+            using,
+            with_check,
+        })
+    }
+
+    fn parse_policy_kind(&mut self) -> Result<PolicyKind, ParserError> {
+        // `PERMISSIVE` and `RESTRICTIVE` are not keywords of PostgreSQL
+        if self.parse_word("PERMISSIVE") {
+            Ok(PolicyKind::Permissive)
+        } else if self.parse_word("RESTRICTIVE") {
+            Ok(PolicyKind::Restrictive)
+        } else {
+            let found = self.peek_token().cloned();
+            self.expected("PERMISSIVE or RESTRICTIVE", found)
+        }
+    }
+
+    fn parse_policy_command(&mut self) -> Result<PolicyCommand, ParserError> {
+        match self.expect_one_of_keywords(&[
+            Keyword::ALL,
+            Keyword::SELECT,
+            Keyword::INSERT,
+            Keyword::UPDATE,
+            Keyword::DELETE,
+        ])? {
+            Keyword::ALL => Ok(PolicyCommand::All),
+            Keyword::SELECT => Ok(PolicyCommand::Select),
+            Keyword::INSERT => Ok(PolicyCommand::Insert),
+            Keyword::UPDATE => Ok(PolicyCommand::Update),
+            Keyword::DELETE => Ok(PolicyCommand::Delete),
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_policy_expr(&mut self) -> Result<Box<Expr>, ParserError> {
+        self.parse_delimited(&Token::LeftParen, Self::parse_expr, &Token::RightParen)
+            .map(Box::new)
+    }
+
+    /// Parses a `DROP POLICY` statement.
+    ///
+    /// ```txt
+    /// DROP POLICY [ IF EXISTS ] <policy name> ON <table name> [ CASCADE | RESTRICT ]
+    /// ```
+    pub fn parse_drop_policy_stmt(&mut self) -> Result<DropPolicyStmt, ParserError> {
+        self.expect_keywords(&[Keyword::DROP, Keyword::POLICY])?;
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_identifier()?;
+        self.expect_keyword(Keyword::ON)?;
+        let table = self.parse_object_name()?;
+        let behavior = self.parse_drop_behavior()?;
+        Ok(DropPolicyStmt {
+            if_exists,
+            name,
+            table,
+            behavior,
+        })
+    }
+
+    // ========================================================================
+    // drop statement
+    // ========================================================================
+
+    /// Parses a `DROP { SCHEMA | TABLE | VIEW | DOMAIN | TYPE | DATABASE | INDEX }` statement.
+    ///
+    /// ```txt
+    /// <drop schema statement> ::= DROP SCHEMA <schema name> [ IF EXISTS ] <drop behavior>
+    /// <drop table statement> ::= DROP TABLE <table name> [ IF EXISTS ] <drop behavior>
+    /// <drop view statement> ::= DROP VIEW <table name> [ IF EXISTS ] <drop behavior>
+    /// <drop domain statement> ::= DROP DOMAIN <domain name> [ IF EXISTS ] <drop behavior>
+    /// <drop data type statement> ::= DROP TYPE <type name> [ IF EXISTS ] <drop behavior>
+    ///
+    /// // Not ANSI SQL
+    /// <drop database statement> ::= DROP DATABASE <database name> [ IF EXISTS ] <drop behavior>
+    /// <drop index statement> ::= DROP INDEX <index name> [ IF EXISTS ] <drop behavior>
+    /// ```
+    pub fn parse_drop_stmt(&mut self) -> Result<DropStmt, ParserError> {
+        self.expect_keyword(Keyword::DROP)?;
+        let ty = self.parse_drop_type()?;
+        // Many dialects support the non standard `IF EXISTS` clause and allow
+        // specifying multiple objects to delete in a single statement
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let names = self.parse_comma_separated(Self::parse_object_name)?;
+        let behavior = self.parse_drop_behavior()?;
+        Ok(DropStmt {
+            ty,
+            if_exists,
+            names,
+            behavior,
+        })
+    }
+
+    /// Parses drop type.
+    pub fn parse_drop_type(&mut self) -> Result<ObjectType, ParserError> {
+        match self.parse_one_of_keywords(&[
+            Keyword::SCHEMA,
+            Keyword::TABLE,
+            Keyword::VIEW,
+            Keyword::DOMAIN,
+            Keyword::TYPE,
+            Keyword::DATABASE,
+            Keyword::INDEX,
+            Keyword::SEQUENCE,
+        ]) {
+            Some(keyword) => Ok(match keyword {
+                Keyword::SCHEMA => ObjectType::Schema,
+                Keyword::TABLE => ObjectType::Table,
+                Keyword::VIEW => ObjectType::View,
+                Keyword::DOMAIN => ObjectType::Domain,
+                Keyword::TYPE => ObjectType::Type,
+                Keyword::DATABASE => ObjectType::Database,
+                Keyword::INDEX => ObjectType::Index,
+                Keyword::SEQUENCE => ObjectType::Sequence,
+                _ => unreachable!(),
+            }),
+            None => {
+                let found = self.peek_token().cloned();
+                self.expected(
+                    "SCHEMA, TABLE, VIEW, DOMAIN, TYPE, DATABASE, INDEX or SEQUENCE after DROP",
+                    found,
+                )
+            }
+        }
+    }
+
+    /// Parses a drop behavior.
+    ///
+    /// ```txt
+    /// <drop behavior> ::= CASCADE | RESTRICT
+    /// ```
+    fn parse_drop_behavior(&mut self) -> Result<Option<DropBehavior>, ParserError> {
+        match self.parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]) {
+            Some(Keyword::CASCADE) => Ok(Some(DropBehavior::Cascade)),
+            Some(Keyword::RESTRICT) => Ok(Some(DropBehavior::Restrict)),
+            Some(_) => {
+                let found = self.peek_token().cloned();
                 self.expected("CASCADE or RESTRICT", found)
             }
             None => Ok(None),
@@ -937,15 +1976,22 @@ mod tests {
                         },
                         ColumnDef {
                             name: Ident::new("baz"),
-                            data_type: DataType::Varchar(10),
+                            data_type: DataType::Varchar {
+                                length: 10,
+                                charset: None,
+                                collation: None,
+                            },
                             constraints: vec![],
                         },
                     ],
                     constraints: vec![TableConstraint::Unique {
                         is_primary: true,
                         columns: vec![Ident::new("bar")],
+                        on_conflict: None,
                     }],
                 },
+                partition_by: None,
+                system_versioning: false,
                 on_commit: None
             }
         );
@@ -963,17 +2009,26 @@ mod tests {
                             data_type: DataType::Int(None),
                             constraints: vec![ColumnConstraintDef {
                                 name: None,
-                                constraint: ColumnConstraint::Unique { is_primary: true }
+                                constraint: ColumnConstraint::Unique {
+                                    is_primary: true,
+                                    on_conflict: None,
+                                }
                             }],
                         },
                         ColumnDef {
                             name: Ident::new("baz"),
-                            data_type: DataType::Varchar(10),
+                            data_type: DataType::Varchar {
+                                length: 10,
+                                charset: None,
+                                collation: None,
+                            },
                             constraints: vec![],
                         },
                     ],
                     constraints: vec![],
                 },
+                partition_by: None,
+                system_versioning: false,
                 on_commit: None
             }
         );
@@ -988,12 +2043,168 @@ mod tests {
                     table: ObjectName::new(vec!["bar"]),
                     options: Some(vec![LikeOption::IncludingIdentity])
                 }),
+                partition_by: None,
+                system_versioning: false,
                 on_commit: None
             }
         );
         Ok(())
     }
 
+    #[test]
+    fn parse_partitioned_table() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "CREATE TABLE m_2021 PARTITION OF measurement \
+            FOR VALUES FROM ('2021-01-01', MINVALUE) TO ('2022-01-01', MAXVALUE) \
+            PARTITION BY list (city_id)";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_create_table_stmt()?;
+        assert_eq!(
+            stmt.content,
+            TableContent::PartitionOf {
+                parent: ObjectName::new(vec!["measurement"]),
+                bound: PartitionBound::Range {
+                    from: vec![
+                        PartitionRangeValue::Expr(Expr::Literal(Literal::String(
+                            "2021-01-01".into()
+                        ))),
+                        PartitionRangeValue::MinValue,
+                    ],
+                    to: vec![
+                        PartitionRangeValue::Expr(Expr::Literal(Literal::String(
+                            "2022-01-01".into()
+                        ))),
+                        PartitionRangeValue::MaxValue,
+                    ],
+                },
+            }
+        );
+        assert_eq!(
+            stmt.partition_by,
+            Some(PartitionScheme {
+                strategy: PartitionStrategy::List,
+                keys: vec![Expr::Identifier(Ident::new("city_id"))],
+                partitions: None,
+                subpartition_by: None,
+                definitions: vec![],
+            })
+        );
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE TABLE m_2021 PARTITION OF measurement \
+            FOR VALUES FROM ('2021-01-01', MINVALUE) TO ('2022-01-01', MAXVALUE) \
+            PARTITION BY LIST (city_id)"
+        );
+
+        let cases = [
+            "CREATE TABLE measurement (city_id INT, logdate DATE) PARTITION BY RANGE (logdate, city_id + 1)",
+            "CREATE TABLE cities_ab PARTITION OF cities FOR VALUES IN ('a', 'b')",
+            "CREATE TABLE orders_p1 PARTITION OF orders FOR VALUES WITH (MODULUS 4, REMAINDER 0)",
+            "CREATE TABLE cities_other PARTITION OF cities DEFAULT",
+            "ALTER TABLE cities ATTACH PARTITION cities_ab FOR VALUES IN ('a', 'b')",
+            "ALTER TABLE cities ATTACH PARTITION cities_other DEFAULT",
+            "ALTER TABLE cities DETACH PARTITION cities_ab CONCURRENTLY",
+            "ALTER TABLE cities DETACH PARTITION cities_ab",
+        ];
+        for sql in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+        for sql in [
+            "CREATE TABLE p PARTITION OF t",
+            "CREATE TABLE p PARTITION OF t FOR VALUES FROM (1)",
+            "CREATE TABLE p PARTITION OF t FOR VALUES WITH (REMAINDER 0, MODULUS 4)",
+            "CREATE TABLE t (a INT) PARTITION BY KEY (a)",
+        ] {
+            assert!(Parser::new_with_sql(&dialect, sql)?.parse_stmt().is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_mysql_partition_definitions() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "CREATE TABLE t (id INT, d DATE) \
+            PARTITION BY RANGE (YEAR(d)) SUBPARTITION BY HASH (id) SUBPARTITIONS 2 (\
+            PARTITION p0 VALUES LESS THAN (2000) ENGINE InnoDB, \
+            PARTITION p1 VALUES LESS THAN MAXVALUE (SUBPARTITION s0 COMMENT 'a', SUBPARTITION s1))";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_create_table_stmt()?;
+        let partition_by = stmt.partition_by.clone().unwrap();
+        assert_eq!(partition_by.strategy, PartitionStrategy::Range);
+        assert_eq!(
+            partition_by.subpartition_by,
+            Some(SubpartitionScheme {
+                strategy: PartitionStrategy::Hash,
+                keys: vec![Expr::Identifier(Ident::new("id"))],
+                subpartitions: Some(2),
+            })
+        );
+        assert_eq!(
+            partition_by.definitions[0],
+            PartitionDef {
+                name: Ident::new("p0"),
+                values: Some(PartitionValues::LessThan(vec![PartitionRangeValue::Expr(
+                    Expr::Literal(Literal::Number("2000".into()))
+                )])),
+                options: vec![PartitionOption::Engine(Ident::new("InnoDB"))],
+                subpartitions: vec![],
+            }
+        );
+        assert_eq!(
+            partition_by.definitions[1].subpartitions,
+            vec![
+                SubpartitionDef {
+                    name: Ident::new("s0"),
+                    options: vec![PartitionOption::Comment("a".into())],
+                },
+                SubpartitionDef {
+                    name: Ident::new("s1"),
+                    options: vec![],
+                },
+            ]
+        );
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE TABLE t (id INT, d DATE) \
+            PARTITION BY RANGE (YEAR(d)) SUBPARTITION BY HASH (id) SUBPARTITIONS 2 (\
+            PARTITION p0 VALUES LESS THAN (2000) ENGINE = InnoDB, \
+            PARTITION p1 VALUES LESS THAN (MAXVALUE) (SUBPARTITION s0 COMMENT = 'a', SUBPARTITION s1))"
+        );
+
+        let cases = [
+            "CREATE TABLE t (a INT) PARTITION BY LINEAR KEY ALGORITHM = 2 () PARTITIONS 4",
+            "CREATE TABLE t (a INT) PARTITION BY KEY (a) PARTITIONS 2",
+            "CREATE TABLE t (a INT) PARTITION BY LINEAR HASH (a) PARTITIONS 3",
+            "CREATE TABLE t (a INT, b INT) PARTITION BY RANGE COLUMNS (a, b) (\
+                PARTITION p0 VALUES LESS THAN (5, MAXVALUE) DATA DIRECTORY = '/data' MAX_ROWS = 100)",
+            "CREATE TABLE t (a VARCHAR(10)) PARTITION BY LIST COLUMNS (a) (\
+                PARTITION p0 VALUES IN ('x', 'y') TABLESPACE = ts1, PARTITION p1 VALUES IN ('z'))",
+        ];
+        for sql in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // the partition definitions are only supported by MySQL and MariaDB
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::parse_script(
+                &dialect,
+                "CREATE TABLE t (a INT) PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10))"
+            )
+            .map(|_| ()),
+            unsupported_capability(Capability::PartitionDefinitions)
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_alter_table_stmt() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -1029,6 +2240,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_rename_table_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let stmt = Parser::new_with_sql(&dialect, "RENAME TABLE a TO b, db.c TO d")?
+            .parse_rename_table_stmt()?;
+        assert_eq!(
+            stmt,
+            RenameTableStmt {
+                tables: vec![
+                    RenameTable {
+                        from: ObjectName::new(vec!["a"]),
+                        to: ObjectName::new(vec!["b"]),
+                    },
+                    RenameTable {
+                        from: ObjectName::new(vec!["db", "c"]),
+                        to: ObjectName::new(vec!["d"]),
+                    },
+                ],
+            }
+        );
+        assert_eq!(stmt.to_string(), "RENAME TABLE a TO b, db.c TO d");
+
+        let alter = stmt.to_alter_table_stmts();
+        assert_eq!(alter[1].to_string(), "ALTER TABLE db.c RENAME TO d");
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ALTER TABLE a RENAME TO b")?
+                .parse_alter_table_stmt()?,
+            alter[0]
+        );
+
+        // the RENAME TABLE statement is only supported by MySQL and MariaDB
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::parse_script(&dialect, "RENAME TABLE a TO b").map(|_| ()),
+            unsupported_capability(Capability::RenameTable)
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_create_view_stmt() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -1037,10 +2287,14 @@ mod tests {
                 .parse_create_view_stmt()?,
             CreateViewStmt {
                 or_replace: false,
+                algorithm: None,
+                definer: None,
+                sql_security: None,
                 recursive: false,
                 if_not_exists: false,
                 name: ObjectName::new(vec!["foo"]),
                 columns: None,
+                options: vec![],
                 query: Box::new(Query {
                     with: None,
                     body: QueryBody::QuerySpec(Box::new(QuerySpec {
@@ -1050,12 +2304,16 @@ mod tests {
                             list: vec![TableReference {
                                 relation: TableFactor::Table {
                                     name: ObjectName::new(vec!["bar"]),
+                                    partitions: None,
+                                    system_time: None,
                                     alias: None,
+                                    index_hints: vec![],
                                 },
                                 joins: vec![]
                             }]
                         }),
                         r#where: None,
+                        connect_by: None,
                         group_by: None,
                         having: None,
                         window: None
@@ -1064,6 +2322,7 @@ mod tests {
                     offset: None,
                     limit: None,
                     fetch: None,
+                    output: None,
                 }),
                 check_option: None
             }
@@ -1076,10 +2335,14 @@ mod tests {
             .parse_create_view_stmt()?,
             CreateViewStmt {
                 or_replace: false,
+                algorithm: None,
+                definer: None,
+                sql_security: None,
                 recursive: false,
                 if_not_exists: false,
                 name: ObjectName::new(vec!["foo"]),
                 columns: Some(vec![Ident::new("id1"), Ident::new("id2")]),
+                options: vec![],
                 query: Box::new(Query {
                     with: None,
                     body: QueryBody::QuerySpec(Box::new(QuerySpec {
@@ -1089,12 +2352,16 @@ mod tests {
                             list: vec![TableReference {
                                 relation: TableFactor::Table {
                                     name: ObjectName::new(vec!["bar"]),
+                                    partitions: None,
+                                    system_time: None,
                                     alias: None,
+                                    index_hints: vec![],
                                 },
                                 joins: vec![]
                             }]
                         }),
                         r#where: None,
+                        connect_by: None,
                         group_by: None,
                         having: None,
                         window: None
@@ -1103,10 +2370,81 @@ mod tests {
                     offset: None,
                     limit: None,
                     fetch: None,
+                    output: None,
                 }),
                 check_option: Some(ViewCheckOption::Cascaded),
             }
         );
+
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "CREATE OR REPLACE ALGORITHM = MERGE DEFINER = 'root'@'localhost' SQL SECURITY INVOKER VIEW v AS SELECT 1";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_create_view_stmt()?;
+        assert_eq!(stmt.algorithm, Some(ViewAlgorithm::Merge));
+        assert_eq!(
+            stmt.definer,
+            Some(UserName {
+                user: Ident::with_quote('\'', "root"),
+                host: Some(Ident::with_quote('\'', "localhost")),
+            })
+        );
+        assert_eq!(stmt.sql_security, Some(SqlSecurity::Invoker));
+        assert_eq!(stmt.to_string(), sql);
+        let sqls = [
+            "CREATE DEFINER = CURRENT_USER VIEW v AS SELECT 1",
+            "CREATE ALGORITHM = TEMPTABLE SQL SECURITY DEFINER VIEW v (a) AS SELECT 1 WITH LOCAL CHECK OPTION",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+        let sql = "CREATE DEFINER = CURRENT_USER() VIEW v AS SELECT 1";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?
+                .parse_stmt()?
+                .to_string(),
+            "CREATE DEFINER = CURRENT_USER VIEW v AS SELECT 1"
+        );
+
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "CREATE VIEW v WITH (security_barrier, check_option = local) AS SELECT * FROM t";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_create_view_stmt()?;
+        assert_eq!(
+            stmt.options,
+            vec![
+                ViewOption {
+                    name: Ident::new("security_barrier"),
+                    value: None,
+                },
+                ViewOption {
+                    name: Ident::new("check_option"),
+                    value: Some(Box::new(Expr::Identifier(Ident::new("local")))),
+                },
+            ]
+        );
+        assert_eq!(stmt.to_string(), sql);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_alter_view_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ALTER VIEW IF EXISTS foo RENAME TO bar")?
+                .parse_alter_view_stmt()?,
+            AlterViewStmt {
+                if_exists: true,
+                name: ObjectName::new(vec!["foo"]),
+                action: AlterViewAction::RenameTo(Ident::new("bar")),
+            }
+        );
+        let dialect = crate::mysql::MysqlDialect::default();
+        let stmt = Parser::new_with_sql(&dialect, "ALTER VIEW foo (a) AS SELECT 1")?
+            .parse_alter_view_stmt()?;
+        assert_eq!(stmt.to_string(), "ALTER VIEW foo (a) AS SELECT 1");
         Ok(())
     }
 
@@ -1265,6 +2603,375 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn parse_alter_schema_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ALTER SCHEMA foo RENAME TO bar")?
+                .parse_alter_schema_stmt()?,
+            AlterSchemaStmt {
+                name: ObjectName::new(vec!["foo"]),
+                action: AlterSchemaAction::RenameTo(Ident::new("bar")),
+            }
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ALTER SCHEMA foo OWNER TO bar")?
+                .parse_alter_schema_stmt()?,
+            AlterSchemaStmt {
+                name: ObjectName::new(vec!["foo"]),
+                action: AlterSchemaAction::OwnerTo(Ident::new("bar")),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_alter_index_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ALTER INDEX IF EXISTS foo RENAME TO bar")?
+                .parse_alter_index_stmt()?,
+            AlterIndexStmt {
+                if_exists: true,
+                name: ObjectName::new(vec!["foo"]),
+                action: AlterIndexAction::RenameTo(Ident::new("bar")),
+            }
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ALTER INDEX foo SET TABLESPACE bar")?
+                .parse_alter_index_stmt()?,
+            AlterIndexStmt {
+                if_exists: false,
+                name: ObjectName::new(vec!["foo"]),
+                action: AlterIndexAction::SetTablespace(Ident::new("bar")),
+            }
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "ALTER INDEX foo SET (fillfactor = 70)")?
+                .parse_alter_index_stmt()?,
+            AlterIndexStmt {
+                if_exists: false,
+                name: ObjectName::new(vec!["foo"]),
+                action: AlterIndexAction::SetParameters(vec![Assignment {
+                    target: Ident::new("fillfactor"),
+                    value: Box::new(Expr::Literal(Literal::Number("70".into()))),
+                }]),
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_create_database_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql =
+            "CREATE DATABASE IF NOT EXISTS foo DEFAULT CHARACTER SET = utf8mb4 COLLATE utf8mb4_bin";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_create_database_stmt()?;
+        assert_eq!(
+            stmt,
+            CreateDatabaseStmt {
+                if_not_exists: true,
+                name: ObjectName::new(vec!["foo"]),
+                options: vec![
+                    DatabaseOption::CharacterSet(Ident::new("utf8mb4")),
+                    DatabaseOption::Collate(Ident::new("utf8mb4_bin")),
+                ]
+            }
+        );
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE DATABASE IF NOT EXISTS foo CHARACTER SET utf8mb4 COLLATE utf8mb4_bin"
+        );
+
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "CREATE DATABASE foo WITH OWNER = bar TEMPLATE template0 ENCODING 'UTF8' LC_COLLATE 'C' LC_CTYPE = 'C'";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?.parse_create_database_stmt()?,
+            CreateDatabaseStmt {
+                if_not_exists: false,
+                name: ObjectName::new(vec!["foo"]),
+                options: vec![
+                    DatabaseOption::Owner(Ident::new("bar")),
+                    DatabaseOption::Template(Ident::new("template0")),
+                    DatabaseOption::Encoding(Ident::with_quote('\'', "UTF8")),
+                    DatabaseOption::LcCollate(Ident::with_quote('\'', "C")),
+                    DatabaseOption::LcCtype(Ident::with_quote('\'', "C")),
+                ]
+            }
+        );
+
+        // the options are only accepted by the dialects which support them
+        let mysql = crate::mysql::MysqlDialect::default();
+        let mariadb = crate::mariadb::MariadbDialect::default();
+        for sql in [
+            "CREATE DATABASE foo TEMPLATE bar",
+            "CREATE DATABASE foo WITH OWNER bar",
+        ] {
+            let expected = unsupported_capability(Capability::PostgresDatabaseOptions);
+            assert_eq!(Parser::parse_script(&mysql, sql).map(|_| ()), expected);
+            assert_eq!(Parser::parse_script(&mariadb, sql).map(|_| ()), expected);
+        }
+        for sql in [
+            "CREATE DATABASE foo CHARACTER SET utf8",
+            "CREATE DATABASE foo DEFAULT COLLATE utf8_bin",
+        ] {
+            let expected = unsupported_capability(Capability::MysqlDatabaseOptions);
+            assert_eq!(Parser::parse_script(&dialect, sql).map(|_| ()), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_create_sequence_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mariadb::MariadbDialect::default();
+        let sql =
+            "CREATE OR REPLACE SEQUENCE IF NOT EXISTS s START 10 INCREMENT -1 NOMAXVALUE NOCACHE";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        assert_eq!(
+            stmt,
+            Stmt::CreateSequence(CreateSequenceStmt {
+                or_replace: true,
+                if_not_exists: true,
+                name: ObjectName::new(vec!["s"]),
+                options: vec![
+                    SequenceOption::StartWith(Expr::Literal(Literal::Number("10".into()))),
+                    SequenceOption::IncrementBy(Expr::unary(
+                        UnaryOperator::Minus,
+                        Expr::Literal(Literal::Number("1".into()))
+                    )),
+                    SequenceOption::MaxValue(None),
+                    SequenceOption::Cache(None),
+                ],
+            })
+        );
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE OR REPLACE SEQUENCE IF NOT EXISTS s START WITH 10 INCREMENT BY - 1 NO MAXVALUE NOCACHE"
+        );
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        let sql = "CREATE SEQUENCE s AS INT MINVALUE 1 NO MAXVALUE NO CYCLE";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?
+                .parse_stmt()?
+                .to_string(),
+            sql
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "DROP SEQUENCE s")?
+                .parse_stmt()?
+                .to_string(),
+            "DROP SEQUENCE s"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_system_versioning() -> Result<(), ParserError> {
+        let dialect = crate::mariadb::MariadbDialect::default();
+        let sqls = [
+            "CREATE TABLE t (x INT) WITH SYSTEM VERSIONING",
+            "ALTER TABLE t ADD SYSTEM VERSIONING",
+            "ALTER TABLE t DROP SYSTEM VERSIONING",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // the system-versioned tables are only supported by MariaDB
+        let dialect = crate::ansi::AnsiDialect::default();
+        for sql in sqls {
+            assert_eq!(
+                Parser::parse_script(&dialect, sql).map(|_| ()),
+                unsupported_capability(Capability::SystemVersioning)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_on_conflict_clause() -> Result<(), ParserError> {
+        let dialect = crate::sqlite::SqliteDialect::default();
+        let sql = "NOT NULL ON CONFLICT FAIL PRIMARY KEY ON CONFLICT ROLLBACK UNIQUE";
+        let constraints = Parser::new_with_sql(&dialect, sql)?.parse_column_constraint_defs()?;
+        assert_eq!(
+            constraints,
+            vec![
+                ColumnConstraintDef {
+                    name: None,
+                    constraint: ColumnConstraint::NotNull {
+                        on_conflict: Some(ConflictResolution::Fail),
+                    },
+                },
+                ColumnConstraintDef {
+                    name: None,
+                    constraint: ColumnConstraint::Unique {
+                        is_primary: true,
+                        on_conflict: Some(ConflictResolution::Rollback),
+                    },
+                },
+                ColumnConstraintDef {
+                    name: None,
+                    constraint: ColumnConstraint::Unique {
+                        is_primary: false,
+                        on_conflict: None,
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            constraints[0].to_string(),
+            "NOT NULL ON CONFLICT FAIL".to_string()
+        );
+
+        let sql = "CONSTRAINT pk PRIMARY KEY (a, b) ON CONFLICT REPLACE";
+        let constraint = Parser::new_with_sql(&dialect, sql)?
+            .parse_constraint_def(Parser::parse_table_constraint)?;
+        assert_eq!(constraint.to_string(), sql);
+
+        // the ON CONFLICT clause is only supported by SQLite
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "CREATE TABLE t (a INT NOT NULL ON CONFLICT FAIL)";
+        assert!(Parser::parse_script(&dialect, sql).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_foreign_data_stmts() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "CREATE FOREIGN TABLE IF NOT EXISTS s.films (code CHAR(5) NOT NULL, title TEXT) \
+            SERVER film_server OPTIONS (schema_name 'public', table_name 'films')";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        match &stmt {
+            Stmt::CreateForeignTable(stmt) => {
+                assert!(stmt.if_not_exists);
+                assert_eq!(stmt.name, ObjectName::new(vec!["s", "films"]));
+                assert_eq!(stmt.columns.len(), 2);
+                assert_eq!(stmt.server, Ident::new("film_server"));
+                assert_eq!(
+                    stmt.options,
+                    vec![
+                        FdwOption {
+                            name: Ident::new("schema_name"),
+                            value: "public".into(),
+                        },
+                        FdwOption {
+                            name: Ident::new("table_name"),
+                            value: "films".into(),
+                        },
+                    ]
+                );
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+        assert_eq!(stmt.to_string(), sql);
+
+        let cases = [
+            "CREATE SERVER IF NOT EXISTS foo TYPE 'oracle' VERSION '11' FOREIGN DATA WRAPPER oracle_fdw \
+                OPTIONS (host 'foo', dbname 'foodb', port '5432')",
+            "CREATE SERVER bar FOREIGN DATA WRAPPER postgres_fdw",
+            "CREATE USER MAPPING IF NOT EXISTS FOR PUBLIC SERVER foo OPTIONS (user 'bob', password 'secret')",
+            "CREATE USER MAPPING FOR CURRENT_USER SERVER foo",
+            "CREATE FOREIGN TABLE t () SERVER foo",
+            "IMPORT FOREIGN SCHEMA remote LIMIT TO (a, b) FROM SERVER foo INTO local OPTIONS (import_default 'true')",
+            "IMPORT FOREIGN SCHEMA remote EXCEPT (a) FROM SERVER foo INTO local",
+        ];
+        for sql in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+        for sql in [
+            "CREATE SERVER foo",
+            "CREATE FOREIGN TABLE t (a INT)",
+            "CREATE USER MAPPING FOR bob SERVER foo OPTIONS (user bob)",
+            "IMPORT FOREIGN SCHEMA remote FROM SERVER foo",
+        ] {
+            assert!(Parser::new_with_sql(&dialect, sql)?.parse_stmt().is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_policy_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "CREATE POLICY p ON s.t AS restrictive FOR UPDATE TO alice, CURRENT_USER \
+            USING (owner = user_name) WITH CHECK (amount > 0)";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        assert_eq!(
+            stmt,
+            Stmt::CreatePolicy(CreatePolicyStmt {
+                name: Ident::new("p"),
+                table: ObjectName::new(vec!["s", "t"]),
+                kind: Some(PolicyKind::Restrictive),
+                command: Some(PolicyCommand::Update),
+                roles: vec![Ident::new("alice"), Ident::new("CURRENT_USER")],
+                using: Some(Box::new(Expr::binary(
+                    Expr::Identifier(Ident::new("owner")),
+                    BinaryOperator::Equal,
+                    Expr::Identifier(Ident::new("user_name")),
+                ))),
+                with_check: Some(Box::new(Expr::binary(
+                    Expr::Identifier(Ident::new("amount")),
+                    BinaryOperator::Greater,
+                    Expr::Literal(Literal::Number("0".into())),
+                ))),
+            })
+        );
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE POLICY p ON s.t AS RESTRICTIVE FOR UPDATE TO alice, CURRENT_USER \
+            USING (owner = user_name) WITH CHECK (amount > 0)"
+        );
+        let cases = [
+            (
+                "CREATE POLICY p ON t USING (true)",
+                "CREATE POLICY p ON t USING (TRUE)",
+            ),
+            (
+                "DROP POLICY IF EXISTS p ON t CASCADE",
+                "DROP POLICY IF EXISTS p ON t CASCADE",
+            ),
+            (
+                "ALTER TABLE t ENABLE ROW LEVEL SECURITY",
+                "ALTER TABLE t ENABLE ROW LEVEL SECURITY",
+            ),
+            (
+                "ALTER TABLE t NO FORCE ROW LEVEL SECURITY",
+                "ALTER TABLE t NO FORCE ROW LEVEL SECURITY",
+            ),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                expected
+            );
+        }
+        for sql in [
+            "CREATE POLICY p ON t AS lenient",
+            "CREATE POLICY p ON t USING true",
+            "DROP POLICY p",
+        ] {
+            assert!(Parser::new_with_sql(&dialect, sql)?.parse_stmt().is_err());
+        }
+
+        // `POLICY` is not a keyword of MySQL
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "CREATE POLICY p ON t USING (true)";
+        assert!(Parser::new_with_sql(&dialect, sql)?.parse_stmt().is_err());
+        Ok(())
+    }
+
     #[test]
     fn parse_drop_stmt() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -1,9 +1,16 @@
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 use crate::{
-    ast::statement::*, dialect::Dialect, error::ParserError, keywords::Keyword, parser::Parser,
-    tokens::Token,
+    ast::{
+        expression::{Query, SelectItem, Values},
+        statement::*,
+    },
+    dialect::{Capability, Dialect, DialectParserConf},
+    error::{unsupported_capability, ParserError},
+    keywords::Keyword,
+    parser::Parser,
+    tokens::{Token, TokenKind},
 };
 
 impl<'a, D: Dialect> Parser<'a, D> {
@@ -14,49 +21,161 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// Parses a `INSERT` statement.
     ///
     /// ```txt
-    /// <insert statement> ::= INERT INTO <table name> <insert columns and source>
+    /// <insert statement> ::= [ <with clause> ] INSERT INTO <table name> <insert columns and source>
     ///
     /// 1. INSERT INTO <table name> [ (column1, column2, ...) ]
     ///     [ OVERRIDING { SYSTEM | USER } VALUE ] <query expression>
     /// 2. INSERT INTO <table name> [ (column1, column2, ...) ]
     ///     [ OVERRIDING { SYSTEM | USER } VALUE ] VALUES (value1, value2, ...)
     /// 3. INSERT INTO <table name> DEFAULT VALUES
+    ///
+    /// // SQLite
+    /// INSERT [ OR <conflict resolution> ] INTO ...
+    ///
+    /// // MySQL
+    /// INSERT INTO <table name> SET <column name> = <expr> [, ...]
+    ///
+    /// The statement can be followed by a `RETURNING <select list>` clause if the dialect supports it.
     /// ```
     pub fn parse_insert_stmt(&mut self) -> Result<InsertStmt, ParserError> {
-        self.expect_keywords(&[Keyword::INSERT, Keyword::INTO])?;
+        self.expect_keyword(Keyword::INSERT)?;
+        let or = if self.dialect.parser_conf().supports_conflict_clause()
+            && self.parse_keyword(Keyword::OR)
+        {
+            Some(self.parse_conflict_resolution()?)
+        } else {
+            None
+        };
+        self.parse_insert_into(false, or)
+    }
+
+    /// Parses a `REPLACE` statement. (MySQL specific)
+    ///
+    /// ```txt
+    /// REPLACE INTO <table name> <insert columns and source>
+    /// ```
+    pub fn parse_replace_stmt(&mut self) -> Result<InsertStmt, ParserError> {
+        self.expect_keyword(Keyword::REPLACE)?;
+        self.parse_insert_into(true, None)
+    }
+
+    fn parse_insert_into(
+        &mut self,
+        replace: bool,
+        or: Option<ConflictResolution>,
+    ) -> Result<InsertStmt, ParserError> {
+        self.expect_keyword(Keyword::INTO)?;
         let table = self.parse_object_name()?;
-        if self.parse_keywords(&[Keyword::DEFAULT, Keyword::VALUES]) {
+        let source = if self.parse_keywords(&[Keyword::DEFAULT, Keyword::VALUES]) {
             // <from default>
-            let source = InsertSource::Default;
-            Ok(InsertStmt { table, source })
+            InsertSource::Default
+        } else if self.parse_keyword(Keyword::SET) {
+            if !self.dialect.parser_conf().supports_insert_set() {
+                return unsupported_capability(Capability::InsertSet);
+            }
+            // <from assignments>
+            InsertSource::Set(self.parse_comma_separated(Self::parse_assignment)?)
         } else {
-            let columns = self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?;
+            let columns = if self.parse_empty_insert_list()? {
+                Some(Vec::new())
+            } else {
+                self.parse_parenthesized_comma_separated(Self::parse_identifier, true)?
+            };
             let overriding = self.parse_optional_insert_overriding_clause()?;
             // <from subquery> or <from constructor>
-            match self.peek_token().cloned() {
-                Some(token) if token.is_keyword(Keyword::SELECT) => {
+            match self.peek_kind() {
+                Some(kind) if kind.is_keyword(Keyword::SELECT) => {
                     let subquery = Box::new(self.parse_query_expr(true)?);
-                    let source = InsertSource::Subquery {
+                    InsertSource::Subquery {
                         columns,
                         overriding,
                         subquery,
-                    };
-                    Ok(InsertStmt { table, source })
+                    }
                 }
-                Some(token) if token.is_keyword(Keyword::VALUES) => {
-                    let values = self.parse_table_values()?;
-                    let source = InsertSource::Values {
+                Some(kind) if kind.is_keyword(Keyword::VALUES) => {
+                    let values = self.parse_insert_values()?;
+                    InsertSource::Values {
                         columns,
                         overriding,
                         values,
-                    };
-                    Ok(InsertStmt { table, source })
+                    }
                 }
                 _ => {
                     let found = self.peek_token().cloned();
-                    self.expected("insert source", found)
+                    return self.expected("insert source", found);
                 }
             }
+        };
+        let returning = self.parse_returning_clause()?;
+        Ok(InsertStmt {
+            with: None,
+            replace,
+            or,
+            table,
+            source,
+            returning,
+        })
+    }
+
+    /// Parses the `VALUES` of the `INSERT` statement, whose rows can be empty if the dialect
+    /// supports it.
+    ///
+    /// ```txt
+    /// VALUES <row> [, ...]
+    ///
+    /// // MySQL
+    /// <row> ::= <table row value expression> | ()
+    /// ```
+    fn parse_insert_values(&mut self) -> Result<Values, ParserError> {
+        self.expect_keyword(Keyword::VALUES)?;
+        let list = self.parse_comma_separated(|parser| {
+            if parser.parse_empty_insert_list()? {
+                Ok(Vec::new())
+            } else {
+                parser.parse_table_row_value()
+            }
+        })?;
+        Ok(Values { list })
+    }
+
+    /// Consumes an empty `()`, the empty column list or row of the `INSERT` statement,
+    /// returns false if the next tokens are not `()`.
+    fn parse_empty_insert_list(&mut self) -> Result<bool, ParserError> {
+        if self.peek_nth_kind(0) != Some(TokenKind::LeftParen)
+            || self.peek_nth_kind(1) != Some(TokenKind::RightParen)
+        {
+            return Ok(false);
+        }
+        if !self.dialect.parser_conf().supports_empty_insert_values() {
+            return unsupported_capability(Capability::EmptyInsertValues);
+        }
+        self.next_token();
+        self.next_token();
+        Ok(true)
+    }
+
+    /// Parses a conflict resolution algorithm.
+    ///
+    /// ```txt
+    /// <conflict resolution> ::= ROLLBACK | ABORT | FAIL | IGNORE | REPLACE
+    /// ```
+    pub fn parse_conflict_resolution(&mut self) -> Result<ConflictResolution, ParserError> {
+        match self.parse_one_of_keywords(&[
+            Keyword::ROLLBACK,
+            Keyword::ABORT,
+            Keyword::FAIL,
+            Keyword::IGNORE,
+            Keyword::REPLACE,
+        ]) {
+            Some(Keyword::ROLLBACK) => Ok(ConflictResolution::Rollback),
+            Some(Keyword::ABORT) => Ok(ConflictResolution::Abort),
+            Some(Keyword::FAIL) => Ok(ConflictResolution::Fail),
+            Some(Keyword::IGNORE) => Ok(ConflictResolution::Ignore),
+            Some(Keyword::REPLACE) => Ok(ConflictResolution::Replace),
+            _ => {
+                let found = self.peek_token().cloned();
+                self.expected("ROLLBACK, ABORT, FAIL, IGNORE or REPLACE", found)
+            }
         }
     }
 
@@ -93,30 +212,36 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// Parses a `DELETE` statement.
     ///
     /// ```txt
-    /// <delete statement> ::= DELETE FROM <table name> [ [ AS ] <alias> ] [ WHERE <search condition> ]
+    /// <delete statement> ::= [ <with clause> ] DELETE FROM <table name> [ [ AS ] <alias> ] [ WHERE <search condition> ]
+    ///     [ RETURNING <select list> ]
     /// ```
     pub fn parse_delete_stmt(&mut self) -> Result<DeleteStmt, ParserError> {
         self.expect_keywords(&[Keyword::DELETE, Keyword::FROM])?;
         let table = self.parse_object_name()?;
-        match self.peek_token() {
-            Some(token) if token.is_keyword(Keyword::WHERE) => {
-                let selection = self.parse_where_clause()?;
-                Ok(DeleteStmt {
-                    table,
-                    alias: None,
-                    selection,
-                })
-            }
-            _ => {
-                self.parse_keyword(Keyword::AS);
-                let alias = self.parse_identifier()?;
-                let selection = self.parse_where_clause()?;
-                Ok(DeleteStmt {
-                    table,
-                    alias: Some(alias),
-                    selection,
-                })
-            }
+        let alias = self.parse_optional_alias()?;
+        let selection = self.parse_where_clause()?;
+        let returning = self.parse_returning_clause()?;
+        Ok(DeleteStmt {
+            with: None,
+            table,
+            alias,
+            selection,
+            returning,
+        })
+    }
+
+    /// Parses an optional `RETURNING` clause if the dialect supports it.
+    ///
+    /// ```txt
+    /// RETURNING <select item> [, ...]
+    /// ```
+    pub fn parse_returning_clause(&mut self) -> Result<Option<Vec<SelectItem>>, ParserError> {
+        if !self.parse_keyword(Keyword::RETURNING) {
+            Ok(None)
+        } else if self.dialect.parser_conf().supports_returning_clause() {
+            Ok(Some(self.parse_comma_separated(Self::parse_select_item)?))
+        } else {
+            unsupported_capability(Capability::ReturningClause)
         }
     }
 
@@ -127,8 +252,9 @@ impl<'a, D: Dialect> Parser<'a, D> {
     /// Parses a `UPDATE` statement.
     ///
     /// ```txt
-    /// <update statement> ::= UPDATE <table name> [ [ AS] <alias> ]
+    /// <update statement> ::= [ <with clause> ] UPDATE <table name> [ [ AS] <alias> ]
     ///     SET <set clause> [ { , <set clause> }... ] [ WHERE <search condition> ]
+    ///     [ RETURNING <select list> ]
     ///
     /// <set clause> ::= <multiple column assignment> | <set target> = <update source>
     ///
@@ -137,28 +263,32 @@ impl<'a, D: Dialect> Parser<'a, D> {
     pub fn parse_update_stmt(&mut self) -> Result<UpdateStmt, ParserError> {
         self.expect_keyword(Keyword::UPDATE)?;
         let table = self.parse_object_name()?;
-        if self.parse_keyword(Keyword::SET) {
-            let assignments = self.parse_comma_separated(Self::parse_assignment)?;
-            let selection = self.parse_where_clause()?;
-            Ok(UpdateStmt {
-                table,
-                alias: None,
-                assignments,
-                selection,
-            })
+        let alias = if self.parse_keyword(Keyword::SET) {
+            None
         } else {
             self.parse_keyword(Keyword::AS);
             let alias = self.parse_identifier()?;
             self.expect_keyword(Keyword::SET)?;
-            let assignments = self.parse_comma_separated(Self::parse_assignment)?;
-            let selection = self.parse_where_clause()?;
-            Ok(UpdateStmt {
-                table,
-                alias: Some(alias),
-                assignments,
-                selection,
-            })
+            Some(alias)
+        };
+        let assignments = self.parse_comma_separated(Self::parse_assignment)?;
+        let selection = self.parse_where_clause()?;
+        let returning = matches!(
+            self.peek_token(),
+            Some(token) if token.is_keyword(Keyword::RETURNING)
+        );
+        if returning && !self.dialect.parser_conf().supports_update_returning() {
+            return unsupported_capability(Capability::UpdateReturning);
         }
+        let returning = self.parse_returning_clause()?;
+        Ok(UpdateStmt {
+            with: None,
+            table,
+            alias,
+            assignments,
+            selection,
+            returning,
+        })
     }
 
     /// Parses a set clause `target = expr`, used in an UPDATE statement.
@@ -169,6 +299,46 @@ impl<'a, D: Dialect> Parser<'a, D> {
         Ok(Assignment { target, value })
     }
 
+    // ========================================================================
+    // with statement
+    // ========================================================================
+
+    /// Parses a statement with a leading `WITH` clause, which is a query or a data-modifying
+    /// statement.
+    ///
+    /// ```txt
+    /// <with clause> { <query expression> | <insert statement> | <update statement> | <delete statement> }
+    /// ```
+    pub fn parse_with_stmt(&mut self) -> Result<Stmt, ParserError> {
+        let with = self.parse_with_clause()?;
+        let keyword = match self.peek_token() {
+            Some(Token::Word(w)) => w.keyword,
+            _ => None,
+        };
+        match keyword {
+            Some(Keyword::INSERT) => {
+                let stmt = self.parse_insert_stmt()?;
+                Ok(Stmt::Insert(InsertStmt { with, ..stmt }))
+            }
+            Some(Keyword::REPLACE) if self.dialect.parser_conf().supports_insert_set() => {
+                let stmt = self.parse_replace_stmt()?;
+                Ok(Stmt::Insert(InsertStmt { with, ..stmt }))
+            }
+            Some(Keyword::DELETE) => {
+                let stmt = self.parse_delete_stmt()?;
+                Ok(Stmt::Delete(DeleteStmt { with, ..stmt }))
+            }
+            Some(Keyword::UPDATE) => {
+                let stmt = self.parse_update_stmt()?;
+                Ok(Stmt::Update(UpdateStmt { with, ..stmt }))
+            }
+            _ => {
+                let query = self.parse_query_expr(true)?;
+                Ok(Stmt::Select(SelectStmt(Box::new(Query { with, ..query }))))
+            }
+        }
+    }
+
     // ========================================================================
     // select statement
     // ========================================================================
@@ -194,14 +364,21 @@ mod tests {
         assert_eq!(
             Parser::new_with_sql(&dialect, sql)?.parse_insert_stmt()?,
             InsertStmt {
+                with: None,
+                replace: false,
+                or: None,
                 table: ObjectName::new(vec!["table1"]),
                 source: InsertSource::Default,
+                returning: None,
             }
         );
         let sql = "INSERT INTO table1 VALUES ROW(1, 'foo'), ROW(2, 'bar')";
         assert_eq!(
             Parser::new_with_sql(&dialect, sql)?.parse_insert_stmt()?,
             InsertStmt {
+                with: None,
+                replace: false,
+                or: None,
                 table: ObjectName::new(vec!["table1"]),
                 source: InsertSource::Values {
                     columns: None,
@@ -219,12 +396,16 @@ mod tests {
                         ]
                     }
                 },
+                returning: None,
             }
         );
         let sql = "INSERT INTO table1 SELECT * FROM table2 where id < 100";
         assert_eq!(
             Parser::new_with_sql(&dialect, sql)?.parse_insert_stmt()?,
             InsertStmt {
+                with: None,
+                replace: false,
+                or: None,
                 table: ObjectName::new(vec!["table1"]),
                 source: InsertSource::Subquery {
                     columns: None,
@@ -238,7 +419,10 @@ mod tests {
                                 list: vec![TableReference {
                                     relation: TableFactor::Table {
                                         name: ObjectName(vec![Ident::new("table2")]),
+                                        partitions: None,
+                                        system_time: None,
                                         alias: None,
+                                        index_hints: vec![],
                                     },
                                     joins: vec![]
                                 }],
@@ -250,6 +434,7 @@ mod tests {
                                     right: Box::new(Expr::Literal(Literal::Number("100".into())))
                                 }))
                             }),
+                            connect_by: None,
                             group_by: None,
                             having: None,
                             window: None
@@ -257,9 +442,11 @@ mod tests {
                         order_by: None,
                         limit: None,
                         offset: None,
-                        fetch: None
+                        fetch: None,
+                        output: None
                     }),
                 },
+                returning: None,
             }
         );
         Ok(())
@@ -272,6 +459,7 @@ mod tests {
         assert_eq!(
             Parser::new_with_sql(&dialect, sql)?.parse_delete_stmt()?,
             DeleteStmt {
+                with: None,
                 table: ObjectName::new(vec!["table1"]),
                 alias: Some(Ident::new("t1")),
                 selection: Some(Where {
@@ -280,9 +468,226 @@ mod tests {
                         op: BinaryOperator::Equal,
                         right: Box::new(Expr::Literal(Literal::Number("1".into())))
                     }))
-                })
+                }),
+                returning: None,
             }
         );
+
+        // neither alias nor search condition
+        let sql = "DELETE FROM table1";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?.parse_delete_stmt()?,
+            DeleteStmt {
+                with: None,
+                table: ObjectName::new(vec!["table1"]),
+                alias: None,
+                selection: None,
+                returning: None,
+            }
+        );
+        let sql = "DELETE FROM table1 t1";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?
+                .parse_stmt()?
+                .to_string(),
+            "DELETE FROM table1 AS t1"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_returning_clause() -> Result<(), ParserError> {
+        let dialect = crate::mariadb::MariadbDialect::default();
+        let sqls = [
+            "INSERT INTO t (a, b) VALUES (1, 2) RETURNING a, b AS c",
+            "INSERT INTO t DEFAULT VALUES RETURNING *",
+            "DELETE FROM t WHERE a = 1 RETURNING *",
+            "DELETE FROM t RETURNING a",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // the RETURNING clause is only supported by some dialects
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "DELETE FROM t WHERE a = 1 RETURNING *";
+        assert_eq!(
+            Parser::parse_script(&dialect, sql).map(|_| ()),
+            unsupported_capability(Capability::ReturningClause)
+        );
+
+        // MariaDB doesn't support the RETURNING clause of UPDATE
+        let dialect = crate::mariadb::MariadbDialect::default();
+        let sql = "UPDATE t SET a = 1 RETURNING *";
+        assert_eq!(
+            Parser::parse_script(&dialect, sql).map(|_| ()),
+            unsupported_capability(Capability::UpdateReturning)
+        );
+
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sql = "UPDATE t AS x SET a = 1 WHERE b = 2 RETURNING a, b AS c";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_update_stmt()?;
+        assert_eq!(stmt.returning.as_ref().map(Vec::len), Some(2));
+        assert_eq!(stmt.to_string(), sql);
+        let sqls = [
+            "UPDATE t SET a = 1 RETURNING *",
+            "WITH u AS (UPDATE t SET a = a + 1 RETURNING *) SELECT * FROM u",
+            "WITH u AS (UPDATE t SET a = 1 WHERE b = 2 RETURNING a) DELETE FROM t2 WHERE a IN (SELECT a FROM u) RETURNING a",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+        let sql = "WITH u AS (UPDATE t SET a = 1 RETURNING a) SELECT * FROM u";
+        match Parser::new_with_sql(&dialect, sql)?.parse_stmt()? {
+            Stmt::Select(SelectStmt(query)) => match &query.with.as_ref().unwrap().ctes[0].body {
+                CteBody::Update(update) => assert!(update.returning.is_some()),
+                body => panic!("unexpected CTE body: {:?}", body),
+            },
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+        let dialect = crate::sqlite::SqliteDialect::default();
+        let sql = "UPDATE t SET a = 1 RETURNING a";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?
+                .parse_stmt()?
+                .to_string(),
+            sql
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_insert_or_stmt() -> Result<(), ParserError> {
+        let dialect = crate::sqlite::SqliteDialect::default();
+        let sql = "INSERT OR REPLACE INTO t1 DEFAULT VALUES";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?.parse_insert_stmt()?,
+            InsertStmt {
+                with: None,
+                replace: false,
+                or: Some(ConflictResolution::Replace),
+                table: ObjectName::new(vec!["t1"]),
+                source: InsertSource::Default,
+                returning: None,
+            }
+        );
+        for resolution in ["ROLLBACK", "ABORT", "FAIL", "IGNORE", "REPLACE"] {
+            let sql = format!("INSERT OR {} INTO t1 DEFAULT VALUES", resolution);
+            assert_eq!(
+                Parser::new_with_sql(&dialect, &sql)?
+                    .parse_insert_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+        assert!(
+            Parser::new_with_sql(&dialect, "INSERT OR UPDATE INTO t1 DEFAULT VALUES")?
+                .parse_insert_stmt()
+                .is_err()
+        );
+
+        // the conflict clause is only supported by SQLite
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "INSERT OR REPLACE INTO t1 DEFAULT VALUES";
+        assert!(Parser::parse_script(&dialect, sql).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_mysql_insert_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "INSERT INTO t1 SET a = 1, b = 'x'";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?.parse_insert_stmt()?,
+            InsertStmt {
+                with: None,
+                replace: false,
+                or: None,
+                table: ObjectName::new(vec!["t1"]),
+                source: InsertSource::Set(vec![
+                    Assignment {
+                        target: Ident::new("a"),
+                        value: Box::new(Expr::Literal(Literal::Number("1".into()))),
+                    },
+                    Assignment {
+                        target: Ident::new("b"),
+                        value: Box::new(Expr::Literal(Literal::String("x".into()))),
+                    },
+                ]),
+                returning: None,
+            }
+        );
+        let sqls = [
+            "REPLACE INTO t1 VALUES (1, 'x')",
+            "REPLACE INTO t1 SET a = 1",
+            "REPLACE INTO t1 (a, b) SELECT a, b FROM t2",
+        ];
+        for sql in sqls {
+            let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+            assert!(matches!(
+                stmt,
+                Stmt::Insert(InsertStmt { replace: true, .. })
+            ));
+            assert_eq!(stmt.to_string(), sql);
+        }
+
+        // the empty column list and rows insert a row of the default values
+        let sql = "INSERT INTO t1 () VALUES ()";
+        assert_eq!(
+            Parser::new_with_sql(&dialect, sql)?.parse_insert_stmt()?,
+            InsertStmt {
+                with: None,
+                replace: false,
+                or: None,
+                table: ObjectName::new(vec!["t1"]),
+                source: InsertSource::Values {
+                    columns: Some(vec![]),
+                    overriding: None,
+                    values: Values { list: vec![vec![]] },
+                },
+                returning: None,
+            }
+        );
+        let sqls = [
+            "INSERT INTO t1 VALUES (), ()",
+            "INSERT INTO t1 (a) VALUES (1), ()",
+            "REPLACE INTO t1 () VALUES ()",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        // these forms are only supported by MySQL and MariaDB
+        let dialect = crate::postgres::PostgresDialect::default();
+        for sql in ["INSERT INTO t1 () VALUES (1)", "INSERT INTO t1 VALUES ()"] {
+            assert_eq!(
+                Parser::parse_script(&dialect, sql).map(|_| ()),
+                unsupported_capability(Capability::EmptyInsertValues)
+            );
+        }
+        assert_eq!(
+            Parser::parse_script(&dialect, "INSERT INTO t1 SET a = 1").map(|_| ()),
+            unsupported_capability(Capability::InsertSet)
+        );
+        assert_eq!(
+            Parser::parse_script(&dialect, "REPLACE INTO t1 VALUES (1)").map(|_| ()),
+            unsupported_capability(Capability::InsertSet)
+        );
         Ok(())
     }
 
@@ -293,6 +698,7 @@ mod tests {
         assert_eq!(
             Parser::new_with_sql(&dialect, sql)?.parse_update_stmt()?,
             UpdateStmt {
+                with: None,
                 table: ObjectName::new(vec!["table1"]),
                 alias: Some(Ident::new("t1")),
                 assignments: vec![
@@ -311,12 +717,49 @@ mod tests {
                         op: BinaryOperator::Equal,
                         right: Box::new(Expr::Literal(Literal::Number("3".into())))
                     }))
-                })
+                }),
+                returning: None,
             }
         );
         Ok(())
     }
 
+    #[test]
+    fn parse_with_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let sqls = [
+            "WITH t AS (SELECT 1) SELECT * FROM t",
+            "WITH t AS (SELECT 1) INSERT INTO t1 SELECT * FROM t",
+            "WITH RECURSIVE t (n) AS (SELECT 1) UPDATE t1 SET a = 1 WHERE b IN (SELECT n FROM t)",
+            "WITH t AS (SELECT id FROM t2) DELETE FROM t1 WHERE id IN (SELECT id FROM t) RETURNING id",
+        ];
+        for sql in sqls {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_with_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        let sql = "WITH t AS (SELECT 1) DELETE FROM t1";
+        match Parser::new_with_sql(&dialect, sql)?.parse_with_stmt()? {
+            Stmt::Delete(stmt) => {
+                let with = stmt.with.expect("with clause");
+                assert!(!with.recursive);
+                assert_eq!(with.ctes[0].name, Ident::new("t"));
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+
+        assert!(
+            Parser::new_with_sql(&dialect, "WITH t AS (SELECT 1) DROP TABLE t1")?
+                .parse_with_stmt()
+                .is_err()
+        );
+        Ok(())
+    }
+
     #[test]
     fn parse_select_stmt() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
@@ -332,7 +775,10 @@ mod tests {
                         list: vec![TableReference {
                             relation: TableFactor::Table {
                                 name: ObjectName::new(vec!["table1"]),
+                                partitions: None,
+                                system_time: None,
                                 alias: None,
+                                index_hints: vec![],
                             },
                             joins: vec![]
                         }],
@@ -344,6 +790,7 @@ mod tests {
                             right: Box::new(Expr::Literal(Literal::Number("1".into())))
                         }))
                     }),
+                    connect_by: None,
                     group_by: None,
                     having: None,
                     window: None
@@ -351,9 +798,12 @@ mod tests {
                 order_by: Some(OrderBy {
                     list: vec![SortSpec {
                         expr: Box::new(Expr::Identifier(Ident::new("col2"))),
+                        ordinal: None,
                         asc: Some(false),
+                        using: None,
                         nulls_first: None
-                    }]
+                    }],
+                    all: None
                 }),
                 limit: None,
                 offset: Some(Offset {
@@ -364,7 +814,8 @@ mod tests {
                     quantity: Some(Literal::Number("10".into())),
                     percent: false,
                     with_ties: false,
-                })
+                }),
+                output: None
             }))
         );
 
@@ -380,7 +831,10 @@ mod tests {
                         list: vec![TableReference {
                             relation: TableFactor::Table {
                                 name: ObjectName::new(vec!["table1"]),
+                                partitions: None,
+                                system_time: None,
                                 alias: None,
+                                index_hints: vec![],
                             },
                             joins: vec![]
                         }],
@@ -392,6 +846,7 @@ mod tests {
                             right: Box::new(Expr::Literal(Literal::Number("1".into())))
                         }))
                     }),
+                    connect_by: None,
                     group_by: None,
                     having: None,
                     window: None
@@ -399,9 +854,12 @@ mod tests {
                 order_by: Some(OrderBy {
                     list: vec![SortSpec {
                         expr: Box::new(Expr::Identifier(Ident::new("col2"))),
+                        ordinal: None,
                         asc: Some(false),
+                        using: None,
                         nulls_first: None
-                    }]
+                    }],
+                    all: None
                 }),
                 limit: None,
                 offset: Some(Offset {
@@ -409,6 +867,7 @@ mod tests {
                     rows: OffsetRows::Rows,
                 }),
                 fetch: None,
+                output: None,
             }))
         );
 
@@ -425,7 +884,10 @@ mod tests {
                         list: vec![TableReference {
                             relation: TableFactor::Table {
                                 name: ObjectName::new(vec!["table1"]),
+                                partitions: None,
+                                system_time: None,
                                 alias: None,
+                                index_hints: vec![],
                             },
                             joins: vec![]
                         }],
@@ -437,6 +899,7 @@ mod tests {
                             right: Box::new(Expr::Literal(Literal::Number("1".into())))
                         }))
                     }),
+                    connect_by: None,
                     group_by: None,
                     having: None,
                     window: None
@@ -444,9 +907,12 @@ mod tests {
                 order_by: Some(OrderBy {
                     list: vec![SortSpec {
                         expr: Box::new(Expr::Identifier(Ident::new("col2"))),
+                        ordinal: None,
                         asc: Some(false),
+                        using: None,
                         nulls_first: None
-                    }]
+                    }],
+                    all: None
                 }),
                 limit: Some(Limit {
                     count: Literal::Number("100".into()),
@@ -456,6 +922,7 @@ mod tests {
                     rows: OffsetRows::Rows,
                 }),
                 fetch: None,
+                output: None,
             }))
         );
         Ok(())
@@ -1,3 +1,329 @@
+mod admin;
+mod dcl;
 mod ddl;
 mod dml;
+mod procedural;
 mod transaction;
+
+use crate::{
+    ast::statement::*,
+    dialect::{Capability, Dialect, DialectParserConf},
+    error::{unsupported_capability, ParserError},
+    keywords::Keyword,
+    parser::Parser,
+    tokens::Token,
+};
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses a top-level statement (SELECT, INSERT, CREATE, etc.).
+    pub fn parse_stmt(&mut self) -> Result<Stmt, ParserError> {
+        let keyword = match self.peek_token() {
+            Some(Token::Word(w)) => w.keyword,
+            Some(Token::LeftParen) => return Ok(Stmt::Select(self.parse_select_stmt()?)),
+            Some(Token::MetaCommand(_)) => {
+                return Ok(Stmt::MetaCommand(self.parse_meta_command_stmt()?))
+            }
+            _ => None,
+        };
+        match keyword {
+            Some(Keyword::CREATE) => self.parse_create_stmt(),
+            Some(Keyword::ALTER) => self.parse_alter_stmt(),
+            Some(Keyword::DROP) => match self.peek_second_keyword() {
+                Some(Keyword::POLICY) => Ok(Stmt::DropPolicy(self.parse_drop_policy_stmt()?)),
+                Some(Keyword::ROLE | Keyword::USER) => {
+                    Ok(Stmt::DropRole(self.parse_drop_role_stmt()?))
+                }
+                _ => Ok(Stmt::Drop(self.parse_drop_stmt()?)),
+            },
+            Some(Keyword::RENAME) if self.dialect.parser_conf().supports_rename_table() => {
+                Ok(Stmt::RenameTable(self.parse_rename_table_stmt()?))
+            }
+            Some(Keyword::RENAME) => unsupported_capability(Capability::RenameTable),
+
+            Some(Keyword::INSERT) => Ok(Stmt::Insert(self.parse_insert_stmt()?)),
+            Some(Keyword::REPLACE) if self.dialect.parser_conf().supports_insert_set() => {
+                Ok(Stmt::Insert(self.parse_replace_stmt()?))
+            }
+            Some(Keyword::REPLACE) => unsupported_capability(Capability::InsertSet),
+            Some(Keyword::DELETE) => Ok(Stmt::Delete(self.parse_delete_stmt()?)),
+            Some(Keyword::UPDATE) => Ok(Stmt::Update(self.parse_update_stmt()?)),
+            Some(Keyword::WITH) => self.parse_with_stmt(),
+            Some(Keyword::SELECT | Keyword::VALUES) => Ok(Stmt::Select(self.parse_select_stmt()?)),
+
+            Some(Keyword::START) => {
+                Ok(Stmt::StartTransaction(self.parse_start_transaction_stmt()?))
+            }
+            Some(Keyword::BEGIN) => Ok(Stmt::StartTransaction(self.parse_begin_stmt()?)),
+            Some(Keyword::SET) => Ok(Stmt::SetTransaction(self.parse_set_transaction_stmt()?)),
+            Some(Keyword::COMMIT) => Ok(Stmt::CommitTransaction(self.parse_commit_stmt()?)),
+            Some(Keyword::ROLLBACK) => Ok(Stmt::RollbackTransaction(self.parse_rollback_stmt()?)),
+
+            Some(Keyword::IMPORT) => Ok(Stmt::ImportForeignSchema(
+                self.parse_import_foreign_schema_stmt()?,
+            )),
+
+            Some(Keyword::DO) => Ok(Stmt::Do(self.parse_do_stmt()?)),
+
+            Some(keyword) if self.dialect.parser_conf().supports_sqlite_statements() => {
+                match keyword {
+                    Keyword::PRAGMA => Ok(Stmt::Pragma(self.parse_pragma_stmt()?)),
+                    Keyword::ATTACH => Ok(Stmt::Attach(self.parse_attach_stmt()?)),
+                    Keyword::DETACH => Ok(Stmt::Detach(self.parse_detach_stmt()?)),
+                    Keyword::VACUUM => Ok(Stmt::Vacuum(self.parse_vacuum_stmt()?)),
+                    Keyword::ANALYZE => Ok(Stmt::Analyze(self.parse_analyze_stmt()?)),
+                    Keyword::REINDEX => Ok(Stmt::Reindex(self.parse_reindex_stmt()?)),
+                    _ => {
+                        let found = self.peek_token().cloned();
+                        self.expected_any_keyword("statement", found)
+                    }
+                }
+            }
+            _ => {
+                let found = self.peek_token().cloned();
+                self.expected_any_keyword("statement", found)
+            }
+        }
+    }
+
+    /// Parses a psql meta-command.
+    pub fn parse_meta_command_stmt(&mut self) -> Result<MetaCommandStmt, ParserError> {
+        match self.next_token() {
+            Some(Token::MetaCommand(command)) => {
+                let command = command.trim();
+                let (name, args) = match command.find(char::is_whitespace) {
+                    Some(idx) => (&command[..idx], command[idx..].trim_start()),
+                    None => (command, ""),
+                };
+                Ok(MetaCommandStmt {
+                    name: name.into(),
+                    args: args.into(),
+                })
+            }
+            unexpected => self.expected("meta-command", unexpected),
+        }
+    }
+
+    /// Parses a `CREATE { TABLE | VIEW | DOMAIN | TYPE | DATABASE | SEQUENCE } ...` statement.
+    fn parse_create_stmt(&mut self) -> Result<Stmt, ParserError> {
+        let or_replace_sequence = matches!(
+            self.peek_nth_token(3),
+            Some(token) if token.is_keyword(Keyword::SEQUENCE)
+        );
+        let user_mapping = matches!(
+            self.peek_nth_token(2),
+            Some(token) if token.is_keyword(Keyword::MAPPING)
+        );
+        let keyword = match self.peek_second_keyword() {
+            // the object is specified after the `DEFINER` clause of MySQL
+            Some(Keyword::DEFINER) => self.peek_definer_object().or(Some(Keyword::DEFINER)),
+            keyword => keyword,
+        };
+        match keyword {
+            Some(Keyword::OR) if or_replace_sequence => {
+                Ok(Stmt::CreateSequence(self.parse_create_sequence_stmt()?))
+            }
+            Some(Keyword::TABLE | Keyword::GLOBAL | Keyword::LOCAL) => {
+                Ok(Stmt::CreateTable(self.parse_create_table_stmt()?))
+            }
+            Some(
+                Keyword::VIEW
+                | Keyword::OR
+                | Keyword::RECURSIVE
+                | Keyword::ALGORITHM
+                | Keyword::DEFINER
+                | Keyword::SQL,
+            ) => Ok(Stmt::CreateView(self.parse_create_view_stmt()?)),
+            Some(Keyword::DOMAIN) => Ok(Stmt::CreateDomain(self.parse_create_domain_stmt()?)),
+            Some(Keyword::TYPE) => Ok(Stmt::CreateType(self.parse_create_type_stmt()?)),
+            Some(Keyword::DATABASE) => Ok(Stmt::CreateDatabase(self.parse_create_database_stmt()?)),
+            Some(Keyword::SEQUENCE) => Ok(Stmt::CreateSequence(self.parse_create_sequence_stmt()?)),
+            Some(Keyword::POLICY) => Ok(Stmt::CreatePolicy(self.parse_create_policy_stmt()?)),
+            Some(Keyword::USER) if user_mapping => Ok(Stmt::CreateUserMapping(
+                self.parse_create_user_mapping_stmt()?,
+            )),
+            Some(Keyword::ROLE | Keyword::USER) => {
+                Ok(Stmt::CreateRole(self.parse_create_role_stmt()?))
+            }
+            Some(Keyword::SERVER) => Ok(Stmt::CreateServer(self.parse_create_server_stmt()?)),
+            Some(Keyword::FOREIGN) => Ok(Stmt::CreateForeignTable(
+                self.parse_create_foreign_table_stmt()?,
+            )),
+            #[cfg(feature = "procedural")]
+            Some(Keyword::PROCEDURE | Keyword::FUNCTION) => {
+                Ok(Stmt::CreateRoutine(self.parse_create_routine_stmt()?))
+            }
+            #[cfg(feature = "procedural")]
+            Some(Keyword::TRIGGER) => Ok(Stmt::CreateTrigger(self.parse_create_trigger_stmt()?)),
+            _ => {
+                self.next_token();
+                let found = self.peek_token().cloned();
+                self.expected(
+                    "TABLE, VIEW, DOMAIN, TYPE, DATABASE or SEQUENCE after CREATE",
+                    found,
+                )
+            }
+        }
+    }
+
+    /// Parses a `ALTER { TABLE | VIEW | DOMAIN | TYPE | SCHEMA | INDEX } ...` statement.
+    fn parse_alter_stmt(&mut self) -> Result<Stmt, ParserError> {
+        match self.peek_second_keyword() {
+            Some(Keyword::TABLE) => Ok(Stmt::AlterTable(self.parse_alter_table_stmt()?)),
+            Some(Keyword::VIEW) => Ok(Stmt::AlterView(self.parse_alter_view_stmt()?)),
+            Some(Keyword::DOMAIN) => Ok(Stmt::AlterDomain(self.parse_alter_domain_stmt()?)),
+            Some(Keyword::TYPE) => Ok(Stmt::AlterType(self.parse_alter_type_stmt()?)),
+            Some(Keyword::SCHEMA) => Ok(Stmt::AlterSchema(self.parse_alter_schema_stmt()?)),
+            Some(Keyword::INDEX) => Ok(Stmt::AlterIndex(self.parse_alter_index_stmt()?)),
+            Some(Keyword::ROLE | Keyword::USER) => {
+                Ok(Stmt::AlterRole(self.parse_alter_role_stmt()?))
+            }
+            _ => {
+                self.next_token();
+                let found = self.peek_token().cloned();
+                self.expected(
+                    "TABLE, VIEW, DOMAIN, TYPE, SCHEMA or INDEX after ALTER",
+                    found,
+                )
+            }
+        }
+    }
+
+    /// Returns the first keyword of the objects which may follow the `DEFINER` clause of a
+    /// `CREATE` statement, like `VIEW` or `PROCEDURE`, without advancing the iterator.
+    fn peek_definer_object(&mut self) -> Option<Keyword> {
+        self.reset_peek_cursor();
+        let keyword = loop {
+            match self.peek_next_token() {
+                Some(Token::SemiColon) | None => break None,
+                Some(token) => {
+                    let keyword = token.is_one_of_keywords(&[
+                        Keyword::VIEW,
+                        Keyword::SQL,
+                        Keyword::PROCEDURE,
+                        Keyword::FUNCTION,
+                        Keyword::TRIGGER,
+                    ]);
+                    if keyword.is_some() {
+                        break keyword;
+                    }
+                }
+            }
+        };
+        self.reset_peek_cursor();
+        keyword
+    }
+
+    /// Returns the keyword of the second token without advancing the iterator.
+    fn peek_second_keyword(&mut self) -> Option<Keyword> {
+        self.reset_peek_cursor();
+        self.peek_next_token();
+        let keyword = match self.peek_next_token() {
+            Some(Token::Word(w)) => w.keyword,
+            _ => None,
+        };
+        self.reset_peek_cursor();
+        keyword
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_stmt() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let parse = |sql: &str| Parser::new_with_sql(&dialect, sql)?.parse_stmt();
+
+        assert!(matches!(
+            parse("CREATE TABLE foo (bar INT)")?,
+            Stmt::CreateTable(_)
+        ));
+        assert!(matches!(
+            parse("CREATE OR REPLACE VIEW foo AS SELECT 1")?,
+            Stmt::CreateView(_)
+        ));
+        assert!(matches!(
+            parse("ALTER TABLE foo DROP COLUMN bar")?,
+            Stmt::AlterTable(_)
+        ));
+        assert!(matches!(parse("DROP TABLE foo")?, Stmt::Drop(_)));
+        assert!(matches!(
+            parse("INSERT INTO foo DEFAULT VALUES")?,
+            Stmt::Insert(_)
+        ));
+        assert!(matches!(
+            parse("DELETE FROM foo WHERE bar = 1")?,
+            Stmt::Delete(_)
+        ));
+        assert!(matches!(parse("UPDATE foo SET bar = 1")?, Stmt::Update(_)));
+        assert!(matches!(parse("SELECT * FROM foo")?, Stmt::Select(_)));
+        assert!(matches!(
+            parse("START TRANSACTION")?,
+            Stmt::StartTransaction(_)
+        ));
+        assert!(matches!(parse("COMMIT")?, Stmt::CommitTransaction(_)));
+        assert!(matches!(parse("ROLLBACK")?, Stmt::RollbackTransaction(_)));
+
+        let dialect = crate::postgres::PostgresDialect::new(
+            crate::postgres::PostgresLexerConfig { psql_mode: true },
+            Default::default(),
+        );
+        assert_eq!(
+            Parser::new_with_sql(&dialect, "\\i  file.sql ")?.parse_stmt()?,
+            Stmt::MetaCommand(MetaCommandStmt {
+                name: "i".into(),
+                args: "file.sql".into(),
+            })
+        );
+
+        assert!(parse("CREATE foo").is_err());
+        assert!(parse("foo").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_quoted_identifier_round_trip() -> Result<(), ParserError> {
+        fn round_trip<D: crate::Dialect>(dialect: &D, sql: &str) -> Result<(), ParserError> {
+            let stmt = Parser::new_with_sql(dialect, sql)?.parse_stmt()?;
+            assert_eq!(stmt.to_string(), sql);
+            Ok(())
+        }
+
+        let postgres = crate::postgres::PostgresDialect::default();
+        let sqls = [
+            "SELECT \"a\"\"b\", \"Col\" FROM \"Sch\".\"T\" AS \"x\" WHERE \"x\".\"Col\" = 1",
+            "CREATE TABLE \"T\" (\"Col\" INT)",
+            "ALTER TABLE \"T\" RENAME TO \"U\"",
+            "INSERT INTO \"T\" (\"Col\") VALUES (1)",
+            "UPDATE \"T\" SET \"Col\" = 1",
+            "DROP TABLE \"T\"",
+        ];
+        for sql in sqls {
+            round_trip(&postgres, sql)?;
+        }
+
+        let mysql = crate::mysql::MysqlDialect::default();
+        round_trip(&mysql, "SELECT `a``b`, \"c\" FROM `t` ORDER BY `a``b`")?;
+
+        let sqlite = crate::sqlite::SqliteDialect::default();
+        round_trip(&sqlite, "SELECT [a b], \"c\", `d` FROM [t]")?;
+        Ok(())
+    }
+
+    #[test]
+    fn parse_stmt_with_token_stream() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        let stream = crate::Lexer::new(&dialect, "SELECT a FROM foo").tokenize_stream()?;
+        assert_eq!(stream.len(), 7);
+
+        let first = Parser::new_with_token_stream(&dialect, &stream).parse_stmt()?;
+        let second = Parser::new_with_token_stream(&dialect, &stream).parse_stmt()?;
+        assert_eq!(first, second);
+        assert_eq!(
+            first,
+            Parser::new_with_sql(&dialect, "SELECT a FROM foo")?.parse_stmt()?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,609 @@
+#[cfg(all(feature = "procedural", not(feature = "std")))]
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use crate::{
+    ast::statement::*, dialect::Dialect, error::ParserError, keywords::Keyword, parser::Parser,
+    tokens::Token,
+};
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses a `DO` statement.
+    ///
+    /// ```txt
+    /// DO [ LANGUAGE <lang name> ] <code>
+    /// ```
+    ///
+    /// The `LANGUAGE` clause can also follow the code.
+    pub fn parse_do_stmt(&mut self) -> Result<DoStmt, ParserError> {
+        self.expect_keyword(Keyword::DO)?;
+        let mut language = self.parse_keyword_then(Keyword::LANGUAGE, Self::parse_identifier)?;
+        let (code, tag) = match self.next_token() {
+            Some(Token::DollarString { tag, value }) => (value, Some(tag)),
+            Some(Token::String(s)) => (s, None),
+            unexpected => return self.expected("code block", unexpected),
+        };
+        if language.is_none() {
+            language = self.parse_keyword_then(Keyword::LANGUAGE, Self::parse_identifier)?;
+        }
+        Ok(DoStmt {
+            language,
+            code,
+            tag,
+        })
+    }
+
+    /// Parses a `CREATE PROCEDURE` or `CREATE FUNCTION` statement.
+    ///
+    /// ```txt
+    /// CREATE [ DEFINER = <user name> ] PROCEDURE [ IF NOT EXISTS ] <routine name>
+    ///     ( [ <routine parameter> [, ...] ] ) [ <routine characteristic> [...] ]
+    ///     <procedural statement>
+    ///
+    /// CREATE [ DEFINER = <user name> ] FUNCTION [ IF NOT EXISTS ] <routine name>
+    ///     ( [ <routine parameter> [, ...] ] ) RETURNS <data type> [ <routine characteristic> [...] ]
+    ///     <procedural statement>
+    /// ```
+    #[cfg(feature = "procedural")]
+    pub fn parse_create_routine_stmt(&mut self) -> Result<CreateRoutineStmt, ParserError> {
+        self.expect_keyword(Keyword::CREATE)?;
+        let definer = self.parse_definer()?;
+        let kind = match self.expect_one_of_keywords(&[Keyword::PROCEDURE, Keyword::FUNCTION])? {
+            Keyword::PROCEDURE => RoutineKind::Procedure,
+            _ => RoutineKind::Function,
+        };
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        self.expect_token(&Token::LeftParen)?;
+        let params = if self.next_token_if_is(&Token::RightParen) {
+            vec![]
+        } else {
+            let params = self.parse_comma_separated(|parser| parser.parse_routine_param(kind))?;
+            self.expect_token(&Token::RightParen)?;
+            params
+        };
+        let returns = match kind {
+            RoutineKind::Function => {
+                self.expect_keyword(Keyword::RETURNS)?;
+                Some(self.parse_data_type()?)
+            }
+            RoutineKind::Procedure => None,
+        };
+        let mut characteristics = vec![];
+        while let Some(characteristic) = self.parse_routine_characteristic()? {
+            characteristics.push(characteristic);
+        }
+        let body = self.parse_procedural_stmt()?;
+        Ok(CreateRoutineStmt {
+            definer,
+            kind,
+            if_not_exists,
+            name,
+            params,
+            returns,
+            characteristics,
+            body,
+        })
+    }
+
+    /// Parses a parameter of a stored routine, the mode is only allowed for a procedure.
+    ///
+    /// ```txt
+    /// <routine parameter> ::= [ IN | OUT | INOUT ] <parameter name> <data type>
+    /// ```
+    #[cfg(feature = "procedural")]
+    fn parse_routine_param(&mut self, kind: RoutineKind) -> Result<RoutineParam, ParserError> {
+        let mode = match kind {
+            RoutineKind::Procedure => {
+                match self.parse_one_of_keywords(&[Keyword::IN, Keyword::OUT, Keyword::INOUT]) {
+                    Some(Keyword::IN) => Some(ParamMode::In),
+                    Some(Keyword::OUT) => Some(ParamMode::Out),
+                    Some(Keyword::INOUT) => Some(ParamMode::InOut),
+                    _ => None,
+                }
+            }
+            RoutineKind::Function => None,
+        };
+        let name = self.parse_identifier()?;
+        let data_type = self.parse_data_type()?;
+        Ok(RoutineParam {
+            mode,
+            name,
+            data_type,
+        })
+    }
+
+    /// Parses a characteristic of a stored routine, returns `None` if there is no one.
+    ///
+    /// ```txt
+    /// <routine characteristic> ::=
+    ///     COMMENT <string>
+    ///     | LANGUAGE SQL
+    ///     | [ NOT ] DETERMINISTIC
+    ///     | { CONTAINS SQL | NO SQL | READS SQL DATA | MODIFIES SQL DATA }
+    ///     | SQL SECURITY { DEFINER | INVOKER }
+    /// ```
+    #[cfg(feature = "procedural")]
+    fn parse_routine_characteristic(
+        &mut self,
+    ) -> Result<Option<RoutineCharacteristic>, ParserError> {
+        let keyword = self.parse_one_of_keywords(&[
+            Keyword::COMMENT,
+            Keyword::LANGUAGE,
+            Keyword::NOT,
+            Keyword::DETERMINISTIC,
+            Keyword::CONTAINS,
+            Keyword::NO,
+            Keyword::READS,
+            Keyword::MODIFIES,
+            Keyword::SQL,
+        ]);
+        Ok(Some(match keyword {
+            Some(Keyword::COMMENT) => {
+                RoutineCharacteristic::Comment(self.parse_literal_string("comment")?)
+            }
+            Some(Keyword::LANGUAGE) => {
+                self.expect_keyword(Keyword::SQL)?;
+                RoutineCharacteristic::LanguageSql
+            }
+            Some(Keyword::NOT) => {
+                self.expect_keyword(Keyword::DETERMINISTIC)?;
+                RoutineCharacteristic::Deterministic(false)
+            }
+            Some(Keyword::DETERMINISTIC) => RoutineCharacteristic::Deterministic(true),
+            Some(Keyword::CONTAINS) => {
+                self.expect_keyword(Keyword::SQL)?;
+                RoutineCharacteristic::ContainsSql
+            }
+            Some(Keyword::NO) => {
+                self.expect_keyword(Keyword::SQL)?;
+                RoutineCharacteristic::NoSql
+            }
+            Some(Keyword::READS) => {
+                self.expect_keywords(&[Keyword::SQL, Keyword::DATA])?;
+                RoutineCharacteristic::ReadsSqlData
+            }
+            Some(Keyword::MODIFIES) => {
+                self.expect_keywords(&[Keyword::SQL, Keyword::DATA])?;
+                RoutineCharacteristic::ModifiesSqlData
+            }
+            Some(Keyword::SQL) => {
+                self.expect_keyword(Keyword::SECURITY)?;
+                RoutineCharacteristic::SqlSecurity(self.parse_sql_security()?)
+            }
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Parses a `CREATE TRIGGER` statement.
+    ///
+    /// ```txt
+    /// CREATE [ DEFINER = <user name> ] TRIGGER [ IF NOT EXISTS ] <trigger name>
+    ///     { BEFORE | AFTER } { INSERT | UPDATE | DELETE } ON <table name> FOR EACH ROW
+    ///     [ { FOLLOWS | PRECEDES } <trigger name> ] <procedural statement>
+    /// ```
+    #[cfg(feature = "procedural")]
+    pub fn parse_create_trigger_stmt(&mut self) -> Result<CreateTriggerStmt, ParserError> {
+        self.expect_keyword(Keyword::CREATE)?;
+        let definer = self.parse_definer()?;
+        self.expect_keyword(Keyword::TRIGGER)?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        let timing = match self.expect_one_of_keywords(&[Keyword::BEFORE, Keyword::AFTER])? {
+            Keyword::BEFORE => TriggerTiming::Before,
+            _ => TriggerTiming::After,
+        };
+        let event = match self.expect_one_of_keywords(&[
+            Keyword::INSERT,
+            Keyword::UPDATE,
+            Keyword::DELETE,
+        ])? {
+            Keyword::INSERT => TriggerEvent::Insert,
+            Keyword::UPDATE => TriggerEvent::Update,
+            _ => TriggerEvent::Delete,
+        };
+        self.expect_keyword(Keyword::ON)?;
+        let table = self.parse_object_name()?;
+        self.expect_keywords(&[Keyword::FOR, Keyword::EACH, Keyword::ROW])?;
+        let order = match self.parse_one_of_keywords(&[Keyword::FOLLOWS, Keyword::PRECEDES]) {
+            Some(Keyword::FOLLOWS) => Some(TriggerOrder::Follows(self.parse_identifier()?)),
+            Some(Keyword::PRECEDES) => Some(TriggerOrder::Precedes(self.parse_identifier()?)),
+            _ => None,
+        };
+        let body = self.parse_procedural_stmt()?;
+        Ok(CreateTriggerStmt {
+            definer,
+            if_not_exists,
+            name,
+            timing,
+            event,
+            table,
+            order,
+            body,
+        })
+    }
+
+    /// Parses the optional `DEFINER = <user name>` clause of a stored program.
+    #[cfg(feature = "procedural")]
+    fn parse_definer(&mut self) -> Result<Option<UserName>, ParserError> {
+        if self.parse_keyword(Keyword::DEFINER) {
+            self.expect_token(&Token::Equal)?;
+            Ok(Some(self.parse_user_name()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parses a compound statement, like the body of a stored procedure or a trigger.
+    ///
+    /// ```txt
+    /// BEGIN [ <statement list> ] END
+    /// ```
+    ///
+    /// **NOTE**: a top-level `BEGIN` starts a transaction, so the compound statement
+    /// is only parsed where a stored program body is expected, see
+    /// [`Parser::parse_create_routine_stmt`] and [`Parser::parse_create_trigger_stmt`].
+    #[cfg(feature = "procedural")]
+    pub fn parse_compound_stmt(&mut self) -> Result<CompoundStmt, ParserError> {
+        self.expect_keyword(Keyword::BEGIN)?;
+        let stmts = self.parse_procedural_stmts()?;
+        self.expect_keyword(Keyword::END)?;
+        Ok(CompoundStmt { stmts })
+    }
+
+    /// Parses a statement of a stored program body.
+    #[cfg(feature = "procedural")]
+    pub fn parse_procedural_stmt(&mut self) -> Result<ProceduralStmt, ParserError> {
+        let keyword = self.peek_token().and_then(|token| {
+            token.is_one_of_keywords(&[
+                Keyword::BEGIN,
+                Keyword::DECLARE,
+                Keyword::IF,
+                Keyword::WHILE,
+                Keyword::RETURN,
+            ])
+        });
+        Ok(match keyword {
+            Some(Keyword::BEGIN) => ProceduralStmt::Compound(self.parse_compound_stmt()?),
+            Some(Keyword::DECLARE) => ProceduralStmt::Declare(self.parse_declare_stmt()?),
+            Some(Keyword::IF) => ProceduralStmt::If(self.parse_if_stmt()?),
+            Some(Keyword::WHILE) => ProceduralStmt::While(self.parse_while_stmt()?),
+            Some(Keyword::RETURN) => ProceduralStmt::Return(self.parse_return_stmt()?),
+            _ => ProceduralStmt::Sql(Box::new(self.parse_stmt()?)),
+        })
+    }
+
+    /// Parses the `;` terminated statements until `END`, `ELSE` or `ELSEIF`.
+    ///
+    /// ```txt
+    /// <statement list> ::= <procedural statement> ; [ ... ]
+    /// ```
+    #[cfg(feature = "procedural")]
+    fn parse_procedural_stmts(&mut self) -> Result<Vec<ProceduralStmt>, ParserError> {
+        self.recurse(|parser| {
+            let mut stmts = vec![];
+            loop {
+                match parser.peek_token() {
+                    Some(token)
+                        if token
+                            .is_one_of_keywords(&[Keyword::END, Keyword::ELSE, Keyword::ELSEIF])
+                            .is_none() =>
+                    {
+                        stmts.push(parser.parse_procedural_stmt()?);
+                        parser.expect_token(&Token::SemiColon)?;
+                    }
+                    _ => return Ok(stmts),
+                }
+            }
+        })
+    }
+
+    /// Parses a local variable declaration.
+    ///
+    /// ```txt
+    /// DECLARE <variable name> [, ...] <data type> [ DEFAULT <expr> ]
+    /// ```
+    #[cfg(feature = "procedural")]
+    pub fn parse_declare_stmt(&mut self) -> Result<DeclareStmt, ParserError> {
+        self.expect_keyword(Keyword::DECLARE)?;
+        let names = self.parse_comma_separated(Self::parse_identifier)?;
+        let data_type = self.parse_data_type()?;
+        let default =
+            self.parse_keyword_then(Keyword::DEFAULT, |parser| parser.parse_expr().map(Box::new))?;
+        Ok(DeclareStmt {
+            names,
+            data_type,
+            default,
+        })
+    }
+
+    /// Parses an `IF` statement.
+    ///
+    /// ```txt
+    /// IF <search condition> THEN <statement list>
+    ///     [ ELSEIF <search condition> THEN <statement list> ] [...]
+    ///     [ ELSE <statement list> ]
+    ///     END IF
+    /// ```
+    #[cfg(feature = "procedural")]
+    pub fn parse_if_stmt(&mut self) -> Result<IfStmt, ParserError> {
+        self.expect_keyword(Keyword::IF)?;
+        let mut branches = vec![];
+        loop {
+            let condition = Box::new(self.parse_expr()?);
+            self.expect_keyword(Keyword::THEN)?;
+            let stmts = self.parse_procedural_stmts()?;
+            branches.push(ConditionalStmts { condition, stmts });
+            if !self.parse_keyword(Keyword::ELSEIF) {
+                break;
+            }
+        }
+        let else_stmts = if self.parse_keyword(Keyword::ELSE) {
+            Some(self.parse_procedural_stmts()?)
+        } else {
+            None
+        };
+        self.expect_keywords(&[Keyword::END, Keyword::IF])?;
+        Ok(IfStmt {
+            branches,
+            else_stmts,
+        })
+    }
+
+    /// Parses a `WHILE` statement.
+    ///
+    /// ```txt
+    /// WHILE <search condition> DO <statement list> END WHILE
+    /// ```
+    #[cfg(feature = "procedural")]
+    pub fn parse_while_stmt(&mut self) -> Result<WhileStmt, ParserError> {
+        self.expect_keyword(Keyword::WHILE)?;
+        let condition = Box::new(self.parse_expr()?);
+        self.expect_keyword(Keyword::DO)?;
+        let stmts = self.parse_procedural_stmts()?;
+        self.expect_keywords(&[Keyword::END, Keyword::WHILE])?;
+        Ok(WhileStmt { condition, stmts })
+    }
+
+    /// Parses a `RETURN` statement.
+    ///
+    /// ```txt
+    /// RETURN <expr>
+    /// ```
+    #[cfg(feature = "procedural")]
+    pub fn parse_return_stmt(&mut self) -> Result<ReturnStmt, ParserError> {
+        self.expect_keyword(Keyword::RETURN)?;
+        let expr = Box::new(self.parse_expr()?);
+        Ok(ReturnStmt { expr })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ast::types::Ident, postgres::PostgresDialect};
+
+    #[test]
+    fn parse_do_stmt() -> Result<(), ParserError> {
+        let dialect = PostgresDialect::default();
+        let sql = "DO $$\nBEGIN\n  IF NOT EXISTS (SELECT 1 FROM t) THEN RAISE NOTICE 'empty'; END IF;\nEND\n$$";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_do_stmt()?;
+        assert_eq!(
+            stmt,
+            DoStmt {
+                language: None,
+                code: "\nBEGIN\n  IF NOT EXISTS (SELECT 1 FROM t) THEN RAISE NOTICE 'empty'; END IF;\nEND\n"
+                    .into(),
+                tag: Some("".into()),
+            }
+        );
+        assert_eq!(stmt.to_string(), sql);
+
+        let cases = [
+            (
+                "DO LANGUAGE plpgsql $body$ BEGIN PERFORM 1; END $body$",
+                "DO LANGUAGE plpgsql $body$ BEGIN PERFORM 1; END $body$",
+            ),
+            (
+                "DO $x$ SELECT '$$' $x$ LANGUAGE plpgsql",
+                "DO LANGUAGE plpgsql $x$ SELECT '$$' $x$",
+            ),
+            ("DO 'BEGIN NULL; END'", "DO 'BEGIN NULL; END'"),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                expected
+            );
+        }
+        let stmt = Parser::new_with_sql(&dialect, "DO LANGUAGE plpgsql $$ $$")?.parse_do_stmt()?;
+        assert_eq!(stmt.language, Some(Ident::new("plpgsql")));
+        assert!(Parser::new_with_sql(&dialect, "DO $$ BEGIN END").is_err());
+        assert!(Parser::new_with_sql(&dialect, "DO LANGUAGE plpgsql")?
+            .parse_do_stmt()
+            .is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "procedural")]
+    #[test]
+    fn parse_compound_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "BEGIN \
+            DECLARE i, total INT DEFAULT 0; \
+            WHILE i < 10 DO \
+                IF i % 2 = 0 THEN UPDATE t SET total = total + i; \
+                ELSEIF i = 5 THEN BEGIN END; \
+                ELSE INSERT INTO t VALUES (i); \
+                END IF; \
+                DELETE FROM t WHERE a = i; \
+            END WHILE; \
+            RETURN total; \
+        END";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_compound_stmt()?;
+        assert_eq!(stmt.stmts.len(), 3);
+        assert!(matches!(stmt.stmts[0], ProceduralStmt::Declare(_)));
+        assert!(matches!(stmt.stmts[2], ProceduralStmt::Return(_)));
+        match &stmt.stmts[1] {
+            ProceduralStmt::While(WhileStmt { stmts, .. }) => {
+                match &stmts[0] {
+                    ProceduralStmt::If(stmt) => {
+                        assert_eq!(stmt.branches.len(), 2);
+                        assert!(stmt.else_stmts.is_some());
+                    }
+                    stmt => panic!("unexpected statement: {:?}", stmt),
+                }
+                assert!(matches!(stmts[1], ProceduralStmt::Sql(_)));
+            }
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        }
+        assert_eq!(
+            stmt.to_string(),
+            "BEGIN DECLARE i, total INT DEFAULT 0; \
+            WHILE i < 10 DO IF i % 2 = 0 THEN UPDATE t SET total = total + i; \
+            ELSEIF i = 5 THEN BEGIN END; ELSE INSERT INTO t VALUES (i); END IF; \
+            DELETE FROM t WHERE a = i; END WHILE; RETURN total; END"
+        );
+
+        for sql in [
+            "BEGIN SELECT 1 END",
+            "BEGIN IF a THEN SELECT 1; END",
+            "BEGIN WHILE a DO SELECT 1; END; END",
+            "BEGIN SELECT 1;",
+        ] {
+            assert!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_compound_stmt()
+                    .is_err(),
+                "{}",
+                sql
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "procedural")]
+    #[test]
+    fn parse_create_routine_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "CREATE DEFINER = 'admin'@'localhost' PROCEDURE IF NOT EXISTS db.p(IN a INT, OUT b VARCHAR(10), INOUT c INT, d INT) \
+            COMMENT 'p' LANGUAGE SQL NOT DETERMINISTIC MODIFIES SQL DATA SQL SECURITY INVOKER \
+            BEGIN DECLARE i INT DEFAULT 0; WHILE i < a DO INSERT INTO t VALUES (i); END WHILE; END";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        let create = match &stmt {
+            Stmt::CreateRoutine(create) => create,
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        };
+        assert_eq!(create.kind, RoutineKind::Procedure);
+        assert!(create.definer.is_some());
+        assert_eq!(
+            create
+                .params
+                .iter()
+                .map(|param| param.mode)
+                .collect::<Vec<_>>(),
+            vec![
+                Some(ParamMode::In),
+                Some(ParamMode::Out),
+                Some(ParamMode::InOut),
+                None
+            ]
+        );
+        assert_eq!(create.returns, None);
+        assert_eq!(create.characteristics.len(), 5);
+        assert!(matches!(create.body, ProceduralStmt::Compound(_)));
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE DEFINER = 'admin'@'localhost' PROCEDURE IF NOT EXISTS db.p(IN a INT, OUT b VARCHAR(10), INOUT c INT, d INT) \
+            COMMENT 'p' LANGUAGE SQL NOT DETERMINISTIC MODIFIES SQL DATA SQL SECURITY INVOKER \
+            BEGIN DECLARE i INT DEFAULT 0; WHILE i < a DO INSERT INTO t VALUES (i); END WHILE; END"
+        );
+
+        let cases = [
+            (
+                "CREATE PROCEDURE p() SELECT 1",
+                "CREATE PROCEDURE p() SELECT 1",
+            ),
+            (
+                "CREATE FUNCTION f(a INT) RETURNS INT DETERMINISTIC NO SQL RETURN a + 1",
+                "CREATE FUNCTION f(a INT) RETURNS INT DETERMINISTIC NO SQL RETURN a + 1",
+            ),
+            (
+                "CREATE DEFINER = CURRENT_USER FUNCTION f() RETURNS INT CONTAINS SQL READS SQL DATA BEGIN RETURN 1; END",
+                "CREATE DEFINER = CURRENT_USER FUNCTION f() RETURNS INT CONTAINS SQL READS SQL DATA BEGIN RETURN 1; END",
+            ),
+        ];
+        for (sql, expected) in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                expected
+            );
+        }
+
+        for sql in [
+            "CREATE PROCEDURE p BEGIN END",
+            "CREATE FUNCTION f() RETURN 1",
+            "CREATE FUNCTION f(IN a INT) RETURNS INT RETURN a",
+            "CREATE PROCEDURE p() LANGUAGE PLPGSQL BEGIN END",
+            "CREATE PROCEDURE p() BEGIN SELECT 1 END",
+        ] {
+            assert!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_create_routine_stmt()
+                    .is_err(),
+                "{}",
+                sql
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "procedural")]
+    #[test]
+    fn parse_create_trigger_stmt() -> Result<(), ParserError> {
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "CREATE DEFINER = 'admin'@'%' TRIGGER IF NOT EXISTS db.tr AFTER UPDATE ON db.t FOR EACH ROW FOLLOWS tr0 \
+            BEGIN IF NEW.a < 0 THEN DELETE FROM t WHERE a < 0; END IF; END";
+        let stmt = Parser::new_with_sql(&dialect, sql)?.parse_stmt()?;
+        let create = match &stmt {
+            Stmt::CreateTrigger(create) => create,
+            stmt => panic!("unexpected statement: {:?}", stmt),
+        };
+        assert!(create.if_not_exists);
+        assert_eq!(create.timing, TriggerTiming::After);
+        assert_eq!(create.event, TriggerEvent::Update);
+        assert_eq!(create.order, Some(TriggerOrder::Follows(Ident::new("tr0"))));
+        assert_eq!(stmt.to_string(), sql);
+
+        let cases = [
+            "CREATE TRIGGER tr BEFORE INSERT ON t FOR EACH ROW PRECEDES tr0 INSERT INTO log VALUES (1)",
+            "CREATE TRIGGER tr AFTER DELETE ON t FOR EACH ROW IF a THEN DELETE FROM log; END IF",
+        ];
+        for sql in cases {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_stmt()?
+                    .to_string(),
+                sql
+            );
+        }
+
+        for sql in [
+            "CREATE TRIGGER tr INSERT ON t FOR EACH ROW SELECT 1",
+            "CREATE TRIGGER tr BEFORE TRUNCATE ON t FOR EACH ROW SELECT 1",
+            "CREATE TRIGGER tr BEFORE INSERT ON t SELECT 1",
+        ] {
+            assert!(
+                Parser::new_with_sql(&dialect, sql)?
+                    .parse_create_trigger_stmt()
+                    .is_err(),
+                "{}",
+                sql
+            );
+        }
+        Ok(())
+    }
+}
@@ -126,7 +126,6 @@ impl<'a, D: Dialect> Parser<'a, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::error::parse_error;
 
     #[test]
     fn parse_start_transaction_stmt() -> Result<(), ParserError> {
@@ -238,7 +237,9 @@ mod tests {
         );
         assert_eq!(
             Parser::new_with_sql(&dialect, "SET TRANSACTION")?.parse_set_transaction_stmt(),
-            parse_error("Expected: transaction characteristic, but not found")
+            Err(ParserError::UnexpectedEof {
+                expected: "transaction characteristic".into()
+            })
         );
         // MySQL: SET [GLOBAL | SESSION] TRANSACTION transaction_mode [, ...]
         let dialect = crate::mysql::MysqlDialect::default();
@@ -0,0 +1,234 @@
+use std::{collections::VecDeque, io::Read, mem, vec::Vec};
+
+use crate::{
+    ast::statement::Stmt,
+    dialect::Dialect,
+    error::ParserError,
+    lexer::PushLexer,
+    parser::{script::BlockTracker, Parser},
+    tokens::Token,
+};
+
+/// The number of bytes read from the source at a time.
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// An iterator over the statements read from an [`io::Read`](std::io::Read) source,
+/// see [`Parser::parse_stream`].
+pub struct StmtStream<'a, D: Dialect, R> {
+    dialect: &'a D,
+    reader: R,
+    /// The lexer of the source, `None` once the end of the source has been reached.
+    lexer: Option<PushLexer<'a, D>>,
+    /// The buffer of the chunk read from the source.
+    chunk: Vec<u8>,
+    /// The tokens completed by the chunks read but not split into statements yet.
+    tokens: VecDeque<Token>,
+    /// The tokens of the current incomplete statement.
+    stmt: Vec<Token>,
+    /// The blocks of the current statement, see [`BlockTracker`].
+    blocks: BlockTracker,
+    /// Whether the iteration is stopped by an unrecoverable error.
+    done: bool,
+}
+
+impl<'a, D: Dialect, R: Read> StmtStream<'a, D, R> {
+    fn new(reader: R, dialect: &'a D) -> Self {
+        Self {
+            dialect,
+            reader,
+            lexer: Some(PushLexer::new(dialect)),
+            chunk: vec![0; CHUNK_SIZE],
+            tokens: VecDeque::new(),
+            stmt: Vec::new(),
+            blocks: BlockTracker::default(),
+            done: false,
+        }
+    }
+
+    fn fail(&mut self, err: ParserError) -> Option<Result<Stmt, ParserError>> {
+        self.done = true;
+        self.tokens.clear();
+        self.stmt.clear();
+        Some(Err(err))
+    }
+
+    /// Reads the next chunk of the source, and tokenizes it incrementally so that only the new
+    /// bytes (and the token cut off at the end of the previous chunk) are scanned.
+    fn fill_tokens(&mut self) -> Result<(), ParserError> {
+        let n = loop {
+            match self.reader.read(&mut self.chunk) {
+                Ok(n) => break n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(ParserError::TokenizeError(err.to_string())),
+            }
+        };
+        let tokens = match self.lexer.take() {
+            Some(lexer) if n == 0 => lexer.finish()?,
+            Some(mut lexer) => {
+                let tokens = lexer.feed(&self.chunk[..n])?;
+                self.lexer = Some(lexer);
+                tokens
+            }
+            None => Vec::new(),
+        };
+        self.tokens.extend(tokens);
+        Ok(())
+    }
+
+    /// Splits the tokens of the first complete statement from the tokenized input.
+    /// Returns `None` if more input is needed to complete the statement.
+    fn split_stmt(&mut self) -> Option<Vec<Token>> {
+        while let Some(token) = self.tokens.pop_front() {
+            if token.is_whitespace() || token.is_comment() {
+                continue;
+            }
+            match token {
+                Token::SemiColon if self.stmt.is_empty() => {
+                    // empty statement
+                    continue;
+                }
+                Token::SemiColon if !self.blocks.in_block() => {
+                    return Some(mem::take(&mut self.stmt))
+                }
+                // meta-commands are terminated by the end of line instead of `;`
+                Token::MetaCommand(_) if !self.stmt.is_empty() => {
+                    self.tokens.push_front(token);
+                    return Some(mem::take(&mut self.stmt));
+                }
+                Token::MetaCommand(_) => return Some(vec![token]),
+                token => {
+                    self.blocks.push(&token, self.stmt.is_empty());
+                    self.stmt.push(token);
+                }
+            }
+        }
+        if self.lexer.is_none() && !self.stmt.is_empty() {
+            Some(mem::take(&mut self.stmt))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, D: Dialect, R: Read> Iterator for StmtStream<'a, D, R> {
+    type Item = Result<Stmt, ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            if let Some(tokens) = self.split_stmt() {
+                return Some(Parser::parse_single_stmt(self.dialect, tokens));
+            }
+            if self.lexer.is_none() {
+                self.done = true;
+                return None;
+            }
+            if let Err(err) = self.fill_tokens() {
+                return self.fail(err);
+            }
+        }
+    }
+}
+
+impl<'a, D: Dialect> Parser<'a, D> {
+    /// Parses the `;` separated statements read from the source incrementally, so that a huge
+    /// input like a dump file can be processed without being loaded into memory at once.
+    ///
+    /// Every statement is parsed as soon as its terminator is read, a parse error of a statement
+    /// doesn't stop the iteration. An I/O error or a tokenize error ends the iteration.
+    ///
+    /// **NOTE**: the input is buffered until the next statement terminator is read, so an
+    /// invalid token (like an unterminated string literal) makes the rest of the input buffered.
+    pub fn parse_stream<R: Read>(reader: R, dialect: &'a D) -> StmtStream<'a, D, R> {
+        StmtStream::new(reader, dialect)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::postgres::{PostgresDialect, PostgresLexerConfig, PostgresParserConfig};
+
+    /// A reader that returns one byte at a time.
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl Read for ByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.0.split_first() {
+                Some((byte, rest)) if !buf.is_empty() => {
+                    buf[0] = *byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    fn parse_stream<D: Dialect>(dialect: &D, sql: &str) -> Vec<Result<String, ParserError>> {
+        let reader = ByteReader(sql.as_bytes());
+        Parser::parse_stream(reader, dialect)
+            .map(|stmt| stmt.map(|stmt| stmt.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_stream_stmts() {
+        let dialect = PostgresDialect::default();
+        let sql = "SELECT 'a;b' -- c;\n; ;; INSERT INTO t VALUES ('é', 1) ;\n/* ; */ DELETE FROM t";
+        assert_eq!(
+            parse_stream(&dialect, sql),
+            vec![
+                Ok("SELECT 'a;b'".into()),
+                Ok("INSERT INTO t VALUES ('é', 1)".into()),
+                Ok("DELETE FROM t".into()),
+            ]
+        );
+        assert_eq!(parse_stream(&dialect, " ; -- end"), vec![]);
+
+        // a parse error doesn't stop the iteration
+        let stmts = parse_stream(&dialect, "SELECT 1; SELECT FROM; SELECT 2");
+        assert_eq!(stmts.len(), 3);
+        assert!(stmts[1].is_err());
+        assert_eq!(stmts[2], Ok("SELECT 2".into()));
+
+        // a tokenize error ends the iteration
+        let stmts = parse_stream(&dialect, "SELECT 1; SELECT 'a");
+        assert_eq!(stmts.len(), 2);
+        assert!(matches!(stmts[1], Err(ParserError::TokenizeError(_))));
+
+        let dialect = PostgresDialect::new(
+            PostgresLexerConfig { psql_mode: true },
+            PostgresParserConfig::default(),
+        );
+        let stmts = parse_stream(&dialect, "\\connect db\nSELECT 1\n\\i file.sql");
+        assert_eq!(
+            stmts,
+            vec![
+                Ok("\\connect db".into()),
+                Ok("SELECT 1".into()),
+                Ok("\\i file.sql".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_stream_large_stmt() {
+        // every byte is read separately, only the new bytes should be scanned
+        let dialect = PostgresDialect::default();
+        let values = (0..20_000)
+            .map(|i| format!("({}, 'v{}')", i, i))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!("INSERT INTO t VALUES {};\nSELECT 1", values);
+        let stmts = parse_stream(&dialect, &sql);
+        assert_eq!(stmts.len(), 2);
+        assert_eq!(
+            stmts[0].as_ref().map(String::len),
+            Ok(sql.find(';').unwrap())
+        );
+        assert_eq!(stmts[1], Ok("SELECT 1".into()));
+    }
+}
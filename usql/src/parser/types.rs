@@ -1,11 +1,11 @@
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, format, string::String, vec};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
 
 use crate::{
     ast::types::*,
-    dialect::Dialect,
-    error::{parse_error, ParserError},
-    keywords::Keyword,
+    dialect::{Dialect, DialectParserConf},
+    error::{unsupported, ParserError},
+    keywords::{Keyword, KeywordDef},
     parser::Parser,
     tokens::{Token, Word},
 };
@@ -22,6 +22,27 @@ impl<'a, D: Dialect> Parser<'a, D> {
         }
     }
 
+    /// Parses an optional alias, the `AS` keyword can be omitted.
+    ///
+    /// ```txt
+    /// [ [ AS ] <alias name> ]
+    /// ```
+    ///
+    /// An implicit alias (without `AS`) can't be a string literal, a reserved keyword of the
+    /// dialect or one of the keywords returned by [`DialectParserConf::reserved_for_alias`].
+    pub fn parse_optional_alias(&mut self) -> Result<Option<Ident>, ParserError> {
+        if self.parse_keyword(Keyword::AS) {
+            return self.parse_identifier().map(Some);
+        }
+        let dialect = self.dialect;
+        match self.peek_token() {
+            Some(Token::Word(w)) if is_implicit_alias(dialect, w) => {
+                self.parse_identifier().map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
     /// Parses an object name.
     pub fn parse_object_name(&mut self) -> Result<ObjectName, ParserError> {
         let mut idents = vec![];
@@ -49,11 +70,23 @@ impl<'a, D: Dialect> Parser<'a, D> {
                     Some('\'') => Ok(Literal::String(w.value)),
                     _ => self.expected("literal", Some(Token::Word(w))),
                 },
+                None if w.value.starts_with('_')
+                    && self.dialect.parser_conf().supports_charset_introducer() =>
+                {
+                    let value = self.parse_literal_string("string after character set")?;
+                    Ok(Literal::CharsetString(CharsetString {
+                        charset: w.value[1..].into(),
+                        value,
+                    }))
+                }
                 _ => self.expected("literal", Some(Token::Word(w))),
             },
             Some(Token::Number(n)) => Ok(Literal::Number(n)),
             Some(Token::String(s)) => Ok(Literal::String(s)),
             Some(Token::NationalString(s)) => Ok(Literal::NationalString(s)),
+            Some(Token::EscapeString { raw, value }) => {
+                Ok(Literal::EscapeString(EscapeString { raw, value }))
+            }
             Some(Token::HexString(s)) => Ok(Literal::HexString(s)),
             Some(Token::BitString(s)) => Ok(Literal::BitString(s)),
             unexpected => self.expected("literal", unexpected),
@@ -162,35 +195,35 @@ impl<'a, D: Dialect> Parser<'a, D> {
         })
     }
 
-    /// Parses a data type.
+    /// Parses a data type, the collection type suffixes can be nested,
+    /// e.g. `INTEGER[][]` or `INTEGER ARRAY[5] ARRAY[6]`.
     pub fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
-        // NOTE: we only support one-dimensional array
-        let data_type = self.parse_simple_data_type()?;
-        if self.parse_keyword(Keyword::ARRAY) {
-            // ANSI SQL, e.g. INTEGER ARRAY, INTEGER ARRAY[10]
-            if self.next_token_if_is(&Token::LeftBracket) {
-                let length = self.parse_literal_uint()?;
-                self.expect_token(&Token::RightBracket)?;
-                Ok(DataType::Array(Box::new(data_type), Some(length)))
-            } else {
-                Ok(DataType::Array(Box::new(data_type), None))
-            }
-        } else if self.parse_keyword(Keyword::MULTISET) {
-            // ANSI SQL, e.g. INTEGER MULTISET
-            Ok(DataType::Multiset(Box::new(data_type)))
-        } else {
-            // PostgreSQL-specific array, e.g. INTEGER[], INTEGER[10]
-            if self.next_token_if_is(&Token::LeftBracket) {
+        let mut data_type = self.parse_simple_data_type()?;
+        loop {
+            data_type = if self.parse_keyword(Keyword::ARRAY) {
+                // ANSI SQL, e.g. INTEGER ARRAY, INTEGER ARRAY[10]
+                if self.next_token_if_is(&Token::LeftBracket) {
+                    let length = self.parse_literal_uint()?;
+                    self.expect_token(&Token::RightBracket)?;
+                    DataType::Array(Box::new(data_type), Some(length))
+                } else {
+                    DataType::Array(Box::new(data_type), None)
+                }
+            } else if self.parse_keyword(Keyword::MULTISET) {
+                // ANSI SQL, e.g. INTEGER MULTISET
+                DataType::Multiset(Box::new(data_type))
+            } else if self.next_token_if_is(&Token::LeftBracket) {
+                // PostgreSQL-specific array, e.g. INTEGER[], INTEGER[10]
                 if self.next_token_if_is(&Token::RightBracket) {
-                    Ok(DataType::Array(Box::new(data_type), None))
+                    DataType::Array(Box::new(data_type), None)
                 } else {
                     let length = self.parse_literal_uint()?;
                     self.expect_token(&Token::RightBracket)?;
-                    Ok(DataType::Array(Box::new(data_type), Some(length)))
+                    DataType::Array(Box::new(data_type), Some(length))
                 }
             } else {
-                Ok(data_type)
-            }
+                return Ok(data_type);
+            };
         }
     }
 
@@ -203,39 +236,88 @@ impl<'a, D: Dialect> Parser<'a, D> {
             })) => match keyword {
                 Keyword::BOOLEAN => Ok(DataType::Boolean),
 
-                Keyword::TINYINT => Ok(DataType::TinyInt(self.parse_optional_precision()?)),
-                Keyword::SMALLINT => Ok(DataType::SmallInt(self.parse_optional_precision()?)),
+                Keyword::TINYINT => {
+                    let ty = DataType::TinyInt(self.parse_optional_precision()?);
+                    Ok(self.parse_numeric_attributes(ty))
+                }
+                Keyword::SMALLINT => {
+                    let ty = DataType::SmallInt(self.parse_optional_precision()?);
+                    Ok(self.parse_numeric_attributes(ty))
+                }
+                Keyword::MEDIUMINT => {
+                    let ty = DataType::MediumInt(self.parse_optional_precision()?);
+                    Ok(self.parse_numeric_attributes(ty))
+                }
                 Keyword::INT | Keyword::INTEGER => {
-                    Ok(DataType::Int(self.parse_optional_precision()?))
+                    let ty = DataType::Int(self.parse_optional_precision()?);
+                    Ok(self.parse_numeric_attributes(ty))
+                }
+                Keyword::BIGINT => {
+                    let ty = DataType::BigInt(self.parse_optional_precision()?);
+                    Ok(self.parse_numeric_attributes(ty))
                 }
-                Keyword::BIGINT => Ok(DataType::BigInt(self.parse_optional_precision()?)),
+                Keyword::SERIAL => Ok(DataType::Serial),
 
                 Keyword::NUMERIC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
-                    Ok(DataType::Numeric { precision, scale })
+                    Ok(self.parse_numeric_attributes(DataType::Numeric { precision, scale }))
                 }
                 Keyword::DECIMAL | Keyword::DEC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
-                    Ok(DataType::Decimal { precision, scale })
+                    Ok(self.parse_numeric_attributes(DataType::Decimal { precision, scale }))
                 }
 
-                Keyword::FLOAT => Ok(DataType::Float(self.parse_optional_precision()?)),
-                Keyword::REAL => Ok(DataType::Real),
+                Keyword::FLOAT => {
+                    let ty = DataType::Float(self.parse_optional_precision()?);
+                    Ok(self.parse_numeric_attributes(ty))
+                }
+                Keyword::REAL => Ok(self.parse_numeric_attributes(DataType::Real)),
                 Keyword::DOUBLE => {
                     let _ = self.parse_keyword(Keyword::PRECISION);
-                    Ok(DataType::Double)
+                    Ok(self.parse_numeric_attributes(DataType::Double))
                 }
 
                 Keyword::CHAR | Keyword::CHARACTER => {
                     if self.parse_keyword(Keyword::VARYING) {
-                        Ok(DataType::Varchar(self.parse_precision()?))
+                        let length = self.parse_precision()?;
+                        let (charset, collation) = self.parse_charset_collation()?;
+                        Ok(DataType::Varchar {
+                            length,
+                            charset,
+                            collation,
+                        })
                     } else {
-                        Ok(DataType::Char(self.parse_optional_precision()?))
+                        let length = self.parse_optional_precision()?;
+                        let (charset, collation) = self.parse_charset_collation()?;
+                        Ok(DataType::Char {
+                            length,
+                            charset,
+                            collation,
+                        })
                     }
                 }
-                Keyword::VARCHAR => Ok(DataType::Varchar(self.parse_precision()?)),
-                Keyword::CLOB => Ok(DataType::Clob(self.parse_optional_precision()?)),
-                Keyword::TEXT => Ok(DataType::Text),
+                Keyword::VARCHAR => {
+                    let length = self.parse_precision()?;
+                    let (charset, collation) = self.parse_charset_collation()?;
+                    Ok(DataType::Varchar {
+                        length,
+                        charset,
+                        collation,
+                    })
+                }
+                Keyword::CLOB => {
+                    let length = self.parse_optional_precision()?;
+                    let (charset, collation) = self.parse_charset_collation()?;
+                    Ok(DataType::Clob {
+                        length,
+                        charset,
+                        collation,
+                    })
+                }
+                Keyword::TEXT => {
+                    let (charset, collation) = self.parse_charset_collation()?;
+                    Ok(DataType::Text { charset, collation })
+                }
 
                 Keyword::BINARY => {
                     if self.parse_keyword(Keyword::VARYING) {
@@ -250,32 +332,117 @@ impl<'a, D: Dialect> Parser<'a, D> {
 
                 Keyword::DATE => Ok(DataType::Date),
                 Keyword::TIME => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
-                    }
-                    Ok(DataType::Time)
+                    let precision = self.parse_optional_precision()?;
+                    let with_time_zone = self.parse_time_zone()?;
+                    Ok(DataType::Time {
+                        precision,
+                        with_time_zone,
+                    })
                 }
                 Keyword::TIMESTAMP => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
-                    }
-                    Ok(DataType::Timestamp)
+                    let precision = self.parse_optional_precision()?;
+                    let with_time_zone = self.parse_time_zone()?;
+                    Ok(DataType::Timestamp {
+                        precision,
+                        with_time_zone,
+                    })
                 }
                 // Interval types can be followed by a complicated interval qualifier that we don't currently support.
                 // See parse_literal_interval for a taste.
                 Keyword::INTERVAL => Ok(DataType::Interval),
+
+                Keyword::JSON => Ok(DataType::Json),
+                Keyword::ENUM => Ok(DataType::Enum(self.parse_type_values()?)),
+                Keyword::SET => Ok(DataType::Set(self.parse_type_values()?)),
                 unexpected => self.expected("data type", Some(unexpected)),
             },
+            // PostgreSQL type names that are not keywords
+            Some(Token::Word(Word {
+                keyword: None,
+                value,
+                quote: None,
+            })) => match value.to_uppercase().as_str() {
+                "SMALLSERIAL" => Ok(DataType::SmallSerial),
+                "SERIAL" => Ok(DataType::Serial),
+                "BIGSERIAL" => Ok(DataType::BigSerial),
+                "MONEY" => Ok(DataType::Money),
+                "UUID" => Ok(DataType::Uuid),
+                "INET" => Ok(DataType::Inet),
+                "JSON" => Ok(DataType::Json),
+                "JSONB" => Ok(DataType::Jsonb),
+                // TODO: custom types
+                _ => unsupported("custom data type"),
+            },
             Some(Token::Word(Word { keyword, .. })) if keyword.is_none() => {
                 // TODO: custom types
-                parse_error("Don't support custom data type yet")
+                unsupported("custom data type")
             }
             unexpected => self.expected("data type", unexpected),
         }
     }
 
+    /// Parses the optional time zone specification of a time type, returns true if the time zone
+    /// is included, `WITHOUT TIME ZONE` is the default.
+    ///
+    /// ```txt
+    /// [ { WITH | WITHOUT } TIME ZONE ]
+    /// ```
+    fn parse_time_zone(&mut self) -> Result<bool, ParserError> {
+        if self.parse_keyword(Keyword::WITH) {
+            self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+            Ok(true)
+        } else if self.parse_keyword(Keyword::WITHOUT) {
+            self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+            Ok(false)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Parses the optional character set and collation of a character string type, e.g.
+    /// `CHARACTER SET utf8mb4 COLLATE utf8mb4_bin`.
+    ///
+    /// ```txt
+    /// [ { CHARACTER SET | CHARSET } <character set name> ] [ COLLATE <collation name> ]
+    /// ```
+    fn parse_charset_collation(
+        &mut self,
+    ) -> Result<(Option<ObjectName>, Option<ObjectName>), ParserError> {
+        let charset = if self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+            || self.parse_keyword(Keyword::CHARSET)
+        {
+            Some(self.parse_object_name()?)
+        } else {
+            None
+        };
+        let collation = self.parse_keyword_then(Keyword::COLLATE, Self::parse_object_name)?;
+        Ok((charset, collation))
+    }
+
+    /// Parses the optional `UNSIGNED` and `ZEROFILL` attributes of a numeric type (MySQL specific),
+    /// `ZEROFILL` implies `UNSIGNED`.
+    fn parse_numeric_attributes(&mut self, data_type: DataType) -> DataType {
+        let unsigned = self.parse_keyword(Keyword::UNSIGNED);
+        let zerofill = self.parse_keyword(Keyword::ZEROFILL);
+        if unsigned || zerofill {
+            DataType::Unsigned {
+                data_type: Box::new(data_type),
+                zerofill,
+            }
+        } else {
+            data_type
+        }
+    }
+
+    /// Parses the string values of `ENUM` and `SET` types, e.g. `('a', 'b')`.
+    fn parse_type_values(&mut self) -> Result<Vec<String>, ParserError> {
+        self.expect_token(&Token::LeftParen)?;
+        let values =
+            self.parse_comma_separated(|parser| parser.parse_literal_string("string literal"))?;
+        self.expect_token(&Token::RightParen)?;
+        Ok(values)
+    }
+
     fn parse_precision(&mut self) -> Result<u64, ParserError> {
         self.expect_token(&Token::LeftParen)?;
         let n = self.parse_literal_uint()?;
@@ -314,7 +481,7 @@ impl<'a, D: Dialect> Parser<'a, D> {
     pub(crate) fn parse_literal_uint(&mut self) -> Result<u64, ParserError> {
         match self.next_token() {
             Some(Token::Number(n)) => n.parse::<u64>().map_err(|e| {
-                ParserError::ParseError(format!("Could not parse '{}' as u64: {}", n, e))
+                ParserError::InvalidLiteral(format!("Could not parse '{}' as u64: {}", n, e))
             }),
             unexpected => self.expected("literal unsigned int", unexpected),
         }
@@ -345,6 +512,29 @@ impl<'a, D: Dialect> Parser<'a, D> {
     }
 }
 
+/// Checks if the word can be used as an alias without the `AS` keyword.
+fn is_implicit_alias<D: Dialect>(dialect: &D, word: &Word) -> bool {
+    match word.keyword {
+        _ if word.quote == Some('\'') => false,
+        // the hierarchical query clause may follow a table, like `FROM t CONNECT BY ...`
+        _ if word.quote.is_none()
+            && dialect.parser_conf().supports_connect_by()
+            && (word.value.eq_ignore_ascii_case("CONNECT")
+                || word.value.eq_ignore_ascii_case("START")) =>
+        {
+            false
+        }
+        None => true,
+        Some(keyword) => {
+            !D::Keyword::RESERVED_KEYWORDS.contains(&keyword)
+                && !dialect
+                    .parser_conf()
+                    .reserved_for_alias()
+                    .contains(&keyword)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -410,6 +600,20 @@ mod tests {
         assert_eq!(literal, Literal::HexString("1234567890abcdef".into()));
         let literal = Parser::new_with_sql(&dialect, "B'10101010'")?.parse_literal()?;
         assert_eq!(literal, Literal::BitString("10101010".into()));
+
+        let dialect = crate::mysql::MysqlDialect::default();
+        let literal = Parser::new_with_sql(&dialect, "_utf8mb4'foo'")?.parse_literal()?;
+        assert_eq!(
+            literal,
+            Literal::CharsetString(CharsetString {
+                charset: "utf8mb4".into(),
+                value: "foo".into(),
+            })
+        );
+        let expr = Parser::new_with_sql(&dialect, "_latin1 'bar'")?.parse_expr()?;
+        assert_eq!(expr.to_string(), "_latin1'bar'");
+        let expr = Parser::new_with_sql(&dialect, "_foo")?.parse_expr()?;
+        assert_eq!(expr.to_string(), "_foo");
         Ok(())
     }
 
@@ -544,6 +748,7 @@ mod tests {
         parse_data_type_character_string()?;
         parse_data_type_binary_string()?;
         parse_data_type_datetime()?;
+        parse_data_type_other()?;
         Ok(())
     }
 
@@ -566,6 +771,28 @@ mod tests {
             Parser::new_with_sql(&dialect, "INTEGER[10]")?.parse_data_type()?,
             DataType::Array(Box::new(DataType::Int(None)), Some(10))
         );
+
+        // multi-dimensional arrays
+        let ty = Parser::new_with_sql(&dialect, "INT[][]")?.parse_data_type()?;
+        assert_eq!(
+            ty,
+            DataType::Array(
+                Box::new(DataType::Array(Box::new(DataType::Int(None)), None)),
+                None
+            )
+        );
+        assert_eq!(ty.to_string(), "INT[][]");
+        let ty = Parser::new_with_sql(&dialect, "INTEGER ARRAY[5] ARRAY[6]")?.parse_data_type()?;
+        assert_eq!(
+            ty,
+            DataType::Array(
+                Box::new(DataType::Array(Box::new(DataType::Int(None)), Some(5))),
+                Some(6)
+            )
+        );
+        assert_eq!(ty.to_string(), "INT[5][6]");
+        let ty = Parser::new_with_sql(&dialect, "INTEGER ARRAY MULTISET")?.parse_data_type()?;
+        assert_eq!(ty.to_string(), "INT[] MULTISET");
         Ok(())
     }
 
@@ -612,6 +839,32 @@ mod tests {
         assert_eq!(ty, DataType::TinyInt(None));
         let ty = Parser::new_with_sql(&dialect, "TINYINT(3)")?.parse_data_type()?;
         assert_eq!(ty, DataType::TinyInt(Some(3)));
+        let ty = Parser::new_with_sql(&dialect, "MEDIUMINT(8)")?.parse_data_type()?;
+        assert_eq!(ty, DataType::MediumInt(Some(8)));
+        let ty = Parser::new_with_sql(&dialect, "INT UNSIGNED")?.parse_data_type()?;
+        assert_eq!(
+            ty,
+            DataType::Unsigned {
+                data_type: Box::new(DataType::Int(None)),
+                zerofill: false
+            }
+        );
+        assert_eq!(ty.to_string(), "INT UNSIGNED");
+        let ty = Parser::new_with_sql(&dialect, "INT(10) ZEROFILL")?.parse_data_type()?;
+        assert_eq!(ty.to_string(), "INT(10) UNSIGNED ZEROFILL");
+        let ty = Parser::new_with_sql(&dialect, "DECIMAL(10, 2) UNSIGNED ZEROFILL")?
+            .parse_data_type()?;
+        assert_eq!(ty.to_string(), "DECIMAL(10,2) UNSIGNED ZEROFILL");
+        let ty = Parser::new_with_sql(&dialect, "SERIAL")?.parse_data_type()?;
+        assert_eq!(ty, DataType::Serial);
+
+        let dialect = crate::postgres::PostgresDialect::default();
+        let ty = Parser::new_with_sql(&dialect, "smallserial")?.parse_data_type()?;
+        assert_eq!(ty, DataType::SmallSerial);
+        let ty = Parser::new_with_sql(&dialect, "SERIAL")?.parse_data_type()?;
+        assert_eq!(ty, DataType::Serial);
+        let ty = Parser::new_with_sql(&dialect, "BIGSERIAL")?.parse_data_type()?;
+        assert_eq!(ty, DataType::BigSerial);
         Ok(())
     }
 
@@ -704,27 +957,131 @@ mod tests {
     fn parse_data_type_character_string() -> Result<(), ParserError> {
         let dialect = crate::ansi::AnsiDialect::default();
         let ty = Parser::new_with_sql(&dialect, "CHAR")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Char(None));
+        assert_eq!(
+            ty,
+            DataType::Char {
+                length: None,
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "CHAR(10)")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Char(Some(10)));
+        assert_eq!(
+            ty,
+            DataType::Char {
+                length: Some(10),
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "CHARACTER")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Char(None));
+        assert_eq!(
+            ty,
+            DataType::Char {
+                length: None,
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "CHARACTER(10)")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Char(Some(10)));
+        assert_eq!(
+            ty,
+            DataType::Char {
+                length: Some(10),
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "CHAR VARYING(10)")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Varchar(10));
+        assert_eq!(
+            ty,
+            DataType::Varchar {
+                length: 10,
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "CHARACTER VARYING(10)")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Varchar(10));
+        assert_eq!(
+            ty,
+            DataType::Varchar {
+                length: 10,
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "VARCHAR(10)")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Varchar(10));
+        assert_eq!(
+            ty,
+            DataType::Varchar {
+                length: 10,
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "CLOB")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Clob(None));
+        assert_eq!(
+            ty,
+            DataType::Clob {
+                length: None,
+                charset: None,
+                collation: None
+            }
+        );
         let ty = Parser::new_with_sql(&dialect, "CLOB(10)")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Clob(Some(10)));
+        assert_eq!(
+            ty,
+            DataType::Clob {
+                length: Some(10),
+                charset: None,
+                collation: None
+            }
+        );
 
         let dialect = crate::postgres::PostgresDialect::default();
         let ty = Parser::new_with_sql(&dialect, "TEXT")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Text);
+        assert_eq!(
+            ty,
+            DataType::Text {
+                charset: None,
+                collation: None
+            }
+        );
+        let ty = Parser::new_with_sql(&dialect, "TEXT COLLATE \"C\"")?.parse_data_type()?;
+        assert_eq!(
+            ty,
+            DataType::Text {
+                charset: None,
+                collation: Some(ObjectName(vec![Ident::with_quote('"', "C")])),
+            }
+        );
+
+        let dialect = crate::ansi::AnsiDialect::default();
+        let ty =
+            Parser::new_with_sql(&dialect, "CHAR(5) CHARACTER SET latin1")?.parse_data_type()?;
+        assert_eq!(
+            ty,
+            DataType::Char {
+                length: Some(5),
+                charset: Some(ObjectName::new(vec!["latin1"])),
+                collation: None,
+            }
+        );
+
+        let dialect = crate::mysql::MysqlDialect::default();
+        let sql = "VARCHAR(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin";
+        let ty = Parser::new_with_sql(&dialect, sql)?.parse_data_type()?;
+        assert_eq!(
+            ty,
+            DataType::Varchar {
+                length: 255,
+                charset: Some(ObjectName::new(vec!["utf8mb4"])),
+                collation: Some(ObjectName::new(vec!["utf8mb4_bin"])),
+            }
+        );
+        assert_eq!(ty.to_string(), sql);
+        let ty = Parser::new_with_sql(&dialect, "TEXT CHARSET latin1")?.parse_data_type()?;
+        assert_eq!(ty.to_string(), "TEXT CHARACTER SET latin1");
         Ok(())
     }
 
@@ -755,21 +1112,71 @@ mod tests {
         let dialect = crate::ansi::AnsiDialect::default();
         let ty = Parser::new_with_sql(&dialect, "DATE")?.parse_data_type()?;
         assert_eq!(ty, DataType::Date);
-        let ty = Parser::new_with_sql(&dialect, "TIME")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Time);
-        let ty = Parser::new_with_sql(&dialect, "TIME WITH TIME ZONE")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Time);
-        let ty = Parser::new_with_sql(&dialect, "TIME WITHOUT TIME ZONE")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Time);
-        let ty = Parser::new_with_sql(&dialect, "TIMESTAMP")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Timestamp);
-        let ty = Parser::new_with_sql(&dialect, "TIMESTAMP WITH TIME ZONE")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Timestamp);
-        let ty =
-            Parser::new_with_sql(&dialect, "TIMESTAMP WITHOUT TIME ZONE")?.parse_data_type()?;
-        assert_eq!(ty, DataType::Timestamp);
+        let time = |precision, with_time_zone| DataType::Time {
+            precision,
+            with_time_zone,
+        };
+        let timestamp = |precision, with_time_zone| DataType::Timestamp {
+            precision,
+            with_time_zone,
+        };
+        for (sql, expected) in [
+            ("TIME", time(None, false)),
+            ("TIME WITH TIME ZONE", time(None, true)),
+            ("TIME WITHOUT TIME ZONE", time(None, false)),
+            ("TIME(3)", time(Some(3), false)),
+            ("TIME(3) WITH TIME ZONE", time(Some(3), true)),
+            ("TIMESTAMP", timestamp(None, false)),
+            ("TIMESTAMP WITH TIME ZONE", timestamp(None, true)),
+            ("TIMESTAMP WITHOUT TIME ZONE", timestamp(None, false)),
+            ("TIMESTAMP(6)", timestamp(Some(6), false)),
+            ("TIMESTAMP(6) WITH TIME ZONE", timestamp(Some(6), true)),
+        ] {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?.parse_data_type()?,
+                expected
+            );
+        }
+        assert_eq!(time(Some(3), true).to_string(), "TIME(3) WITH TIME ZONE");
+        assert_eq!(timestamp(Some(6), false).to_string(), "TIMESTAMP(6)");
         let ty = Parser::new_with_sql(&dialect, "INTERVAL")?.parse_data_type()?;
         assert_eq!(ty, DataType::Interval);
         Ok(())
     }
+
+    // #[test]
+    fn parse_data_type_other() -> Result<(), ParserError> {
+        let dialect = crate::postgres::PostgresDialect::default();
+        for (sql, expected) in [
+            ("MONEY", DataType::Money),
+            ("uuid", DataType::Uuid),
+            ("INET", DataType::Inet),
+            ("JSON", DataType::Json),
+            ("jsonb", DataType::Jsonb),
+        ] {
+            assert_eq!(
+                Parser::new_with_sql(&dialect, sql)?.parse_data_type()?,
+                expected
+            );
+        }
+        assert!(Parser::new_with_sql(&dialect, "\"uuid\"")?
+            .parse_data_type()
+            .is_err());
+
+        let dialect = crate::mysql::MysqlDialect::default();
+        let ty = Parser::new_with_sql(&dialect, "JSON")?.parse_data_type()?;
+        assert_eq!(ty, DataType::Json);
+        let ty = Parser::new_with_sql(&dialect, "ENUM('a', 'b')")?.parse_data_type()?;
+        assert_eq!(ty, DataType::Enum(vec!["a".into(), "b".into()]));
+        assert_eq!(ty.to_string(), "ENUM('a', 'b')");
+        let ty = DataType::Enum(vec!["it's".into()]);
+        assert_eq!(ty.to_string(), "ENUM('it''s')");
+        let ty = Parser::new_with_sql(&dialect, "SET('x','y')")?.parse_data_type()?;
+        assert_eq!(ty, DataType::Set(vec!["x".into(), "y".into()]));
+        assert_eq!(ty.to_string(), "SET('x', 'y')");
+        assert!(Parser::new_with_sql(&dialect, "ENUM()")?
+            .parse_data_type()
+            .is_err());
+        Ok(())
+    }
 }
@@ -96,6 +96,7 @@ define_keyword! {
         CYCLE,
         DATA,
         DATABASE,
+        DATE,
         DAY,
         DEALLOCATE,
         DEC,
@@ -209,6 +210,8 @@ define_keyword! {
         LARGE,
         LAST,
         LATERAL,
+        LC_COLLATE,
+        LC_CTYPE,
         LEADING,
         LEAKPROOF,
         LEAST,
@@ -1,7 +1,16 @@
 mod keyword;
 
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
 pub use self::keyword::PostgresKeyword;
-use crate::dialect::{CustomDialect, DialectLexerConf, DialectParserConf};
+use crate::{
+    ast::types::Ident,
+    dialect::{
+        CaseFolding, CustomDialect, DialectLexerConf, DialectParserConf, IdentifierValidity,
+        Precedence,
+    },
+};
 
 /// The PostgreSQL dialect.
 pub type PostgresDialect =
@@ -10,7 +19,11 @@ pub type PostgresDialect =
 /// The lexer configuration of PostgreSQL dialect.
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct PostgresLexerConfig {}
+pub struct PostgresLexerConfig {
+    /// If the psql mode is enabled, lines starting with a backslash are tokenized as psql
+    /// meta-commands, as found in dump and migration files.
+    pub psql_mode: bool,
+}
 
 impl DialectLexerConf for PostgresLexerConfig {
     // See https://www.postgresql.org/docs/13/sql-syntax-lexical.html#SQL-SYNTAX-IDENTIFIERS
@@ -32,11 +45,125 @@ impl DialectLexerConf for PostgresLexerConfig {
     fn is_identifier_part(&self, ch: char) -> bool {
         ch.is_ascii_alphanumeric() || ch == '_' || ch == '$'
     }
+
+    fn supports_meta_command(&self) -> bool {
+        self.psql_mode
+    }
+
+    // See https://www.postgresql.org/docs/13/sql-syntax-lexical.html#SQL-SYNTAX-DOLLAR-QUOTING
+    fn supports_dollar_quoted_string(&self) -> bool {
+        true
+    }
+
+    // See https://www.postgresql.org/docs/13/sql-syntax-lexical.html#SQL-SYNTAX-BIT-STRINGS
+    //
+    // A hexadecimal bit-string constant is a bit string, where each hexadecimal digit stands for
+    // four binary digits, so the number of digits is not required to be even.
+    fn requires_even_hex_digits(&self) -> bool {
+        false
+    }
+
+    // See https://www.postgresql.org/docs/13/sql-syntax-lexical.html#SQL-SYNTAX-STRINGS-ESCAPE
+    fn supports_escape_string(&self) -> bool {
+        true
+    }
+
+    // Key words and unquoted identifiers are case insensitive, and folded to lower case.
+    fn identifier_case_folding(&self) -> CaseFolding {
+        CaseFolding::Lower
+    }
+
+    // The system uses no more than NAMEDATALEN-1 bytes of an identifier; longer names can be
+    // written in commands, but they will be truncated. By default, NAMEDATALEN is 64.
+    //
+    // Quoted identifiers can contain any character, except the character with code zero.
+    fn validate_identifier(&self, value: &str, quoted: bool) -> IdentifierValidity {
+        if quoted && value.contains('\0') {
+            IdentifierValidity::Invalid("Identifier contains the character with code zero".into())
+        } else if value.len() > 63 {
+            IdentifierValidity::Warning(
+                "Identifier exceeds the maximum length of 63 bytes and will be truncated".into(),
+            )
+        } else {
+            IdentifierValidity::Valid
+        }
+    }
 }
 
 /// The parser configuration of PostgreSQL dialect.
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct PostgresParserConfig {}
+pub struct PostgresParserConfig {
+    /// The type names of the catalog, a call to one of them like `int4(x)` is parsed as a
+    /// function-style cast. Unquoted names are matched case-insensitively.
+    pub type_names: Vec<String>,
+}
 
-impl DialectParserConf for PostgresParserConfig {}
+impl DialectParserConf for PostgresParserConfig {
+    // See https://www.postgresql.org/docs/13/sql-syntax-lexical.html#SQL-PRECEDENCE
+    fn prec_value(&self, prec: Precedence) -> u8 {
+        match prec {
+            Precedence::Or => 5,
+            Precedence::And => 10,
+            Precedence::UnaryNot => 15,
+            Precedence::Is => 17,
+            Precedence::Comparison | Precedence::LessGreater => 20,
+            Precedence::Like | Precedence::Between => 22,
+            // `||`, bitwise and all other operators share the same precedence
+            Precedence::BitwiseOr
+            | Precedence::BitwiseXor
+            | Precedence::BitwiseAnd
+            | Precedence::BitwiseShift
+            | Precedence::Other
+            | Precedence::Concat => 25,
+            Precedence::PlusMinus => 30,
+            Precedence::MulDivMod => 40,
+            Precedence::Caret => 42,
+            Precedence::Collate => 45,
+            Precedence::DoubleColon => 50,
+            // There is no `XOR` operator.
+            Precedence::Xor => 0,
+        }
+    }
+
+    fn supports_postgres_operators(&self) -> bool {
+        true
+    }
+
+    fn supports_select_without_from(&self) -> bool {
+        true
+    }
+
+    // See https://www.postgresql.org/docs/13/dml-returning.html
+    fn supports_returning_clause(&self) -> bool {
+        true
+    }
+
+    // See https://www.postgresql.org/docs/13/sql-update.html
+    fn supports_update_returning(&self) -> bool {
+        true
+    }
+
+    // See https://www.postgresql.org/docs/13/queries-with.html#QUERIES-WITH-MODIFYING
+    fn supports_data_modifying_cte(&self) -> bool {
+        true
+    }
+
+    // See https://www.postgresql.org/docs/13/sql-select.html#SQL-GROUPBY
+    fn supports_group_by_select_list(&self) -> bool {
+        true
+    }
+
+    // See https://www.postgresql.org/docs/current/sql-createdatabase.html
+    fn supports_postgres_database_options(&self) -> bool {
+        true
+    }
+
+    // See https://www.postgresql.org/docs/13/sql-expressions.html#SQL-SYNTAX-TYPE-CASTS
+    fn is_type_name(&self, name: &Ident) -> bool {
+        self.type_names.iter().any(|type_name| match name.quote {
+            None => type_name.eq_ignore_ascii_case(&name.value),
+            Some(_) => type_name == &name.value,
+        })
+    }
+}
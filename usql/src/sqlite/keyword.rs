@@ -57,6 +57,7 @@ define_keyword! {
         EXISTS,
         EXPLAIN,
         FAIL,
+        FALSE,
         FILTER,
         FIRST,
         FOLLOWING,
@@ -138,6 +139,7 @@ define_keyword! {
         TO,
         TRANSACTION,
         TRIGGER,
+        TRUE,
         UNBOUNDED,
         UNION,
         UNIQUE,
@@ -1,7 +1,10 @@
 mod keyword;
 
 pub use self::keyword::SqliteKeyword;
-use crate::dialect::{CustomDialect, DialectLexerConf, DialectParserConf};
+use crate::dialect::{
+    CaseFolding, CustomDialect, DialectLexerConf, DialectParserConf, Precedence,
+    StringConcatenation,
+};
 
 /// The SQLite dialect.
 pub type SqliteDialect = CustomDialect<SqliteKeyword, SqliteLexerConfig, SqliteParserConfig>;
@@ -45,6 +48,23 @@ impl DialectLexerConf for SqliteLexerConfig {
             || ch == '$'
             || ('\u{0080}'..='\u{ffff}').contains(&ch)
     }
+
+    // See https://www.sqlite.org/lang_comment.html
+    //
+    // C-style comments can span multiple lines. C-style comments do not nest.
+    fn supports_nested_comments(&self) -> bool {
+        false
+    }
+
+    // Identifiers are case insensitive, but the case of unquoted identifiers is preserved.
+    fn identifier_case_folding(&self) -> CaseFolding {
+        CaseFolding::None
+    }
+
+    // Adjacent string literals are not concatenated.
+    fn string_literal_concatenation(&self) -> StringConcatenation {
+        StringConcatenation::Never
+    }
 }
 
 /// The parser configuration of SQLite dialect.
@@ -52,4 +72,61 @@ impl DialectLexerConf for SqliteLexerConfig {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SqliteParserConfig {}
 
-impl DialectParserConf for SqliteParserConfig {}
+impl DialectParserConf for SqliteParserConfig {
+    // See https://www.sqlite.org/lang_expr.html#operators_and_parse_affecting_attributes
+    fn prec_value(&self, prec: Precedence) -> u8 {
+        match prec {
+            Precedence::Or => 5,
+            Precedence::And => 10,
+            Precedence::UnaryNot => 15,
+            Precedence::Is
+            | Precedence::Comparison
+            | Precedence::Like
+            | Precedence::Between
+            | Precedence::Other => 20,
+            Precedence::LessGreater => 21,
+            // `|`, `&`, `<<` and `>>` share the same precedence
+            Precedence::BitwiseOr
+            | Precedence::BitwiseXor
+            | Precedence::BitwiseAnd
+            | Precedence::BitwiseShift
+            | Precedence::Caret => 25,
+            Precedence::PlusMinus => 30,
+            Precedence::MulDivMod => 40,
+            Precedence::Concat => 42,
+            Precedence::Collate => 45,
+            Precedence::DoubleColon => 50,
+            // There is no `XOR` operator.
+            Precedence::Xor => 0,
+        }
+    }
+
+    fn supports_select_without_from(&self) -> bool {
+        true
+    }
+
+    // See https://www.sqlite.org/lang_returning.html
+    fn supports_returning_clause(&self) -> bool {
+        true
+    }
+
+    // See https://www.sqlite.org/lang_returning.html
+    fn supports_update_returning(&self) -> bool {
+        true
+    }
+
+    // See https://www.sqlite.org/lang.html
+    fn supports_sqlite_statements(&self) -> bool {
+        true
+    }
+
+    // See https://www.sqlite.org/lang_conflict.html
+    fn supports_conflict_clause(&self) -> bool {
+        true
+    }
+
+    // See https://www.sqlite.org/lang_select.html#resultset
+    fn supports_group_by_select_list(&self) -> bool {
+        true
+    }
+}
@@ -1,12 +1,16 @@
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec::Vec};
-use core::fmt;
+use core::fmt::{self, Write};
 
-use crate::keywords::{Keyword, KeywordDef};
+use crate::{
+    error::Span,
+    keywords::{Keyword, KeywordDef},
+};
 
 /// SQL token
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Token {
     /// Whitespace (space, newline, tab).
     Whitespace(Whitespace),
@@ -24,6 +28,20 @@ pub enum Token {
     HexString(String),
     /// Bit string literal: i.e.: B'101010'. (Not ANSI SQL)
     BitString(String),
+    /// Escape string literal: i.e.: E'it\'s\n'. (PostgreSQL specific)
+    EscapeString {
+        /// The string between the quotes, kept verbatim.
+        raw: String,
+        /// The string with the escape sequences decoded.
+        value: String,
+    },
+    /// Dollar-quoted string literal: i.e.: $tag$string$tag$ or $$string$$. (PostgreSQL specific)
+    DollarString {
+        /// The tag between the dollar signs, may be empty.
+        tag: String,
+        /// The string between the delimiters, kept verbatim.
+        value: String,
+    },
 
     /// A keyword (like SELECT) or an optionally quoted SQL identifier.
     /// Non-reserved keywords are permitted as identifiers without quoting.
@@ -66,6 +84,8 @@ pub enum Token {
     Greater,
     /// Greater than or equal `>=`
     GreaterOrEqual,
+    /// Spaceship `<=>` (MySQL NULL-safe equal)
+    Spaceship,
 
     /// Left Shift `<<`
     LeftShift,
@@ -93,18 +113,40 @@ pub enum Token {
     Question,
     /// Tilde `~`
     Tilde,
+    /// Double tilde `~~`
+    DoubleTilde,
+    /// Exclamation tilde `!~`
+    ExclamationTilde,
+    /// Exclamation double tilde `!~~`
+    ExclamationDoubleTilde,
+    /// Tilde asterisk `~*`
+    TildeAsterisk,
+    /// Exclamation tilde asterisk `!~*`
+    ExclamationTildeAsterisk,
     /// Ampersand `&`
     Ampersand,
     /// Pipe `|`
     Pipe,
     /// Concat `||`
     Concat,
+    /// Pipe slash `|/` (PostgreSQL square root)
+    PipeSlash,
+    /// Double pipe slash `||/` (PostgreSQL cube root)
+    DoublePipeSlash,
     /// Backslash `\`
     Backslash,
     /// Sharp `#`
     Sharp,
     /// At `@`
     At,
+    /// At minus at `@-@` (PostgreSQL length)
+    AtMinusAt,
+    /// Less minus greater `<->` (PostgreSQL distance)
+    LessMinusGreater,
+
+    /// A psql meta-command line: i.e. `\connect db`, without the leading backslash.
+    /// (PostgreSQL specific, only produced if enabled in the lexer configuration)
+    MetaCommand(String),
 
     /// A character that could not be tokenized.
     Char(char),
@@ -120,6 +162,8 @@ impl fmt::Display for Token {
             Token::NationalString(s) => write!(f, "N'{}'", s),
             Token::BitString(s) => write!(f, "B'{}'", s),
             Token::HexString(s) => write!(f, "X'{}'", s),
+            Token::EscapeString { raw, .. } => write!(f, "E'{}'", raw),
+            Token::DollarString { tag, value } => write!(f, "${}${}${}$", tag, value, tag),
             Token::Word(word) => write!(f, "{}", word),
             Token::Comma => f.write_str(","),
             Token::SemiColon => f.write_str(";"),
@@ -138,6 +182,7 @@ impl fmt::Display for Token {
             Token::LessOrEqual => f.write_str("<="),
             Token::Greater => f.write_str(">"),
             Token::GreaterOrEqual => f.write_str(">="),
+            Token::Spaceship => f.write_str("<=>"),
             Token::LeftShift => f.write_str("<<"),
             Token::RightShift => f.write_str(">>"),
             Token::Plus => f.write_str("+"),
@@ -150,12 +195,22 @@ impl fmt::Display for Token {
             Token::DoubleExclamation => f.write_str("!!"),
             Token::Question => f.write_str("?"),
             Token::Tilde => f.write_str("~"),
+            Token::DoubleTilde => f.write_str("~~"),
+            Token::ExclamationTilde => f.write_str("!~"),
+            Token::ExclamationDoubleTilde => f.write_str("!~~"),
+            Token::TildeAsterisk => f.write_str("~*"),
+            Token::ExclamationTildeAsterisk => f.write_str("!~*"),
             Token::Ampersand => f.write_str("&"),
             Token::Pipe => f.write_str("|"),
             Token::Concat => f.write_str("||"),
+            Token::PipeSlash => f.write_str("|/"),
+            Token::DoublePipeSlash => f.write_str("||/"),
             Token::Backslash => f.write_str("\\"),
             Token::Sharp => f.write_str("#"),
             Token::At => f.write_str("@"),
+            Token::AtMinusAt => f.write_str("@-@"),
+            Token::LessMinusGreater => f.write_str("<->"),
+            Token::MetaCommand(command) => write!(f, "\\{}", command),
             Token::Char(c) => write!(f, "{}", c),
         }
     }
@@ -167,15 +222,7 @@ impl Token {
     pub fn word<K: KeywordDef, W: Into<String>>(value: W, quote: Option<char>) -> Self {
         let value = value.into();
         Self::Word(Word {
-            keyword: if quote.is_none() {
-                let keyword_uppercase = value.to_uppercase();
-                K::KEYWORDS_STRING
-                    .binary_search(&keyword_uppercase.as_str())
-                    .map(|x| K::KEYWORDS[x])
-                    .ok()
-            } else {
-                None
-            },
+            keyword: if quote.is_none() { K::lookup(&value) } else { None },
             value,
             quote,
         })
@@ -185,12 +232,7 @@ impl Token {
     // https://github.com/rust-lang/rust/issues/83701
     pub fn keyword<K: KeywordDef, W: Into<String>>(value: W) -> Option<Self> {
         let value = value.into();
-        let keyword_uppercase = value.to_uppercase();
-        let keyword = K::KEYWORDS_STRING
-            .binary_search(&keyword_uppercase.as_str())
-            .map(|x| K::KEYWORDS[x])
-            .ok();
-        keyword.map(|kw| {
+        K::lookup(&value).map(|kw| {
             Self::Word(Word {
                 keyword: Some(kw),
                 value,
@@ -199,6 +241,72 @@ impl Token {
         })
     }
 
+    /// Returns the kind of the token.
+    pub fn kind(&self) -> TokenKind {
+        match self {
+            Token::Whitespace(_) => TokenKind::Whitespace,
+            Token::Comment(_) => TokenKind::Comment,
+            Token::Number(_) => TokenKind::Number,
+            Token::String(_) => TokenKind::String,
+            Token::NationalString(_) => TokenKind::NationalString,
+            Token::HexString(_) => TokenKind::HexString,
+            Token::BitString(_) => TokenKind::BitString,
+            Token::EscapeString { .. } => TokenKind::EscapeString,
+            Token::DollarString { .. } => TokenKind::DollarString,
+            Token::Word(word) => TokenKind::Word {
+                keyword: word.keyword,
+                quote: word.quote,
+            },
+            Token::Period => TokenKind::Period,
+            Token::Comma => TokenKind::Comma,
+            Token::SemiColon => TokenKind::SemiColon,
+            Token::Colon => TokenKind::Colon,
+            Token::DoubleColon => TokenKind::DoubleColon,
+            Token::LeftParen => TokenKind::LeftParen,
+            Token::RightParen => TokenKind::RightParen,
+            Token::LeftBracket => TokenKind::LeftBracket,
+            Token::RightBracket => TokenKind::RightBracket,
+            Token::LeftBrace => TokenKind::LeftBrace,
+            Token::RightBrace => TokenKind::RightBrace,
+            Token::Equal => TokenKind::Equal,
+            Token::NotEqual => TokenKind::NotEqual,
+            Token::Less => TokenKind::Less,
+            Token::LessOrEqual => TokenKind::LessOrEqual,
+            Token::Greater => TokenKind::Greater,
+            Token::GreaterOrEqual => TokenKind::GreaterOrEqual,
+            Token::Spaceship => TokenKind::Spaceship,
+            Token::LeftShift => TokenKind::LeftShift,
+            Token::RightShift => TokenKind::RightShift,
+            Token::Plus => TokenKind::Plus,
+            Token::Minus => TokenKind::Minus,
+            Token::Asterisk => TokenKind::Asterisk,
+            Token::Slash => TokenKind::Slash,
+            Token::Percent => TokenKind::Percent,
+            Token::Caret => TokenKind::Caret,
+            Token::Exclamation => TokenKind::Exclamation,
+            Token::DoubleExclamation => TokenKind::DoubleExclamation,
+            Token::Question => TokenKind::Question,
+            Token::Tilde => TokenKind::Tilde,
+            Token::DoubleTilde => TokenKind::DoubleTilde,
+            Token::ExclamationTilde => TokenKind::ExclamationTilde,
+            Token::ExclamationDoubleTilde => TokenKind::ExclamationDoubleTilde,
+            Token::TildeAsterisk => TokenKind::TildeAsterisk,
+            Token::ExclamationTildeAsterisk => TokenKind::ExclamationTildeAsterisk,
+            Token::Ampersand => TokenKind::Ampersand,
+            Token::Pipe => TokenKind::Pipe,
+            Token::Concat => TokenKind::Concat,
+            Token::PipeSlash => TokenKind::PipeSlash,
+            Token::DoublePipeSlash => TokenKind::DoublePipeSlash,
+            Token::Backslash => TokenKind::Backslash,
+            Token::Sharp => TokenKind::Sharp,
+            Token::At => TokenKind::At,
+            Token::AtMinusAt => TokenKind::AtMinusAt,
+            Token::LessMinusGreater => TokenKind::LessMinusGreater,
+            Token::MetaCommand(_) => TokenKind::MetaCommand,
+            Token::Char(ch) => TokenKind::Char(*ch),
+        }
+    }
+
     /// Checks if the token is whitespace.
     pub fn is_whitespace(&self) -> bool {
         matches!(self, Token::Whitespace(_))
@@ -209,6 +317,41 @@ impl Token {
         matches!(self, Token::Comment(_))
     }
 
+    /// Checks if the token is the single character punctuation `ch`, e.g. `Token::Plus` for `+`.
+    pub fn is_punct(&self, ch: char) -> bool {
+        let token = match ch {
+            '.' => Token::Period,
+            ',' => Token::Comma,
+            ';' => Token::SemiColon,
+            ':' => Token::Colon,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            '{' => Token::LeftBrace,
+            '}' => Token::RightBrace,
+            '=' => Token::Equal,
+            '<' => Token::Less,
+            '>' => Token::Greater,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Asterisk,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '^' => Token::Caret,
+            '!' => Token::Exclamation,
+            '?' => Token::Question,
+            '~' => Token::Tilde,
+            '&' => Token::Ampersand,
+            '|' => Token::Pipe,
+            '\\' => Token::Backslash,
+            '#' => Token::Sharp,
+            '@' => Token::At,
+            _ => return false,
+        };
+        *self == token
+    }
+
     /// Checks if the token is keyword.
     #[inline]
     pub fn is_keyword(&self, keyword: Keyword) -> bool {
@@ -228,10 +371,274 @@ impl Token {
     }
 }
 
+/// The kind of a [`Token`] without the owned data (like the value of a literal), which is cheap
+/// to copy, so that the parser can dispatch on the next token without cloning it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum TokenKind {
+    /// Whitespace (space, newline, tab).
+    Whitespace,
+    /// Single-line comment or multi-line comment.
+    Comment,
+    /// An unsigned numeric literal.
+    Number,
+    /// Character string literal: i.e: 'string'
+    String,
+    /// National character string literal: i.e: N'string'.
+    NationalString,
+    /// Hexadecimal string literal: i.e.: X'deadbeef'.
+    HexString,
+    /// Bit string literal: i.e.: B'101010'. (Not ANSI SQL)
+    BitString,
+    /// Escape string literal: i.e.: E'it\'s\n'. (PostgreSQL specific)
+    EscapeString,
+    /// Dollar-quoted string literal: i.e.: $tag$string$tag$ or $$string$$. (PostgreSQL specific)
+    DollarString,
+    /// A keyword or an optionally quoted identifier, see [`Word`].
+    Word {
+        /// See [`Word::keyword`].
+        keyword: Option<Keyword>,
+        /// See [`Word::quote`].
+        quote: Option<char>,
+    },
+    /// Period `.`
+    Period,
+    /// Comma `,`
+    Comma,
+    /// SemiColon `;`
+    SemiColon,
+    /// Colon `:`
+    Colon,
+    /// Double colon `::`
+    DoubleColon,
+    /// Left parenthesis `(`
+    LeftParen,
+    /// Right parenthesis `)`
+    RightParen,
+    /// Left bracket `[`
+    LeftBracket,
+    /// Right bracket `]`
+    RightBracket,
+    /// Left brace `{`
+    LeftBrace,
+    /// Right brace `}`
+    RightBrace,
+    /// Equal `=`
+    Equal,
+    /// Not equal `<>` or `!=`
+    NotEqual,
+    /// Less than `<`
+    Less,
+    /// Less than or equal `<=`
+    LessOrEqual,
+    /// Greater than `>`
+    Greater,
+    /// Greater than or equal `>=`
+    GreaterOrEqual,
+    /// Spaceship `<=>` (MySQL NULL-safe equal)
+    Spaceship,
+    /// Left Shift `<<`
+    LeftShift,
+    /// Right Shift `>>`
+    RightShift,
+    /// Plus `+`
+    Plus,
+    /// Minus `-`
+    Minus,
+    /// Asterisk `*`
+    Asterisk,
+    /// Slash `/`
+    Slash,
+    /// Percent `%`
+    Percent,
+    /// Caret `^`
+    Caret,
+    /// Exclamation `!`
+    Exclamation,
+    /// Double exclamation `!!`
+    DoubleExclamation,
+    /// Question `?`
+    Question,
+    /// Tilde `~`
+    Tilde,
+    /// Double tilde `~~`
+    DoubleTilde,
+    /// Exclamation tilde `!~`
+    ExclamationTilde,
+    /// Exclamation double tilde `!~~`
+    ExclamationDoubleTilde,
+    /// Tilde asterisk `~*`
+    TildeAsterisk,
+    /// Exclamation tilde asterisk `!~*`
+    ExclamationTildeAsterisk,
+    /// Ampersand `&`
+    Ampersand,
+    /// Pipe `|`
+    Pipe,
+    /// Concat `||`
+    Concat,
+    /// Pipe slash `|/` (PostgreSQL square root)
+    PipeSlash,
+    /// Double pipe slash `||/` (PostgreSQL cube root)
+    DoublePipeSlash,
+    /// Backslash `\`
+    Backslash,
+    /// Sharp `#`
+    Sharp,
+    /// At `@`
+    At,
+    /// At minus at `@-@` (PostgreSQL length)
+    AtMinusAt,
+    /// Less minus greater `<->` (PostgreSQL distance)
+    LessMinusGreater,
+    /// A psql meta-command line: i.e. `\connect db`, without the leading backslash.
+    MetaCommand,
+    /// A character that could not be tokenized.
+    Char(char),
+}
+
+impl TokenKind {
+    /// Checks if the token is the keyword.
+    #[inline]
+    pub fn is_keyword(self, keyword: Keyword) -> bool {
+        matches!(self, TokenKind::Word { keyword: Some(kw), .. } if kw == keyword)
+    }
+}
+
+/// A sequence of tokens paired with their byte ranges in the input.
+///
+/// The token stream can be cached (e.g. serialized with the `serde` feature) and parsed
+/// multiple times without lexing the input again, see [`Parser::new_with_token_stream`].
+///
+/// [`Parser::new_with_token_stream`]: crate::Parser::new_with_token_stream
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenStream {
+    /// The tokens and their source ranges, in source order.
+    pub tokens: Vec<(Token, Span)>,
+}
+
+impl TokenStream {
+    /// Creates a new token stream with the given tokens.
+    pub fn new(tokens: Vec<(Token, Span)>) -> Self {
+        Self { tokens }
+    }
+
+    /// Returns an iterator over the tokens and their source ranges.
+    pub fn iter(&self) -> impl Iterator<Item = &(Token, Span)> {
+        self.tokens.iter()
+    }
+
+    /// Returns the number of tokens.
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Returns true if the stream contains no tokens.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+}
+
+impl From<Vec<(Token, Span)>> for TokenStream {
+    fn from(tokens: Vec<(Token, Span)>) -> Self {
+        Self::new(tokens)
+    }
+}
+
+/// The delimiter of a token group.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Delimiter {
+    /// Parenthesis `( ... )`
+    Parenthesis,
+    /// Bracket `[ ... ]`
+    Bracket,
+    /// Brace `{ ... }`
+    Brace,
+}
+
+impl Delimiter {
+    /// Returns the delimiter opened by the token.
+    pub fn from_open_token(token: &Token) -> Option<Self> {
+        match token {
+            Token::LeftParen => Some(Delimiter::Parenthesis),
+            Token::LeftBracket => Some(Delimiter::Bracket),
+            Token::LeftBrace => Some(Delimiter::Brace),
+            _ => None,
+        }
+    }
+
+    /// Returns the delimiter closed by the token.
+    pub fn from_close_token(token: &Token) -> Option<Self> {
+        match token {
+            Token::RightParen => Some(Delimiter::Parenthesis),
+            Token::RightBracket => Some(Delimiter::Bracket),
+            Token::RightBrace => Some(Delimiter::Brace),
+            _ => None,
+        }
+    }
+
+    /// Returns the opening token of the delimiter.
+    pub fn open_token(&self) -> Token {
+        match self {
+            Delimiter::Parenthesis => Token::LeftParen,
+            Delimiter::Bracket => Token::LeftBracket,
+            Delimiter::Brace => Token::LeftBrace,
+        }
+    }
+
+    /// Returns the closing token of the delimiter.
+    pub fn close_token(&self) -> Token {
+        match self {
+            Delimiter::Parenthesis => Token::RightParen,
+            Delimiter::Bracket => Token::RightBracket,
+            Delimiter::Brace => Token::RightBrace,
+        }
+    }
+}
+
+/// A balanced sequence of token trees enclosed by a delimiter, e.g. `(a, b)`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group {
+    /// The delimiter of the group.
+    pub delimiter: Delimiter,
+    /// The token trees between the delimiters.
+    pub trees: Vec<TokenTree>,
+    /// The source range of the group, including the delimiters.
+    pub span: Span,
+}
+
+/// A single token or a delimited group of token trees.
+///
+/// Nesting the balanced `()`, `[]` and `{}` regions makes it cheap to look past
+/// a whole region (e.g. a subquery) or to skip it for error recovery.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TokenTree {
+    /// A token that isn't a delimiter.
+    Token(Token, Span),
+    /// A delimited group.
+    Group(Group),
+}
+
+impl TokenTree {
+    /// Returns the source range of the token tree.
+    pub fn span(&self) -> Span {
+        match self {
+            TokenTree::Token(_, span) => *span,
+            TokenTree::Group(group) => group.span,
+        }
+    }
+}
+
 /// Whitespace token
 #[doc(hidden)]
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Whitespace {
     Space,
     Newline,
@@ -251,6 +658,7 @@ impl fmt::Display for Whitespace {
 /// Comment token
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Comment {
     /// Single line comment.
     SingleLine {
@@ -301,11 +709,23 @@ pub struct Word {
 
 impl fmt::Display for Word {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.quote {
-            None => f.write_str(&self.value),
-            Some(q) if q == '"' || q == '`' => write!(f, "{}{}{}", q, self.value, q),
+        let (open_quote, close_quote) = match self.quote {
+            None => return f.write_str(&self.value),
+            Some(q) if q == '"' || q == '`' => (q, q),
+            // the close bracket can't be escaped, so the identifier is quoted with double quotes
+            Some('[') if self.value.contains(']') => ('"', '"'),
+            Some('[') => ('[', ']'),
             Some(q) => panic!("Unsupported quote character {} for SQL identifier!", q),
+        };
+        f.write_char(open_quote)?;
+        for ch in self.value.chars() {
+            // escape the close quote by doubling it
+            if ch == close_quote {
+                f.write_char(ch)?;
+            }
+            f.write_char(ch)?;
         }
+        f.write_char(close_quote)
     }
 }
 
@@ -313,6 +733,34 @@ impl fmt::Display for Word {
 mod tests {
     use super::*;
 
+    #[test]
+    fn token_is_punct() {
+        assert!(Token::Plus.is_punct('+'));
+        assert!(Token::LeftParen.is_punct('('));
+        assert!(!Token::Plus.is_punct('-'));
+        assert!(!Token::DoubleColon.is_punct(':'));
+        assert!(!Token::Number("1".into()).is_punct('1'));
+    }
+
+    #[test]
+    fn token_kind() {
+        assert_eq!(Token::Number("1".into()).kind(), TokenKind::Number);
+        assert_eq!(Token::Comma.kind(), TokenKind::Comma);
+        assert_eq!(Token::Char('\u{1}').kind(), TokenKind::Char('\u{1}'));
+
+        let select = Token::word::<crate::ansi::AnsiKeyword, _>("select", None);
+        assert_eq!(
+            select.kind(),
+            TokenKind::Word {
+                keyword: Some(Keyword::SELECT),
+                quote: None
+            }
+        );
+        assert!(select.kind().is_keyword(Keyword::SELECT));
+        let quoted = Token::word::<crate::ansi::AnsiKeyword, _>("select", Some('"'));
+        assert!(!quoted.kind().is_keyword(Keyword::SELECT));
+    }
+
     #[test]
     fn comment_display() {
         let comment = Comment::SingleLine {
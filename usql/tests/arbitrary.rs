@@ -0,0 +1,31 @@
+//! Tests of the random ASTs generated by `arbitrary::Arbitrary`, which is derived for all the AST
+//! types with the `arbitrary` feature.
+
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use usql::ast::statement::Stmt;
+
+/// Generates the pseudo-random bytes with a xorshift generator.
+fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn render_arbitrary_stmts() {
+    for seed in 1..=256 {
+        let bytes = random_bytes(seed, 4096);
+        let stmt = Stmt::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        // every generated AST can be rendered, though not always parsed back
+        let _ = stmt.to_string();
+        assert_eq!(stmt.clone(), stmt);
+    }
+}
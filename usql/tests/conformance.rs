@@ -0,0 +1,133 @@
+//! Multi-dialect conformance tests.
+//!
+//! Every dialect has a set of sample statements in `tests/samples/<dialect>.sql`, one statement
+//! per line. The samples are hand-written after the test suites and the documentation of the
+//! databases (the header of every file names its models), they aren't excerpts of them.
+//! A statement that the parser is known not to support is preceded by a comment line:
+//!
+//! ```txt
+//! -- unsupported: <reason>
+//! ```
+//!
+//! The test fails when an unmarked statement can't be parsed, or when a marked statement can be
+//! parsed (the marker should be removed then), so the markers always track the real gaps.
+//! Run `cargo test --test conformance -- --nocapture` to print the report.
+
+use std::{fmt::Write, fs, path::Path};
+
+use usql::{Dialect, Parser};
+
+const UNSUPPORTED_MARKER: &str = "-- unsupported:";
+
+struct Case {
+    line: usize,
+    sql: String,
+    unsupported: Option<String>,
+}
+
+fn load_samples(name: &str) -> Vec<Case> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("samples")
+        .join(format!("{}.sql", name));
+    let content = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err));
+
+    let mut cases = vec![];
+    let mut unsupported = None;
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if let Some(reason) = line.strip_prefix(UNSUPPORTED_MARKER) {
+            unsupported = Some(reason.trim().to_string());
+        } else if line.is_empty() || line.starts_with("--") {
+            continue;
+        } else {
+            cases.push(Case {
+                line: idx + 1,
+                sql: line.to_string(),
+                unsupported: unsupported.take(),
+            });
+        }
+    }
+    cases
+}
+
+fn run_samples<D: Dialect>(name: &str, dialect: &D, min_success_rate: f64) {
+    let cases = load_samples(name);
+    assert!(!cases.is_empty(), "samples `{}` are empty", name);
+
+    let mut passed = 0;
+    let mut report = String::new();
+    let mut mismatches = vec![];
+    for case in &cases {
+        let result = Parser::parse_script(dialect, &case.sql);
+        match (&result, &case.unsupported) {
+            (Ok(_), None) => passed += 1,
+            (Err(_), Some(reason)) => {
+                writeln!(report, "  {}:{}: {}", name, case.line, reason).unwrap();
+            }
+            (Ok(_), Some(_)) => {
+                passed += 1;
+                mismatches.push(format!(
+                    "{}:{}: marked as unsupported but parsed successfully: {}",
+                    name, case.line, case.sql
+                ));
+            }
+            (Err(err), None) => {
+                mismatches.push(format!("{}:{}: {}: {}", name, case.line, err, case.sql))
+            }
+        }
+    }
+
+    let rate = passed as f64 / cases.len() as f64;
+    println!(
+        "{}: {}/{} statements parsed ({:.1}%), known unsupported constructs:\n{}",
+        name,
+        passed,
+        cases.len(),
+        rate * 100.0,
+        report
+    );
+
+    assert!(
+        mismatches.is_empty(),
+        "samples `{}` don't match the known unsupported constructs:\n{}",
+        name,
+        mismatches.join("\n")
+    );
+    assert!(
+        rate >= min_success_rate,
+        "success rate of samples `{}` dropped to {:.1}% (minimum: {:.1}%)",
+        name,
+        rate * 100.0,
+        min_success_rate * 100.0
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_conformance() {
+    run_samples(
+        "postgres",
+        &usql::postgres::PostgresDialect::default(),
+        0.65,
+    );
+}
+
+#[cfg(feature = "mariadb")]
+#[test]
+fn mariadb_conformance() {
+    run_samples("mariadb", &usql::mariadb::MariadbDialect::default(), 0.8);
+}
+
+#[cfg(feature = "mysql")]
+#[test]
+fn mysql_conformance() {
+    run_samples("mysql", &usql::mysql::MysqlDialect::default(), 0.6);
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_conformance() {
+    run_samples("sqlite", &usql::sqlite::SqliteDialect::default(), 0.6);
+}
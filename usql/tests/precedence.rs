@@ -0,0 +1,171 @@
+//! Operator precedence golden tests.
+//!
+//! For every dialect, the binary operators it supports are picked from a table of candidates,
+//! then every pair of them is parsed as `a OP1 b OP2 c`, and the shape of the resulting tree is
+//! compared with the snapshot in `tests/precedence/<dialect>.txt`, one expression per line:
+//!
+//! ```txt
+//! a + b * c => (a + (b * c))
+//! ```
+//!
+//! Run `USQL_BLESS=1 cargo test --test precedence` to regenerate the snapshots after an
+//! intended change of the precedence, and review the diff against the reference databases
+//! rather than against the previous output. For SQLite and PostgreSQL, evaluate both sides of
+//! the changed lines with numeric and string operands, e.g. `SELECT 1 = 2 < 3` and
+//! `SELECT (1 = (2 < 3))` must return the same value. For MySQL, compare them with the
+//! documented operator precedence.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+use usql::{ast::expression::Expr, Dialect, Parser};
+
+/// The spellings of the binary operators, a dialect supports those which can be parsed.
+const OPERATORS: &[&str] = &[
+    "OR",
+    "XOR",
+    "AND",
+    "=",
+    "<>",
+    "!=",
+    "<",
+    "<=",
+    ">",
+    ">=",
+    "<=>",
+    "LIKE",
+    "NOT LIKE",
+    "ILIKE",
+    "NOT ILIKE",
+    "~~",
+    "!~~",
+    "<->",
+    "|",
+    "#",
+    "&",
+    "<<",
+    ">>",
+    "+",
+    "-",
+    "*",
+    "/",
+    "%",
+    "DIV",
+    "MOD",
+    "||",
+    "^",
+];
+
+/// Displays the expression with every binary operation parenthesized.
+fn parenthesize(expr: &Expr) -> String {
+    match expr {
+        Expr::BinaryOp(binary) => format!(
+            "({} {} {})",
+            parenthesize(binary.left()),
+            binary.op(),
+            parenthesize(binary.right())
+        ),
+        Expr::UnaryOp(unary) => format!("({} {})", unary.op(), parenthesize(unary.expr())),
+        expr => expr.to_string(),
+    }
+}
+
+/// Parses the whole SQL as an expression.
+fn parse_expr<D: Dialect>(dialect: &D, sql: &str) -> Option<Expr> {
+    let mut parser = Parser::new_with_sql(dialect, sql).ok()?;
+    let expr = parser.parse_expr().ok()?;
+    if parser.peek_token().is_none() {
+        Some(expr)
+    } else {
+        None
+    }
+}
+
+fn supported_operators<D: Dialect>(dialect: &D) -> Vec<&'static str> {
+    OPERATORS
+        .iter()
+        .copied()
+        .filter(|op| {
+            matches!(
+                parse_expr(dialect, &format!("a {} b", op)),
+                Some(Expr::BinaryOp(_))
+            )
+        })
+        .collect()
+}
+
+fn snapshot<D: Dialect>(dialect: &D) -> String {
+    let operators = supported_operators(dialect);
+    let mut snapshot = String::new();
+    for op1 in &operators {
+        for op2 in &operators {
+            let sql = format!("a {} b {} c", op1, op2);
+            let shape = match parse_expr(dialect, &sql) {
+                Some(expr) => parenthesize(&expr),
+                None => "<error>".into(),
+            };
+            writeln!(snapshot, "{} => {}", sql, shape).unwrap();
+        }
+    }
+    snapshot
+}
+
+fn check_snapshot<D: Dialect>(name: &str, dialect: &D) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("precedence")
+        .join(format!("{}.txt", name));
+    let actual = snapshot(dialect);
+    if env::var_os("USQL_BLESS").is_some() {
+        fs::write(&path, &actual)
+            .unwrap_or_else(|err| panic!("failed to write {}: {}", path.display(), err));
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {}", path.display(), err));
+    let mismatches = expected
+        .lines()
+        .zip(actual.lines())
+        .filter(|(expected, actual)| expected != actual)
+        .map(|(expected, actual)| format!("  expected: {}\n    actual: {}", expected, actual))
+        .collect::<Vec<_>>();
+    assert!(
+        mismatches.is_empty() && expected.lines().count() == actual.lines().count(),
+        "precedence of `{}` doesn't match the snapshot {} ({} lines expected, {} lines actual):\n{}",
+        name,
+        path.display(),
+        expected.lines().count(),
+        actual.lines().count(),
+        mismatches.join("\n")
+    );
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn ansi_precedence() {
+    check_snapshot("ansi", &usql::ansi::AnsiDialect::default());
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_precedence() {
+    check_snapshot("postgres", &usql::postgres::PostgresDialect::default());
+}
+
+#[cfg(feature = "mariadb")]
+#[test]
+fn mariadb_precedence() {
+    check_snapshot("mariadb", &usql::mariadb::MariadbDialect::default());
+}
+
+#[cfg(feature = "mysql")]
+#[test]
+fn mysql_precedence() {
+    check_snapshot("mysql", &usql::mysql::MysqlDialect::default());
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_precedence() {
+    check_snapshot("sqlite", &usql::sqlite::SqliteDialect::default());
+}
@@ -0,0 +1,308 @@
+//! Property-based round-trip tests.
+//!
+//! For every dialect, random queries of a supported grammar subset are generated as AST, then
+//! rendered by `Display` and parsed back, the parsed AST must be equal to the generated one:
+//!
+//! ```txt
+//! parse(render(ast)) == ast
+//! ```
+//!
+//! The subset only contains the constructs whose rendering is canonical, e.g. an operand of an
+//! operator is always parenthesized if it's not a primary expression, so that a failure points
+//! to a real asymmetry between `Display` and the parser.
+//! Set `PROPTEST_CASES` to change the number of the cases per dialect.
+
+use proptest::{
+    prelude::*,
+    strategy::Union,
+    test_runner::{Config, TestRunner},
+};
+use usql::{
+    ast::{
+        expression::*,
+        types::{Ident, Literal, ObjectName},
+    },
+    Capabilities, Dialect, KeywordDef, Parser,
+};
+
+/// The binary operators which are supported by all dialects.
+const BINARY_OPERATORS: &[BinaryOperator] = &[
+    BinaryOperator::Plus,
+    BinaryOperator::Minus,
+    BinaryOperator::Multiply,
+    BinaryOperator::Divide,
+    BinaryOperator::Greater,
+    BinaryOperator::Less,
+    BinaryOperator::GreaterOrEqual,
+    BinaryOperator::LessOrEqual,
+    BinaryOperator::Equal,
+    BinaryOperator::NotEqual,
+    BinaryOperator::And,
+    BinaryOperator::Or,
+];
+
+/// An identifier which is not a keyword of the dialect.
+fn ident<D: Dialect + 'static>() -> impl Strategy<Value = Ident> {
+    "[a-z][a-z0-9_]{0,7}"
+        .prop_filter("keyword", |value| {
+            !D::Keyword::KEYWORDS_STRING.contains(&value.to_uppercase().as_str())
+        })
+        .prop_map(Ident::new)
+}
+
+/// A literal of the dialect, `TRUE` and `FALSE` are identifiers if they are not keywords.
+fn literal<D: Dialect + 'static>() -> impl Strategy<Value = Literal> {
+    let mut literals = vec![
+        Just(Literal::Null).boxed(),
+        any::<u32>()
+            .prop_map(|n| Literal::Number(n.to_string()))
+            .boxed(),
+        "[a-zA-Z0-9 ]{0,8}".prop_map(Literal::String).boxed(),
+    ];
+    if D::Keyword::KEYWORDS_STRING.contains(&"TRUE") {
+        literals.push(any::<bool>().prop_map(Literal::Boolean).boxed());
+    }
+    Union::new(literals)
+}
+
+/// Parenthesizes the expression unless it's a primary expression.
+fn operand(expr: Expr) -> Expr {
+    match expr {
+        Expr::Literal(_) | Expr::Identifier(_) | Expr::CompoundIdentifier(_) | Expr::Nested(_) => {
+            expr
+        }
+        expr => Expr::Nested(Box::new(expr)),
+    }
+}
+
+fn expr<D: Dialect + 'static>() -> impl Strategy<Value = Expr> {
+    let leaf = prop_oneof![
+        literal::<D>().prop_map(Expr::Literal),
+        ident::<D>().prop_map(Expr::Identifier),
+        prop::collection::vec(ident::<D>(), 2..=3).prop_map(Expr::CompoundIdentifier),
+    ];
+    leaf.prop_recursive(4, 32, 3, |inner| {
+        prop_oneof![
+            (
+                inner.clone(),
+                prop::sample::select(BINARY_OPERATORS),
+                inner.clone()
+            )
+                .prop_map(|(left, op, right)| Expr::BinaryOp(BinaryOpExpr::new(
+                    operand(left),
+                    op,
+                    operand(right)
+                ))),
+            inner
+                .clone()
+                .prop_map(|expr| Expr::UnaryOp(UnaryOpExpr::new(
+                    UnaryOperator::Not,
+                    operand(expr)
+                ))),
+            (inner.clone(), any::<bool>()).prop_map(|(expr, negated)| Expr::IsNull(
+                IsNullExpr::new(operand(expr)).with_negated(negated)
+            )),
+            (
+                inner.clone(),
+                any::<bool>(),
+                prop::collection::vec(inner.clone(), 1..=3)
+            )
+                .prop_map(|(expr, negated, list)| Expr::InList(
+                    InListExpr::new(operand(expr), list).with_negated(negated)
+                )),
+            (inner.clone(), any::<bool>(), inner.clone(), inner.clone()).prop_map(
+                |(expr, negated, low, high)| Expr::Between(
+                    BetweenExpr::new(operand(expr), operand(low), operand(high))
+                        .with_negated(negated)
+                )
+            ),
+            inner.prop_map(|expr| Expr::Nested(Box::new(expr))),
+        ]
+    })
+}
+
+fn select_item<D: Dialect + 'static>() -> impl Strategy<Value = SelectItem> {
+    prop_oneof![
+        1 => Just(SelectItem::Wildcard),
+        4 => (expr::<D>(), prop::option::of(ident::<D>())).prop_map(|(expr, alias)| {
+            SelectItem::DerivedColumn {
+                expr: Box::new(expr),
+                alias,
+            }
+        }),
+    ]
+}
+
+fn table_reference<D: Dialect + 'static>() -> impl Strategy<Value = TableReference> {
+    (
+        prop::collection::vec(ident::<D>(), 1..=2),
+        prop::option::of(ident::<D>()),
+    )
+        .prop_map(|(name, alias)| {
+            TableReference::new(
+                TableFactor::Table {
+                    name: ObjectName(name),
+                    partitions: None,
+                    system_time: None,
+                    alias: alias.map(TableAlias::new),
+                    index_hints: vec![],
+                },
+                vec![],
+            )
+        })
+}
+
+fn query_spec<D: Dialect + 'static>(
+    capabilities: Capabilities,
+) -> impl Strategy<Value = QuerySpec> {
+    let from = prop::collection::vec(table_reference::<D>(), 1..=2).prop_map(From::new);
+    let from = if capabilities.select_without_from {
+        prop::option::of(from).boxed()
+    } else {
+        from.prop_map(Some).boxed()
+    };
+    (
+        prop::collection::vec(select_item::<D>(), 1..=3),
+        from,
+        prop::option::of(expr::<D>()),
+    )
+        .prop_map(|(projection, from, r#where)| {
+            let mut spec = QuerySpec::new(projection);
+            if let Some(from) = from {
+                spec = spec.with_from(from);
+            }
+            if let Some(r#where) = r#where {
+                spec = spec.with_where(Where::new(r#where));
+            }
+            spec
+        })
+}
+
+fn sort_spec<D: Dialect + 'static>() -> impl Strategy<Value = SortSpec> {
+    (
+        ident::<D>(),
+        prop::option::of(any::<bool>()),
+        prop::option::of(any::<bool>()),
+    )
+        .prop_map(|(name, asc, nulls_first)| {
+            let mut spec = SortSpec::new(Expr::Identifier(name));
+            if let Some(asc) = asc {
+                spec = spec.with_asc(asc);
+            }
+            if let Some(nulls_first) = nulls_first {
+                spec = spec.with_nulls_first(nulls_first);
+            }
+            spec
+        })
+}
+
+fn count() -> impl Strategy<Value = Literal> {
+    (0u32..1000).prop_map(|n| Literal::Number(n.to_string()))
+}
+
+fn query<D: Dialect + 'static>(capabilities: Capabilities) -> impl Strategy<Value = Query> {
+    let offset = (
+        count(),
+        prop_oneof![
+            Just(OffsetRows::Row),
+            Just(OffsetRows::Rows),
+            Just(OffsetRows::None)
+        ],
+    )
+        .prop_map(|(count, rows)| Offset::new(count, rows));
+    let fetch = (count(), any::<bool>()).prop_map(|(quantity, with_ties)| {
+        Fetch::default()
+            .with_quantity(quantity)
+            .with_with_ties(with_ties)
+    });
+    let limit = count().prop_map(Limit::new);
+    // a query has either a FETCH FIRST clause or a LIMIT clause
+    let fetch_or_limit = match (capabilities.fetch_first, capabilities.limit_clause) {
+        (true, true) => prop_oneof![
+            Just((None, None)),
+            fetch.prop_map(|fetch| (Some(fetch), None)),
+            limit.prop_map(|limit| (None, Some(limit))),
+        ]
+        .boxed(),
+        (true, false) => prop::option::of(fetch)
+            .prop_map(|fetch| (fetch, None))
+            .boxed(),
+        (false, true) => prop::option::of(limit)
+            .prop_map(|limit| (None, limit))
+            .boxed(),
+        (false, false) => Just((None, None)).boxed(),
+    };
+    (
+        query_spec::<D>(capabilities),
+        prop::option::of(prop::collection::vec(sort_spec::<D>(), 1..=2)),
+        prop::option::of(offset),
+        fetch_or_limit,
+    )
+        .prop_map(|(spec, order_by, offset, (fetch, limit))| {
+            let mut query = Query::new(QueryBody::QuerySpec(Box::new(spec)));
+            if let Some(order_by) = order_by {
+                query = query.with_order_by(OrderBy::new(order_by));
+            }
+            if let Some(offset) = offset {
+                query = query.with_offset(offset);
+            }
+            if let Some(fetch) = fetch {
+                query = query.with_fetch(fetch);
+            }
+            if let Some(limit) = limit {
+                query = query.with_limit(limit);
+            }
+            query
+        })
+}
+
+fn check_roundtrip<D: Dialect + 'static>(dialect: &D) {
+    let capabilities = dialect.capabilities();
+    let mut runner = TestRunner::new(Config {
+        failure_persistence: None,
+        ..Config::default()
+    });
+    runner
+        .run(&query::<D>(capabilities), |query| {
+            let sql = query.to_string();
+            let mut parser = Parser::new_with_sql(dialect, &sql)
+                .map_err(|err| TestCaseError::fail(format!("{}: {}", sql, err)))?;
+            let parsed = parser
+                .parse_query_expr(false)
+                .map_err(|err| TestCaseError::fail(format!("{}: {}", sql, err)))?;
+            prop_assert!(parser.peek_token().is_none(), "{}: not fully parsed", sql);
+            prop_assert_eq!(parsed, query, "{}", sql);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn ansi_roundtrip() {
+    check_roundtrip(&usql::ansi::AnsiDialect::default());
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_roundtrip() {
+    check_roundtrip(&usql::postgres::PostgresDialect::default());
+}
+
+#[cfg(feature = "mariadb")]
+#[test]
+fn mariadb_roundtrip() {
+    check_roundtrip(&usql::mariadb::MariadbDialect::default());
+}
+
+#[cfg(feature = "mysql")]
+#[test]
+fn mysql_roundtrip() {
+    check_roundtrip(&usql::mysql::MysqlDialect::default());
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn sqlite_roundtrip() {
+    check_roundtrip(&usql::sqlite::SqliteDialect::default());
+}
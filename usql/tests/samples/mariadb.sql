@@ -0,0 +1,46 @@
+-- Hand-written statements modelled on the examples of the MariaDB documentation,
+-- not copied from it.
+
+SELECT 1;
+SELECT 5--7;
+SELECT 'foo' 'bar';
+SELECT `id`, `name` FROM `t1` WHERE `id` > 10 ORDER BY `name` LIMIT 10;
+SELECT a DIV 2, a MOD 2 FROM t1;
+SELECT _utf8mb4'abc';
+SELECT IF(a > 0, 'pos', 'neg') FROM t1;
+SELECT * FROM t1 FOR SYSTEM_TIME AS OF TIMESTAMP '2016-10-09 08:07:06';
+SELECT x FROM t1 FOR SYSTEM_TIME BETWEEN '2016-01-01 00:00:00' AND NOW() AS h WHERE x > 1;
+SELECT x FROM t1 FOR SYSTEM_TIME FROM '2016-01-01 00:00:00' TO '2017-01-01 00:00:00';
+SELECT * FROM t1 FOR SYSTEM_TIME ALL;
+SELECT NEXT VALUE FOR s;
+INSERT INTO t1 (id) VALUES (NEXT VALUE FOR db1.s);
+SELECT NEXTVAL(s), LASTVAL(s);
+INSERT INTO t1 VALUES (1, 'a'), (2, 'b');
+INSERT INTO t1 (id, name) VALUES (1, 'a') RETURNING id, name;
+INSERT INTO t2 SELECT * FROM t1 RETURNING *;
+DELETE FROM t1 WHERE id = 1 RETURNING id;
+DELETE FROM t1 RETURNING *;
+UPDATE t1 SET name = 'x' WHERE id = 1;
+CREATE TABLE t1 (id INT NOT NULL, name VARCHAR(20), PRIMARY KEY (id));
+CREATE TABLE t2 (x INT) WITH SYSTEM VERSIONING;
+-- unsupported: generated row period columns
+CREATE TABLE t3 (x INT, start_ts TIMESTAMP(6) GENERATED ALWAYS AS ROW START, end_ts TIMESTAMP(6) GENERATED ALWAYS AS ROW END, PERIOD FOR SYSTEM_TIME (start_ts, end_ts)) WITH SYSTEM VERSIONING;
+ALTER TABLE t1 ADD SYSTEM VERSIONING;
+ALTER TABLE t1 DROP SYSTEM VERSIONING;
+CREATE SEQUENCE s START WITH 100 INCREMENT BY 10;
+CREATE SEQUENCE IF NOT EXISTS s2 MINVALUE 1 MAXVALUE 1000 CYCLE CACHE 100;
+CREATE OR REPLACE SEQUENCE s3 NOMAXVALUE NOMINVALUE NOCYCLE NOCACHE;
+CREATE SEQUENCE s4 START WITH -1 INCREMENT BY -1 NO MINVALUE NO CYCLE;
+-- unsupported: ALTER SEQUENCE
+ALTER SEQUENCE s RESTART WITH 1;
+DROP SEQUENCE IF EXISTS s, s2;
+DROP TABLE t1;
+START TRANSACTION;
+COMMIT;
+CREATE TABLE t1 (a INT, b VARCHAR(10)) PARTITION BY LIST COLUMNS (b) (PARTITION p0 VALUES IN ('x', 'y') ENGINE = InnoDB, PARTITION p1 VALUES IN ('z') COMMENT = 'last');
+SELECT a, b FROM t1 WHERE a > 1 INTO OUTFILE '/tmp/t1.csv' FIELDS TERMINATED BY ',' OPTIONALLY ENCLOSED BY '"' LINES TERMINATED BY ';';
+SELECT a FROM t1 LIMIT 1 INTO DUMPFILE '/tmp/t1.bin';
+-- unsupported: INTO OUTFILE before the FROM clause
+SELECT a INTO OUTFILE '/tmp/t1.csv' FROM t1;
+CREATE TABLE 1t (2col INT, `123` INT);
+SELECT 2col, `123` FROM 1t WHERE 2col > 10;
@@ -0,0 +1,137 @@
+-- Hand-written statements modelled on mysqldump output and the MySQL employees sample
+-- database, not copied from them.
+
+SELECT 1;
+SELECT 5--7;
+SELECT 'foo' 'bar';
+SELECT `emp_no`, `first_name` FROM `employees` WHERE `emp_no` < 10010;
+SELECT e.emp_no, s.salary FROM employees AS e INNER JOIN salaries AS s ON e.emp_no = s.emp_no;
+SELECT e.emp_no, d.dept_name FROM employees e LEFT JOIN dept_emp de ON e.emp_no = de.emp_no LEFT JOIN departments d ON de.dept_no = d.dept_no;
+SELECT dept_no, COUNT(*) AS cnt FROM dept_emp GROUP BY dept_no HAVING cnt > 100 ORDER BY cnt DESC;
+SELECT dept_no AS dept, COUNT(*) FROM dept_emp GROUP BY 1;
+SELECT * FROM employees ORDER BY hire_date LIMIT 10;
+SELECT * FROM employees LIMIT 10 OFFSET 20;
+-- unsupported: LIMIT offset, count
+SELECT * FROM employees LIMIT 20, 10;
+SELECT DISTINCT title FROM titles;
+SELECT CONCAT(first_name, ' ', last_name) AS name FROM employees;
+SELECT IF(gender = 'M', 'male', 'female') FROM employees;
+SELECT IFNULL(to_date, NOW()) FROM dept_emp;
+SELECT CAST(salary AS CHAR) FROM salaries;
+SELECT EXTRACT(YEAR FROM hire_date) FROM employees;
+SELECT SUBSTRING(first_name, 1, 3) FROM employees;
+SELECT TRIM(LEADING ' ' FROM first_name) FROM employees;
+SELECT POSITION('a' IN first_name) FROM employees;
+SELECT _utf8mb4'abc';
+SELECT 5 DIV 2, 5 MOD 2, 1 XOR 0;
+-- unsupported: REGEXP operator
+SELECT 'a' REGEXP '^a';
+SELECT * FROM employees WHERE first_name LIKE 'Geo%' AND last_name NOT IN ('Facello', 'Simmel');
+SELECT * FROM employees WHERE hire_date BETWEEN '1990-01-01' AND '1990-12-31';
+SELECT * FROM salaries WHERE emp_no IN (SELECT emp_no FROM employees WHERE gender = 'F');
+SELECT * FROM employees WHERE EXISTS (SELECT 1 FROM titles WHERE titles.emp_no = employees.emp_no);
+SELECT * FROM salaries WHERE emp_no IN (SELECT emp_no FROM dept_emp UNION SELECT emp_no FROM dept_manager);
+SELECT emp_no, salary - (SELECT AVG(salary) FROM salaries) FROM salaries WHERE salary > (SELECT AVG(salary) FROM salaries) + 1000;
+SELECT emp_no, SUM(CASE WHEN salary > 60000 THEN 1 ELSE 0 END) OVER (PARTITION BY emp_no ORDER BY from_date) FROM salaries;
+SELECT CASE gender WHEN 'M' THEN 1 ELSE 0 END FROM employees;
+SELECT COALESCE(NULL, 'x'), NULLIF(1, 2), GREATEST(1, 2, 3), LEAST(3, 4);
+SELECT emp_no FROM employees UNION SELECT emp_no FROM salaries;
+SELECT * FROM (SELECT emp_no FROM employees) AS t;
+-- unsupported: system variables
+SELECT @@version;
+-- unsupported: user variables and `:=`
+SELECT @total := 0;
+SELECT * FROM employees FORCE INDEX (PRIMARY) WHERE emp_no = 1;
+SELECT * FROM employees AS e USE INDEX FOR ORDER BY (idx_hire_date) IGNORE KEY (idx_name) ORDER BY hire_date;
+SELECT * FROM employees PARTITION (p0, p1) WHERE emp_no < 100;
+SELECT * FROM employees WHERE hire_date < CURRENT_DATE();
+SELECT e.emp_no id, d.dept_no FROM employees e JOIN dept_emp d ON e.emp_no = d.emp_no;
+SELECT * FROM employees e JOIN (dept_emp de JOIN departments d ON de.dept_no = d.dept_no) ON e.emp_no = de.emp_no;
+-- unsupported: table reference list in parentheses
+SELECT * FROM (employees, dept_emp) JOIN departments USING (dept_no);
+-- unsupported: SELECT modifiers
+SELECT SQL_NO_CACHE * FROM employees;
+-- unsupported: locking clause
+SELECT * FROM employees FOR UPDATE;
+-- unsupported: GROUP_CONCAT SEPARATOR
+SELECT GROUP_CONCAT(title SEPARATOR ',') FROM titles;
+INSERT INTO `departments` VALUES ('d001', 'Marketing'), ('d002', 'Finance');
+INSERT INTO departments (dept_no, dept_name) VALUES ('d010', 'Research');
+INSERT INTO departments (dept_no, dept_name) SELECT dept_no, dept_name FROM departments_backup;
+-- unsupported: INSERT IGNORE
+INSERT IGNORE INTO departments VALUES ('d001', 'Marketing');
+-- unsupported: ON DUPLICATE KEY UPDATE
+INSERT INTO departments VALUES ('d001', 'Marketing') ON DUPLICATE KEY UPDATE dept_name = 'Marketing';
+REPLACE INTO departments VALUES ('d001', 'Marketing');
+REPLACE INTO departments SET dept_no = 'd001', dept_name = 'Marketing';
+INSERT INTO departments SET dept_no = 'd010', dept_name = 'Support';
+INSERT INTO departments () VALUES ();
+UPDATE salaries SET salary = salary * 1.1 WHERE emp_no = 10001;
+-- unsupported: UPDATE ... ORDER BY ... LIMIT
+UPDATE salaries SET salary = salary + 1 ORDER BY emp_no LIMIT 10;
+DELETE FROM salaries WHERE emp_no = 10001;
+-- unsupported: DELETE ... LIMIT
+DELETE FROM salaries WHERE emp_no = 10001 LIMIT 1;
+DROP TABLE IF EXISTS `employees`;
+CREATE TABLE `employees` (`emp_no` INT NOT NULL, `birth_date` DATE NOT NULL, `first_name` VARCHAR(14) NOT NULL, `last_name` VARCHAR(16) NOT NULL, `hire_date` DATE NOT NULL, PRIMARY KEY (`emp_no`));
+CREATE TABLE `departments` (`dept_no` CHAR(4) NOT NULL, `dept_name` VARCHAR(40) NOT NULL, PRIMARY KEY (`dept_no`), UNIQUE (`dept_name`));
+-- unsupported: unnamed FOREIGN KEY table constraint
+CREATE TABLE `dept_emp` (`emp_no` INT NOT NULL, `dept_no` CHAR(4) NOT NULL, FOREIGN KEY (`emp_no`) REFERENCES `employees` (`emp_no`) ON DELETE CASCADE);
+-- unsupported: table options
+CREATE TABLE `salaries` (`emp_no` INT NOT NULL, `salary` INT NOT NULL, `from_date` DATE NOT NULL) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
+-- unsupported: AUTO_INCREMENT column option
+CREATE TABLE `titles` (`emp_no` INT NOT NULL, `title` VARCHAR(50) NOT NULL, `id` INT NOT NULL AUTO_INCREMENT, PRIMARY KEY (`id`));
+CREATE TABLE `gender_t` (`gender` ENUM('M', 'F') NOT NULL);
+CREATE TABLE `names` (`name` VARCHAR(255) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin NOT NULL, `note` TEXT CHARSET latin1);
+-- unsupported: DATETIME data type
+CREATE TABLE t1 (a INT UNSIGNED, b TINYINT, c BIGINT, d DECIMAL(10, 2), e TEXT, f DATETIME, g BLOB);
+CREATE TABLE t2 LIKE t1;
+-- unsupported: CREATE TEMPORARY TABLE
+CREATE TEMPORARY TABLE tmp (a INT);
+CREATE VIEW `dept_emp_latest_date` AS SELECT emp_no, MAX(from_date) AS from_date FROM dept_emp GROUP BY emp_no;
+CREATE OR REPLACE VIEW v1 AS SELECT 1;
+CREATE ALGORITHM=MERGE DEFINER=`root`@`localhost` SQL SECURITY DEFINER VIEW v2 AS SELECT * FROM t1;
+CREATE DATABASE IF NOT EXISTS employees;
+-- unsupported: CREATE INDEX
+CREATE INDEX idx_last_name ON employees (last_name);
+ALTER TABLE employees ADD COLUMN middle_name VARCHAR(14);
+ALTER TABLE employees DROP COLUMN middle_name;
+ALTER TABLE employees RENAME TO staff;
+-- unsupported: ALTER TABLE ... MODIFY COLUMN
+ALTER TABLE staff MODIFY COLUMN first_name VARCHAR(20);
+RENAME TABLE staff TO employees;
+-- unsupported: TRUNCATE
+TRUNCATE TABLE salaries;
+-- unsupported: LOCK TABLES
+LOCK TABLES `employees` WRITE;
+-- unsupported: UNLOCK TABLES
+UNLOCK TABLES;
+START TRANSACTION;
+BEGIN;
+SET TRANSACTION ISOLATION LEVEL READ COMMITTED;
+COMMIT;
+ROLLBACK;
+-- unsupported: SET NAMES
+SET NAMES utf8mb4;
+-- unsupported: SET variable assignment
+SET @OLD_UNIQUE_CHECKS = @@UNIQUE_CHECKS, UNIQUE_CHECKS = 0;
+-- unsupported: USE
+USE employees;
+-- unsupported: SHOW
+SHOW TABLES;
+-- unsupported: DESCRIBE
+DESCRIBE employees;
+CREATE USER 'jeffrey'@'localhost' IDENTIFIED BY 'password';
+CREATE USER IF NOT EXISTS 'u1'@'%' IDENTIFIED WITH caching_sha2_password BY 'secret', 'u2'@'%';
+CREATE ROLE 'app_read', 'app_write';
+DROP USER 'jeffrey'@'localhost';
+CREATE SERVER s FOREIGN DATA WRAPPER mysql OPTIONS (USER 'Remote', HOST '198.51.100.106', DATABASE 'test');
+CREATE TABLE employees (id INT NOT NULL, store_id INT NOT NULL) PARTITION BY RANGE (store_id) (PARTITION p0 VALUES LESS THAN (6), PARTITION p1 VALUES LESS THAN (11), PARTITION p3 VALUES LESS THAN MAXVALUE);
+CREATE TABLE ts (id INT, purchased DATE) PARTITION BY RANGE (YEAR(purchased)) SUBPARTITION BY HASH (TO_DAYS(purchased)) SUBPARTITIONS 2 (PARTITION p0 VALUES LESS THAN (1990), PARTITION p1 VALUES LESS THAN MAXVALUE);
+CREATE TABLE tk (col1 INT, col2 CHAR(5)) PARTITION BY LINEAR KEY (col1) PARTITIONS 3;
+SELECT a, b FROM t1 WHERE a > 1 INTO OUTFILE '/tmp/t1.csv' FIELDS TERMINATED BY ',' OPTIONALLY ENCLOSED BY '"' LINES TERMINATED BY ';';
+SELECT a FROM t1 LIMIT 1 INTO DUMPFILE '/tmp/t1.bin';
+-- unsupported: INTO OUTFILE before the FROM clause
+SELECT a INTO OUTFILE '/tmp/t1.csv' FROM t1;
+CREATE TABLE 1t (2col INT, `123` INT);
+SELECT 2col, `123` FROM 1t WHERE 2col > 10;
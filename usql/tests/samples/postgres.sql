@@ -0,0 +1,177 @@
+-- Hand-written queries modelled on the PostgreSQL regression tests (src/test/regress),
+-- not copied from them; the table names follow the regression database.
+
+SELECT 1;
+SELECT 1 + 2 * 3 AS result;
+SELECT 'hello' || ' ' || 'world';
+SELECT * FROM onek WHERE onek.unique1 < 10 ORDER BY unique1;
+SELECT onek.unique1, onek.stringu1 FROM onek WHERE onek.unique1 < 20 ORDER BY unique1 DESC;
+-- unsupported: SELECT INTO
+SELECT two, stringu1, ten, string4 INTO TABLE tmp FROM onek;
+SELECT DISTINCT two FROM tmp ORDER BY 1;
+-- unsupported: DISTINCT ON and ORDER BY USING
+SELECT DISTINCT ON (string4) string4, two, ten FROM tmp ORDER BY string4 USING <, two USING >, ten USING <;
+SELECT count(*) FROM tenk1 WHERE unique1 IN (1, 42, 7);
+SELECT ten, count(*), sum(four) FROM onek GROUP BY ten HAVING count(*) > 1 ORDER BY ten;
+SELECT ten, count(*) FROM onek GROUP BY 1 ORDER BY 2 DESC;
+SELECT a.unique1, b.unique2 FROM tenk1 a JOIN tenk2 b ON a.unique1 = b.unique2 WHERE a.ten = 4;
+SELECT * FROM j1_tbl LEFT OUTER JOIN j2_tbl USING (i) ORDER BY i, k, t;
+SELECT * FROM j1_tbl NATURAL JOIN j2_tbl;
+SELECT * FROM j1_tbl CROSS JOIN j2_tbl;
+SELECT * FROM (j1_tbl JOIN j2_tbl USING (i)) JOIN j3_tbl USING (i);
+SELECT * FROM j1_tbl, j2_tbl LEFT JOIN j3_tbl ON j2_tbl.i = j3_tbl.i;
+SELECT * FROM (SELECT unique1 FROM tenk1 LIMIT 10) AS ss;
+SELECT unique1 FROM tenk1 ORDER BY unique1 LIMIT 5 OFFSET 10;
+SELECT unique1 FROM tenk1 ORDER BY unique1 OFFSET 10 ROWS FETCH FIRST 5 ROWS ONLY;
+SELECT f1 FROM int4_tbl UNION SELECT f1 FROM int8_tbl;
+SELECT f1 FROM int4_tbl UNION ALL SELECT f1 FROM int8_tbl ORDER BY 1;
+SELECT f1 FROM int4_tbl INTERSECT SELECT f1 FROM int8_tbl;
+SELECT f1 FROM int4_tbl EXCEPT SELECT f1 FROM int8_tbl;
+WITH q1 AS (SELECT 1 AS x) SELECT * FROM q1;
+WITH RECURSIVE t (n) AS (VALUES (1) UNION ALL SELECT n + 1 FROM t WHERE n < 100) SELECT sum(n) FROM t;
+WITH old AS (SELECT unique1 FROM tenk1 WHERE ten = 1) DELETE FROM onek WHERE unique1 IN (SELECT unique1 FROM old);
+WITH moved AS (DELETE FROM tenk1 WHERE ten = 1 RETURNING *) INSERT INTO onek SELECT * FROM moved;
+WITH u AS (UPDATE onek SET ten = 0 WHERE ten = 1 RETURNING *) SELECT count(*) FROM u;
+SELECT CASE WHEN unique1 < 10 THEN 'small' ELSE 'big' END FROM tenk1;
+SELECT CAST(f1 AS TEXT) FROM int4_tbl;
+SELECT f1::TEXT FROM int4_tbl;
+SELECT EXTRACT(YEAR FROM d1) FROM date_tbl;
+SELECT SUBSTRING('hello' FROM 2 FOR 3);
+SELECT TRIM(BOTH 'x' FROM 'xxhixx');
+SELECT POSITION('b' IN 'abc');
+SELECT COALESCE(NULL, 1, 2), NULLIF(1, 1), GREATEST(1, 2), LEAST(1, 2);
+SELECT * FROM tenk1 WHERE EXISTS (SELECT 1 FROM tenk2 WHERE tenk2.unique1 = tenk1.unique1);
+SELECT * FROM tenk1 WHERE unique1 IN ((SELECT unique1 FROM tenk2) EXCEPT (SELECT unique2 FROM onek));
+SELECT * FROM tenk1 WHERE EXISTS ((SELECT 1 FROM tenk2) UNION (SELECT 1 FROM onek));
+SELECT unique1, COALESCE((SELECT max(unique2) FROM tenk2 WHERE tenk2.unique1 = tenk1.unique1), 0) + 1 FROM tenk1;
+SELECT * FROM tenk1 WHERE unique1 BETWEEN 10 AND 20;
+SELECT * FROM tenk1 WHERE stringu1 LIKE 'A%' AND stringu2 NOT LIKE '%Z';
+SELECT * FROM tenk1 WHERE stringu1 ILIKE 'a%';
+SELECT * FROM tenk1 WHERE f1 IS NULL OR f1 IS NOT NULL;
+SELECT * FROM tenk1 WHERE f1 IS DISTINCT FROM 1;
+-- unsupported: SIMILAR TO
+SELECT * FROM tenk1 WHERE f1 SIMILAR TO 'a%';
+SELECT * FROM tenk1 WHERE f1 ~ '^a' AND f1 !~ 'b$';
+-- unsupported: ORDER BY inside aggregate calls
+SELECT array_agg(f1 ORDER BY f1) FROM int4_tbl;
+SELECT count(DISTINCT ten) FROM tenk1;
+SELECT row_number() OVER (PARTITION BY ten ORDER BY unique1) FROM tenk1;
+SELECT CURRENT_TIMESTAMP, LOCALTIME(2), CURRENT_USER FROM tenk1;
+SELECT t.unique1 u1, t.ten FROM tenk1 t WHERE t.ten = 1;
+SELECT sum(unique1) OVER (ORDER BY unique1 ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM tenk1;
+SELECT depname, sum(salary) OVER w, rank() OVER (w ORDER BY salary DESC) FROM empsalary WINDOW w AS (PARTITION BY depname);
+SELECT depname, sum(CASE WHEN salary > 5000 THEN 1 ELSE 0 END) OVER (PARTITION BY depname ORDER BY empno) FROM empsalary;
+SELECT ARRAY[1, 2, 3];
+SELECT ARRAY[[1, 2], [3, 4]], ARRAY(SELECT f1 FROM int4_tbl);
+SELECT (compos).f1, (compos).* FROM compos_tbl;
+SELECT '{1,2,3}'::INT[];
+-- unsupported: positional parameters
+SELECT $1 + $2;
+SELECT E'it\'s\n';
+-- unsupported: locking clause
+SELECT * FROM tenk1 FOR UPDATE;
+SELECT * FROM generate_series(1, 10) AS g (i);
+-- unsupported: TABLESAMPLE
+SELECT * FROM tenk1 TABLESAMPLE SYSTEM (10);
+VALUES (1, 'one'), (2, 'two');
+INSERT INTO onek VALUES (1, 2, 'abc');
+INSERT INTO onek (unique1, unique2) VALUES (1, 2), (3, 4);
+INSERT INTO onek DEFAULT VALUES;
+-- unsupported: empty column list
+INSERT INTO onek () VALUES ();
+INSERT INTO onek SELECT * FROM tenk1;
+INSERT INTO onek (unique1) VALUES (1) RETURNING *;
+-- unsupported: ON CONFLICT
+INSERT INTO onek (unique1) VALUES (1) ON CONFLICT (unique1) DO NOTHING;
+UPDATE onek SET unique1 = unique1 + 1 WHERE ten = 2;
+UPDATE onek SET unique1 = unique1 + 1 WHERE ten = 2 RETURNING unique1, ten;
+-- unsupported: UPDATE ... FROM
+UPDATE onek SET unique1 = t.x FROM tmp AS t WHERE onek.ten = t.y;
+DELETE FROM onek WHERE unique1 < 10;
+-- unsupported: DELETE ... USING
+DELETE FROM onek USING tmp WHERE onek.unique1 = tmp.x;
+CREATE TABLE hobbies_r (name TEXT, person TEXT);
+CREATE TABLE equipment_r (name TEXT, hobby TEXT NOT NULL DEFAULT 'none', PRIMARY KEY (name));
+CREATE TABLE IF NOT EXISTS t1 (a INT PRIMARY KEY, b VARCHAR(10) UNIQUE, c NUMERIC(10, 2) CHECK (c > 0));
+CREATE TABLE t2 (a INT REFERENCES t1 (a) ON DELETE CASCADE);
+-- unsupported: CREATE TEMPORARY TABLE
+CREATE TEMPORARY TABLE temp_t (a INT);
+CREATE TABLE t3 AS SELECT * FROM t1;
+CREATE TABLE t4 (a SERIAL, b TIMESTAMP WITH TIME ZONE, c INTERVAL, d BOOLEAN);
+CREATE TABLE matrix (m INT[][], n TEXT[3][3]);
+CREATE VIEW street AS SELECT r.name, r.thepath FROM road r;
+CREATE OR REPLACE VIEW v1 AS SELECT 1 AS x;
+CREATE VIEW secure_users WITH (security_barrier) AS SELECT id, name FROM users WHERE NOT hidden;
+CREATE DOMAIN posint AS INT CHECK (VALUE > 0);
+CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy');
+CREATE DATABASE regression;
+-- unsupported: CREATE INDEX
+CREATE INDEX onek_unique1 ON onek USING btree (unique1 int4_ops);
+-- unsupported: CREATE SCHEMA
+CREATE SCHEMA test_schema;
+CREATE SEQUENCE seq1 START WITH 1 INCREMENT BY 1;
+-- unsupported: CREATE FUNCTION
+CREATE FUNCTION add(INT, INT) RETURNS INT AS 'select $1 + $2' LANGUAGE SQL;
+ALTER TABLE t1 ADD COLUMN d INT;
+ALTER TABLE t1 DROP COLUMN d;
+ALTER TABLE t1 RENAME TO t5;
+-- unsupported: ALTER TABLE ... ALTER COLUMN
+ALTER TABLE t5 ALTER COLUMN b SET DEFAULT 'x';
+-- unsupported: ALTER TABLE ... ADD CONSTRAINT
+ALTER TABLE t5 ADD CONSTRAINT t5_c_check CHECK (c > 1);
+ALTER VIEW v1 RENAME TO v2;
+ALTER DOMAIN posint SET DEFAULT 1;
+-- unsupported: ALTER TYPE ... RENAME TO
+ALTER TYPE mood RENAME TO feeling;
+ALTER SCHEMA test_schema RENAME TO test_schema2;
+ALTER INDEX onek_unique1 RENAME TO onek_u1;
+DROP TABLE t1;
+DROP TABLE IF EXISTS t1, t2 CASCADE;
+DROP VIEW v2;
+DROP INDEX onek_u1;
+DROP DOMAIN posint;
+DROP TYPE feeling;
+DROP SCHEMA test_schema2;
+-- unsupported: TRUNCATE
+TRUNCATE TABLE onek;
+BEGIN;
+START TRANSACTION ISOLATION LEVEL SERIALIZABLE;
+SET TRANSACTION READ ONLY;
+COMMIT;
+ROLLBACK;
+-- unsupported: SAVEPOINT
+SAVEPOINT sp1;
+-- unsupported: ROLLBACK TO SAVEPOINT
+ROLLBACK TO SAVEPOINT sp1;
+-- unsupported: EXPLAIN
+EXPLAIN SELECT * FROM onek;
+-- unsupported: ANALYZE
+ANALYZE onek;
+-- unsupported: SET configuration parameter
+SET search_path TO public;
+-- unsupported: SHOW
+SHOW search_path;
+-- unsupported: GRANT
+GRANT SELECT ON onek TO PUBLIC;
+-- unsupported: COPY
+COPY onek FROM STDIN;
+DO $$ BEGIN IF NOT EXISTS (SELECT 1 FROM pg_type WHERE typname = 'mood') THEN CREATE TYPE mood AS ENUM ('sad', 'ok'); END IF; END $$;
+CREATE POLICY account_managers ON accounts TO managers USING (manager = current_user);
+CREATE POLICY user_mod ON passwd AS RESTRICTIVE FOR UPDATE USING (current_user = user_name) WITH CHECK (current_user = user_name AND shell IN ('/bin/bash', '/bin/sh'));
+ALTER TABLE accounts ENABLE ROW LEVEL SECURITY;
+ALTER TABLE accounts FORCE ROW LEVEL SECURITY;
+DROP POLICY IF EXISTS account_managers ON accounts;
+CREATE ROLE miriam WITH LOGIN PASSWORD 'jw8s0F4' VALID UNTIL '2005-01-01';
+CREATE USER davide CREATEDB NOINHERIT IN ROLE admins;
+ALTER ROLE worker_bee SET maintenance_work_mem = 100000;
+ALTER ROLE fred IN DATABASE devel RESET ALL;
+DROP ROLE IF EXISTS jonathan;
+CREATE SERVER myserver FOREIGN DATA WRAPPER postgres_fdw OPTIONS (host 'foo', dbname 'foodb', port '5432');
+CREATE USER MAPPING FOR bob SERVER foo OPTIONS (user 'bob', password 'secret');
+CREATE FOREIGN TABLE films (code char(5) NOT NULL, title varchar(40) NOT NULL, did integer NOT NULL, date_prod date, kind varchar(10), len interval) SERVER film_server;
+IMPORT FOREIGN SCHEMA foreign_films LIMIT TO (actors, directors) FROM SERVER film_server INTO films;
+CREATE TABLE measurement (city_id int NOT NULL, logdate date NOT NULL, peaktemp int) PARTITION BY RANGE (logdate);
+CREATE TABLE measurement_y2006m02 PARTITION OF measurement FOR VALUES FROM ('2006-02-01') TO ('2006-03-01');
+CREATE TABLE orders_p1 PARTITION OF orders FOR VALUES WITH (MODULUS 4, REMAINDER 0);
+ALTER TABLE measurement ATTACH PARTITION measurement_y2008m02 FOR VALUES FROM ('2008-02-01') TO ('2008-03-01');
+ALTER TABLE measurement DETACH PARTITION measurement_y2006m02;
@@ -0,0 +1,124 @@
+-- Hand-written statements modelled on the SQLite TCL test suite, not copied from it.
+
+SELECT 1;
+SELECT 1, 2, 3;
+SELECT a, b FROM t1 WHERE a > 1 ORDER BY b;
+SELECT a x, b y FROM t1 t WHERE t.a > 1;
+SELECT * FROM t1, t2 WHERE t1.a = t2.b;
+SELECT * FROM t1 JOIN t2 ON t1.a = t2.b;
+SELECT * FROM t1 LEFT JOIN t2 USING (a);
+SELECT * FROM t1 NATURAL JOIN t2;
+SELECT * FROM t1 CROSS JOIN t2;
+SELECT count(*), max(a), min(b), avg(c), sum(d), total(e) FROM t1;
+SELECT a, count(*) FROM t1 GROUP BY a HAVING count(*) > 1;
+SELECT a, count(*) FROM t1 GROUP BY 1;
+SELECT * FROM t1 ORDER BY a DESC LIMIT 5 OFFSET 2;
+SELECT * FROM t1 ORDER BY a NULLS FIRST;
+SELECT DISTINCT a FROM t1;
+SELECT a FROM t1 UNION SELECT b FROM t2;
+SELECT a FROM t1 UNION ALL SELECT b FROM t2;
+SELECT a FROM t1 INTERSECT SELECT b FROM t2;
+SELECT a FROM t1 EXCEPT SELECT b FROM t2;
+SELECT * FROM (SELECT a FROM t1) AS sub;
+SELECT * FROM t1 WHERE a IN (SELECT b FROM t2);
+SELECT * FROM t1 WHERE EXISTS (SELECT 1 FROM t2 WHERE t2.b = t1.a);
+SELECT * FROM t1 WHERE a BETWEEN 1 AND 10;
+SELECT * FROM t1 WHERE b LIKE 'abc%';
+-- unsupported: GLOB operator
+SELECT * FROM t1 WHERE b GLOB 'abc*';
+SELECT * FROM t1 WHERE a IS NULL;
+-- unsupported: IS [NOT] with arbitrary operands
+SELECT * FROM t1 WHERE a IS NOT 5;
+-- unsupported: NOTNULL postfix operator
+SELECT * FROM t1 WHERE a NOTNULL;
+SELECT CASE WHEN a > 0 THEN 'pos' WHEN a < 0 THEN 'neg' ELSE 'zero' END FROM t1;
+-- unsupported: TEXT data type
+SELECT CAST(a AS TEXT) FROM t1;
+SELECT 'abc' || 'def';
+SELECT coalesce(a, b), nullif(a, b), ifnull(a, 0), iif(a > 0, 1, 0) FROM t1;
+SELECT substr('hello', 2, 3), length('hello'), upper('x'), lower('X');
+SELECT typeof(a) FROM t1;
+SELECT a FROM t1 WHERE rowid = 1;
+SELECT x'0102';
+-- unsupported: bind parameters
+SELECT ?1, :name, @var, $var;
+SELECT [a], "b", `c` FROM [t1];
+SELECT row_number() OVER (ORDER BY a) FROM t1;
+SELECT a, sum(CASE b WHEN 0 THEN 0 ELSE 1 END) OVER (ORDER BY a ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM t1;
+WITH c AS (SELECT 1 AS x) SELECT x FROM c;
+WITH RECURSIVE cnt (x) AS (VALUES (1) UNION ALL SELECT x + 1 FROM cnt WHERE x < 10) SELECT x FROM cnt;
+WITH c AS (SELECT 1 AS x) INSERT INTO t1 SELECT x FROM c;
+VALUES (1, 2), (3, 4);
+INSERT INTO t1 VALUES (1, 'one');
+INSERT INTO t1 (a, b) VALUES (1, 'one'), (2, 'two');
+INSERT INTO t1 SELECT * FROM t2;
+INSERT INTO t1 DEFAULT VALUES;
+INSERT OR REPLACE INTO t1 VALUES (1, 'x');
+-- unsupported: REPLACE
+REPLACE INTO t1 VALUES (1, 'x');
+-- unsupported: ON CONFLICT
+INSERT INTO t1 VALUES (1, 'x') ON CONFLICT (a) DO NOTHING;
+UPDATE t1 SET b = 'x' WHERE a = 1;
+UPDATE t1 SET b = 'y' WHERE a = 2 RETURNING a, b;
+-- unsupported: UPDATE OR <conflict resolution>
+UPDATE OR IGNORE t1 SET b = 'x';
+DELETE FROM t1 WHERE a = 1;
+DELETE FROM t1;
+-- unsupported: column definitions without data type
+CREATE TABLE t1 (a, b);
+-- unsupported: INTEGER, TEXT, REAL and BLOB data types
+CREATE TABLE t2 (a INTEGER PRIMARY KEY, b TEXT NOT NULL, c REAL, d BLOB);
+-- unsupported: INTEGER and TEXT data types
+CREATE TABLE t3 (a INTEGER PRIMARY KEY AUTOINCREMENT, b TEXT UNIQUE);
+-- unsupported: INTEGER and TEXT data types
+CREATE TABLE t4 (a TEXT DEFAULT 'x', b INTEGER CHECK (b > 0));
+-- unsupported: INTEGER data type
+CREATE TABLE t5 (a INTEGER REFERENCES t2 (a));
+-- unsupported: TEXT data type
+CREATE TABLE t6 (a TEXT, b TEXT, PRIMARY KEY (a, b)) WITHOUT ROWID;
+-- unsupported: TEXT data type
+CREATE TABLE IF NOT EXISTS t7 (a TEXT);
+-- unsupported: CREATE TEMP TABLE
+CREATE TEMP TABLE t8 (a TEXT);
+CREATE TABLE t9 AS SELECT * FROM t1;
+CREATE VIEW v1 AS SELECT a FROM t1;
+-- unsupported: CREATE INDEX
+CREATE INDEX i1 ON t1 (a);
+-- unsupported: CREATE UNIQUE INDEX
+CREATE UNIQUE INDEX IF NOT EXISTS i2 ON t1 (a, b DESC);
+-- unsupported: CREATE TRIGGER
+CREATE TRIGGER r1 AFTER INSERT ON t1 BEGIN UPDATE t2 SET a = 1; END;
+-- unsupported: CREATE VIRTUAL TABLE
+CREATE VIRTUAL TABLE ft USING fts5 (content);
+ALTER TABLE t1 RENAME TO t10;
+-- unsupported: TEXT data type
+ALTER TABLE t10 ADD COLUMN c TEXT;
+-- unsupported: ALTER TABLE ... RENAME COLUMN
+ALTER TABLE t10 RENAME COLUMN c TO d;
+ALTER TABLE t10 DROP COLUMN d;
+DROP TABLE t10;
+DROP TABLE IF EXISTS t2;
+DROP VIEW v1;
+DROP INDEX i1;
+-- unsupported: DROP TRIGGER
+DROP TRIGGER r1;
+BEGIN;
+BEGIN TRANSACTION;
+BEGIN IMMEDIATE;
+COMMIT;
+-- unsupported: END
+END;
+ROLLBACK;
+-- unsupported: SAVEPOINT
+SAVEPOINT s1;
+-- unsupported: RELEASE
+RELEASE s1;
+PRAGMA foreign_keys = ON;
+PRAGMA table_info(t1);
+ATTACH DATABASE 'test.db' AS aux;
+DETACH aux;
+VACUUM;
+ANALYZE;
+REINDEX;
+-- unsupported: EXPLAIN QUERY PLAN
+EXPLAIN QUERY PLAN SELECT * FROM t1;